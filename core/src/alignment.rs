@@ -14,6 +14,12 @@ pub enum Alignment {
 
     /// Fill the entire axis.
     Fill,
+
+    /// Align the baselines of the items across a horizontal axis.
+    ///
+    /// Items without a baseline are aligned by their bottom edge. Along a
+    /// vertical axis, it behaves like [`Alignment::Start`].
+    Baseline,
 }
 
 impl From<Horizontal> for Alignment {
@@ -17,6 +17,32 @@ pub enum Font {
     },
 }
 
+impl PartialEq for Font {
+    fn eq(&self, other: &Font) -> bool {
+        match (self, other) {
+            (Font::Default, Font::Default) => true,
+            (
+                Font::External {
+                    name: name_a,
+                    bytes: bytes_a,
+                },
+                Font::External {
+                    name: name_b,
+                    bytes: bytes_b,
+                },
+            ) => {
+                // The bytes of a font are usually shared, so comparing their
+                // address first avoids comparing their contents.
+                name_a == name_b
+                    && (std::ptr::eq(*bytes_a, *bytes_b) || bytes_a == bytes_b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Font {}
+
 impl Default for Font {
     fn default() -> Font {
         Font::Default
@@ -16,6 +16,25 @@ pub enum Length {
     /// Fill the least amount of space
     Shrink,
 
+    /// Fill the least amount of space, but never more than the given amount
+    /// of units.
+    ///
+    /// This is equivalent to [`Length::Shrink`] combined with a maximum size.
+    /// Like [`Length::Shrink`], it does not take any of the remaining space
+    /// away from [`Length::Fill`] siblings.
+    FitContent(u16),
+
+    /// Fill the given amount of units when there is room for it, shrinking
+    /// below it only when space runs out.
+    ///
+    /// This sits between [`Length::Units`] and [`Length::Fill`]: it never
+    /// grows past its preferred size, but it gives way under pressure instead
+    /// of overflowing. When multiple items with a preferred size compete for
+    /// the same space, every item shrinks proportionally to its preferred
+    /// size. Like [`Length::Shrink`], it does not take any of the remaining
+    /// space away from [`Length::Fill`] siblings.
+    Preferred(u16),
+
     /// Fill a fixed amount of space
     Units(u16),
 }
@@ -31,6 +50,8 @@ impl Length {
             Length::Fill => 1,
             Length::FillPortion(factor) => *factor,
             Length::Shrink => 0,
+            Length::FitContent(_) => 0,
+            Length::Preferred(_) => 0,
             Length::Units(_) => 0,
         }
     }
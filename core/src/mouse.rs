@@ -1,8 +1,10 @@
 //! Handle mouse events.
 mod button;
+mod cursor;
 mod event;
 mod interaction;
 
 pub use button::Button;
+pub use cursor::Cursor;
 pub use event::{Event, ScrollDelta};
 pub use interaction::Interaction;
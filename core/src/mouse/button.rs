@@ -1,4 +1,18 @@
 /// The button of a mouse.
+///
+/// Widgets receive the [`Button`] of every press and release through
+/// [`Event::ButtonPressed`] and [`Event::ButtonReleased`], so they can react
+/// to any button they need.
+///
+/// Most built-in widgets, like buttons, checkboxes, and sliders, only react
+/// to the [`Button::Left`]. A few react to any [`Button`]:
+///
+/// - A scrollable is focused by a press of any [`Button`] over it.
+/// - A text input is unfocused by a press of any [`Button`] outside of it.
+/// - A pick list closes its menu on a press of any [`Button`].
+///
+/// [`Event::ButtonPressed`]: crate::mouse::Event::ButtonPressed
+/// [`Event::ButtonReleased`]: crate::mouse::Event::ButtonReleased
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 pub enum Button {
     /// The left mouse button.
@@ -10,6 +24,12 @@ pub enum Button {
     /// The middle (wheel) button.
     Middle,
 
-    /// Some other button.
-    Other(u8),
+    /// The back button, usually on the side of the mouse.
+    Back,
+
+    /// The forward button, usually on the side of the mouse.
+    Forward,
+
+    /// Some other button, identified by the code the platform reports for it.
+    Other(u16),
 }
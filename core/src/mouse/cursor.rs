@@ -0,0 +1,127 @@
+use crate::{Point, Rectangle, Vector};
+
+/// The mouse cursor state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cursor {
+    /// The cursor has a defined position.
+    Available(Point),
+
+    /// The cursor is currently unavailable (i.e. out of bounds or busy).
+    Unavailable,
+}
+
+impl Cursor {
+    /// Returns the absolute position of the [`Cursor`], if available.
+    pub fn position(self) -> Option<Point> {
+        match self {
+            Cursor::Available(position) => Some(position),
+            Cursor::Unavailable => None,
+        }
+    }
+
+    /// Returns the absolute position of the [`Cursor`], if available and
+    /// over the given bounds.
+    pub fn position_over(self, bounds: &Rectangle) -> Option<Point> {
+        self.position()
+            .filter(|position| bounds.contains(*position))
+    }
+
+    /// Returns the relative position of the [`Cursor`] inside the given bounds,
+    /// if available.
+    ///
+    /// If the [`Cursor`] is not over the provided bounds, this method will
+    /// return `None`.
+    pub fn position_in(self, bounds: &Rectangle) -> Option<Point> {
+        if self.is_over(bounds) {
+            self.position_from(bounds.position())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the relative position of the [`Cursor`] from the given origin,
+    /// if available.
+    pub fn position_from(self, origin: Point) -> Option<Point> {
+        match self {
+            Cursor::Available(position) => {
+                Some(Point::new(position.x - origin.x, position.y - origin.y))
+            }
+            Cursor::Unavailable => None,
+        }
+    }
+
+    /// Returns whether the [`Cursor`] is currently over the provided bounds
+    /// or not.
+    pub fn is_over(self, bounds: &Rectangle) -> bool {
+        self.position_over(bounds).is_some()
+    }
+}
+
+impl From<Point> for Cursor {
+    fn from(position: Point) -> Self {
+        Cursor::Available(position)
+    }
+}
+
+impl std::ops::Add<Vector> for Cursor {
+    type Output = Self;
+
+    fn add(self, translation: Vector) -> Self {
+        match self {
+            Cursor::Available(position) => {
+                Cursor::Available(position + translation)
+            }
+            Cursor::Unavailable => Cursor::Unavailable,
+        }
+    }
+}
+
+impl std::ops::Sub<Vector> for Cursor {
+    type Output = Self;
+
+    fn sub(self, translation: Vector) -> Self {
+        match self {
+            Cursor::Available(position) => {
+                Cursor::Available(position - translation)
+            }
+            Cursor::Unavailable => Cursor::Unavailable,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translation_keeps_availability() {
+        let translation = Vector::new(5.0, -10.0);
+
+        assert_eq!(
+            Cursor::Available(Point::new(1.0, 2.0)) + translation,
+            Cursor::Available(Point::new(6.0, -8.0))
+        );
+        assert_eq!(Cursor::Unavailable - translation, Cursor::Unavailable);
+    }
+
+    #[test]
+    fn positions_are_available_cursors() {
+        let position = Point::new(3.0, 4.0);
+
+        assert_eq!(Cursor::from(position), Cursor::Available(position));
+        assert_eq!(Cursor::from(position).position(), Some(position));
+    }
+
+    #[test]
+    fn unavailable_cursor_is_never_over_bounds() {
+        let bounds = Rectangle {
+            x: f32::MIN,
+            y: f32::MIN,
+            width: f32::MAX,
+            height: f32::MAX,
+        };
+
+        assert!(!Cursor::Unavailable.is_over(&bounds));
+        assert_eq!(Cursor::Unavailable.position_in(&bounds), None);
+    }
+}
@@ -1,9 +1,10 @@
 //! This example showcases a drawing a quad.
 mod quad {
     use iced_native::layout::{self, Layout};
+    use iced_native::mouse;
     use iced_native::renderer;
     use iced_native::widget::{self, Widget};
-    use iced_native::{Color, Element, Length, Point, Rectangle, Size};
+    use iced_native::{Color, Element, Length, Rectangle, Size};
 
     pub struct CustomQuad {
         size: f32,
@@ -48,7 +49,7 @@ mod quad {
             _theme: &Renderer::Theme,
             _style: &renderer::Style,
             layout: Layout<'_>,
-            _cursor_position: Point,
+            _cursor: mouse::Cursor,
             _viewport: &Rectangle,
         ) {
             renderer.fill_quad(
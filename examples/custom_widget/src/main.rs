@@ -10,9 +10,10 @@ mod circle {
     // if you wish to, by creating your own `Renderer` trait, which could be
     // implemented by `iced_wgpu` and other renderers.
     use iced_native::layout::{self, Layout};
+    use iced_native::mouse;
     use iced_native::renderer;
     use iced_native::widget::{self, Widget};
-    use iced_native::{Color, Element, Length, Point, Rectangle, Size};
+    use iced_native::{Color, Element, Length, Rectangle, Size};
 
     pub struct Circle {
         radius: f32,
@@ -55,7 +56,7 @@ mod circle {
             _theme: &Renderer::Theme,
             _style: &renderer::Style,
             layout: Layout<'_>,
-            _cursor_position: Point,
+            _cursor: mouse::Cursor,
             _viewport: &Rectangle,
         ) {
             renderer.fill_quad(
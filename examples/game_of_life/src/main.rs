@@ -186,7 +186,7 @@ fn view_controls<'a>(
         playback_controls,
         speed_controls,
         checkbox("Grid", is_grid_enabled, Message::ToggleGrid)
-            .size(16)
+            .size(16.0)
             .spacing(5)
             .text_size(16),
         pick_list(preset::ALL, Some(preset), Message::PresetPicked)
@@ -424,6 +424,26 @@ mod grid {
             };
 
             match event {
+                Event::Touch(touch::Event::Gesture(gesture)) => {
+                    let message = match gesture.kind {
+                        touch::gesture::Kind::Pinch
+                        | touch::gesture::Kind::Rotate => {
+                            Some(Message::Scaled(
+                                (self.scaling * gesture.scale).clamp(
+                                    Self::MIN_SCALING,
+                                    Self::MAX_SCALING,
+                                ),
+                                None,
+                            ))
+                        }
+                        touch::gesture::Kind::Pan => Some(Message::Translated(
+                            self.translation
+                                + gesture.translation * (1.0 / self.scaling),
+                        )),
+                    };
+
+                    (event::Status::Captured, message)
+                }
                 Event::Touch(touch::Event::FingerMoved { .. }) => {
                     let message = {
                         *interaction = if is_populated {
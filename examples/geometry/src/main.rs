@@ -15,9 +15,10 @@ mod rainbow {
     use iced_graphics::{Backend, Primitive};
 
     use iced_native::layout;
+    use iced_native::mouse;
     use iced_native::widget::{self, Widget};
     use iced_native::{
-        Element, Layout, Length, Point, Rectangle, Size, Vector,
+        Color, Element, Layout, Length, Rectangle, Size, Vector,
     };
 
     #[derive(Debug, Clone, Copy, Default)]
@@ -56,7 +57,7 @@ mod rainbow {
             _theme: &T,
             _style: &renderer::Style,
             layout: Layout<'_>,
-            cursor_position: Point,
+            cursor: mouse::Cursor,
             _viewport: &Rectangle,
         ) {
             use iced_graphics::triangle::Mesh2D;
@@ -74,12 +75,9 @@ mod rainbow {
             let color_i = [0.5, 0.0, 1.0, 1.0];
             let color_v = [0.75, 0.0, 0.5, 1.0];
 
-            let posn_center = {
-                if b.contains(cursor_position) {
-                    [cursor_position.x - b.x, cursor_position.y - b.y]
-                } else {
-                    [b.width / 2.0, b.height / 2.0]
-                }
+            let posn_center = match cursor.position_in(&b) {
+                Some(position) => [position.x, position.y],
+                None => [b.width / 2.0, b.height / 2.0],
             };
 
             let posn_tl = [0.0, 0.0];
@@ -145,9 +143,28 @@ mod rainbow {
                 },
             };
 
+            // The mesh is drawn in order with the rest of the widgets, so
+            // siblings drawn later will cover it. Wrap it in
+            // `Renderer::with_z_index` to draw it on top of them instead.
             renderer.with_translation(Vector::new(b.x, b.y), |renderer| {
                 renderer.draw_primitive(mesh);
             });
+
+            // Quads are drawn before meshes in the same layer, so the border
+            // is drawn in a new layer on top of the mesh. The layer clips it
+            // to the bounds of the widget, where it fits anyway since borders
+            // are drawn inside the bounds of a quad.
+            renderer.with_layer(b, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: b,
+                        border_radius: 4.0.into(),
+                        border_width: 2.0,
+                        border_color: Color::BLACK,
+                    },
+                    Color::TRANSPARENT,
+                );
+            });
         }
     }
 
@@ -12,6 +12,7 @@ use iced_glow::glow;
 use iced_glow::{Backend, Renderer, Settings, Viewport};
 use iced_glutin::conversion;
 use iced_glutin::glutin;
+use iced_glutin::mouse;
 use iced_glutin::renderer;
 use iced_glutin::{program, Clipboard, Color, Debug, Size};
 
@@ -56,7 +57,7 @@ pub fn main() {
         windowed_context.window().scale_factor(),
     );
 
-    let mut cursor_position = PhysicalPosition::new(-1.0, -1.0);
+    let mut cursor_position: Option<PhysicalPosition<f64>> = None;
     let mut modifiers = ModifiersState::default();
     let mut clipboard = Clipboard::connect(windowed_context.window());
 
@@ -82,7 +83,10 @@ pub fn main() {
             Event::WindowEvent { event, .. } => {
                 match event {
                     WindowEvent::CursorMoved { position, .. } => {
-                        cursor_position = position;
+                        cursor_position = Some(position);
+                    }
+                    WindowEvent::CursorLeft { .. } => {
+                        cursor_position = None;
                     }
                     WindowEvent::ModifiersChanged(new_modifiers) => {
                         modifiers = new_modifiers;
@@ -120,10 +124,15 @@ pub fn main() {
                     // We update iced
                     let _ = state.update(
                         viewport.logical_size(),
-                        conversion::cursor_position(
-                            cursor_position,
-                            viewport.scale_factor(),
-                        ),
+                        match cursor_position {
+                            Some(position) => mouse::Cursor::Available(
+                                conversion::cursor_position(
+                                    position,
+                                    viewport.scale_factor(),
+                                ),
+                            ),
+                            None => mouse::Cursor::Unavailable,
+                        },
                         &mut renderer,
                         &iced_glow::Theme::Dark,
                         &renderer::Style {
@@ -6,8 +6,8 @@ use scene::Scene;
 
 use iced_wgpu::{wgpu, Backend, Renderer, Settings, Viewport};
 use iced_winit::{
-    conversion, futures, program, renderer, winit, Clipboard, Color, Debug,
-    Size,
+    conversion, futures, mouse, program, renderer, winit, Clipboard, Color,
+    Debug, Size,
 };
 
 use winit::{
@@ -56,7 +56,7 @@ pub fn main() {
         Size::new(physical_size.width, physical_size.height),
         window.scale_factor(),
     );
-    let mut cursor_position = PhysicalPosition::new(-1.0, -1.0);
+    let mut cursor_position: Option<PhysicalPosition<f64>> = None;
     let mut modifiers = ModifiersState::default();
     let mut clipboard = Clipboard::connect(&window);
 
@@ -153,7 +153,10 @@ pub fn main() {
             Event::WindowEvent { event, .. } => {
                 match event {
                     WindowEvent::CursorMoved { position, .. } => {
-                        cursor_position = position;
+                        cursor_position = Some(position);
+                    }
+                    WindowEvent::CursorLeft { .. } => {
+                        cursor_position = None;
                     }
                     WindowEvent::ModifiersChanged(new_modifiers) => {
                         modifiers = new_modifiers;
@@ -182,10 +185,15 @@ pub fn main() {
                     // We update iced
                     let _ = state.update(
                         viewport.logical_size(),
-                        conversion::cursor_position(
-                            cursor_position,
-                            viewport.scale_factor(),
-                        ),
+                        match cursor_position {
+                            Some(position) => mouse::Cursor::Available(
+                                conversion::cursor_position(
+                                    position,
+                                    viewport.scale_factor(),
+                                ),
+                            ),
+                            None => mouse::Cursor::Unavailable,
+                        },
                         &mut renderer,
                         &iced_wgpu::Theme::Dark,
                         &renderer::Style { text_color: Color::WHITE },
@@ -117,6 +117,7 @@ impl canvas::Program<Message> for State {
                     event::Status::Captured,
                     Some(Message::FingerLifted { id }),
                 ),
+                touch::Event::Gesture(_) => (event::Status::Ignored, None),
             },
             _ => (event::Status::Ignored, None),
         }
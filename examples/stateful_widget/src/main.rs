@@ -0,0 +1,235 @@
+//! This example showcases a native custom widget with its own internal state.
+mod hold_button {
+    // A stateful widget keeps its state in the widget tree, since the widget
+    // itself is rebuilt on every `view`.
+    //
+    // The widget tells the runtime the type of its state with `tag`, creates
+    // it with `state`, and downcasts it back whenever it is given its `Tree`.
+    use iced_native::event::{self, Event};
+    use iced_native::layout::{self, Layout};
+    use iced_native::mouse;
+    use iced_native::renderer;
+    use iced_native::time::{Duration, Instant};
+    use iced_native::widget::{tree, Tree, Widget};
+    use iced_native::window;
+    use iced_native::{
+        Clipboard, Color, Element, Length, Rectangle, Shell, Size,
+    };
+
+    pub struct HoldButton<Message> {
+        duration: Duration,
+        on_confirm: Message,
+    }
+
+    pub fn hold_button<Message>(
+        duration: Duration,
+        on_confirm: Message,
+    ) -> HoldButton<Message> {
+        HoldButton {
+            duration,
+            on_confirm,
+        }
+    }
+
+    // The state is private, so no other widget can share its tag and inherit
+    // it by mistake when replacing a `HoldButton` in the tree.
+    #[derive(Debug, Default)]
+    struct State {
+        pressed_at: Option<Instant>,
+        progress: f32,
+    }
+
+    impl<Message, Renderer> Widget<Message, Renderer> for HoldButton<Message>
+    where
+        Message: Clone,
+        Renderer: renderer::Renderer,
+    {
+        fn tag(&self) -> tree::Tag {
+            tree::Tag::of::<State>()
+        }
+
+        fn state(&self) -> tree::State {
+            tree::State::new(State::default())
+        }
+
+        fn width(&self) -> Length {
+            Length::Units(200)
+        }
+
+        fn height(&self) -> Length {
+            Length::Units(40)
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Renderer,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            let limits =
+                limits.width(Length::Units(200)).height(Length::Units(40));
+
+            layout::Node::new(limits.resolve(Size::ZERO))
+        }
+
+        fn on_event(
+            &mut self,
+            tree: &mut Tree,
+            event: Event,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            _renderer: &Renderer,
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, Message>,
+        ) -> event::Status {
+            let state = tree.state.downcast_mut::<State>();
+
+            match event {
+                Event::Mouse(mouse::Event::ButtonPressed(
+                    mouse::Button::Left,
+                )) if cursor.is_over(&layout.bounds()) => {
+                    state.pressed_at = Some(Instant::now());
+                    state.progress = 0.0;
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    event::Status::Captured
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(
+                    mouse::Button::Left,
+                )) if state.pressed_at.is_some() => {
+                    state.pressed_at = None;
+                    state.progress = 0.0;
+
+                    event::Status::Captured
+                }
+                Event::Window(window::Event::RedrawRequested(now)) => {
+                    if let Some(pressed_at) = state.pressed_at {
+                        state.progress = now
+                            .saturating_duration_since(pressed_at)
+                            .as_secs_f32()
+                            / self.duration.as_secs_f32();
+
+                        if state.progress >= 1.0 {
+                            state.pressed_at = None;
+                            state.progress = 0.0;
+
+                            shell.publish(self.on_confirm.clone());
+                        } else {
+                            shell.request_redraw(
+                                window::RedrawRequest::NextFrame,
+                            );
+                        }
+                    }
+
+                    event::Status::Ignored
+                }
+                _ => event::Status::Ignored,
+            }
+        }
+
+        fn draw(
+            &self,
+            tree: &Tree,
+            renderer: &mut Renderer,
+            _theme: &Renderer::Theme,
+            _style: &renderer::Style,
+            layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+            let state = tree.state.downcast_ref::<State>();
+            let bounds = layout.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 4.0.into(),
+                    border_width: 1.0,
+                    border_color: Color::BLACK,
+                },
+                Color::from_rgb(0.9, 0.9, 0.9),
+            );
+
+            if state.progress > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            width: bounds.width * state.progress.min(1.0),
+                            ..bounds
+                        },
+                        border_radius: 4.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    Color::from_rgb(0.8, 0.2, 0.2),
+                );
+            }
+        }
+    }
+
+    impl<'a, Message, Renderer> From<HoldButton<Message>>
+        for Element<'a, Message, Renderer>
+    where
+        Message: Clone + 'a,
+        Renderer: renderer::Renderer,
+    {
+        fn from(hold_button: HoldButton<Message>) -> Self {
+            Self::new(hold_button)
+        }
+    }
+}
+
+use hold_button::hold_button;
+use iced::time::Duration;
+use iced::widget::{column, container, text};
+use iced::{Alignment, Element, Length, Sandbox, Settings};
+
+pub fn main() -> iced::Result {
+    Example::run(Settings::default())
+}
+
+struct Example {
+    confirmations: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Confirmed,
+}
+
+impl Sandbox for Example {
+    type Message = Message;
+
+    fn new() -> Self {
+        Example { confirmations: 0 }
+    }
+
+    fn title(&self) -> String {
+        String::from("Stateful widget - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Confirmed => {
+                self.confirmations += 1;
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let content = column![
+            text("Hold the button for a second to confirm"),
+            hold_button(Duration::from_secs(1), Message::Confirmed),
+            text(format!("Confirmations: {}", self.confirmations)),
+        ]
+        .spacing(20)
+        .align_items(Alignment::Center);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -14,3 +14,6 @@ pub mod smol;
 #[cfg_attr(docsrs, doc(cfg(feature = "thread-pool",)))]
 #[cfg(feature = "thread-pool")]
 pub mod thread_pool;
+
+#[cfg(any(feature = "tokio", feature = "async-std", feature = "smol"))]
+mod interval;
@@ -20,6 +20,16 @@ pub mod time {
     //! Listen and react to time.
     use crate::subscription::{self, Subscription};
 
+    /// Returns a [`Future`] that completes once the given `duration` has
+    /// elapsed.
+    ///
+    /// [`Future`]: futures::Future
+    pub fn sleep(
+        duration: std::time::Duration,
+    ) -> impl futures::Future<Output = ()> + Send {
+        async_std::task::sleep(duration)
+    }
+
     /// Returns a [`Subscription`] that produces messages at a set interval.
     ///
     /// The first message is produced after a `duration`, and then continues to
@@ -52,9 +62,13 @@ pub mod time {
         ) -> futures::stream::BoxStream<'static, Self::Output> {
             use futures::stream::StreamExt;
 
-            async_std::stream::interval(self.0)
-                .map(|_| std::time::Instant::now())
-                .boxed()
+            crate::backend::native::interval::every(self.0, |deadline| {
+                async_std::task::sleep(
+                    deadline
+                        .saturating_duration_since(std::time::Instant::now()),
+                )
+            })
+            .boxed()
         }
     }
 }
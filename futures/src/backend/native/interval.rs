@@ -0,0 +1,70 @@
+//! Produce drift-free ticks at a set interval.
+use futures::stream::{self, Stream};
+use futures::Future;
+
+use std::time::{Duration, Instant};
+
+/// Returns a [`Stream`] that produces the current [`Instant`] every `period`,
+/// using `sleep_until` to wait for each tick.
+///
+/// Ticks are scheduled on a fixed grid starting a `period` from now, so the
+/// time spent waking up and producing a tick does not accumulate. If some
+/// ticks are missed (e.g. the process was suspended), only a single tick is
+/// produced and the next one is realigned to the grid.
+pub(crate) fn every<S, F>(
+    period: Duration,
+    sleep_until: S,
+) -> impl Stream<Item = Instant>
+where
+    S: Fn(Instant) -> F,
+    F: Future<Output = ()>,
+{
+    stream::unfold(Instant::now() + period, move |deadline| {
+        let sleep = sleep_until(deadline);
+
+        async move {
+            sleep.await;
+
+            let now = Instant::now();
+
+            Some((now, next_deadline(deadline, period, now)))
+        }
+    })
+}
+
+/// Computes the deadline of the tick following the one at `deadline`,
+/// skipping any ticks that were missed by `now`.
+fn next_deadline(deadline: Instant, period: Duration, now: Instant) -> Instant {
+    let next = deadline + period;
+
+    if next > now || period.as_nanos() == 0 {
+        return next.max(now);
+    }
+
+    let late = (now - deadline).as_nanos() % period.as_nanos();
+
+    now + (period - Duration::from_nanos(late as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_deadline_keeps_the_grid() {
+        let start = Instant::now();
+        let period = Duration::from_millis(100);
+
+        // Waking up late does not shift the next tick
+        assert_eq!(
+            next_deadline(start, period, start + Duration::from_millis(30)),
+            start + period
+        );
+
+        // Missed ticks are coalesced and realigned
+        assert_eq!(
+            next_deadline(start, period, start + Duration::from_millis(450)),
+            start + Duration::from_millis(500)
+        );
+    }
+}
@@ -21,6 +21,18 @@ pub mod time {
     //! Listen and react to time.
     use crate::subscription::{self, Subscription};
 
+    /// Returns a [`Future`] that completes once the given `duration` has
+    /// elapsed.
+    ///
+    /// [`Future`]: futures::Future
+    pub fn sleep(
+        duration: std::time::Duration,
+    ) -> impl futures::Future<Output = ()> + Send {
+        use futures::future::FutureExt;
+
+        smol::Timer::after(duration).map(|_| ())
+    }
+
     /// Returns a [`Subscription`] that produces messages at a set interval.
     ///
     /// The first message is produced after a `duration`, and then continues to
@@ -51,9 +63,13 @@ pub mod time {
             self: Box<Self>,
             _input: futures::stream::BoxStream<'static, E>,
         ) -> futures::stream::BoxStream<'static, Self::Output> {
+            use futures::future::FutureExt;
             use futures::stream::StreamExt;
 
-            smol::Timer::interval(self.0).boxed()
+            crate::backend::native::interval::every(self.0, |deadline| {
+                smol::Timer::at(deadline).map(|_| ())
+            })
+            .boxed()
         }
     }
 }
@@ -24,6 +24,16 @@ pub mod time {
     //! Listen and react to time.
     use crate::subscription::{self, Subscription};
 
+    /// Returns a [`Future`] that completes once the given `duration` has
+    /// elapsed.
+    ///
+    /// [`Future`]: futures::Future
+    pub fn sleep(
+        duration: std::time::Duration,
+    ) -> impl futures::Future<Output = ()> + Send {
+        async move { tokio::time::sleep(duration).await }
+    }
+
     /// Returns a [`Subscription`] that produces messages at a set interval.
     ///
     /// The first message is produced after a `duration`, and then continues to
@@ -56,18 +66,12 @@ pub mod time {
         ) -> futures::stream::BoxStream<'static, Self::Output> {
             use futures::stream::StreamExt;
 
-            let start = tokio::time::Instant::now() + self.0;
-
-            let stream = {
-                futures::stream::unfold(
-                    tokio::time::interval_at(start, self.0),
-                    |mut interval| async move {
-                        Some((interval.tick().await, interval))
-                    },
-                )
-            };
-
-            stream.map(tokio::time::Instant::into_std).boxed()
+            crate::backend::native::interval::every(self.0, |deadline| {
+                tokio::time::sleep_until(tokio::time::Instant::from_std(
+                    deadline,
+                ))
+            })
+            .boxed()
         }
     }
 }
@@ -19,6 +19,18 @@ pub mod time {
     use crate::subscription::{self, Subscription};
     use crate::BoxStream;
 
+    /// Returns a [`Future`] that completes once the given `duration` has
+    /// elapsed.
+    ///
+    /// [`Future`]: futures::Future
+    pub fn sleep(
+        duration: std::time::Duration,
+    ) -> impl futures::Future<Output = ()> {
+        use futures::future::FutureExt;
+
+        wasm_timer::Delay::new(duration).map(|_| ())
+    }
+
     /// Returns a [`Subscription`] that produces messages at a set interval.
     ///
     /// The first message is produced after a `duration`, and then continues to
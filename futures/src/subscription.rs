@@ -19,6 +19,14 @@ use crate::BoxStream;
 /// This type is normally aliased by runtimes with a specific `Event` and/or
 /// `Hasher`.
 ///
+/// # Identity
+/// Runtimes identify every [`Recipe`] of a [`Subscription`] by its hash. When
+/// an application returns a new [`Subscription`], the streams of the recipes
+/// with a hash that was already running are kept alive—the new recipes are
+/// simply discarded—and only the recipes with a new hash are started. This
+/// means that a [`Subscription`] can be rebuilt on every update without
+/// restarting any timers or connections, as long as its recipes hash the same.
+///
 /// [`Command`]: crate::Command
 #[must_use = "`Subscription` must be returned to runtime to take effect"]
 pub struct Subscription<Hasher, Event, Output> {
@@ -47,6 +55,9 @@ where
 
     /// Batches all the provided subscriptions and returns the resulting
     /// [`Subscription`].
+    ///
+    /// Batching does not change the identity of the batched recipes, so their
+    /// streams keep running regardless of their order in the batch.
     pub fn batch(
         subscriptions: impl IntoIterator<Item = Subscription<H, E, O>>,
     ) -> Self {
@@ -86,6 +97,15 @@ where
     }
 
     /// Transforms the [`Subscription`] output with the given function.
+    ///
+    /// The identity of a mapped [`Subscription`] is the identity of the
+    /// original one together with the function `f` and its output type.
+    /// Therefore, mapping with the same function every time keeps the stream
+    /// running, while mapping the same [`Subscription`] with different
+    /// functions produces distinct subscriptions.
+    ///
+    /// `f` is a function pointer—and not a closure—precisely so it can be
+    /// hashed. Use [`Subscription::with`] to capture values instead.
     pub fn map<A>(mut self, f: fn(O) -> A) -> Subscription<H, E, A>
     where
         H: 'static,
@@ -137,6 +157,15 @@ pub trait Recipe<Hasher: std::hash::Hasher, Event> {
     /// Hashes the [`Recipe`].
     ///
     /// This is used by runtimes to uniquely identify a [`Subscription`].
+    ///
+    /// The hash must be stable: a [`Recipe`] must hash the same every time it
+    /// is rebuilt while it describes the same stream, or its stream will be
+    /// restarted. Conversely, every value that changes the stream must be
+    /// hashed, since a [`Recipe`] with the hash of a running one is discarded
+    /// instead of started. Hashing the [`TypeId`] of the [`Recipe`] avoids
+    /// collisions with other kinds of recipes.
+    ///
+    /// [`TypeId`]: std::any::TypeId
     fn hash(&self, state: &mut Hasher);
 
     /// Executes the [`Recipe`] and produces the stream of events of its
@@ -175,6 +204,7 @@ where
     fn hash(&self, state: &mut H) {
         use std::hash::Hash;
 
+        std::any::TypeId::of::<B>().hash(state);
         self.recipe.hash(state);
         self.mapper.hash(state);
     }
@@ -227,3 +257,71 @@ where
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    struct Ticks(u64);
+
+    impl Recipe<DefaultHasher, ()> for Ticks {
+        type Output = u64;
+
+        fn hash(&self, state: &mut DefaultHasher) {
+            std::any::TypeId::of::<Self>().hash(state);
+            self.0.hash(state);
+        }
+
+        fn stream(self: Box<Self>, _input: BoxStream<()>) -> BoxStream<u64> {
+            Box::pin(futures::stream::empty())
+        }
+    }
+
+    fn ids<O>(subscription: Subscription<DefaultHasher, (), O>) -> Vec<u64> {
+        subscription
+            .recipes()
+            .into_iter()
+            .map(|recipe| {
+                let mut hasher = DefaultHasher::default();
+                recipe.hash(&mut hasher);
+
+                hasher.finish()
+            })
+            .collect()
+    }
+
+    fn ticks(id: u64) -> Subscription<DefaultHasher, (), u64> {
+        Subscription::from_recipe(Ticks(id))
+    }
+
+    fn double(ticks: u64) -> u64 {
+        ticks * 2
+    }
+
+    fn halve(ticks: u64) -> u64 {
+        ticks / 2
+    }
+
+    #[test]
+    fn remapping_keeps_the_identity() {
+        assert_eq!(ids(ticks(1).map(double)), ids(ticks(1).map(double)));
+        assert_ne!(ids(ticks(1).map(double)), ids(ticks(1).map(halve)));
+        assert_ne!(ids(ticks(1).map(double)), ids(ticks(1)));
+    }
+
+    #[test]
+    fn batching_keeps_the_identity() {
+        let batch = ids(Subscription::batch([
+            ticks(1).map(double),
+            ticks(2).map(halve),
+        ]));
+
+        assert_eq!(
+            batch,
+            [ids(ticks(1).map(double)), ids(ticks(2).map(halve))].concat()
+        );
+    }
+}
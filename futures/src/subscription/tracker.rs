@@ -49,6 +49,8 @@ where
     /// - On the other hand, if a [`Recipe`] is currently in execution and the
     /// provided [`Subscription`] does not contain it anymore, then the
     /// [`Tracker`] will close and drop the relevant stream.
+    /// - If the provided [`Subscription`] contains many recipes with the same
+    /// hash, only one of them is run.
     ///
     /// It returns a list of futures that need to be spawned to materialize
     /// the [`Tracker`] changes.
@@ -7,7 +7,8 @@ use crate::{Settings, Transformation, Viewport};
 
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::{Layer, Primitive};
+use iced_graphics::layer::{self, Layer};
+use iced_graphics::Primitive;
 use iced_native::alignment;
 use iced_native::{Font, Size};
 
@@ -32,6 +33,7 @@ impl Backend {
             gl,
             settings.default_font,
             settings.text_multithreading,
+            settings.text_rendering,
         );
 
         let shader_version = program::Version::new(gl);
@@ -51,6 +53,16 @@ impl Backend {
         }
     }
 
+    /// Changes the [`TextRendering`] strategy used for text.
+    ///
+    /// [`TextRendering`]: crate::settings::TextRendering
+    pub fn set_text_rendering(
+        &mut self,
+        rendering: crate::settings::TextRendering,
+    ) {
+        self.text_pipeline.set_rendering(rendering);
+    }
+
     /// Draws the provided primitives in the default framebuffer.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -99,11 +111,34 @@ impl Backend {
 
         bounds.height = bounds.height.min(target_height);
 
+        // Layers are not drawn offscreen, so the primitives of a faded layer
+        // are faded on their own
+        let opacity = layer.opacity;
+        let fade = |[r, g, b, a]: [f32; 4]| [r, g, b, a * opacity];
+
         if !layer.quads.is_empty() {
+            let faded_quads: Vec<_>;
+
+            let quads = if opacity < 1.0 {
+                faded_quads = layer
+                    .quads
+                    .iter()
+                    .map(|quad| layer::Quad {
+                        color: fade(quad.color),
+                        border_color: fade(quad.border_color),
+                        ..*quad
+                    })
+                    .collect();
+
+                &faded_quads
+            } else {
+                &layer.quads
+            };
+
             self.quad_pipeline.draw(
                 gl,
                 target_height,
-                &layer.quads,
+                quads,
                 transformation,
                 scale_factor,
                 bounds,
@@ -120,6 +155,7 @@ impl Backend {
                 target_height,
                 scaled,
                 scale_factor,
+                opacity,
             );
         }
 
@@ -135,13 +171,14 @@ impl Backend {
                 scale_factor,
                 &layer.images,
                 bounds,
+                opacity,
             );
         }
 
         if !layer.text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = glow_glyph::Section {
+                let section = glow_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -171,7 +208,7 @@ impl Backend {
                         },
                         font_id: self.text_pipeline.find_font(text.font),
                         extra: glow_glyph::Extra {
-                            color: text.color,
+                            color: fade(text.color),
                             z: 0.0,
                         },
                     }],
@@ -200,7 +237,16 @@ impl Backend {
                         }),
                 };
 
-                self.text_pipeline.queue(text);
+                let line_height = text
+                    .line_height
+                    .to_absolute(text.size)
+                    .map(|line_height| line_height * scale_factor);
+
+                self.text_pipeline.queue(
+                    section,
+                    line_height,
+                    text.letter_spacing * scale_factor,
+                );
             }
 
             self.text_pipeline.draw_queued(
@@ -236,10 +282,28 @@ impl backend::Text for Backend {
         &self,
         contents: &str,
         size: f32,
+        line_height: iced_native::text::LineHeight,
+        letter_spacing: f32,
         font: Font,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline.measure(
+            contents,
+            size,
+            line_height,
+            letter_spacing,
+            font,
+            bounds,
+        )
+    }
+
+    fn baseline(
+        &self,
+        size: f32,
+        line_height: iced_native::text::LineHeight,
+        font: Font,
+    ) -> f32 {
+        self.text_pipeline.baseline(size, line_height, font)
     }
 
     fn hit_test(
@@ -267,6 +331,17 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn load(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::Status {
+        self.image_pipeline.load(handle)
+    }
+
+    fn notify_on_decode(&self, notify: iced_graphics::image::Notify) {
+        self.image_pipeline.notify_on_decode(notify);
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -277,4 +352,11 @@ impl backend::Svg for Backend {
     ) -> Size<u32> {
         self.image_pipeline.viewport_dimensions(handle)
     }
+
+    fn svg_error(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Option<iced_native::svg::Error> {
+        self.image_pipeline.svg_error(handle)
+    }
 }
@@ -13,6 +13,8 @@ use iced_graphics::image::raster;
 #[cfg(feature = "svg")]
 use iced_graphics::image::vector;
 
+use iced_graphics::image::storage::Entry as _;
+use iced_graphics::image::Orientation;
 use iced_graphics::layer;
 use iced_graphics::Rectangle;
 use iced_graphics::Size;
@@ -30,6 +32,8 @@ pub(crate) struct Pipeline {
     vertex_array: <glow::Context as HasContext>::VertexArray,
     vertex_buffer: <glow::Context as HasContext>::Buffer,
     transform_location: <glow::Context as HasContext>::UniformLocation,
+    opacity_location: <glow::Context as HasContext>::UniformLocation,
+    orientation_location: <glow::Context as HasContext>::UniformLocation,
     storage: Storage,
     #[cfg(feature = "image")]
     raster_cache: RefCell<raster::Cache<Storage>>,
@@ -65,9 +69,25 @@ impl Pipeline {
             unsafe { gl.get_uniform_location(program, "u_Transform") }
                 .expect("Get transform location");
 
+        let opacity_location =
+            unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                .expect("Get opacity location");
+
+        let orientation_location =
+            unsafe { gl.get_uniform_location(program, "u_Orientation") }
+                .expect("Get orientation location");
+
         unsafe {
             gl.use_program(Some(program));
 
+            gl.uniform_1_f32(Some(&opacity_location), 1.0);
+
+            gl.uniform_matrix_3_f32_slice(
+                Some(&orientation_location),
+                false,
+                &Orientation::default().matrix(),
+            );
+
             let transform: [f32; 16] = Transformation::identity().into();
             gl.uniform_matrix_4_f32_slice(
                 Some(&transform_location),
@@ -118,6 +138,8 @@ impl Pipeline {
             vertex_array,
             vertex_buffer,
             transform_location,
+            opacity_location,
+            orientation_location,
             storage: Storage::default(),
             #[cfg(feature = "image")]
             raster_cache: RefCell::new(raster::Cache::default()),
@@ -131,6 +153,19 @@ impl Pipeline {
         self.raster_cache.borrow_mut().load(handle).dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn load(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::Status {
+        self.raster_cache.borrow_mut().load_in_background(handle)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn notify_on_decode(&self, notify: iced_graphics::image::Notify) {
+        self.raster_cache.borrow_mut().notify_on_decode(notify);
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(
         &self,
@@ -142,6 +177,14 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    #[cfg(feature = "svg")]
+    pub fn svg_error(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Option<iced_native::svg::Error> {
+        self.vector_cache.borrow_mut().load(handle).error().cloned()
+    }
+
     pub fn draw(
         &mut self,
         mut gl: &glow::Context,
@@ -150,6 +193,7 @@ impl Pipeline {
         _scale_factor: f32,
         images: &[layer::Image],
         layer_bounds: Rectangle<u32>,
+        layer_opacity: f32,
     ) {
         #[cfg(feature = "tracing")]
         let _ = info_span!("Glow::Image", "DRAW").entered();
@@ -168,20 +212,36 @@ impl Pipeline {
         let mut vector_cache = self.vector_cache.borrow_mut();
 
         for image in images {
-            let (entry, bounds) = match &image {
+            let (entry, region, orientation, bounds, opacity) = match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => (
+                layer::Image::Raster {
+                    handle,
+                    region,
+                    rotation,
+                    flip,
+                    bounds,
+                    opacity,
+                } => (
                     raster_cache.upload(handle, &mut gl, &mut self.storage),
+                    *region,
+                    Orientation::new(*rotation, *flip),
                     bounds,
+                    *opacity,
                 ),
                 #[cfg(not(feature = "image"))]
-                layer::Image::Raster { handle: _, bounds } => (None, bounds),
+                layer::Image::Raster {
+                    region,
+                    bounds,
+                    opacity,
+                    ..
+                } => (None, *region, Orientation::default(), bounds, *opacity),
 
                 #[cfg(feature = "svg")]
                 layer::Image::Vector {
                     handle,
                     color,
                     bounds,
+                    opacity,
                 } => {
                     let size = [bounds.width, bounds.height];
                     (
@@ -193,28 +253,58 @@ impl Pipeline {
                             &mut gl,
                             &mut self.storage,
                         ),
+                        None,
+                        Orientation::default(),
                         bounds,
+                        *opacity,
                     )
                 }
 
                 #[cfg(not(feature = "svg"))]
-                layer::Image::Vector { bounds, .. } => (None, bounds),
+                layer::Image::Vector {
+                    bounds, opacity, ..
+                } => (None, None, Orientation::default(), bounds, *opacity),
+            };
+
+            let entry: &storage::Entry = match entry {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            // A region is drawn by scaling the whole image and clipping
+            // everything outside of the region
+            let (bounds, clip_bounds) = match region {
+                Some(region) => {
+                    let (size, region) =
+                        orientation.place_region(entry.size(), region);
+
+                    match region_bounds(size, region, *bounds, _scale_factor) {
+                        Some((image_bounds, region_bounds)) => {
+                            match Rectangle::<f32>::from(layer_bounds)
+                                .intersection(&region_bounds.into())
+                            {
+                                Some(clip_bounds) => {
+                                    (image_bounds, clip_bounds.snap())
+                                }
+                                None => continue,
+                            }
+                        }
+                        None => continue,
+                    }
+                }
+                None => (*bounds, layer_bounds),
             };
 
             unsafe {
                 gl.scissor(
-                    layer_bounds.x as i32,
-                    (target_height - (layer_bounds.y + layer_bounds.height))
+                    clip_bounds.x as i32,
+                    (target_height - (clip_bounds.y + clip_bounds.height))
                         as i32,
-                    layer_bounds.width as i32,
-                    layer_bounds.height as i32,
+                    clip_bounds.width as i32,
+                    clip_bounds.height as i32,
                 );
 
-                if let Some(storage::Entry { texture, .. }) = entry {
-                    gl.bind_texture(glow::TEXTURE_2D, Some(*texture))
-                } else {
-                    continue;
-                }
+                gl.bind_texture(glow::TEXTURE_2D, Some(entry.texture));
 
                 let translate = Transformation::translate(bounds.x, bounds.y);
                 let scale = Transformation::scale(bounds.width, bounds.height);
@@ -226,6 +316,17 @@ impl Pipeline {
                     &matrix,
                 );
 
+                gl.uniform_1_f32(
+                    Some(&self.opacity_location),
+                    opacity * layer_opacity,
+                );
+
+                gl.uniform_matrix_3_f32_slice(
+                    Some(&self.orientation_location),
+                    false,
+                    &orientation.matrix(),
+                );
+
                 gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
 
                 gl.bind_texture(glow::TEXTURE_2D, None);
@@ -252,3 +353,46 @@ impl Pipeline {
             .trim(&mut self.storage, &mut gl);
     }
 }
+
+/// Returns the bounds of the whole image that make the given `region` fill
+/// the `bounds`, together with the physical bounds of the `region`.
+///
+/// The edges of the physical bounds are rounded independently, so regions
+/// sharing an edge do not leave seams between them.
+fn region_bounds(
+    size: Size<u32>,
+    region: Rectangle<u32>,
+    bounds: Rectangle,
+    scale_factor: f32,
+) -> Option<(Rectangle, Rectangle<u32>)> {
+    if region.width == 0 || region.height == 0 {
+        return None;
+    }
+
+    let scale_x = bounds.width / region.width as f32;
+    let scale_y = bounds.height / region.height as f32;
+
+    let image_bounds = Rectangle {
+        x: bounds.x - region.x as f32 * scale_x,
+        y: bounds.y - region.y as f32 * scale_y,
+        width: size.width as f32 * scale_x,
+        height: size.height as f32 * scale_y,
+    };
+
+    let snap = |value: f32| (value * scale_factor).round().max(0.0) as u32;
+
+    let left = snap(bounds.x);
+    let top = snap(bounds.y);
+    let right = snap(bounds.x + bounds.width);
+    let bottom = snap(bounds.y + bounds.height);
+
+    Some((
+        image_bounds,
+        Rectangle {
+            x: left,
+            y: top,
+            width: right.saturating_sub(left),
+            height: bottom.saturating_sub(top),
+        },
+    ))
+}
@@ -1,5 +1,5 @@
 //! Configure a renderer.
-pub use iced_graphics::Antialiasing;
+pub use iced_graphics::{Antialiasing, TextRendering};
 
 /// The settings of a [`Backend`].
 ///
@@ -22,10 +22,27 @@ pub struct Settings {
     /// By default, it is disabled.
     pub text_multithreading: bool,
 
+    /// The [`TextRendering`] strategy that will be used for text.
+    ///
+    /// Subpixel antialiasing is not supported by this renderer yet, so
+    /// [`TextRendering::Subpixel`] is treated as [`TextRendering::Grayscale`].
+    ///
+    /// By default, it is [`TextRendering::Unhinted`].
+    pub text_rendering: TextRendering,
+
     /// The antialiasing strategy that will be used for triangle primitives.
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// If enabled, only the regions of the window that changed since the
+    /// last frame will be redrawn.
+    ///
+    /// This is not supported by the `glow` backend yet. Enabling it logs a
+    /// warning and the whole window is redrawn every frame.
+    ///
+    /// By default, it is disabled.
+    pub damage_tracking: bool,
 }
 
 impl Default for Settings {
@@ -34,7 +51,9 @@ impl Default for Settings {
             default_font: None,
             default_text_size: 20,
             text_multithreading: false,
+            text_rendering: TextRendering::default(),
             antialiasing: None,
+            damage_tracking: false,
         }
     }
 }
@@ -46,7 +65,9 @@ impl std::fmt::Debug for Settings {
             .field("default_font", &self.default_font.is_none())
             .field("default_text_size", &self.default_text_size)
             .field("text_multithreading", &self.text_multithreading)
+            .field("text_rendering", &self.text_rendering)
             .field("antialiasing", &self.antialiasing)
+            .field("damage_tracking", &self.damage_tracking)
             .finish()
     }
 }
@@ -1,9 +1,10 @@
 uniform mat4 u_Transform;
+uniform mat3 u_Orientation;
 
 in vec2 i_Position;
 out vec2 tex_pos;
 
 void main() {
     gl_Position = u_Transform * vec4(i_Position, 0.0, 1.0);
-    tex_pos = i_Position;
+    tex_pos = (u_Orientation * vec3(i_Position, 1.0)).xy;
 }
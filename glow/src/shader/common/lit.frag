@@ -0,0 +1,33 @@
+#ifdef GL_ES
+#ifdef GL_FRAGMENT_PRECISION_HIGH
+precision highp float;
+#else
+precision mediump float;
+#endif
+#endif
+
+#ifdef HIGHER_THAN_300
+out vec4 fragColor;
+#define gl_FragColor fragColor
+#endif
+
+// Lambertian shading: `xyz` points towards the light and `w` is the ambient
+// intensity.
+uniform vec4 u_Light;
+uniform float u_Opacity;
+
+in vec4 v_Color;
+in vec3 v_Normal;
+
+void main() {
+    float diffuse = 0.0;
+
+    if (length(v_Normal) > 0.0 && length(u_Light.xyz) > 0.0) {
+        diffuse = max(dot(normalize(v_Normal), normalize(u_Light.xyz)), 0.0);
+    }
+
+    float ambient = clamp(u_Light.w, 0.0, 1.0);
+    float intensity = ambient + (1.0 - ambient) * diffuse;
+
+    gl_FragColor = vec4(v_Color.rgb * intensity, v_Color.a * u_Opacity);
+}
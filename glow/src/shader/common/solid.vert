@@ -1,4 +1,5 @@
 uniform mat4 u_Transform;
+uniform vec4 u_Tint;
 
 in vec2 i_Position;
 in vec4 i_Color;
@@ -7,5 +8,5 @@ out vec4 v_Color;
 
 void main() {
     gl_Position = u_Transform * vec4(i_Position, 0.0, 1.0);
-    v_Color = i_Color;
+    v_Color = i_Color * u_Tint;
 }
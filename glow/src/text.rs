@@ -1,17 +1,20 @@
 use crate::Transformation;
 
 use iced_graphics::font;
+use iced_graphics::text::Spacing;
+use iced_graphics::TextRendering;
 
 use glow_glyph::ab_glyph;
 use std::{cell::RefCell, collections::HashMap};
 
-pub use iced_native::text::Hit;
+pub use iced_native::text::{Hit, LineHeight};
 
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<glow_glyph::GlyphBrush>,
     draw_font_map: RefCell<HashMap<String, glow_glyph::FontId>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    rendering: TextRendering,
 }
 
 impl Pipeline {
@@ -19,6 +22,7 @@ impl Pipeline {
         gl: &glow::Context,
         default_font: Option<&[u8]>,
         multithreading: bool,
+        rendering: TextRendering,
     ) -> Self {
         let default_font = default_font.map(|slice| slice.to_vec());
 
@@ -63,11 +67,35 @@ impl Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
             measure_brush: RefCell::new(measure_brush),
+            rendering,
         }
     }
 
-    pub fn queue(&mut self, section: glow_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    /// Changes the [`TextRendering`] strategy of the [`Pipeline`].
+    pub fn set_rendering(&mut self, rendering: TextRendering) {
+        self.rendering = rendering;
+    }
+
+    pub fn queue(
+        &mut self,
+        section: glow_glyph::Section<'_>,
+        line_height: Option<f32>,
+        letter_spacing: f32,
+    ) {
+        let spacing = Spacing::new(
+            section.layout,
+            line_height,
+            letter_spacing,
+            self.rendering,
+        );
+
+        if spacing.is_natural() {
+            self.draw_brush.borrow_mut().queue(section);
+        } else {
+            self.draw_brush
+                .borrow_mut()
+                .queue_custom_layout(section, &spacing);
+        }
     }
 
     pub fn draw_queued(
@@ -90,6 +118,8 @@ impl Pipeline {
         &self,
         content: &str,
         size: f32,
+        line_height: LineHeight,
+        letter_spacing: f32,
         font: iced_native::Font,
         bounds: iced_native::Size,
     ) -> (f32, f32) {
@@ -108,15 +138,58 @@ impl Pipeline {
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
-        {
-            (bounds.width().ceil(), bounds.height().ceil())
+        let spacing = Spacing::new(
+            section.layout,
+            line_height.to_absolute(size),
+            letter_spacing,
+            self.rendering,
+        );
+
+        let mut measure_brush = self.measure_brush.borrow_mut();
+
+        if spacing.is_natural() {
+            if let Some(bounds) = measure_brush.glyph_bounds(section) {
+                (bounds.width().ceil(), bounds.height().ceil())
+            } else {
+                (0.0, 0.0)
+            }
         } else {
-            (0.0, 0.0)
+            use ab_glyph::{Font, ScaleFont};
+
+            let font = measure_brush.fonts()[font_id].clone().into_scaled(size);
+            let natural = font.height() + font.line_gap();
+
+            // The glyph bounds only span the line boxes of the glyphs, so
+            // the extra space of the last line needs to be accounted for.
+            let extra = spacing
+                .line_height
+                .map_or(0.0, |line_height| (line_height - natural).max(0.0));
+
+            if let Some(bounds) =
+                measure_brush.glyph_bounds_custom_layout(section, &spacing)
+            {
+                (bounds.width().ceil(), (bounds.height() + extra).ceil())
+            } else {
+                (0.0, 0.0)
+            }
         }
     }
 
+    pub fn baseline(
+        &self,
+        size: f32,
+        line_height: LineHeight,
+        font: iced_native::Font,
+    ) -> f32 {
+        let glow_glyph::FontId(font_id) = self.find_font(font);
+
+        iced_graphics::text::baseline(
+            &self.measure_brush.borrow().fonts()[font_id],
+            size,
+            line_height.to_absolute(size),
+        )
+    }
+
     pub fn hit_test(
         &self,
         content: &str,
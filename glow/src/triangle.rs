@@ -4,7 +4,8 @@ use crate::Transformation;
 
 use iced_graphics::gradient::Gradient;
 use iced_graphics::layer::mesh::{self, Mesh};
-use iced_graphics::triangle::{ColoredVertex2D, Vertex2D};
+use iced_graphics::triangle::{ColoredVertex2D, LitVertex2D, Vertex2D};
+use iced_graphics::Color;
 
 use glow::HasContext;
 use std::marker::PhantomData;
@@ -20,6 +21,7 @@ pub(crate) struct Pipeline {
     indices: Buffer<u32>,
     solid: solid::Program,
     gradient: gradient::Program,
+    lit: lit::Program,
 }
 
 impl Pipeline {
@@ -35,6 +37,7 @@ impl Pipeline {
 
         let solid = solid::Program::new(gl, shader_version);
         let gradient = gradient::Program::new(gl, shader_version);
+        let lit = lit::Program::new(gl, shader_version);
 
         unsafe {
             gl.bind_vertex_array(Some(solid.vertex_array));
@@ -43,6 +46,9 @@ impl Pipeline {
             gl.bind_vertex_array(Some(gradient.vertex_array));
             indices.bind(gl, 0);
 
+            gl.bind_vertex_array(Some(lit.vertex_array));
+            indices.bind(gl, 0);
+
             gl.bind_vertex_array(None);
         }
 
@@ -50,6 +56,7 @@ impl Pipeline {
             indices,
             solid,
             gradient,
+            lit,
         }
     }
 
@@ -60,6 +67,7 @@ impl Pipeline {
         target_height: u32,
         transformation: Transformation,
         scale_factor: f32,
+        opacity: f32,
     ) {
         #[cfg(feature = "tracing")]
         let _ = info_span!("Glow::Triangle", "DRAW").enter();
@@ -80,6 +88,7 @@ impl Pipeline {
         // We upload all the vertices and indices upfront
         let mut solid_vertex_offset = 0;
         let mut gradient_vertex_offset = 0;
+        let mut lit_vertex_offset = 0;
         let mut index_offset = 0;
 
         for mesh in meshes {
@@ -98,7 +107,10 @@ impl Pipeline {
             match mesh {
                 Mesh::Solid { buffers, .. } => {
                     unsafe {
-                        self.solid.vertices.bind(gl, count.solid_vertices);
+                        self.solid.vertices.bind(
+                            gl,
+                            count.solid_vertices + count.instanced_vertices,
+                        );
 
                         gl.buffer_sub_data_u8_slice(
                             glow::ARRAY_BUFFER,
@@ -111,6 +123,28 @@ impl Pipeline {
 
                     solid_vertex_offset += buffers.vertices.len();
                 }
+                Mesh::Instanced { mesh, .. } => {
+                    // Instances are drawn one by one with the solid program,
+                    // so only the vertices of the mesh need to be uploaded
+                    let vertices = &mesh.mesh().vertices;
+
+                    unsafe {
+                        self.solid.vertices.bind(
+                            gl,
+                            count.solid_vertices + count.instanced_vertices,
+                        );
+
+                        gl.buffer_sub_data_u8_slice(
+                            glow::ARRAY_BUFFER,
+                            (solid_vertex_offset
+                                * std::mem::size_of::<ColoredVertex2D>())
+                                as i32,
+                            bytemuck::cast_slice(vertices),
+                        );
+                    }
+
+                    solid_vertex_offset += vertices.len();
+                }
                 Mesh::Gradient { buffers, .. } => {
                     unsafe {
                         self.gradient
@@ -128,12 +162,28 @@ impl Pipeline {
 
                     gradient_vertex_offset += buffers.vertices.len();
                 }
+                Mesh::Lit { buffers, .. } => {
+                    unsafe {
+                        self.lit.vertices.bind(gl, count.lit_vertices);
+
+                        gl.buffer_sub_data_u8_slice(
+                            glow::ARRAY_BUFFER,
+                            (lit_vertex_offset
+                                * std::mem::size_of::<LitVertex2D>())
+                                as i32,
+                            bytemuck::cast_slice(&buffers.vertices),
+                        );
+                    }
+
+                    lit_vertex_offset += buffers.vertices.len();
+                }
             }
         }
 
         // Then we draw each mesh using offsets
         let mut last_solid_vertex = 0;
         let mut last_gradient_vertex = 0;
+        let mut last_lit_vertex = 0;
         let mut last_index = 0;
 
         for mesh in meshes {
@@ -170,6 +220,14 @@ impl Pipeline {
                         self.solid.uniforms.transform = transform;
                     }
 
+                    self.solid.uniforms.set_tint(
+                        gl,
+                        Color {
+                            a: opacity,
+                            ..Color::WHITE
+                        },
+                    );
+
                     gl.draw_elements_base_vertex(
                         glow::TRIANGLES,
                         indices.len() as i32,
@@ -180,6 +238,44 @@ impl Pipeline {
 
                     last_solid_vertex += buffers.vertices.len();
                 },
+                Mesh::Instanced {
+                    mesh, instances, ..
+                } => unsafe {
+                    gl.use_program(Some(self.solid.program));
+                    gl.bind_vertex_array(Some(self.solid.vertex_array));
+
+                    for instance in instances.iter() {
+                        let transform = transform * instance.transformation;
+
+                        if transform != self.solid.uniforms.transform {
+                            gl.uniform_matrix_4_f32_slice(
+                                Some(&self.solid.uniforms.transform_location),
+                                false,
+                                transform.as_ref(),
+                            );
+
+                            self.solid.uniforms.transform = transform;
+                        }
+
+                        self.solid.uniforms.set_tint(
+                            gl,
+                            Color {
+                                a: instance.color.a * opacity,
+                                ..instance.color
+                            },
+                        );
+
+                        gl.draw_elements_base_vertex(
+                            glow::TRIANGLES,
+                            indices.len() as i32,
+                            glow::UNSIGNED_INT,
+                            (last_index * std::mem::size_of::<u32>()) as i32,
+                            last_solid_vertex as i32,
+                        );
+                    }
+
+                    last_solid_vertex += mesh.mesh().vertices.len();
+                },
                 Mesh::Gradient {
                     buffers, gradient, ..
                 } => unsafe {
@@ -196,7 +292,9 @@ impl Pipeline {
                         self.gradient.uniforms.transform = transform;
                     }
 
-                    if &self.gradient.uniforms.gradient != *gradient {
+                    if &self.gradient.uniforms.gradient != *gradient
+                        || self.gradient.uniforms.opacity != opacity
+                    {
                         match gradient {
                             Gradient::Linear(linear) => {
                                 gl.uniform_4_f32(
@@ -237,7 +335,7 @@ impl Pipeline {
                                     stops[index * 8] = r;
                                     stops[(index * 8) + 1] = g;
                                     stops[(index * 8) + 2] = b;
-                                    stops[(index * 8) + 3] = a;
+                                    stops[(index * 8) + 3] = a * opacity;
                                     stops[(index * 8) + 4] = stop.offset;
                                     stops[(index * 8) + 5] = 0.;
                                     stops[(index * 8) + 6] = 0.;
@@ -258,6 +356,7 @@ impl Pipeline {
                         }
 
                         self.gradient.uniforms.gradient = (*gradient).clone();
+                        self.gradient.uniforms.opacity = opacity;
                     }
 
                     gl.draw_elements_base_vertex(
@@ -270,6 +369,53 @@ impl Pipeline {
 
                     last_gradient_vertex += buffers.vertices.len();
                 },
+                Mesh::Lit { buffers, light, .. } => unsafe {
+                    gl.use_program(Some(self.lit.program));
+                    gl.bind_vertex_array(Some(self.lit.vertex_array));
+
+                    if transform != self.lit.uniforms.transform {
+                        gl.uniform_matrix_4_f32_slice(
+                            Some(&self.lit.uniforms.locations.transform),
+                            false,
+                            transform.as_ref(),
+                        );
+
+                        self.lit.uniforms.transform = transform;
+                    }
+
+                    if *light != self.lit.uniforms.light {
+                        let [x, y, z] = light.direction;
+
+                        gl.uniform_4_f32(
+                            Some(&self.lit.uniforms.locations.light),
+                            x,
+                            y,
+                            z,
+                            light.ambient,
+                        );
+
+                        self.lit.uniforms.light = *light;
+                    }
+
+                    if opacity != self.lit.uniforms.opacity {
+                        gl.uniform_1_f32(
+                            Some(&self.lit.uniforms.locations.opacity),
+                            opacity,
+                        );
+
+                        self.lit.uniforms.opacity = opacity;
+                    }
+
+                    gl.draw_elements_base_vertex(
+                        glow::TRIANGLES,
+                        indices.len() as i32,
+                        glow::UNSIGNED_INT,
+                        (last_index * std::mem::size_of::<u32>()) as i32,
+                        last_lit_vertex as i32,
+                    );
+
+                    last_lit_vertex += buffers.vertices.len();
+                },
             }
 
             last_index += indices.len();
@@ -334,7 +480,7 @@ mod solid {
     use crate::triangle;
     use glow::{Context, HasContext, NativeProgram};
     use iced_graphics::triangle::ColoredVertex2D;
-    use iced_graphics::Transformation;
+    use iced_graphics::{Color, Transformation};
 
     #[derive(Debug)]
     pub struct Program {
@@ -420,6 +566,8 @@ mod solid {
     pub struct Uniforms {
         pub transform: Transformation,
         pub transform_location: <Context as HasContext>::UniformLocation,
+        tint: Color,
+        tint_location: <Context as HasContext>::UniformLocation,
     }
 
     impl Uniforms {
@@ -429,6 +577,10 @@ mod solid {
                 unsafe { gl.get_uniform_location(program, "u_Transform") }
                     .expect("Solid - Get u_Transform.");
 
+            let tint_location =
+                unsafe { gl.get_uniform_location(program, "u_Tint") }
+                    .expect("Solid - Get u_Tint.");
+
             unsafe {
                 gl.use_program(Some(program));
 
@@ -438,12 +590,29 @@ mod solid {
                     transform.as_ref(),
                 );
 
+                gl.uniform_4_f32(Some(&tint_location), 1.0, 1.0, 1.0, 1.0);
+
                 gl.use_program(None);
             }
 
             Self {
                 transform,
                 transform_location,
+                tint: Color::WHITE,
+                tint_location,
+            }
+        }
+
+        /// Multiplies the color of the vertices by the given [`Color`].
+        ///
+        /// The program must be in use.
+        pub unsafe fn set_tint(&mut self, gl: &Context, tint: Color) {
+            if tint != self.tint {
+                let [r, g, b, a] = tint.into_linear();
+
+                gl.uniform_4_f32(Some(&self.tint_location), r, g, b, a);
+
+                self.tint = tint;
             }
         }
     }
@@ -530,6 +699,7 @@ mod gradient {
     #[derive(Debug)]
     pub struct Uniforms {
         pub gradient: Gradient,
+        pub opacity: f32,
         pub transform: Transformation,
         pub locations: Locations,
     }
@@ -582,6 +752,7 @@ mod gradient {
                     end: Default::default(),
                     color_stops: vec![],
                 }),
+                opacity: 1.0,
                 transform: Transformation::identity(),
                 locations: Locations {
                     gradient_direction,
@@ -593,3 +764,164 @@ mod gradient {
         }
     }
 }
+
+mod lit {
+    use crate::program;
+    use crate::triangle;
+    use glow::{Context, HasContext, NativeProgram};
+    use iced_graphics::triangle::{Light, LitVertex2D};
+    use iced_graphics::Transformation;
+
+    #[derive(Debug)]
+    pub struct Program {
+        pub program: <Context as HasContext>::Program,
+        pub vertex_array: <glow::Context as HasContext>::VertexArray,
+        pub vertices: triangle::Buffer<LitVertex2D>,
+        pub uniforms: Uniforms,
+    }
+
+    impl Program {
+        pub fn new(gl: &Context, shader_version: &program::Version) -> Self {
+            let program = unsafe {
+                let vertex_shader = program::Shader::vertex(
+                    gl,
+                    shader_version,
+                    include_str!("shader/common/lit.vert"),
+                );
+
+                let fragment_shader = program::Shader::fragment(
+                    gl,
+                    shader_version,
+                    include_str!("shader/common/lit.frag"),
+                );
+
+                program::create(
+                    gl,
+                    &[vertex_shader, fragment_shader],
+                    &[(0, "i_Position"), (1, "i_Color"), (2, "i_Normal")],
+                )
+            };
+
+            let vertex_array = unsafe {
+                gl.create_vertex_array().expect("Create vertex array")
+            };
+
+            let vertices = unsafe {
+                triangle::Buffer::new(
+                    gl,
+                    glow::ARRAY_BUFFER,
+                    glow::DYNAMIC_DRAW,
+                    super::DEFAULT_VERTICES,
+                )
+            };
+
+            unsafe {
+                gl.bind_vertex_array(Some(vertex_array));
+
+                let stride = std::mem::size_of::<LitVertex2D>() as i32;
+
+                gl.enable_vertex_attrib_array(0);
+                gl.vertex_attrib_pointer_f32(
+                    0,
+                    2,
+                    glow::FLOAT,
+                    false,
+                    stride,
+                    0,
+                );
+
+                gl.enable_vertex_attrib_array(1);
+                gl.vertex_attrib_pointer_f32(
+                    1,
+                    4,
+                    glow::FLOAT,
+                    false,
+                    stride,
+                    4 * 2,
+                );
+
+                gl.enable_vertex_attrib_array(2);
+                gl.vertex_attrib_pointer_f32(
+                    2,
+                    3,
+                    glow::FLOAT,
+                    false,
+                    stride,
+                    4 * (2 + 4),
+                );
+
+                gl.bind_vertex_array(None);
+            };
+
+            Self {
+                program,
+                vertex_array,
+                vertices,
+                uniforms: Uniforms::new(gl, program),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Uniforms {
+        pub transform: Transformation,
+        pub light: Light,
+        pub opacity: f32,
+        pub locations: Locations,
+    }
+
+    #[derive(Debug)]
+    pub struct Locations {
+        pub transform: <Context as HasContext>::UniformLocation,
+        pub light: <Context as HasContext>::UniformLocation,
+        pub opacity: <Context as HasContext>::UniformLocation,
+    }
+
+    impl Uniforms {
+        fn new(gl: &Context, program: NativeProgram) -> Self {
+            let transform = Transformation::identity();
+            let light = Light::default();
+
+            let transform_location =
+                unsafe { gl.get_uniform_location(program, "u_Transform") }
+                    .expect("Lit - Get u_Transform.");
+
+            let light_location =
+                unsafe { gl.get_uniform_location(program, "u_Light") }
+                    .expect("Lit - Get u_Light.");
+
+            let opacity_location =
+                unsafe { gl.get_uniform_location(program, "u_Opacity") }
+                    .expect("Lit - Get u_Opacity.");
+
+            unsafe {
+                gl.use_program(Some(program));
+
+                gl.uniform_matrix_4_f32_slice(
+                    Some(&transform_location),
+                    false,
+                    transform.as_ref(),
+                );
+
+                let [x, y, z] = light.direction;
+
+                gl.uniform_4_f32(Some(&light_location), x, y, z, light.ambient);
+
+                gl.uniform_1_f32(Some(&opacity_location), 1.0);
+
+                gl.use_program(None);
+            }
+
+            Self {
+                transform,
+                light,
+                opacity: 1.0,
+                locations: Locations {
+                    transform: transform_location,
+                    light: light_location,
+                    opacity: opacity_location,
+                },
+            }
+        }
+    }
+}
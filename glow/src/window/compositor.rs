@@ -2,6 +2,7 @@ use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
 use glow::HasContext;
 use iced_graphics::{compositor, Antialiasing, Size};
+use iced_native::profile;
 
 use core::ffi::c_void;
 use std::marker::PhantomData;
@@ -25,6 +26,13 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
 
         log::info!("{:#?}", settings);
 
+        if settings.damage_tracking {
+            log::warn!(
+                "Damage tracking is not supported by the glow backend. \
+                The whole window will be redrawn every frame."
+            );
+        }
+
         let version = gl.version();
         log::info!(
             "OpenGL version: {:?} (Embedded: {})",
@@ -108,4 +116,35 @@ impl<Theme> iced_graphics::window::GLCompositor for Compositor<Theme> {
             backend.present(gl, primitive, viewport, overlay);
         });
     }
+
+    fn count(&self, renderer: &mut Self::Renderer) -> profile::Count {
+        let mut count = profile::Count::default();
+
+        renderer.with_primitives(|_, primitives| {
+            for primitive in primitives {
+                let nested = primitive.count();
+
+                count.primitives += nested.primitives;
+                count.meshes += nested.meshes;
+            }
+        });
+
+        count
+    }
+
+    fn notify_on_decode(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        notify: iced_graphics::image::Notify,
+    ) {
+        #[cfg(feature = "image")]
+        {
+            use iced_graphics::backend::Image;
+
+            renderer.backend().notify_on_decode(notify);
+        }
+
+        #[cfg(not(feature = "image"))]
+        let _ = (renderer, notify);
+    }
 }
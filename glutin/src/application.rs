@@ -1,5 +1,10 @@
 //! Create interactive, native cross-platform applications.
+use crate::event;
+use crate::input_method::InputMethod;
 use crate::mouse;
+use crate::profile;
+use crate::text;
+use crate::touch;
 use crate::{Error, Executor, Runtime};
 
 pub use iced_winit::application::StyleSheet;
@@ -10,10 +15,13 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
+use iced_winit::menu;
 use iced_winit::renderer;
 use iced_winit::time::Instant;
 use iced_winit::user_interface;
-use iced_winit::{Clipboard, Command, Debug, Event, Proxy, Settings};
+use iced_winit::{
+    Clipboard, Command, Debug, Event, Proxy, Settings, UserEvent,
+};
 
 use glutin::window::Window;
 use std::mem::ManuallyDrop;
@@ -31,6 +39,7 @@ where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: text::Renderer,
     <A::Renderer as iced_native::Renderer>::Theme: StyleSheet,
 {
     use futures::task;
@@ -64,10 +73,14 @@ where
         runtime.enter(|| A::new(flags))
     };
 
+    let fit_content = settings.window.fit_content;
+    let size = settings.window.size;
+
     let context = {
+        let monitor = settings.window.target_monitor(&event_loop);
         let builder = settings.window.into_builder(
             &application.title(),
-            event_loop.primary_monitor(),
+            monitor,
             settings.id,
         );
 
@@ -126,12 +139,21 @@ where
     };
 
     #[allow(unsafe_code)]
-    let (compositor, renderer) = unsafe {
+    let (compositor, mut renderer) = unsafe {
         C::new(compositor_settings, |address| {
             context.get_proc_address(address)
         })?
     };
 
+    if fit_content {
+        let _ = application::resize_to_fit(
+            &application,
+            &mut renderer,
+            context.window(),
+            size,
+        );
+    }
+
     let (mut event_sender, event_receiver) = mpsc::unbounded();
     let (control_sender, mut control_receiver) = mpsc::unbounded();
 
@@ -148,6 +170,8 @@ where
             context,
             init_command,
             settings.exit_on_close_request,
+            settings.profiler,
+            settings.overlay,
         );
 
         #[cfg(feature = "tracing")]
@@ -207,19 +231,22 @@ async fn run_instance<A, E, C>(
     mut compositor: C,
     mut renderer: A::Renderer,
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
-    mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
+    mut proxy: glutin::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     mut debug: Debug,
     mut event_receiver: mpsc::UnboundedReceiver<
-        glutin::event::Event<'_, A::Message>,
+        glutin::event::Event<'_, UserEvent<A::Message>>,
     >,
     mut control_sender: mpsc::UnboundedSender<glutin::event_loop::ControlFlow>,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     init_command: Command<A::Message>,
     exit_on_close_request: bool,
+    profiler: Option<profile::Hook>,
+    overlay: profile::overlay::Settings,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: text::Renderer,
     <A::Renderer as iced_native::Renderer>::Theme: StyleSheet,
 {
     use glutin::event;
@@ -228,6 +255,8 @@ async fn run_instance<A, E, C>(
 
     let mut clipboard = Clipboard::connect(context.window());
     let mut cache = user_interface::Cache::default();
+
+    compositor.notify_on_decode(&mut renderer, application::wake(&proxy));
     let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
     let mut should_exit = false;
@@ -235,7 +264,7 @@ async fn run_instance<A, E, C>(
     application::run_command(
         &application,
         &mut cache,
-        &state,
+        &mut state,
         &mut renderer,
         init_command,
         &mut runtime,
@@ -258,9 +287,12 @@ async fn run_instance<A, E, C>(
         ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut input_method = InputMethod::Disabled;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut gestures = touch::gesture::Recognizer::new();
+    let mut profiler = profile::Profiler::new(profiler, overlay);
 
     debug.startup_finished();
 
@@ -275,24 +307,47 @@ async fn run_instance<A, E, C>(
                 );
             }
             event::Event::MainEventsCleared => {
+                for path in menu::activations() {
+                    messages.extend(application.menu().activate(&path));
+                }
+
                 if !redraw_pending && events.is_empty() && messages.is_empty() {
                     continue;
                 }
 
                 debug.event_processing_started();
+                profiler.phase_started(profile::Phase::EventProcessing);
 
-                let (interface_state, statuses) = user_interface.update(
+                let (uncaptured, interceptions) = application::intercept(
+                    &application,
                     &events,
-                    state.cursor_position(),
+                    &mut messages,
+                );
+
+                let (interface_state, statuses) = user_interface.update(
+                    &uncaptured,
+                    state.cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
                 );
 
                 debug.event_processing_finished();
+                profiler.phase_finished(profile::Phase::EventProcessing);
+
+                let mut statuses = statuses.into_iter();
+
+                for (event, interception) in
+                    events.drain(..).zip(interceptions.into_iter())
+                {
+                    let status = match interception {
+                        event::Status::Captured => event::Status::Captured,
+                        event::Status::Ignored => {
+                            statuses.next().unwrap_or(event::Status::Ignored)
+                        }
+                    };
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                    runtime.broadcast((event, status));
                 }
 
                 if !messages.is_empty()
@@ -305,10 +360,11 @@ async fn run_instance<A, E, C>(
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
+                    profiler.phase_started(profile::Phase::Update);
                     application::update(
                         &mut application,
                         &mut cache,
-                        &state,
+                        &mut state,
                         &mut renderer,
                         &mut runtime,
                         &mut clipboard,
@@ -319,10 +375,14 @@ async fn run_instance<A, E, C>(
                         context.window(),
                         || compositor.fetch_information(),
                     );
+                    profiler.phase_finished(profile::Phase::Update);
 
                     // Update window
                     state.synchronize(&application, context.window());
+                    events
+                        .extend(state.scale_factor_event().map(Event::Window));
 
+                    profiler.phase_started(profile::Phase::Layout);
                     user_interface =
                         ManuallyDrop::new(application::build_user_interface(
                             &application,
@@ -331,6 +391,7 @@ async fn run_instance<A, E, C>(
                             state.logical_size(),
                             &mut debug,
                         ));
+                    profiler.phase_finished(profile::Phase::Layout);
 
                     if should_exit {
                         break;
@@ -348,22 +409,30 @@ async fn run_instance<A, E, C>(
 
                 let (interface_state, _) = user_interface.update(
                     &[redraw_event.clone()],
-                    state.cursor_position(),
+                    state.cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
                 );
 
                 debug.draw_started();
+                profiler.phase_started(profile::Phase::Draw);
                 let new_mouse_interaction = user_interface.draw(
                     &mut renderer,
                     state.theme(),
                     &renderer::Style {
                         text_color: state.text_color(),
                     },
-                    state.cursor_position(),
+                    state.cursor(),
                 );
                 debug.draw_finished();
+                profiler.phase_finished(profile::Phase::Draw);
+
+                if let Some(color) = debug.layout_explanation() {
+                    user_interface.explain(&mut renderer, color);
+                }
+
+                profiler.overlay().draw(&mut renderer, state.logical_size());
 
                 if new_mouse_interaction != mouse_interaction {
                     context.window().set_cursor_icon(
@@ -373,6 +442,27 @@ async fn run_instance<A, E, C>(
                     mouse_interaction = new_mouse_interaction;
                 }
 
+                if let user_interface::State::Updated {
+                    input_method: new_input_method,
+                    ..
+                } = interface_state
+                {
+                    if new_input_method != input_method {
+                        let position = conversion::input_method_position(
+                            new_input_method,
+                            state.scale_factor(),
+                        );
+
+                        context.window().set_ime_allowed(position.is_some());
+
+                        if let Some(position) = position {
+                            context.window().set_ime_position(position);
+                        }
+
+                        input_method = new_input_method;
+                    }
+                }
+
                 context.window().request_redraw();
                 runtime
                     .broadcast((redraw_event, crate::event::Status::Ignored));
@@ -380,6 +470,7 @@ async fn run_instance<A, E, C>(
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         crate::window::RedrawRequest::NextFrame => {
                             ControlFlow::Poll
@@ -403,9 +494,12 @@ async fn run_instance<A, E, C>(
                     )),
                 ));
             }
-            event::Event::UserEvent(message) => {
+            event::Event::UserEvent(UserEvent::Message(message)) => {
                 messages.push(message);
             }
+            event::Event::UserEvent(UserEvent::Wake) => {
+                redraw_pending = true;
+            }
             event::Event::RedrawRequested(_) => {
                 #[cfg(feature = "tracing")]
                 let _ = info_span!("Application", "FRAME").entered();
@@ -428,22 +522,34 @@ async fn run_instance<A, E, C>(
                     let logical_size = state.logical_size();
 
                     debug.layout_started();
+                    profiler.phase_started(profile::Phase::Layout);
                     user_interface = ManuallyDrop::new(
                         ManuallyDrop::into_inner(user_interface)
                             .relayout(logical_size, &mut renderer),
                     );
                     debug.layout_finished();
+                    profiler.phase_finished(profile::Phase::Layout);
 
                     debug.draw_started();
+                    profiler.phase_started(profile::Phase::Draw);
                     let new_mouse_interaction = user_interface.draw(
                         &mut renderer,
                         state.theme(),
                         &renderer::Style {
                             text_color: state.text_color(),
                         },
-                        state.cursor_position(),
+                        state.cursor(),
                     );
                     debug.draw_finished();
+                    profiler.phase_finished(profile::Phase::Draw);
+
+                    if let Some(color) = debug.layout_explanation() {
+                        user_interface.explain(&mut renderer, color);
+                    }
+
+                    profiler
+                        .overlay()
+                        .draw(&mut renderer, state.logical_size());
 
                     if new_mouse_interaction != mouse_interaction {
                         context.window().set_cursor_icon(
@@ -465,6 +571,7 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
+                profiler.phase_started(profile::Phase::Render);
                 compositor.present(
                     &mut renderer,
                     state.viewport(),
@@ -473,6 +580,8 @@ async fn run_instance<A, E, C>(
                 );
 
                 context.swap_buffers().expect("Swap buffers");
+                profiler.phase_finished(profile::Phase::Render);
+                profiler.frame_finished(|| compositor.count(&mut renderer));
 
                 debug.render_finished();
 
@@ -484,25 +593,70 @@ async fn run_instance<A, E, C>(
                 ..
             } => {
                 if application::requests_exit(&window_event, state.modifiers())
-                    && exit_on_close_request
                 {
-                    break;
+                    if exit_on_close_request {
+                        break;
+                    }
+
+                    // Quit shortcuts are requests too, so they can be vetoed
+                    if !matches!(
+                        window_event,
+                        event::WindowEvent::CloseRequested
+                    ) {
+                        events.push(Event::Window(
+                            crate::window::Event::CloseRequested,
+                        ));
+                    }
                 }
 
                 state.update(context.window(), &window_event, &mut debug);
 
+                if let event::WindowEvent::Moved(_)
+                | event::WindowEvent::ScaleFactorChanged { .. }
+                | event::WindowEvent::Focused(true) = window_event
+                {
+                    let refresh =
+                        !matches!(window_event, event::WindowEvent::Moved(_));
+
+                    events.extend(
+                        state
+                            .monitor_events(context.window(), refresh)
+                            .into_iter()
+                            .map(Event::Window),
+                    );
+                }
+
+                events.extend(state.scale_factor_event().map(Event::Window));
+
                 if let Some(event) = conversion::window_event(
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
                 ) {
+                    let _ = profiler.overlay_mut().update(&event);
+
+                    let gesture = match &event {
+                        Event::Touch(touch) => gestures.update(touch),
+                        _ => None,
+                    };
+
                     events.push(event);
+                    events.extend(gesture.map(|gesture| {
+                        Event::Touch(touch::Event::Gesture(gesture))
+                    }));
                 }
             }
+            event::Event::LoopDestroyed => break,
             _ => {}
         }
     }
 
+    if let Some(message) = application.on_exit() {
+        debug.log_message(&message);
+
+        let _ = runtime.enter(|| application.update(message));
+    }
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
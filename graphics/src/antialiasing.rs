@@ -1,4 +1,15 @@
 /// An antialiasing strategy.
+///
+/// Antialiasing only applies to triangle meshes (e.g. the geometry of a
+/// `Canvas`). Quads and text are always rendered without multisampling, since
+/// they are already antialiased by their own pipelines.
+///
+/// Multisampling renders meshes into an intermediate target that stores every
+/// sample of every pixel. For a window of `width` × `height` physical pixels,
+/// this target takes roughly `width * height * 4 * sample_count` bytes of
+/// video memory—about 33 MB for a 1080p window with [`Antialiasing::MSAAx4`]
+/// and 66 MB with [`Antialiasing::MSAAx8`]—plus a single-sampled texture of
+/// `width * height * 4` bytes where the samples are resolved.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Antialiasing {
     /// Multisample AA with 2 samples
@@ -22,3 +33,14 @@ impl Antialiasing {
         }
     }
 }
+
+impl Default for Antialiasing {
+    /// Returns [`Antialiasing::MSAAx4`].
+    ///
+    /// 4 samples is the only multisample count that is guaranteed to be
+    /// supported on every platform, including WebGL 2 and mobile GPUs. Other
+    /// counts depend on the capabilities of the graphics adapter.
+    fn default() -> Self {
+        Antialiasing::MSAAx4
+    }
+}
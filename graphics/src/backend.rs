@@ -34,17 +34,35 @@ pub trait Text {
     /// Returns the default size of text.
     fn default_size(&self) -> u16;
 
-    /// Measures the text contents with the given size and font,
-    /// returning the size of a laid out paragraph that fits in the provided
-    /// bounds.
+    /// Measures the text contents with the given size, line height, letter
+    /// spacing and font, returning the size of a laid out paragraph that fits
+    /// in the provided bounds.
     fn measure(
         &self,
         contents: &str,
         size: f32,
+        line_height: text::LineHeight,
+        letter_spacing: f32,
         font: Font,
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the distance from the top of a line of text with the given
+    /// size, line height and font to its baseline.
+    ///
+    /// By default, the ascent is estimated to be 80% of the `size`. The
+    /// built-in backends use the ascent of the font instead.
+    fn baseline(
+        &self,
+        size: f32,
+        line_height: text::LineHeight,
+        _font: Font,
+    ) -> f32 {
+        let line_height = line_height.to_absolute(size).unwrap_or(size);
+
+        (line_height - size) / 2.0 + size * 0.8
+    }
+
     /// Tests whether the provided point is within the boundaries of [`Text`]
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -66,11 +84,42 @@ pub trait Text {
 /// A graphics backend that supports image rendering.
 pub trait Image {
     /// Returns the dimensions of the provided image.
+    ///
+    /// The image should be decoded on demand and cached, so it can be
+    /// measured during layout and then drawn without decoding it again.
     fn dimensions(&self, handle: &image::Handle) -> Size<u32>;
+
+    /// Starts decoding the provided image in the background, if needed, and
+    /// returns its [`image::Status`].
+    ///
+    /// By default, images are decoded on demand by [`dimensions`], so they
+    /// are always [`image::Status::Loaded`].
+    ///
+    /// [`dimensions`]: Self::dimensions
+    fn load(&self, _handle: &image::Handle) -> image::Status {
+        image::Status::Loaded
+    }
+
+    /// Sets the [`Notify`] called every time an image finishes decoding in
+    /// the background.
+    ///
+    /// By default, images are never decoded in the background, so it is
+    /// never called.
+    ///
+    /// [`Notify`]: crate::image::Notify
+    fn notify_on_decode(&self, _notify: crate::image::Notify) {}
 }
 
 /// A graphics backend that supports SVG rendering.
 pub trait Svg {
     /// Returns the viewport dimensions of the provided SVG.
     fn viewport_dimensions(&self, handle: &svg::Handle) -> Size<u32>;
+
+    /// Returns the [`svg::Error`] that prevents the provided SVG from being
+    /// drawn, if any.
+    ///
+    /// By default, no error is ever reported.
+    fn svg_error(&self, _handle: &svg::Handle) -> Option<svg::Error> {
+        None
+    }
 }
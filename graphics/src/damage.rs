@@ -0,0 +1,290 @@
+//! Compute the regions of a frame that need to be redrawn.
+//!
+//! Damage is computed by comparing the primitives of the current frame with
+//! the ones of the previous frame. Any [`Primitive`] that changed damages both
+//! its old and its new bounds.
+use crate::{Primitive, Rectangle, Size};
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Computes the regions damaged between the `previous` and the `current` list
+/// of primitives, in logical coordinates.
+pub fn list(previous: &[Primitive], current: &[Primitive]) -> Vec<Rectangle> {
+    let damage = previous
+        .iter()
+        .zip(current)
+        .flat_map(|(a, b)| regions(a, b));
+
+    if previous.len() == current.len() {
+        damage.collect()
+    } else {
+        let (smaller, bigger) = if previous.len() < current.len() {
+            (previous, current)
+        } else {
+            (current, previous)
+        };
+
+        // Every extra primitive is considered damaged
+        damage
+            .chain(bigger[smaller.len()..].iter().map(Primitive::bounds))
+            .collect()
+    }
+}
+
+/// Computes the regions damaged when replacing the [`Primitive`] `a` with the
+/// [`Primitive`] `b`, in logical coordinates.
+pub fn regions(a: &Primitive, b: &Primitive) -> Vec<Rectangle> {
+    match (a, b) {
+        (
+            Primitive::Group {
+                primitives: primitives_a,
+            },
+            Primitive::Group {
+                primitives: primitives_b,
+            },
+        ) => return list(primitives_a, primitives_b),
+        (
+            Primitive::Clip {
+                bounds: bounds_a,
+                border_radius: border_radius_a,
+                content: content_a,
+            },
+            Primitive::Clip {
+                bounds: bounds_b,
+                border_radius: border_radius_b,
+                content: content_b,
+            },
+        ) if bounds_a == bounds_b && border_radius_a == border_radius_b => {
+            return regions(content_a, content_b)
+                .into_iter()
+                .filter_map(|region| region.intersection(&bounds_a.expand(1.0)))
+                .collect();
+        }
+        (
+            Primitive::Translate {
+                translation: translation_a,
+                content: content_a,
+            },
+            Primitive::Translate {
+                translation: translation_b,
+                content: content_b,
+            },
+        ) if translation_a == translation_b => {
+            return regions(content_a, content_b)
+                .into_iter()
+                .map(|region| region + *translation_a)
+                .collect();
+        }
+        (
+            Primitive::ZIndex {
+                z_index: z_index_a,
+                content: content_a,
+            },
+            Primitive::ZIndex {
+                z_index: z_index_b,
+                content: content_b,
+            },
+        ) if z_index_a == z_index_b => {
+            return regions(content_a, content_b);
+        }
+        (
+            Primitive::Opacity {
+                opacity: opacity_a,
+                content: content_a,
+            },
+            Primitive::Opacity {
+                opacity: opacity_b,
+                content: content_b,
+            },
+        ) if opacity_a == opacity_b => {
+            return regions(content_a, content_b);
+        }
+        (
+            Primitive::Cached { cache: cache_a },
+            Primitive::Cached { cache: cache_b },
+        ) if Arc::ptr_eq(cache_a, cache_b) => {
+            return vec![];
+        }
+        _ if a == b => return vec![],
+        _ => {}
+    }
+
+    let bounds_a = a.bounds();
+    let bounds_b = b.bounds();
+
+    if bounds_a == bounds_b {
+        vec![bounds_a]
+    } else {
+        vec![bounds_a, bounds_b]
+    }
+}
+
+/// Groups the given damage regions, converting them to __physical__ pixels
+/// and clipping them to the given physical `bounds`.
+///
+/// Regions are rounded outwards to whole pixels and padded by one pixel, so
+/// antialiased edges are always redrawn completely. Nearby regions are merged
+/// to reduce the amount of draw calls.
+pub fn group(
+    damage: Vec<Rectangle>,
+    scale_factor: f32,
+    bounds: Size<u32>,
+) -> Vec<Rectangle<u32>> {
+    const AREA_THRESHOLD: f32 = 20_000.0;
+
+    let bounds = Rectangle::with_size(Size::new(
+        bounds.width as f32,
+        bounds.height as f32,
+    ));
+
+    let mut regions: Vec<Rectangle> = damage
+        .into_iter()
+        .filter_map(|region| {
+            (region * scale_factor).expand(1.0).intersection(&bounds)
+        })
+        .map(|region| {
+            let x = region.x.floor();
+            let y = region.y.floor();
+
+            Rectangle {
+                x,
+                y,
+                width: (region.x + region.width).ceil() - x,
+                height: (region.y + region.height).ceil() - y,
+            }
+        })
+        .collect();
+
+    regions.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+    });
+
+    let mut output = Vec::new();
+    let mut regions = regions.into_iter();
+
+    if let Some(mut current) = regions.next() {
+        for region in regions {
+            let union = current.union(&region);
+
+            if union.area() - current.area() - region.area() <= AREA_THRESHOLD {
+                current = union;
+            } else {
+                output.push(current.snap());
+                current = region;
+            }
+        }
+
+        output.push(current.snap());
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Background, Color, Point, Vector};
+
+    fn quad(x: f32, y: f32, color: Color) -> Primitive {
+        Primitive::Quad {
+            bounds: Rectangle::new(Point::new(x, y), Size::new(10.0, 10.0)),
+            background: Background::Color(color),
+            border_radius: [0.0; 4],
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+
+    #[test]
+    fn unchanged_primitives_produce_no_damage() {
+        let primitives = vec![
+            quad(0.0, 0.0, Color::BLACK),
+            Primitive::Translate {
+                translation: Vector::new(5.0, 5.0),
+                content: Box::new(quad(10.0, 10.0, Color::WHITE)),
+            },
+        ];
+
+        assert!(list(&primitives, &primitives.clone()).is_empty());
+    }
+
+    #[test]
+    fn changed_primitive_damages_its_bounds() {
+        let previous = vec![quad(0.0, 0.0, Color::BLACK)];
+        let current = vec![quad(0.0, 0.0, Color::WHITE)];
+
+        assert_eq!(
+            list(&previous, &current),
+            vec![Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0))]
+        );
+    }
+
+    #[test]
+    fn moved_primitive_damages_old_and_new_bounds() {
+        let previous = vec![Primitive::Translate {
+            translation: Vector::new(0.0, 0.0),
+            content: Box::new(quad(0.0, 0.0, Color::BLACK)),
+        }];
+
+        let current = vec![Primitive::Translate {
+            translation: Vector::new(50.0, 0.0),
+            content: Box::new(quad(0.0, 0.0, Color::BLACK)),
+        }];
+
+        assert_eq!(
+            list(&previous, &current),
+            vec![
+                Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0)),
+                Rectangle::new(Point::new(50.0, 0.0), Size::new(10.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn added_primitive_damages_its_bounds() {
+        let previous = vec![quad(0.0, 0.0, Color::BLACK)];
+        let current =
+            vec![quad(0.0, 0.0, Color::BLACK), quad(20.0, 20.0, Color::BLACK)];
+
+        assert_eq!(
+            list(&previous, &current),
+            vec![Rectangle::new(
+                Point::new(20.0, 20.0),
+                Size::new(10.0, 10.0)
+            )]
+        );
+    }
+
+    #[test]
+    fn groups_regions_in_physical_pixels() {
+        let damage = vec![
+            Rectangle::new(Point::new(0.5, 0.5), Size::new(10.0, 10.0)),
+            Rectangle::new(Point::new(12.0, 0.5), Size::new(10.0, 10.0)),
+            Rectangle::new(Point::new(500.0, 500.0), Size::new(10.0, 10.0)),
+            Rectangle::new(Point::new(2000.0, 0.0), Size::new(10.0, 10.0)),
+        ];
+
+        let regions = group(damage, 2.0, Size::new(1200, 1200));
+
+        assert_eq!(
+            regions,
+            vec![
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: 45,
+                    height: 22,
+                },
+                Rectangle {
+                    x: 999,
+                    y: 999,
+                    width: 22,
+                    height: 22,
+                },
+            ]
+        );
+    }
+}
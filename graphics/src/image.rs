@@ -7,4 +7,36 @@ pub mod vector;
 
 pub mod storage;
 
+mod orientation;
+
+pub use orientation::Orientation;
 pub use storage::Storage;
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A function called every time an image finishes decoding in the
+/// background.
+///
+/// Shells use it to wake up their event loop, so widgets waiting for an image
+/// can be updated without polling.
+#[derive(Clone)]
+pub struct Notify(Arc<dyn Fn() + Send + Sync>);
+
+impl Notify {
+    /// Creates a new [`Notify`] calling the given function.
+    pub fn new(f: impl Fn() + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Calls the function of the [`Notify`].
+    pub fn notify(&self) {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for Notify {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Notify")
+    }
+}
@@ -0,0 +1,300 @@
+use iced_native::image::{Flip, Rotation};
+use iced_native::{Rectangle, Size};
+
+/// The mapping between an image drawn with some [`Rotation`] and [`Flip`] and
+/// the original image.
+///
+/// Renderers decode and upload an image once, no matter its orientation, and
+/// map the texture coordinates of every drawn point with an [`Orientation`]
+/// instead.
+///
+/// Coordinates are normalized: the top-left corner of an image is `[0, 0]`,
+/// and its bottom-right corner is `[1, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Orientation {
+    /// Whether the axes are swapped.
+    pub transpose: bool,
+    /// Whether the horizontal axis is mirrored, after swapping the axes.
+    pub mirror_x: bool,
+    /// Whether the vertical axis is mirrored, after swapping the axes.
+    pub mirror_y: bool,
+}
+
+impl Orientation {
+    /// Creates the [`Orientation`] of an image that is flipped and then
+    /// rotated.
+    pub fn new(rotation: Rotation, flip: Flip) -> Self {
+        let (transpose, mirror_x, mirror_y) = match rotation {
+            Rotation::None => (false, false, false),
+            Rotation::Clockwise => (true, false, true),
+            Rotation::UpsideDown => (false, true, true),
+            Rotation::CounterClockwise => (true, true, false),
+        };
+
+        // Flipping happens before the rotation, so it is undone last
+        Self {
+            transpose,
+            mirror_x: mirror_x ^ matches!(flip, Flip::Horizontal | Flip::Both),
+            mirror_y: mirror_y ^ matches!(flip, Flip::Vertical | Flip::Both),
+        }
+    }
+
+    /// Returns whether the [`Orientation`] leaves the image as it is.
+    pub fn is_identity(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Maps a point of the drawn image to the point of the original image
+    /// that it shows.
+    pub fn map(self, [x, y]: [f32; 2]) -> [f32; 2] {
+        let [x, y] = if self.transpose { [y, x] } else { [x, y] };
+
+        [
+            if self.mirror_x { 1.0 - x } else { x },
+            if self.mirror_y { 1.0 - y } else { y },
+        ]
+    }
+
+    /// Maps a point of the original image to the point of the drawn image
+    /// that shows it.
+    ///
+    /// It is the inverse of [`map`](Self::map).
+    pub fn unmap(self, [x, y]: [f32; 2]) -> [f32; 2] {
+        let x = if self.mirror_x { 1.0 - x } else { x };
+        let y = if self.mirror_y { 1.0 - y } else { y };
+
+        if self.transpose {
+            [y, x]
+        } else {
+            [x, y]
+        }
+    }
+
+    /// Returns the rectangle of the drawn image that shows the given
+    /// rectangle of the original image.
+    ///
+    /// Rectangles are given by their top-left and bottom-right corners.
+    pub fn place(self, [left, top, right, bottom]: [f32; 4]) -> [f32; 4] {
+        let (left, right) = if self.mirror_x {
+            (1.0 - right, 1.0 - left)
+        } else {
+            (left, right)
+        };
+
+        let (top, bottom) = if self.mirror_y {
+            (1.0 - bottom, 1.0 - top)
+        } else {
+            (top, bottom)
+        };
+
+        if self.transpose {
+            [top, left, bottom, right]
+        } else {
+            [left, top, right, bottom]
+        }
+    }
+
+    /// Returns the size of the drawn image and its region that shows the
+    /// given region of the original image of the given size, in pixels.
+    pub fn place_region(
+        self,
+        size: Size<u32>,
+        region: Rectangle<u32>,
+    ) -> (Size<u32>, Rectangle<u32>) {
+        let x = if self.mirror_x {
+            size.width.saturating_sub(region.x + region.width)
+        } else {
+            region.x
+        };
+
+        let y = if self.mirror_y {
+            size.height.saturating_sub(region.y + region.height)
+        } else {
+            region.y
+        };
+
+        if self.transpose {
+            (
+                Size::new(size.height, size.width),
+                Rectangle {
+                    x: y,
+                    y: x,
+                    width: region.height,
+                    height: region.width,
+                },
+            )
+        } else {
+            (
+                size,
+                Rectangle {
+                    x,
+                    y,
+                    width: region.width,
+                    height: region.height,
+                },
+            )
+        }
+    }
+
+    /// Returns the [`Orientation`] as a column-major 3x3 matrix that
+    /// [`map`]s homogeneous coordinates.
+    ///
+    /// [`map`]: Self::map
+    pub fn matrix(self) -> [f32; 9] {
+        let [origin_x, origin_y] = self.map([0.0, 0.0]);
+        let [x_x, x_y] = self.map([1.0, 0.0]);
+        let [y_x, y_y] = self.map([0.0, 1.0]);
+
+        [
+            x_x - origin_x,
+            x_y - origin_y,
+            0.0,
+            y_x - origin_x,
+            y_y - origin_y,
+            0.0,
+            origin_x,
+            origin_y,
+            1.0,
+        ]
+    }
+
+    /// Returns the [`Orientation`] packed in the three lowest bits of a
+    /// `u32`: swapping the axes, mirroring the horizontal axis, and
+    /// mirroring the vertical axis, in that order.
+    pub fn bits(self) -> u32 {
+        u32::from(self.transpose)
+            | u32::from(self.mirror_x) << 1
+            | u32::from(self.mirror_y) << 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The corners of an image: top-left, top-right, bottom-right, and
+    /// bottom-left.
+    const CORNERS: [[f32; 2]; 4] =
+        [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+
+    fn corners(orientation: Orientation) -> [[f32; 2]; 4] {
+        CORNERS.map(|corner| orientation.map(corner))
+    }
+
+    #[test]
+    fn rotations_show_the_expected_corners() {
+        let [top_left, top_right, bottom_right, bottom_left] = CORNERS;
+
+        // A clockwise turn brings the bottom-left corner to the top-left
+        assert_eq!(
+            corners(Orientation::new(Rotation::Clockwise, Flip::None)),
+            [bottom_left, top_left, top_right, bottom_right]
+        );
+
+        assert_eq!(
+            corners(Orientation::new(Rotation::UpsideDown, Flip::None)),
+            [bottom_right, bottom_left, top_left, top_right]
+        );
+
+        assert_eq!(
+            corners(Orientation::new(Rotation::CounterClockwise, Flip::None)),
+            [top_right, bottom_right, bottom_left, top_left]
+        );
+    }
+
+    #[test]
+    fn images_are_flipped_before_they_are_rotated() {
+        let [top_left, top_right, bottom_right, bottom_left] = CORNERS;
+
+        // Mirrored first, the top-right corner is brought to the top-left
+        // by the clockwise turn
+        assert_eq!(
+            corners(Orientation::new(Rotation::Clockwise, Flip::Horizontal)),
+            [bottom_right, top_right, top_left, bottom_left]
+        );
+
+        assert_eq!(
+            Orientation::new(Rotation::UpsideDown, Flip::Both),
+            Orientation::default()
+        );
+    }
+
+    #[test]
+    fn placed_rectangles_are_mapped_back() {
+        let rectangle = [0.25, 0.0, 0.5, 0.75];
+
+        for rotation in [
+            Rotation::None,
+            Rotation::Clockwise,
+            Rotation::UpsideDown,
+            Rotation::CounterClockwise,
+        ] {
+            for flip in [Flip::None, Flip::Horizontal, Flip::Vertical] {
+                let orientation = Orientation::new(rotation, flip);
+                let [left, top, right, bottom] = orientation.place(rectangle);
+
+                let [x0, y0] = orientation.map([left, top]);
+                let [x1, y1] = orientation.map([right, bottom]);
+
+                assert_eq!(
+                    [x0.min(x1), y0.min(y1), x0.max(x1), y0.max(y1)],
+                    rectangle
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unmapping_undoes_the_mapping() {
+        for rotation in [Rotation::Clockwise, Rotation::CounterClockwise] {
+            let orientation = Orientation::new(rotation, Flip::Horizontal);
+
+            assert_eq!(
+                orientation.unmap(orientation.map([0.25, 0.75])),
+                [0.25, 0.75]
+            );
+        }
+    }
+
+    #[test]
+    fn regions_are_placed_in_pixels() {
+        let orientation = Orientation::new(Rotation::Clockwise, Flip::None);
+
+        // The top half of a 40x20 image ends up on the right half once
+        // turned clockwise
+        assert_eq!(
+            orientation.place_region(
+                Size::new(40, 20),
+                Rectangle {
+                    x: 0,
+                    y: 0,
+                    width: 40,
+                    height: 10,
+                },
+            ),
+            (
+                Size::new(20, 40),
+                Rectangle {
+                    x: 10,
+                    y: 0,
+                    width: 10,
+                    height: 40,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn the_matrix_maps_like_the_orientation() {
+        let orientation =
+            Orientation::new(Rotation::CounterClockwise, Flip::Vertical);
+        let m = orientation.matrix();
+
+        for [x, y] in [[0.25, 0.5], [1.0, 0.0], [0.0, 1.0]] {
+            assert_eq!(
+                [m[0] * x + m[3] * y + m[6], m[1] * x + m[4] * y + m[7]],
+                orientation.map([x, y])
+            );
+        }
+    }
+}
@@ -1,11 +1,14 @@
 //! Raster image loading and caching.
-use crate::image::Storage;
+use crate::image::{Notify, Storage};
 use crate::Size;
 
 use iced_native::image;
 
 use bitflags::bitflags;
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{self, AtomicBool};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
 
 /// Entry in cache corresponding to an image handle
 #[derive(Debug)]
@@ -22,6 +25,8 @@ pub enum Memory<T: Storage> {
 
 impl<T: Storage> Memory<T> {
     /// Width and height of image
+    ///
+    /// Images that could not be found or decoded are a single pixel.
     pub fn dimensions(&self) -> Size<u32> {
         use crate::image::storage::Entry;
 
@@ -36,69 +41,117 @@ impl<T: Storage> Memory<T> {
             Memory::Invalid => Size::new(1, 1),
         }
     }
+
+    /// Status of image
+    fn status(&self) -> image::Status {
+        match self {
+            Memory::Host(_) | Memory::Device(_) => image::Status::Loaded,
+            Memory::NotFound | Memory::Invalid => image::Status::Failed,
+        }
+    }
 }
 
 /// Caches image raster data
+///
+/// Every image is decoded once, no matter how it is oriented when drawn.
+///
+/// Images can also be decoded in the background with
+/// [`load_in_background`](Self::load_in_background), by a small pool of
+/// threads, once a [`Notify`] is set with
+/// [`notify_on_decode`](Self::notify_on_decode). Each of these decodings is
+/// cancelled when the cache is trimmed if its image was not loaded again
+/// since the last trim.
 #[derive(Debug)]
 pub struct Cache<T: Storage> {
     map: HashMap<u64, Memory<T>>,
     hits: HashSet<u64>,
+    pending: HashMap<u64, Arc<AtomicBool>>,
+    pool: Option<Pool>,
+    notify: Option<Notify>,
 }
 
 impl<T: Storage> Cache<T> {
     /// Load image
     pub fn load(&mut self, handle: &image::Handle) -> &mut Memory<T> {
-        if self.contains(handle) {
-            return self.get(handle).unwrap();
+        let key = handle.id();
+
+        if self.map.contains_key(&key) {
+            return self.get(key).unwrap();
         }
 
-        let memory = match handle.data() {
-            image::Data::Path(path) => {
-                if let Ok(image) = image_rs::open(path) {
-                    let operation = std::fs::File::open(path)
-                        .ok()
-                        .map(std::io::BufReader::new)
-                        .and_then(|mut reader| {
-                            Operation::from_exif(&mut reader).ok()
-                        })
-                        .unwrap_or_else(Operation::empty);
+        let _ = self.map.insert(key, decode(handle).into());
+        self.get(key).unwrap()
+    }
 
-                    Memory::Host(operation.perform(image.to_rgba8()))
-                } else {
-                    Memory::NotFound
-                }
+    /// Sets the [`Notify`] called every time an image finishes decoding in
+    /// the background.
+    pub fn notify_on_decode(&mut self, notify: Notify) {
+        self.notify = Some(notify);
+    }
+
+    /// Starts decoding the image of the given handle in a background thread,
+    /// unless it is already decoded or being decoded, and returns its status.
+    ///
+    /// Nobody would find out when a decoding finishes without a [`Notify`],
+    /// so the image is decoded right away if none is set—or if no background
+    /// thread is available.
+    pub fn load_in_background(
+        &mut self,
+        handle: &image::Handle,
+    ) -> image::Status {
+        self.receive();
+
+        let key = handle.id();
+
+        if let Some(memory) = self.get(key) {
+            return memory.status();
+        }
+
+        if self.pending.contains_key(&handle.id()) {
+            let _ = self.hits.insert(key);
+
+            return image::Status::Loading;
+        }
+
+        let notify = match &self.notify {
+            Some(notify) => notify.clone(),
+            None => return self.load(handle).status(),
+        };
+
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let pool = self.pool.get_or_insert_with(Pool::new);
+
+        let job = Job {
+            handle: handle.clone(),
+            cancelled: cancelled.clone(),
+            notify,
+        };
+
+        match pool.push(job) {
+            Ok(()) => {
+                let _ = self.hits.insert(key);
+                let _ = self.pending.insert(handle.id(), cancelled);
+
+                image::Status::Loading
             }
-            image::Data::Bytes(bytes) => {
-                if let Ok(image) = image_rs::load_from_memory(bytes) {
-                    let operation =
-                        Operation::from_exif(&mut std::io::Cursor::new(bytes))
-                            .ok()
-                            .unwrap_or_else(Operation::empty);
-
-                    Memory::Host(operation.perform(image.to_rgba8()))
-                } else {
-                    Memory::Invalid
-                }
+            Err(job) => {
+                let _ = self.map.insert(key, decode(&job.handle).into());
+
+                self.get(key).unwrap().status()
             }
-            image::Data::Rgba {
-                width,
-                height,
-                pixels,
-            } => {
-                if let Some(image) = image_rs::ImageBuffer::from_vec(
-                    *width,
-                    *height,
-                    pixels.to_vec(),
-                ) {
-                    Memory::Host(image)
-                } else {
-                    Memory::Invalid
+        }
+    }
+
+    /// Stores the images decoded in the background since the last call.
+    fn receive(&mut self) {
+        if let Some(pool) = &self.pool {
+            for (id, decoded) in pool.results.try_iter() {
+                if self.pending.remove(&id).is_some() {
+                    let _ =
+                        self.map.entry(id).or_insert_with(|| decoded.into());
                 }
             }
-        };
-
-        self.insert(handle, memory);
-        self.get(handle).unwrap()
+        }
     }
 
     /// Load image and upload raster data
@@ -126,9 +179,22 @@ impl<T: Storage> Cache<T> {
     }
 
     /// Trim cache misses from cache
+    ///
+    /// The background decodings of the images that were not loaded since the
+    /// last trim are cancelled.
     pub fn trim(&mut self, storage: &mut T, state: &mut T::State<'_>) {
         let hits = &self.hits;
 
+        self.pending.retain(|id, cancelled| {
+            let retain = hits.contains(id);
+
+            if !retain {
+                cancelled.store(true, atomic::Ordering::Relaxed);
+            }
+
+            retain
+        });
+
         self.map.retain(|k, memory| {
             let retain = hits.contains(k);
 
@@ -144,18 +210,10 @@ impl<T: Storage> Cache<T> {
         self.hits.clear();
     }
 
-    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory<T>> {
-        let _ = self.hits.insert(handle.id());
+    fn get(&mut self, key: u64) -> Option<&mut Memory<T>> {
+        let _ = self.hits.insert(key);
 
-        self.map.get_mut(&handle.id())
-    }
-
-    fn insert(&mut self, handle: &image::Handle, memory: Memory<T>) {
-        let _ = self.map.insert(handle.id(), memory);
-    }
-
-    fn contains(&self, handle: &image::Handle) -> bool {
-        self.map.contains_key(&handle.id())
+        self.map.get_mut(&key)
     }
 }
 
@@ -164,6 +222,169 @@ impl<T: Storage> Default for Cache<T> {
         Self {
             map: HashMap::new(),
             hits: HashSet::new(),
+            pending: HashMap::new(),
+            pool: None,
+            notify: None,
+        }
+    }
+}
+
+/// The maximum amount of threads of a [`Pool`].
+const MAX_WORKERS: usize = 4;
+
+/// A pool of background threads that decode images.
+///
+/// Threads are spawned as jobs are pushed, up to [`MAX_WORKERS`] or the
+/// available parallelism—whichever is lower—and they share a single queue.
+#[derive(Debug)]
+struct Pool {
+    jobs: mpsc::Sender<Job>,
+    queue: Arc<Mutex<mpsc::Receiver<Job>>>,
+    sender: mpsc::Sender<(u64, Decoded)>,
+    results: mpsc::Receiver<(u64, Decoded)>,
+    workers: usize,
+}
+
+/// An image to be decoded by a [`Pool`].
+struct Job {
+    handle: image::Handle,
+    cancelled: Arc<AtomicBool>,
+    notify: Notify,
+}
+
+impl Pool {
+    /// Creates a new [`Pool`] without any threads.
+    fn new() -> Self {
+        let (jobs, queue) = mpsc::channel();
+        let (sender, results) = mpsc::channel();
+
+        Self {
+            jobs,
+            queue: Arc::new(Mutex::new(queue)),
+            sender,
+            results,
+            workers: 0,
+        }
+    }
+
+    /// Queues the given [`Job`], spawning a new thread if the [`Pool`] has
+    /// room for it.
+    ///
+    /// The [`Job`] is given back if there is no thread to decode it—for
+    /// instance, on platforms without threads.
+    fn push(&mut self, job: Job) -> Result<(), Job> {
+        let max_workers = thread::available_parallelism()
+            .map_or(1, usize::from)
+            .min(MAX_WORKERS);
+
+        if self.workers < max_workers && self.spawn() {
+            self.workers += 1;
+        }
+
+        if self.workers == 0 {
+            return Err(job);
+        }
+
+        self.jobs.send(job).map_err(|mpsc::SendError(job)| job)
+    }
+
+    /// Spawns a new thread that decodes queued jobs until the [`Pool`] is
+    /// dropped, returning whether it could be spawned.
+    fn spawn(&self) -> bool {
+        let queue = self.queue.clone();
+        let sender = self.sender.clone();
+
+        thread::Builder::new()
+            .name(String::from("iced image decoder"))
+            .spawn(move || loop {
+                let job = match queue.lock() {
+                    Ok(queue) => queue.recv(),
+                    Err(_) => break,
+                };
+
+                let job = match job {
+                    Ok(job) => job,
+                    Err(mpsc::RecvError) => break,
+                };
+
+                if job.cancelled.load(atomic::Ordering::Relaxed) {
+                    continue;
+                }
+
+                let decoded = decode(&job.handle);
+
+                if sender.send((job.handle.id(), decoded)).is_err() {
+                    break;
+                }
+
+                job.notify.notify();
+            })
+            .is_ok()
+    }
+}
+
+/// A decoded image, or the reason why it could not be decoded.
+#[derive(Debug)]
+enum Decoded {
+    Image(::image_rs::ImageBuffer<::image_rs::Rgba<u8>, Vec<u8>>),
+    NotFound,
+    Invalid,
+}
+
+impl<T: Storage> From<Decoded> for Memory<T> {
+    fn from(decoded: Decoded) -> Self {
+        match decoded {
+            Decoded::Image(image) => Memory::Host(image),
+            Decoded::NotFound => Memory::NotFound,
+            Decoded::Invalid => Memory::Invalid,
+        }
+    }
+}
+
+/// Decodes the image of the given handle.
+fn decode(handle: &image::Handle) -> Decoded {
+    match handle.data() {
+        image::Data::Path(path) => {
+            if let Ok(image) = image_rs::open(path) {
+                let operation = std::fs::File::open(path)
+                    .ok()
+                    .map(std::io::BufReader::new)
+                    .and_then(|mut reader| {
+                        Operation::from_exif(&mut reader).ok()
+                    })
+                    .unwrap_or_else(Operation::empty);
+
+                Decoded::Image(operation.perform(image.to_rgba8()))
+            } else {
+                Decoded::NotFound
+            }
+        }
+        image::Data::Bytes(bytes) => {
+            if let Ok(image) = image_rs::load_from_memory(bytes) {
+                let operation =
+                    Operation::from_exif(&mut std::io::Cursor::new(bytes))
+                        .ok()
+                        .unwrap_or_else(Operation::empty);
+
+                Decoded::Image(operation.perform(image.to_rgba8()))
+            } else {
+                Decoded::Invalid
+            }
+        }
+        image::Data::Rgba {
+            width,
+            height,
+            pixels,
+        } => {
+            if let Some(image) = image_rs::ImageBuffer::from_vec(
+                *width,
+                *height,
+                pixels.to_vec(),
+            ) {
+                Decoded::Image(image)
+            } else {
+                Decoded::Invalid
+            }
         }
     }
 }
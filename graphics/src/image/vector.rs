@@ -13,7 +13,7 @@ pub enum Svg {
     /// Parsed svg
     Loaded(usvg::Tree),
     /// Svg not found or failed to parse
-    NotFound,
+    Invalid(svg::Error),
 }
 
 impl Svg {
@@ -25,7 +25,15 @@ impl Svg {
 
                 Size::new(size.width() as u32, size.height() as u32)
             }
-            Svg::NotFound => Size::new(1, 1),
+            Svg::Invalid(_) => Size::new(1, 1),
+        }
+    }
+
+    /// The [`svg::Error`] that prevented the svg from loading, if any
+    pub fn error(&self) -> Option<&svg::Error> {
+        match self {
+            Svg::Loaded(_) => None,
+            Svg::Invalid(error) => Some(error),
         }
     }
 }
@@ -48,26 +56,28 @@ impl<T: Storage> Cache<T> {
             return self.svgs.get(&handle.id()).unwrap();
         }
 
-        let svg = match handle.data() {
-            svg::Data::Path(path) => {
-                let tree = fs::read_to_string(path).ok().and_then(|contents| {
+        let tree = match handle.data() {
+            svg::Data::Path(path) => fs::read_to_string(path)
+                .map_err(|error| svg::Error::Io(error.kind()))
+                .and_then(|contents| {
                     usvg::Tree::from_str(
                         &contents,
                         &usvg::Options::default().to_ref(),
                     )
-                    .ok()
-                });
-
-                tree.map(Svg::Loaded).unwrap_or(Svg::NotFound)
-            }
+                    .map_err(|error| svg::Error::Parse(error.to_string()))
+                }),
             svg::Data::Bytes(bytes) => {
-                match usvg::Tree::from_data(
-                    bytes,
-                    &usvg::Options::default().to_ref(),
-                ) {
-                    Ok(tree) => Svg::Loaded(tree),
-                    Err(_) => Svg::NotFound,
-                }
+                usvg::Tree::from_data(bytes, &usvg::Options::default().to_ref())
+                    .map_err(|error| svg::Error::Parse(error.to_string()))
+            }
+        };
+
+        let svg = match tree {
+            Ok(tree) => Svg::Loaded(tree),
+            Err(error) => {
+                log::warn!("failed to load svg {}: {}", handle.id(), error);
+
+                Svg::Invalid(error)
             }
         };
 
@@ -149,7 +159,7 @@ impl<T: Storage> Cache<T> {
 
                 self.rasterized.get(&key)
             }
-            Svg::NotFound => None,
+            Svg::Invalid(_) => None,
         }
     }
 
@@ -188,7 +198,7 @@ impl std::fmt::Debug for Svg {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Svg::Loaded(_) => write!(f, "Svg::Loaded"),
-            Svg::NotFound => write!(f, "Svg::NotFound"),
+            Svg::Invalid(error) => write!(f, "Svg::Invalid({error:?})"),
         }
     }
 }
@@ -15,12 +15,29 @@ use crate::{
     Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
 };
 
+use iced_native::text::LineHeight;
+
 /// A group of primitives that should be clipped together.
 #[derive(Debug)]
 pub struct Layer<'a> {
     /// The clipping bounds of the [`Layer`].
     pub bounds: Rectangle,
 
+    /// The rounded clips of the [`Layer`], from the outermost to the
+    /// innermost.
+    ///
+    /// The contents of the [`Layer`] are only visible inside all of them—
+    /// their intersection. They apply on top of the clipping
+    /// [`bounds`](Self::bounds).
+    pub rounded_clips: Vec<RoundedClip>,
+
+    /// The opacity of the [`Layer`], from `0.0` to `1.0`.
+    ///
+    /// Renderers composite the contents of the [`Layer`] as a whole with it,
+    /// so its primitives do not show through each other. Nested layers keep
+    /// the opacity of their parent and are composited on their own.
+    pub opacity: f32,
+
     /// The quads of the [`Layer`].
     pub quads: Vec<Quad>,
 
@@ -39,6 +56,8 @@ impl<'a> Layer<'a> {
     pub fn new(bounds: Rectangle) -> Self {
         Self {
             bounds,
+            rounded_clips: Vec::new(),
+            opacity: 1.0,
             quads: Vec::new(),
             meshes: Vec::new(),
             text: Vec::new(),
@@ -62,6 +81,8 @@ impl<'a> Layer<'a> {
                 ),
                 color: [0.9, 0.9, 0.9, 1.0],
                 size: 20.0,
+                line_height: LineHeight::default(),
+                letter_spacing: 0.0,
                 font: Font::Default,
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
@@ -79,8 +100,27 @@ impl<'a> Layer<'a> {
         overlay
     }
 
+    fn with_rounded_clips(mut self, rounded_clips: Vec<RoundedClip>) -> Self {
+        self.rounded_clips = rounded_clips;
+        self
+    }
+
+    fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    fn has_only_quads(&self) -> bool {
+        self.meshes.is_empty() && self.text.is_empty() && self.images.is_empty()
+    }
+
     /// Distributes the given [`Primitive`] and generates a list of layers based
     /// on its contents.
+    ///
+    /// Renderers draw all the quads of a [`Layer`] with a single instanced
+    /// draw call, so the amount of layers bounds the amount of quad draws.
+    /// Consecutive clips with the same bounds share a [`Layer`] as long as it
+    /// only contains quads; any other change of clip starts a new one.
     pub fn generate(
         primitives: &'a [Primitive],
         viewport: &Viewport,
@@ -89,21 +129,52 @@ impl<'a> Layer<'a> {
             Layer::new(Rectangle::with_size(viewport.logical_size()));
 
         let mut layers = vec![first_layer];
+        let mut raised = Vec::new();
 
         for primitive in primitives {
             Self::process_primitive(
                 &mut layers,
+                &mut raised,
                 Vector::new(0.0, 0.0),
                 primitive,
                 0,
             );
         }
 
+        // Raised primitives are drawn after everything else, each in its own
+        // layer. The sort is stable, so primitives with the same z-index keep
+        // their drawing order and the result is the same on every frame.
+        // Primitives raised inside of raised primitives are drawn right
+        // after the current batch.
+        while !raised.is_empty() {
+            let mut batch = std::mem::take(&mut raised);
+            batch.sort_by_key(|primitive| primitive.z_index);
+
+            for primitive in batch {
+                layers.push(
+                    Layer::new(primitive.bounds)
+                        .with_rounded_clips(primitive.rounded_clips)
+                        .with_opacity(primitive.opacity),
+                );
+
+                let current_layer = layers.len() - 1;
+
+                Self::process_primitive(
+                    &mut layers,
+                    &mut raised,
+                    primitive.translation,
+                    primitive.content,
+                    current_layer,
+                );
+            }
+        }
+
         layers
     }
 
     fn process_primitive(
         layers: &mut Vec<Self>,
+        raised: &mut Vec<Raised<'a>>,
         translation: Vector,
         primitive: &'a Primitive,
         current_layer: usize,
@@ -115,6 +186,7 @@ impl<'a> Layer<'a> {
                 for primitive in primitives {
                     Self::process_primitive(
                         layers,
+                        raised,
                         translation,
                         primitive,
                         current_layer,
@@ -125,6 +197,8 @@ impl<'a> Layer<'a> {
                 content,
                 bounds,
                 size,
+                line_height,
+                letter_spacing,
                 color,
                 font,
                 horizontal_alignment,
@@ -136,6 +210,8 @@ impl<'a> Layer<'a> {
                     content,
                     bounds: *bounds + translation,
                     size: *size,
+                    line_height: *line_height,
+                    letter_spacing: *letter_spacing,
                     color: color.into_linear(),
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
@@ -205,22 +281,107 @@ impl<'a> Layer<'a> {
                     });
                 }
             }
-            Primitive::Clip { bounds, content } => {
+            Primitive::LitMesh {
+                buffers,
+                size,
+                light,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                // Only draw visible content
+                if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
+                    layer.meshes.push(Mesh::Lit {
+                        origin: Point::new(translation.x, translation.y),
+                        buffers,
+                        clip_bounds,
+                        light: *light,
+                    });
+                }
+            }
+            Primitive::InstancedMesh {
+                mesh,
+                instances,
+                size,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                // Only draw visible content
+                if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
+                    if !instances.is_empty() {
+                        layer.meshes.push(Mesh::Instanced {
+                            origin: Point::new(translation.x, translation.y),
+                            mesh,
+                            instances,
+                            clip_bounds,
+                        });
+                    }
+                }
+            }
+            Primitive::Clip {
+                bounds,
+                border_radius,
+                content,
+            } => {
                 let layer = &mut layers[current_layer];
                 let translated_bounds = *bounds + translation;
 
+                // Nested layers keep the rounded clips of their parent, since
+                // they are drawn within its bounds, so nested rounded clips
+                // intersect
+                let mut rounded_clips = layer.rounded_clips.clone();
+                let opacity = layer.opacity;
+
+                if border_radius.iter().any(|r| *r > 0.0) {
+                    rounded_clips.push(RoundedClip {
+                        bounds: translated_bounds,
+                        border_radius: *border_radius,
+                    });
+                }
+
                 // Only draw visible content
                 if let Some(clip_bounds) =
                     layer.bounds.intersection(&translated_bounds)
                 {
-                    let clip_layer = Layer::new(clip_bounds);
-                    layers.push(clip_layer);
+                    let last_layer = layers.len() - 1;
+
+                    // Consecutive clips with the same bounds can share a
+                    // layer, as long as the previous one only contains quads.
+                    // This keeps sibling widgets that clip to the same region
+                    // in a single instanced quad draw, while preserving their
+                    // drawing order.
+                    let target_layer = if last_layer != current_layer
+                        && layers[last_layer].bounds == clip_bounds
+                        && layers[last_layer].rounded_clips == rounded_clips
+                        && layers[last_layer].opacity == opacity
+                        && layers[last_layer].has_only_quads()
+                    {
+                        last_layer
+                    } else {
+                        layers.push(
+                            Layer::new(clip_bounds)
+                                .with_rounded_clips(rounded_clips)
+                                .with_opacity(opacity),
+                        );
+
+                        layers.len() - 1
+                    };
 
                     Self::process_primitive(
                         layers,
+                        raised,
                         translation,
                         content,
-                        layers.len() - 1,
+                        target_layer,
                     );
                 }
             }
@@ -230,31 +391,106 @@ impl<'a> Layer<'a> {
             } => {
                 Self::process_primitive(
                     layers,
+                    raised,
                     translation + *new_translation,
                     content,
                     current_layer,
                 );
             }
+            Primitive::ZIndex { z_index, content } => {
+                if *z_index == 0 {
+                    Self::process_primitive(
+                        layers,
+                        raised,
+                        translation,
+                        content,
+                        current_layer,
+                    );
+                } else {
+                    raised.push(Raised {
+                        z_index: *z_index,
+                        bounds: layers[current_layer].bounds,
+                        rounded_clips: layers[current_layer]
+                            .rounded_clips
+                            .clone(),
+                        opacity: layers[current_layer].opacity,
+                        translation,
+                        content,
+                    });
+                }
+            }
+            Primitive::Opacity { opacity, content } => {
+                if *opacity >= 1.0 {
+                    Self::process_primitive(
+                        layers,
+                        raised,
+                        translation,
+                        content,
+                        current_layer,
+                    );
+
+                    return;
+                }
+
+                let layer = &layers[current_layer];
+                let opacity = layer.opacity * opacity.max(0.0);
+
+                // Transparent content is not drawn
+                if opacity == 0.0 {
+                    return;
+                }
+
+                // The content gets a layer of its own, so it can be
+                // composited as a whole
+                layers.push(
+                    Layer::new(layer.bounds)
+                        .with_rounded_clips(layer.rounded_clips.clone())
+                        .with_opacity(opacity),
+                );
+
+                let faded_layer = layers.len() - 1;
+
+                Self::process_primitive(
+                    layers,
+                    raised,
+                    translation,
+                    content,
+                    faded_layer,
+                );
+            }
             Primitive::Cached { cache } => {
                 Self::process_primitive(
                     layers,
+                    raised,
                     translation,
                     cache,
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                region,
+                rotation,
+                flip,
+                bounds,
+                opacity,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    region: *region,
+                    rotation: *rotation,
+                    flip: *flip,
                     bounds: *bounds + translation,
+                    opacity: *opacity,
                 });
             }
             Primitive::Svg {
                 handle,
                 color,
                 bounds,
+                opacity,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -262,8 +498,336 @@ impl<'a> Layer<'a> {
                     handle: handle.clone(),
                     color: *color,
                     bounds: *bounds + translation,
+                    opacity: *opacity,
                 });
             }
         }
     }
 }
+
+/// Rounded corners clipping the contents of a [`Layer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedClip {
+    /// The bounds of the rounded rectangle.
+    pub bounds: Rectangle,
+
+    /// The radii of the corners of the rounded rectangle.
+    pub border_radius: [f32; 4],
+}
+
+/// A [`Primitive`] with a non-zero z-index, deferred until every other
+/// [`Primitive`] has been processed.
+#[derive(Debug)]
+struct Raised<'a> {
+    z_index: u16,
+    bounds: Rectangle,
+    rounded_clips: Vec<RoundedClip>,
+    opacity: f32,
+    translation: Vector,
+    content: &'a Primitive,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::Color;
+
+    const VIEWPORT: Size<u32> = Size::new(1000, 1000);
+
+    fn quad(x: f32, y: f32) -> Primitive {
+        Primitive::Quad {
+            bounds: Rectangle::new(Point::new(x, y), Size::new(8.0, 8.0)),
+            background: Background::Color(Color::BLACK),
+            border_radius: [2.0, 2.0, 2.0, 2.0],
+            border_width: 1.0,
+            border_color: Color::WHITE,
+        }
+    }
+
+    fn grid(clip: impl Fn(f32, f32) -> Option<Rectangle>) -> Vec<Primitive> {
+        (0..100)
+            .flat_map(|row| (0..100).map(move |column| (row, column)))
+            .map(|(row, column)| {
+                let (x, y) = (column as f32 * 10.0, row as f32 * 10.0);
+
+                match clip(x, y) {
+                    Some(bounds) => Primitive::Clip {
+                        bounds,
+                        border_radius: [0.0; 4],
+                        content: Box::new(quad(x, y)),
+                    },
+                    None => quad(x, y),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the amount of quad draw calls needed for the given layers.
+    fn quad_draw_calls(layers: &[Layer<'_>]) -> usize {
+        layers
+            .iter()
+            .filter(|layer| !layer.quads.is_empty())
+            .count()
+    }
+
+    fn generate(primitives: &[Primitive]) -> Vec<Layer<'_>> {
+        Layer::generate(
+            primitives,
+            &Viewport::with_physical_size(VIEWPORT, 1.0),
+        )
+    }
+
+    #[test]
+    fn batches_grid_of_quads() {
+        let primitives = grid(|_, _| None);
+        let layers = generate(&primitives);
+
+        assert_eq!(quad_draw_calls(&layers), 1);
+        assert_eq!(layers[0].quads.len(), 10_000);
+    }
+
+    #[test]
+    fn batches_quads_clipped_to_the_same_region() {
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(1000.0, 1000.0));
+
+        let primitives = grid(|_, _| Some(viewport));
+        let layers = generate(&primitives);
+
+        // Without merging, this used to take 10 000 draw calls.
+        assert_eq!(quad_draw_calls(&layers), 1);
+        assert_eq!(layers[1].quads.len(), 10_000);
+    }
+
+    #[test]
+    fn breaks_batches_on_clip_changes() {
+        let primitives = grid(|x, y| {
+            Some(Rectangle::new(Point::new(x, y), Size::new(5.0, 5.0)))
+        });
+        let layers = generate(&primitives);
+
+        assert_eq!(quad_draw_calls(&layers), 10_000);
+    }
+
+    #[test]
+    fn preserves_order_when_text_is_clipped() {
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(1000.0, 1000.0));
+
+        let text = Primitive::Text {
+            content: String::from("Hello"),
+            bounds: viewport,
+            color: Color::BLACK,
+            size: 20.0,
+            line_height: LineHeight::default(),
+            letter_spacing: 0.0,
+            font: Font::Default,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        };
+
+        let primitives = vec![
+            Primitive::Clip {
+                bounds: viewport,
+                border_radius: [0.0; 4],
+                content: Box::new(text),
+            },
+            Primitive::Clip {
+                bounds: viewport,
+                border_radius: [0.0; 4],
+                content: Box::new(quad(0.0, 0.0)),
+            },
+        ];
+
+        let layers = generate(&primitives);
+
+        // The quad must be drawn on top of the text, so it cannot be batched
+        // with it.
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[2].quads.len(), 1);
+    }
+
+    #[test]
+    fn draws_raised_primitives_above_later_siblings() {
+        let clip = Rectangle::new(Point::new(0.0, 0.0), Size::new(50.0, 50.0));
+
+        let raised = |z_index, x| Primitive::ZIndex {
+            z_index,
+            content: Box::new(quad(x, 0.0)),
+        };
+
+        let primitives = vec![
+            Primitive::Clip {
+                bounds: clip,
+                border_radius: [0.0; 4],
+                content: Box::new(Primitive::Translate {
+                    translation: Vector::new(5.0, 5.0),
+                    content: Box::new(Primitive::Group {
+                        primitives: vec![raised(2, 0.0), raised(1, 10.0)],
+                    }),
+                }),
+            },
+            raised(1, 20.0),
+            quad(30.0, 0.0),
+        ];
+
+        let layers = generate(&primitives);
+        let positions = |layer: &Layer<'_>| {
+            layer
+                .quads
+                .iter()
+                .map(|quad| quad.position)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(layers.len(), 5);
+        assert_eq!(positions(&layers[0]), vec![[30.0, 0.0]]);
+        assert!(layers[1].quads.is_empty());
+
+        // Raised primitives are sorted by z-index, keeping their drawing
+        // order, translation, and clipping bounds.
+        assert_eq!(layers[2].bounds, clip);
+        assert_eq!(positions(&layers[2]), vec![[15.0, 5.0]]);
+        assert_eq!(positions(&layers[3]), vec![[20.0, 0.0]]);
+        assert_eq!(layers[4].bounds, clip);
+        assert_eq!(positions(&layers[4]), vec![[5.0, 5.0]]);
+    }
+
+    #[test]
+    fn nested_layers_keep_the_rounded_clip_of_their_parent() {
+        let card =
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(100.0, 50.0));
+        let inner = Rectangle::new(Point::ORIGIN, Size::new(50.0, 50.0));
+
+        let primitives = vec![Primitive::Clip {
+            bounds: card,
+            border_radius: [8.0, 8.0, 0.0, 0.0],
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    quad(10.0, 10.0),
+                    Primitive::Clip {
+                        bounds: inner,
+                        border_radius: [0.0; 4],
+                        content: Box::new(Primitive::Translate {
+                            translation: Vector::new(10.0, 10.0),
+                            content: Box::new(quad(0.0, 0.0)),
+                        }),
+                    },
+                ],
+            }),
+        }];
+
+        let layers = generate(&primitives);
+        let rounded_clips = vec![RoundedClip {
+            bounds: card,
+            border_radius: [8.0, 8.0, 0.0, 0.0],
+        }];
+
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[0].rounded_clips, []);
+        assert_eq!(layers[1].rounded_clips, rounded_clips);
+        assert_eq!(
+            layers[2].bounds,
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(40.0, 40.0))
+        );
+        assert_eq!(layers[2].rounded_clips, rounded_clips);
+    }
+
+    #[test]
+    fn nested_rounded_clips_intersect() {
+        let outer = Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0));
+        let inner =
+            Rectangle::new(Point::new(20.0, 20.0), Size::new(100.0, 100.0));
+
+        let primitives = vec![Primitive::Clip {
+            bounds: outer,
+            border_radius: [10.0; 4],
+            content: Box::new(Primitive::Clip {
+                bounds: inner,
+                border_radius: [4.0; 4],
+                content: Box::new(quad(50.0, 50.0)),
+            }),
+        }];
+
+        let layers = generate(&primitives);
+
+        assert_eq!(layers.len(), 3);
+        assert_eq!(
+            layers[2].bounds,
+            Rectangle::new(Point::new(20.0, 20.0), Size::new(80.0, 80.0))
+        );
+        assert_eq!(
+            layers[2].rounded_clips,
+            [
+                RoundedClip {
+                    bounds: outer,
+                    border_radius: [10.0; 4],
+                },
+                RoundedClip {
+                    bounds: inner,
+                    border_radius: [4.0; 4],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn faded_primitives_are_composited_in_a_layer_of_their_own() {
+        let cache = std::sync::Arc::new(quad(10.0, 0.0));
+
+        let faded = |opacity, content| Primitive::Opacity {
+            opacity,
+            content: Box::new(content),
+        };
+
+        let primitives = vec![
+            quad(0.0, 0.0),
+            faded(
+                0.5,
+                Primitive::Group {
+                    primitives: vec![
+                        Primitive::Cached {
+                            cache: cache.clone(),
+                        },
+                        faded(0.5, quad(20.0, 0.0)),
+                        faded(1.0, quad(30.0, 0.0)),
+                    ],
+                },
+            ),
+            faded(0.0, quad(40.0, 0.0)),
+        ];
+
+        let layers = generate(&primitives);
+        let opacities = layers
+            .iter()
+            .map(|layer| (layer.opacity, layer.quads.len()))
+            .collect::<Vec<_>>();
+
+        // Fully opaque content is drawn in place and transparent content
+        // is not drawn at all
+        assert_eq!(opacities, vec![(1.0, 1), (0.5, 2), (0.25, 1)]);
+
+        // The cached primitive is faded as part of its layer, untouched
+        assert_eq!(*cache, quad(10.0, 0.0));
+        assert_eq!(layers[1].quads[0].color, Color::BLACK.into_linear());
+    }
+
+    #[test]
+    fn nested_layers_keep_the_opacity_of_their_parent() {
+        let clip = Rectangle::new(Point::ORIGIN, Size::new(50.0, 50.0));
+
+        let primitives = vec![Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Clip {
+                bounds: clip,
+                border_radius: [0.0; 4],
+                content: Box::new(quad(0.0, 0.0)),
+            }),
+        }];
+
+        let layers = generate(&primitives);
+
+        assert_eq!(layers.len(), 3);
+        assert_eq!(layers[2].bounds, clip);
+        assert_eq!(layers[2].opacity, 0.5);
+    }
+}
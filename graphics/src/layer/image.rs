@@ -10,8 +10,22 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The region of the image to draw, in pixels of the image.
+        ///
+        /// The whole image is drawn if `None`.
+        region: Option<Rectangle<u32>>,
+
+        /// The rotation of the image.
+        rotation: image::Rotation,
+
+        /// The flip of the image, applied before its rotation.
+        flip: image::Flip,
+
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The opacity of the image.
+        opacity: f32,
     },
     /// A vector image.
     Vector {
@@ -23,5 +37,8 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The opacity of the image.
+        opacity: f32,
     },
 }
@@ -30,15 +30,44 @@ pub enum Mesh<'a> {
         /// The gradient to apply to the [`Mesh`].
         gradient: &'a Gradient,
     },
+    /// A mesh of triangles shaded by a directional light.
+    Lit {
+        /// The origin of the vertices of the [`Mesh`].
+        origin: Point,
+
+        /// The vertex and index buffers of the [`Mesh`].
+        buffers: &'a triangle::Mesh2D<triangle::LitVertex2D>,
+
+        /// The clipping bounds of the [`Mesh`].
+        clip_bounds: Rectangle<f32>,
+
+        /// The light that shades the [`Mesh`].
+        light: triangle::Light,
+    },
+    /// Many instances of the same mesh of triangles.
+    Instanced {
+        /// The origin of the vertices of the [`Mesh`].
+        origin: Point,
+
+        /// The mesh to draw.
+        mesh: &'a triangle::Handle,
+
+        /// The instances of the mesh.
+        instances: &'a [triangle::Instance],
+
+        /// The clipping bounds of the [`Mesh`].
+        clip_bounds: Rectangle<f32>,
+    },
 }
 
 impl Mesh<'_> {
     /// Returns the origin of the [`Mesh`].
     pub fn origin(&self) -> Point {
         match self {
-            Self::Solid { origin, .. } | Self::Gradient { origin, .. } => {
-                *origin
-            }
+            Self::Solid { origin, .. }
+            | Self::Gradient { origin, .. }
+            | Self::Lit { origin, .. }
+            | Self::Instanced { origin, .. } => *origin,
         }
     }
 
@@ -47,6 +76,8 @@ impl Mesh<'_> {
         match self {
             Self::Solid { buffers, .. } => &buffers.indices,
             Self::Gradient { buffers, .. } => &buffers.indices,
+            Self::Lit { buffers, .. } => &buffers.indices,
+            Self::Instanced { mesh, .. } => &mesh.mesh().indices,
         }
     }
 
@@ -54,9 +85,29 @@ impl Mesh<'_> {
     pub fn clip_bounds(&self) -> Rectangle<f32> {
         match self {
             Self::Solid { clip_bounds, .. }
-            | Self::Gradient { clip_bounds, .. } => *clip_bounds,
+            | Self::Gradient { clip_bounds, .. }
+            | Self::Lit { clip_bounds, .. }
+            | Self::Instanced { clip_bounds, .. } => *clip_bounds,
         }
     }
+
+    /// Restricts the clip bounds of the [`Mesh`] to the given [`Rectangle`].
+    ///
+    /// Returns `None` if the [`Mesh`] would not be visible.
+    pub fn clip(&self, bounds: &Rectangle) -> Option<Self> {
+        let clipped = self.clip_bounds().intersection(bounds)?;
+
+        let mut mesh = *self;
+
+        match &mut mesh {
+            Self::Solid { clip_bounds, .. }
+            | Self::Gradient { clip_bounds, .. }
+            | Self::Lit { clip_bounds, .. }
+            | Self::Instanced { clip_bounds, .. } => *clip_bounds = clipped,
+        }
+
+        Some(mesh)
+    }
 }
 
 /// The result of counting the attributes of a set of meshes.
@@ -68,6 +119,16 @@ pub struct AttributeCount {
     /// The total amount of gradient vertices.
     pub gradient_vertices: usize,
 
+    /// The total amount of lit vertices.
+    pub lit_vertices: usize,
+
+    /// The total amount of vertices of instanced meshes, counting every
+    /// mesh once.
+    pub instanced_vertices: usize,
+
+    /// The total amount of instances of instanced meshes.
+    pub instances: usize,
+
     /// The total amount of indices.
     pub indices: usize,
 }
@@ -86,6 +147,17 @@ pub fn attribute_count_of<'a>(meshes: &'a [Mesh<'a>]) -> AttributeCount {
                     count.gradient_vertices += buffers.vertices.len();
                     count.indices += buffers.indices.len();
                 }
+                Mesh::Lit { buffers, .. } => {
+                    count.lit_vertices += buffers.vertices.len();
+                    count.indices += buffers.indices.len();
+                }
+                Mesh::Instanced {
+                    mesh, instances, ..
+                } => {
+                    count.instanced_vertices += mesh.mesh().vertices.len();
+                    count.instances += instances.len();
+                    count.indices += mesh.mesh().indices.len();
+                }
             }
 
             count
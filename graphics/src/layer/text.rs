@@ -1,5 +1,7 @@
 use crate::{alignment, Font, Rectangle};
 
+use iced_native::text::LineHeight;
+
 /// A paragraph of text.
 #[derive(Debug, Clone, Copy)]
 pub struct Text<'a> {
@@ -15,6 +17,12 @@ pub struct Text<'a> {
     /// The size of the [`Text`].
     pub size: f32,
 
+    /// The line height of the [`Text`].
+    pub line_height: LineHeight,
+
+    /// The letter spacing of the [`Text`], in logical pixels.
+    pub letter_spacing: f32,
+
     /// The font of the [`Text`].
     pub font: Font,
 
@@ -24,10 +24,12 @@
 mod antialiasing;
 mod error;
 mod primitive;
+mod text_rendering;
 mod transformation;
 mod viewport;
 
 pub mod backend;
+pub mod damage;
 pub mod font;
 pub mod gradient;
 pub mod image;
@@ -38,6 +40,10 @@ pub mod triangle;
 pub mod widget;
 pub mod window;
 
+#[cfg(feature = "text-layout")]
+#[cfg_attr(docsrs, doc(cfg(feature = "text-layout")))]
+pub mod text;
+
 pub use antialiasing::Antialiasing;
 pub use backend::Backend;
 pub use error::Error;
@@ -45,6 +51,7 @@ pub use gradient::Gradient;
 pub use layer::Layer;
 pub use primitive::Primitive;
 pub use renderer::Renderer;
+pub use text_rendering::TextRendering;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
 pub use window::compositor;
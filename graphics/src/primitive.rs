@@ -1,5 +1,7 @@
 use iced_native::image;
+use iced_native::profile;
 use iced_native::svg;
+use iced_native::text;
 use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
 
 use crate::alignment;
@@ -9,7 +11,7 @@ use crate::triangle;
 use std::sync::Arc;
 
 /// A rendering primitive.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Primitive {
     /// An empty primitive
     None,
@@ -28,6 +30,10 @@ pub enum Primitive {
         color: Color,
         /// The size of the text
         size: f32,
+        /// The line height of the text
+        line_height: text::LineHeight,
+        /// The letter spacing of the text
+        letter_spacing: f32,
         /// The font of the text
         font: Font,
         /// The horizontal alignment of the text
@@ -52,8 +58,18 @@ pub enum Primitive {
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The region of the image to draw, in pixels of the image
+        ///
+        /// The whole image is drawn if `None`.
+        region: Option<Rectangle<u32>>,
+        /// The rotation of the image
+        rotation: image::Rotation,
+        /// The flip of the image, applied before its rotation
+        flip: image::Flip,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The opacity of the image
+        opacity: f32,
     },
     /// An SVG primitive
     Svg {
@@ -65,11 +81,18 @@ pub enum Primitive {
 
         /// The bounds of the viewport
         bounds: Rectangle,
+
+        /// The opacity of the SVG
+        opacity: f32,
     },
     /// A clip primitive
     Clip {
         /// The bounds of the clip
         bounds: Rectangle,
+        /// The border radii of the corners of the clip
+        ///
+        /// Backends that do not support rounded clipping ignore them.
+        border_radius: [f32; 4],
         /// The content of the clip
         content: Box<Primitive>,
     },
@@ -81,6 +104,35 @@ pub enum Primitive {
         /// The primitive to translate
         content: Box<Primitive>,
     },
+    /// A primitive that is drawn above every other primitive with a lower
+    /// z-index, regardless of the drawing order.
+    ///
+    /// The content keeps the translation and the clipping bounds that apply
+    /// where it is recorded. Primitives with the same z-index are drawn in
+    /// the order they are recorded.
+    ZIndex {
+        /// The z-index of the content.
+        ///
+        /// A z-index of `0` is the default and has no effect.
+        z_index: u16,
+
+        /// The primitive to draw with the z-index
+        content: Box<Primitive>,
+    },
+    /// A primitive that is drawn as a whole with the given opacity.
+    ///
+    /// The content is drawn in a [`Layer`] of its own, which renderers can
+    /// composite at once—so overlapping primitives inside of it do not show
+    /// through each other.
+    ///
+    /// [`Layer`]: crate::Layer
+    Opacity {
+        /// The opacity of the content, from `0.0` to `1.0`
+        opacity: f32,
+
+        /// The primitive to draw with the opacity
+        content: Box<Primitive>,
+    },
     /// A low-level primitive to render a mesh of triangles with a solid color.
     ///
     /// It can be used to render many kinds of geometry freely.
@@ -108,6 +160,41 @@ pub enum Primitive {
         /// The [`Gradient`] to apply to the mesh.
         gradient: Gradient,
     },
+    /// A low-level primitive to render a mesh of triangles shaded by a
+    /// directional [`triangle::Light`].
+    ///
+    /// It can be used to give some depth to custom geometry. Meshes without
+    /// normals should use a [`Primitive::SolidMesh`] instead.
+    LitMesh {
+        /// The vertices and indices of the mesh.
+        buffers: triangle::Mesh2D<triangle::LitVertex2D>,
+
+        /// The size of the drawable region of the mesh.
+        ///
+        /// Any geometry that falls out of this region will be clipped.
+        size: Size,
+
+        /// The [`triangle::Light`] that shades the mesh.
+        light: triangle::Light,
+    },
+    /// A low-level primitive to render many instances of the same mesh of
+    /// triangles.
+    ///
+    /// The vertices of the mesh are uploaded only once and reused across
+    /// frames for as long as its [`triangle::Handle`] keeps being drawn.
+    /// Renderers that cannot draw instances natively draw them one by one.
+    InstancedMesh {
+        /// The mesh to draw.
+        mesh: triangle::Handle,
+
+        /// The instances of the mesh.
+        instances: Vec<triangle::Instance>,
+
+        /// The size of the drawable region of the instances.
+        ///
+        /// Any geometry that falls out of this region will be clipped.
+        size: Size,
+    },
     /// A cached primitive.
     ///
     /// This can be useful if you are implementing a widget where primitive
@@ -118,6 +205,125 @@ pub enum Primitive {
     },
 }
 
+impl Primitive {
+    /// Returns the bounds of the [`Primitive`], including the space needed
+    /// for antialiasing its contents.
+    pub fn bounds(&self) -> Rectangle {
+        match self {
+            Primitive::None => Rectangle::with_size(Size::ZERO),
+            Primitive::Group { primitives } => primitives
+                .iter()
+                .map(Primitive::bounds)
+                .filter(|bounds| bounds.area() > 0.0)
+                .reduce(|a, b| a.union(&b))
+                .unwrap_or_else(|| Rectangle::with_size(Size::ZERO)),
+            Primitive::Text {
+                bounds,
+                horizontal_alignment,
+                vertical_alignment,
+                ..
+            } => {
+                // Text without bounds may span the whole axis
+                const UNBOUNDED: f32 = 1_000_000.0;
+
+                let (x, width) = if bounds.width.is_finite() {
+                    let x = match horizontal_alignment {
+                        alignment::Horizontal::Left => bounds.x,
+                        alignment::Horizontal::Center => {
+                            bounds.x - bounds.width / 2.0
+                        }
+                        alignment::Horizontal::Right => bounds.x - bounds.width,
+                    };
+
+                    (x, bounds.width)
+                } else {
+                    (-UNBOUNDED, 2.0 * UNBOUNDED)
+                };
+
+                let (y, height) = if bounds.height.is_finite() {
+                    let y = match vertical_alignment {
+                        alignment::Vertical::Top => bounds.y,
+                        alignment::Vertical::Center => {
+                            bounds.y - bounds.height / 2.0
+                        }
+                        alignment::Vertical::Bottom => bounds.y - bounds.height,
+                    };
+
+                    (y, bounds.height)
+                } else {
+                    (-UNBOUNDED, 2.0 * UNBOUNDED)
+                };
+
+                // Glyphs may overflow their bounds slightly
+                Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                }
+                .expand(1.5)
+            }
+            Primitive::Quad { bounds, .. }
+            | Primitive::Image { bounds, .. }
+            | Primitive::Svg { bounds, .. } => *bounds,
+            Primitive::Clip {
+                bounds, content, ..
+            } => content.bounds().intersection(bounds).unwrap_or_else(|| {
+                Rectangle::new(bounds.position(), Size::ZERO)
+            }),
+            Primitive::Translate {
+                translation,
+                content,
+            } => content.bounds() + *translation,
+            Primitive::ZIndex { content, .. }
+            | Primitive::Opacity { content, .. } => content.bounds(),
+            Primitive::SolidMesh { size, .. }
+            | Primitive::GradientMesh { size, .. }
+            | Primitive::LitMesh { size, .. }
+            | Primitive::InstancedMesh { size, .. } => {
+                Rectangle::with_size(*size)
+            }
+            Primitive::Cached { cache } => cache.bounds(),
+        }
+    }
+
+    /// Counts the [`Primitive`] and all of its nested primitives, including
+    /// the meshes among them.
+    pub fn count(&self) -> profile::Count {
+        let nested = match self {
+            Primitive::Group { primitives } => primitives.iter().fold(
+                profile::Count::default(),
+                |count, primitive| {
+                    let nested = primitive.count();
+
+                    profile::Count {
+                        primitives: count.primitives + nested.primitives,
+                        meshes: count.meshes + nested.meshes,
+                    }
+                },
+            ),
+            Primitive::Clip { content, .. }
+            | Primitive::Translate { content, .. }
+            | Primitive::ZIndex { content, .. }
+            | Primitive::Opacity { content, .. } => content.count(),
+            Primitive::Cached { cache } => cache.count(),
+            Primitive::SolidMesh { .. }
+            | Primitive::GradientMesh { .. }
+            | Primitive::LitMesh { .. }
+            | Primitive::InstancedMesh { .. } => profile::Count {
+                primitives: 0,
+                meshes: 1,
+            },
+            _ => profile::Count::default(),
+        };
+
+        profile::Count {
+            primitives: nested.primitives + 1,
+            ..nested
+        }
+    }
+}
+
 impl Default for Primitive {
     fn default() -> Primitive {
         Primitive::None
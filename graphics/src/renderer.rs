@@ -17,6 +17,8 @@ use std::marker::PhantomData;
 pub struct Renderer<B: Backend, Theme> {
     backend: B,
     primitives: Vec<Primitive>,
+    previous: Vec<Primitive>,
+    generation: u64,
     theme: PhantomData<Theme>,
 }
 
@@ -26,6 +28,8 @@ impl<B: Backend, T> Renderer<B, T> {
         Self {
             backend,
             primitives: Vec::new(),
+            previous: Vec::new(),
+            generation: 0,
             theme: PhantomData,
         }
     }
@@ -36,6 +40,17 @@ impl<B: Backend, T> Renderer<B, T> {
     }
 
     /// Enqueues the given [`Primitive`] in the [`Renderer`] for drawing.
+    ///
+    /// The [`Primitive`] follows the same drawing order as the rest of the
+    /// primitives of the [`Renderer`]. It is drawn on top of the ones
+    /// recorded before it and it is affected by any enclosing
+    /// [`with_translation`], [`with_layer`], [`with_z_index`], and
+    /// [`with_opacity`] calls.
+    ///
+    /// [`with_translation`]: iced_native::Renderer::with_translation
+    /// [`with_layer`]: iced_native::Renderer::with_layer
+    /// [`with_z_index`]: iced_native::Renderer::with_z_index
+    /// [`with_opacity`]: iced_native::Renderer::with_opacity
     pub fn draw_primitive(&mut self, primitive: Primitive) {
         self.primitives.push(primitive);
     }
@@ -45,6 +60,27 @@ impl<B: Backend, T> Renderer<B, T> {
     pub fn with_primitives(&mut self, f: impl FnOnce(&mut B, &[Primitive])) {
         f(&mut self.backend, &self.primitives);
     }
+
+    /// Returns the generation of the recorded primitives of the [`Renderer`].
+    ///
+    /// The generation increases every time the [`Renderer`] is cleared, so
+    /// primitives presented with the same generation are unchanged.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Runs the given closure with the [`Backend`], the recorded primitives
+    /// of the [`Renderer`], and the primitives recorded in the previous
+    /// generation.
+    ///
+    /// The primitives of the previous generation are kept when the
+    /// [`Renderer`] is cleared, so they can be compared without cloning.
+    pub fn with_previous_primitives(
+        &mut self,
+        f: impl FnOnce(&mut B, &[Primitive], &[Primitive]),
+    ) {
+        f(&mut self.backend, &self.primitives, &self.previous);
+    }
 }
 
 impl<B, T> iced_native::Renderer for Renderer<B, T>
@@ -75,6 +111,29 @@ where
 
         self.primitives.push(Primitive::Clip {
             bounds,
+            border_radius: [0.0; 4],
+            content: Box::new(Primitive::Group {
+                primitives: layer_primitives,
+            }),
+        });
+    }
+
+    fn with_rounded_clip(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: renderer::BorderRadius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let layer_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::Clip {
+            bounds,
+            border_radius: border_radius.into(),
             content: Box::new(Primitive::Group {
                 primitives: layer_primitives,
             }),
@@ -101,6 +160,43 @@ where
         });
     }
 
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let faded_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::Opacity {
+            opacity,
+            content: Box::new(Primitive::Group {
+                primitives: faded_primitives,
+            }),
+        });
+    }
+
+    fn with_z_index(&mut self, z_index: u16, f: impl FnOnce(&mut Self)) {
+        if z_index == 0 {
+            f(self);
+            return;
+        }
+
+        let current_primitives = std::mem::take(&mut self.primitives);
+
+        f(self);
+
+        let raised_primitives =
+            std::mem::replace(&mut self.primitives, current_primitives);
+
+        self.primitives.push(Primitive::ZIndex {
+            z_index,
+            content: Box::new(Primitive::Group {
+                primitives: raised_primitives,
+            }),
+        });
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -116,7 +212,8 @@ where
     }
 
     fn clear(&mut self) {
-        self.primitives.clear();
+        self.previous = std::mem::take(&mut self.primitives);
+        self.generation += 1;
     }
 }
 
@@ -138,11 +235,28 @@ where
         &self,
         content: &str,
         size: u16,
+        line_height: text::LineHeight,
+        letter_spacing: f32,
         font: Font,
         bounds: Size,
     ) -> (f32, f32) {
-        self.backend()
-            .measure(content, f32::from(size), font, bounds)
+        self.backend().measure(
+            content,
+            f32::from(size),
+            line_height,
+            letter_spacing,
+            font,
+            bounds,
+        )
+    }
+
+    fn baseline(
+        &self,
+        size: u16,
+        line_height: text::LineHeight,
+        font: Font,
+    ) -> f32 {
+        self.backend().baseline(f32::from(size), line_height, font)
     }
 
     fn hit_test(
@@ -169,6 +283,8 @@ where
             content: text.content.to_string(),
             bounds: text.bounds,
             size: text.size,
+            line_height: text.line_height,
+            letter_spacing: text.letter_spacing,
             color: text.color,
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
@@ -187,8 +303,52 @@ where
         self.backend().dimensions(handle)
     }
 
+    fn load(&self, handle: &image::Handle) -> image::Status {
+        self.backend().load(handle)
+    }
+
     fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Image { handle, bounds })
+        self.draw_primitive(Primitive::Image {
+            handle,
+            region: None,
+            rotation: image::Rotation::None,
+            flip: image::Flip::None,
+            bounds,
+            opacity: 1.0,
+        })
+    }
+
+    fn draw_oriented(
+        &mut self,
+        handle: image::Handle,
+        rotation: image::Rotation,
+        flip: image::Flip,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            region: None,
+            rotation,
+            flip,
+            bounds,
+            opacity: 1.0,
+        })
+    }
+
+    fn draw_region(
+        &mut self,
+        handle: image::Handle,
+        region: Rectangle<u32>,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            region: Some(region),
+            rotation: image::Rotation::None,
+            flip: image::Flip::None,
+            bounds,
+            opacity: 1.0,
+        })
     }
 }
 
@@ -200,6 +360,10 @@ where
         self.backend().viewport_dimensions(handle)
     }
 
+    fn error(&self, handle: &svg::Handle) -> Option<svg::Error> {
+        self.backend().svg_error(handle)
+    }
+
     fn draw(
         &mut self,
         handle: svg::Handle,
@@ -210,6 +374,7 @@ where
             handle,
             color,
             bounds,
+            opacity: 1.0,
         })
     }
 }
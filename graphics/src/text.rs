@@ -0,0 +1,304 @@
+//! Lay out text with custom spacing on top of [`glyph_brush`].
+use crate::TextRendering;
+
+use glyph_brush::ab_glyph::{self, Font, ScaleFont};
+use glyph_brush::{
+    BuiltInLineBreaker, GlyphPositioner, HorizontalAlign, Layout, LineBreak,
+    LineBreaker, SectionGeometry, SectionGlyph, ToSectionText, VerticalAlign,
+};
+
+use std::hash::{Hash, Hasher};
+
+/// A [`GlyphPositioner`] that applies a custom line height and letter spacing
+/// on top of a regular [`Layout`].
+///
+/// Paragraphs are wrapped with the letter spacing applied, so the lines of a
+/// [`Layout::Wrap`] stay within the bounds of the section.
+///
+/// When hinted, the origin of every glyph is snapped to the pixel grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spacing {
+    /// The [`Layout`] providing the alignment and the line breaks.
+    pub layout: Layout<BuiltInLineBreaker>,
+
+    /// The height of a line, if different from the natural one of the font.
+    pub line_height: Option<f32>,
+
+    /// The extra horizontal space between two glyphs.
+    pub letter_spacing: f32,
+
+    /// Whether the origin of every glyph is snapped to the pixel grid.
+    pub is_hinted: bool,
+}
+
+impl Spacing {
+    /// Creates a [`Spacing`] for the given [`Layout`] that hints its glyphs
+    /// as the given [`TextRendering`] strategy dictates.
+    ///
+    /// Renderers create the [`Spacing`] of a section with this both when
+    /// drawing and when measuring it, so the measurements match what is
+    /// drawn.
+    pub fn new(
+        layout: Layout<BuiltInLineBreaker>,
+        line_height: Option<f32>,
+        letter_spacing: f32,
+        rendering: TextRendering,
+    ) -> Self {
+        Self {
+            layout,
+            line_height,
+            letter_spacing,
+            is_hinted: rendering.is_hinted(),
+        }
+    }
+
+    /// Returns whether the [`Spacing`] lays out glyphs exactly like its
+    /// [`Layout`] does.
+    ///
+    /// Sections with natural spacing can be queued and measured with the
+    /// regular [`Layout`] directly.
+    pub fn is_natural(&self) -> bool {
+        self.line_height.is_none()
+            && self.letter_spacing == 0.0
+            && !self.is_hinted
+    }
+}
+
+/// Returns the distance from the top of a line of text laid out with the
+/// given font, size, and line height to its baseline.
+///
+/// The extra space of a custom line height is split evenly above and below
+/// the line, just like a [`Spacing`] does.
+pub fn baseline(font: &impl Font, size: f32, line_height: Option<f32>) -> f32 {
+    let font = font.as_scaled(size);
+    let natural = font.height() + font.line_gap();
+    let extra = line_height.map_or(0.0, |line_height| line_height - natural);
+
+    extra / 2.0 + font.ascent()
+}
+
+impl Hash for Spacing {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.layout.hash(state);
+        self.line_height.map(f32::to_bits).hash(state);
+        self.letter_spacing.to_bits().hash(state);
+        self.is_hinted.hash(state);
+    }
+}
+
+impl GlyphPositioner for Spacing {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let (line_breaker, h_align, v_align, max_width) = match self.layout {
+            Layout::SingleLine {
+                line_breaker,
+                h_align,
+                v_align,
+            } => (line_breaker, h_align, v_align, f32::INFINITY),
+            Layout::Wrap {
+                line_breaker,
+                h_align,
+                v_align,
+            } => (line_breaker, h_align, v_align, geometry.bounds.0),
+        };
+
+        // Every paragraph is laid out on a single line from the origin first.
+        // We break and align the lines ourselves, since their width changes
+        // with the letter spacing.
+        let unbounded = SectionGeometry {
+            screen_position: (0.0, 0.0),
+            bounds: (f32::INFINITY, f32::INFINITY),
+        };
+
+        let mut glyphs = match self.layout {
+            Layout::SingleLine { .. } => Layout::SingleLine {
+                line_breaker,
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Top,
+            },
+            Layout::Wrap { .. } => Layout::Wrap {
+                line_breaker,
+                h_align: HorizontalAlign::Left,
+                v_align: VerticalAlign::Top,
+            },
+        }
+        .calculate_glyphs(fonts, &unbounded, sections);
+
+        let texts: Vec<&str> = sections
+            .iter()
+            .map(|section| section.to_section_text().text)
+            .collect();
+
+        let soft_breaks: Vec<Vec<usize>> = texts
+            .iter()
+            .map(|text| {
+                line_breaker
+                    .line_breaks(text)
+                    .filter_map(|line_break| match line_break {
+                        LineBreak::Soft(offset) => Some(offset),
+                        LineBreak::Hard(_) => None,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let font = |glyph: &SectionGlyph| {
+            fonts[glyph.font_id.0].as_scaled(glyph.glyph.scale)
+        };
+
+        let natural = |glyph: &SectionGlyph| {
+            let font = font(glyph);
+
+            font.height() + font.line_gap()
+        };
+
+        let advance =
+            |glyph: &SectionGlyph| font(glyph).h_advance(glyph.glyph.id);
+
+        let is_whitespace = |glyph: &SectionGlyph| {
+            texts[glyph.section_index][glyph.byte_index..]
+                .chars()
+                .next()
+                .map_or(false, char::is_whitespace)
+        };
+
+        let is_soft_break = |glyph: &SectionGlyph| {
+            soft_breaks[glyph.section_index]
+                .binary_search(&glyph.byte_index)
+                .is_ok()
+        };
+
+        // The paragraph of every glyph, given by the hard line breaks
+        let paragraph = |glyph: &SectionGlyph| {
+            ((glyph.glyph.position.y - font(glyph).ascent()) / natural(glyph))
+                .round()
+                .max(0.0) as usize
+        };
+
+        // The line and horizontal offset of every glyph
+        let mut placements = vec![(0, 0.0); glyphs.len()];
+        let mut current = None;
+        let mut start = 0;
+        let mut last_break = None;
+        let mut wraps = 0;
+        let mut i = 0;
+
+        while i < glyphs.len() {
+            let glyph = &glyphs[i];
+            let line = paragraph(glyph);
+
+            if current != Some(line) {
+                current = Some(line);
+                start = i;
+                last_break = None;
+            }
+
+            if i > start && is_soft_break(glyph) {
+                last_break = Some(i);
+            }
+
+            let x = glyph.glyph.position.x - glyphs[start].glyph.position.x
+                + (i - start) as f32 * self.letter_spacing;
+
+            // Like trailing whitespace, the first glyph of a line never wraps
+            if i > start
+                && !is_whitespace(glyph)
+                && x + advance(glyph) > max_width
+            {
+                let next = last_break.unwrap_or(i);
+
+                wraps += 1;
+                start = next;
+                last_break = None;
+                i = next;
+
+                continue;
+            }
+
+            placements[i] = (line + wraps, x);
+            i += 1;
+        }
+
+        let lines = placements
+            .iter()
+            .map(|(line, _)| line + 1)
+            .max()
+            .unwrap_or(0);
+
+        let mut widths = vec![0.0f32; lines];
+
+        for (glyph, (line, x)) in glyphs.iter().zip(&placements) {
+            if !is_whitespace(glyph) {
+                widths[*line] = widths[*line].max(x + advance(glyph));
+            }
+        }
+
+        let line_height = |glyph: &SectionGlyph| {
+            self.line_height.unwrap_or_else(|| natural(glyph))
+        };
+
+        let total_height = glyphs
+            .first()
+            .map_or(0.0, |glyph| line_height(glyph) * lines as f32);
+
+        let y_offset = match v_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Center => -total_height / 2.0,
+            VerticalAlign::Bottom => -total_height,
+        };
+
+        let x_factor = match h_align {
+            HorizontalAlign::Left => 0.0,
+            HorizontalAlign::Center => 0.5,
+            HorizontalAlign::Right => 1.0,
+        };
+
+        let (left, top) = geometry.screen_position;
+        let mut previous = None;
+
+        for (glyph, (line, x)) in glyphs.iter_mut().zip(placements) {
+            let line_height = line_height(glyph);
+            let extra = line_height - natural(glyph);
+
+            let x = left + x - widths[line] * x_factor;
+
+            // Negative spacing never moves a glyph behind its predecessor
+            let x = match previous {
+                Some((previous_line, previous_x)) if previous_line == line => {
+                    f32::max(x, previous_x)
+                }
+                _ => x,
+            };
+
+            glyph.glyph.position.x = x;
+            glyph.glyph.position.y = top
+                + y_offset
+                + line as f32 * line_height
+                + extra / 2.0
+                + font(glyph).ascent();
+
+            previous = Some((line, x));
+        }
+
+        if self.is_hinted {
+            for glyph in &mut glyphs {
+                glyph.glyph.position.x = glyph.glyph.position.x.round();
+                glyph.glyph.position.y = glyph.glyph.position.y.round();
+            }
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> ab_glyph::Rect {
+        self.layout.bounds_rect(geometry)
+    }
+}
@@ -0,0 +1,47 @@
+/// A text rendering strategy.
+///
+/// Hinting snaps the origin of every glyph to the pixel grid. This makes
+/// small text look sharper on low-DPI displays, at the cost of slightly
+/// uneven spacing between glyphs and text that moves in whole pixels when
+/// animated. On high-DPI displays, pixels are small enough for unhinted text
+/// to look crisp already.
+///
+/// Subpixel antialiasing uses the red, green, and blue stripes of every pixel
+/// separately, tripling the horizontal resolution of text. It assumes the
+/// stripes of the display are laid out horizontally in RGB order; any other
+/// layout shows color fringes. The stripes can only be used when the pixels
+/// under the text are opaque, so text drawn over a transparent background
+/// always falls back to grayscale antialiasing. The same applies to glyphs
+/// that are stretched when drawn, so subpixel antialiasing is only used for
+/// text that ends up aligned to the pixel grid.
+///
+/// Not every renderer supports subpixel antialiasing. Those that do not use
+/// [`TextRendering::Grayscale`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextRendering {
+    /// Grayscale antialiasing with hinting.
+    Grayscale,
+    /// Subpixel antialiasing with hinting.
+    Subpixel,
+    /// Grayscale antialiasing without hinting.
+    Unhinted,
+}
+
+impl TextRendering {
+    /// Returns whether the glyphs are snapped to the pixel grid with the
+    /// [`TextRendering`].
+    pub fn is_hinted(self) -> bool {
+        match self {
+            TextRendering::Grayscale | TextRendering::Subpixel => true,
+            TextRendering::Unhinted => false,
+        }
+    }
+}
+
+impl Default for TextRendering {
+    /// Returns [`TextRendering::Unhinted`], which lays out text exactly like
+    /// the renderers did before hinting was supported.
+    fn default() -> Self {
+        TextRendering::Unhinted
+    }
+}
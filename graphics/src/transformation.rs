@@ -1,3 +1,5 @@
+use crate::Point;
+
 use glam::{Mat4, Vec3};
 use std::ops::Mul;
 
@@ -30,6 +32,14 @@ impl Transformation {
     pub fn scale(x: f32, y: f32) -> Transformation {
         Transformation(Mat4::from_scale(Vec3::new(x, y, 1.0)))
     }
+
+    /// Applies the [`Transformation`] to the given [`Point`].
+    pub fn transform_point(&self, point: Point) -> Point {
+        let transformed =
+            self.0.transform_point3(Vec3::new(point.x, point.y, 0.0));
+
+        Point::new(transformed.x, transformed.y)
+    }
 }
 
 impl Mul for Transformation {
@@ -1,8 +1,15 @@
 //! Draw geometry using meshes of triangles.
+pub mod polyline;
+
+use crate::{Color, Transformation};
+
 use bytemuck::{Pod, Zeroable};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Arc;
 
 /// A set of [`Vertex2D`] and indices representing a list of triangles.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Mesh2D<T> {
     /// The vertices of the mesh
     pub vertices: Vec<T>,
@@ -14,7 +21,7 @@ pub struct Mesh2D<T> {
 }
 
 /// A two-dimensional vertex.
-#[derive(Copy, Clone, Debug, Zeroable, Pod)]
+#[derive(Copy, Clone, Debug, PartialEq, Zeroable, Pod)]
 #[repr(C)]
 pub struct Vertex2D {
     /// The vertex position in 2D space.
@@ -22,7 +29,7 @@ pub struct Vertex2D {
 }
 
 /// A two-dimensional vertex with a color.
-#[derive(Copy, Clone, Debug, Zeroable, Pod)]
+#[derive(Copy, Clone, Debug, PartialEq, Zeroable, Pod)]
 #[repr(C)]
 pub struct ColoredVertex2D {
     /// The vertex position in 2D space.
@@ -31,3 +38,273 @@ pub struct ColoredVertex2D {
     /// The color of the vertex in __linear__ RGBA.
     pub color: [f32; 4],
 }
+
+/// A shared handle to a [`Mesh2D`] of [`ColoredVertex2D`] that can be drawn
+/// many times with [`Primitive::InstancedMesh`].
+///
+/// Renderers upload the vertices of the mesh only once and keep them cached
+/// for as long as the [`Handle`] keeps being drawn. Since the mesh of a
+/// [`Handle`] is immutable, a new [`Handle`] must be created whenever the
+/// mesh changes. The cached vertices of the old [`Handle`] are discarded
+/// once it stops being drawn.
+///
+/// [`Primitive::InstancedMesh`]: crate::Primitive::InstancedMesh
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    mesh: Arc<Mesh2D<ColoredVertex2D>>,
+}
+
+impl Handle {
+    /// Creates a new [`Handle`] for the given [`Mesh2D`].
+    pub fn new(mesh: Mesh2D<ColoredVertex2D>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            mesh: Arc::new(mesh),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Handle`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the [`Mesh2D`] of the [`Handle`].
+    pub fn mesh(&self) -> &Mesh2D<ColoredVertex2D> {
+        &self.mesh
+    }
+
+    /// Produces a single [`Mesh2D`] containing all the given instances of
+    /// the [`Handle`].
+    ///
+    /// This can be used by renderers that are not capable of drawing
+    /// instances directly.
+    pub fn instantiate(
+        &self,
+        instances: &[Instance],
+    ) -> Mesh2D<ColoredVertex2D> {
+        let mut vertices =
+            Vec::with_capacity(self.mesh.vertices.len() * instances.len());
+        let mut indices =
+            Vec::with_capacity(self.mesh.indices.len() * instances.len());
+
+        for instance in instances {
+            let offset = vertices.len() as u32;
+
+            vertices.extend(
+                self.mesh
+                    .vertices
+                    .iter()
+                    .map(|vertex| instance.apply(*vertex)),
+            );
+
+            indices
+                .extend(self.mesh.indices.iter().map(|index| index + offset));
+        }
+
+        Mesh2D { vertices, indices }
+    }
+}
+
+impl PartialEq for Handle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Handle {}
+
+impl Hash for Handle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// An instance of a [`Handle`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Instance {
+    /// The [`Transformation`] applied to the vertices of the mesh.
+    pub transformation: Transformation,
+
+    /// The [`Color`] multiplied with the colors of the vertices of the mesh.
+    pub color: Color,
+}
+
+impl Instance {
+    /// Creates a new [`Instance`] translated to the given position and with
+    /// the original colors of the mesh.
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            transformation: Transformation::translate(x, y),
+            color: Color::WHITE,
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Instance`].
+    pub fn color(self, color: impl Into<Color>) -> Self {
+        Self {
+            color: color.into(),
+            ..self
+        }
+    }
+
+    /// Applies the [`Instance`] to the given vertex.
+    pub fn apply(&self, vertex: ColoredVertex2D) -> ColoredVertex2D {
+        let position = self.transformation.transform_point(crate::Point::new(
+            vertex.position[0],
+            vertex.position[1],
+        ));
+
+        let tint = self.color.into_linear();
+
+        ColoredVertex2D {
+            position: [position.x, position.y],
+            color: [0, 1, 2, 3].map(|i| vertex.color[i] * tint[i]),
+        }
+    }
+}
+
+/// A two-dimensional vertex with a color and a normal used for lighting.
+///
+/// Meshes of [`LitVertex2D`] are shaded by a [`Light`].
+#[derive(Copy, Clone, Debug, PartialEq, Zeroable, Pod)]
+#[repr(C)]
+pub struct LitVertex2D {
+    /// The vertex position in 2D space.
+    pub position: [f32; 2],
+
+    /// The color of the vertex in __linear__ RGBA.
+    pub color: [f32; 4],
+
+    /// The normal of the surface at the vertex.
+    ///
+    /// It uses the same coordinate system as the position—with `y` pointing
+    /// down—and `z` pointing towards the viewer. It does not need to be
+    /// normalized, but it must not be zero.
+    pub normal: [f32; 3],
+}
+
+/// A directional light that shades a mesh of [`LitVertex2D`].
+///
+/// The shading follows a simple Lambertian model. The color of every
+/// fragment is multiplied by:
+///
+/// ```text
+/// ambient + (1.0 - ambient) * max(dot(normal, direction), 0.0)
+/// ```
+///
+/// where `normal` is the interpolated normal of the vertices and `direction`
+/// is the [`Light::direction`]. Both are normalized first. Alpha is left
+/// untouched.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Light {
+    /// The direction pointing __towards__ the light, in the same coordinate
+    /// system as [`LitVertex2D::normal`].
+    pub direction: [f32; 3],
+
+    /// The amount of light that reaches every fragment regardless of its
+    /// normal, from `0.0` to `1.0`.
+    pub ambient: f32,
+}
+
+impl Light {
+    /// Returns the intensity of the [`Light`] on a surface with the given
+    /// normal, from `0.0` to `1.0`.
+    pub fn intensity(&self, normal: [f32; 3]) -> f32 {
+        let normal = normalize(normal);
+        let direction = normalize(self.direction);
+
+        let diffuse = (normal[0] * direction[0]
+            + normal[1] * direction[1]
+            + normal[2] * direction[2])
+            .max(0.0);
+
+        let ambient = self.ambient.clamp(0.0, 1.0);
+
+        ambient + (1.0 - ambient) * diffuse
+    }
+}
+
+impl Default for Light {
+    /// Returns a [`Light`] placed at the viewer, with no ambient light.
+    ///
+    /// Surfaces facing the viewer keep their original colors.
+    fn default() -> Self {
+        Self {
+            direction: [0.0, 0.0, 1.0],
+            ambient: 0.0,
+        }
+    }
+}
+
+fn normalize([x, y, z]: [f32; 3]) -> [f32; 3] {
+    let length = (x * x + y * y + z * z).sqrt();
+
+    if length > 0.0 {
+        [x / length, y / length, z / length]
+    } else {
+        [0.0; 3]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn instantiates_handles() {
+        let handle = Handle::new(Mesh2D {
+            vertices: vec![
+                ColoredVertex2D {
+                    position: [0.0, 0.0],
+                    color: [1.0, 1.0, 1.0, 1.0],
+                },
+                ColoredVertex2D {
+                    position: [1.0, 0.0],
+                    color: [1.0, 1.0, 1.0, 1.0],
+                },
+                ColoredVertex2D {
+                    position: [0.0, 1.0],
+                    color: [1.0, 1.0, 1.0, 1.0],
+                },
+            ],
+            indices: vec![0, 1, 2],
+        });
+
+        let mesh = handle.instantiate(&[
+            Instance::new(0.0, 0.0),
+            Instance::new(10.0, 5.0).color(Color::BLACK),
+        ]);
+
+        assert_eq!(mesh.vertices.len(), 6);
+        assert_eq!(mesh.indices, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(mesh.vertices[4].position, [11.0, 5.0]);
+        assert_eq!(mesh.vertices[4].color, [0.0, 0.0, 0.0, 1.0]);
+
+        assert_ne!(handle, Handle::new(handle.mesh().clone()));
+        assert_eq!(handle, handle.clone());
+    }
+
+    #[test]
+    fn light_intensity_follows_lambert() {
+        let light = Light::default();
+
+        assert_eq!(light.intensity([0.0, 0.0, 1.0]), 1.0);
+        assert_eq!(light.intensity([0.0, 0.0, 5.0]), 1.0);
+        assert_eq!(light.intensity([1.0, 0.0, 0.0]), 0.0);
+        assert_eq!(light.intensity([0.0, 0.0, -1.0]), 0.0);
+
+        let angled = light.intensity([1.0, 0.0, 1.0]);
+        assert!((angled - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+
+        let ambient = Light {
+            ambient: 0.25,
+            ..Light::default()
+        };
+
+        assert_eq!(ambient.intensity([0.0, 0.0, -1.0]), 0.25);
+        assert_eq!(ambient.intensity([0.0, 0.0, 1.0]), 1.0);
+    }
+}
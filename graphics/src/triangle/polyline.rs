@@ -0,0 +1,492 @@
+//! Tessellate thick polylines into meshes of triangles.
+//!
+//! The resulting [`Mesh2D`] can be drawn with a [`Primitive::SolidMesh`].
+//!
+//! Every segment, join and cap is tessellated separately, so the triangles
+//! of a polyline may overlap around its joins. This is invisible for opaque
+//! colors, but translucent polylines will be blended more than once there.
+//!
+//! [`Primitive::SolidMesh`]: crate::Primitive::SolidMesh
+use crate::triangle::{ColoredVertex2D, Mesh2D};
+use crate::{Color, Point, Vector};
+
+use std::f32::consts::PI;
+
+/// The maximum distance between a round join or cap and its tessellation.
+const TOLERANCE: f32 = 0.1;
+
+/// The shape used at the corners of a polyline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Join {
+    /// Segments are extended until they meet in a sharp corner.
+    ///
+    /// Corners sharper than the [`Style::miter_limit`] are beveled instead.
+    Miter,
+
+    /// Segments are connected with a straight line across their outer
+    /// corners.
+    Bevel,
+
+    /// Segments are connected with an arc.
+    Round,
+}
+
+impl Default for Join {
+    fn default() -> Self {
+        Self::Miter
+    }
+}
+
+/// The shape used at the endpoints of an open polyline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cap {
+    /// The polyline ends exactly at its endpoints.
+    Butt,
+
+    /// The polyline is extended past its endpoints by half of its width.
+    Square,
+
+    /// The polyline ends with a half circle centered at its endpoints.
+    Round,
+}
+
+impl Default for Cap {
+    fn default() -> Self {
+        Self::Butt
+    }
+}
+
+/// The style of a tessellated polyline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The width of the polyline.
+    pub width: f32,
+
+    /// The color of the polyline.
+    pub color: Color,
+
+    /// The [`Join`] used at the corners of the polyline.
+    pub join: Join,
+
+    /// The [`Cap`] used at the endpoints of the polyline.
+    ///
+    /// It is ignored when the polyline is closed.
+    pub cap: Cap,
+
+    /// The maximum ratio between the length of a miter and the width of the
+    /// polyline.
+    ///
+    /// [`Join::Miter`] falls back to [`Join::Bevel`] for corners exceeding
+    /// it, so very sharp angles do not produce long spikes.
+    pub miter_limit: f32,
+
+    /// Whether the last point of the polyline is connected to its first one.
+    pub closed: bool,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            color: Color::BLACK,
+            join: Join::default(),
+            cap: Cap::default(),
+            miter_limit: 4.0,
+            closed: false,
+        }
+    }
+}
+
+/// Tessellates the polyline defined by the given points with the given
+/// [`Style`].
+///
+/// Consecutive duplicated points are ignored. If less than two distinct
+/// points remain, the resulting [`Mesh2D`] is empty.
+pub fn stroke(points: &[Point], style: &Style) -> Mesh2D<ColoredVertex2D> {
+    let mut builder = Builder {
+        vertices: Vec::new(),
+        indices: Vec::new(),
+        color: style.color.into_linear(),
+    };
+
+    let mut points: Vec<Point> = points.to_vec();
+    points.dedup();
+
+    if style.closed && points.len() > 2 && points.first() == points.last() {
+        let _ = points.pop();
+    }
+
+    let half_width = style.width / 2.0;
+
+    if points.len() < 2 || half_width <= 0.0 {
+        return builder.finish();
+    }
+
+    let closed = style.closed && points.len() > 2;
+
+    let segments: Vec<(Point, Point)> = if closed {
+        points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| (*a, *b))
+            .collect()
+    } else {
+        points.windows(2).map(|pair| (pair[0], pair[1])).collect()
+    };
+
+    let directions: Vec<Vector> =
+        segments.iter().map(|(a, b)| direction(*a, *b)).collect();
+
+    for (index, ((start, end), direction)) in
+        segments.iter().zip(&directions).enumerate()
+    {
+        let (mut start, mut end) = (*start, *end);
+
+        if !closed && style.cap == Cap::Square {
+            if index == 0 {
+                start = start - *direction * half_width;
+            }
+
+            if index == segments.len() - 1 {
+                end = end + *direction * half_width;
+            }
+        }
+
+        let offset = normal(*direction) * half_width;
+
+        builder.quad([
+            start + offset,
+            end + offset,
+            end - offset,
+            start - offset,
+        ]);
+    }
+
+    // Joins between consecutive segments
+    let joins = if closed {
+        segments.len()
+    } else {
+        segments.len() - 1
+    };
+
+    for index in 0..joins {
+        let next = (index + 1) % segments.len();
+
+        builder.join(
+            segments[index].1,
+            directions[index],
+            directions[next],
+            half_width,
+            style,
+        );
+    }
+
+    if !closed && style.cap == Cap::Round {
+        let first = directions[0];
+        let last = directions[directions.len() - 1];
+
+        builder.arc(segments[0].0, normal(first) * half_width, PI);
+
+        builder.arc(
+            segments[segments.len() - 1].1,
+            normal(last) * -half_width,
+            PI,
+        );
+    }
+
+    builder.finish()
+}
+
+struct Builder {
+    vertices: Vec<ColoredVertex2D>,
+    indices: Vec<u32>,
+    color: [f32; 4],
+}
+
+impl Builder {
+    fn vertex(&mut self, point: Point) -> u32 {
+        self.vertices.push(ColoredVertex2D {
+            position: [point.x, point.y],
+            color: self.color,
+        });
+
+        self.vertices.len() as u32 - 1
+    }
+
+    fn triangle(&mut self, points: [Point; 3]) {
+        let [a, b, c] = points.map(|point| self.vertex(point));
+
+        self.indices.extend([a, b, c]);
+    }
+
+    fn quad(&mut self, points: [Point; 4]) {
+        let [a, b, c, d] = points.map(|point| self.vertex(point));
+
+        self.indices.extend([a, b, c, a, c, d]);
+    }
+
+    /// Adds the join of two segments meeting at `point`.
+    fn join(
+        &mut self,
+        point: Point,
+        incoming: Vector,
+        outgoing: Vector,
+        half_width: f32,
+        style: &Style,
+    ) {
+        let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+        let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+
+        // Collinear segments going in the same direction need no join
+        if cross.abs() <= f32::EPSILON && dot > 0.0 {
+            return;
+        }
+
+        // The outer side of the corner is opposite to the turn
+        let side = if cross > 0.0 { -1.0 } else { 1.0 };
+
+        let from = normal(incoming) * (half_width * side);
+        let to = normal(outgoing) * (half_width * side);
+
+        match style.join {
+            Join::Round => {
+                let sweep = angle(from, to);
+
+                self.arc(point, from, sweep);
+            }
+            Join::Miter => {
+                let bisector = from + to;
+                let length_squared =
+                    bisector.x * bisector.x + bisector.y * bisector.y;
+
+                // The ratio between the miter length and the width is
+                // `1 / cos(θ / 2)`, where `θ` is the angle between normals
+                let cos_half = length_squared.sqrt() / (2.0 * half_width);
+
+                if cos_half > 0.0 && 1.0 / cos_half <= style.miter_limit {
+                    let tip = point
+                        + bisector
+                            * (2.0 * half_width * half_width / length_squared);
+
+                    self.quad([point, point + from, tip, point + to]);
+                } else {
+                    self.triangle([point, point + from, point + to]);
+                }
+            }
+            Join::Bevel => {
+                self.triangle([point, point + from, point + to]);
+            }
+        }
+    }
+
+    /// Adds a fan of triangles around `center`, starting at `center + from`
+    /// and sweeping the given angle.
+    fn arc(&mut self, center: Point, from: Vector, sweep: f32) {
+        let radius = magnitude(from);
+
+        if radius <= 0.0 || sweep == 0.0 {
+            return;
+        }
+
+        let step = 2.0 * (1.0 - TOLERANCE.min(radius) / radius).acos();
+
+        let segments = if step > 0.0 {
+            (sweep.abs() / step).ceil().max(1.0) as u32
+        } else {
+            1
+        };
+
+        let center_index = self.vertex(center);
+        let mut previous = self.vertex(center + from);
+
+        for segment in 1..=segments {
+            let angle = sweep * segment as f32 / segments as f32;
+            let (sin, cos) = angle.sin_cos();
+
+            let current = self.vertex(
+                center
+                    + Vector::new(
+                        from.x * cos - from.y * sin,
+                        from.x * sin + from.y * cos,
+                    ),
+            );
+
+            self.indices.extend([center_index, previous, current]);
+
+            previous = current;
+        }
+    }
+
+    fn finish(self) -> Mesh2D<ColoredVertex2D> {
+        Mesh2D {
+            vertices: self.vertices,
+            indices: self.indices,
+        }
+    }
+}
+
+fn direction(start: Point, end: Point) -> Vector {
+    let vector = end - start;
+    let length = magnitude(vector);
+
+    Vector::new(vector.x / length, vector.y / length)
+}
+
+/// Returns the given [`Vector`] rotated by 90 degrees.
+fn normal(direction: Vector) -> Vector {
+    Vector::new(-direction.y, direction.x)
+}
+
+fn magnitude(vector: Vector) -> f32 {
+    (vector.x * vector.x + vector.y * vector.y).sqrt()
+}
+
+/// Returns the signed angle needed to rotate `from` into `to`.
+fn angle(from: Vector, to: Vector) -> f32 {
+    let cross = from.x * to.y - from.y * to.x;
+    let dot = from.x * to.x + from.y * to.y;
+
+    cross.atan2(dot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn max_x(mesh: &Mesh2D<ColoredVertex2D>) -> f32 {
+        mesh.vertices
+            .iter()
+            .map(|vertex| vertex.position[0])
+            .fold(f32::MIN, f32::max)
+    }
+
+    #[test]
+    fn strokes_a_single_segment() {
+        let mesh = stroke(
+            &[Point::new(0.0, 0.0), Point::new(10.0, 0.0)],
+            &Style {
+                width: 2.0,
+                ..Style::default()
+            },
+        );
+
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.indices.len(), 6);
+
+        let mut ys: Vec<f32> = mesh
+            .vertices
+            .iter()
+            .map(|vertex| vertex.position[1])
+            .collect();
+        ys.sort_by(f32::total_cmp);
+
+        assert_eq!(ys, vec![-1.0, -1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn ignores_degenerate_polylines() {
+        let style = Style::default();
+
+        assert!(stroke(&[], &style).indices.is_empty());
+        assert!(stroke(&[Point::ORIGIN], &style).indices.is_empty());
+        assert!(stroke(&[Point::ORIGIN, Point::ORIGIN], &style)
+            .indices
+            .is_empty());
+    }
+
+    #[test]
+    fn falls_back_to_bevel_on_sharp_angles() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(0.0, 1.0),
+        ];
+
+        let miter = stroke(
+            &points,
+            &Style {
+                width: 2.0,
+                miter_limit: 1000.0,
+                ..Style::default()
+            },
+        );
+
+        let limited = stroke(
+            &points,
+            &Style {
+                width: 2.0,
+                ..Style::default()
+            },
+        );
+
+        assert!(max_x(&miter) > 20.0);
+        assert!(max_x(&limited) < 11.0);
+    }
+
+    #[test]
+    fn joins_closed_polylines() {
+        let square = [
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+            Point::new(0.0, 0.0),
+        ];
+
+        let mesh = stroke(
+            &square,
+            &Style {
+                width: 2.0,
+                closed: true,
+                ..Style::default()
+            },
+        );
+
+        // 4 segments and 4 miter joins
+        assert_eq!(mesh.indices.len(), 4 * 6 + 4 * 6);
+
+        let has_corner = |x: f32, y: f32| {
+            mesh.vertices.iter().any(|vertex| vertex.position == [x, y])
+        };
+
+        for (x, y) in [(-1.0, -1.0), (11.0, -1.0), (11.0, 11.0), (-1.0, 11.0)] {
+            assert!(has_corner(x, y), "missing corner at ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn caps_open_polylines() {
+        let points = [Point::new(0.0, 0.0), Point::new(10.0, 0.0)];
+
+        let square = stroke(
+            &points,
+            &Style {
+                width: 2.0,
+                cap: Cap::Square,
+                ..Style::default()
+            },
+        );
+
+        let xs: Vec<f32> = square
+            .vertices
+            .iter()
+            .map(|vertex| vertex.position[0])
+            .collect();
+
+        assert!(xs.contains(&-1.0) && xs.contains(&11.0));
+
+        let round = stroke(
+            &points,
+            &Style {
+                width: 2.0,
+                cap: Cap::Round,
+                ..Style::default()
+            },
+        );
+
+        assert!(round.vertices.iter().any(|vertex| {
+            (vertex.position[0] - -1.0).abs() < 1e-5
+                && vertex.position[1].abs() < 1e-5
+        }));
+    }
+}
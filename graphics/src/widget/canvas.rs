@@ -9,25 +9,26 @@ pub mod path;
 pub mod stroke;
 
 mod cache;
-mod cursor;
 mod frame;
 mod geometry;
 mod program;
 mod style;
 mod text;
+mod transform;
 
 pub use crate::gradient::{self, Gradient};
 pub use cache::Cache;
-pub use cursor::Cursor;
 pub use event::Event;
 pub use fill::{Fill, FillRule};
 pub use frame::Frame;
 pub use geometry::Geometry;
+pub use iced_native::mouse::Cursor;
 pub use path::Path;
 pub use program::Program;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use style::Style;
 pub use text::Text;
+pub use transform::Transform;
 
 use crate::{Backend, Primitive, Renderer};
 
@@ -36,7 +37,7 @@ use iced_native::mouse;
 use iced_native::renderer;
 use iced_native::widget::tree::{self, Tree};
 use iced_native::{
-    Clipboard, Element, Length, Point, Rectangle, Shell, Size, Vector, Widget,
+    Clipboard, Element, Length, Rectangle, Shell, Size, Vector, Widget,
 };
 
 use std::marker::PhantomData;
@@ -84,6 +85,21 @@ use std::marker::PhantomData;
 /// // Finally, we simply use our `Circle` to create the `Canvas`!
 /// let canvas = Canvas::new(Circle { radius: 50.0 });
 /// ```
+///
+/// ## Coordinates
+/// Every coordinate of a [`Canvas`] is given in logical pixels—the units of
+/// the layout—regardless of the scale factor of the window. The y axis points
+/// down, so positive angles rotate clockwise on the screen.
+///
+/// A [`Frame`] has its origin at the top-left corner of the [`Canvas`], while
+/// the `bounds` given to a [`Program`] and the position of its [`Cursor`] are
+/// absolute. [`Cursor::position_in`] returns the position of the [`Cursor`]
+/// relative to the [`Canvas`].
+///
+/// Once a [`Frame`] is transformed, its coordinates differ from the ones of
+/// the [`Canvas`]. A [`Transform`] describes the mapping between both—in
+/// either direction—so the [`Cursor`] can be mapped to the coordinates of
+/// the drawn shapes, e.g. to hit-test them in a zoomable [`Canvas`].
 #[derive(Debug)]
 pub struct Canvas<Message, Theme, P>
 where
@@ -164,7 +180,7 @@ where
         tree: &mut Tree,
         event: iced_native::Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer<B, T>,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -184,8 +200,6 @@ where
             _ => None,
         };
 
-        let cursor = Cursor::from_window_position(cursor_position);
-
         if let Some(canvas_event) = canvas_event {
             let state = tree.state.downcast_mut::<P::State>();
 
@@ -206,12 +220,11 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer<B, T>,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
-        let cursor = Cursor::from_window_position(cursor_position);
         let state = tree.state.downcast_ref::<P::State>();
 
         self.program.mouse_interaction(state, bounds, cursor)
@@ -224,7 +237,7 @@ where
         theme: &T,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         use iced_native::Renderer as _;
@@ -236,7 +249,6 @@ where
         }
 
         let translation = Vector::new(bounds.x, bounds.y);
-        let cursor = Cursor::from_window_position(cursor_position);
         let state = tree.state.downcast_ref::<P::State>();
 
         renderer.with_translation(translation, |renderer| {
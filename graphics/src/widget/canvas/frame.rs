@@ -1,8 +1,11 @@
 use crate::gradient::Gradient;
 use crate::triangle;
-use crate::widget::canvas::{path, Fill, Geometry, Path, Stroke, Style, Text};
+use crate::widget::canvas::{
+    self, path, Fill, Geometry, Path, Stroke, Style, Text,
+};
 use crate::Primitive;
 
+use iced_native::text::LineHeight;
 use iced_native::{Point, Rectangle, Size, Vector};
 
 use lyon::geom::euclid;
@@ -145,7 +148,9 @@ impl Frame {
     /// Creates a new empty [`Frame`] with the given dimensions.
     ///
     /// The default coordinate system of a [`Frame`] has its origin at the
-    /// top-left corner of its bounds.
+    /// top-left corner of its bounds, with the x axis pointing right and the
+    /// y axis pointing down. Its units are logical pixels, like the ones of
+    /// the layout, regardless of the scale factor of the window.
     pub fn new(size: Size) -> Frame {
         Frame {
             size,
@@ -331,12 +336,20 @@ impl Frame {
             },
             color: text.color,
             size: text.size,
+            line_height: LineHeight::default(),
+            letter_spacing: 0.0,
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
         });
     }
 
+    /// Draws the given [`Primitive`] on the [`Frame`] as it is, ignoring the
+    /// current transform.
+    pub(crate) fn draw_primitive(&mut self, primitive: Primitive) {
+        self.primitives.push(primitive);
+    }
+
     /// Stores the current transform of the [`Frame`] and executes the given
     /// drawing operations, restoring the transform afterwards.
     ///
@@ -381,6 +394,7 @@ impl Frame {
                     translation,
                     content: Box::new(Primitive::Clip {
                         bounds: Rectangle::with_size(region.size()),
+                        border_radius: [0.0; 4],
                         content: Box::new(Primitive::Group {
                             primitives: text,
                         }),
@@ -423,6 +437,32 @@ impl Frame {
         self.transforms.current.is_identity = false;
     }
 
+    /// Applies the given [`Transform`] to the current transform of the
+    /// [`Frame`].
+    ///
+    /// [`Transform`]: canvas::Transform
+    #[inline]
+    pub fn apply_transform(&mut self, transform: canvas::Transform) {
+        self.transforms.current.raw =
+            transform.raw().then(&self.transforms.current.raw);
+        self.transforms.current.is_identity = false;
+    }
+
+    /// Returns the current transform of the [`Frame`]—the composition of
+    /// every transform applied to it and not yet restored by
+    /// [`Frame::with_save`].
+    ///
+    /// Its inverse maps points in the local coordinates of the [`Canvas`],
+    /// like the position of the cursor, to the coordinates currently used to
+    /// draw on the [`Frame`]. See [`Transform`] for details.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    /// [`Transform`]: canvas::Transform
+    #[inline]
+    pub fn transform(&self) -> canvas::Transform {
+        canvas::Transform::from_raw(self.transforms.current.raw)
+    }
+
     /// Produces the [`Geometry`] representing everything drawn on the [`Frame`].
     pub fn into_geometry(self) -> Geometry {
         Geometry::from_primitive(Primitive::Group {
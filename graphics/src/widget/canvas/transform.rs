@@ -0,0 +1,212 @@
+use crate::widget::canvas::Cursor;
+
+use iced_native::{Point, Rectangle, Vector};
+
+/// A 2D affine transform from the coordinate system used to draw on a
+/// [`Frame`] to the local coordinate system of its [`Canvas`].
+///
+/// It is the composition of the translations, rotations, and scalings
+/// applied to a [`Frame`]. A [`Program`] that keeps its [`Transform`]—its pan
+/// and zoom, for instance—in its state can apply it when drawing with
+/// [`Frame::apply_transform`], and use its inverse to map the [`Cursor`]
+/// into the same coordinates in [`Program::update`]. Hit-testing the drawn
+/// shapes against the mapped [`Cursor`] is then consistent with what is on
+/// the screen.
+///
+/// # Example
+/// ```
+/// use iced_graphics::widget::canvas::{Cursor, Transform};
+/// use iced_native::{Point, Rectangle, Size, Vector};
+///
+/// let bounds = Rectangle::new(Point::new(10.0, 10.0), Size::new(100.0, 100.0));
+///
+/// let transform = Transform::identity()
+///     .translate(Vector::new(50.0, 50.0))
+///     .scale(2.0);
+///
+/// let cursor = Cursor::Available(Point::new(70.0, 70.0));
+///
+/// assert_eq!(
+///     transform.cursor_position(cursor, bounds),
+///     Some(Point::new(5.0, 5.0))
+/// );
+/// ```
+///
+/// [`Canvas`]: crate::widget::Canvas
+/// [`Frame`]: crate::widget::canvas::Frame
+/// [`Frame::apply_transform`]: crate::widget::canvas::Frame::apply_transform
+/// [`Program`]: crate::widget::canvas::Program
+/// [`Program::update`]: crate::widget::canvas::Program::update
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    raw: lyon::math::Transform,
+}
+
+impl Transform {
+    /// Creates the identity [`Transform`], which leaves every point as it is.
+    pub fn identity() -> Self {
+        Self::from_raw(lyon::math::Transform::identity())
+    }
+
+    /// Applies a translation to the [`Transform`].
+    ///
+    /// Like [`Frame::translate`], the translation is applied before the
+    /// current [`Transform`]—so it is expressed in its coordinates.
+    ///
+    /// [`Frame::translate`]: crate::widget::canvas::Frame::translate
+    pub fn translate(self, translation: Vector) -> Self {
+        Self::from_raw(self.raw.pre_translate(lyon::math::Vector::new(
+            translation.x,
+            translation.y,
+        )))
+    }
+
+    /// Applies a rotation in radians to the [`Transform`].
+    ///
+    /// Like [`Frame::rotate`], the rotation is applied before the current
+    /// [`Transform`]. Since the y axis points down, positive angles rotate
+    /// clockwise on the screen.
+    ///
+    /// [`Frame::rotate`]: crate::widget::canvas::Frame::rotate
+    pub fn rotate(self, angle: f32) -> Self {
+        Self::from_raw(self.raw.pre_rotate(lyon::math::Angle::radians(angle)))
+    }
+
+    /// Applies a scaling to the [`Transform`].
+    ///
+    /// Like [`Frame::scale`], the scaling is applied before the current
+    /// [`Transform`].
+    ///
+    /// [`Frame::scale`]: crate::widget::canvas::Frame::scale
+    pub fn scale(self, scale: f32) -> Self {
+        Self::from_raw(self.raw.pre_scale(scale, scale))
+    }
+
+    /// Applies the given [`Transform`] before this one, producing their
+    /// composition.
+    ///
+    /// Like [`Frame::apply_transform`], the given [`Transform`] is expressed
+    /// in the coordinates of the current one.
+    ///
+    /// [`Frame::apply_transform`]: crate::widget::canvas::Frame::apply_transform
+    pub fn apply_transform(self, transform: Transform) -> Self {
+        Self::from_raw(transform.raw.then(&self.raw))
+    }
+
+    /// Returns the inverse of the [`Transform`], if it has one.
+    ///
+    /// A [`Transform`] with a scaling of zero has no inverse.
+    pub fn inverse(self) -> Option<Self> {
+        self.raw.inverse().map(Self::from_raw)
+    }
+
+    /// Maps a [`Point`] drawn on a [`Frame`] with this [`Transform`] to the
+    /// local coordinates of its [`Canvas`].
+    ///
+    /// [`Frame`]: crate::widget::canvas::Frame
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn transform_point(&self, point: Point) -> Point {
+        let point = self
+            .raw
+            .transform_point(lyon::math::Point::new(point.x, point.y));
+
+        Point::new(point.x, point.y)
+    }
+
+    /// Maps a [`Point`] in the local coordinates of a [`Canvas`] to the
+    /// coordinates of a [`Frame`] drawn with this [`Transform`], if the
+    /// [`Transform`] has an inverse.
+    ///
+    /// This is the inverse of [`Transform::transform_point`].
+    ///
+    /// [`Frame`]: crate::widget::canvas::Frame
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn inverse_transform_point(&self, point: Point) -> Option<Point> {
+        self.inverse().map(|inverse| inverse.transform_point(point))
+    }
+
+    /// Maps the position of the [`Cursor`] to the coordinates of a [`Frame`]
+    /// drawn with this [`Transform`], given the `bounds` of the [`Canvas`].
+    ///
+    /// The `bounds` are the ones given to the [`Program`], where the
+    /// position of the [`Cursor`] is absolute. It returns `None` if the
+    /// [`Cursor`] is unavailable or out of the `bounds`, or if the
+    /// [`Transform`] has no inverse.
+    ///
+    /// [`Frame`]: crate::widget::canvas::Frame
+    /// [`Canvas`]: crate::widget::Canvas
+    /// [`Program`]: crate::widget::canvas::Program
+    pub fn cursor_position(
+        &self,
+        cursor: Cursor,
+        bounds: Rectangle,
+    ) -> Option<Point> {
+        self.inverse_transform_point(cursor.position_in(&bounds)?)
+    }
+
+    pub(crate) fn from_raw(raw: lyon::math::Transform) -> Self {
+        Self { raw }
+    }
+
+    pub(crate) fn raw(&self) -> &lyon::math::Transform {
+        &self.raw
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: Point, b: Point) {
+        assert!(a.distance(b) < 1e-4, "{a:?} is not close enough to {b:?}");
+    }
+
+    #[test]
+    fn inverse_undoes_composed_transforms() {
+        let transform = Transform::identity()
+            .translate(Vector::new(40.0, -10.0))
+            .rotate(std::f32::consts::FRAC_PI_3)
+            .scale(2.5)
+            .translate(Vector::new(-3.0, 7.0));
+
+        for point in [
+            Point::ORIGIN,
+            Point::new(12.5, -4.0),
+            Point::new(-100.0, 250.0),
+        ] {
+            let transformed = transform.transform_point(point);
+
+            assert_near(
+                transform.inverse_transform_point(transformed).unwrap(),
+                point,
+            );
+        }
+    }
+
+    #[test]
+    fn transforms_apply_in_frame_order() {
+        // The rotation happens in the translated coordinates, like on a frame
+        let transform = Transform::identity()
+            .translate(Vector::new(10.0, 0.0))
+            .rotate(std::f32::consts::FRAC_PI_2);
+
+        assert_near(
+            transform.transform_point(Point::new(1.0, 0.0)),
+            Point::new(10.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn zero_scaling_has_no_inverse() {
+        let transform = Transform::identity().scale(0.0);
+
+        assert_eq!(transform.inverse(), None);
+        assert_eq!(transform.inverse_transform_point(Point::ORIGIN), None);
+    }
+}
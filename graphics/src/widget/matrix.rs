@@ -0,0 +1,424 @@
+//! Display a grid of colored cells, like QR codes, heatmaps, or pixel art.
+use crate::renderer::{self, Renderer};
+use crate::triangle::{self, ColoredVertex2D};
+use crate::widget::canvas;
+use crate::{Backend, Primitive};
+
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::widget::tree::{self, Tree};
+use iced_native::{
+    Color, Element, Layout, Length, Rectangle, Size, Vector, Widget,
+};
+
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+const DEFAULT_CELL_SIZE: f32 = 4.0;
+
+/// A grid of cells, each filled with a [`Color`].
+///
+/// The cells are given row by row. A [`Matrix`] can be [`monochrome`], where
+/// every cell is either on or off, or it can have a [`Color`] per cell with
+/// [`Matrix::colors`].
+///
+/// The whole grid is drawn as a single mesh in one draw call, no matter its
+/// size, and horizontal runs of cells with the same [`Color`] are merged
+/// together. Neighboring cells share their edges exactly, so fractional cell
+/// sizes do not leave any gaps between them.
+///
+/// The mesh is cached and only built again when the cells, the colors, or
+/// the size of the [`Matrix`] change.
+///
+/// [`monochrome`]: Matrix::monochrome
+#[derive(Debug)]
+pub struct Matrix<'a> {
+    cells: Cells<'a>,
+    width: usize,
+    cell_size: f32,
+    quiet_zone: u16,
+    background: Color,
+}
+
+#[derive(Debug)]
+enum Cells<'a> {
+    Monochrome { cells: &'a [bool], color: Color },
+    Colors(&'a [Color]),
+}
+
+impl<'a> Matrix<'a> {
+    /// Creates a new monochrome [`Matrix`] with the given width, in cells,
+    /// and the given cells.
+    ///
+    /// The cells that are on are black by default, while the ones that are
+    /// off show the background of the [`Matrix`].
+    pub fn monochrome(width: usize, cells: &'a [bool]) -> Self {
+        Self::with_cells(
+            width,
+            Cells::Monochrome {
+                cells,
+                color: Color::BLACK,
+            },
+        )
+    }
+
+    /// Creates a new [`Matrix`] with the given width, in cells, and the
+    /// [`Color`] of every cell.
+    pub fn colors(width: usize, cells: &'a [Color]) -> Self {
+        Self::with_cells(width, Cells::Colors(cells))
+    }
+
+    fn with_cells(width: usize, cells: Cells<'a>) -> Self {
+        Self {
+            cells,
+            width,
+            cell_size: DEFAULT_CELL_SIZE,
+            quiet_zone: 0,
+            background: Color::TRANSPARENT,
+        }
+    }
+
+    /// Sets the [`Color`] of the cells of a monochrome [`Matrix`] that are
+    /// on.
+    ///
+    /// It has no effect on a [`Matrix`] with a [`Color`] per cell.
+    pub fn color(mut self, color: Color) -> Self {
+        if let Cells::Monochrome { color: current, .. } = &mut self.cells {
+            *current = color;
+        }
+
+        self
+    }
+
+    /// Sets the background [`Color`] of the [`Matrix`].
+    ///
+    /// The background fills the quiet zone and shows through the cells that
+    /// are off or transparent. It is transparent by default.
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Sets the size of the cells of the [`Matrix`].
+    ///
+    /// It can be fractional.
+    pub fn cell_size(mut self, cell_size: f32) -> Self {
+        self.cell_size = cell_size.max(0.0);
+        self
+    }
+
+    /// Sets the width, in cells, of the empty margin around the grid of the
+    /// [`Matrix`].
+    pub fn quiet_zone(mut self, quiet_zone: u16) -> Self {
+        self.quiet_zone = quiet_zone;
+        self
+    }
+
+    /// Returns the amount of cells of the [`Matrix`].
+    fn len(&self) -> usize {
+        match self.cells {
+            Cells::Monochrome { cells, .. } => cells.len(),
+            Cells::Colors(cells) => cells.len(),
+        }
+    }
+
+    /// Returns the amount of rows of the [`Matrix`].
+    fn rows(&self) -> usize {
+        if self.width == 0 {
+            0
+        } else {
+            (self.len() + self.width - 1) / self.width
+        }
+    }
+
+    /// Returns the size of the [`Matrix`], including its quiet zone.
+    fn size(&self) -> Size {
+        let margin = 2 * usize::from(self.quiet_zone);
+
+        Size::new(
+            (self.width + margin) as f32 * self.cell_size,
+            (self.rows() + margin) as f32 * self.cell_size,
+        )
+    }
+
+    /// Returns a hash of everything that changes the mesh of the [`Matrix`].
+    fn key(&self) -> u64 {
+        fn hash_color(color: Color, state: &mut impl Hasher) {
+            color.into_linear().map(f32::to_bits).hash(state);
+        }
+
+        let mut hasher = DefaultHasher::new();
+
+        self.width.hash(&mut hasher);
+        self.cell_size.to_bits().hash(&mut hasher);
+        self.quiet_zone.hash(&mut hasher);
+        hash_color(self.background, &mut hasher);
+
+        match self.cells {
+            Cells::Monochrome { cells, color } => {
+                cells.hash(&mut hasher);
+                hash_color(color, &mut hasher);
+            }
+            Cells::Colors(cells) => {
+                for color in cells {
+                    hash_color(*color, &mut hasher);
+                }
+            }
+        }
+
+        hasher.finish()
+    }
+
+    /// Builds the mesh of the [`Matrix`].
+    fn mesh(&self) -> triangle::Mesh2D<ColoredVertex2D> {
+        let mut mesh = triangle::Mesh2D {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        let size = self.size();
+
+        push_rectangle(
+            &mut mesh,
+            [0.0, 0.0],
+            [size.width, size.height],
+            self.background,
+        );
+
+        if self.width == 0 {
+            return mesh;
+        }
+
+        // Every edge is computed from its index alone, so the edges of
+        // neighboring cells match exactly
+        let quiet_zone = usize::from(self.quiet_zone);
+        let edge = |index: usize| (index + quiet_zone) as f32 * self.cell_size;
+
+        let color = |index: usize| match self.cells {
+            Cells::Monochrome { cells, color } => cells[index].then_some(color),
+            Cells::Colors(cells) => {
+                Some(cells[index]).filter(|color| color.a > 0.0)
+            }
+        };
+
+        let len = self.len();
+
+        for row in 0..self.rows() {
+            let start = row * self.width;
+            let end = (start + self.width).min(len);
+
+            let mut column = 0;
+
+            while start + column < end {
+                let run_color = color(start + column);
+                let run_start = column;
+
+                while start + column < end && color(start + column) == run_color
+                {
+                    column += 1;
+                }
+
+                if let Some(run_color) = run_color {
+                    push_rectangle(
+                        &mut mesh,
+                        [edge(run_start), edge(row)],
+                        [edge(column), edge(row + 1)],
+                        run_color,
+                    );
+                }
+            }
+        }
+
+        mesh
+    }
+}
+
+/// Pushes a rectangle with the given corners and [`Color`] to the mesh.
+fn push_rectangle(
+    mesh: &mut triangle::Mesh2D<ColoredVertex2D>,
+    top_left: [f32; 2],
+    bottom_right: [f32; 2],
+    color: Color,
+) {
+    if color.a <= 0.0 {
+        return;
+    }
+
+    let color = color.into_linear();
+    let base = mesh.vertices.len() as u32;
+
+    mesh.vertices.extend(
+        [
+            top_left,
+            [bottom_right[0], top_left[1]],
+            bottom_right,
+            [top_left[0], bottom_right[1]],
+        ]
+        .into_iter()
+        .map(|position| ColoredVertex2D { position, color }),
+    );
+
+    mesh.indices
+        .extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+}
+
+/// The state of a [`Matrix`].
+#[derive(Debug, Default)]
+struct State {
+    cache: canvas::Cache,
+    key: Cell<Option<u64>>,
+}
+
+impl<'a, Message, B, T> Widget<Message, Renderer<B, T>> for Matrix<'a>
+where
+    B: Backend,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer<B, T>,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(self.size())
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer<B, T>,
+        _theme: &T,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        use iced_native::Renderer as _;
+
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let key = self.key();
+
+        if state.key.replace(Some(key)) != Some(key) {
+            state.cache.clear();
+        }
+
+        let geometry = state.cache.draw(bounds.size(), |frame| {
+            let buffers = self.mesh();
+
+            if !buffers.indices.is_empty() {
+                frame.draw_primitive(Primitive::SolidMesh {
+                    buffers,
+                    size: bounds.size(),
+                });
+            }
+        });
+
+        let translation = Vector::new(bounds.x, bounds.y);
+
+        renderer.with_translation(translation, |renderer| {
+            renderer.draw_primitive(geometry.into_primitive());
+        });
+    }
+}
+
+impl<'a, Message, B, T> From<Matrix<'a>>
+    for Element<'a, Message, Renderer<B, T>>
+where
+    B: Backend,
+{
+    fn from(matrix: Matrix<'a>) -> Self {
+        Self::new(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rectangles(mesh: &triangle::Mesh2D<ColoredVertex2D>) -> Vec<[f32; 4]> {
+        mesh.vertices
+            .chunks(4)
+            .map(|corners| {
+                let [left, top] = corners[0].position;
+                let [right, bottom] = corners[2].position;
+
+                [left, top, right, bottom]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn runs_of_cells_are_merged() {
+        let cells = [true, true, false, true, false, false];
+        let matrix = Matrix::monochrome(3, &cells).cell_size(2.0);
+
+        assert_eq!(matrix.size(), Size::new(6.0, 4.0));
+        assert_eq!(
+            rectangles(&matrix.mesh()),
+            vec![[0.0, 0.0, 4.0, 2.0], [0.0, 2.0, 2.0, 4.0]]
+        );
+    }
+
+    #[test]
+    fn quiet_zone_surrounds_the_cells() {
+        let cells = [Color::BLACK, Color::TRANSPARENT, Color::WHITE];
+        let matrix = Matrix::colors(3, &cells)
+            .cell_size(1.5)
+            .quiet_zone(2)
+            .background(Color::WHITE);
+
+        assert_eq!(matrix.size(), Size::new(10.5, 7.5));
+        assert_eq!(
+            rectangles(&matrix.mesh()),
+            vec![
+                [0.0, 0.0, 10.5, 7.5],
+                [3.0, 3.0, 4.5, 4.5],
+                [6.0, 3.0, 7.5, 4.5],
+            ]
+        );
+    }
+
+    #[test]
+    fn key_changes_with_the_data() {
+        let cells = [true, false, true, false];
+        let other_cells = [true, true, true, false];
+
+        let key = Matrix::monochrome(2, &cells).key();
+
+        assert_eq!(Matrix::monochrome(2, &cells).key(), key);
+        assert_ne!(Matrix::monochrome(2, &other_cells).key(), key);
+        assert_ne!(Matrix::monochrome(4, &cells).key(), key);
+        assert_ne!(
+            Matrix::monochrome(2, &cells).color(Color::WHITE).key(),
+            key
+        );
+    }
+
+    #[test]
+    fn fractional_cells_share_their_edges() {
+        let cells = [Color::BLACK, Color::WHITE, Color::BLACK];
+        let matrix = Matrix::colors(3, &cells).cell_size(1.0 / 3.0);
+
+        let rectangles = rectangles(&matrix.mesh());
+
+        for pair in rectangles.windows(2) {
+            assert_eq!(pair[0][2], pair[1][0]);
+        }
+    }
+}
@@ -78,7 +78,7 @@ where
         _theme: &T,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         use iced_native::Renderer as _;
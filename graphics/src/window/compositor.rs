@@ -1,7 +1,10 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
+use crate::image::Notify;
 use crate::{Color, Error, Viewport};
 
+use iced_native::profile;
+
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 use thiserror::Error;
 
@@ -55,6 +58,31 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Counts the primitives and meshes recorded by the [`Renderer`].
+    ///
+    /// This is only called when profiling is enabled. By default, it returns
+    /// an empty [`profile::Count`].
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn count(&self, _renderer: &mut Self::Renderer) -> profile::Count {
+        profile::Count::default()
+    }
+
+    /// Sets the [`Notify`] called every time the [`Renderer`] finishes
+    /// decoding an image in the background, so the event loop can be woken
+    /// up.
+    ///
+    /// Until it is set, images are decoded right away when they are loaded.
+    /// By default, it does nothing.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn notify_on_decode(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _notify: Notify,
+    ) {
+    }
 }
 
 /// Result of an unsuccessful call to [`Compositor::present`].
@@ -1,8 +1,11 @@
 //! A compositor is responsible for initializing a renderer and managing window
 //! surfaces.
 use crate::compositor::Information;
+use crate::image::Notify;
 use crate::{Color, Error, Size, Viewport};
 
+use iced_native::profile;
+
 use core::ffi::c_void;
 
 /// A basic OpenGL compositor.
@@ -68,4 +71,29 @@ pub trait GLCompositor: Sized {
         background_color: Color,
         overlay: &[T],
     );
+
+    /// Counts the primitives and meshes recorded by the [`Renderer`].
+    ///
+    /// This is only called when profiling is enabled. By default, it returns
+    /// an empty [`profile::Count`].
+    ///
+    /// [`Renderer`]: crate::Renderer
+    fn count(&self, _renderer: &mut Self::Renderer) -> profile::Count {
+        profile::Count::default()
+    }
+
+    /// Sets the [`Notify`] called every time the [`Renderer`] finishes
+    /// decoding an image in the background, so the event loop can be woken
+    /// up.
+    ///
+    /// Until it is set, images are decoded right away when they are loaded.
+    /// By default, it does nothing.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    fn notify_on_decode(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _notify: Notify,
+    ) {
+    }
 }
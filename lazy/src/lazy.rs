@@ -20,6 +20,7 @@ pub struct Lazy<'a, Message, Renderer, Dependency, View> {
     element: RefCell<
         Option<Rc<RefCell<Option<Element<'static, Message, Renderer>>>>>,
     >,
+    layout: RefCell<Option<layout::Cache>>,
 }
 
 impl<'a, Message, Renderer, Dependency, View>
@@ -36,6 +37,7 @@ where
             dependency,
             view: Box::new(view),
             element: RefCell::new(None),
+            layout: RefCell::new(None),
         }
     }
 
@@ -100,7 +102,11 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
-        self.with_element(|element| vec![Tree::new(element.as_widget())])
+        let child = self.with_element(|element| Tree::new(element.as_widget()));
+
+        (*self.layout.borrow_mut()) = Some(child.layout.clone());
+
+        vec![child]
     }
 
     fn diff(&self, tree: &mut Tree) {
@@ -123,6 +129,10 @@ where
         } else {
             (*self.element.borrow_mut()) = Some(current.element.clone());
         }
+
+        // The subtree is only diffed when the dependency changes, so the
+        // layout cache of its tree stays valid otherwise
+        (*self.layout.borrow_mut()) = Some(tree.children[0].layout.clone());
     }
 
     fn width(&self) -> Length {
@@ -138,9 +148,18 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.with_element(|element| {
-            element.as_widget().layout(renderer, limits)
-        })
+        let compute = || {
+            self.with_element(|element| {
+                element.as_widget().layout(renderer, limits)
+            })
+        };
+
+        // The subtree only changes when the dependency does, so its layout
+        // can be reused as long as the limits stay the same.
+        match self.layout.borrow().as_ref() {
+            Some(cache) => cache.layout(limits, compute),
+            None => compute(),
+        }
     }
 
     fn operate(
@@ -165,29 +184,37 @@ where
         tree: &mut Tree,
         event: iced_native::Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.with_element_mut(|element| {
+        let status = self.with_element_mut(|element| {
             element.as_widget_mut().on_event(
                 &mut tree.children[0],
                 event,
                 layout,
-                cursor_position,
+                cursor,
                 renderer,
                 clipboard,
                 shell,
             )
-        })
+        });
+
+        // Stateful widgets in the subtree (e.g. components) may change their
+        // layout without the dependency changing.
+        if shell.is_layout_invalid() {
+            tree.children[0].invalidate_layout();
+        }
+
+        status
     }
 
     fn mouse_interaction(
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
@@ -195,7 +222,7 @@ where
             element.as_widget().mouse_interaction(
                 &tree.children[0],
                 layout,
-                cursor_position,
+                cursor,
                 viewport,
                 renderer,
             )
@@ -209,7 +236,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         self.with_element(|element| {
@@ -219,7 +246,7 @@ where
                 theme,
                 style,
                 layout,
-                cursor_position,
+                cursor,
                 viewport,
             )
         })
@@ -326,27 +353,22 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) {
         let _ = self.with_overlay_maybe(|overlay| {
-            overlay.draw(renderer, theme, style, layout, cursor_position);
+            overlay.draw(renderer, theme, style, layout, cursor);
         });
     }
 
     fn mouse_interaction(
         &self,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
         self.with_overlay_maybe(|overlay| {
-            overlay.mouse_interaction(
-                layout,
-                cursor_position,
-                viewport,
-                renderer,
-            )
+            overlay.mouse_interaction(layout, cursor, viewport, renderer)
         })
         .unwrap_or_default()
     }
@@ -355,20 +377,13 @@ where
         &mut self,
         event: iced_native::Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         self.with_overlay_mut_maybe(|overlay| {
-            overlay.on_event(
-                event,
-                layout,
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
-            )
+            overlay.on_event(event, layout, cursor, renderer, clipboard, shell)
         })
         .unwrap_or(iced_native::event::Status::Ignored)
     }
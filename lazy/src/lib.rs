@@ -64,3 +64,17 @@ where
 {
     Responsive::new(f)
 }
+
+/// Creates a new [`Responsive`] widget with a closure that produces its
+/// contents given its current [`Size`], the active theme, and the `Renderer`.
+///
+/// See [`Responsive::with_theme`] for the details.
+pub fn responsive_with<'a, Message, Renderer>(
+    f: impl Fn(Size, &Renderer::Theme, &Renderer) -> Element<'a, Message, Renderer>
+        + 'a,
+) -> Responsive<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    Responsive::with_theme(f)
+}
@@ -18,12 +18,66 @@ use std::ops::Deref;
 ///
 /// A [`Responsive`] widget will always try to fill all the available space of
 /// its parent.
+///
+/// The widget state of the contents is kept every time they are rebuilt, as
+/// long as the new contents have the same structure. When the contents change
+/// structure—e.g. a [`Row`] turning into a [`Column`] past some width—the
+/// state of the widgets that moved is reset.
+///
+/// [`Row`]: iced_native::widget::Row
+/// [`Column`]: iced_native::widget::Column
 #[allow(missing_debug_implementations)]
-pub struct Responsive<'a, Message, Renderer> {
-    view: Box<dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a>,
+pub struct Responsive<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    view: View<'a, Message, Renderer>,
     content: RefCell<Content<'a, Message, Renderer>>,
 }
 
+/// The closure producing the contents of a [`Responsive`] widget.
+enum View<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Produces the contents given their size.
+    Size(Box<SizeView<'a, Message, Renderer>>),
+
+    /// Produces the contents given their size, the active theme, and the
+    /// renderer.
+    Theme(Box<ThemeView<'a, Message, Renderer>>),
+}
+
+type SizeView<'a, Message, Renderer> =
+    dyn Fn(Size) -> Element<'a, Message, Renderer> + 'a;
+
+type ThemeView<'a, Message, Renderer> = dyn Fn(
+        Size,
+        &<Renderer as iced_native::Renderer>::Theme,
+        &Renderer,
+    ) -> Element<'a, Message, Renderer>
+    + 'a;
+
+impl<'a, Message, Renderer> View<'a, Message, Renderer>
+where
+    Renderer: iced_native::Renderer,
+{
+    /// Produces the contents with the given [`Size`] and theme.
+    ///
+    /// Returns `None` if the contents need a theme and it is not known yet.
+    fn build(
+        &self,
+        size: Size,
+        theme: Option<&Renderer::Theme>,
+        renderer: &Renderer,
+    ) -> Option<Element<'a, Message, Renderer>> {
+        match self {
+            View::Size(view) => Some(view(size)),
+            View::Theme(view) => theme.map(|theme| view(size, theme, renderer)),
+        }
+    }
+}
+
 impl<'a, Message, Renderer> Responsive<'a, Message, Renderer>
 where
     Renderer: iced_native::Renderer,
@@ -37,10 +91,38 @@ where
     pub fn new(
         view: impl Fn(Size) -> Element<'a, Message, Renderer> + 'a,
     ) -> Self {
+        Self::with_view(View::Size(Box::new(view)))
+    }
+
+    /// Creates a new [`Responsive`] widget with a closure that produces its
+    /// contents given its current [`Size`], the active theme, and the
+    /// `Renderer`—which can be used to measure text, for instance.
+    ///
+    /// The `view` closure runs again whenever the [`Size`] or the theme
+    /// change.
+    ///
+    /// The active theme is only known once the [`Responsive`] widget is
+    /// drawn, so its contents are built when it is first drawn. Until then,
+    /// the widget is empty: it ignores any events and operations. Theme
+    /// changes are noticed when drawing as well, so the contents are rebuilt
+    /// at that point and the next events already see the new contents.
+    pub fn with_theme(
+        view: impl Fn(
+                Size,
+                &Renderer::Theme,
+                &Renderer,
+            ) -> Element<'a, Message, Renderer>
+            + 'a,
+    ) -> Self {
+        Self::with_view(View::Theme(Box::new(view)))
+    }
+
+    fn with_view(view: View<'a, Message, Renderer>) -> Self {
         Self {
-            view: Box::new(view),
+            view,
             content: RefCell::new(Content {
                 size: Size::ZERO,
+                is_outdated: true,
                 layout: layout::Node::new(Size::ZERO),
                 element: Element::new(horizontal_space(Length::Units(0))),
             }),
@@ -50,6 +132,7 @@ where
 
 struct Content<'a, Message, Renderer> {
     size: Size,
+    is_outdated: bool,
     layout: layout::Node,
     element: Element<'a, Message, Renderer>,
 }
@@ -58,19 +141,25 @@ impl<'a, Message, Renderer> Content<'a, Message, Renderer>
 where
     Renderer: iced_native::Renderer,
 {
+    /// Rebuilds the contents if needed, returning whether they are built.
     fn update(
         &mut self,
         tree: &mut Tree,
         renderer: &Renderer,
         new_size: Size,
-        view: &dyn Fn(Size) -> Element<'a, Message, Renderer>,
-    ) {
-        if self.size == new_size {
-            return;
+        theme: Option<&Renderer::Theme>,
+        view: &View<'a, Message, Renderer>,
+    ) -> bool {
+        if self.size == new_size && !self.is_outdated {
+            return true;
         }
 
-        self.element = view(new_size);
+        self.element = match view.build(new_size, theme, renderer) {
+            Some(element) => element,
+            None => return false,
+        };
         self.size = new_size;
+        self.is_outdated = false;
 
         tree.diff(&self.element);
 
@@ -78,6 +167,8 @@ where
             .element
             .as_widget()
             .layout(renderer, &layout::Limits::new(Size::ZERO, self.size));
+
+        true
     }
 
     fn resolve<R, T>(
@@ -85,44 +176,58 @@ where
         tree: &mut Tree,
         renderer: R,
         layout: Layout<'_>,
-        view: &dyn Fn(Size) -> Element<'a, Message, Renderer>,
+        theme: Option<&Renderer::Theme>,
+        view: &View<'a, Message, Renderer>,
         f: impl FnOnce(
             &mut Tree,
             R,
             Layout<'_>,
             &mut Element<'a, Message, Renderer>,
         ) -> T,
-    ) -> T
+    ) -> Option<T>
     where
         R: Deref<Target = Renderer>,
     {
-        self.update(tree, renderer.deref(), layout.bounds().size(), view);
+        let is_built = self.update(
+            tree,
+            renderer.deref(),
+            layout.bounds().size(),
+            theme,
+            view,
+        );
+
+        if !is_built {
+            return None;
+        }
 
         let content_layout = Layout::with_offset(
             layout.position() - Point::ORIGIN,
             &self.layout,
         );
 
-        f(tree, renderer, content_layout, &mut self.element)
+        Some(f(tree, renderer, content_layout, &mut self.element))
     }
 }
 
-struct State {
+struct State<Theme> {
     tree: RefCell<Tree>,
+    theme: RefCell<Option<Theme>>,
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Responsive<'a, Message, Renderer>
 where
     Renderer: iced_native::Renderer,
+    Renderer::Theme: Clone + PartialEq + 'static,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State>()
+        tree::Tag::of::<State<Renderer::Theme>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State {
+        tree::State::new(State::<Renderer::Theme> {
             tree: RefCell::new(Tree::empty()),
+            theme: RefCell::new(None),
         })
     }
 
@@ -149,13 +254,14 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation<Message>,
     ) {
-        let state = tree.state.downcast_mut::<State>();
+        let state = tree.state.downcast_mut::<State<Renderer::Theme>>();
         let mut content = self.content.borrow_mut();
 
-        content.resolve(
+        let _ = content.resolve(
             &mut state.tree.borrow_mut(),
             renderer,
             layout,
+            state.theme.borrow().as_ref(),
             &self.view,
             |tree, renderer, layout, element| {
                 element
@@ -170,31 +276,28 @@ where
         tree: &mut Tree,
         event: iced_native::Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        let state = tree.state.downcast_mut::<State>();
+        let state = tree.state.downcast_mut::<State<Renderer::Theme>>();
         let mut content = self.content.borrow_mut();
 
-        content.resolve(
-            &mut state.tree.borrow_mut(),
-            renderer,
-            layout,
-            &self.view,
-            |tree, renderer, layout, element| {
-                element.as_widget_mut().on_event(
-                    tree,
-                    event,
-                    layout,
-                    cursor_position,
-                    renderer,
-                    clipboard,
-                    shell,
-                )
-            },
-        )
+        content
+            .resolve(
+                &mut state.tree.borrow_mut(),
+                renderer,
+                layout,
+                state.theme.borrow().as_ref(),
+                &self.view,
+                |tree, renderer, layout, element| {
+                    element.as_widget_mut().on_event(
+                        tree, event, layout, cursor, renderer, clipboard, shell,
+                    )
+                },
+            )
+            .unwrap_or(event::Status::Ignored)
     }
 
     fn draw(
@@ -204,57 +307,60 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_ref::<State<Renderer::Theme>>();
         let mut content = self.content.borrow_mut();
 
-        content.resolve(
+        if let View::Theme(_) = self.view {
+            let mut last_theme = state.theme.borrow_mut();
+
+            if last_theme.as_ref() != Some(theme) {
+                *last_theme = Some(theme.clone());
+                content.is_outdated = true;
+            }
+        }
+
+        let _ = content.resolve(
             &mut state.tree.borrow_mut(),
             renderer,
             layout,
+            state.theme.borrow().as_ref(),
             &self.view,
             |tree, renderer, layout, element| {
                 element.as_widget().draw(
-                    tree,
-                    renderer,
-                    theme,
-                    style,
-                    layout,
-                    cursor_position,
-                    viewport,
+                    tree, renderer, theme, style, layout, cursor, viewport,
                 )
             },
-        )
+        );
     }
 
     fn mouse_interaction(
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_ref::<State<Renderer::Theme>>();
         let mut content = self.content.borrow_mut();
 
-        content.resolve(
-            &mut state.tree.borrow_mut(),
-            renderer,
-            layout,
-            &self.view,
-            |tree, renderer, layout, element| {
-                element.as_widget().mouse_interaction(
-                    tree,
-                    layout,
-                    cursor_position,
-                    viewport,
-                    renderer,
-                )
-            },
-        )
+        content
+            .resolve(
+                &mut state.tree.borrow_mut(),
+                renderer,
+                layout,
+                state.theme.borrow().as_ref(),
+                &self.view,
+                |tree, renderer, layout, element| {
+                    element.as_widget().mouse_interaction(
+                        tree, layout, cursor, viewport, renderer,
+                    )
+                },
+            )
+            .unwrap_or_default()
     }
 
     fn overlay<'b>(
@@ -265,20 +371,26 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         use std::ops::DerefMut;
 
-        let state = tree.state.downcast_ref::<State>();
+        let state = tree.state.downcast_ref::<State<Renderer::Theme>>();
+        let theme = state.theme.borrow();
 
         let overlay = OverlayBuilder {
             content: self.content.borrow_mut(),
             tree: state.tree.borrow_mut(),
             types: PhantomData,
             overlay_builder: |content: &mut RefMut<Content<_, _>>, tree| {
-                content.update(
+                let is_built = content.update(
                     tree,
                     renderer,
                     layout.bounds().size(),
+                    theme.as_ref(),
                     &self.view,
                 );
 
+                if !is_built {
+                    return None;
+                }
+
                 let Content {
                     element,
                     layout: content_layout,
@@ -310,6 +422,7 @@ impl<'a, Message, Renderer> From<Responsive<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: iced_native::Renderer + 'a,
+    Renderer::Theme: Clone + PartialEq + 'static,
     Message: 'a,
 {
     fn from(responsive: Responsive<'a, Message, Renderer>) -> Self {
@@ -369,27 +482,22 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) {
         let _ = self.with_overlay_maybe(|overlay| {
-            overlay.draw(renderer, theme, style, layout, cursor_position);
+            overlay.draw(renderer, theme, style, layout, cursor);
         });
     }
 
     fn mouse_interaction(
         &self,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
         self.with_overlay_maybe(|overlay| {
-            overlay.mouse_interaction(
-                layout,
-                cursor_position,
-                viewport,
-                renderer,
-            )
+            overlay.mouse_interaction(layout, cursor, viewport, renderer)
         })
         .unwrap_or_default()
     }
@@ -398,20 +506,13 @@ where
         &mut self,
         event: iced_native::Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         self.with_overlay_mut_maybe(|overlay| {
-            overlay.on_event(
-                event,
-                layout,
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
-            )
+            overlay.on_event(event, layout, cursor, renderer, clipboard, shell)
         })
         .unwrap_or(iced_native::event::Status::Ignored)
     }
@@ -423,3 +524,129 @@ where
         .unwrap_or_default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::renderer::Null;
+    use iced_native::widget::{Container, Space};
+    use iced_native::{Event, Theme};
+
+    use std::rc::Rc;
+
+    fn draw(
+        element: &Element<'_, &'static str, Null>,
+        tree: &Tree,
+        node: &layout::Node,
+        theme: &Theme,
+    ) {
+        element.as_widget().draw(
+            tree,
+            &mut Null::new(),
+            theme,
+            &renderer::Style::default(),
+            Layout::new(node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(node.size()),
+        );
+    }
+
+    fn click(
+        element: &mut Element<'_, &'static str, Null>,
+        tree: &mut Tree,
+        node: &layout::Node,
+    ) -> Vec<&'static str> {
+        let mut messages = Vec::new();
+
+        for event in [
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = element.as_widget_mut().on_event(
+                tree,
+                Event::Mouse(event),
+                Layout::new(node),
+                mouse::Cursor::Available(Point::new(10.0, 10.0)),
+                &Null::new(),
+                &mut iced_native::clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn builds_the_contents_with_the_drawn_theme() {
+        let themes = Rc::new(RefCell::new(Vec::new()));
+
+        let element: Element<'_, &'static str, Null> = {
+            let themes = themes.clone();
+
+            Responsive::with_theme(move |_size, theme: &Theme, _renderer| {
+                themes.borrow_mut().push(theme.clone());
+
+                Space::new(Length::Fill, Length::Fill).into()
+            })
+            .into()
+        };
+
+        let tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        for theme in [Theme::Dark, Theme::Dark, Theme::Light] {
+            draw(&element, &tree, &node, &theme);
+        }
+
+        // Redrawing with the same theme keeps the contents
+        assert_eq!(*themes.borrow(), [Theme::Dark, Theme::Light]);
+    }
+
+    #[test]
+    fn ignores_events_until_the_theme_is_known() {
+        let mut element: Element<'_, &'static str, Null> =
+            Responsive::with_theme(|_size, _theme: &Theme, _renderer| {
+                let content = Space::new(Length::Units(50), Length::Units(50));
+
+                Container::new(content).on_press("pressed").into()
+            })
+            .into();
+
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        assert!(click(&mut element, &mut tree, &node).is_empty());
+
+        draw(&element, &tree, &node, &Theme::Dark);
+
+        assert_eq!(click(&mut element, &mut tree, &node), ["pressed"]);
+    }
+
+    #[test]
+    fn keeps_handling_events_without_a_theme() {
+        let mut element: Element<'_, &'static str, Null> =
+            Responsive::new(|_size| {
+                let content = Space::new(Length::Units(50), Length::Units(50));
+
+                Container::new(content).on_press("pressed").into()
+            })
+            .into();
+
+        let mut tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        assert_eq!(click(&mut element, &mut tree, &node), ["pressed"]);
+    }
+}
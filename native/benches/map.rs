@@ -0,0 +1,84 @@
+//! Compares deep widget trees where every level is mapped—once, or through a
+//! chain of nested maps—with the same trees left unmapped.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use iced_native::clipboard;
+use iced_native::event::Event;
+use iced_native::mouse;
+use iced_native::renderer;
+use iced_native::user_interface::{self, UserInterface};
+use iced_native::widget::{button, column, text};
+use iced_native::{Element, Point, Size};
+
+type Renderer = renderer::Null;
+
+const DEPTH: u32 = 100;
+
+fn view(depth: u32, maps: usize) -> Element<'static, u32, Renderer> {
+    let leaf = button(text(depth)).on_press(depth).into();
+
+    if depth == 0 {
+        return leaf;
+    }
+
+    let level: Element<'static, u32, Renderer> =
+        column(vec![leaf, view(depth - 1, maps)]).into();
+
+    (0..maps).fold(level, |level, _| level.map(|message| message + 1))
+}
+
+fn update(c: &mut Criterion, name: &str, maps: usize) {
+    let mut renderer = Renderer::new();
+    let bounds = Size::new(1024.0, 768.0);
+    let cursor_position = Point::new(5.0, 5.0);
+
+    let events = [
+        Event::Mouse(mouse::Event::CursorMoved {
+            position: cursor_position,
+        }),
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+    ];
+
+    let mut cache = Some(user_interface::Cache::new());
+
+    let _ = c.bench_function(name, |b| {
+        b.iter(|| {
+            let mut user_interface = UserInterface::build(
+                view(DEPTH, maps),
+                bounds,
+                cache.take().unwrap(),
+                &mut renderer,
+            );
+
+            let mut messages = Vec::new();
+
+            let _ = user_interface.update(
+                &events,
+                mouse::Cursor::Available(cursor_position),
+                &mut renderer,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+
+            cache = Some(user_interface.into_cache());
+
+            black_box(messages)
+        })
+    });
+}
+
+fn unmapped(c: &mut Criterion) {
+    update(c, "update a deep unmapped tree", 0);
+}
+
+fn mapped(c: &mut Criterion) {
+    update(c, "update a deep mapped tree", 1);
+}
+
+fn chained(c: &mut Criterion) {
+    update(c, "update a deep tree of chained maps", 4);
+}
+
+criterion_group!(benches, unmapped, mapped, chained);
+criterion_main!(benches);
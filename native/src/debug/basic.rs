@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 use crate::time;
+use crate::Color;
 
 use std::collections::VecDeque;
 
@@ -7,6 +8,7 @@ use std::collections::VecDeque;
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
+    is_layout_explained: bool,
 
     startup_start: time::Instant,
     startup_duration: time::Duration,
@@ -40,6 +42,7 @@ impl Debug {
 
         Self {
             is_enabled: false,
+            is_layout_explained: false,
             startup_start: now,
             startup_duration: time::Duration::from_secs(0),
 
@@ -70,6 +73,20 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn toggle_layout(&mut self) {
+        self.is_layout_explained = !self.is_layout_explained;
+    }
+
+    /// Returns the [`Color`] to outline the layout of the user interface
+    /// with, if it is explained.
+    pub fn layout_explanation(&self) -> Option<Color> {
+        if self.is_layout_explained {
+            Some(Color::from_rgb(0.9, 0.1, 0.4))
+        } else {
+            None
+        }
+    }
+
     pub fn startup_started(&mut self) {
         self.startup_start = time::Instant::now();
     }
@@ -7,6 +7,10 @@ impl Debug {
         Self
     }
 
+    pub fn layout_explanation(&self) -> Option<crate::Color> {
+        None
+    }
+
     pub fn startup_started(&mut self) {}
 
     pub fn startup_finished(&mut self) {}
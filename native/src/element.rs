@@ -4,13 +4,13 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::widget;
+use crate::widget::scrollable;
 use crate::widget::tree::{self, Tree};
-use crate::{
-    Clipboard, Color, Layout, Length, Point, Rectangle, Shell, Widget,
-};
+use crate::{Clipboard, Color, Layout, Length, Rectangle, Shell, Widget};
 
 use std::any::Any;
 use std::borrow::Borrow;
+use std::marker::PhantomData;
 
 /// A generic [`Widget`].
 ///
@@ -23,7 +23,7 @@ use std::borrow::Borrow;
 /// [built-in widget]: crate::widget
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
-    widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    content: Content<'a, Message, Renderer>,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
@@ -33,18 +33,18 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
         Renderer: crate::Renderer,
     {
         Self {
-            widget: Box::new(widget),
+            content: Content::Widget(Box::new(widget)),
         }
     }
 
     /// Returns a reference to the [`Widget`] of the [`Element`],
     pub fn as_widget(&self) -> &dyn Widget<Message, Renderer> {
-        self.widget.as_ref()
+        self.content.as_widget()
     }
 
     /// Returns a mutable reference to the [`Widget`] of the [`Element`],
     pub fn as_widget_mut(&mut self) -> &mut dyn Widget<Message, Renderer> {
-        self.widget.as_mut()
+        self.content.as_widget_mut()
     }
 
     /// Applies a transformation to the produced message of the [`Element`].
@@ -181,16 +181,50 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
     ///     }
     /// }
     /// ```
+    ///
+    /// # Performance
+    /// Mapping an [`Element`] wraps its [`Widget`] in a new layer that
+    /// forwards every call to it. The closure is stored inline in the layer,
+    /// so the only allocation is the one of the layer itself.
+    ///
+    /// Layout, drawing, and mouse interaction go through an additional
+    /// virtual call. Processing an event collects the messages of the mapped
+    /// subtree in a local buffer before applying the closure, which only
+    /// allocates when messages are actually produced.
+    ///
+    /// Mapping an [`Element`] that was already mapped—whether the message
+    /// type changes or stays the same—does not stack another layer on top.
+    /// Instead, the closures are fused into a single one, so a chain of maps
+    /// of any length costs two layers: one applying the composed closure,
+    /// and one erasing the message type of the original [`Widget`]. In
+    /// exchange, every message of a fused chain is boxed once on its way up.
+    ///
+    /// Mapping each level of a deep tree still adds a layer per level, since
+    /// the maps are not nested directly. Prefer mapping a whole subtree once
+    /// at its root over mapping each of its leaves.
     pub fn map<B>(
         self,
         f: impl Fn(Message) -> B + 'a,
     ) -> Element<'a, B, Renderer>
     where
-        Message: 'a,
+        Message: 'static,
         Renderer: crate::Renderer + 'a,
         B: 'a,
     {
-        Element::new(Map::new(self.widget, f))
+        let content = match self.content {
+            Content::Widget(widget) => {
+                Content::Mapped(Box::new(Map::new(widget, f)))
+            }
+            Content::Mapped(mapped) => {
+                let (widget, mapper) = mapped.erase();
+                let mapper: Box<dyn Fn(Erased) -> B + 'a> =
+                    Box::new(move |message| f(mapper(message)));
+
+                Content::Mapped(Box::new(Fused(Map::new(widget, mapper))))
+            }
+        };
+
+        Element { content }
     }
 
     /// Marks the [`Element`] as _to-be-explained_.
@@ -198,6 +232,18 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
     /// The [`Renderer`] will explain the layout of the [`Element`] graphically.
     /// This can be very useful for debugging your layout!
     ///
+    /// The bounds of the [`Element`] and of every widget inside of it are
+    /// outlined on top of its contents. The hue of the `color` is rotated by a
+    /// third of a turn at every level of nesting, so nested outlines can be
+    /// told apart—unless the `color` is a shade of gray. The outlines are
+    /// only drawn; the layout and the events of the [`Element`] are left
+    /// untouched.
+    ///
+    /// Since the view is rebuilt after every update, the explanation can be
+    /// toggled at runtime by only calling this method when some flag of the
+    /// application state is set. Applications built with the `debug` feature
+    /// can also outline their whole layout by pressing `Shift+F12`.
+    ///
     /// [`Renderer`]: crate::Renderer
     pub fn explain<C: Into<Color>>(
         self,
@@ -207,9 +253,7 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
         Message: 'static,
         Renderer: crate::Renderer + 'a,
     {
-        Element {
-            widget: Box::new(Explain::new(self, color.into())),
-        }
+        Element::new(Explain::new(self, color.into()))
     }
 }
 
@@ -217,7 +261,7 @@ impl<'a, Message, Renderer> Borrow<dyn Widget<Message, Renderer> + 'a>
     for Element<'a, Message, Renderer>
 {
     fn borrow(&self) -> &(dyn Widget<Message, Renderer> + 'a) {
-        self.widget.borrow()
+        self.content.as_widget()
     }
 }
 
@@ -225,35 +269,142 @@ impl<'a, Message, Renderer> Borrow<dyn Widget<Message, Renderer> + 'a>
     for &Element<'a, Message, Renderer>
 {
     fn borrow(&self) -> &(dyn Widget<Message, Renderer> + 'a) {
-        self.widget.borrow()
+        self.content.as_widget()
+    }
+}
+
+/// The contents of an [`Element`], which remember whether its [`Widget`] is
+/// mapped, so that nested maps can be fused.
+enum Content<'a, Message, Renderer> {
+    Widget(Box<dyn Widget<Message, Renderer> + 'a>),
+    Mapped(Box<dyn Mapped<'a, Message, Renderer> + 'a>),
+}
+
+impl<'a, Message, Renderer> Content<'a, Message, Renderer> {
+    fn as_widget(&self) -> &(dyn Widget<Message, Renderer> + 'a) {
+        match self {
+            Content::Widget(widget) => widget.as_ref(),
+            Content::Mapped(mapped) => mapped.as_widget(),
+        }
+    }
+
+    fn as_widget_mut(&mut self) -> &mut (dyn Widget<Message, Renderer> + 'a) {
+        match self {
+            Content::Widget(widget) => widget.as_mut(),
+            Content::Mapped(mapped) => mapped.as_widget_mut(),
+        }
     }
 }
 
-struct Map<'a, A, B, Renderer> {
+/// A message whose type was erased by a fused [`Map`].
+type Erased = Box<dyn Any>;
+
+/// A [`Widget`] produced by [`Element::map`].
+trait Mapped<'a, Message, Renderer> {
+    fn as_widget(&self) -> &(dyn Widget<Message, Renderer> + 'a);
+
+    fn as_widget_mut(&mut self) -> &mut (dyn Widget<Message, Renderer> + 'a);
+
+    /// Splits the mapped [`Widget`] into one producing [`Erased`] messages
+    /// and the closure turning them into a `Message`, ready to be composed.
+    #[allow(clippy::type_complexity)]
+    fn erase(
+        self: Box<Self>,
+    ) -> (
+        Box<dyn Widget<Erased, Renderer> + 'a>,
+        Box<dyn Fn(Erased) -> Message + 'a>,
+    );
+}
+
+impl<'a, A, B, Renderer, F> Mapped<'a, B, Renderer>
+    for Map<'a, A, B, Renderer, F>
+where
+    Renderer: crate::Renderer + 'a,
+    A: 'static,
+    B: 'a,
+    F: Fn(A) -> B + 'a,
+{
+    fn as_widget(&self) -> &(dyn Widget<B, Renderer> + 'a) {
+        self
+    }
+
+    fn as_widget_mut(&mut self) -> &mut (dyn Widget<B, Renderer> + 'a) {
+        self
+    }
+
+    fn erase(
+        self: Box<Self>,
+    ) -> (
+        Box<dyn Widget<Erased, Renderer> + 'a>,
+        Box<dyn Fn(Erased) -> B + 'a>,
+    ) {
+        let Map { widget, mapper, .. } = *self;
+
+        (
+            Box::new(Map::new(widget, |message: A| -> Erased {
+                Box::new(message)
+            })),
+            Box::new(move |message: Erased| {
+                mapper(*message.downcast().expect("Restore erased message"))
+            }),
+        )
+    }
+}
+
+/// A chain of nested maps fused into a single [`Map`].
+struct Fused<'a, Message, Renderer>(
+    Map<'a, Erased, Message, Renderer, Box<dyn Fn(Erased) -> Message + 'a>>,
+);
+
+impl<'a, Message, Renderer> Mapped<'a, Message, Renderer>
+    for Fused<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer + 'a,
+    Message: 'a,
+{
+    fn as_widget(&self) -> &(dyn Widget<Message, Renderer> + 'a) {
+        &self.0
+    }
+
+    fn as_widget_mut(&mut self) -> &mut (dyn Widget<Message, Renderer> + 'a) {
+        &mut self.0
+    }
+
+    fn erase(
+        self: Box<Self>,
+    ) -> (
+        Box<dyn Widget<Erased, Renderer> + 'a>,
+        Box<dyn Fn(Erased) -> Message + 'a>,
+    ) {
+        (self.0.widget, self.0.mapper)
+    }
+}
+
+struct Map<'a, A, B, Renderer, F> {
     widget: Box<dyn Widget<A, Renderer> + 'a>,
-    mapper: Box<dyn Fn(A) -> B + 'a>,
+    mapper: F,
+    message: PhantomData<fn(A) -> B>,
 }
 
-impl<'a, A, B, Renderer> Map<'a, A, B, Renderer> {
-    pub fn new<F>(
-        widget: Box<dyn Widget<A, Renderer> + 'a>,
-        mapper: F,
-    ) -> Map<'a, A, B, Renderer>
-    where
-        F: 'a + Fn(A) -> B,
-    {
+impl<'a, A, B, Renderer, F> Map<'a, A, B, Renderer, F>
+where
+    F: Fn(A) -> B,
+{
+    pub fn new(widget: Box<dyn Widget<A, Renderer> + 'a>, mapper: F) -> Self {
         Map {
             widget,
-            mapper: Box::new(mapper),
+            mapper,
+            message: PhantomData,
         }
     }
 }
 
-impl<'a, A, B, Renderer> Widget<B, Renderer> for Map<'a, A, B, Renderer>
+impl<'a, A, B, Renderer, F> Widget<B, Renderer> for Map<'a, A, B, Renderer, F>
 where
     Renderer: crate::Renderer + 'a,
     A: 'a,
     B: 'a,
+    F: Fn(A) -> B + 'a,
 {
     fn tag(&self) -> tree::Tag {
         self.widget.tag()
@@ -287,6 +438,14 @@ where
         self.widget.layout(renderer, limits)
     }
 
+    fn baseline(
+        &self,
+        renderer: &Renderer,
+        layout: &layout::Node,
+    ) -> Option<f32> {
+        self.widget.baseline(renderer, layout)
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -311,6 +470,10 @@ where
                 });
             }
 
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&widget::Id>) {
+                self.operation.bounds(bounds, id);
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -338,6 +501,10 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn target(&self) -> Option<&widget::Id> {
+                self.operation.target()
+            }
         }
 
         self.widget.operate(
@@ -353,19 +520,19 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, B>,
     ) -> event::Status {
         let mut local_messages = Vec::new();
-        let mut local_shell = Shell::new(&mut local_messages);
+        let mut local_shell = shell.nest(&mut local_messages);
 
         let status = self.widget.on_event(
             tree,
             event,
             layout,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             &mut local_shell,
@@ -383,35 +550,23 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        self.widget.draw(
-            tree,
-            renderer,
-            theme,
-            style,
-            layout,
-            cursor_position,
-            viewport,
-        )
+        self.widget
+            .draw(tree, renderer, theme, style, layout, cursor, viewport)
     }
 
     fn mouse_interaction(
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.widget.mouse_interaction(
-            tree,
-            layout,
-            cursor_position,
-            viewport,
-            renderer,
-        )
+        self.widget
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
     }
 
     fn overlay<'b>(
@@ -448,27 +603,27 @@ where
     Renderer: crate::Renderer,
 {
     fn width(&self) -> Length {
-        self.element.widget.width()
+        self.element.as_widget().width()
     }
 
     fn height(&self) -> Length {
-        self.element.widget.height()
+        self.element.as_widget().height()
     }
 
     fn tag(&self) -> tree::Tag {
-        self.element.widget.tag()
+        self.element.as_widget().tag()
     }
 
     fn state(&self) -> tree::State {
-        self.element.widget.state()
+        self.element.as_widget().state()
     }
 
     fn children(&self) -> Vec<Tree> {
-        self.element.widget.children()
+        self.element.as_widget().children()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        self.element.widget.diff(tree);
+        self.element.as_widget().diff(tree);
     }
 
     fn layout(
@@ -476,7 +631,15 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.element.widget.layout(renderer, limits)
+        self.element.as_widget().layout(renderer, limits)
+    }
+
+    fn baseline(
+        &self,
+        renderer: &Renderer,
+        layout: &layout::Node,
+    ) -> Option<f32> {
+        self.element.as_widget().baseline(renderer, layout)
     }
 
     fn operate(
@@ -487,7 +650,7 @@ where
         operation: &mut dyn widget::Operation<Message>,
     ) {
         self.element
-            .widget
+            .as_widget()
             .operate(state, layout, renderer, operation)
     }
 
@@ -496,20 +659,14 @@ where
         state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.element.widget.on_event(
-            state,
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        )
+        self.element
+            .as_widget_mut()
+            .on_event(state, event, layout, cursor, renderer, clipboard, shell)
     }
 
     fn draw(
@@ -519,57 +676,27 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        fn explain_layout<Renderer: crate::Renderer>(
-            renderer: &mut Renderer,
-            color: Color,
-            layout: Layout<'_>,
-        ) {
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds: layout.bounds(),
-                    border_color: color,
-                    border_width: 1.0,
-                    border_radius: 0.0.into(),
-                },
-                Color::TRANSPARENT,
-            );
-
-            for child in layout.children() {
-                explain_layout(renderer, color, child);
-            }
-        }
-
-        self.element.widget.draw(
-            state,
-            renderer,
-            theme,
-            style,
-            layout,
-            cursor_position,
-            viewport,
-        );
+        self.element
+            .as_widget()
+            .draw(state, renderer, theme, style, layout, cursor, viewport);
 
-        explain_layout(renderer, self.color, layout);
+        explain(renderer, self.color, layout, Some(state));
     }
 
     fn mouse_interaction(
         &self,
         state: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.element.widget.mouse_interaction(
-            state,
-            layout,
-            cursor_position,
-            viewport,
-            renderer,
-        )
+        self.element
+            .as_widget()
+            .mouse_interaction(state, layout, cursor, viewport, renderer)
     }
 
     fn overlay<'b>(
@@ -578,6 +705,302 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        self.element.widget.overlay(state, layout, renderer)
+        self.element
+            .as_widget_mut()
+            .overlay(state, layout, renderer)
+    }
+}
+
+/// Outlines the bounds of the given [`Layout`] and of all of its children,
+/// rotating the hue of the `color` by a third of a turn at every level.
+///
+/// The [`Tree`] of the widgets, if given, is used to mirror the translation
+/// and clipping of the contents of any [`Scrollable`] inside.
+///
+/// [`Scrollable`]: crate::widget::Scrollable
+pub(crate) fn explain<Renderer: crate::Renderer>(
+    renderer: &mut Renderer,
+    color: Color,
+    layout: Layout<'_>,
+    tree: Option<&Tree>,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: layout.bounds(),
+            border_color: color,
+            border_width: 1.0,
+            border_radius: 0.0.into(),
+        },
+        Color::TRANSPARENT,
+    );
+
+    // Permuting the channels is the same as rotating the hue by 120 degrees
+    let nested = Color {
+        r: color.b,
+        g: color.r,
+        b: color.g,
+        a: color.a,
+    };
+
+    match tree {
+        Some(tree) if tree.tag == tree::Tag::of::<scrollable::State>() => {
+            scrollable::explain(
+                tree.state.downcast_ref::<scrollable::State>(),
+                renderer,
+                layout,
+                |renderer, content| {
+                    explain(renderer, nested, content, tree.children.first());
+                },
+            );
+        }
+        _ => {
+            let children = tree.map(|tree| &tree.children[..]).unwrap_or(&[]);
+
+            for (i, child) in layout.children().enumerate() {
+                explain(renderer, nested, child, children.get(i));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Recorder;
+    use crate::widget::{Column, Space};
+    use crate::{Point, Size, Theme};
+
+    #[test]
+    fn explain_outlines_nested_layouts_without_changing_them() {
+        let column = || -> Element<'static, (), Recorder> {
+            Column::new()
+                .push(
+                    Column::new()
+                        .push(Space::new(Length::Units(10), Length::Units(20))),
+                )
+                .push(Space::new(Length::Units(30), Length::Units(10)))
+                .into()
+        };
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let mut renderer = Recorder::new();
+
+        let explained = column().explain(Color::from_rgb(1.0, 0.0, 0.0));
+        let node = explained.as_widget().layout(&renderer, &limits);
+
+        assert_eq!(
+            node.bounds(),
+            column().as_widget().layout(&renderer, &limits).bounds()
+        );
+
+        explained.as_widget().draw(
+            &Tree::new(&explained),
+            &mut renderer,
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &node.bounds(),
+        );
+
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+        let blue = Color::from_rgb(0.0, 0.0, 1.0);
+
+        assert_eq!(
+            renderer
+                .quads
+                .iter()
+                .map(|(quad, _)| (quad.bounds, quad.border_color))
+                .collect::<Vec<_>>(),
+            [
+                (Rectangle::new(Point::ORIGIN, Size::new(30.0, 30.0)), red),
+                (Rectangle::new(Point::ORIGIN, Size::new(10.0, 20.0)), green),
+                (Rectangle::new(Point::ORIGIN, Size::new(10.0, 20.0)), blue),
+                (
+                    Rectangle::new(
+                        Point::new(0.0, 20.0),
+                        Size::new(30.0, 10.0)
+                    ),
+                    green
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn explain_mirrors_the_translation_and_clip_of_scrollables() {
+        use crate::widget::operation::scrollable::{snap_to, RelativeOffset};
+        use crate::widget::scrollable::{self, Scrollable};
+
+        let id = scrollable::Id::new("scrollable");
+
+        let explained: Element<'static, (), Recorder> = Scrollable::new(
+            Column::new()
+                .push(Space::new(Length::Units(50), Length::Units(60)))
+                .push(Space::new(Length::Units(50), Length::Units(60))),
+        )
+        .id(id.clone())
+        .height(Length::Units(50))
+        .into();
+
+        let explained = explained.explain(Color::from_rgb(1.0, 0.0, 0.0));
+
+        let mut renderer = Recorder::new();
+        let mut tree = Tree::new(&explained);
+
+        let node = explained.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        explained.as_widget().operate(
+            &mut tree,
+            Layout::new(&node),
+            &renderer,
+            &mut snap_to(id.into(), RelativeOffset { x: 0.0, y: 1.0 }),
+        );
+
+        explained.as_widget().draw(
+            &tree,
+            &mut renderer,
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &node.bounds(),
+        );
+
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+        let blue = Color::from_rgb(0.0, 0.0, 1.0);
+
+        let outlines: Vec<_> = renderer
+            .quads
+            .iter()
+            .map(|(quad, _)| (quad.bounds, quad.border_color))
+            .filter(|(_, color)| [red, green, blue].contains(color))
+            .collect();
+
+        // The contents are scrolled to their end, 70 units up
+        assert_eq!(
+            outlines,
+            [
+                (Rectangle::new(Point::ORIGIN, Size::new(50.0, 50.0)), red),
+                (
+                    Rectangle::new(
+                        Point::new(0.0, -70.0),
+                        Size::new(50.0, 120.0)
+                    ),
+                    green
+                ),
+                (
+                    Rectangle::new(
+                        Point::new(0.0, -70.0),
+                        Size::new(50.0, 60.0)
+                    ),
+                    blue
+                ),
+                (
+                    Rectangle::new(
+                        Point::new(0.0, -10.0),
+                        Size::new(50.0, 60.0)
+                    ),
+                    blue
+                ),
+            ]
+        );
+
+        // The outlines of the contents are clipped to the `Scrollable`
+        assert_eq!(
+            renderer.layers.last().map(|(bounds, _)| *bounds),
+            Some(Rectangle::new(Point::ORIGIN, Size::new(50.0, 50.0)))
+        );
+    }
+
+    /// Publishes its message on every event.
+    struct Publish(u8);
+
+    impl Widget<u8, Recorder> for Publish {
+        fn width(&self) -> Length {
+            Length::Shrink
+        }
+
+        fn height(&self) -> Length {
+            Length::Shrink
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Recorder,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::ZERO)
+        }
+
+        fn on_event(
+            &mut self,
+            _tree: &mut Tree,
+            _event: Event,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _renderer: &Recorder,
+            _clipboard: &mut dyn Clipboard,
+            shell: &mut Shell<'_, u8>,
+        ) -> event::Status {
+            shell.publish(self.0);
+
+            event::Status::Captured
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            _renderer: &mut Recorder,
+            _theme: &Theme,
+            _style: &renderer::Style,
+            _layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+        }
+    }
+
+    fn publish<Message>(
+        mut element: Element<'_, Message, Recorder>,
+    ) -> Vec<Message> {
+        let node = layout::Node::new(Size::ZERO);
+        let mut tree = Tree::new(&element);
+        let mut messages = Vec::new();
+
+        let status = element.as_widget_mut().on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::CursorEntered),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Recorder::new(),
+            &mut crate::clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        assert_eq!(status, event::Status::Captured);
+
+        messages
+    }
+
+    #[test]
+    fn nested_maps_fuse_their_closures() {
+        assert_eq!(publish(Element::new(Publish(1)).map(u16::from)), [1]);
+
+        // Both the maps that change the message type and the ones that keep
+        // it are fused
+        let fused = Element::new(Publish(1))
+            .map(u16::from)
+            .map(|n| n * 10)
+            .map(|n| n + 2)
+            .map(|n| format!("{n}!"));
+
+        assert_eq!(publish(fused), ["12!"]);
     }
 }
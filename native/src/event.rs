@@ -1,4 +1,11 @@
 //! Handle events of a user interface.
+#[cfg(feature = "debug")]
+mod trace;
+
+#[cfg(feature = "debug")]
+pub use trace::{Trace, Visit};
+
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -24,6 +31,9 @@ pub enum Event {
     /// A touch event
     Touch(touch::Event),
 
+    /// An input method event
+    InputMethod(input_method::Event),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
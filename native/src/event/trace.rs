@@ -0,0 +1,143 @@
+use crate::event::{Event, Status};
+
+/// The path followed by an [`Event`] through a widget tree.
+///
+/// A [`Trace`] records every widget that an [`Event`] was dispatched to and the
+/// [`Status`] it produced. Widgets are identified by their path in the tree:
+/// the indices of the children traversed from the root.
+///
+/// Only the children dispatched through [`Shell::traced`] are recorded, which
+/// is the case for all the built-in container widgets. Widgets handling
+/// events in their own local [`Shell`]—like a `Component`—are recorded as a
+/// single visit.
+///
+/// Tracing is only available when the `debug` feature is enabled. It can be
+/// turned on with [`UserInterface::trace_events`]. When it is not, event
+/// dispatching is left untouched.
+///
+/// [`Shell`]: crate::Shell
+/// [`Shell::traced`]: crate::Shell::traced
+///
+/// [`UserInterface::trace_events`]: crate::UserInterface::trace_events
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    event: Event,
+    overlay: Option<Status>,
+    visits: Vec<Visit>,
+    stack: Vec<usize>,
+}
+
+/// A widget visited by an [`Event`] in a [`Trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Visit {
+    /// The path of the widget, starting from the root.
+    pub path: Vec<usize>,
+
+    /// The [`Status`] produced by the widget, including its children.
+    pub status: Status,
+}
+
+impl Trace {
+    /// Creates a new, empty [`Trace`] for the given [`Event`].
+    pub(crate) fn new(event: Event) -> Self {
+        Self {
+            event,
+            overlay: None,
+            visits: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns the traced [`Event`].
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    /// Returns the [`Status`] produced by the overlay of the widget tree,
+    /// if the [`Event`] was dispatched to one.
+    pub fn overlay(&self) -> Option<Status> {
+        self.overlay
+    }
+
+    /// Returns the widgets visited by the [`Event`], in the order they were
+    /// visited—a parent always comes before its children.
+    pub fn visits(&self) -> &[Visit] {
+        &self.visits
+    }
+
+    /// Returns the paths of the widgets that captured the [`Event`].
+    ///
+    /// Since the [`Status`] of a widget includes the one of its children, only
+    /// the deepest widgets that produced [`Status::Captured`] are returned.
+    pub fn captured_by(&self) -> impl Iterator<Item = &[usize]> + '_ {
+        self.visits
+            .iter()
+            .enumerate()
+            .filter(move |(i, visit)| {
+                visit.status == Status::Captured
+                    && !self.visits[i + 1..].iter().any(|descendant| {
+                        descendant.status == Status::Captured
+                            && descendant.path.starts_with(&visit.path)
+                    })
+            })
+            .map(|(_, visit)| visit.path.as_slice())
+    }
+
+    pub(crate) fn enter_root(&mut self) {
+        self.stack.push(self.visits.len());
+        self.visits.push(Visit {
+            path: Vec::new(),
+            status: Status::Ignored,
+        });
+    }
+
+    pub(crate) fn enter(&mut self, index: usize) {
+        let parent = self.stack.last().map(|&i| &self.visits[i]);
+
+        let mut path =
+            parent.map(|parent| parent.path.clone()).unwrap_or_default();
+        path.push(index);
+
+        self.stack.push(self.visits.len());
+        self.visits.push(Visit {
+            path,
+            status: Status::Ignored,
+        });
+    }
+
+    pub(crate) fn exit(&mut self, status: Status) {
+        if let Some(i) = self.stack.pop() {
+            self.visits[i].status = status;
+        }
+    }
+
+    pub(crate) fn set_overlay(&mut self, status: Status) {
+        self.overlay = Some(status);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window;
+
+    #[test]
+    fn captured_by_returns_the_deepest_captures() {
+        let mut trace = Trace::new(Event::Window(window::Event::Focused));
+
+        trace.enter_root();
+        trace.enter(0);
+        trace.exit(Status::Ignored);
+        trace.enter(1);
+        trace.enter(0);
+        trace.exit(Status::Captured);
+        trace.exit(Status::Captured);
+        trace.exit(Status::Captured);
+
+        let paths: Vec<_> =
+            trace.visits().iter().map(|v| &v.path[..]).collect();
+
+        assert_eq!(paths, vec![&[][..], &[0], &[1], &[1, 0]]);
+        assert_eq!(trace.captured_by().collect::<Vec<_>>(), vec![&[1, 0][..]]);
+    }
+}
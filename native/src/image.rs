@@ -84,6 +84,14 @@ impl Hash for Handle {
     }
 }
 
+impl PartialEq for Handle {
+    fn eq(&self, other: &Handle) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Handle {}
+
 /// The data of a raster image.
 #[derive(Clone, Hash)]
 pub enum Data {
@@ -116,6 +124,86 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The rotation of an image, in steps of 90 degrees.
+///
+/// Only quarter turns are supported, so a rotated image always fits an
+/// axis-aligned rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    /// The image is not rotated.
+    None,
+
+    /// The image is rotated 90 degrees clockwise.
+    Clockwise,
+
+    /// The image is rotated 180 degrees.
+    UpsideDown,
+
+    /// The image is rotated 90 degrees counterclockwise.
+    CounterClockwise,
+}
+
+impl Rotation {
+    /// Returns whether the [`Rotation`] is a quarter turn, which swaps the
+    /// width and the height of an image.
+    pub fn is_quarter_turn(self) -> bool {
+        matches!(self, Rotation::Clockwise | Rotation::CounterClockwise)
+    }
+
+    /// Returns the size of an image of the given size after the
+    /// [`Rotation`].
+    pub fn apply<T>(self, size: Size<T>) -> Size<T> {
+        if self.is_quarter_turn() {
+            Size::new(size.height, size.width)
+        } else {
+            size
+        }
+    }
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Rotation::None
+    }
+}
+
+/// The mirroring of an image along its axes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Flip {
+    /// The image is not flipped.
+    None,
+
+    /// The image is mirrored horizontally—its left side becomes its right
+    /// side.
+    Horizontal,
+
+    /// The image is mirrored vertically—its top side becomes its bottom
+    /// side.
+    Vertical,
+
+    /// The image is mirrored along both axes.
+    Both,
+}
+
+impl Default for Flip {
+    fn default() -> Self {
+        Flip::None
+    }
+}
+
+/// The status of an image decoded in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The image is still being decoded.
+    Loading,
+
+    /// The image is decoded and ready to be drawn.
+    Loaded,
+
+    /// The image could not be found or decoded.
+    Failed,
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -125,10 +213,102 @@ pub trait Renderer: crate::Renderer {
     /// [`Handle`]: Self::Handle
     type Handle: Clone + Hash;
 
-    /// Returns the dimensions of an image for the given [`Handle`].
+    /// Returns the natural dimensions of an image for the given [`Handle`],
+    /// in pixels.
+    ///
+    /// It can be called from [`Widget::layout`] to size a widget after its
+    /// image—for instance, to keep its aspect ratio—before it is drawn.
+    ///
+    /// The image is decoded on demand the first time it is measured, no
+    /// matter if its [`Handle`] points to a file or to some memory, and it
+    /// is cached by the id of the [`Handle`]. Repeated queries—and the draw
+    /// calls that follow, no matter their [`Rotation`] or [`Flip`]—reuse the
+    /// decoded image. There is no pending size: the decoding is synchronous.
+    /// Images that cannot be found or decoded measure a single pixel.
+    ///
+    /// The dimensions are those of the image as it is stored, before it is
+    /// flipped or rotated.
+    ///
+    /// [`Handle`]: Self::Handle
+    /// [`Widget::layout`]: crate::Widget::layout
     fn dimensions(&self, handle: &Self::Handle) -> Size<u32>;
 
+    /// Starts decoding the image of the given [`Handle`] in the background,
+    /// unless it is already decoded or being decoded, and returns its
+    /// [`Status`].
+    ///
+    /// Decoded images share the cache of [`dimensions`], keyed by the id of
+    /// the [`Handle`]. An image must be loaded every frame while it is being
+    /// decoded, or its decoding is cancelled—for instance, when its
+    /// [`Handle`] is replaced.
+    ///
+    /// Once an image finishes decoding, the renderer wakes up the shell,
+    /// which updates the user interface with a
+    /// [`window::Event::RedrawRequested`]—so there is no need to poll. An
+    /// image is only ever [`Status::Loading`] if the renderer can wake up the
+    /// shell; otherwise, it is decoded right away.
+    ///
+    /// By default, images are decoded synchronously when they are measured,
+    /// so they are always [`Status::Loaded`].
+    ///
+    /// [`Handle`]: Self::Handle
+    /// [`dimensions`]: Self::dimensions
+    /// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+    fn load(&self, handle: &Self::Handle) -> Status {
+        let _ = handle;
+
+        Status::Loaded
+    }
+
     /// Draws an image with the given [`Handle`] and inside the provided
     /// `bounds`.
     fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+
+    /// Draws an image with the given [`Handle`] inside the provided `bounds`
+    /// after flipping it and then rotating it.
+    ///
+    /// The `bounds` are those of the rotated image—their width and height
+    /// are swapped for quarter turns.
+    fn draw_oriented(
+        &mut self,
+        handle: Self::Handle,
+        rotation: Rotation,
+        flip: Flip,
+        bounds: Rectangle,
+    );
+
+    /// Draws the given `region` of an image with the given [`Handle`] inside
+    /// the provided `bounds`.
+    ///
+    /// The `region` is given in pixels of the image, with the origin at its
+    /// top-left corner.
+    ///
+    /// By default, the whole image is drawn scaled and clipped, so only the
+    /// `region` is visible inside the `bounds`.
+    fn draw_region(
+        &mut self,
+        handle: Self::Handle,
+        region: Rectangle<u32>,
+        bounds: Rectangle,
+    ) {
+        if region.width == 0 || region.height == 0 {
+            return;
+        }
+
+        let size = self.dimensions(&handle);
+
+        let scale_x = bounds.width / region.width as f32;
+        let scale_y = bounds.height / region.height as f32;
+
+        let image_bounds = Rectangle {
+            x: bounds.x - region.x as f32 * scale_x,
+            y: bounds.y - region.y as f32 * scale_y,
+            width: size.width as f32 * scale_x,
+            height: size.height as f32 * scale_y,
+        };
+
+        self.with_layer(bounds, |renderer| {
+            renderer.draw(handle, image_bounds);
+        });
+    }
 }
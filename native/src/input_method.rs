@@ -0,0 +1,64 @@
+//! Compose text with an input method editor (IME).
+use crate::Point;
+
+use std::ops::Range;
+
+/// An input method event.
+///
+/// Input methods let users compose text that cannot be typed directly with
+/// their keyboard, like Chinese, Japanese, or Korean characters. While
+/// composing, the text is not part of the value of the widget yet: it is a
+/// _preedit_ that is shown in place until it is committed or cancelled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The input method was enabled.
+    Opened,
+
+    /// The preedit text changed.
+    ///
+    /// The range is the byte range of the cursor, or the selection, in the
+    /// preedit text, if any. An empty preedit means the composition was
+    /// cancelled.
+    Preedit(String, Option<Range<usize>>),
+
+    /// The composition was confirmed and the given text must be inserted.
+    Commit(String),
+
+    /// The input method was disabled.
+    Closed,
+}
+
+/// The input method requested by the focused widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputMethod {
+    /// The input method is disabled.
+    Disabled,
+
+    /// The input method is enabled.
+    Enabled {
+        /// The position where the candidate window should be placed, in
+        /// the coordinates of the user interface.
+        ///
+        /// It is normally the bottom left corner of the text cursor.
+        position: Point,
+    },
+}
+
+impl InputMethod {
+    /// Merges two [`InputMethod`] requests into one.
+    ///
+    /// An enabled input method takes precedence, and the first enabled
+    /// request is kept.
+    pub fn merge(self, other: Self) -> Self {
+        match self {
+            InputMethod::Disabled => other,
+            InputMethod::Enabled { .. } => self,
+        }
+    }
+}
+
+impl Default for InputMethod {
+    fn default() -> Self {
+        InputMethod::Disabled
+    }
+}
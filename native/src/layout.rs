@@ -2,8 +2,10 @@
 mod limits;
 mod node;
 
+pub mod cache;
 pub mod flex;
 
+pub use cache::Cache;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -41,7 +43,10 @@ impl<'a> Layout<'a> {
     /// Returns the bounds of the [`Layout`].
     ///
     /// The returned [`Rectangle`] describes the position and size of a
-    /// [`Node`].
+    /// [`Node`] in absolute coordinates; that is, with the positions of all
+    /// of its ancestors added up. These are the same coordinates as the
+    /// cursor position given to a widget in `on_event`, so they can be
+    /// compared directly for hit-testing.
     pub fn bounds(&self) -> Rectangle {
         let bounds = self.node.bounds();
 
@@ -0,0 +1,225 @@
+//! Reuse the layout of a widget subtree across frames.
+//!
+//! A [`Cache`] remembers the last [`Node`] computed for some [`Limits`]. As
+//! long as the subtree it belongs to does not change and it is laid out
+//! with the same [`Limits`], the previous [`Node`] is reused instead of
+//! running the layout logic again.
+//!
+//! Every [`Tree`] owns a [`Cache`] for the layout of its widget, which is
+//! invalidated whenever the [`Tree`] is diffed. [`Row`] and [`Column`] lay
+//! out their children through the caches of their trees, so subtrees that
+//! are not diffed again—like the contents of a `Lazy` widget whose
+//! dependency did not change—keep their layout when they are laid out again
+//! with the same [`Limits`], e.g. when the window is resized.
+//!
+//! Every [`Cache`] counts its own hits and misses, which can be queried with
+//! [`Cache::statistics`] or summed up for a whole [`Tree`] with
+//! [`Tree::layout_statistics`].
+//!
+//! [`Tree`]: crate::widget::Tree
+//! [`Tree::layout_statistics`]: crate::widget::Tree::layout_statistics
+//! [`Row`]: crate::widget::Row
+//! [`Column`]: crate::widget::Column
+use crate::layout::{Limits, Node};
+
+use std::cell::RefCell;
+use std::ops::{Add, AddAssign};
+use std::rc::Rc;
+
+/// The cached layout of a widget subtree.
+///
+/// A [`Cache`] is a handle: its clones share the same cached [`Node`] and
+/// [`Statistics`]. This lets a widget lay out its children through the
+/// caches stored in their [`Tree`].
+///
+/// [`Tree`]: crate::widget::Tree
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    internal: Rc<RefCell<Internal>>,
+}
+
+#[derive(Debug, Default)]
+struct Internal {
+    entry: Option<(Limits, Node)>,
+    statistics: Statistics,
+}
+
+impl Cache {
+    /// Creates a new empty [`Cache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the cached [`Node`] if it was computed with the given
+    /// [`Limits`]. Otherwise, computes a new [`Node`] with the given closure
+    /// and caches it.
+    pub fn layout(&self, limits: &Limits, f: impl FnOnce() -> Node) -> Node {
+        {
+            let mut internal = self.internal.borrow_mut();
+
+            if let Some((cached, node)) = &internal.entry {
+                if cached == limits {
+                    let node = node.clone();
+                    internal.statistics.hits += 1;
+
+                    return node;
+                }
+            }
+
+            internal.statistics.misses += 1;
+        }
+
+        // The closure may lay out other widgets, so the cache must not be
+        // borrowed while it runs
+        let node = f();
+
+        self.internal.borrow_mut().entry = Some((*limits, node.clone()));
+
+        node
+    }
+
+    /// Discards the cached [`Node`], if any.
+    pub fn invalidate(&self) {
+        self.internal.borrow_mut().entry = None;
+    }
+
+    /// Returns true if the [`Cache`] currently holds a [`Node`].
+    pub fn is_empty(&self) -> bool {
+        self.internal.borrow().entry.is_none()
+    }
+
+    /// Returns the [`Statistics`] of the [`Cache`] since its creation or the
+    /// last call to [`Cache::reset_statistics`].
+    pub fn statistics(&self) -> Statistics {
+        self.internal.borrow().statistics
+    }
+
+    /// Resets the [`Statistics`] of the [`Cache`].
+    pub fn reset_statistics(&self) {
+        self.internal.borrow_mut().statistics = Statistics::default();
+    }
+}
+
+/// The amount of hits and misses of one or more layout caches.
+///
+/// The [`Statistics`] of many caches can be added together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Statistics {
+    /// The number of layouts that were reused.
+    pub hits: usize,
+
+    /// The number of layouts that had to be computed.
+    pub misses: usize,
+}
+
+impl Statistics {
+    /// Returns the ratio of hits over all the lookups, between `0.0` and
+    /// `1.0`.
+    ///
+    /// If there have been no lookups, `0.0` is returned.
+    pub fn hit_rate(&self) -> f32 {
+        let total = self.hits + self.misses;
+
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f32 / total as f32
+        }
+    }
+}
+
+impl Add for Statistics {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            hits: self.hits + other.hits,
+            misses: self.misses + other.misses,
+        }
+    }
+}
+
+impl AddAssign for Statistics {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Size;
+
+    #[test]
+    fn reuses_node_for_same_limits() {
+        let cache = Cache::new();
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let mut computed = 0;
+
+        for _ in 0..3 {
+            let node = cache.layout(&limits, || {
+                computed += 1;
+
+                Node::new(Size::new(50.0, 20.0))
+            });
+
+            assert_eq!(node.size(), Size::new(50.0, 20.0));
+        }
+
+        assert_eq!(computed, 1);
+        assert_eq!(cache.statistics(), Statistics { hits: 2, misses: 1 });
+    }
+
+    #[test]
+    fn invalidates_on_limits_change() {
+        let cache = Cache::new();
+        let mut computed = 0;
+
+        for width in [100.0, 200.0, 200.0] {
+            let limits = Limits::new(Size::ZERO, Size::new(width, 100.0));
+
+            let _ = cache.layout(&limits, || {
+                computed += 1;
+
+                Node::new(Size::new(width, 20.0))
+            });
+        }
+
+        assert_eq!(computed, 2);
+
+        cache.invalidate();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn computes_hit_rate() {
+        let statistics = Statistics { hits: 3, misses: 1 };
+
+        assert_eq!(statistics.hit_rate(), 0.75);
+        assert_eq!(Statistics::default().hit_rate(), 0.0);
+    }
+
+    #[test]
+    fn keeps_statistics_per_cache() {
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let node = || Node::new(Size::new(10.0, 10.0));
+
+        let a = Cache::new();
+        let b = Cache::new();
+
+        let _ = a.layout(&limits, node);
+        let _ = a.layout(&limits, node);
+        let _ = b.layout(&limits, node);
+
+        // Clones share their statistics
+        let _ = a.clone().layout(&limits, node);
+
+        assert_eq!(a.statistics(), Statistics { hits: 2, misses: 1 });
+        assert_eq!(b.statistics(), Statistics { hits: 0, misses: 1 });
+
+        a.reset_statistics();
+
+        assert_eq!(a.statistics(), Statistics::default());
+        assert_eq!(b.statistics(), Statistics { hits: 0, misses: 1 });
+    }
+}
@@ -1,4 +1,112 @@
 //! Distribute elements using a flex-based layout.
+//!
+//! This is the layout engine behind [`Column`] and [`Row`]. It can be used
+//! directly to build custom containers that lay out their children the same
+//! way.
+//!
+//! The items of a flex layout are placed one after another along the main
+//! [`Axis`]. Items that do not fill the main axis are laid out first, with all
+//! the space left. Then, the remaining space is split between the items that
+//! [`Fill`] the main axis, proportionally to their fill factors.
+//!
+//! Items with a [`Preferred`] length are laid out right after the rest of the
+//! items that do not fill the main axis. If the space left fits all of their
+//! preferred sizes, every item gets its preferred size. Otherwise, every
+//! preferred size is scaled down by the same factor—the space left divided by
+//! the sum of the preferred sizes—so the items shrink proportionally. The
+//! items that fill the main axis only get what remains after that.
+//!
+//! With [`LayoutMode::Wrap`], the items are broken into lines that fit the
+//! main axis, and every line is laid out on its own as described above.
+//!
+//! # Example
+//! A toolbar that lays out its items horizontally, just like a [`Row`]:
+//!
+//! ```
+//! use iced_native::layout::{self, flex, Layout};
+//! use iced_native::mouse;
+//! use iced_native::renderer;
+//! use iced_native::widget::{Tree, Widget};
+//! use iced_native::{Alignment, Element, Length, Padding, Rectangle};
+//!
+//! struct Toolbar<'a, Message, Renderer> {
+//!     items: Vec<Element<'a, Message, Renderer>>,
+//! }
+//!
+//! impl<'a, Message, Renderer> Widget<Message, Renderer>
+//!     for Toolbar<'a, Message, Renderer>
+//! where
+//!     Renderer: iced_native::Renderer,
+//! {
+//!     fn width(&self) -> Length {
+//!         Length::Fill
+//!     }
+//!
+//!     fn height(&self) -> Length {
+//!         Length::Shrink
+//!     }
+//!
+//!     fn children(&self) -> Vec<Tree> {
+//!         self.items.iter().map(Tree::new).collect()
+//!     }
+//!
+//!     fn diff(&self, tree: &mut Tree) {
+//!         tree.diff_children(&self.items);
+//!     }
+//!
+//!     fn layout(
+//!         &self,
+//!         renderer: &Renderer,
+//!         limits: &layout::Limits,
+//!     ) -> layout::Node {
+//!         let limits = limits.width(self.width()).height(self.height());
+//!
+//!         flex::resolve(
+//!             flex::Axis::Horizontal,
+//!             flex::LayoutMode::Layout,
+//!             renderer,
+//!             &limits,
+//!             Padding::new(4),
+//!             8.0,
+//!             Alignment::Center,
+//!             &self.items,
+//!         )
+//!     }
+//!
+//!     fn draw(
+//!         &self,
+//!         tree: &Tree,
+//!         renderer: &mut Renderer,
+//!         theme: &Renderer::Theme,
+//!         style: &renderer::Style,
+//!         layout: Layout<'_>,
+//!         cursor: mouse::Cursor,
+//!         viewport: &Rectangle,
+//!     ) {
+//!         for ((item, tree), layout) in self
+//!             .items
+//!             .iter()
+//!             .zip(&tree.children)
+//!             .zip(layout.children())
+//!         {
+//!             item.as_widget().draw(
+//!                 tree,
+//!                 renderer,
+//!                 theme,
+//!                 style,
+//!                 layout,
+//!                 cursor,
+//!                 viewport,
+//!             );
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`Column`]: crate::widget::Column
+//! [`Row`]: crate::widget::Row
+//! [`Fill`]: Length::Fill
+//! [`Preferred`]: Length::Preferred
 // This code is heavily inspired by the [`druid`] codebase.
 //
 // [`druid`]: https://github.com/xi-editor/druid
@@ -18,11 +126,11 @@
 // limitations under the License.
 use crate::Element;
 
-use crate::layout::{Limits, Node};
-use crate::{Alignment, Padding, Point, Size};
+use crate::layout::{Cache, Limits, Node};
+use crate::{Alignment, Length, Padding, Point, Size, Vector};
 
 /// The main axis of a flex layout.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Axis {
     /// The horizontal axis
     Horizontal,
@@ -32,44 +140,220 @@ pub enum Axis {
 }
 
 impl Axis {
-    fn main(&self, size: Size) -> f32 {
+    /// Returns the length of the given [`Size`] along the [`Axis`].
+    pub fn main(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.width,
             Axis::Vertical => size.height,
         }
     }
 
-    fn cross(&self, size: Size) -> f32 {
+    /// Returns the length of the given [`Size`] across the [`Axis`].
+    pub fn cross(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.height,
             Axis::Vertical => size.width,
         }
     }
 
-    fn pack(&self, main: f32, cross: f32) -> (f32, f32) {
+    /// Packs the given lengths along and across the [`Axis`] into a pair of
+    /// horizontal and vertical lengths.
+    pub fn pack(&self, main: f32, cross: f32) -> (f32, f32) {
         match self {
             Axis::Horizontal => (main, cross),
             Axis::Vertical => (cross, main),
         }
     }
+
+    /// Returns the [`Length`] of the given item along the [`Axis`].
+    fn main_length<Renderer>(&self, item: &impl ItemProxy<Renderer>) -> Length {
+        match self {
+            Axis::Horizontal => item.width(),
+            Axis::Vertical => item.height(),
+        }
+    }
+
+    /// Returns the [`Length`] of the given item across the [`Axis`].
+    fn cross_length<Renderer>(
+        &self,
+        item: &impl ItemProxy<Renderer>,
+    ) -> Length {
+        match self {
+            Axis::Horizontal => item.height(),
+            Axis::Vertical => item.width(),
+        }
+    }
+}
+
+/// How a flex layout treats the items that [`Fill`] its main axis.
+///
+/// [`Fill`]: Length::Fill
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// The items are laid out to fit the limits.
+    ///
+    /// The space left by the rest of the items is split between the items
+    /// that fill the main axis, proportionally to their fill factors. This is
+    /// the mode used by [`Column`] and [`Row`].
+    ///
+    /// [`Column`]: crate::widget::Column
+    /// [`Row`]: crate::widget::Row
+    Layout,
+
+    /// The items are measured to find the minimum size of the layout.
+    ///
+    /// The items that fill the main axis get no space along it, so they are
+    /// laid out at their minimum size—normally zero. The resulting [`Node`]
+    /// has the smallest size that fits the rest of the items, which makes it
+    /// useful to compute the minimum size of a container.
+    Measure,
+
+    /// The items are wrapped onto multiple lines when they overflow the main
+    /// axis.
+    ///
+    /// The items are placed on the current line until the next one does not
+    /// fit, which then starts a new line. An item that does not fit even on
+    /// its own gets a line of its own. Every line is laid out like in
+    /// [`LayoutMode::Layout`], and the lines are stacked along the cross axis
+    /// with the same spacing as the items.
+    ///
+    /// The alignment of the items applies within each line: with
+    /// [`Alignment::Fill`], the items are stretched to the biggest item of
+    /// their line. Items that [`Fill`] the main axis are measured at their
+    /// minimum size—normally zero—when breaking lines, so they join the
+    /// current line whenever they fit and then take the rest of its space.
+    ///
+    /// [`Fill`]: Length::Fill
+    Wrap,
+}
+
+/// An item of a flex layout.
+///
+/// It is implemented for [`Element`], but custom containers can implement it
+/// for their own items—e.g. to lay out a subset of their children.
+pub trait ItemProxy<Renderer> {
+    /// Returns the width [`Length`] of the item.
+    fn width(&self) -> Length;
+
+    /// Returns the height [`Length`] of the item.
+    fn height(&self) -> Length;
+
+    /// Returns the layout [`Node`] of the item within the given [`Limits`].
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node;
+
+    /// Returns the distance from the top of the given [`Node`] of the item to
+    /// its first baseline, if it has one.
+    ///
+    /// It is only used with [`Alignment::Baseline`].
+    fn baseline(&self, _renderer: &Renderer, _layout: &Node) -> Option<f32> {
+        None
+    }
+}
+
+impl<'a, Message, Renderer> ItemProxy<Renderer>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.as_widget().layout(renderer, limits)
+    }
+
+    fn baseline(&self, renderer: &Renderer, layout: &Node) -> Option<f32> {
+        self.as_widget().baseline(renderer, layout)
+    }
+}
+
+/// An item of a flex layout that is laid out through a layout [`Cache`].
+///
+/// [`Row`] and [`Column`] lay out their children through the caches of
+/// their trees with it. The [`Cache`] must be invalidated whenever the item
+/// changes—which [`Tree::diff`] takes care of.
+///
+/// [`Row`]: crate::widget::Row
+/// [`Column`]: crate::widget::Column
+/// [`Tree::diff`]: crate::widget::Tree::diff
+#[derive(Debug)]
+pub struct Cached<'a, Item> {
+    item: &'a Item,
+    cache: &'a Cache,
+}
+
+impl<'a, Item> Cached<'a, Item> {
+    /// Creates a new [`Cached`] item with the given [`Cache`].
+    pub fn new(item: &'a Item, cache: &'a Cache) -> Self {
+        Self { item, cache }
+    }
+}
+
+impl<'a, Item, Renderer> ItemProxy<Renderer> for Cached<'a, Item>
+where
+    Item: ItemProxy<Renderer>,
+{
+    fn width(&self) -> Length {
+        self.item.width()
+    }
+
+    fn height(&self) -> Length {
+        self.item.height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        self.cache
+            .layout(limits, || self.item.layout(renderer, limits))
+    }
+
+    fn baseline(&self, renderer: &Renderer, layout: &Node) -> Option<f32> {
+        self.item.baseline(renderer, layout)
+    }
 }
 
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
-/// It returns a new layout [`Node`].
-pub fn resolve<Message, Renderer>(
+/// The [`LayoutMode`] decides how the items that fill the main axis are
+/// treated. With [`Alignment::Fill`], every item is stretched along the
+/// cross axis to the size of the biggest item that does not fill it.
+///
+/// With [`Alignment::Baseline`] along a horizontal axis, the items are
+/// placed so their baselines share the same line, and the cross axis grows to
+/// fit them. Items without a baseline are aligned by their bottom edge.
+///
+/// It returns a new layout [`Node`], with a child [`Node`] for every item in
+/// the same order.
+pub fn resolve<Renderer, Item>(
     axis: Axis,
+    mode: LayoutMode,
     renderer: &Renderer,
     limits: &Limits,
     padding: Padding,
     spacing: f32,
     align_items: Alignment,
-    items: &[Element<'_, Message, Renderer>],
+    items: &[Item],
 ) -> Node
 where
-    Renderer: crate::Renderer,
+    Item: ItemProxy<Renderer>,
 {
+    if mode == LayoutMode::Wrap {
+        return wrap(
+            axis,
+            renderer,
+            limits,
+            padding,
+            spacing,
+            align_items,
+            items,
+        );
+    }
+
     let limits = limits.pad(padding);
     let total_spacing = spacing * items.len().saturating_sub(1) as f32;
     let max_cross = axis.cross(limits.max());
@@ -85,11 +369,7 @@ where
         let mut fill_cross = axis.cross(limits.min());
 
         items.iter().for_each(|child| {
-            let cross_fill_factor = match axis {
-                Axis::Horizontal => child.as_widget().height(),
-                Axis::Vertical => child.as_widget().width(),
-            }
-            .fill_factor();
+            let cross_fill_factor = axis.cross_length(child).fill_factor();
 
             if cross_fill_factor == 0 {
                 let (max_width, max_height) = axis.pack(available, max_cross);
@@ -97,7 +377,7 @@ where
                 let child_limits =
                     Limits::new(Size::ZERO, Size::new(max_width, max_height));
 
-                let layout = child.as_widget().layout(renderer, &child_limits);
+                let layout = child.layout(renderer, &child_limits);
                 let size = layout.size();
 
                 fill_cross = fill_cross.max(axis.cross(size));
@@ -107,32 +387,39 @@ where
         cross = fill_cross;
     }
 
-    for (i, child) in items.iter().enumerate() {
-        let fill_factor = match axis {
-            Axis::Horizontal => child.as_widget().width(),
-            Axis::Vertical => child.as_widget().height(),
-        }
-        .fill_factor();
+    let item_limits = |max_main: f32, cross: f32| {
+        let (min_width, min_height) = if align_items == Alignment::Fill {
+            axis.pack(0.0, cross)
+        } else {
+            axis.pack(0.0, 0.0)
+        };
 
-        if fill_factor == 0 {
-            let (min_width, min_height) = if align_items == Alignment::Fill {
-                axis.pack(0.0, cross)
-            } else {
-                axis.pack(0.0, 0.0)
-            };
+        let (max_width, max_height) = if align_items == Alignment::Fill {
+            axis.pack(max_main, cross)
+        } else {
+            axis.pack(max_main, max_cross)
+        };
 
-            let (max_width, max_height) = if align_items == Alignment::Fill {
-                axis.pack(available, cross)
-            } else {
-                axis.pack(available, max_cross)
-            };
+        Limits::new(
+            Size::new(min_width, min_height),
+            Size::new(max_width, max_height),
+        )
+    };
 
-            let child_limits = Limits::new(
-                Size::new(min_width, min_height),
-                Size::new(max_width, max_height),
-            );
+    let mut preferred_sum = 0.0;
+
+    for (i, child) in items.iter().enumerate() {
+        let main_length = axis.main_length(child);
+
+        if let Length::Preferred(preferred) = main_length {
+            preferred_sum += f32::from(preferred);
+        } else if main_length.fill_factor() == 0 {
+            let max_main = match main_length {
+                Length::FitContent(max) => available.min(f32::from(max)),
+                _ => available,
+            };
 
-            let layout = child.as_widget().layout(renderer, &child_limits);
+            let layout = child.layout(renderer, &item_limits(max_main, cross));
             let size = layout.size();
 
             available -= axis.main(size);
@@ -143,18 +430,41 @@ where
 
             nodes[i] = layout;
         } else {
-            fill_sum += fill_factor;
+            fill_sum += main_length.fill_factor();
         }
     }
 
-    let remaining = available.max(0.0);
+    // Preferred sizes are only scaled down when they do not fit
+    let scale = if preferred_sum > available.max(0.0) {
+        available.max(0.0) / preferred_sum
+    } else {
+        1.0
+    };
 
     for (i, child) in items.iter().enumerate() {
-        let fill_factor = match axis {
-            Axis::Horizontal => child.as_widget().width(),
-            Axis::Vertical => child.as_widget().height(),
+        if let Length::Preferred(preferred) = axis.main_length(child) {
+            let max_main = f32::from(preferred) * scale;
+
+            let layout = child.layout(renderer, &item_limits(max_main, cross));
+            let size = layout.size();
+
+            available -= axis.main(size);
+
+            if align_items != Alignment::Fill {
+                cross = cross.max(axis.cross(size));
+            }
+
+            nodes[i] = layout;
         }
-        .fill_factor();
+    }
+
+    let remaining = match mode {
+        LayoutMode::Layout | LayoutMode::Wrap => available.max(0.0),
+        LayoutMode::Measure => 0.0,
+    };
+
+    for (i, child) in items.iter().enumerate() {
+        let fill_factor = axis.main_length(child).fill_factor();
 
         if fill_factor != 0 {
             let max_main = remaining * fill_factor as f32 / fill_sum as f32;
@@ -181,7 +491,7 @@ where
                 Size::new(max_width, max_height),
             );
 
-            let layout = child.as_widget().layout(renderer, &child_limits);
+            let layout = child.layout(renderer, &child_limits);
 
             if align_items != Alignment::Fill {
                 cross = cross.max(axis.cross(layout.size()));
@@ -191,6 +501,40 @@ where
         }
     }
 
+    // The offset of every baseline from the top of its node, if the items
+    // are aligned by their baselines
+    let baselines: Option<Vec<f32>> = if axis == Axis::Horizontal
+        && align_items == Alignment::Baseline
+    {
+        Some(
+            items
+                .iter()
+                .zip(&nodes)
+                .map(|(item, node)| {
+                    item.baseline(renderer, node).unwrap_or(node.size().height)
+                })
+                .collect(),
+        )
+    } else {
+        None
+    };
+
+    let ascent = baselines
+        .iter()
+        .flatten()
+        .fold(0.0f32, |ascent, &baseline| ascent.max(baseline));
+
+    if let Some(baselines) = &baselines {
+        let descent = baselines.iter().zip(&nodes).fold(
+            0.0f32,
+            |descent, (baseline, node)| {
+                descent.max(node.size().height - baseline)
+            },
+        );
+
+        cross = cross.max(ascent + descent);
+    }
+
     let pad = axis.pack(padding.left as f32, padding.top as f32);
     let mut main = pad.0;
 
@@ -199,7 +543,10 @@ where
             main += spacing;
         }
 
-        let (x, y) = axis.pack(main, pad.1);
+        let (x, y) = match &baselines {
+            Some(baselines) => (main, pad.1 + ascent - baselines[i]),
+            None => axis.pack(main, pad.1),
+        };
 
         node.move_to(Point::new(x, y));
 
@@ -230,3 +577,384 @@ where
 
     Node::with_children(size.pad(padding), nodes)
 }
+
+/// Breaks the items into lines that fit the main axis and lays out every
+/// line with [`resolve`], stacking the lines along the cross axis.
+fn wrap<Renderer, Item>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    spacing: f32,
+    align_items: Alignment,
+    items: &[Item],
+) -> Node
+where
+    Item: ItemProxy<Renderer>,
+{
+    let limits = limits.pad(padding);
+    let max_main = axis.main(limits.max());
+    let max_cross = axis.cross(limits.max());
+
+    // The size of every item along the main axis, used to break the lines
+    let lengths = items.iter().map(|item| {
+        let main_length = axis.main_length(item);
+
+        let max = match main_length {
+            Length::FitContent(max) | Length::Preferred(max) => {
+                max_main.min(f32::from(max))
+            }
+            _ if main_length.fill_factor() == 0 => max_main,
+            _ => 0.0,
+        };
+
+        let (max_width, max_height) = axis.pack(max, max_cross);
+        let node = item.layout(
+            renderer,
+            &Limits::new(Size::ZERO, Size::new(max_width, max_height)),
+        );
+
+        axis.main(node.size())
+    });
+
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut line_length = 0.0;
+
+    for (i, length) in lengths.enumerate() {
+        if i > start && line_length + spacing + length > max_main {
+            lines.push(start..i);
+
+            start = i;
+            line_length = length;
+        } else if i > start {
+            line_length += spacing + length;
+        } else {
+            line_length = length;
+        }
+    }
+
+    if start < items.len() {
+        lines.push(start..items.len());
+    }
+
+    let (max_width, max_height) = axis.pack(max_main, max_cross);
+    let line_limits = Limits::new(Size::ZERO, Size::new(max_width, max_height))
+        .width(Length::Shrink)
+        .height(Length::Shrink);
+
+    let mut nodes = Vec::with_capacity(items.len());
+    let mut main = 0.0f32;
+    let mut cross = 0.0;
+
+    for (i, line) in lines.into_iter().enumerate() {
+        if i > 0 {
+            cross += spacing;
+        }
+
+        let line = resolve(
+            axis,
+            LayoutMode::Layout,
+            renderer,
+            &line_limits,
+            Padding::ZERO,
+            spacing,
+            align_items,
+            &items[line],
+        );
+
+        let (x, y) = axis.pack(0.0, cross);
+        let offset =
+            Vector::new(padding.left as f32 + x, padding.top as f32 + y);
+
+        nodes.extend(
+            line.children()
+                .iter()
+                .map(|node| node.clone().translate(offset)),
+        );
+
+        main = main.max(axis.main(line.size()));
+        cross += axis.cross(line.size());
+    }
+
+    let (width, height) = axis.pack(main, cross);
+    let size = limits.resolve(Size::new(width, height));
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::Limits;
+    use crate::renderer::Null;
+    use crate::widget::{Container, Row, Space};
+    use crate::{Element, Length, Renderer, Size};
+
+    fn chip(content_width: u16, cap: u16) -> Element<'static, (), Null> {
+        Container::new(Space::new(
+            Length::Units(content_width),
+            Length::Units(10),
+        ))
+        .width(Length::FitContent(cap))
+        .into()
+    }
+
+    fn widths(row: Row<'static, (), Null>, max_width: f32) -> Vec<f32> {
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = row.into();
+
+        let node = renderer.layout(
+            &element,
+            &Limits::new(Size::ZERO, Size::new(max_width, 100.0)),
+        );
+
+        node.children()
+            .iter()
+            .map(|child| child.size().width)
+            .collect()
+    }
+
+    #[test]
+    fn fit_content_clamps_to_its_cap() {
+        let row = Row::new().push(chip(120, 50));
+
+        assert_eq!(widths(row, 200.0), vec![50.0]);
+    }
+
+    #[test]
+    fn fit_content_shrinks_below_its_cap() {
+        let row = Row::new().push(chip(30, 50));
+
+        assert_eq!(widths(row, 200.0), vec![30.0]);
+    }
+
+    #[test]
+    fn fit_content_leaves_remaining_space_to_fill() {
+        let row = Row::new()
+            .push(chip(120, 50))
+            .push(Space::with_width(Length::Fill))
+            .push(chip(30, 50));
+
+        assert_eq!(widths(row, 200.0), vec![50.0, 120.0, 30.0]);
+    }
+
+    #[test]
+    fn fit_content_respects_the_available_space() {
+        let row = Row::new().push(chip(120, 50));
+
+        assert_eq!(widths(row, 40.0), vec![40.0]);
+    }
+
+    #[test]
+    fn measure_mode_gives_no_space_to_fill_items() {
+        use super::{resolve, Axis, LayoutMode};
+        use crate::{Alignment, Padding};
+
+        let items: Vec<Element<'static, (), Null>> = vec![
+            chip(30, 50),
+            Container::new(Space::new(Length::Units(20), Length::Units(10)))
+                .width(Length::Fill)
+                .into(),
+        ];
+
+        let widths = |mode| {
+            resolve(
+                Axis::Horizontal,
+                mode,
+                &Null::new(),
+                &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+                Padding::ZERO,
+                5.0,
+                Alignment::Start,
+                &items,
+            )
+            .children()
+            .iter()
+            .map(|child| child.size().width)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(widths(LayoutMode::Layout), vec![30.0, 165.0]);
+        assert_eq!(widths(LayoutMode::Measure), vec![30.0, 0.0]);
+    }
+
+    #[test]
+    fn baseline_alignment_shares_the_baseline_of_mixed_text() {
+        use crate::widget::Text;
+        use crate::Alignment;
+
+        let row = Row::new()
+            .align_items(Alignment::Baseline)
+            .push(Text::new("Small").size(10))
+            .push(Text::new("Big").size(30))
+            .push(Space::new(Length::Units(10), Length::Units(10)));
+
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = row.into();
+
+        let node = renderer.layout(
+            &element,
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let tops: Vec<f32> = node
+            .children()
+            .iter()
+            .map(|child| child.bounds().y)
+            .collect();
+
+        // The baselines of the text sit at 80% of their size, while the
+        // space falls back to its bottom edge
+        assert_eq!(tops, vec![16.0, 0.0, 14.0]);
+        assert_eq!(tops[0] + 8.0, tops[1] + 24.0);
+
+        // The lowest descent comes from the small text
+        assert_eq!(node.size().height, 36.0);
+    }
+
+    #[test]
+    fn baseline_alignment_looks_through_containers_and_buttons() {
+        use crate::widget::{Button, Container, Text};
+        use crate::Alignment;
+
+        let row = Row::new()
+            .align_items(Alignment::Baseline)
+            .push(Container::new(Text::new("Padded").size(10)).padding(10))
+            .push(Button::new(Text::new("Big").size(30)).padding(0));
+
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = row.into();
+
+        let node = renderer.layout(
+            &element,
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let tops: Vec<f32> = node
+            .children()
+            .iter()
+            .map(|child| child.bounds().y)
+            .collect();
+
+        // The baseline of the padded text is 10 + 8 units below the top of
+        // its container, and the one of the big text 24 units below the top
+        // of its button
+        assert_eq!(tops, vec![6.0, 0.0]);
+    }
+
+    #[test]
+    fn preferred_sizes_are_kept_when_space_allows() {
+        let row = Row::new()
+            .push(Space::with_width(Length::Preferred(100)))
+            .push(Space::with_width(Length::Fill));
+
+        assert_eq!(widths(row, 300.0), vec![100.0, 200.0]);
+    }
+
+    #[test]
+    fn preferred_sizes_shrink_proportionally_under_pressure() {
+        let row = Row::new()
+            .spacing(10)
+            .push(Space::with_width(Length::Units(30)))
+            .push(Space::with_width(Length::Preferred(100)))
+            .push(Space::with_width(Length::Preferred(200)))
+            .push(Space::with_width(Length::Fill));
+
+        // 30 units of spacing and 30 units of content leave 150 units for
+        // 300 preferred units, so every preferred size is halved
+        assert_eq!(widths(row, 210.0), vec![30.0, 50.0, 100.0, 0.0]);
+    }
+
+    #[test]
+    fn preferred_sizes_take_whatever_space_is_left() {
+        let row = || {
+            Row::new()
+                .push(Space::with_width(Length::Units(80)))
+                .push(Space::with_width(Length::Preferred(100)))
+        };
+
+        assert_eq!(widths(row(), 100.0), vec![80.0, 20.0]);
+        assert_eq!(widths(row(), 80.0), vec![80.0, 0.0]);
+    }
+
+    fn bounds(
+        row: Row<'static, (), Null>,
+        max_width: f32,
+    ) -> (Size, Vec<crate::Rectangle>) {
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = row.into();
+
+        let node = renderer.layout(
+            &element,
+            &Limits::new(Size::ZERO, Size::new(max_width, 100.0)),
+        );
+
+        (
+            node.size(),
+            node.children().iter().map(|child| child.bounds()).collect(),
+        )
+    }
+
+    fn square(size: u16) -> Space {
+        Space::new(Length::Units(size), Length::Units(size))
+    }
+
+    #[test]
+    fn wrapping_breaks_overflowing_items_into_lines() {
+        let row = (0..5).fold(Row::new().spacing(10).wrap(true), |row, _| {
+            row.push(square(40))
+        });
+
+        let (size, bounds) = bounds(row, 100.0);
+
+        let positions: Vec<(f32, f32)> =
+            bounds.iter().map(|bounds| (bounds.x, bounds.y)).collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                (0.0, 0.0),
+                (50.0, 0.0),
+                (0.0, 50.0),
+                (50.0, 50.0),
+                (0.0, 100.0)
+            ]
+        );
+        assert_eq!(size, Size::new(90.0, 100.0));
+    }
+
+    #[test]
+    fn wrapping_aligns_items_within_their_line() {
+        use crate::Alignment;
+
+        let row = Row::new()
+            .wrap(true)
+            .align_items(Alignment::Center)
+            .push(square(40))
+            .push(square(20))
+            .push(square(10))
+            .push(square(30));
+
+        let (_, bounds) = bounds(row, 65.0);
+
+        let tops: Vec<f32> = bounds.iter().map(|bounds| bounds.y).collect();
+
+        // The second line is only as tall as its tallest item
+        assert_eq!(tops, vec![0.0, 10.0, 50.0, 40.0]);
+    }
+
+    #[test]
+    fn wrapping_fills_the_rest_of_the_line() {
+        let row = Row::new()
+            .wrap(true)
+            .push(square(60))
+            .push(Space::new(Length::Fill, Length::Units(10)))
+            .push(square(60));
+
+        let (_, bounds) = bounds(row, 100.0);
+
+        assert_eq!(bounds[1].x, 60.0);
+        assert_eq!(bounds[1].width, 40.0);
+        assert_eq!((bounds[2].x, bounds[2].y), (0.0, 60.0));
+    }
+}
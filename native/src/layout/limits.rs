@@ -2,7 +2,7 @@
 use crate::{Length, Padding, Size};
 
 /// A set of size constraints for layouting.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Limits {
     min: Size,
     max: Size,
@@ -47,10 +47,15 @@ impl Limits {
             Length::Shrink => {
                 self.fill.width = self.min.width;
             }
+            Length::FitContent(max) => {
+                self.max.width =
+                    self.max.width.min(max as f32).max(self.min.width);
+                self.fill.width = self.min.width;
+            }
             Length::Fill | Length::FillPortion(_) => {
                 self.fill.width = self.fill.width.min(self.max.width);
             }
-            Length::Units(units) => {
+            Length::Units(units) | Length::Preferred(units) => {
                 let new_width =
                     (units as f32).min(self.max.width).max(self.min.width);
 
@@ -69,10 +74,15 @@ impl Limits {
             Length::Shrink => {
                 self.fill.height = self.min.height;
             }
+            Length::FitContent(max) => {
+                self.max.height =
+                    self.max.height.min(max as f32).max(self.min.height);
+                self.fill.height = self.min.height;
+            }
             Length::Fill | Length::FillPortion(_) => {
                 self.fill.height = self.fill.height.min(self.max.height);
             }
-            Length::Units(units) => {
+            Length::Units(units) | Length::Preferred(units) => {
                 let new_height =
                     (units as f32).min(self.max.height).max(self.min.height);
 
@@ -97,6 +107,7 @@ impl Limits {
     pub fn max_width(mut self, max_width: u32) -> Limits {
         self.max.width =
             self.max.width.min(max_width as f32).max(self.min.width);
+        self.fill.width = self.fill.width.min(self.max.width);
 
         self
     }
@@ -113,6 +124,7 @@ impl Limits {
     pub fn max_height(mut self, max_height: u32) -> Limits {
         self.max.height =
             self.max.height.min(max_height as f32).max(self.min.height);
+        self.fill.height = self.fill.height.min(self.max.height);
 
         self
     }
@@ -1,6 +1,20 @@
 use crate::{Alignment, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
+///
+/// The position of a [`Node`] is relative to its parent. A [`Layout`] adds
+/// up the positions of a [`Node`] and all of its ancestors to obtain its
+/// absolute bounds, which are the ones widgets use for hit-testing during
+/// [`Widget::on_event`]. Therefore, moving or translating a [`Node`] moves
+/// all of its children along with it.
+///
+/// Custom widgets can position their children freely, without the
+/// [`flex`] engine, by laying them out and placing them with
+/// [`Node::move_to`] or [`Node::with_positioned_children`].
+///
+/// [`Layout`]: super::Layout
+/// [`Widget::on_event`]: crate::Widget::on_event
+/// [`flex`]: super::flex
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
@@ -26,12 +40,32 @@ impl Node {
         }
     }
 
+    /// Creates a new [`Node`] with the given [`Size`] and children, moving
+    /// every child to its paired position.
+    ///
+    /// The positions are relative to the new [`Node`].
+    pub fn with_positioned_children(
+        size: Size,
+        children: impl IntoIterator<Item = (Point, Node)>,
+    ) -> Self {
+        Self::with_children(
+            size,
+            children
+                .into_iter()
+                .map(|(position, mut child)| {
+                    child.move_to(position);
+                    child
+                })
+                .collect(),
+        )
+    }
+
     /// Returns the [`Size`] of the [`Node`].
     pub fn size(&self) -> Size {
         Size::new(self.bounds.width, self.bounds.height)
     }
 
-    /// Returns the bounds of the [`Node`].
+    /// Returns the bounds of the [`Node`], relative to its parent.
     pub fn bounds(&self) -> Rectangle {
         self.bounds
     }
@@ -49,7 +83,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -62,7 +96,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Alignment::Start => {}
+            Alignment::Start | Alignment::Baseline => {}
             Alignment::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
@@ -75,13 +109,19 @@ impl Node {
         }
     }
 
-    /// Moves the [`Node`] to the given position.
+    /// Moves the [`Node`] to the given position, relative to its parent.
+    ///
+    /// Its children keep their relative positions, so their absolute bounds
+    /// move by the same amount.
     pub fn move_to(&mut self, position: Point) {
         self.bounds.x = position.x;
         self.bounds.y = position.y;
     }
 
     /// Translates the [`Node`] by the given translation.
+    ///
+    /// Its children keep their relative positions, so their absolute bounds
+    /// are translated as well.
     pub fn translate(self, translation: Vector) -> Self {
         Self {
             bounds: self.bounds + translation,
@@ -89,3 +129,55 @@ impl Node {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Layout;
+
+    fn absolute_bounds(node: &Node) -> Vec<Rectangle> {
+        let layout = Layout::new(node);
+
+        std::iter::once(layout.bounds())
+            .chain(layout.children().map(|child| child.bounds()))
+            .collect()
+    }
+
+    #[test]
+    fn children_are_placed_at_their_positions() {
+        let node = Node::with_positioned_children(
+            Size::new(100.0, 100.0),
+            [
+                (Point::new(10.0, 20.0), Node::new(Size::new(5.0, 5.0))),
+                (Point::new(50.0, 0.0), Node::new(Size::new(10.0, 10.0))),
+            ],
+        );
+
+        assert_eq!(
+            absolute_bounds(&node),
+            vec![
+                Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+                Rectangle::new(Point::new(10.0, 20.0), Size::new(5.0, 5.0)),
+                Rectangle::new(Point::new(50.0, 0.0), Size::new(10.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn translation_moves_the_children() {
+        let node = Node::with_positioned_children(
+            Size::new(100.0, 100.0),
+            [(Point::new(10.0, 20.0), Node::new(Size::new(5.0, 5.0)))],
+        )
+        .translate(Vector::new(3.0, -4.0));
+
+        assert_eq!(
+            absolute_bounds(&node),
+            vec![
+                Rectangle::new(Point::new(3.0, -4.0), Size::new(100.0, 100.0)),
+                Rectangle::new(Point::new(13.0, 16.0), Size::new(5.0, 5.0)),
+            ]
+        );
+    }
+}
@@ -48,10 +48,13 @@ pub mod clipboard;
 pub mod command;
 pub mod event;
 pub mod image;
+pub mod input_method;
 pub mod keyboard;
 pub mod layout;
+pub mod menu;
 pub mod mouse;
 pub mod overlay;
+pub mod profile;
 pub mod program;
 pub mod renderer;
 pub mod subscription;
@@ -59,6 +62,7 @@ pub mod svg;
 pub mod system;
 pub mod text;
 pub mod touch;
+pub mod tray;
 pub mod user_interface;
 pub mod widget;
 pub mod window;
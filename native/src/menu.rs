@@ -0,0 +1,290 @@
+//! Define the menu of an application.
+use crate::keyboard::{KeyCode, Modifiers};
+
+use std::fmt;
+
+/// A tree of [`Item`]s describing the menu of an application.
+///
+/// The top-level items of a menu bar are normally submenus (e.g. _File_,
+/// _Edit_, and _View_).
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates an empty [`Menu`].
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds an [`Entry`] to the [`Menu`].
+    pub fn entry(self, entry: Entry<Message>) -> Self {
+        self.push(Item::Entry(entry))
+    }
+
+    /// Adds a submenu with the given label to the [`Menu`].
+    pub fn submenu(
+        self,
+        label: impl Into<String>,
+        menu: Menu<Message>,
+    ) -> Self {
+        self.push(Item::Submenu {
+            label: label.into(),
+            menu,
+        })
+    }
+
+    /// Adds a separator to the [`Menu`].
+    pub fn separator(self) -> Self {
+        self.push(Item::Separator)
+    }
+
+    /// Adds an [`Item`] to the [`Menu`].
+    pub fn push(mut self, item: Item<Message>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Returns the [`Item`]s of the [`Menu`].
+    pub fn items(&self) -> &[Item<Message>] {
+        &self.items
+    }
+
+    /// Returns true if the [`Menu`] has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the message of the enabled [`Entry`] at the given path of
+    /// item indices, descending into submenus.
+    pub fn activate(self, path: &[usize]) -> Option<Message> {
+        let (index, rest) = path.split_first()?;
+
+        match self.items.into_iter().nth(*index)? {
+            Item::Entry(entry) if rest.is_empty() && entry.enabled => {
+                Some(entry.on_activate)
+            }
+            Item::Submenu { menu, .. } => menu.activate(rest),
+            _ => None,
+        }
+    }
+
+    /// Returns the message of the first enabled [`Entry`] with an
+    /// [`Accelerator`] matching the given key press.
+    pub fn accelerate(
+        self,
+        key_code: KeyCode,
+        modifiers: Modifiers,
+    ) -> Option<Message> {
+        self.items.into_iter().find_map(|item| match item {
+            Item::Entry(entry)
+                if entry.enabled
+                    && entry.accelerator.map_or(false, |accelerator| {
+                        accelerator.matches(key_code, modifiers)
+                    }) =>
+            {
+                Some(entry.on_activate)
+            }
+            Item::Submenu { menu, .. } => menu.accelerate(key_code, modifiers),
+            _ => None,
+        })
+    }
+}
+
+impl<Message> Default for Menu<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An item of a [`Menu`].
+#[derive(Debug, Clone)]
+pub enum Item<Message> {
+    /// An [`Entry`] that can be activated.
+    Entry(Entry<Message>),
+
+    /// A nested [`Menu`].
+    Submenu {
+        /// The label of the submenu.
+        label: String,
+        /// The items of the submenu.
+        menu: Menu<Message>,
+    },
+
+    /// A line separating groups of items.
+    Separator,
+}
+
+/// An item of a [`Menu`] that produces a message when activated.
+#[derive(Debug, Clone)]
+pub struct Entry<Message> {
+    /// The label of the [`Entry`].
+    pub label: String,
+
+    /// The message produced when the [`Entry`] is activated.
+    pub on_activate: Message,
+
+    /// The [`Accelerator`] of the [`Entry`], if any.
+    pub accelerator: Option<Accelerator>,
+
+    /// Whether the [`Entry`] can be activated.
+    pub enabled: bool,
+
+    /// Whether the [`Entry`] is checked, if it can be.
+    pub checked: Option<bool>,
+}
+
+impl<Message> Entry<Message> {
+    /// Creates a new enabled [`Entry`] with the given label and the message
+    /// to produce when activated.
+    pub fn new(label: impl Into<String>, on_activate: Message) -> Self {
+        Self {
+            label: label.into(),
+            on_activate,
+            accelerator: None,
+            enabled: true,
+            checked: None,
+        }
+    }
+
+    /// Sets the [`Accelerator`] of the [`Entry`].
+    pub fn accelerator(mut self, accelerator: Accelerator) -> Self {
+        self.accelerator = Some(accelerator);
+        self
+    }
+
+    /// Sets whether the [`Entry`] can be activated.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Makes the [`Entry`] checkable and sets whether it is checked.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+}
+
+/// A keyboard shortcut that activates an [`Entry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    /// The modifiers that must be held.
+    pub modifiers: Modifiers,
+
+    /// The key that must be pressed.
+    pub key_code: KeyCode,
+}
+
+impl Accelerator {
+    /// Creates a new [`Accelerator`] with the given modifiers and key.
+    pub fn new(modifiers: Modifiers, key_code: KeyCode) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns true if the [`Accelerator`] matches the given key press.
+    ///
+    /// The modifiers must match exactly.
+    pub fn matches(&self, key_code: KeyCode, modifiers: Modifiers) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (
+                Modifiers::LOGO,
+                if cfg!(target_os = "macos") {
+                    "Cmd"
+                } else {
+                    "Super"
+                },
+            ),
+        ];
+
+        for (modifier, name) in modifiers {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        let key = format!("{:?}", self.key_code);
+
+        // Digits are named `Key0` to `Key9`
+        match key.strip_prefix("Key") {
+            Some(digit) if digit.len() == 1 => write!(f, "{digit}"),
+            _ => write!(f, "{key}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn menu(save_enabled: bool) -> Menu<&'static str> {
+        Menu::new()
+            .submenu(
+                "File",
+                Menu::new()
+                    .entry(Entry::new("New", "new"))
+                    .separator()
+                    .entry(
+                        Entry::new("Save", "save")
+                            .accelerator(Accelerator::new(
+                                Modifiers::CTRL,
+                                KeyCode::S,
+                            ))
+                            .enabled(save_enabled),
+                    ),
+            )
+            .submenu(
+                "View",
+                Menu::new().submenu(
+                    "Zoom",
+                    Menu::new().entry(Entry::new("Reset", "reset")),
+                ),
+            )
+    }
+
+    #[test]
+    fn activates_entries_by_path() {
+        assert_eq!(menu(true).activate(&[0, 0]), Some("new"));
+        assert_eq!(menu(true).activate(&[1, 0, 0]), Some("reset"));
+
+        // Separators, submenus, and disabled entries produce nothing
+        assert_eq!(menu(true).activate(&[0, 1]), None);
+        assert_eq!(menu(true).activate(&[1, 0]), None);
+        assert_eq!(menu(false).activate(&[0, 2]), None);
+    }
+
+    #[test]
+    fn accelerates_enabled_entries_only() {
+        assert_eq!(
+            menu(true).accelerate(KeyCode::S, Modifiers::CTRL),
+            Some("save")
+        );
+        assert_eq!(
+            menu(true)
+                .accelerate(KeyCode::S, Modifiers::CTRL | Modifiers::SHIFT),
+            None
+        );
+        assert_eq!(menu(false).accelerate(KeyCode::S, Modifiers::CTRL), None);
+    }
+
+    #[test]
+    fn displays_accelerators() {
+        let accelerator =
+            Accelerator::new(Modifiers::CTRL | Modifiers::SHIFT, KeyCode::Key1);
+
+        assert_eq!(accelerator.to_string(), "Ctrl+Shift+1");
+    }
+}
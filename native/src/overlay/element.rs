@@ -68,35 +68,25 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.overlay.on_event(
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        )
+        self.overlay
+            .on_event(event, layout, cursor, renderer, clipboard, shell)
     }
 
     /// Returns the current [`mouse::Interaction`] of the [`Element`].
     pub fn mouse_interaction(
         &self,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.overlay.mouse_interaction(
-            layout,
-            cursor_position,
-            viewport,
-            renderer,
-        )
+        self.overlay
+            .mouse_interaction(layout, cursor, viewport, renderer)
     }
 
     /// Draws the [`Element`] and its children using the given [`Layout`].
@@ -106,10 +96,9 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) {
-        self.overlay
-            .draw(renderer, theme, style, layout, cursor_position)
+        self.overlay.draw(renderer, theme, style, layout, cursor)
     }
 
     /// Applies a [`widget::Operation`] to the [`Element`].
@@ -178,6 +167,10 @@ where
                 });
             }
 
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&widget::Id>) {
+                self.operation.bounds(bounds, id);
+            }
+
             fn focusable(
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
@@ -205,6 +198,10 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn target(&self) -> Option<&widget::Id> {
+                self.operation.target()
+            }
         }
 
         self.content
@@ -215,7 +212,7 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, B>,
@@ -226,7 +223,7 @@ where
         let event_status = self.content.on_event(
             event,
             layout,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             &mut local_shell,
@@ -240,16 +237,12 @@ where
     fn mouse_interaction(
         &self,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.content.mouse_interaction(
-            layout,
-            cursor_position,
-            viewport,
-            renderer,
-        )
+        self.content
+            .mouse_interaction(layout, cursor, viewport, renderer)
     }
 
     fn draw(
@@ -258,10 +251,9 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) {
-        self.content
-            .draw(renderer, theme, style, layout, cursor_position)
+        self.content.draw(renderer, theme, style, layout, cursor)
     }
 
     fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
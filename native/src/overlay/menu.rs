@@ -4,6 +4,7 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
+use crate::overlay::position::{self, Side, Strategy};
 use crate::renderer;
 use crate::text::{self, Text};
 use crate::touch;
@@ -11,8 +12,8 @@ use crate::widget::container::{self, Container};
 use crate::widget::scrollable::{self, Scrollable};
 use crate::widget::Tree;
 use crate::{
-    Clipboard, Color, Element, Layout, Length, Padding, Point, Rectangle,
-    Shell, Size, Vector, Widget,
+    Alignment, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
 };
 
 pub use iced_style::menu::{Appearance, StyleSheet};
@@ -26,12 +27,14 @@ where
 {
     state: &'a mut State,
     options: &'a [T],
+    headers: &'a [Header],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
     width: u16,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
+    strategy: Strategy,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -53,16 +56,27 @@ where
         Menu {
             state,
             options,
+            headers: &[],
             hovered_option,
             last_selection,
             width: 0,
             padding: Padding::ZERO,
             text_size: None,
             font: Default::default(),
+            strategy: Strategy::Auto,
             style: Default::default(),
         }
     }
 
+    /// Sets the [`Header`]s of the [`Menu`], sorted by the index of the
+    /// option they are shown before.
+    ///
+    /// Headers cannot be hovered nor selected.
+    pub fn headers(mut self, headers: &'a [Header]) -> Self {
+        self.headers = headers;
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     pub fn width(mut self, width: u16) -> Self {
         self.width = width;
@@ -87,6 +101,14 @@ where
         self
     }
 
+    /// Sets the [`Strategy`] used to keep the [`Menu`] within the viewport.
+    ///
+    /// By default, it is [`Strategy::Auto`].
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     /// Sets the style of the [`Menu`].
     pub fn style(
         mut self,
@@ -99,9 +121,11 @@ where
     /// Turns the [`Menu`] into an overlay [`Element`] at the given target
     /// position.
     ///
-    /// The `target_height` will be used to display the menu either on top
-    /// of the target or under it, depending on the screen position and the
-    /// dimensions of the [`Menu`].
+    /// The [`Menu`] is displayed under the target, which is `target_height`
+    /// tall. Its [`Strategy`] decides whether it can be displayed on top of
+    /// the target instead, or moved to the left if it would overflow the
+    /// right edge of the screen. Either way, the [`Menu`] becomes scrollable
+    /// when it is taller than the space available.
     pub fn overlay<Message: 'a>(
         self,
         position: Point,
@@ -114,6 +138,16 @@ where
     }
 }
 
+/// A header of a [`Menu`], labeling a group of its options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    /// The label of the [`Header`].
+    pub label: String,
+
+    /// The index of the option that the [`Header`] is shown before.
+    pub index: usize,
+}
+
 /// The local state of a [`Menu`].
 #[derive(Debug)]
 pub struct State {
@@ -144,6 +178,7 @@ where
     container: Container<'a, Message, Renderer>,
     width: u16,
     target_height: f32,
+    strategy: Strategy,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -162,17 +197,20 @@ where
         let Menu {
             state,
             options,
+            headers,
             hovered_option,
             last_selection,
             width,
             padding,
             font,
             text_size,
+            strategy,
             style,
         } = menu;
 
         let container = Container::new(Scrollable::new(List {
             options,
+            headers,
             hovered_option,
             last_selection,
             font,
@@ -188,6 +226,7 @@ where
             container,
             width,
             target_height,
+            strategy,
             style,
         }
     }
@@ -208,26 +247,54 @@ where
         let space_below = bounds.height - (position.y + self.target_height);
         let space_above = position.y;
 
-        let limits = layout::Limits::new(
-            Size::ZERO,
-            Size::new(
-                bounds.width - position.x,
-                if space_below > space_above {
-                    space_below
-                } else {
-                    space_above
-                },
-            ),
-        )
-        .width(Length::Units(self.width));
+        let viewport = Rectangle::with_size(bounds);
+        let anchor = Rectangle::new(
+            position,
+            Size::new(f32::from(self.width), self.target_height),
+        );
 
-        let mut node = self.container.layout(renderer, &limits);
+        let layout = |max_height: f32| {
+            let limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(bounds.width, max_height.max(0.0)),
+            )
+            .width(Length::Units(self.width));
+
+            self.container.layout(renderer, &limits)
+        };
+
+        let place = |size| {
+            position::place(
+                anchor,
+                size,
+                Side::Bottom,
+                Alignment::Start,
+                0.0,
+                viewport,
+                self.strategy,
+            )
+        };
 
-        node.move_to(if space_below > space_above {
-            position + Vector::new(0.0, self.target_height)
+        let mut node = layout(if self.strategy.flips() {
+            space_below.max(space_above)
         } else {
-            position - Vector::new(0.0, node.size().height)
+            space_below
         });
+        let mut placement = place(node.size());
+
+        let space = if placement.y + placement.height <= position.y {
+            space_above
+        } else {
+            space_below
+        };
+
+        // The menu is scrollable, so it can shrink to fit the chosen side
+        if node.size().height > space {
+            node = layout(space);
+            placement = place(node.size());
+        }
+
+        node.move_to(placement.position());
 
         node
     }
@@ -236,36 +303,25 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         self.container.on_event(
-            self.state,
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
+            self.state, event, layout, cursor, renderer, clipboard, shell,
         )
     }
 
     fn mouse_interaction(
         &self,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.container.mouse_interaction(
-            self.state,
-            layout,
-            cursor_position,
-            viewport,
-            renderer,
-        )
+        self.container
+            .mouse_interaction(self.state, layout, cursor, viewport, renderer)
     }
 
     fn draw(
@@ -274,7 +330,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) {
         let appearance = theme.appearance(&self.style);
         let bounds = layout.bounds();
@@ -289,15 +345,8 @@ where
             appearance.background,
         );
 
-        self.container.draw(
-            self.state,
-            renderer,
-            theme,
-            style,
-            layout,
-            cursor_position,
-            &bounds,
-        );
+        self.container
+            .draw(self.state, renderer, theme, style, layout, cursor, &bounds);
     }
 }
 
@@ -307,6 +356,7 @@ where
     Renderer::Theme: StyleSheet,
 {
     options: &'a [T],
+    headers: &'a [Header],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
     padding: Padding,
@@ -315,6 +365,76 @@ where
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
+/// A row of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row<'a> {
+    Header(&'a str),
+    Option(usize),
+}
+
+impl<'a, T, Renderer> List<'a, T, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the amount of rows of the [`List`], including its headers.
+    fn rows(&self) -> usize {
+        self.options.len() + self.headers.len()
+    }
+
+    /// Returns the [`Row`] at the given index, if any.
+    fn row(&self, index: usize) -> Option<Row<'a>> {
+        row(self.headers, self.options.len(), index)
+    }
+
+    /// Returns the index of the option at the given position of the
+    /// [`List`], if any.
+    fn option_at(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+        renderer: &Renderer,
+    ) -> Option<usize> {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        let index = ((cursor_position.y - bounds.y)
+            / f32::from(text_size + self.padding.vertical()))
+            as usize;
+
+        match self.row(index)? {
+            Row::Option(option) => Some(option),
+            Row::Header(_) => None,
+        }
+    }
+}
+
+/// Returns the [`Row`] at the given index of a list of `options` with the
+/// given `headers`.
+fn row(headers: &[Header], options: usize, index: usize) -> Option<Row<'_>> {
+    let mut headers_before = 0;
+
+    for header in headers {
+        let header_row = header.index + headers_before;
+
+        if header_row == index {
+            return Some(Row::Header(&header.label));
+        } else if header_row > index {
+            break;
+        }
+
+        headers_before += 1;
+    }
+
+    let option = index - headers_before;
+
+    if option < options {
+        Some(Row::Option(option))
+    } else {
+        None
+    }
+}
+
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
     for List<'a, T, Renderer>
 where
@@ -345,7 +465,7 @@ where
             let intrinsic = Size::new(
                 0.0,
                 f32::from(text_size + self.padding.vertical())
-                    * self.options.len() as f32,
+                    * self.rows() as f32,
             );
 
             limits.resolve(intrinsic)
@@ -359,7 +479,7 @@ where
         _state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         _shell: &mut Shell<'_, Message>,
@@ -368,7 +488,7 @@ where
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
+                if cursor.is_over(&bounds) {
                     if let Some(index) = *self.hovered_option {
                         if let Some(option) = self.options.get(index) {
                             *self.last_selection = Some(option.clone());
@@ -379,31 +499,17 @@ where
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
-                    let text_size = self
-                        .text_size
-                        .unwrap_or_else(|| renderer.default_size());
-
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding.vertical()))
-                            as usize,
-                    );
+                if let Some(cursor_position) = cursor.position_over(&bounds) {
+                    *self.hovered_option =
+                        self.option_at(bounds, cursor_position, renderer);
                 }
             }
             Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
-                    let text_size = self
-                        .text_size
-                        .unwrap_or_else(|| renderer.default_size());
-
-                    *self.hovered_option = Some(
-                        ((cursor_position.y - bounds.y)
-                            / f32::from(text_size + self.padding.vertical()))
-                            as usize,
-                    );
+                if let Some(cursor_position) = cursor.position_over(&bounds) {
+                    *self.hovered_option =
+                        self.option_at(bounds, cursor_position, renderer);
 
                     if let Some(index) = *self.hovered_option {
                         if let Some(option) = self.options.get(index) {
@@ -422,11 +528,11 @@ where
         &self,
         _state: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        let is_mouse_over = layout.bounds().contains(cursor_position);
+        let is_mouse_over = cursor.is_over(&layout.bounds());
 
         if is_mouse_over {
             mouse::Interaction::Pointer
@@ -442,10 +548,11 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         let appearance = theme.appearance(&self.style);
+        let header_color = theme.header_color(&self.style);
         let bounds = layout.bounds();
 
         let text_size =
@@ -457,12 +564,7 @@ where
         let end =
             ((offset + viewport.height) / option_height as f32).ceil() as usize;
 
-        let visible_options = &self.options[start..end.min(self.options.len())];
-
-        for (i, option) in visible_options.iter().enumerate() {
-            let i = start + i;
-            let is_selected = *self.hovered_option == Some(i);
-
+        for i in start..end.min(self.rows()) {
             let bounds = Rectangle {
                 x: bounds.x,
                 y: bounds.y + (option_height * i) as f32,
@@ -470,6 +572,34 @@ where
                 height: f32::from(text_size + self.padding.vertical()),
             };
 
+            let index = match self.row(i) {
+                Some(Row::Option(index)) => index,
+                Some(Row::Header(label)) => {
+                    renderer.fill_text(Text {
+                        content: label,
+                        bounds: Rectangle {
+                            x: bounds.x + self.padding.left as f32,
+                            y: bounds.center_y(),
+                            width: f32::INFINITY,
+                            ..bounds
+                        },
+                        size: f32::from(text_size),
+                        line_height: text::LineHeight::default(),
+                        letter_spacing: 0.0,
+                        font: self.font.clone(),
+                        color: header_color,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+
+                    continue;
+                }
+                None => break,
+            };
+
+            let option = &self.options[index];
+            let is_selected = *self.hovered_option == Some(index);
+
             if is_selected {
                 renderer.fill_quad(
                     renderer::Quad {
@@ -491,6 +621,8 @@ where
                     ..bounds
                 },
                 size: f32::from(text_size),
+                line_height: text::LineHeight::default(),
+                letter_spacing: 0.0,
                 font: self.font.clone(),
                 color: if is_selected {
                     appearance.selected_text_color
@@ -516,3 +648,37 @@ where
         Element::new(list)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headers_are_placed_before_their_options() {
+        let headers = [
+            Header {
+                label: String::from("Fruits"),
+                index: 0,
+            },
+            Header {
+                label: String::from("Vegetables"),
+                index: 2,
+            },
+        ];
+
+        let rows: Vec<_> =
+            (0..6).map(|index| row(&headers, 3, index)).collect();
+
+        assert_eq!(
+            rows,
+            [
+                Some(Row::Header("Fruits")),
+                Some(Row::Option(0)),
+                Some(Row::Option(1)),
+                Some(Row::Header("Vegetables")),
+                Some(Row::Option(2)),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Keep overlays within the viewport.
+use crate::{Alignment, Point, Rectangle, Size};
+
+/// The side of an anchor where an overlay is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    Bottom,
+    /// On the left of the anchor.
+    Left,
+    /// On the right of the anchor.
+    Right,
+}
+
+impl Side {
+    /// Returns the opposite [`Side`].
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Side::Top | Side::Bottom)
+    }
+}
+
+/// The strategy used to keep an overlay within the viewport when it would
+/// overflow it.
+///
+/// The main axis of an overlay is the one that goes from its anchor to the
+/// overlay—the vertical axis for [`Side::Top`] and [`Side::Bottom`]—while
+/// its cross axis is the other one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// The overlay is placed as requested, even if it overflows.
+    None,
+
+    /// The overlay is moved to the opposite side of its anchor when it
+    /// overflows the viewport along its main axis and the opposite side has
+    /// more room.
+    Flip,
+
+    /// The overlay is nudged back within the viewport along its cross axis.
+    ///
+    /// The overlay never moves along its main axis, so it never covers its
+    /// anchor.
+    Shift,
+
+    /// The overlay is flipped and then shifted.
+    ///
+    /// As a last resort, when the overlay fits on neither side of its anchor,
+    /// it is also nudged back within the viewport along its main axis, which
+    /// covers the anchor.
+    Auto,
+}
+
+impl Strategy {
+    pub(crate) fn flips(self) -> bool {
+        matches!(self, Strategy::Flip | Strategy::Auto)
+    }
+
+    fn shifts(self) -> bool {
+        matches!(self, Strategy::Shift | Strategy::Auto)
+    }
+}
+
+impl Default for Strategy {
+    fn default() -> Self {
+        Strategy::Auto
+    }
+}
+
+/// Computes the bounds of an overlay with the given [`Size`], placed on the
+/// given [`Side`] of its `anchor` and separated from it by a `gap`.
+///
+/// The `alignment` positions the overlay along the cross axis of the
+/// anchor: [`Alignment::Start`] lines up their left or top edges, for
+/// instance. The [`Strategy`] is then used to keep the overlay within the
+/// `viewport`.
+///
+/// ```
+/// use iced_native::overlay::position::{self, Side, Strategy};
+/// use iced_native::{Alignment, Point, Rectangle, Size};
+///
+/// let viewport = Rectangle::with_size(Size::new(100.0, 100.0));
+/// let anchor = Rectangle::new(Point::new(10.0, 80.0), Size::new(20.0, 10.0));
+///
+/// // There is no room below the anchor, so the overlay flips above it
+/// let bounds = position::place(
+///     anchor,
+///     Size::new(40.0, 30.0),
+///     Side::Bottom,
+///     Alignment::Center,
+///     0.0,
+///     viewport,
+///     Strategy::Auto,
+/// );
+///
+/// assert_eq!(bounds.position(), Point::new(0.0, 50.0));
+/// ```
+pub fn place(
+    anchor: Rectangle,
+    size: Size,
+    side: Side,
+    alignment: Alignment,
+    gap: f32,
+    viewport: Rectangle,
+    strategy: Strategy,
+) -> Rectangle {
+    let side = if strategy.flips() {
+        flip(anchor, size, side, gap, viewport)
+    } else {
+        side
+    };
+
+    let mut bounds = attach(anchor, size, side, alignment, gap);
+
+    if strategy.shifts() {
+        if side.is_vertical() {
+            bounds.x = shift(bounds.x, size.width, viewport.x, viewport.width);
+        } else {
+            bounds.y =
+                shift(bounds.y, size.height, viewport.y, viewport.height);
+        }
+    }
+
+    if strategy == Strategy::Auto
+        && space(anchor, side, gap, viewport) < length(size, side)
+    {
+        // The overlay fits nowhere, so keeping it visible comes first
+        if side.is_vertical() {
+            bounds.y =
+                shift(bounds.y, size.height, viewport.y, viewport.height);
+        } else {
+            bounds.x = shift(bounds.x, size.width, viewport.x, viewport.width);
+        }
+    }
+
+    bounds
+}
+
+/// Returns the [`Side`] where an overlay fits best, preferring the given
+/// one.
+fn flip(
+    anchor: Rectangle,
+    size: Size,
+    side: Side,
+    gap: f32,
+    viewport: Rectangle,
+) -> Side {
+    let available = space(anchor, side, gap, viewport);
+    let opposite = side.opposite();
+
+    if available < length(size, side)
+        && space(anchor, opposite, gap, viewport) > available
+    {
+        opposite
+    } else {
+        side
+    }
+}
+
+/// Returns the room left in the viewport on the given [`Side`] of the
+/// anchor, after the gap.
+fn space(anchor: Rectangle, side: Side, gap: f32, viewport: Rectangle) -> f32 {
+    match side {
+        Side::Top => anchor.y - gap - viewport.y,
+        Side::Bottom => {
+            viewport.y + viewport.height - (anchor.y + anchor.height) - gap
+        }
+        Side::Left => anchor.x - gap - viewport.x,
+        Side::Right => {
+            viewport.x + viewport.width - (anchor.x + anchor.width) - gap
+        }
+    }
+}
+
+/// Returns the length of an overlay along its main axis.
+fn length(size: Size, side: Side) -> f32 {
+    if side.is_vertical() {
+        size.height
+    } else {
+        size.width
+    }
+}
+
+/// Places an overlay right next to the anchor on the given [`Side`].
+fn attach(
+    anchor: Rectangle,
+    size: Size,
+    side: Side,
+    alignment: Alignment,
+    gap: f32,
+) -> Rectangle {
+    let align = |start: f32, anchor_length: f32, length: f32| match alignment {
+        Alignment::Center => start + (anchor_length - length) / 2.0,
+        Alignment::End => start + anchor_length - length,
+        Alignment::Start | Alignment::Fill | Alignment::Baseline => start,
+    };
+
+    let position = match side {
+        Side::Top => Point::new(
+            align(anchor.x, anchor.width, size.width),
+            anchor.y - gap - size.height,
+        ),
+        Side::Bottom => Point::new(
+            align(anchor.x, anchor.width, size.width),
+            anchor.y + anchor.height + gap,
+        ),
+        Side::Left => Point::new(
+            anchor.x - gap - size.width,
+            align(anchor.y, anchor.height, size.height),
+        ),
+        Side::Right => Point::new(
+            anchor.x + anchor.width + gap,
+            align(anchor.y, anchor.height, size.height),
+        ),
+    };
+
+    Rectangle::new(position, size)
+}
+
+/// Moves a segment of the given `length` within the viewport segment, if
+/// possible. Otherwise, it is aligned to the start of the viewport.
+fn shift(start: f32, length: f32, min: f32, extent: f32) -> f32 {
+    if length >= extent {
+        min
+    } else {
+        start.clamp(min, min + extent - length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    fn place_at(
+        anchor: Rectangle,
+        size: Size,
+        side: Side,
+        strategy: Strategy,
+    ) -> Point {
+        place(
+            anchor,
+            size,
+            side,
+            Alignment::Start,
+            5.0,
+            VIEWPORT,
+            strategy,
+        )
+        .position()
+    }
+
+    #[test]
+    fn flipping_keeps_the_overlay_attached() {
+        let anchor =
+            Rectangle::new(Point::new(80.0, 70.0), Size::new(10.0, 10.0));
+        let size = Size::new(40.0, 30.0);
+
+        assert_eq!(
+            place_at(anchor, size, Side::Bottom, Strategy::None),
+            Point::new(80.0, 85.0)
+        );
+        assert_eq!(
+            place_at(anchor, size, Side::Bottom, Strategy::Flip),
+            Point::new(80.0, 35.0)
+        );
+        assert_eq!(
+            place_at(anchor, size, Side::Right, Strategy::Flip),
+            Point::new(35.0, 70.0)
+        );
+
+        // The overlay fits where it was requested
+        assert_eq!(
+            place_at(anchor, size, Side::Top, Strategy::Flip),
+            Point::new(80.0, 35.0)
+        );
+    }
+
+    #[test]
+    fn shifting_never_covers_the_anchor() {
+        let anchor =
+            Rectangle::new(Point::new(80.0, 70.0), Size::new(10.0, 10.0));
+        let size = Size::new(40.0, 30.0);
+
+        assert_eq!(
+            place_at(anchor, size, Side::Bottom, Strategy::Shift),
+            Point::new(60.0, 85.0)
+        );
+        assert_eq!(
+            place_at(anchor, size, Side::Bottom, Strategy::Auto),
+            Point::new(60.0, 35.0)
+        );
+    }
+
+    #[test]
+    fn overlays_that_fit_nowhere_stay_visible() {
+        let anchor =
+            Rectangle::new(Point::new(10.0, 40.0), Size::new(10.0, 20.0));
+        let size = Size::new(20.0, 60.0);
+
+        // There is more room below, but not enough
+        assert_eq!(
+            place_at(anchor, size, Side::Bottom, Strategy::Flip),
+            Point::new(10.0, 65.0)
+        );
+        assert_eq!(
+            place_at(anchor, size, Side::Bottom, Strategy::Auto),
+            Point::new(10.0, 40.0)
+        );
+
+        // Overlays larger than the viewport start at its edge
+        assert_eq!(
+            place_at(anchor, Size::new(20.0, 200.0), Side::Top, Strategy::Auto),
+            Point::new(10.0, 0.0)
+        );
+    }
+}
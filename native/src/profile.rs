@@ -0,0 +1,205 @@
+//! Profile the frames of an application.
+pub mod overlay;
+
+pub use overlay::Overlay;
+
+use crate::time::{Duration, Instant};
+
+use std::fmt;
+use std::sync::Arc;
+
+/// The timings and statistics of a single frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Frame {
+    /// The [`Kind`] of the [`Frame`].
+    pub kind: Kind,
+
+    /// The time spent processing events in the widget tree.
+    pub event_processing: Duration,
+
+    /// The time spent updating the application with new messages.
+    pub update: Duration,
+
+    /// The time spent building and laying out the widget tree.
+    ///
+    /// It is zero for [`Kind::Redraw`] frames.
+    pub layout: Duration,
+
+    /// The time spent generating primitives.
+    ///
+    /// Tessellation of canvas geometry happens while drawing, so it is
+    /// included here.
+    pub draw: Duration,
+
+    /// The time spent presenting the primitives to the window surface.
+    pub render: Duration,
+
+    /// The amount of primitives presented, including nested ones.
+    pub primitives: usize,
+
+    /// The amount of meshes presented.
+    pub meshes: usize,
+}
+
+/// The kind of a [`Frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// The widget tree was built and laid out again.
+    Relayout,
+
+    /// The existing layout was redrawn.
+    Redraw,
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Self::Redraw
+    }
+}
+
+/// The amount of primitives and meshes of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Count {
+    /// The amount of primitives, including nested ones.
+    pub primitives: usize,
+
+    /// The amount of meshes.
+    pub meshes: usize,
+}
+
+/// A profiling hook that is called by the runtime after every presented
+/// [`Frame`].
+///
+/// The hook runs on the event loop, so it should return quickly.
+#[derive(Clone)]
+pub struct Hook(Arc<dyn Fn(&Frame) + Send + Sync>);
+
+impl Hook {
+    /// Creates a new [`Hook`] from the given function.
+    pub fn new(f: impl Fn(&Frame) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+}
+
+impl fmt::Debug for Hook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Hook").finish()
+    }
+}
+
+/// The phase of a [`Frame`] measured by a [`Profiler`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// See [`Frame::event_processing`].
+    EventProcessing,
+
+    /// See [`Frame::update`].
+    Update,
+
+    /// See [`Frame::layout`].
+    Layout,
+
+    /// See [`Frame::draw`].
+    Draw,
+
+    /// See [`Frame::render`].
+    Render,
+}
+
+/// Records the [`Frame`] timings of a runtime and reports them to a [`Hook`]
+/// and an [`Overlay`].
+///
+/// When no [`Hook`] is set and the [`Overlay`] is hidden, the [`Profiler`]
+/// does not query the clock and does not allocate.
+#[derive(Debug)]
+pub struct Profiler {
+    hook: Option<Hook>,
+    overlay: Overlay,
+    frame: Frame,
+    starts: [Option<Instant>; 5],
+}
+
+impl Profiler {
+    /// Creates a new [`Profiler`] reporting to the given [`Hook`], if any,
+    /// and to an [`Overlay`] with the given [`overlay::Settings`].
+    pub fn new(hook: Option<Hook>, overlay: overlay::Settings) -> Self {
+        Self {
+            hook,
+            overlay: Overlay::new(overlay),
+            frame: Frame::default(),
+            starts: [None; 5],
+        }
+    }
+
+    /// Returns whether the [`Profiler`] is recording timings.
+    pub fn is_enabled(&self) -> bool {
+        self.hook.is_some() || self.overlay.is_visible()
+    }
+
+    /// Returns the [`Overlay`] of the [`Profiler`].
+    pub fn overlay(&self) -> &Overlay {
+        &self.overlay
+    }
+
+    /// Returns a mutable reference to the [`Overlay`] of the [`Profiler`].
+    pub fn overlay_mut(&mut self) -> &mut Overlay {
+        &mut self.overlay
+    }
+
+    /// Marks the start of the given [`Phase`].
+    pub fn phase_started(&mut self, phase: Phase) {
+        if self.is_enabled() {
+            self.starts[phase as usize] = Some(Instant::now());
+        }
+    }
+
+    /// Marks the end of the given [`Phase`], adding its duration to the
+    /// current [`Frame`].
+    ///
+    /// Finishing a [`Phase::Layout`] marks the [`Frame`] as a
+    /// [`Kind::Relayout`].
+    pub fn phase_finished(&mut self, phase: Phase) {
+        let start = match self.starts[phase as usize].take() {
+            Some(start) => start,
+            None => return,
+        };
+
+        let duration = Instant::now() - start;
+        let frame = &mut self.frame;
+
+        match phase {
+            Phase::EventProcessing => frame.event_processing += duration,
+            Phase::Update => frame.update += duration,
+            Phase::Layout => {
+                frame.layout += duration;
+                frame.kind = Kind::Relayout;
+            }
+            Phase::Draw => frame.draw += duration,
+            Phase::Render => frame.render += duration,
+        }
+    }
+
+    /// Finishes the current [`Frame`] and reports it to the [`Hook`] and the
+    /// [`Overlay`].
+    ///
+    /// The [`Count`] is only computed when the [`Profiler`] is enabled.
+    pub fn frame_finished(&mut self, count: impl FnOnce() -> Count) {
+        if !self.is_enabled() {
+            self.frame = Frame::default();
+            return;
+        }
+
+        let count = count();
+        let frame = Frame {
+            primitives: count.primitives,
+            meshes: count.meshes,
+            ..std::mem::take(&mut self.frame)
+        };
+
+        if let Some(hook) = &self.hook {
+            (hook.0)(&frame);
+        }
+
+        self.overlay.record(frame);
+    }
+}
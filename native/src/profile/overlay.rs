@@ -0,0 +1,266 @@
+//! Display frame statistics on top of an application.
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::profile::{Frame, Kind};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
+use crate::{alignment, Color, Point, Rectangle, Size};
+
+use std::collections::VecDeque;
+
+const TEXT_SIZE: u16 = 14;
+const MARGIN: f32 = 8.0;
+const PADDING: f32 = 6.0;
+
+/// The settings of an [`Overlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    /// Whether the [`Overlay`] is visible at startup.
+    pub visible: bool,
+
+    /// The [`Corner`] of the window where the [`Overlay`] is displayed.
+    pub corner: Corner,
+
+    /// The amount of information displayed by the [`Overlay`].
+    pub verbosity: Verbosity,
+
+    /// The key that toggles the visibility of the [`Overlay`], if any.
+    pub shortcut: Option<keyboard::KeyCode>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            corner: Corner::TopRight,
+            verbosity: Verbosity::Compact,
+            shortcut: None,
+        }
+    }
+}
+
+/// A corner of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top-left corner.
+    TopLeft,
+
+    /// The top-right corner.
+    TopRight,
+
+    /// The bottom-left corner.
+    BottomLeft,
+
+    /// The bottom-right corner.
+    BottomRight,
+}
+
+/// The amount of information displayed by an [`Overlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Verbosity {
+    /// The frames per second, the average frame time and the amount of
+    /// primitives.
+    Compact,
+
+    /// Everything in [`Verbosity::Compact`], plus the time spent in every
+    /// phase of a frame and the amount of meshes.
+    Detailed,
+}
+
+/// An overlay displaying the frame statistics of the last second.
+///
+/// The [`Overlay`] is drawn after the user interface and outside of any
+/// measured phase, so it does not count towards the frame time it displays.
+/// It is drawn in logical coordinates and, therefore, respects the scale
+/// factor of the window.
+#[derive(Debug)]
+pub struct Overlay {
+    settings: Settings,
+    is_visible: bool,
+    frames: VecDeque<(Instant, Frame)>,
+}
+
+impl Overlay {
+    /// Creates a new [`Overlay`] with the given [`Settings`].
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            settings,
+            is_visible: settings.visible,
+            frames: VecDeque::new(),
+        }
+    }
+
+    /// Returns whether the [`Overlay`] is visible.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Toggles the visibility of the [`Overlay`].
+    pub fn toggle(&mut self) {
+        self.is_visible = !self.is_visible;
+        self.frames.clear();
+    }
+
+    /// Processes an [`Event`], toggling the [`Overlay`] if its shortcut was
+    /// pressed.
+    ///
+    /// The [`Event`] is never captured.
+    pub fn update(&mut self, event: &Event) -> event::Status {
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code, ..
+        }) = event
+        {
+            if Some(*key_code) == self.settings.shortcut {
+                self.toggle();
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    /// Records a presented [`Frame`].
+    pub fn record(&mut self, frame: Frame) {
+        if !self.is_visible {
+            return;
+        }
+
+        let now = Instant::now();
+
+        while let Some((presented_at, _)) = self.frames.front() {
+            if now - *presented_at > Duration::from_secs(1) {
+                let _ = self.frames.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        self.frames.push_back((now, frame));
+    }
+
+    /// Draws the [`Overlay`] in a window of the given logical size.
+    pub fn draw<Renderer>(&self, renderer: &mut Renderer, viewport: Size)
+    where
+        Renderer: text::Renderer,
+    {
+        if !self.is_visible {
+            return;
+        }
+
+        let lines = self.lines();
+        let font = Renderer::Font::default();
+
+        let width = lines
+            .iter()
+            .map(|line| renderer.measure_width(line, TEXT_SIZE, font.clone()))
+            .fold(0.0, f32::max);
+
+        let line_height = f32::from(TEXT_SIZE) * 1.2;
+
+        let size = Size::new(
+            width + PADDING * 2.0,
+            line_height * lines.len() as f32 + PADDING * 2.0,
+        );
+
+        let position = match self.settings.corner {
+            Corner::TopLeft => Point::new(MARGIN, MARGIN),
+            Corner::TopRight => {
+                Point::new(viewport.width - size.width - MARGIN, MARGIN)
+            }
+            Corner::BottomLeft => {
+                Point::new(MARGIN, viewport.height - size.height - MARGIN)
+            }
+            Corner::BottomRight => Point::new(
+                viewport.width - size.width - MARGIN,
+                viewport.height - size.height - MARGIN,
+            ),
+        };
+
+        let bounds = Rectangle::new(position, size);
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 4.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Color::from_rgba(0.0, 0.0, 0.0, 0.7),
+            );
+
+            for (i, line) in lines.iter().enumerate() {
+                renderer.fill_text(Text {
+                    content: line,
+                    bounds: Rectangle {
+                        x: bounds.x + PADDING,
+                        y: bounds.y + PADDING + line_height * i as f32,
+                        width: width + 1.0,
+                        height: line_height,
+                    },
+                    size: f32::from(TEXT_SIZE),
+                    line_height: text::LineHeight::default(),
+                    letter_spacing: 0.0,
+                    color: Color::WHITE,
+                    font: font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                });
+            }
+        });
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let count = self.frames.len().max(1) as u32;
+        let average = |phase: fn(&Frame) -> Duration| {
+            self.frames
+                .iter()
+                .map(|(_, frame)| phase(frame))
+                .sum::<Duration>()
+                / count
+        };
+
+        let frame_time = average(|frame| {
+            frame.event_processing
+                + frame.update
+                + frame.layout
+                + frame.draw
+                + frame.render
+        });
+
+        let last = self
+            .frames
+            .back()
+            .map(|(_, frame)| *frame)
+            .unwrap_or_default();
+
+        let mut lines = vec![
+            format!("FPS: {}", self.frames.len()),
+            format!("Frame time: {frame_time:.2?}"),
+            format!("Primitives: {}", last.primitives),
+        ];
+
+        if self.settings.verbosity == Verbosity::Detailed {
+            let relayouts = self
+                .frames
+                .iter()
+                .filter(|(_, frame)| frame.kind == Kind::Relayout)
+                .count();
+
+            lines.extend([
+                format!("Meshes: {}", last.meshes),
+                format!("Relayouts: {relayouts}"),
+                format!(
+                    "Events: {:.2?}",
+                    average(|frame| frame.event_processing)
+                ),
+                format!("Update: {:.2?}", average(|frame| frame.update)),
+                format!("Layout: {:.2?}", average(|frame| frame.layout)),
+                format!("Draw: {:.2?}", average(|frame| frame.draw)),
+                format!("Render: {:.2?}", average(|frame| frame.render)),
+            ]);
+        }
+
+        lines
+    }
+}
@@ -3,7 +3,7 @@ use crate::event::{self, Event};
 use crate::mouse;
 use crate::renderer;
 use crate::user_interface::{self, UserInterface};
-use crate::{Clipboard, Command, Debug, Point, Program, Size};
+use crate::{Clipboard, Command, Debug, Program, Size};
 
 /// The execution state of a [`Program`]. It leverages caching, event
 /// processing, and rendering primitive storage.
@@ -89,7 +89,7 @@ where
     pub fn update(
         &mut self,
         bounds: Size,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &mut P::Renderer,
         theme: &<P::Renderer as crate::Renderer>::Theme,
         style: &renderer::Style,
@@ -109,7 +109,7 @@ where
 
         let (_, event_statuses) = user_interface.update(
             &self.queued_events,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             &mut messages,
@@ -132,7 +132,7 @@ where
         let command = if messages.is_empty() {
             debug.draw_started();
             self.mouse_interaction =
-                user_interface.draw(renderer, theme, style, cursor_position);
+                user_interface.draw(renderer, theme, style, cursor);
             debug.draw_finished();
 
             self.cache = Some(user_interface.into_cache());
@@ -164,7 +164,7 @@ where
 
             debug.draw_started();
             self.mouse_interaction =
-                user_interface.draw(renderer, theme, style, cursor_position);
+                user_interface.draw(renderer, theme, style, cursor);
             debug.draw_finished();
 
             self.cache = Some(user_interface.into_cache());
@@ -4,10 +4,32 @@ mod null;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
+#[cfg(test)]
+mod recorder;
+#[cfg(test)]
+pub use recorder::Recorder;
+
 use crate::layout;
 use crate::{Background, Color, Element, Rectangle, Vector};
 
 /// A component that can be used by widgets to draw themselves on a screen.
+///
+/// # Drawing order
+/// Primitives are drawn in the order they are recorded, so a widget is drawn
+/// on top of the siblings drawn before it. The order is a pure function of
+/// the recorded primitives, so it stays the same across frames.
+///
+/// There are two exceptions:
+///
+/// - The contents of [`Renderer::with_layer`] are drawn on top of everything
+///   recorded outside of the layer, even primitives recorded after it.
+/// - The contents of [`Renderer::with_z_index`] are drawn on top of every
+///   primitive with a lower z-index.
+///
+/// Renderers may also batch primitives of the same kind inside a layer. For
+/// instance, the built-in renderers draw all the quads of a layer before its
+/// text. Use [`Renderer::with_layer`] when the relative order of different
+/// kinds of primitives matters.
 pub trait Renderer: Sized {
     /// The supported theme of the [`Renderer`].
     type Theme;
@@ -27,8 +49,84 @@ pub trait Renderer: Sized {
     /// Draws the primitives recorded in the given closure in a new layer.
     ///
     /// The layer will clip its contents to the provided `bounds`.
+    ///
+    /// The layer is drawn on top of the layer it is recorded in, including
+    /// any primitives recorded in the latter after the new layer.
     fn with_layer(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self));
 
+    /// Draws the primitives recorded in the given closure in a new layer,
+    /// clipping its contents to the provided `bounds` with rounded corners.
+    ///
+    /// Every kind of primitive is clipped alike—quads, text, images, and
+    /// meshes. Nested rounded clips intersect: the contents are only visible
+    /// inside all of them. Like [`Renderer::with_layer`], the layer is drawn
+    /// on top of the layer it is recorded in.
+    ///
+    /// Renderers that do not support rounded clipping clip the contents to
+    /// the `bounds` only, like [`Renderer::with_layer`].
+    ///
+    /// # Performance
+    /// The `wgpu` and `tiny-skia` renderers draw the contents of a rounded
+    /// clip to an offscreen target first and then composite them through the
+    /// rounded bounds. Prefer [`Renderer::with_layer`] when the corners are not
+    /// rounded.
+    fn with_rounded_clip(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let _ = border_radius;
+
+        self.with_layer(bounds, f);
+    }
+
+    /// Draws the primitives recorded in the given closure on top of every
+    /// primitive with a lower `z_index`, regardless of the drawing order.
+    ///
+    /// This is useful for decorations that need to be drawn over sibling
+    /// widgets, like focus rings or badges. The primitives keep the current
+    /// translation and clipping bounds. Primitives with the same `z_index`
+    /// are drawn in the order they are recorded.
+    ///
+    /// Every primitive has a `z_index` of `0` by default. Renderers that do
+    /// not support z-ordering simply draw the primitives in order.
+    fn with_z_index(&mut self, z_index: u16, f: impl FnOnce(&mut Self)) {
+        let _ = z_index;
+
+        f(self);
+    }
+
+    /// Draws the primitives recorded in the given closure with the given
+    /// `opacity`, which multiplies the alpha of all of them uniformly.
+    ///
+    /// Nested calls multiply their opacities. Renderers that do not support
+    /// opacity draw the primitives fully opaque.
+    ///
+    /// This lets custom widgets draw semi-transparent groups of primitives
+    /// without premultiplying each of their colors.
+    ///
+    /// # Performance
+    /// A renderer can implement opacity in two ways:
+    ///
+    /// - By rendering the primitives to an offscreen texture and compositing
+    /// it with the given `opacity`. Overlapping primitives blend correctly,
+    /// but every call needs an additional render pass and a texture as big as
+    /// the bounds of its primitives.
+    /// - By scaling the alpha of every primitive on its own. It costs nothing
+    /// on the GPU and only a pass over the recorded primitives on the CPU, but
+    /// overlapping primitives show through each other.
+    ///
+    /// The `wgpu` and `tiny-skia` renderers composite the primitives
+    /// offscreen, each clipped layer among them on its own. The `glow`
+    /// renderer scales every primitive on its own while drawing it. Neither
+    /// copies the recorded primitives, so cached geometry stays shared.
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let _ = opacity;
+
+        f(self);
+    }
+
     /// Applies a `translation` to the primitives recorded in the given closure.
     fn with_translation(
         &mut self,
@@ -37,6 +135,34 @@ pub trait Renderer: Sized {
     );
 
     /// Fills a [`Quad`] with the provided [`Background`].
+    ///
+    /// This is how the built-in widgets draw their backgrounds and borders,
+    /// and custom widgets can use it the same way. Anything that converts
+    /// into a [`Background`] is accepted, like a [`Color`]. A transparent
+    /// background draws only the border of the [`Quad`].
+    ///
+    /// The [`Quad`] honors the current translation—see
+    /// [`Renderer::with_translation`]—and it is clipped by the bounds of the
+    /// current layer, like any other primitive.
+    ///
+    /// ```
+    /// use iced_native::renderer::{self, Renderer as _};
+    /// use iced_native::{Color, Point, Rectangle, Size};
+    ///
+    /// # let mut renderer = iced_native::renderer::Null::new();
+    /// let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0));
+    ///
+    /// // A rounded rectangle with a red border
+    /// renderer.fill_quad(
+    ///     renderer::Quad {
+    ///         bounds,
+    ///         border_radius: 8.0.into(),
+    ///         border_width: 2.0,
+    ///         border_color: Color::from_rgb(1.0, 0.0, 0.0),
+    ///     },
+    ///     Color::WHITE,
+    /// );
+    /// ```
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
     /// Clears all of the recorded primitives in the [`Renderer`].
@@ -44,15 +170,23 @@ pub trait Renderer: Sized {
 }
 
 /// A polygon with four sides.
+///
+/// It can be drawn with [`Renderer::fill_quad`].
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Quad {
-    /// The bounds of the [`Quad`].
+    /// The bounds of the [`Quad`], relative to the current translation.
     pub bounds: Rectangle,
 
     /// The border radius of the [`Quad`].
+    ///
+    /// A single radius rounds every corner the same, while an array of four
+    /// radii rounds every corner on its own. See [`BorderRadius`].
     pub border_radius: BorderRadius,
 
     /// The border width of the [`Quad`].
+    ///
+    /// The border is drawn inside the bounds of the [`Quad`], on top of its
+    /// background. A width of zero draws no border.
     pub border_width: f32,
 
     /// The border color of the [`Quad`].
@@ -52,6 +52,8 @@ impl text::Renderer for Null {
         &self,
         _content: &str,
         _size: u16,
+        _line_height: text::LineHeight,
+        _letter_spacing: f32,
         _font: Font,
         _bounds: Size,
     ) -> (f32, f32) {
@@ -0,0 +1,238 @@
+use crate::image;
+use crate::renderer::{self, BorderRadius, Renderer};
+use crate::text::{self, Text};
+use crate::{Background, Font, Point, Rectangle, Size, Theme, Vector};
+
+use std::cell::Cell;
+
+/// A renderer that records what is drawn with it.
+///
+/// Text is laid out in cells of 10x20 units, and every image has the
+/// same `image_size`. Everything is recorded with the bounds it would have on
+/// the screen, after any translation.
+#[derive(Debug)]
+pub struct Recorder {
+    /// The bounds and border radius of every layer, in order.
+    pub layers: Vec<(Rectangle, BorderRadius)>,
+
+    /// Every drawn quad, with the opacity it was drawn with.
+    pub quads: Vec<(renderer::Quad, f32)>,
+
+    /// Every drawn image.
+    pub images: Vec<Image>,
+
+    /// The size of every image.
+    pub image_size: Size<u32>,
+
+    /// The status of every image.
+    pub image_status: Cell<image::Status>,
+
+    opacity: f32,
+    translation: Vector,
+}
+
+/// An image drawn with a [`Recorder`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Image {
+    /// The region of the image that was drawn, if not all of it.
+    pub region: Option<Rectangle<u32>>,
+
+    /// The rotation of the image.
+    pub rotation: image::Rotation,
+
+    /// The mirroring of the image.
+    pub flip: image::Flip,
+
+    /// The bounds of the image.
+    pub bounds: Rectangle,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self {
+            layers: Vec::new(),
+            quads: Vec::new(),
+            images: Vec::new(),
+            image_size: Size::new(40, 20),
+            image_status: Cell::new(image::Status::Loaded),
+            opacity: 1.0,
+            translation: Vector::ZERO,
+        }
+    }
+}
+
+impl Renderer for Recorder {
+    type Theme = Theme;
+
+    fn with_layer(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self)) {
+        self.layers
+            .push((bounds + self.translation, BorderRadius::default()));
+
+        f(self);
+    }
+
+    fn with_rounded_clip(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.layers.push((bounds + self.translation, border_radius));
+
+        f(self);
+    }
+
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let parent = self.opacity;
+
+        self.opacity *= opacity;
+        f(self);
+        self.opacity = parent;
+    }
+
+    fn with_translation(
+        &mut self,
+        translation: Vector,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let parent = self.translation;
+
+        self.translation = self.translation + translation;
+        f(self);
+        self.translation = parent;
+    }
+
+    fn clear(&mut self) {}
+
+    fn fill_quad(
+        &mut self,
+        quad: renderer::Quad,
+        _background: impl Into<Background>,
+    ) {
+        self.quads.push((
+            renderer::Quad {
+                bounds: quad.bounds + self.translation,
+                ..quad
+            },
+            self.opacity,
+        ));
+    }
+}
+
+impl text::Renderer for Recorder {
+    type Font = Font;
+
+    const ICON_FONT: Font = Font::Default;
+    const CHECKMARK_ICON: char = '0';
+    const ARROW_DOWN_ICON: char = '0';
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure(
+        &self,
+        content: &str,
+        _size: u16,
+        _line_height: text::LineHeight,
+        letter_spacing: f32,
+        _font: Font,
+        _bounds: Size,
+    ) -> (f32, f32) {
+        let lines = content.split('\n');
+
+        let width = lines
+            .clone()
+            .map(|line| {
+                let count = line.chars().count() as f32;
+
+                count * 10.0 + (count - 1.0).max(0.0) * letter_spacing
+            })
+            .fold(0.0, f32::max);
+
+        (width, lines.count() as f32 * 20.0)
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        _size: f32,
+        _font: Font,
+        _bounds: Size,
+        point: Point,
+        _nearest_only: bool,
+    ) -> Option<text::Hit> {
+        let count = contents.chars().count();
+
+        if count == 0 || contents.contains('\n') {
+            return None;
+        }
+
+        let index = ((point.x / 10.0).floor().max(0.0) as usize).min(count - 1);
+        let center = index as f32 * 10.0 + 5.0;
+
+        Some(text::Hit::NearestCharOffset(
+            index,
+            Vector::new(point.x - center, 0.0),
+        ))
+    }
+
+    fn fill_text(&mut self, _text: Text<'_, Self::Font>) {}
+}
+
+impl image::Renderer for Recorder {
+    type Handle = ();
+
+    fn dimensions(&self, _handle: &()) -> Size<u32> {
+        self.image_size
+    }
+
+    fn load(&self, _handle: &()) -> image::Status {
+        self.image_status.get()
+    }
+
+    fn draw(&mut self, _handle: (), bounds: Rectangle) {
+        self.draw_oriented(
+            (),
+            image::Rotation::None,
+            image::Flip::None,
+            bounds,
+        );
+    }
+
+    fn draw_oriented(
+        &mut self,
+        _handle: (),
+        rotation: image::Rotation,
+        flip: image::Flip,
+        bounds: Rectangle,
+    ) {
+        self.images.push(Image {
+            region: None,
+            rotation,
+            flip,
+            bounds: bounds + self.translation,
+        });
+    }
+
+    fn draw_region(
+        &mut self,
+        _handle: (),
+        region: Rectangle<u32>,
+        bounds: Rectangle,
+    ) {
+        self.images.push(Image {
+            region: Some(region),
+            rotation: image::Rotation::None,
+            flip: image::Flip::None,
+            bounds: bounds + self.translation,
+        });
+    }
+}
@@ -1,4 +1,7 @@
+use crate::event;
+use crate::input_method::InputMethod;
 use crate::window;
+use crate::Vector;
 
 /// A connection to the state of a shell.
 ///
@@ -10,8 +13,12 @@ use crate::window;
 pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     redraw_request: Option<window::RedrawRequest>,
+    input_method: InputMethod,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    overscroll: Option<Vector>,
+    #[cfg(feature = "debug")]
+    trace: Option<event::Trace>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -20,11 +27,73 @@ impl<'a, Message> Shell<'a, Message> {
         Self {
             messages,
             redraw_request: None,
+            input_method: InputMethod::Disabled,
             is_layout_invalid: false,
             are_widgets_invalid: false,
+            overscroll: None,
+            #[cfg(feature = "debug")]
+            trace: None,
         }
     }
 
+    /// Creates a new [`Shell`] with the provided buffer of messages, which
+    /// takes over the event [`Trace`] of the current one, if any.
+    ///
+    /// The [`Trace`] is given back once the nested [`Shell`] is merged with
+    /// [`Shell::merge`]. This method is useful for composition.
+    ///
+    /// [`Trace`]: crate::event::Trace
+    pub fn nest<'b, B>(&mut self, messages: &'b mut Vec<B>) -> Shell<'b, B> {
+        #[allow(unused_mut)]
+        let mut shell = Shell::new(messages);
+
+        #[cfg(feature = "debug")]
+        {
+            shell.trace = self.trace.take();
+        }
+
+        shell
+    }
+
+    /// Dispatches an event to the child with the given `index` using the
+    /// provided function.
+    ///
+    /// When an event is being traced, the child is recorded as visited
+    /// together with the resulting [`event::Status`]. Otherwise, the function
+    /// is simply called.
+    pub fn traced(
+        &mut self,
+        index: usize,
+        on_event: impl FnOnce(&mut Self) -> event::Status,
+    ) -> event::Status {
+        #[cfg(feature = "debug")]
+        if let Some(trace) = &mut self.trace {
+            trace.enter(index);
+
+            let status = on_event(self);
+
+            if let Some(trace) = &mut self.trace {
+                trace.exit(status);
+            }
+
+            return status;
+        }
+
+        let _ = index;
+
+        on_event(self)
+    }
+
+    #[cfg(feature = "debug")]
+    pub(crate) fn start_trace(&mut self, trace: event::Trace) {
+        self.trace = Some(trace);
+    }
+
+    #[cfg(feature = "debug")]
+    pub(crate) fn finish_trace(&mut self) -> Option<event::Trace> {
+        self.trace.take()
+    }
+
     /// Returns true if the [`Shell`] contains no published messages
     pub fn is_empty(&self) -> bool {
         self.messages.is_empty()
@@ -53,6 +122,22 @@ impl<'a, Message> Shell<'a, Message> {
         self.redraw_request
     }
 
+    /// Requests the given [`InputMethod`] for the current window.
+    ///
+    /// A focused widget that accepts text should request an enabled
+    /// [`InputMethod`] every time it processes a [`RedrawRequested`] event.
+    /// The input method is disabled when no widget requests it.
+    ///
+    /// [`RedrawRequested`]: crate::window::Event::RedrawRequested
+    pub fn request_input_method(&mut self, input_method: InputMethod) {
+        self.input_method = self.input_method.merge(input_method);
+    }
+
+    /// Returns the [`InputMethod`] requested by the widgets, if any.
+    pub fn input_method(&self) -> InputMethod {
+        self.input_method
+    }
+
     /// Returns whether the current layout is invalid or not.
     pub fn is_layout_invalid(&self) -> bool {
         self.is_layout_invalid
@@ -88,6 +173,23 @@ impl<'a, Message> Shell<'a, Message> {
         self.are_widgets_invalid = true;
     }
 
+    /// Reports an amount of scrolling that could not be consumed while
+    /// processing the current event, so an ancestor can consume it instead.
+    ///
+    /// The delta follows the convention of [`mouse::ScrollDelta`], in
+    /// pixels.
+    ///
+    /// [`mouse::ScrollDelta`]: crate::mouse::ScrollDelta
+    pub fn overscroll(&mut self, delta: Vector) {
+        self.overscroll = Some(delta);
+    }
+
+    /// Takes the amount of scrolling reported with [`Shell::overscroll`], if
+    /// any.
+    pub fn take_overscroll(&mut self) -> Option<Vector> {
+        self.overscroll.take()
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -99,10 +201,21 @@ impl<'a, Message> Shell<'a, Message> {
             self.request_redraw(at);
         }
 
+        self.request_input_method(other.input_method);
+
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
         self.are_widgets_invalid =
             self.are_widgets_invalid || other.are_widgets_invalid;
+
+        if other.overscroll.is_some() {
+            self.overscroll = other.overscroll;
+        }
+
+        #[cfg(feature = "debug")]
+        if other.trace.is_some() {
+            self.trace = other.trace;
+        }
     }
 }
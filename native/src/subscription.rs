@@ -53,6 +53,12 @@ pub fn events() -> Subscription<Event> {
 ///
 /// - Returns `None`, the [`Event`] will be discarded.
 /// - Returns `Some` message, the `Message` will be produced.
+///
+/// Events are delivered after they have been processed by the application
+/// shell and the widget tree. An [`Event`] intercepted by the application
+/// before reaching any widget is delivered with [`event::Status::Captured`].
+///
+/// See [`events_with_status`] for the events covered and their statuses.
 pub fn events_with<Message>(
     f: fn(Event, event::Status) -> Option<Message>,
 ) -> Subscription<Message>
@@ -78,6 +84,33 @@ where
     })
 }
 
+/// Returns a [`Subscription`] to all the runtime events, together with the
+/// final [`event::Status`] of each one of them.
+///
+/// This is useful to find out whether the user interface consumed some input,
+/// e.g. for analytics or debugging purposes. The status of an [`Event`] is:
+///
+/// - [`event::Status::Captured`] if the application intercepted it before it
+/// reached the widgets—with a menu accelerator or `Application::intercept`.
+/// - Otherwise, the status of the widget tree: [`event::Status::Captured`] if
+/// any widget captured it, and [`event::Status::Ignored`] if none did.
+///
+/// Every keyboard, mouse, touch, input method, window, and platform-specific
+/// [`Event`] is delivered. Window events produced by the runtime itself
+/// instead of the user—like the [`window::Event::ModeChanged`] that follows a
+/// [`window::Action::SetMode`]—never reach the widgets, so they are always
+/// [`event::Status::Ignored`]. [`window::Event::RedrawRequested`] is not
+/// delivered at all, since it happens every frame.
+///
+/// Like any [`Subscription`], it has no cost when it is not in use: the
+/// runtime only keeps track of the statuses while some [`Subscription`]
+/// listens to the events.
+///
+/// [`window::Action::SetMode`]: crate::window::Action::SetMode
+pub fn events_with_status() -> Subscription<(Event, event::Status)> {
+    events_with(|event, status| Some((event, status)))
+}
+
 pub(crate) fn raw_events<Message>(
     f: fn(Event, event::Status) -> Option<Message>,
 ) -> Subscription<Message>
@@ -100,11 +133,51 @@ where
     })
 }
 
-/// Returns a [`Subscription`] that will create and asynchronously run the
-/// given [`Stream`].
+/// Returns a [`Subscription`] that will call the given function to create and
+/// asynchronously run a [`Stream`].
 ///
-/// The `id` will be used to uniquely identify the [`Subscription`].
-pub fn run<I, S, Message>(id: I, stream: S) -> Subscription<Message>
+/// The `id` uniquely identifies the [`Subscription`] and controls the lifetime
+/// of the [`Stream`]:
+///
+/// - The `builder` is called once, when the `id` first appears in the
+/// [`Subscription`] of the application.
+/// - The [`Stream`] keeps running as long as the `id` keeps being returned.
+/// Returning the same `id` on later updates does not restart it.
+/// - The [`Stream`] is dropped once the `id` stops being returned.
+/// - Changing the `id` drops the running [`Stream`] and starts a new one.
+///
+/// # Passing initial parameters
+/// Any value captured by the `builder` is only used when the [`Stream`] is
+/// started; later changes to the value are ignored while the [`Stream`] keeps
+/// running. If the [`Stream`] should restart when a parameter changes, make
+/// the parameter part of the `id`:
+///
+/// ```
+/// use iced_native::futures::stream;
+/// use iced_native::subscription::{self, Subscription};
+///
+/// #[derive(Debug, Clone)]
+/// enum Event {
+///     Listening(u16),
+/// }
+///
+/// fn server(port: u16) -> Subscription<Event> {
+///     struct Server;
+///
+///     // Changing the `port` restarts the server
+///     subscription::run((std::any::TypeId::of::<Server>(), port), move || {
+///         stream::once(async move {
+///             // Bind to the `port` and start accepting connections...
+///
+///             Event::Listening(port)
+///         })
+///     })
+/// }
+/// ```
+pub fn run<I, S, Message>(
+    id: I,
+    builder: impl FnOnce() -> S + 'static,
+) -> Subscription<Message>
 where
     I: Hash + 'static,
     S: Stream<Item = Message> + MaybeSend + 'static,
@@ -112,7 +185,39 @@ where
 {
     Subscription::from_recipe(Runner {
         id,
-        spawn: move |_| stream,
+        spawn: move |_| builder(),
+    })
+}
+
+/// Returns a [`Subscription`] that will call the given function to create and
+/// asynchronously run a [`Future`], producing the messages sent through the
+/// provided channel.
+///
+/// This is the easiest way to run a long-lived background task that emits
+/// messages—like a server. The channel has a buffer of the given `size` and
+/// the task waits for capacity when it is full.
+///
+/// The `id` controls the lifetime of the task, exactly like in [`run`]: the
+/// task is dropped as soon as the `id` stops being returned, and the
+/// [`Subscription`] finishes once the [`Future`] completes.
+pub fn channel<I, Fut, Message>(
+    id: I,
+    size: usize,
+    f: impl FnOnce(futures::channel::mpsc::Sender<Message>) -> Fut + 'static,
+) -> Subscription<Message>
+where
+    I: Hash + 'static,
+    Fut: Future<Output = ()> + MaybeSend + 'static,
+    Message: 'static + MaybeSend,
+{
+    use futures::stream::{self, StreamExt};
+
+    run(id, move || {
+        let (sender, receiver) = futures::channel::mpsc::channel(size);
+
+        let task = stream::once(f(sender)).filter_map(|_| async { None });
+
+        stream::select(receiver, task)
     })
 }
 
@@ -199,11 +304,10 @@ where
     use futures::future::{self, FutureExt};
     use futures::stream::StreamExt;
 
-    run(
-        id,
+    run(id, move || {
         futures::stream::unfold(initial, move |state| f(state).map(Some))
-            .filter_map(future::ready),
-    )
+            .filter_map(future::ready)
+    })
 }
 
 struct Runner<I, F, S, Message>
@@ -233,3 +337,39 @@ where
         iced_futures::boxed_stream((self.spawn)(input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn counter(id: u32, starts: &Arc<AtomicUsize>) -> Subscription<()> {
+        let starts = starts.clone();
+
+        run(id, move || {
+            let _ = starts.fetch_add(1, Ordering::SeqCst);
+
+            futures::stream::pending()
+        })
+    }
+
+    #[test]
+    fn run_is_restarted_only_when_the_id_changes() {
+        let mut tracker = Tracker::new();
+        let (sender, _receiver) = futures::channel::mpsc::unbounded();
+        let starts = Arc::new(AtomicUsize::new(0));
+
+        let _ = tracker.update(counter(1, &starts), sender.clone());
+        let _ = tracker.update(counter(1, &starts), sender.clone());
+        assert_eq!(starts.load(Ordering::SeqCst), 1);
+
+        let _ = tracker.update(counter(2, &starts), sender.clone());
+        assert_eq!(starts.load(Ordering::SeqCst), 2);
+
+        let _ = tracker.update(Subscription::none(), sender.clone());
+        let _ = tracker.update(counter(2, &starts), sender);
+        assert_eq!(starts.load(Ordering::SeqCst), 3);
+    }
+}
@@ -1,4 +1,17 @@
 //! Load and draw vector graphics.
+//!
+//! # Supported features
+//! SVG data is parsed with [`usvg`] and rasterized with [`resvg`], which
+//! support the static subset of SVG 1.1 and parts of SVG 2: shapes, paths,
+//! fills and strokes, gradients, patterns, clipping paths, masks, opacity,
+//! transforms and most filters. Text is supported as long as its fonts are
+//! available to the system.
+//!
+//! Animations, scripting, interactivity and external resources—other than
+//! embedded images—are not supported and are ignored.
+//!
+//! [`usvg`]: https://docs.rs/usvg
+//! [`resvg`]: https://docs.rs/resvg
 use crate::{Color, Hasher, Rectangle, Size};
 
 use std::borrow::Cow;
@@ -25,6 +38,14 @@ impl Handle {
     ///
     /// This is useful if you already have your SVG data in-memory, maybe
     /// because you downloaded or generated it procedurally.
+    ///
+    /// The [`Handle`] is identified by a hash of the bytes, so renderers reuse
+    /// the parsed SVG and its rasterizations at every size for any [`Handle`]
+    /// with identical contents. Changing the contents produces a different
+    /// [`Handle`], which is parsed and rasterized anew.
+    ///
+    /// If the bytes cannot be parsed, nothing is drawn and the [`Error`] can
+    /// be obtained with [`Renderer::error`].
     pub fn from_memory(bytes: impl Into<Cow<'static, [u8]>>) -> Handle {
         Self::from_data(Data::Bytes(bytes.into()))
     }
@@ -56,6 +77,14 @@ impl Hash for Handle {
     }
 }
 
+impl PartialEq for Handle {
+    fn eq(&self, other: &Handle) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Handle {}
+
 /// The data of a vectorial image.
 #[derive(Clone, Hash)]
 pub enum Data {
@@ -77,6 +106,29 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// An error that prevents an SVG from being drawn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The file of the SVG could not be read.
+    Io(std::io::ErrorKind),
+
+    /// The data of the SVG could not be parsed.
+    Parse(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(kind) => write!(f, "the SVG could not be read: {kind}"),
+            Error::Parse(error) => {
+                write!(f, "the SVG could not be parsed: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 /// A [`Renderer`] that can render vector graphics.
 ///
 /// [renderer]: crate::renderer
@@ -84,6 +136,35 @@ pub trait Renderer: crate::Renderer {
     /// Returns the default dimensions of an SVG for the given [`Handle`].
     fn dimensions(&self, handle: &Handle) -> Size<u32>;
 
+    /// Returns the [`Error`] that prevents the SVG of the given [`Handle`]
+    /// from being drawn, if any.
+    ///
+    /// By default, no error is ever reported.
+    fn error(&self, _handle: &Handle) -> Option<Error> {
+        None
+    }
+
     /// Draws an SVG with the given [`Handle`], an optional [`Color`] filter, and inside the provided `bounds`.
     fn draw(&mut self, handle: Handle, color: Option<Color>, bounds: Rectangle);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handles_are_identified_by_their_contents() {
+        let circle = |radius: u32| {
+            Handle::from_memory(
+                format!(
+                    "<svg xmlns=\"http://www.w3.org/2000/svg\">\
+                     <circle r=\"{radius}\"/></svg>"
+                )
+                .into_bytes(),
+            )
+        };
+
+        assert_eq!(circle(5), circle(5));
+        assert_ne!(circle(5), circle(6));
+    }
+}
@@ -14,6 +14,12 @@ pub struct Text<'a, Font> {
     /// The size of the [`Text`].
     pub size: f32,
 
+    /// The [`LineHeight`] of the [`Text`].
+    pub line_height: LineHeight,
+
+    /// The extra space between characters of the [`Text`], in logical pixels.
+    pub letter_spacing: f32,
+
     /// The color of the [`Text`].
     pub color: Color,
 
@@ -27,6 +33,75 @@ pub struct Text<'a, Font> {
     pub vertical_alignment: alignment::Vertical,
 }
 
+/// Returns the point that a [`Text`] with the given alignment must be
+/// anchored to in order to be aligned inside the given `bounds`.
+///
+/// This is how the `Text` widget positions its contents.
+pub fn anchor(
+    bounds: Rectangle,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) -> Point {
+    let x = match horizontal_alignment {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => bounds.center_x(),
+        alignment::Horizontal::Right => bounds.x + bounds.width,
+    };
+
+    let y = match vertical_alignment {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => bounds.center_y(),
+        alignment::Vertical::Bottom => bounds.y + bounds.height,
+    };
+
+    Point::new(x, y)
+}
+
+/// The height of a line of text in a paragraph.
+///
+/// Line heights are applied by distributing the extra space (or lack thereof)
+/// evenly above and below each line, so the glyphs stay vertically centered
+/// in their lines.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    /// The natural line height of the font (i.e. its ascent, descent and line
+    /// gap).
+    Natural,
+
+    /// A factor of the size of the text.
+    ///
+    /// For instance, `LineHeight::Relative(1.5)` with a text size of `20`
+    /// produces lines that are `30` logical pixels tall.
+    Relative(f32),
+
+    /// An absolute height in logical pixels.
+    Absolute(f32),
+}
+
+impl LineHeight {
+    /// Returns the line height in logical pixels for the given text size, if
+    /// it differs from the natural line height of the font.
+    pub fn to_absolute(self, text_size: f32) -> Option<f32> {
+        match self {
+            Self::Natural => None,
+            Self::Relative(factor) => Some(factor * text_size),
+            Self::Absolute(pixels) => Some(pixels),
+        }
+    }
+}
+
+impl Default for LineHeight {
+    fn default() -> Self {
+        Self::Natural
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(factor: f32) -> Self {
+        Self::Relative(factor)
+    }
+}
+
 /// The result of hit testing on text.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Hit {
@@ -54,6 +129,255 @@ impl Hit {
     }
 }
 
+/// The caret at some position of a text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Caret {
+    /// The index of the `char` the caret is placed before.
+    ///
+    /// It counts every `char` of the content, including line breaks.
+    pub index: usize,
+
+    /// The bounds of the caret.
+    ///
+    /// They are one logical pixel wide—like the cursor of a `TextInput`—and
+    /// as tall as a line of the text.
+    pub bounds: Rectangle,
+}
+
+/// Finds the [`Caret`] nearest to the given `point` of some `content` drawn
+/// at the top left corner of the given `bounds`.
+///
+/// The line is chosen by dividing the vertical position of the `point` by
+/// the height of a line, clamping it to the first and last lines. In that
+/// line, the caret is placed at the nearest boundary between characters;
+/// therefore, a `point` past the end of a line produces the index at the end
+/// of the line.
+///
+/// Lines are only broken at line breaks (`\n`), since wrapping depends on the
+/// renderer. The boundary is found with [`Renderer::hit_test`] when there is
+/// no `letter_spacing`. Otherwise—or if the renderer cannot hit test—the line
+/// is measured with [`Renderer::measure`], with a cost that grows
+/// logarithmically with the length of the line.
+pub fn find_caret<Renderer>(
+    renderer: &Renderer,
+    content: &str,
+    size: u16,
+    line_height: LineHeight,
+    letter_spacing: f32,
+    font: Renderer::Font,
+    bounds: Rectangle,
+    point: Point,
+) -> Caret
+where
+    Renderer: self::Renderer,
+{
+    let height = line_height_of(renderer, size, line_height, font.clone());
+    let lines: Vec<&str> = content.split('\n').collect();
+
+    let line = (((point.y - bounds.y) / height).floor().max(0.0) as usize)
+        .min(lines.len() - 1);
+
+    let start: usize = lines[..line]
+        .iter()
+        .map(|line| line.chars().count() + 1)
+        .sum();
+
+    let text = lines[line];
+    let count = text.chars().count();
+    let x = point.x - bounds.x;
+
+    let offset = |column: usize| {
+        column_offset(
+            renderer,
+            text,
+            column,
+            size,
+            line_height,
+            letter_spacing,
+            font.clone(),
+        )
+    };
+
+    let hit = if letter_spacing == 0.0 {
+        renderer.hit_test(
+            text,
+            f32::from(size),
+            font.clone(),
+            Size::INFINITY,
+            Point::new(x, height / 2.0),
+            true,
+        )
+    } else {
+        None
+    };
+
+    let column = match hit {
+        Some(hit) => hit.cursor().min(count),
+        None => {
+            // Find the first column at or past the point...
+            let (mut low, mut high) = (0, count);
+
+            while low < high {
+                let middle = (low + high) / 2;
+
+                if offset(middle) < x {
+                    low = middle + 1;
+                } else {
+                    high = middle;
+                }
+            }
+
+            // ...and keep it unless the previous one is nearer
+            if low > 0 && x - offset(low - 1) < offset(low) - x {
+                low - 1
+            } else {
+                low
+            }
+        }
+    };
+
+    Caret {
+        index: start + column,
+        bounds: Rectangle {
+            x: bounds.x + offset(column),
+            y: bounds.y + line as f32 * height,
+            width: 1.0,
+            height,
+        },
+    }
+}
+
+/// Returns the bounds of the caret placed before the `char` at the given
+/// `index` of some `content` drawn at the top left corner of the given
+/// `bounds`.
+///
+/// This is the inverse of [`find_caret`]. An `index` past the end of the
+/// `content` places the caret at its end.
+pub fn caret_bounds<Renderer>(
+    renderer: &Renderer,
+    content: &str,
+    size: u16,
+    line_height: LineHeight,
+    letter_spacing: f32,
+    font: Renderer::Font,
+    bounds: Rectangle,
+    index: usize,
+) -> Rectangle
+where
+    Renderer: self::Renderer,
+{
+    let height = line_height_of(renderer, size, line_height, font.clone());
+
+    let mut line = 0;
+    let mut column = index;
+    let mut text = "";
+
+    for (i, current) in content.split('\n').enumerate() {
+        let length = current.chars().count();
+
+        line = i;
+        text = current;
+
+        if column <= length {
+            break;
+        }
+
+        column -= length + 1;
+    }
+
+    let column = column.min(text.chars().count());
+
+    Rectangle {
+        x: bounds.x
+            + column_offset(
+                renderer,
+                text,
+                column,
+                size,
+                line_height,
+                letter_spacing,
+                font,
+            ),
+        y: bounds.y + line as f32 * height,
+        width: 1.0,
+        height,
+    }
+}
+
+/// Returns the horizontal offset of the caret placed before the `char` at
+/// the given `column` of a single line of `text`.
+///
+/// The caret is placed right before the `char`, after the letter spacing of
+/// its predecessor.
+fn column_offset<Renderer>(
+    renderer: &Renderer,
+    text: &str,
+    column: usize,
+    size: u16,
+    line_height: LineHeight,
+    letter_spacing: f32,
+    font: Renderer::Font,
+) -> f32
+where
+    Renderer: self::Renderer,
+{
+    if column == 0 {
+        return 0.0;
+    }
+
+    let (width, _) = renderer.measure(
+        until(text, column),
+        size,
+        line_height,
+        letter_spacing,
+        font,
+        Size::INFINITY,
+    );
+
+    if column < text.chars().count() {
+        width + letter_spacing
+    } else {
+        width
+    }
+}
+
+/// Returns the height of a line of text with the given parameters.
+fn line_height_of<Renderer>(
+    renderer: &Renderer,
+    size: u16,
+    line_height: LineHeight,
+    font: Renderer::Font,
+) -> f32
+where
+    Renderer: self::Renderer,
+{
+    match line_height.to_absolute(f32::from(size)) {
+        Some(height) => height,
+        None => {
+            let (_, height) = renderer.measure(
+                "M",
+                size,
+                line_height,
+                0.0,
+                font,
+                Size::INFINITY,
+            );
+
+            height
+        }
+    }
+}
+
+/// Returns the slice of `text` before the `char` at the given `column`.
+fn until(text: &str, column: usize) -> &str {
+    let end = text
+        .char_indices()
+        .nth(column)
+        .map_or(text.len(), |(index, _)| index);
+
+    &text[..end]
+}
+
 /// A renderer capable of measuring and drawing [`Text`].
 pub trait Renderer: crate::Renderer {
     /// The font type used.
@@ -77,21 +401,55 @@ pub trait Renderer: crate::Renderer {
 
     /// Measures the text in the given bounds and returns the minimum boundaries
     /// that can fit the contents.
+    ///
+    /// The `letter_spacing` is added to the advance of every character but the
+    /// last one of each line. Negative values are allowed, but characters are
+    /// never moved behind their predecessors; therefore, the measured width
+    /// never shrinks below the width of the widest glyph run.
     fn measure(
         &self,
         content: &str,
         size: u16,
+        line_height: LineHeight,
+        letter_spacing: f32,
         font: Self::Font,
         bounds: Size,
     ) -> (f32, f32);
 
     /// Measures the width of the text as if it were laid out in a single line.
     fn measure_width(&self, content: &str, size: u16, font: Self::Font) -> f32 {
-        let (width, _) = self.measure(content, size, font, Size::INFINITY);
+        let (width, _) = self.measure(
+            content,
+            size,
+            LineHeight::default(),
+            0.0,
+            font,
+            Size::INFINITY,
+        );
 
         width
     }
 
+    /// Returns the distance from the top of a line of text with the given
+    /// parameters to its baseline.
+    ///
+    /// By default, it is estimated without looking at the font: the ascent
+    /// is assumed to be 80% of the `size`, the natural line height to be
+    /// the `size` itself, and any extra line height to be split evenly above
+    /// and below the line. Renderers with access to the font metrics should
+    /// override it.
+    fn baseline(
+        &self,
+        size: u16,
+        line_height: LineHeight,
+        _font: Self::Font,
+    ) -> f32 {
+        let size = f32::from(size);
+        let line_height = line_height.to_absolute(size).unwrap_or(size);
+
+        (line_height - size) / 2.0 + size * 0.8
+    }
+
     /// Tests whether the provided point is within the boundaries of text
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -110,5 +468,184 @@ pub trait Renderer: crate::Renderer {
     ) -> Option<Hit>;
 
     /// Draws the given [`Text`].
+    ///
+    /// The `bounds` of the [`Text`] are anchored at the point given by its
+    /// alignment (e.g. the center of the text for centered text). Use
+    /// [`fill_text_in`] to align text inside some bounds instead.
+    ///
+    /// [`fill_text_in`]: Self::fill_text_in
     fn fill_text(&mut self, text: Text<'_, Self::Font>);
+
+    /// Draws the given `content` aligned inside the given `bounds`, exactly
+    /// like a `Text` widget with the same bounds and default settings would.
+    ///
+    /// If no `size` is given, the [`default_size`] of the [`Renderer`] is
+    /// used. The text is drawn inside a layer with the given `bounds`, so any
+    /// glyphs outside of them are clipped. Like with [`fill_text`], lines are
+    /// wrapped to the width of the `bounds`. Therefore, the `bounds` should be
+    /// at least as big as the result of [`measure`] for the text to be
+    /// fully visible.
+    ///
+    /// The `bounds` are relative to the current translation of the
+    /// [`Renderer`] (see [`with_translation`]), and the text is also clipped
+    /// by the bounds of any enclosing layer (see [`with_layer`]).
+    ///
+    /// [`default_size`]: Self::default_size
+    /// [`fill_text`]: Self::fill_text
+    /// [`measure`]: Self::measure
+    /// [`with_translation`]: crate::Renderer::with_translation
+    /// [`with_layer`]: crate::Renderer::with_layer
+    fn fill_text_in(
+        &mut self,
+        content: &str,
+        bounds: Rectangle,
+        color: Color,
+        size: Option<u16>,
+        font: Self::Font,
+        alignment: (alignment::Horizontal, alignment::Vertical),
+    ) {
+        let (horizontal_alignment, vertical_alignment) = alignment;
+        let position = anchor(bounds, horizontal_alignment, vertical_alignment);
+
+        let size = f32::from(size.unwrap_or_else(|| self.default_size()));
+
+        self.with_layer(bounds, |renderer| {
+            renderer.fill_text(Text {
+                content,
+                bounds: Rectangle {
+                    x: position.x,
+                    y: position.y,
+                    ..bounds
+                },
+                size,
+                line_height: LineHeight::default(),
+                letter_spacing: 0.0,
+                color,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+            });
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Recorder;
+    use crate::Font;
+
+    const CONTENT: &str = "héllo\nhi";
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 100.0,
+        y: 50.0,
+        width: 200.0,
+        height: 100.0,
+    };
+
+    fn caret(x: f32, y: f32) -> Caret {
+        spaced_caret(0.0, x, y)
+    }
+
+    fn bounds(index: usize) -> Rectangle {
+        spaced_bounds(0.0, index)
+    }
+
+    fn spaced_caret(letter_spacing: f32, x: f32, y: f32) -> Caret {
+        find_caret(
+            &Recorder::new(),
+            CONTENT,
+            20,
+            LineHeight::Natural,
+            letter_spacing,
+            Font::Default,
+            BOUNDS,
+            Point::new(BOUNDS.x + x, BOUNDS.y + y),
+        )
+    }
+
+    fn spaced_bounds(letter_spacing: f32, index: usize) -> Rectangle {
+        caret_bounds(
+            &Recorder::new(),
+            CONTENT,
+            20,
+            LineHeight::Natural,
+            letter_spacing,
+            Font::Default,
+            BOUNDS,
+            index,
+        )
+    }
+
+    #[test]
+    fn finds_the_nearest_character_boundary() {
+        assert_eq!(caret(-5.0, 5.0).index, 0);
+        assert_eq!(caret(14.0, 5.0).index, 1);
+        assert_eq!(caret(16.0, 5.0).index, 2);
+
+        assert_eq!(
+            caret(16.0, 5.0).bounds,
+            Rectangle {
+                x: 120.0,
+                y: 50.0,
+                width: 1.0,
+                height: 20.0,
+            }
+        );
+    }
+
+    #[test]
+    fn points_past_the_end_of_a_line_find_its_end() {
+        assert_eq!(caret(500.0, 5.0).index, 5);
+        assert_eq!(caret(500.0, 25.0).index, 8);
+    }
+
+    #[test]
+    fn lines_are_chosen_by_their_height() {
+        assert_eq!(caret(0.0, 19.0).index, 0);
+        assert_eq!(caret(0.0, 21.0).index, 6);
+
+        // Points outside of the text are clamped to its first and last lines
+        assert_eq!(caret(10.0, -30.0).index, 1);
+        assert_eq!(caret(10.0, 300.0).index, 7);
+    }
+
+    #[test]
+    fn caret_bounds_are_the_inverse_of_find_caret() {
+        for index in 0..=CONTENT.chars().count() {
+            let bounds = bounds(index);
+
+            assert_eq!(
+                caret(bounds.x - BOUNDS.x, bounds.center_y() - BOUNDS.y).index,
+                index
+            );
+        }
+
+        assert_eq!(bounds(100), bounds(8));
+    }
+
+    #[test]
+    fn letter_spacing_moves_the_character_boundaries() {
+        // Every character is followed by 10 units of spacing, but the last
+        // one of each line
+        assert_eq!(spaced_caret(10.0, 25.0, 5.0).index, 1);
+        assert_eq!(spaced_caret(10.0, 35.0, 5.0).index, 2);
+        assert_eq!(spaced_caret(10.0, 500.0, 5.0).bounds.x, BOUNDS.x + 90.0);
+
+        for index in 0..=CONTENT.chars().count() {
+            let bounds = spaced_bounds(10.0, index);
+
+            assert_eq!(
+                spaced_caret(
+                    10.0,
+                    bounds.x - BOUNDS.x,
+                    bounds.center_y() - BOUNDS.y
+                )
+                .index,
+                index
+            );
+        }
+    }
 }
@@ -1,4 +1,8 @@
 //! Build touch events.
+pub mod gesture;
+
+pub use gesture::Gesture;
+
 use crate::Point;
 
 /// A touch interaction.
@@ -16,6 +20,12 @@ pub enum Event {
 
     /// A touch interaction was canceled.
     FingerLost { id: Finger, position: Point },
+
+    /// A two-finger [`Gesture`] was recognized, updated or ended.
+    ///
+    /// Gestures are produced by the shell after the finger events that
+    /// caused them.
+    Gesture(Gesture),
 }
 
 /// A unique identifier representing a finger on a touch interaction.
@@ -0,0 +1,372 @@
+//! Recognize two-finger gestures from touch events.
+//!
+//! Gestures are derived from the touch points reported by the shell, so they
+//! are available on every backend that reports touch events. Touchpad
+//! gestures (e.g. trackpad pinch or rotate on macOS) are not reported by the
+//! current windowing backend; on those platforms no [`Gesture`] is produced.
+use crate::touch::{Event, Finger};
+use crate::{Point, Vector};
+
+use std::f32::consts::PI;
+
+/// The minimum distance, in logical pixels, that two fingers need to travel
+/// before a [`Gesture`] is recognized.
+const THRESHOLD: f32 = 8.0;
+
+/// A two-finger gesture.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gesture {
+    /// The [`Kind`] of the [`Gesture`].
+    pub kind: Kind,
+
+    /// The [`Phase`] of the [`Gesture`].
+    pub phase: Phase,
+
+    /// The midpoint between the two fingers.
+    pub center: Point,
+
+    /// The scale factor since the previous [`Gesture`] event.
+    ///
+    /// It is always `1.0` for a [`Kind::Pan`] and when the [`Gesture`] ends.
+    pub scale: f32,
+
+    /// The rotation since the previous [`Gesture`] event, in radians.
+    ///
+    /// Positive values are clockwise in screen coordinates. It is always
+    /// `0.0` for a [`Kind::Pan`] and when the [`Gesture`] ends.
+    pub rotation: f32,
+
+    /// The translation of the [`center`] since the previous [`Gesture`]
+    /// event.
+    ///
+    /// [`center`]: Self::center
+    pub translation: Vector,
+}
+
+/// The kind of a [`Gesture`].
+///
+/// Pinching and rotating usually happen at the same time. Both a
+/// [`Kind::Pinch`] and a [`Kind::Rotate`] report the [`scale`] and the
+/// [`rotation`] of the fingers; the [`Kind`] only tells which motion was
+/// dominant when the [`Gesture`] was recognized.
+///
+/// [`scale`]: Gesture::scale
+/// [`rotation`]: Gesture::rotation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// The fingers are moving apart or closer together (e.g. to zoom).
+    Pinch,
+
+    /// The fingers are turning around their midpoint (e.g. to rotate).
+    Rotate,
+
+    /// The fingers are moving together in the same direction (e.g. to
+    /// scroll or pan).
+    Pan,
+}
+
+/// The phase of a [`Gesture`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// The [`Gesture`] was recognized.
+    Started,
+
+    /// The [`Gesture`] is on-going.
+    Updated,
+
+    /// One of the fingers was lifted or lost and the [`Gesture`] ended.
+    Ended,
+}
+
+/// Recognizes two-finger gestures from a stream of touch [`Event`]s.
+///
+/// The [`Kind`] of a [`Gesture`] is decided once, as soon as the fingers
+/// travel further than a small threshold, by comparing how much the distance
+/// between them changes, how far they turn around their midpoint, and how
+/// far the midpoint itself moves.
+#[derive(Debug, Clone, Default)]
+pub struct Recognizer {
+    fingers: Vec<(Finger, Point)>,
+    tracking: Option<Tracking>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Tracking {
+    fingers: [Finger; 2],
+    origin: Snapshot,
+    last: Snapshot,
+    kind: Option<Kind>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Snapshot {
+    center: Point,
+    distance: f32,
+    angle: f32,
+}
+
+impl Recognizer {
+    /// Creates a new [`Recognizer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes a touch [`Event`], returning a [`Gesture`] if the event
+    /// started, updated or ended one.
+    pub fn update(&mut self, event: &Event) -> Option<Gesture> {
+        match *event {
+            Event::FingerPressed { id, position } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+                self.fingers.push((id, position));
+
+                if self.tracking.is_none() && self.fingers.len() == 2 {
+                    let snapshot = self.snapshot(self.fingers[0].0, id)?;
+
+                    self.tracking = Some(Tracking {
+                        fingers: [self.fingers[0].0, id],
+                        origin: snapshot,
+                        last: snapshot,
+                        kind: None,
+                    });
+                }
+
+                None
+            }
+            Event::FingerMoved { id, position } => {
+                if let Some((_, current)) =
+                    self.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                {
+                    *current = position;
+                }
+
+                let tracking = self.tracking?;
+
+                if !tracking.fingers.contains(&id) {
+                    return None;
+                }
+
+                let current =
+                    self.snapshot(tracking.fingers[0], tracking.fingers[1])?;
+
+                let (kind, phase, previous) = match tracking.kind {
+                    Some(kind) => (kind, Phase::Updated, tracking.last),
+                    None => {
+                        let stretch =
+                            (current.distance - tracking.origin.distance).abs();
+                        let turn =
+                            angle_between(tracking.origin.angle, current.angle)
+                                .abs()
+                                * current.distance
+                                / 2.0;
+                        let travel =
+                            current.center.distance(tracking.origin.center);
+
+                        if stretch.max(turn).max(travel) < THRESHOLD {
+                            return None;
+                        }
+
+                        let kind = if travel >= stretch && travel >= turn {
+                            Kind::Pan
+                        } else if stretch >= turn {
+                            Kind::Pinch
+                        } else {
+                            Kind::Rotate
+                        };
+
+                        (kind, Phase::Started, tracking.origin)
+                    }
+                };
+
+                let (scale, rotation) = match kind {
+                    Kind::Pan => (1.0, 0.0),
+                    Kind::Pinch | Kind::Rotate => (
+                        if previous.distance > 0.0 {
+                            current.distance / previous.distance
+                        } else {
+                            1.0
+                        },
+                        angle_between(previous.angle, current.angle),
+                    ),
+                };
+
+                self.tracking = Some(Tracking {
+                    last: current,
+                    kind: Some(kind),
+                    ..tracking
+                });
+
+                Some(Gesture {
+                    kind,
+                    phase,
+                    center: current.center,
+                    scale,
+                    rotation,
+                    translation: current.center - previous.center,
+                })
+            }
+            Event::FingerLifted { id, .. } | Event::FingerLost { id, .. } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+
+                let tracking = self.tracking?;
+
+                if !tracking.fingers.contains(&id) {
+                    return None;
+                }
+
+                self.tracking = None;
+
+                tracking.kind.map(|kind| Gesture {
+                    kind,
+                    phase: Phase::Ended,
+                    center: tracking.last.center,
+                    scale: 1.0,
+                    rotation: 0.0,
+                    translation: Vector::new(0.0, 0.0),
+                })
+            }
+            Event::Gesture(_) => None,
+        }
+    }
+
+    fn snapshot(&self, a: Finger, b: Finger) -> Option<Snapshot> {
+        let position = |id| {
+            self.fingers
+                .iter()
+                .find(|(finger, _)| *finger == id)
+                .map(|(_, position)| *position)
+        };
+
+        let a = position(a)?;
+        let b = position(b)?;
+
+        Some(Snapshot {
+            center: Point::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0),
+            distance: a.distance(b),
+            angle: (b.y - a.y).atan2(b.x - a.x),
+        })
+    }
+}
+
+/// Returns the signed angle from `from` to `to`, in the range `(-PI, PI]`.
+fn angle_between(from: f32, to: f32) -> f32 {
+    let delta = (to - from) % (2.0 * PI);
+
+    if delta > PI {
+        delta - 2.0 * PI
+    } else if delta <= -PI {
+        delta + 2.0 * PI
+    } else {
+        delta
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pressed(id: u64, x: f32, y: f32) -> Event {
+        Event::FingerPressed {
+            id: Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn moved(id: u64, x: f32, y: f32) -> Event {
+        Event::FingerMoved {
+            id: Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    fn lifted(id: u64, x: f32, y: f32) -> Event {
+        Event::FingerLifted {
+            id: Finger(id),
+            position: Point::new(x, y),
+        }
+    }
+
+    #[test]
+    fn recognizes_pinch() {
+        let mut recognizer = Recognizer::new();
+
+        assert_eq!(recognizer.update(&pressed(0, 0.0, 0.0)), None);
+        assert_eq!(recognizer.update(&pressed(1, 100.0, 0.0)), None);
+        assert_eq!(recognizer.update(&moved(1, 104.0, 0.0)), None);
+
+        let gesture = recognizer.update(&moved(1, 200.0, 0.0)).unwrap();
+
+        assert_eq!(gesture.kind, Kind::Pinch);
+        assert_eq!(gesture.phase, Phase::Started);
+        assert_eq!(gesture.scale, 2.0);
+
+        let gesture = recognizer.update(&moved(0, 100.0, 0.0)).unwrap();
+
+        assert_eq!(gesture.phase, Phase::Updated);
+        assert_eq!(gesture.scale, 0.5);
+
+        let gesture = recognizer.update(&lifted(0, 100.0, 0.0)).unwrap();
+
+        assert_eq!(gesture.phase, Phase::Ended);
+        assert_eq!(recognizer.update(&moved(1, 300.0, 0.0)), None);
+    }
+
+    #[test]
+    fn recognizes_pan() {
+        let mut recognizer = Recognizer::new();
+
+        let _ = recognizer.update(&pressed(0, 0.0, 0.0));
+        let _ = recognizer.update(&pressed(1, 100.0, 0.0));
+
+        assert_eq!(recognizer.update(&moved(0, 0.0, 6.0)), None);
+        assert_eq!(recognizer.update(&moved(1, 100.0, 6.0)), None);
+
+        let gesture = recognizer.update(&moved(0, 0.0, 12.0)).unwrap();
+
+        assert_eq!(gesture.kind, Kind::Pan);
+        assert_eq!(gesture.phase, Phase::Started);
+        assert_eq!(gesture.scale, 1.0);
+        assert_eq!(gesture.rotation, 0.0);
+        assert_eq!(gesture.translation, Vector::new(0.0, 9.0));
+
+        let gesture = recognizer.update(&moved(1, 100.0, 12.0)).unwrap();
+
+        assert_eq!(gesture.phase, Phase::Updated);
+        assert_eq!(gesture.translation, Vector::new(0.0, 3.0));
+    }
+
+    #[test]
+    fn recognizes_rotation() {
+        let mut recognizer = Recognizer::new();
+
+        let _ = recognizer.update(&pressed(0, -50.0, 0.0));
+        let _ = recognizer.update(&pressed(1, 50.0, 0.0));
+
+        let gestures: Vec<_> = (1..=6)
+            .flat_map(|step| {
+                let (sin, cos) = (step as f32 * PI / 36.0).sin_cos();
+
+                [
+                    moved(0, -50.0 * cos, -50.0 * sin),
+                    moved(1, 50.0 * cos, 50.0 * sin),
+                ]
+            })
+            .filter_map(|event| recognizer.update(&event))
+            .collect();
+
+        assert_eq!(gestures[0].kind, Kind::Rotate);
+        assert_eq!(gestures[0].phase, Phase::Started);
+
+        let scale: f32 = gestures.iter().map(|gesture| gesture.scale).product();
+        let rotation: f32 =
+            gestures.iter().map(|gesture| gesture.rotation).sum();
+
+        assert!((scale - 1.0).abs() < 1e-4);
+        assert!((rotation - PI / 6.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn wraps_angles() {
+        assert!((angle_between(PI - 0.1, -PI + 0.1) - 0.2).abs() < 1e-4);
+        assert!((angle_between(-PI + 0.1, PI - 0.1) + 0.2).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,174 @@
+//! Show an icon with a menu in the system tray.
+mod action;
+
+pub use action::Action;
+
+use std::fmt;
+
+/// The settings of a tray icon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tray {
+    /// The [`Icon`] of the tray.
+    pub icon: Icon,
+
+    /// The tooltip shown when hovering the tray icon.
+    pub tooltip: Option<String>,
+
+    /// The [`Menu`] shown when the tray icon is clicked.
+    pub menu: Menu,
+}
+
+impl Tray {
+    /// Creates a new [`Tray`] with the given [`Icon`], no tooltip, and an
+    /// empty [`Menu`].
+    pub fn new(icon: Icon) -> Self {
+        Self {
+            icon,
+            tooltip: None,
+            menu: Menu::new(),
+        }
+    }
+
+    /// Sets the tooltip of the [`Tray`].
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Sets the [`Menu`] of the [`Tray`].
+    pub fn menu(mut self, menu: Menu) -> Self {
+        self.menu = menu;
+        self
+    }
+}
+
+/// The icon of a [`Tray`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Icon {
+    /// The pixels of the icon, in RGBA order.
+    pub rgba: Vec<u8>,
+
+    /// The width of the icon, in pixels.
+    pub width: u32,
+
+    /// The height of the icon, in pixels.
+    pub height: u32,
+}
+
+impl Icon {
+    /// Creates an [`Icon`] from its RGBA pixels and dimensions.
+    ///
+    /// The pixels are validated when the [`Icon`] is applied, producing an
+    /// [`Error::InvalidIcon`] if they do not match the dimensions.
+    pub fn from_rgba(rgba: Vec<u8>, width: u32, height: u32) -> Self {
+        Self {
+            rgba,
+            width,
+            height,
+        }
+    }
+}
+
+/// The menu of a [`Tray`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Menu {
+    /// The items of the menu, from top to bottom.
+    pub items: Vec<Item>,
+}
+
+impl Menu {
+    /// Creates an empty [`Menu`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an enabled entry with the given id and label to the [`Menu`].
+    ///
+    /// The id is reported in an [`Event::MenuItemSelected`] when the entry is
+    /// selected.
+    pub fn item(self, id: impl Into<String>, label: impl Into<String>) -> Self {
+        self.push(Item::Entry {
+            id: id.into(),
+            label: label.into(),
+            enabled: true,
+        })
+    }
+
+    /// Adds a separator to the [`Menu`].
+    pub fn separator(self) -> Self {
+        self.push(Item::Separator)
+    }
+
+    /// Adds an [`Item`] to the [`Menu`].
+    pub fn push(mut self, item: Item) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// An item of a [`Menu`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Item {
+    /// An entry that can be selected.
+    Entry {
+        /// The id reported when the entry is selected.
+        id: String,
+        /// The label of the entry.
+        label: String,
+        /// Whether the entry can be selected.
+        enabled: bool,
+    },
+
+    /// A line separating groups of entries.
+    Separator,
+}
+
+/// An interaction with a [`Tray`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The tray icon was clicked with the left mouse button.
+    Clicked,
+
+    /// The tray icon was clicked with the right mouse button.
+    RightClicked,
+
+    /// The tray icon was double clicked.
+    DoubleClicked,
+
+    /// An entry of the [`Menu`] with the given id was selected.
+    MenuItemSelected(String),
+}
+
+/// An error produced when applying a [`Tray`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The current platform does not support tray icons, or the runtime was
+    /// built without tray support.
+    Unsupported,
+
+    /// The pixels of the [`Icon`] do not match its dimensions.
+    InvalidIcon,
+
+    /// A [`Tray`] must be registered before it can be updated.
+    NotRegistered,
+
+    /// The platform failed to apply the [`Tray`].
+    Platform(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => {
+                write!(f, "tray icons are not supported on this platform")
+            }
+            Self::InvalidIcon => {
+                write!(f, "the icon pixels do not match its dimensions")
+            }
+            Self::NotRegistered => write!(f, "no tray icon is registered"),
+            Self::Platform(error) => write!(f, "platform error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,67 @@
+use crate::tray::{Error, Icon, Menu, Tray};
+
+use iced_futures::MaybeSend;
+use std::fmt;
+
+/// A tray action to be performed by some [`Command`].
+///
+/// Every action produces `T` with its result.
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Register a [`Tray`], replacing the current one.
+    Register(Tray, Box<dyn FnOnce(Result<(), Error>) -> T>),
+
+    /// Change the [`Icon`] of the registered [`Tray`].
+    SetIcon(Icon, Box<dyn FnOnce(Result<(), Error>) -> T>),
+
+    /// Change the tooltip of the registered [`Tray`].
+    SetTooltip(Option<String>, Box<dyn FnOnce(Result<(), Error>) -> T>),
+
+    /// Change the [`Menu`] of the registered [`Tray`].
+    SetMenu(Menu, Box<dyn FnOnce(Result<(), Error>) -> T>),
+
+    /// Remove the registered [`Tray`], if any.
+    Remove,
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a tray [`Action`] using the provided closure.
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + MaybeSend + Sync,
+    ) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Register(tray, o) => {
+                Action::Register(tray, Box::new(move |r| f(o(r))))
+            }
+            Self::SetIcon(icon, o) => {
+                Action::SetIcon(icon, Box::new(move |r| f(o(r))))
+            }
+            Self::SetTooltip(tooltip, o) => {
+                Action::SetTooltip(tooltip, Box::new(move |r| f(o(r))))
+            }
+            Self::SetMenu(menu, o) => {
+                Action::SetMenu(menu, Box::new(move |r| f(o(r))))
+            }
+            Self::Remove => Action::Remove,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Register(_, _) => write!(f, "Action::Register"),
+            Self::SetIcon(_, _) => write!(f, "Action::SetIcon"),
+            Self::SetTooltip(tooltip, _) => {
+                write!(f, "Action::SetTooltip({tooltip:?})")
+            }
+            Self::SetMenu(menu, _) => write!(f, "Action::SetMenu({menu:?})"),
+            Self::Remove => write!(f, "Action::Remove"),
+        }
+    }
+}
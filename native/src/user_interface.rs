@@ -1,13 +1,15 @@
 //! Implement your own event loop to drive a user interface.
 use crate::application;
+use crate::element;
 use crate::event::{self, Event};
+use crate::input_method::InputMethod;
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::widget;
 use crate::window;
 use crate::{
-    Clipboard, Element, Layout, Point, Rectangle, Shell, Size, Vector,
+    Clipboard, Color, Element, Layout, Rectangle, Shell, Size, Vector,
 };
 
 /// A set of interactive graphical elements with a specific [`Layout`].
@@ -30,6 +32,8 @@ pub struct UserInterface<'a, Message, Renderer> {
     state: widget::Tree,
     overlay: Option<layout::Node>,
     bounds: Size,
+    #[cfg(feature = "debug")]
+    traces: Option<Vec<event::Trace>>,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -111,9 +115,28 @@ where
             state,
             overlay: None,
             bounds,
+            #[cfg(feature = "debug")]
+            traces: None,
         }
     }
 
+    /// Starts recording an [`event::Trace`] for every [`Event`] processed by
+    /// [`UserInterface::update`].
+    ///
+    /// The recorded traces can be obtained with
+    /// [`UserInterface::event_traces`].
+    #[cfg(feature = "debug")]
+    pub fn trace_events(&mut self) {
+        let _ = self.traces.get_or_insert_with(Vec::new);
+    }
+
+    /// Returns the [`event::Trace`] of every [`Event`] processed since
+    /// [`UserInterface::trace_events`] was called.
+    #[cfg(feature = "debug")]
+    pub fn event_traces(&self) -> &[event::Trace] {
+        self.traces.as_deref().unwrap_or(&[])
+    }
+
     /// Updates the [`UserInterface`] by processing each provided [`Event`].
     ///
     /// It returns __messages__ that may have been produced as a result of user
@@ -124,7 +147,7 @@ where
     /// completing [the previous example](#example):
     ///
     /// ```no_run
-    /// use iced_native::{clipboard, Size, Point};
+    /// use iced_native::{clipboard, mouse, Size};
     /// use iced_native::user_interface::{self, UserInterface};
     /// use iced_wgpu::Renderer;
     ///
@@ -147,7 +170,7 @@ where
     /// let mut cache = user_interface::Cache::new();
     /// let mut renderer = Renderer::new();
     /// let mut window_size = Size::new(1024.0, 768.0);
-    /// let mut cursor_position = Point::default();
+    /// let mut cursor = mouse::Cursor::Unavailable;
     /// let mut clipboard = clipboard::Null;
     ///
     /// // Initialize our event storage
@@ -167,7 +190,7 @@ where
     ///     // Update the user interface
     ///     let (state, event_statuses) = user_interface.update(
     ///         &events,
-    ///         cursor_position,
+    ///         cursor,
     ///         &mut renderer,
     ///         &mut clipboard,
     ///         &mut messages
@@ -184,7 +207,7 @@ where
     pub fn update(
         &mut self,
         events: &[Event],
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &mut Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
@@ -193,6 +216,7 @@ where
 
         let mut outdated = false;
         let mut redraw_request = None;
+        let mut input_method = InputMethod::Disabled;
 
         let mut manual_overlay =
             ManuallyDrop::new(self.root.as_widget_mut().overlay(
@@ -201,6 +225,9 @@ where
                 renderer,
             ));
 
+        #[cfg(feature = "debug")]
+        let has_overlay = manual_overlay.is_some();
+
         let (base_cursor, overlay_statuses) = if manual_overlay.is_some() {
             let bounds = self.bounds;
 
@@ -214,7 +241,7 @@ where
                 let event_status = overlay.on_event(
                     event,
                     Layout::new(&layout),
-                    cursor_position,
+                    cursor,
                     renderer,
                     clipboard,
                     &mut shell,
@@ -232,9 +259,13 @@ where
                     _ => {}
                 }
 
+                input_method = input_method.merge(shell.input_method());
+
                 if shell.is_layout_invalid() {
                     let _ = ManuallyDrop::into_inner(manual_overlay);
 
+                    self.state.invalidate_layout();
+
                     self.base = renderer.layout(
                         &self.root,
                         &layout::Limits::new(Size::ZERO, self.bounds),
@@ -266,19 +297,18 @@ where
             let base_cursor = manual_overlay
                 .as_ref()
                 .filter(|overlay| {
-                    overlay.is_over(Layout::new(&layout), cursor_position)
-                })
-                .map(|_| {
-                    // TODO: Type-safe cursor availability
-                    Point::new(-1.0, -1.0)
+                    cursor.position().map_or(false, |cursor_position| {
+                        overlay.is_over(Layout::new(&layout), cursor_position)
+                    })
                 })
-                .unwrap_or(cursor_position);
+                .map(|_| mouse::Cursor::Unavailable)
+                .unwrap_or(cursor);
 
             self.overlay = Some(layout);
 
             (base_cursor, event_statuses)
         } else {
-            (cursor_position, vec![event::Status::Ignored; events.len()])
+            (cursor, vec![event::Status::Ignored; events.len()])
         };
 
         let _ = ManuallyDrop::into_inner(manual_overlay);
@@ -288,12 +318,36 @@ where
             .cloned()
             .zip(overlay_statuses.into_iter())
             .map(|(event, overlay_status)| {
+                #[cfg(feature = "debug")]
+                let trace = self.traces.as_ref().map(|_| {
+                    let mut trace = event::Trace::new(event.clone());
+
+                    if has_overlay {
+                        trace.set_overlay(overlay_status);
+                    }
+
+                    trace
+                });
+
                 if matches!(overlay_status, event::Status::Captured) {
+                    #[cfg(feature = "debug")]
+                    if let (Some(traces), Some(trace)) =
+                        (&mut self.traces, trace)
+                    {
+                        traces.push(trace);
+                    }
+
                     return overlay_status;
                 }
 
                 let mut shell = Shell::new(messages);
 
+                #[cfg(feature = "debug")]
+                if let Some(mut trace) = trace {
+                    trace.enter_root();
+                    shell.start_trace(trace);
+                }
+
                 let event_status = self.root.as_widget_mut().on_event(
                     &mut self.state,
                     event,
@@ -304,6 +358,14 @@ where
                     &mut shell,
                 );
 
+                #[cfg(feature = "debug")]
+                if let (Some(traces), Some(mut trace)) =
+                    (&mut self.traces, shell.finish_trace())
+                {
+                    trace.exit(event_status);
+                    traces.push(trace);
+                }
+
                 if matches!(event_status, event::Status::Captured) {
                     self.overlay = None;
                 }
@@ -318,7 +380,11 @@ where
                     _ => {}
                 }
 
+                input_method = input_method.merge(shell.input_method());
+
                 shell.revalidate_layout(|| {
+                    self.state.invalidate_layout();
+
                     self.base = renderer.layout(
                         &self.root,
                         &layout::Limits::new(Size::ZERO, self.bounds),
@@ -339,7 +405,10 @@ where
             if outdated {
                 State::Outdated
             } else {
-                State::Updated { redraw_request }
+                State::Updated {
+                    redraw_request,
+                    input_method,
+                }
             },
             event_statuses,
         )
@@ -360,7 +429,7 @@ where
     /// use iced_native::clipboard;
     /// use iced_native::renderer;
     /// use iced_native::user_interface::{self, UserInterface};
-    /// use iced_native::{Size, Point, Theme};
+    /// use iced_native::{mouse, Size, Theme};
     /// use iced_wgpu::Renderer;
     ///
     /// # mod iced_wgpu {
@@ -382,7 +451,7 @@ where
     /// let mut cache = user_interface::Cache::new();
     /// let mut renderer = Renderer::new();
     /// let mut window_size = Size::new(1024.0, 768.0);
-    /// let mut cursor_position = Point::default();
+    /// let mut cursor = mouse::Cursor::Unavailable;
     /// let mut clipboard = clipboard::Null;
     /// let mut events = Vec::new();
     /// let mut messages = Vec::new();
@@ -400,14 +469,14 @@ where
     ///     // Update the user interface
     ///     let event_statuses = user_interface.update(
     ///         &events,
-    ///         cursor_position,
+    ///         cursor,
     ///         &mut renderer,
     ///         &mut clipboard,
     ///         &mut messages
     ///     );
     ///
     ///     // Draw the user interface
-    ///     let mouse_cursor = user_interface.draw(&mut renderer, &Theme::default(), &renderer::Style::default(), cursor_position);
+    ///     let mouse_cursor = user_interface.draw(&mut renderer, &Theme::default(), &renderer::Style::default(), cursor);
     ///
     ///     cache = user_interface.into_cache();
     ///
@@ -424,7 +493,7 @@ where
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         style: &renderer::Style,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> mouse::Interaction {
         // TODO: Move to shell level (?)
         renderer.clear();
@@ -440,18 +509,17 @@ where
                 overlay.layout(renderer, self.bounds, Vector::ZERO)
             });
 
-            let new_cursor_position =
-                if overlay_layout.bounds().contains(cursor_position) {
-                    Point::new(-1.0, -1.0)
-                } else {
-                    cursor_position
-                };
+            let new_cursor = if cursor.is_over(&overlay_layout.bounds()) {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            };
 
             self.overlay = Some(overlay_layout);
 
-            new_cursor_position
+            new_cursor
         } else {
-            cursor_position
+            cursor
         };
 
         self.root.as_widget().draw(
@@ -467,7 +535,7 @@ where
         let base_interaction = self.root.as_widget().mouse_interaction(
             &self.state,
             Layout::new(&self.base),
-            cursor_position,
+            cursor,
             &viewport,
             renderer,
         );
@@ -492,7 +560,7 @@ where
                     .map(|overlay| {
                         let overlay_interaction = overlay.mouse_interaction(
                             Layout::new(layout),
-                            cursor_position,
+                            cursor,
                             &viewport,
                             renderer,
                         );
@@ -505,12 +573,21 @@ where
                                 theme,
                                 style,
                                 Layout::new(layout),
-                                cursor_position,
+                                cursor,
                             );
                         });
 
-                        if overlay.is_over(Layout::new(layout), cursor_position)
-                        {
+                        let is_over = cursor.position().map_or(
+                            false,
+                            |cursor_position| {
+                                overlay.is_over(
+                                    Layout::new(layout),
+                                    cursor_position,
+                                )
+                            },
+                        );
+
+                        if is_over {
                             overlay_interaction
                         } else {
                             base_interaction
@@ -520,6 +597,27 @@ where
             .unwrap_or(base_interaction)
     }
 
+    /// Outlines the layout bounds of every widget of the [`UserInterface`]
+    /// with the given [`Color`], like [`Element::explain`] does.
+    ///
+    /// It should be called right after [`UserInterface::draw`], so the
+    /// outlines are drawn on top. The overlay drawn last, if any, is outlined
+    /// too.
+    pub fn explain(&self, renderer: &mut Renderer, color: Color) {
+        element::explain(
+            renderer,
+            color,
+            Layout::new(&self.base),
+            Some(&self.state),
+        );
+
+        if let Some(overlay) = &self.overlay {
+            renderer.with_layer(overlay.bounds(), |renderer| {
+                element::explain(renderer, color, Layout::new(overlay), None);
+            });
+        }
+    }
+
     /// Applies a [`widget::Operation`] to the [`UserInterface`].
     pub fn operate(
         &mut self,
@@ -553,8 +651,28 @@ where
 
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
+    ///
+    /// The widget tree was already reconciled when the [`UserInterface`] was
+    /// built, so the layout caches of the widgets are kept; only the parts of
+    /// the layout that depend on the new bounds are computed again.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(self.root, bounds, Cache { state: self.state }, renderer)
+        let base = renderer
+            .layout(&self.root, &layout::Limits::new(Size::ZERO, bounds));
+
+        UserInterface {
+            base,
+            overlay: None,
+            bounds,
+            ..self
+        }
+    }
+
+    /// Returns the sum of the [`Statistics`] of the layout caches of all the
+    /// widgets of the [`UserInterface`].
+    ///
+    /// [`Statistics`]: layout::cache::Statistics
+    pub fn layout_statistics(&self) -> layout::cache::Statistics {
+        self.state.layout_statistics()
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
@@ -599,5 +717,8 @@ pub enum State {
     Updated {
         /// The [`Instant`] when a redraw should be performed.
         redraw_request: Option<window::RedrawRequest>,
+
+        /// The [`InputMethod`] requested by the widgets.
+        input_method: InputMethod,
     },
 }
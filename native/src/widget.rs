@@ -13,10 +13,16 @@
 //! [renderer]: crate::renderer
 pub mod button;
 pub mod checkbox;
+pub mod choices;
 pub mod column;
 pub mod container;
+pub mod drag_value;
 pub mod helpers;
 pub mod image;
+pub mod keyed;
+pub mod label;
+pub mod menu_bar;
+pub mod opacity;
 pub mod operation;
 pub mod pane_grid;
 pub mod pick_list;
@@ -27,9 +33,12 @@ pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
+pub mod spinner;
+pub mod split_button;
 pub mod svg;
 pub mod text;
 pub mod text_input;
+pub mod toast;
 pub mod toggler;
 pub mod tooltip;
 pub mod tree;
@@ -43,14 +52,22 @@ pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use choices::Choices;
+#[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use drag_value::DragValue;
+#[doc(no_inline)]
 pub use helpers::*;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -69,6 +86,10 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use split_button::SplitButton;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
 pub use text::Text;
@@ -92,13 +113,42 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
-use crate::{Clipboard, Layout, Length, Point, Rectangle, Shell};
+use crate::{Clipboard, Layout, Length, Rectangle, Shell};
 
 /// A component that displays information and allows interaction.
 ///
 /// If you want to build your own widgets, you will need to implement this
 /// trait.
 ///
+/// Only [`width`], [`height`], [`layout`], and [`draw`] are required. Every
+/// other method has a default that suits a stateless leaf widget, which
+/// neither reacts to events nor contains other widgets. The ones commonly
+/// overridden are:
+///
+/// - [`tag`] and [`state`], by widgets that keep some local state in the
+/// [`Tree`], like the cursor of a [`TextInput`].
+/// - [`children`] and [`diff`], by widgets that contain other widgets. They
+/// must be overridden together, so the state of the children is created and
+/// reconciled alike—[`Tree::diff_children`] does the latter for most
+/// containers.
+/// - [`on_event`] and [`mouse_interaction`], by interactive widgets.
+/// - [`operate`] and [`overlay`], by widgets that contain other widgets—which
+/// should forward them to their children—or that can be focused or show
+/// an overlay themselves.
+///
+/// [`width`]: Self::width
+/// [`height`]: Self::height
+/// [`layout`]: Self::layout
+/// [`draw`]: Self::draw
+/// [`tag`]: Self::tag
+/// [`state`]: Self::state
+/// [`children`]: Self::children
+/// [`diff`]: Self::diff
+/// [`on_event`]: Self::on_event
+/// [`mouse_interaction`]: Self::mouse_interaction
+/// [`operate`]: Self::operate
+/// [`overlay`]: Self::overlay
+///
 /// # Examples
 /// The repository has some [examples] showcasing how to implement a custom
 /// widget:
@@ -136,6 +186,21 @@ where
         limits: &layout::Limits,
     ) -> layout::Node;
 
+    /// Returns the distance from the top of the given [`layout::Node`] of the
+    /// [`Widget`] to its first baseline, if it has one.
+    ///
+    /// It is used to align widgets with [`Alignment::Baseline`]. Widgets that
+    /// return `None`—the default—are aligned by their bottom edge instead.
+    ///
+    /// [`Alignment::Baseline`]: crate::Alignment::Baseline
+    fn baseline(
+        &self,
+        _renderer: &Renderer,
+        _layout: &layout::Node,
+    ) -> Option<f32> {
+        None
+    }
+
     /// Draws the [`Widget`] using the associated `Renderer`.
     fn draw(
         &self,
@@ -144,33 +209,56 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     );
 
     /// Returns the [`Tag`] of the [`Widget`].
     ///
+    /// By default, it returns [`Tag::stateless`]. A [`Tree`] is recreated
+    /// whenever its [`Tag`] changes.
+    ///
     /// [`Tag`]: tree::Tag
+    /// [`Tag::stateless`]: tree::Tag::stateless
     fn tag(&self) -> tree::Tag {
         tree::Tag::stateless()
     }
 
     /// Returns the [`State`] of the [`Widget`].
     ///
+    /// By default, it returns [`State::None`].
+    ///
     /// [`State`]: tree::State
+    /// [`State::None`]: tree::State::None
     fn state(&self) -> tree::State {
         tree::State::None
     }
 
     /// Returns the state [`Tree`] of the children of the [`Widget`].
+    ///
+    /// By default, it returns no children. Widgets that contain other widgets
+    /// must override it together with [`diff`].
+    ///
+    /// [`diff`]: Self::diff
     fn children(&self) -> Vec<Tree> {
         Vec::new()
     }
 
     /// Reconciliates the [`Widget`] with the provided [`Tree`].
+    ///
+    /// It is only called when the [`Tag`] of the [`Tree`] matches the one of
+    /// the [`Widget`]. By default, it does nothing, which keeps the [`Tree`]
+    /// as it is. Widgets that contain other widgets must override it to
+    /// reconcile the state of their children, usually with
+    /// [`Tree::diff_children`].
+    ///
+    /// [`Tag`]: tree::Tag
     fn diff(&self, _tree: &mut Tree) {}
 
     /// Applies an [`Operation`] to the [`Widget`].
+    ///
+    /// By default, it does nothing. Widgets that contain other widgets should
+    /// forward the [`Operation`] to their children.
     fn operate(
         &self,
         _state: &mut Tree,
@@ -188,7 +276,7 @@ where
         _state: &mut Tree,
         _event: Event,
         _layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         _shell: &mut Shell<'_, Message>,
@@ -203,7 +291,7 @@ where
         &self,
         _state: &Tree,
         _layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
@@ -211,6 +299,10 @@ where
     }
 
     /// Returns the overlay of the [`Widget`], if there is any.
+    ///
+    /// By default, it returns `None`. Widgets that contain other widgets
+    /// should return the overlays of their children—with
+    /// [`overlay::from_children`], for instance.
     fn overlay<'a>(
         &'a mut self,
         _state: &'a mut Tree,
@@ -2,6 +2,7 @@ use crate::widget::operation::{
     self, Focusable, Operation, Scrollable, TextInput,
 };
 use crate::widget::Id;
+use crate::Rectangle;
 
 use iced_futures::MaybeSend;
 
@@ -72,6 +73,10 @@ where
                 });
             }
 
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+                self.operation.bounds(bounds, id);
+            }
+
             fn scrollable(
                 &mut self,
                 state: &mut dyn Scrollable,
@@ -99,6 +104,10 @@ where
             fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                 self.operation.custom(state, id);
             }
+
+            fn target(&self) -> Option<&Id> {
+                self.operation.target()
+            }
         }
 
         let Self { operation, .. } = self;
@@ -109,6 +118,10 @@ where
         .container(id, operate_on_children);
     }
 
+    fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+        self.operation.bounds(bounds, id);
+    }
+
     fn focusable(
         &mut self,
         state: &mut dyn operation::Focusable,
@@ -137,6 +150,10 @@ where
         self.operation.custom(state, id);
     }
 
+    fn target(&self) -> Option<&Id> {
+        self.operation.target()
+    }
+
     fn finish(&self) -> operation::Outcome<B> {
         match self.operation.finish() {
             operation::Outcome::None => operation::Outcome::None,
@@ -2,19 +2,29 @@
 //!
 //! A [`Button`] has some local [`State`].
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::time::{Duration, Instant};
 use crate::touch;
+use crate::widget;
+use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
-use crate::widget::Operation;
+use crate::window;
 use crate::{
-    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
-    Rectangle, Shell, Vector, Widget,
+    Background, Clipboard, Color, Command, Element, Layout, Length, Padding,
+    Point, Rectangle, Shell, Vector, Widget,
 };
 
 pub use iced_style::button::{Appearance, StyleSheet};
+pub use iced_style::transition::DEFAULT_DURATION;
+
+use iced_style::theme::cache::Cache;
+use iced_style::transition::Transition;
+
+use std::cell::Cell;
 
 /// A generic widget that produces a message when pressed.
 ///
@@ -50,17 +60,26 @@ pub use iced_style::button::{Appearance, StyleSheet};
 ///     disabled_button().on_press(Message::ButtonPressed)
 /// }
 /// ```
+///
+/// An enabled [`Button`] can be focused, and a focused [`Button`] is pressed
+/// with <kbd>Space</kbd> when the key is pressed, or with <kbd>Enter</kbd>
+/// when the key is released. A focus ring is drawn around the [`Button`] only
+/// when it is focused with the keyboard—with [`focus`] or the focus
+/// operations—and not when it is clicked.
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Renderer>
 where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Option<Id>,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
     width: Length,
     height: Length,
     padding: Padding,
+    ripple: bool,
+    transition: Option<Duration>,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -72,15 +91,24 @@ where
     /// Creates a new [`Button`] with the given content.
     pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
         Button {
+            id: None,
             content: content.into(),
             on_press: None,
             width: Length::Shrink,
             height: Length::Shrink,
             padding: Padding::new(5),
+            ripple: false,
+            transition: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Button`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -101,12 +129,35 @@ where
 
     /// Sets the message that will be produced when the [`Button`] is pressed.
     ///
+    /// Only the left mouse button presses a [`Button`]; other buttons are
+    /// ignored.
+    ///
     /// Unless `on_press` is called, the [`Button`] will be disabled.
     pub fn on_press(mut self, msg: Message) -> Self {
         self.on_press = Some(msg);
         self
     }
 
+    /// Sets whether the [`Button`] shows a ripple when pressed.
+    ///
+    /// A ripple is a circle that expands from the press position while
+    /// fading out, clipped to the bounds of the [`Button`]. Its color is
+    /// given by [`StyleSheet::ripple`].
+    pub fn ripple(mut self, ripple: bool) -> Self {
+        self.ripple = ripple;
+        self
+    }
+
+    /// Sets the duration of the transition between the appearances of the
+    /// [`Button`] when it is hovered, pressed, or disabled.
+    ///
+    /// A zero duration switches appearances immediately. By default, it is
+    /// given by [`StyleSheet::transition`].
+    pub fn transition(mut self, duration: Duration) -> Self {
+        self.transition = Some(duration);
+        self
+    }
+
     /// Sets the style variant of this [`Button`].
     pub fn style(
         mut self,
@@ -129,7 +180,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::with_debug(State::new())
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -165,6 +216,19 @@ where
         )
     }
 
+    fn baseline(
+        &self,
+        renderer: &Renderer,
+        layout: &layout::Node,
+    ) -> Option<f32> {
+        let content = layout.children().first()?;
+
+        self.content
+            .as_widget()
+            .baseline(renderer, content)
+            .map(|baseline| content.bounds().y + baseline)
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -172,6 +236,15 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        // A disabled button cannot be pressed, so it is skipped when moving
+        // the focus around
+        if self.on_press.is_some() {
+            operation.focusable(
+                tree.state.downcast_mut::<State>(),
+                self.id.as_ref().map(|id| &id.0),
+            );
+        }
+
         operation.container(None, &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -187,29 +260,37 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        if let event::Status::Captured = self.content.as_widget_mut().on_event(
-            &mut tree.children[0],
-            event.clone(),
-            layout.children().next().unwrap(),
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        ) {
+        if let event::Status::Captured = shell.traced(0, |shell| {
+            self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+            )
+        }) {
             return event::Status::Captured;
         }
 
+        let transition = self.transition.unwrap_or_else(|| {
+            tree.state.downcast_ref::<State>().theme_transition.get()
+        });
+
         update(
             event,
             layout,
-            cursor_position,
+            cursor,
             shell,
             &self.on_press,
+            self.ripple,
+            transition,
             || tree.state.downcast_mut::<State>(),
         )
     }
@@ -221,22 +302,36 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
 
+        tree.state
+            .downcast_ref::<State>()
+            .theme_transition
+            .set(theme.transition(&self.style));
+
         let styling = draw(
             renderer,
             bounds,
-            cursor_position,
+            cursor,
             self.on_press.is_some(),
             theme,
             &self.style,
             || tree.state.downcast_ref::<State>(),
         );
 
+        if self.ripple {
+            draw_ripples(
+                renderer,
+                bounds,
+                theme.ripple(&self.style),
+                tree.state.downcast_ref::<State>(),
+            );
+        }
+
         self.content.as_widget().draw(
             &tree.children[0],
             renderer,
@@ -245,7 +340,7 @@ where
                 text_color: styling.text_color,
             },
             content_layout,
-            cursor_position,
+            cursor,
             &bounds,
         );
     }
@@ -254,11 +349,11 @@ where
         &self,
         _tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(layout, cursor_position, self.on_press.is_some())
+        mouse_interaction(layout, cursor, self.on_press.is_some())
     }
 
     fn overlay<'b>(
@@ -288,39 +383,254 @@ where
 }
 
 /// The local state of a [`Button`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_enter_pressed: bool,
+    focus: Option<Focus>,
+    ripples: Vec<Ripple>,
+    transition: Option<Transition<Status>>,
+    // Events do not know the theme, so its transition is kept when drawing
+    theme_transition: Cell<Duration>,
+    appearances: Cache<(u64, Status), Appearance>,
+}
+
+/// The way a [`Button`] was focused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    /// The [`Button`] was clicked or touched, so its focus ring is hidden.
+    Pointer,
+    /// The [`Button`] was focused with the keyboard, so its focus ring is
+    /// visible.
+    Keyboard,
+}
+
+/// The status of a [`Button`], which determines its [`Appearance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Status {
+    Active,
+    Hovered,
+    Pressed,
+    Disabled,
 }
 
+impl Status {
+    fn new(
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        is_enabled: bool,
+        is_pressed: bool,
+    ) -> Self {
+        if !is_enabled {
+            Status::Disabled
+        } else if cursor.is_over(&bounds) {
+            if is_pressed {
+                Status::Pressed
+            } else {
+                Status::Hovered
+            }
+        } else {
+            Status::Active
+        }
+    }
+
+    fn appearance<Style: Default>(
+        self,
+        style_sheet: &dyn StyleSheet<Style = Style>,
+        style: &Style,
+    ) -> Appearance {
+        match self {
+            Status::Active => style_sheet.active(style),
+            Status::Hovered => style_sheet.hovered(style),
+            Status::Pressed => style_sheet.pressed(style),
+            Status::Disabled => style_sheet.disabled(style),
+        }
+    }
+}
+
+/// A ripple of a [`Button`], expanding from the position it was pressed at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Ripple {
+    origin: Vector,
+    started_at: Instant,
+    progress: f32,
+}
+
+/// The time it takes a ripple to expand and fade out completely.
+const RIPPLE_DURATION: Duration = Duration::from_millis(600);
+
+/// The width of the focus ring of a [`Button`].
+const FOCUS_RING_WIDTH: f32 = 2.0;
+
+/// The space between a [`Button`] and its focus ring.
+const FOCUS_RING_OFFSET: f32 = 2.0;
+
 impl State {
     /// Creates a new [`State`].
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_some()
+    }
+
+    /// Returns whether the [`Button`] is focused with the keyboard, and its
+    /// focus ring is visible.
+    pub fn is_focus_visible(&self) -> bool {
+        self.focus == Some(Focus::Keyboard)
+    }
+
+    /// Focuses the [`Button`] as if it was done with the keyboard, showing
+    /// its focus ring.
+    pub fn focus(&mut self) {
+        self.focus = Some(Focus::Keyboard);
+    }
+
+    /// Unfocuses the [`Button`].
+    pub fn unfocus(&mut self) {
+        self.focus = None;
+        self.is_enter_pressed = false;
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+/// The identifier of a [`Button`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`Button`] with the given [`Id`].
+///
+/// The focus ring of the [`Button`] is shown, since the focus was not moved
+/// with a click.
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
 }
 
 /// Processes the given [`Event`] and updates the [`State`] of a [`Button`]
 /// accordingly.
+///
+/// The appearance of the [`Button`] eases towards its current status over
+/// the `transition` duration, requesting a redraw until it is done.
 pub fn update<'a, Message: Clone>(
     event: Event,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     shell: &mut Shell<'_, Message>,
     on_press: &Option<Message>,
+    ripple: bool,
+    transition: Duration,
     state: impl FnOnce() -> &'a mut State,
+) -> event::Status {
+    let state = state();
+
+    let now = match event {
+        Event::Window(window::Event::RedrawRequested(now)) => now,
+        _ => Instant::now(),
+    };
+
+    let status = process(event, layout, cursor, shell, on_press, ripple, state);
+
+    let target = Status::new(
+        layout.bounds(),
+        cursor,
+        on_press.is_some(),
+        state.is_pressed,
+    );
+
+    match &mut state.transition {
+        Some(current) => {
+            current.retarget(target, now, transition);
+            current.update(now, transition);
+
+            if current.is_animating() {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        None => {
+            // The first status of a button is shown without a transition
+            state.transition = Some(Transition::new(target));
+        }
+    }
+
+    status
+}
+
+fn process<Message: Clone>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+    on_press: &Option<Message>,
+    ripple: bool,
+    state: &mut State,
 ) -> event::Status {
     match event {
-        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        Event::Mouse(mouse::Event::ButtonPressed(_))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            if on_press.is_some() {
-                let bounds = layout.bounds();
+            let bounds = layout.bounds();
+            let is_left = !matches!(
+                event,
+                Event::Mouse(mouse::Event::ButtonPressed(button))
+                    if button != mouse::Button::Left
+            );
 
-                if bounds.contains(cursor_position) {
-                    let state = state();
+            // Clicking a button focuses it without showing its focus ring,
+            // while clicking anywhere else unfocuses it
+            if on_press.is_some() && cursor.is_over(&bounds) {
+                state.focus = Some(Focus::Pointer);
+            } else {
+                state.unfocus();
+            }
 
+            if on_press.is_some() && is_left {
+                if let Some(cursor_position) = cursor.position_over(&bounds) {
                     state.is_pressed = true;
 
+                    if ripple {
+                        state.ripples.push(Ripple {
+                            origin: cursor_position - bounds.position(),
+                            started_at: Instant::now(),
+                            progress: 0.0,
+                        });
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+
                     return event::Status::Captured;
                 }
             }
@@ -328,14 +638,12 @@ pub fn update<'a, Message: Clone>(
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. }) => {
             if let Some(on_press) = on_press.clone() {
-                let state = state();
-
                 if state.is_pressed {
                     state.is_pressed = false;
 
                     let bounds = layout.bounds();
 
-                    if bounds.contains(cursor_position) {
+                    if cursor.is_over(&bounds) {
                         shell.publish(on_press);
                     }
 
@@ -344,10 +652,56 @@ pub fn update<'a, Message: Clone>(
             }
         }
         Event::Touch(touch::Event::FingerLost { .. }) => {
-            let state = state();
-
             state.is_pressed = false;
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. })
+            if state.is_focused() =>
+        {
+            if let Some(on_press) = on_press.clone() {
+                match key_code {
+                    keyboard::KeyCode::Space => {
+                        shell.publish(on_press);
+
+                        // Capturing the key keeps any parent scrollable from
+                        // scrolling by a page
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        state.is_enter_pressed = true;
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyReleased {
+            key_code: keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter,
+            ..
+        }) if state.is_enter_pressed => {
+            state.is_enter_pressed = false;
+
+            if let Some(on_press) = on_press.clone() {
+                shell.publish(on_press);
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Window(window::Event::RedrawRequested(now)) => {
+            if !state.ripples.is_empty() {
+                state.ripples.retain_mut(|ripple| {
+                    ripple.progress = now
+                        .saturating_duration_since(ripple.started_at)
+                        .as_secs_f32()
+                        / RIPPLE_DURATION.as_secs_f32();
+
+                    ripple.progress < 1.0
+                });
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
         _ => {}
     }
 
@@ -358,7 +712,7 @@ pub fn update<'a, Message: Clone>(
 pub fn draw<'a, Renderer: crate::Renderer>(
     renderer: &mut Renderer,
     bounds: Rectangle,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     is_enabled: bool,
     style_sheet: &dyn StyleSheet<
         Style = <Renderer::Theme as StyleSheet>::Style,
@@ -369,20 +723,27 @@ pub fn draw<'a, Renderer: crate::Renderer>(
 where
     Renderer::Theme: StyleSheet,
 {
-    let is_mouse_over = bounds.contains(cursor_position);
+    let state = state();
 
-    let styling = if !is_enabled {
-        style_sheet.disabled(style)
-    } else if is_mouse_over {
-        let state = state();
+    let status = Status::new(bounds, cursor, is_enabled, state.is_pressed);
 
-        if state.is_pressed {
-            style_sheet.pressed(style)
-        } else {
-            style_sheet.hovered(style)
+    let key = style_sheet.cache_key(style);
+    let appearance = |status: Status| match key {
+        Some(key) => {
+            state.appearances.get(key.theme, (key.style, status), || {
+                status.appearance(style_sheet, style)
+            })
         }
-    } else {
-        style_sheet.active(style)
+        None => status.appearance(style_sheet, style),
+    };
+
+    // Fall back to the current status if the transition is out of sync,
+    // since the button may have been drawn without being updated first
+    let styling = match &state.transition {
+        Some(transition) if transition.target() == status => {
+            transition.appearance(appearance)
+        }
+        _ => appearance(status),
     };
 
     if styling.background.is_some() || styling.border_width > 0.0 {
@@ -416,9 +777,69 @@ where
         );
     }
 
+    if is_enabled && state.is_focus_visible() {
+        let spread = FOCUS_RING_OFFSET + FOCUS_RING_WIDTH;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: bounds.expand(spread),
+                border_radius: (styling.border_radius + spread).into(),
+                border_width: FOCUS_RING_WIDTH,
+                border_color: style_sheet.focus_ring(style),
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
     styling
 }
 
+/// Draws the ongoing ripples of a [`Button`] with the given [`Color`].
+pub fn draw_ripples<Renderer: crate::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+    state: &State,
+) {
+    if state.ripples.is_empty() {
+        return;
+    }
+
+    renderer.with_layer(bounds, |renderer| {
+        for ripple in &state.ripples {
+            // The ripple covers the whole button when done, so it needs to
+            // reach the corner farthest from its origin
+            let max_radius = Vector::new(
+                ripple.origin.x.max(bounds.width - ripple.origin.x),
+                ripple.origin.y.max(bounds.height - ripple.origin.y),
+            );
+            let max_radius = max_radius.x.hypot(max_radius.y);
+
+            // Ease out, so the ripple expands quickly at first
+            let expansion = 1.0 - (1.0 - ripple.progress).powi(2);
+            let radius = max_radius * expansion;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + ripple.origin.x - radius,
+                        y: bounds.y + ripple.origin.y - radius,
+                        width: radius * 2.0,
+                        height: radius * 2.0,
+                    },
+                    border_radius: radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Color {
+                    a: color.a * (1.0 - ripple.progress),
+                    ..color
+                },
+            );
+        }
+    });
+}
+
 /// Computes the layout of a [`Button`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -442,10 +863,10 @@ pub fn layout<Renderer>(
 /// Returns the [`mouse::Interaction`] of a [`Button`].
 pub fn mouse_interaction(
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     is_enabled: bool,
 ) -> mouse::Interaction {
-    let is_mouse_over = layout.bounds().contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&layout.bounds());
 
     if is_mouse_over && is_enabled {
         mouse::Interaction::Pointer
@@ -453,3 +874,336 @@ pub fn mouse_interaction(
         mouse::Interaction::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Recorder;
+    use crate::Size;
+
+    use iced_style::theme;
+
+    #[test]
+    fn ripples_fade_independently() {
+        let node = layout::Node::new(Size::new(100.0, 40.0));
+        let layout = Layout::new(&node);
+        let on_press = Some(());
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let mut state = State::new();
+
+        let press = |state: &mut State, shell: &mut Shell<'_, ()>| {
+            for event in [
+                mouse::Event::ButtonPressed(mouse::Button::Left),
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+            ] {
+                let _ = update(
+                    Event::Mouse(event),
+                    layout,
+                    mouse::Cursor::Available(Point::new(10.0, 10.0)),
+                    shell,
+                    &on_press,
+                    true,
+                    DEFAULT_DURATION,
+                    || state,
+                );
+            }
+        };
+
+        press(&mut state, &mut shell);
+        press(&mut state, &mut shell);
+
+        assert_eq!(state.ripples.len(), 2);
+        assert_eq!(state.ripples[0].origin, Vector::new(10.0, 10.0));
+
+        let first_started_at = state.ripples[0].started_at;
+        state.ripples[1].started_at = first_started_at + RIPPLE_DURATION / 2;
+
+        let _ = update(
+            Event::Window(window::Event::RedrawRequested(
+                first_started_at + RIPPLE_DURATION,
+            )),
+            layout,
+            mouse::Cursor::Available(Point::new(10.0, 10.0)),
+            &mut shell,
+            &on_press,
+            true,
+            DEFAULT_DURATION,
+            || &mut state,
+        );
+
+        assert_eq!(state.ripples.len(), 1);
+        assert!((state.ripples[0].progress - 0.5).abs() < 0.01);
+        assert_eq!(
+            shell.redraw_request(),
+            Some(window::RedrawRequest::NextFrame)
+        );
+    }
+
+    #[test]
+    fn transitions_retarget_from_the_current_appearance() {
+        let node = layout::Node::new(Size::new(100.0, 40.0));
+        let layout = Layout::new(&node);
+        let on_press = Some(());
+        let duration = Duration::from_millis(100);
+
+        let mut messages = Vec::new();
+        let mut state = State::new();
+
+        let mut update_at = |state: &mut State, event, cursor| {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = update(
+                event,
+                layout,
+                cursor,
+                &mut shell,
+                &on_press,
+                false,
+                duration,
+                || state,
+            );
+
+            shell.redraw_request()
+        };
+
+        let redraw = |now| Event::Window(window::Event::RedrawRequested(now));
+
+        // Shades of the background of the button for every status
+        let shade =
+            |state: &State| {
+                state.transition.as_ref().unwrap().appearance(|status| {
+                    match status {
+                        Status::Active => 0.0,
+                        Status::Hovered => 1.0,
+                        Status::Pressed | Status::Disabled => 2.0,
+                    }
+                })
+            };
+
+        let start = Instant::now();
+        let outside = mouse::Cursor::Available(Point::new(-10.0, -10.0));
+        let inside = mouse::Cursor::Available(Point::new(10.0, 10.0));
+
+        // The first status is shown without a transition
+        assert_eq!(update_at(&mut state, redraw(start), outside), None);
+        assert_eq!(shade(&state), 0.0);
+
+        let _ = update_at(&mut state, redraw(start), inside);
+        assert_eq!(
+            update_at(&mut state, redraw(start + duration / 2), inside),
+            Some(window::RedrawRequest::NextFrame)
+        );
+        let halfway = shade(&state);
+        assert!(halfway > 0.0 && halfway < 1.0);
+
+        // Leaving halfway eases back from the current shade
+        let _ = update_at(&mut state, redraw(start + duration / 2), outside);
+        assert_eq!(shade(&state), halfway);
+
+        let _ = update_at(&mut state, redraw(start + duration), outside);
+        assert!(shade(&state) < halfway);
+
+        assert_eq!(
+            update_at(&mut state, redraw(start + duration * 2), outside),
+            None
+        );
+        assert_eq!(shade(&state), 0.0);
+        assert!(!state.transition.as_ref().unwrap().is_animating());
+    }
+
+    #[test]
+    fn keyboard_presses_focused_buttons() {
+        let node = layout::Node::new(Size::new(100.0, 40.0));
+        let layout = Layout::new(&node);
+        let on_press = Some(());
+
+        let mut messages = Vec::new();
+        let mut state = State::new();
+
+        let mut key = |state: &mut State, event| {
+            let mut shell = Shell::new(&mut messages);
+
+            update(
+                Event::Keyboard(event),
+                layout,
+                mouse::Cursor::Available(Point::new(-10.0, -10.0)),
+                &mut shell,
+                &on_press,
+                false,
+                DEFAULT_DURATION,
+                || state,
+            )
+        };
+
+        let pressed = |key_code| keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        };
+        let released = |key_code| keyboard::Event::KeyReleased {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        };
+
+        // Unfocused buttons ignore the keyboard
+        assert_eq!(
+            key(&mut state, pressed(keyboard::KeyCode::Space)),
+            event::Status::Ignored
+        );
+
+        operation::Focusable::focus(&mut state);
+        assert!(state.is_focus_visible());
+
+        // Space presses on press, and it is captured so it does not scroll
+        assert_eq!(
+            key(&mut state, pressed(keyboard::KeyCode::Space)),
+            event::Status::Captured
+        );
+        assert_eq!(
+            key(&mut state, released(keyboard::KeyCode::Space)),
+            event::Status::Ignored
+        );
+
+        // Enter presses on release
+        assert_eq!(
+            key(&mut state, pressed(keyboard::KeyCode::Enter)),
+            event::Status::Captured
+        );
+        assert_eq!(
+            key(&mut state, released(keyboard::KeyCode::Enter)),
+            event::Status::Captured
+        );
+
+        // Releasing a key that was pressed elsewhere does nothing
+        assert_eq!(
+            key(&mut state, released(keyboard::KeyCode::Enter)),
+            event::Status::Ignored
+        );
+
+        assert_eq!(messages.len(), 2);
+    }
+
+    #[test]
+    fn clicks_focus_without_a_focus_ring() {
+        let node = layout::Node::new(Size::new(100.0, 40.0));
+        let layout = Layout::new(&node);
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let mut state = State::new();
+
+        let mut click = |state: &mut State, cursor, on_press| {
+            let _ = update(
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                layout,
+                cursor,
+                &mut shell,
+                &on_press,
+                false,
+                DEFAULT_DURATION,
+                || state,
+            );
+        };
+
+        click(
+            &mut state,
+            mouse::Cursor::Available(Point::new(10.0, 10.0)),
+            Some(()),
+        );
+        assert!(state.is_focused());
+        assert!(!state.is_focus_visible());
+
+        click(
+            &mut state,
+            mouse::Cursor::Available(Point::new(-10.0, -10.0)),
+            Some(()),
+        );
+        assert!(!state.is_focused());
+
+        // Disabled buttons cannot be focused
+        click(
+            &mut state,
+            mouse::Cursor::Available(Point::new(10.0, 10.0)),
+            None,
+        );
+        assert!(!state.is_focused());
+    }
+
+    /// Counts the appearances it produces, which are cached with its `id`
+    /// except for text buttons.
+    struct Counting {
+        id: u64,
+        produced: Cell<usize>,
+    }
+
+    impl StyleSheet for Counting {
+        type Style = theme::Button;
+
+        fn active(&self, _style: &theme::Button) -> Appearance {
+            self.produced.set(self.produced.get() + 1);
+
+            Appearance::default()
+        }
+
+        fn cache_key(
+            &self,
+            style: &theme::Button,
+        ) -> Option<theme::cache::Key> {
+            match style {
+                theme::Button::Text => None,
+                _ => Some(theme::cache::Key {
+                    theme: self.id,
+                    style: 0,
+                }),
+            }
+        }
+    }
+
+    #[test]
+    fn appearances_are_cached_until_the_theme_changes() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0));
+        let inside = mouse::Cursor::Available(Point::new(10.0, 10.0));
+        let outside = mouse::Cursor::Available(Point::new(-10.0, -10.0));
+
+        let state = State::new();
+        let mut renderer = Recorder::new();
+        let mut style_sheet = Counting {
+            id: 0,
+            produced: Cell::new(0),
+        };
+
+        let mut draw_with = |style_sheet: &Counting, style, cursor| {
+            let _ = draw(
+                &mut renderer,
+                bounds,
+                cursor,
+                true,
+                style_sheet,
+                &style,
+                || &state,
+            );
+
+            style_sheet.produced.get()
+        };
+
+        assert_eq!(draw_with(&style_sheet, theme::Button::Primary, outside), 1);
+        assert_eq!(draw_with(&style_sheet, theme::Button::Primary, outside), 1);
+
+        // Every status has its own appearance
+        assert_eq!(draw_with(&style_sheet, theme::Button::Primary, inside), 2);
+        assert_eq!(draw_with(&style_sheet, theme::Button::Primary, inside), 2);
+        assert_eq!(draw_with(&style_sheet, theme::Button::Primary, outside), 2);
+
+        // A new theme discards all of them
+        style_sheet.id = 1;
+
+        assert_eq!(draw_with(&style_sheet, theme::Button::Primary, outside), 3);
+        assert_eq!(draw_with(&style_sheet, theme::Button::Primary, inside), 4);
+
+        // Styles without a key are never cached
+        assert_eq!(draw_with(&style_sheet, theme::Button::Text, outside), 5);
+        assert_eq!(draw_with(&style_sheet, theme::Button::Text, outside), 6);
+    }
+}
@@ -6,14 +6,15 @@ use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::touch;
-use crate::widget::{self, Row, Text, Tree};
+use crate::widget::{self, label, Tree};
 use crate::{
-    Alignment, Clipboard, Element, Layout, Length, Point, Rectangle, Shell,
-    Widget,
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Size, Widget,
 };
 
 pub use iced_style::checkbox::{Appearance, StyleSheet};
 
+use iced_style::disabled;
+
 /// A box that can be checked.
 ///
 /// # Example
@@ -38,13 +39,17 @@ where
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
     is_checked: bool,
+    is_disabled: bool,
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     label: String,
     width: Length,
-    size: u16,
+    size: Option<f32>,
+    stroke_width: Option<f32>,
     spacing: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
+    label_position: label::Position,
+    label_anchor: label::Anchor,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -53,8 +58,8 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet + widget::text::StyleSheet,
 {
-    /// The default size of a [`Checkbox`].
-    const DEFAULT_SIZE: u16 = 20;
+    /// The default size of a [`Checkbox`], relative to its text size.
+    const DEFAULT_SIZE_RATIO: f32 = 1.0;
 
     /// The default spacing of a [`Checkbox`].
     const DEFAULT_SPACING: u16 = 15;
@@ -73,20 +78,45 @@ where
     {
         Checkbox {
             is_checked,
+            is_disabled: false,
             on_toggle: Box::new(f),
             label: label.into(),
             width: Length::Shrink,
-            size: Self::DEFAULT_SIZE,
+            size: None,
+            stroke_width: None,
             spacing: Self::DEFAULT_SPACING,
             text_size: None,
             font: Renderer::Font::default(),
+            label_position: label::Position::Right,
+            label_anchor: label::Anchor::default(),
             style: Default::default(),
         }
     }
 
-    /// Sets the size of the [`Checkbox`].
-    pub fn size(mut self, size: u16) -> Self {
-        self.size = size;
+    /// Sets whether the [`Checkbox`] is disabled.
+    ///
+    /// A disabled [`Checkbox`] cannot be toggled and is drawn with its
+    /// disabled appearance.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets the size of the box of the [`Checkbox`].
+    ///
+    /// By default, the box is as big as the text size, so it scales with it.
+    /// The checkmark scales proportionally to the box.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the width of the border of the box of the [`Checkbox`],
+    /// overriding the one of its style.
+    ///
+    /// The width is clamped so the checkmark stays legible at small sizes.
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = Some(stroke_width);
         self
     }
 
@@ -116,6 +146,19 @@ where
         self
     }
 
+    /// Sets the side of the [`Checkbox`] where its label is placed.
+    pub fn label_position(mut self, position: label::Position) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets how the box of the [`Checkbox`] is vertically aligned to its
+    /// label.
+    pub fn label_anchor(mut self, anchor: label::Anchor) -> Self {
+        self.label_anchor = anchor;
+        self
+    }
+
     /// Sets the style of the [`Checkbox`].
     pub fn style(
         mut self,
@@ -145,25 +188,22 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        Row::<(), Renderer>::new()
-            .width(self.width)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(self.size))
-                    .height(Length::Units(self.size)),
-            )
-            .push(
-                Text::new(&self.label)
-                    .font(self.font.clone())
-                    .width(self.width)
-                    .size(
-                        self.text_size
-                            .unwrap_or_else(|| renderer.default_size()),
-                    ),
-            )
-            .layout(renderer, limits)
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let size = self
+            .size
+            .unwrap_or_else(|| f32::from(text_size) * Self::DEFAULT_SIZE_RATIO);
+
+        label::layout(
+            renderer,
+            limits,
+            self.width,
+            self.spacing,
+            Size::new(size, size),
+            Some((&self.label, text_size, self.font.clone())),
+            self.label_position,
+            self.label_anchor,
+        )
     }
 
     fn on_event(
@@ -171,7 +211,7 @@ where
         _tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -179,9 +219,9 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+                let mouse_over = cursor.is_over(&layout.bounds());
 
-                if mouse_over {
+                if mouse_over && !self.is_disabled {
                     shell.publish((self.on_toggle)(!self.is_checked));
 
                     return event::Status::Captured;
@@ -197,11 +237,11 @@ where
         &self,
         _tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if layout.bounds().contains(cursor_position) {
+        if cursor.is_over(&layout.bounds()) && !self.is_disabled {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -215,15 +255,17 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(&bounds);
 
         let mut children = layout.children();
 
-        let custom_style = if is_mouse_over {
+        let custom_style = if self.is_disabled {
+            theme.disabled(&self.style, self.is_checked)
+        } else if is_mouse_over {
             theme.hovered(&self.style, self.is_checked)
         } else {
             theme.active(&self.style, self.is_checked)
@@ -233,11 +275,16 @@ where
             let layout = children.next().unwrap();
             let bounds = layout.bounds();
 
+            let border_width = label::stroke_width(
+                self.stroke_width.unwrap_or(custom_style.border_width),
+                bounds.height,
+            );
+
             renderer.fill_quad(
                 renderer::Quad {
                     bounds,
                     border_radius: custom_style.border_radius.into(),
-                    border_width: custom_style.border_width,
+                    border_width,
                     border_color: custom_style.border_color,
                 },
                 custom_style.background,
@@ -247,7 +294,10 @@ where
                 renderer.fill_text(text::Text {
                     content: &Renderer::CHECKMARK_ICON.to_string(),
                     font: Renderer::ICON_FONT,
-                    size: bounds.height * 0.7,
+                    // Keep the checkmark legible at small sizes
+                    size: (bounds.height * 0.7).max(6.0).min(bounds.height),
+                    line_height: text::LineHeight::default(),
+                    letter_spacing: 0.0,
                     bounds: Rectangle {
                         x: bounds.center_x(),
                         y: bounds.center_y(),
@@ -269,9 +319,14 @@ where
                 label_layout,
                 &self.label,
                 self.text_size,
+                text::LineHeight::default(),
+                0.0,
                 self.font.clone(),
                 widget::text::Appearance {
-                    color: custom_style.text_color,
+                    color: custom_style.text_color.or_else(|| {
+                        self.is_disabled
+                            .then(|| disabled::color(style.text_color))
+                    }),
                 },
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
@@ -293,3 +348,78 @@ where
         Element::new(checkbox)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::{Point, Size};
+
+    fn control_size(checkbox: Checkbox<'static, (), Null>) -> Size {
+        let renderer = Null::new();
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0));
+
+        let node = Widget::<(), Null>::layout(&checkbox, &renderer, &limits);
+
+        node.children()[0].bounds().size()
+    }
+
+    #[test]
+    fn size_tracks_text_size_by_default() {
+        let checkbox = Checkbox::new("Label", false, |_| ());
+        assert_eq!(control_size(checkbox), Size::new(20.0, 20.0));
+
+        let checkbox = Checkbox::new("Label", false, |_| ()).text_size(30);
+        assert_eq!(control_size(checkbox), Size::new(30.0, 30.0));
+
+        let checkbox = Checkbox::new("Label", false, |_| ())
+            .text_size(30)
+            .size(12.0);
+        assert_eq!(control_size(checkbox), Size::new(12.0, 12.0));
+    }
+
+    #[test]
+    fn stroke_width_is_clamped_at_small_sizes() {
+        assert_eq!(label::stroke_width(1.0, 24.0), 1.0);
+        assert_eq!(label::stroke_width(4.0, 12.0), 2.0);
+        assert_eq!(label::stroke_width(-1.0, 12.0), 0.0);
+    }
+
+    #[test]
+    fn disabled_checkboxes_are_not_toggled() {
+        let renderer = Null::new();
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0));
+
+        let press = |mut checkbox: Checkbox<'static, bool, Null>| {
+            let node =
+                Widget::<bool, Null>::layout(&checkbox, &renderer, &limits);
+            let mut tree = Tree::empty();
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let status = checkbox.on_event(
+                &mut tree,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(5.0, 5.0)),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+
+            (status, messages)
+        };
+
+        assert_eq!(
+            press(Checkbox::new("Label", false, |checked| checked)),
+            (event::Status::Captured, vec![true])
+        );
+        assert_eq!(
+            press(
+                Checkbox::new("Label", false, |checked| checked).disabled(true)
+            ),
+            (event::Status::Ignored, vec![])
+        );
+    }
+}
@@ -0,0 +1,418 @@
+//! Lay out a group of choices as radio buttons or checkboxes.
+use crate::text;
+use crate::widget::{self, checkbox, label, radio};
+use crate::widget::{Checkbox, Column, Radio, Row};
+use crate::{Alignment, Element, Length};
+
+use std::rc::Rc;
+
+/// A group of choices, shown either as radio buttons or as checkboxes.
+///
+/// Every choice of the group shares the same spacing and label placement, so
+/// their controls and labels line up:
+///
+/// - In a [`Direction::Vertical`] group, the choices are stacked in a
+/// [`Column`] and their controls share the same edge. Give the group a width
+/// other than [`Length::Shrink`] to line up the controls of labels placed on
+/// their [`label::Position::Left`] too.
+/// - In a [`Direction::Horizontal`] group, the choices are placed in a [`Row`]
+/// that wraps to a new line when it runs out of space. The choices of a line
+/// are centered vertically with [`label::Anchor::Center`], while they are
+/// aligned to their top with [`label::Anchor::FirstLine`]—so the first lines
+/// of their labels stay aligned.
+///
+/// # Example
+/// ```
+/// # type Element<'a, Message> =
+/// #     iced_native::Element<'a, Message, iced_native::renderer::Null>;
+/// #
+/// use iced_native::widget::choices::{Choices, Direction};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Fruit {
+///     Apple,
+///     Orange,
+/// }
+///
+/// impl std::fmt::Display for Fruit {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         f.write_str(match self {
+///             Fruit::Apple => "Apple",
+///             Fruit::Orange => "Orange",
+///         })
+///     }
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     FruitSelected(Fruit),
+///     FruitsChanged(Vec<Fruit>),
+/// }
+///
+/// let favorite: Element<'_, Message> =
+///     Choices::radio([Fruit::Apple, Fruit::Orange], None, Message::FruitSelected)
+///         .direction(Direction::Horizontal)
+///         .into();
+///
+/// let basket: Element<'_, Message> = Choices::checkbox(
+///     [Fruit::Apple, Fruit::Orange],
+///     &[Fruit::Orange],
+///     Message::FruitsChanged,
+/// )
+/// .into();
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Choices<'a, T, Message> {
+    choices: Vec<T>,
+    kind: Kind<'a, T, Message>,
+    direction: Direction,
+    width: Length,
+    spacing: u16,
+    label_spacing: Option<u16>,
+    text_size: Option<u16>,
+    label_position: label::Position,
+    label_anchor: label::Anchor,
+}
+
+/// The direction in which the choices of a [`Choices`] group are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The choices are stacked on top of each other.
+    Vertical,
+
+    /// The choices are placed next to each other, wrapping to new lines.
+    Horizontal,
+}
+
+enum Kind<'a, T, Message> {
+    Radio {
+        selected: Option<T>,
+        on_select: Box<dyn Fn(T) -> Message + 'a>,
+    },
+    Checkbox {
+        selected: Rc<[T]>,
+        on_change: Rc<dyn Fn(Vec<T>) -> Message + 'a>,
+    },
+}
+
+impl<'a, T, Message> Choices<'a, T, Message>
+where
+    T: Copy + Eq + ToString,
+{
+    /// The default spacing between the choices of a [`Choices`] group.
+    pub const DEFAULT_SPACING: u16 = 10;
+
+    /// Creates a new [`Choices`] group of radio buttons, where a single
+    /// choice can be selected.
+    ///
+    /// It expects:
+    ///   * the choices, labeled with their [`ToString`] implementation
+    ///   * the currently selected choice, if any
+    ///   * a function that produces a `Message` when a choice is selected
+    pub fn radio(
+        choices: impl IntoIterator<Item = T>,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self::new(
+            choices,
+            Kind::Radio {
+                selected,
+                on_select: Box::new(on_select),
+            },
+        )
+    }
+
+    /// Creates a new [`Choices`] group of checkboxes, where any number of
+    /// choices can be selected.
+    ///
+    /// It expects:
+    ///   * the choices, labeled with their [`ToString`] implementation
+    ///   * the currently selected choices
+    ///   * a function that produces a `Message` with the new selection when
+    ///     a choice is toggled
+    ///
+    /// The new selection keeps the order of the choices.
+    pub fn checkbox(
+        choices: impl IntoIterator<Item = T>,
+        selected: &[T],
+        on_change: impl Fn(Vec<T>) -> Message + 'a,
+    ) -> Self {
+        Self::new(
+            choices,
+            Kind::Checkbox {
+                selected: selected.into(),
+                on_change: Rc::new(on_change),
+            },
+        )
+    }
+
+    fn new(
+        choices: impl IntoIterator<Item = T>,
+        kind: Kind<'a, T, Message>,
+    ) -> Self {
+        Self {
+            choices: choices.into_iter().collect(),
+            kind,
+            direction: Direction::Vertical,
+            width: Length::Shrink,
+            spacing: Self::DEFAULT_SPACING,
+            label_spacing: None,
+            text_size: None,
+            label_position: label::Position::Right,
+            label_anchor: label::Anchor::default(),
+        }
+    }
+
+    /// Sets the [`Direction`] of the [`Choices`] group.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the width of the [`Choices`] group.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the choices of the [`Choices`] group—and
+    /// between its lines, when it wraps.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the spacing between the control and the label of every choice.
+    pub fn label_spacing(mut self, spacing: u16) -> Self {
+        self.label_spacing = Some(spacing);
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Choices`] group.
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the side of the controls where their labels are placed.
+    pub fn label_position(mut self, position: label::Position) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets the vertical alignment of the controls relative to their labels.
+    pub fn label_anchor(mut self, anchor: label::Anchor) -> Self {
+        self.label_anchor = anchor;
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> From<Choices<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: Copy + Eq + ToString + 'a,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme:
+        radio::StyleSheet + checkbox::StyleSheet + widget::text::StyleSheet,
+{
+    fn from(choices: Choices<'a, T, Message>) -> Self {
+        let Choices {
+            choices,
+            kind,
+            direction,
+            width,
+            spacing,
+            label_spacing,
+            text_size,
+            label_position,
+            label_anchor,
+        } = choices;
+
+        let item_width = match (direction, width) {
+            (Direction::Vertical, Length::Shrink) => Length::Shrink,
+            (Direction::Vertical, _) => Length::Fill,
+            (Direction::Horizontal, _) => Length::Shrink,
+        };
+
+        let items: Vec<Element<'a, Message, Renderer>> = match kind {
+            Kind::Radio {
+                selected,
+                on_select,
+            } => choices
+                .iter()
+                .map(|choice| {
+                    let radio = Radio::new(
+                        *choice,
+                        choice.to_string(),
+                        selected,
+                        &on_select,
+                    )
+                    .width(item_width)
+                    .label_position(label_position)
+                    .label_anchor(label_anchor);
+
+                    let radio = match label_spacing {
+                        Some(spacing) => radio.spacing(spacing),
+                        None => radio,
+                    };
+
+                    match text_size {
+                        Some(text_size) => radio.text_size(text_size).into(),
+                        None => radio.into(),
+                    }
+                })
+                .collect(),
+            Kind::Checkbox {
+                selected,
+                on_change,
+            } => {
+                let all: Rc<[T]> = choices.into();
+
+                all.iter()
+                    .map(|choice| {
+                        let choice = *choice;
+                        let all = all.clone();
+                        let selected = selected.clone();
+                        let on_change = on_change.clone();
+
+                        let is_checked = selected.contains(&choice);
+
+                        let checkbox = Checkbox::new(
+                            choice.to_string(),
+                            is_checked,
+                            move |is_checked| {
+                                on_change(
+                                    all.iter()
+                                        .copied()
+                                        .filter(|other| {
+                                            if *other == choice {
+                                                is_checked
+                                            } else {
+                                                selected.contains(other)
+                                            }
+                                        })
+                                        .collect(),
+                                )
+                            },
+                        )
+                        .width(item_width)
+                        .label_position(label_position)
+                        .label_anchor(label_anchor);
+
+                        let checkbox = match label_spacing {
+                            Some(spacing) => checkbox.spacing(spacing),
+                            None => checkbox,
+                        };
+
+                        match text_size {
+                            Some(text_size) => {
+                                checkbox.text_size(text_size).into()
+                            }
+                            None => checkbox.into(),
+                        }
+                    })
+                    .collect()
+            }
+        };
+
+        match direction {
+            Direction::Vertical => Column::with_children(items)
+                .width(width)
+                .spacing(spacing)
+                .into(),
+            Direction::Horizontal => Row::with_children(items)
+                .width(width)
+                .spacing(spacing)
+                .align_items(match label_anchor {
+                    label::Anchor::Center => Alignment::Center,
+                    label::Anchor::FirstLine => Alignment::Start,
+                })
+                .wrap(true)
+                .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::event::{self, Event};
+    use crate::layout::{self, Layout};
+    use crate::mouse;
+    use crate::renderer::Null;
+    use crate::widget::Tree;
+    use crate::{Point, Shell, Size};
+
+    fn layout(
+        element: &Element<'_, Vec<i32>, Null>,
+        max_width: f32,
+    ) -> layout::Node {
+        element.as_widget().layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(max_width, 200.0)),
+        )
+    }
+
+    #[test]
+    fn horizontal_choices_wrap_instead_of_overflowing() {
+        let element: Element<'_, Vec<i32>, Null> =
+            Choices::checkbox([1, 2, 3], &[], |selection| selection)
+                .direction(Direction::Horizontal)
+                .into();
+
+        // Every choice is a 20x20 box and an empty label, 15 units apart
+        let node = layout(&element, 90.0);
+
+        let positions: Vec<_> = Layout::new(&node)
+            .children()
+            .map(|choice| choice.position())
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(45.0, 0.0),
+                Point::new(0.0, 30.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn toggling_a_checkbox_emits_the_new_selection() {
+        let mut element: Element<'_, Vec<i32>, Null> =
+            Choices::checkbox([1, 2, 3], &[3], |selection| selection).into();
+
+        let node = layout(&element, 200.0);
+        let mut tree = Tree::new(&element);
+
+        let mut click = |index: usize| {
+            let layout = Layout::new(&node);
+            let choice = layout.children().nth(index).unwrap();
+
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let status = element.as_widget_mut().on_event(
+                &mut tree,
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                layout,
+                mouse::Cursor::Available(choice.bounds().center()),
+                &Null::new(),
+                &mut clipboard::Null,
+                &mut shell,
+            );
+
+            assert_eq!(status, event::Status::Captured);
+
+            messages
+        };
+
+        assert_eq!(click(0), vec![vec![1, 3]]);
+        assert_eq!(click(2), vec![Vec::<i32>::new()]);
+    }
+}
@@ -6,10 +6,12 @@ use crate::overlay;
 use crate::renderer;
 use crate::widget::{Operation, Tree};
 use crate::{
-    Alignment, Clipboard, Element, Layout, Length, Padding, Point, Rectangle,
-    Shell, Widget,
+    Alignment, Clipboard, Element, Layout, Length, Padding, Rectangle, Shell,
+    Widget,
 };
 
+use std::cell::RefCell;
+
 /// A container that distributes its contents vertically.
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message, Renderer> {
@@ -18,8 +20,11 @@ pub struct Column<'a, Message, Renderer> {
     width: Length,
     height: Length,
     max_width: u32,
+    max_height: u32,
     align_items: Alignment,
+    wrap: bool,
     children: Vec<Element<'a, Message, Renderer>>,
+    caches: RefCell<Vec<layout::Cache>>,
 }
 
 impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
@@ -38,8 +43,11 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
+            max_height: u32::MAX,
             align_items: Alignment::Start,
+            wrap: false,
             children,
+            caches: RefCell::new(Vec::new()),
         }
     }
 
@@ -77,12 +85,38 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the maximum height of the [`Column`].
+    ///
+    /// It bounds the height of the [`Column`] even if it fills its parent,
+    /// and it bounds the viewport of any [`Scrollable`] inside of it.
+    ///
+    /// [`Scrollable`]: crate::widget::Scrollable
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Column`] .
     pub fn align_items(mut self, align: Alignment) -> Self {
         self.align_items = align;
         self
     }
 
+    /// Sets whether the contents of the [`Column`] wrap onto multiple columns
+    /// when they overflow its height.
+    ///
+    /// The contents are aligned within each column, and the columns are
+    /// separated by the same spacing as the contents. A [`Length::Fill`]
+    /// element takes the rest of the height of its column.
+    ///
+    /// It is disabled by default. See [`LayoutMode::Wrap`] for the details.
+    ///
+    /// [`LayoutMode::Wrap`]: layout::flex::LayoutMode::Wrap
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     /// Adds an element to the [`Column`].
     pub fn push(
         mut self,
@@ -99,17 +133,60 @@ impl<'a, Message, Renderer> Default for Column<'a, Message, Renderer> {
     }
 }
 
+impl<'a, Message, Renderer> Column<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Keeps the layout caches of the given children trees, so the next
+    /// layout can reuse the layout of the children that did not change.
+    fn store_caches(&self, children: &[Tree]) {
+        *self.caches.borrow_mut() =
+            children.iter().map(|child| child.layout.clone()).collect();
+    }
+
+    fn resolve<Item>(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+        items: &[Item],
+    ) -> layout::Node
+    where
+        Item: layout::flex::ItemProxy<Renderer>,
+    {
+        layout::flex::resolve(
+            layout::flex::Axis::Vertical,
+            if self.wrap {
+                layout::flex::LayoutMode::Wrap
+            } else {
+                layout::flex::LayoutMode::Layout
+            },
+            renderer,
+            limits,
+            self.padding,
+            self.spacing as f32,
+            self.align_items,
+            items,
+        )
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Column<'a, Message, Renderer>
 where
     Renderer: crate::Renderer,
 {
     fn children(&self) -> Vec<Tree> {
-        self.children.iter().map(Tree::new).collect()
+        let children: Vec<Tree> = self.children.iter().map(Tree::new).collect();
+
+        self.store_caches(&children);
+
+        children
     }
 
     fn diff(&self, tree: &mut Tree) {
         tree.diff_children(&self.children);
+
+        self.store_caches(&tree.children);
     }
 
     fn width(&self) -> Length {
@@ -127,18 +204,26 @@ where
     ) -> layout::Node {
         let limits = limits
             .max_width(self.max_width)
+            .max_height(self.max_height)
             .width(self.width)
             .height(self.height);
 
-        layout::flex::resolve(
-            layout::flex::Axis::Vertical,
-            renderer,
-            &limits,
-            self.padding,
-            self.spacing as f32,
-            self.align_items,
-            &self.children,
-        )
+        let caches = self.caches.borrow();
+
+        // The caches are only stored once the children trees are created or
+        // diffed
+        if caches.len() == self.children.len() {
+            let children: Vec<_> = self
+                .children
+                .iter()
+                .zip(caches.iter())
+                .map(|(child, cache)| layout::flex::Cached::new(child, cache))
+                .collect();
+
+            self.resolve(renderer, &limits, &children)
+        } else {
+            self.resolve(renderer, &limits, &self.children)
+        }
     }
 
     fn operate(
@@ -166,7 +251,7 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -175,16 +260,19 @@ where
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
-            .map(|((child, state), layout)| {
-                child.as_widget_mut().on_event(
-                    state,
-                    event.clone(),
-                    layout,
-                    cursor_position,
-                    renderer,
-                    clipboard,
-                    shell,
-                )
+            .enumerate()
+            .map(|(index, ((child, state), layout))| {
+                shell.traced(index, |shell| {
+                    child.as_widget_mut().on_event(
+                        state,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                })
             })
             .fold(event::Status::Ignored, event::Status::merge)
     }
@@ -193,7 +281,7 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
@@ -203,11 +291,7 @@ where
             .zip(layout.children())
             .map(|((child, state), layout)| {
                 child.as_widget().mouse_interaction(
-                    state,
-                    layout,
-                    cursor_position,
-                    viewport,
-                    renderer,
+                    state, layout, cursor, viewport, renderer,
                 )
             })
             .max()
@@ -221,7 +305,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         for ((child, state), layout) in self
@@ -230,15 +314,9 @@ where
             .zip(&tree.children)
             .zip(layout.children())
         {
-            child.as_widget().draw(
-                state,
-                renderer,
-                theme,
-                style,
-                layout,
-                cursor_position,
-                viewport,
-            );
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
         }
     }
 
@@ -262,3 +340,100 @@ where
         Self::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::layout::Limits;
+    use crate::renderer::Null;
+    use crate::widget::{Scrollable, Space};
+    use crate::{Renderer as _, Size};
+
+    fn height(column: Column<'static, (), Null>, max_height: f32) -> f32 {
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = column.into();
+
+        renderer
+            .layout(
+                &element,
+                &Limits::new(Size::ZERO, Size::new(100.0, max_height)),
+            )
+            .size()
+            .height
+    }
+
+    fn items(count: usize) -> Column<'static, (), Null> {
+        (0..count).fold(Column::new(), |column, _| {
+            column.push(Space::new(Length::Units(10), Length::Units(10)))
+        })
+    }
+
+    #[test]
+    fn max_height_clamps_the_contents() {
+        assert_eq!(height(items(3).max_height(25), 100.0), 25.0);
+        assert_eq!(height(items(2).max_height(25), 100.0), 20.0);
+    }
+
+    #[test]
+    fn max_height_yields_to_tighter_limits() {
+        assert_eq!(height(items(5).max_height(40), 30.0), 30.0);
+    }
+
+    #[test]
+    fn max_height_bounds_a_filling_column() {
+        let column = items(1).height(Length::Fill).max_height(50);
+
+        assert_eq!(height(column, 200.0), 50.0);
+    }
+
+    #[test]
+    fn max_height_bounds_the_viewport_of_a_scrollable() {
+        let column = Column::new()
+            .max_height(40)
+            .push(Scrollable::new(items(10)));
+
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = column.into();
+
+        let node = renderer.layout(
+            &element,
+            &Limits::new(Size::ZERO, Size::new(100.0, 500.0)),
+        );
+
+        let scrollable = &node.children()[0];
+
+        assert_eq!(scrollable.size().height, 40.0);
+        assert_eq!(scrollable.children()[0].size().height, 100.0);
+    }
+
+    #[test]
+    fn reuses_the_layout_of_children_until_diffed() {
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = items(3).into();
+        let mut tree = Tree::new(&element);
+
+        let limits = Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let layout = |renderer: &mut Null| {
+            let _ = renderer.layout(&element, &limits);
+        };
+
+        layout(&mut renderer);
+        layout(&mut renderer);
+
+        let statistics = tree.layout_statistics();
+        assert_eq!((statistics.hits, statistics.misses), (3, 3));
+
+        tree.diff(&element);
+        layout(&mut renderer);
+
+        let statistics = tree.layout_statistics();
+        assert_eq!((statistics.hits, statistics.misses), (3, 6));
+
+        tree.invalidate_layout();
+        layout(&mut renderer);
+
+        let statistics = tree.layout_statistics();
+        assert_eq!((statistics.hits, statistics.misses), (3, 9));
+    }
+}
@@ -1,19 +1,25 @@
 //! Decorate content and apply alignment.
 use crate::alignment::{self, Alignment};
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
-use crate::widget::{Operation, Tree};
+use crate::touch;
+use crate::widget::operation::{self, Focusable};
+use crate::widget::tree::{self, Tree};
+use crate::widget::{self, scrollable, Operation, Scrollable, Space};
 use crate::{
-    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
-    Rectangle, Shell, Widget,
+    Background, Clipboard, Color, Command, Element, Layout, Length, Padding,
+    Point, Rectangle, Shell, Size, Widget,
 };
 
 use std::u32;
 
-pub use iced_style::container::{Appearance, StyleSheet};
+pub use iced_style::container::{
+    Appearance, BorderColor, BorderWidth, StyleSheet,
+};
 
 /// An element decorating some content.
 ///
@@ -24,6 +30,7 @@ where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    id: Option<Id>,
     padding: Padding,
     width: Length,
     height: Length,
@@ -31,7 +38,15 @@ where
     max_height: u32,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    overflow: Overflow,
+    scroll: Option<Scroll<'a, Message, Renderer>>,
+    clip: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
+    on_hover: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_unhover: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_press: Option<Press<'a, Message>>,
+    on_key_press: Option<Box<KeyHandler<'a, Message>>>,
+    on_key_release: Option<Box<KeyHandler<'a, Message>>>,
     content: Element<'a, Message, Renderer>,
 }
 
@@ -46,6 +61,7 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Container {
+            id: None,
             padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
@@ -53,11 +69,29 @@ where
             max_height: u32::MAX,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            overflow: Overflow::Visible,
+            scroll: None,
+            clip: false,
             style: Default::default(),
+            on_hover: None,
+            on_unhover: None,
+            on_press: None,
+            on_key_press: None,
+            on_key_release: None,
             content: content.into(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Container`].
+    ///
+    /// The [`Container`] reports its bounds with its [`Id`] to operations,
+    /// which makes it a target of [`scrollable::scroll_to_item`] and
+    /// [`scrollable::scroll_to_widget`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the [`Padding`] of the [`Container`].
     pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
         self.padding = padding.into();
@@ -89,17 +123,36 @@ where
     }
 
     /// Sets the content alignment for the horizontal axis of the [`Container`].
+    ///
+    /// The contents are aligned within the padded area of the [`Container`].
+    /// This has no effect when the contents fill the horizontal axis.
     pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
         self.horizontal_alignment = alignment;
         self
     }
 
     /// Sets the content alignment for the vertical axis of the [`Container`].
+    ///
+    /// The contents are aligned within the padded area of the [`Container`].
+    /// This has no effect when the contents fill the vertical axis.
     pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
         self.vertical_alignment = alignment;
         self
     }
 
+    /// Sets the content alignment for both axes of the [`Container`] at once.
+    ///
+    /// Each axis is aligned independently, like [`Container::align_x`] and
+    /// [`Container::align_y`] do. The alignment of an axis that the contents
+    /// fill is ignored.
+    pub fn align(
+        self,
+        horizontal: alignment::Horizontal,
+        vertical: alignment::Vertical,
+    ) -> Self {
+        self.align_x(horizontal).align_y(vertical)
+    }
+
     /// Centers the contents in the horizontal axis of the [`Container`].
     pub fn center_x(mut self) -> Self {
         self.horizontal_alignment = alignment::Horizontal::Center;
@@ -112,6 +165,11 @@ where
         self
     }
 
+    /// Centers the contents in both axes of the [`Container`].
+    pub fn center(self) -> Self {
+        self.center_x().center_y()
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(
         mut self,
@@ -120,6 +178,240 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the message that should be produced when the cursor enters the
+    /// [`Container`].
+    ///
+    /// Hover is determined by the position of the cursor, so it is detected
+    /// even if the contents of the [`Container`] capture the event.
+    pub fn on_hover(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_hover = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the message that should be produced when the cursor leaves the
+    /// [`Container`].
+    pub fn on_unhover(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_unhover = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Container`] is
+    /// pressed, turning it into a clickable surface—like a card.
+    ///
+    /// The [`Container`] publishes the message when it is released with the
+    /// cursor still over it, like a [`Button`]. Contents that handle their
+    /// own clicks, like a nested [`Button`], take precedence: the
+    /// [`Container`] is only pressed when its contents ignore the press.
+    ///
+    /// A [`Container`] that can be pressed is focusable, and it can be
+    /// pressed with <kbd>Space</kbd> or <kbd>Enter</kbd> while focused. It
+    /// is drawn with the [`StyleSheet::pressed`] and [`StyleSheet::focused`]
+    /// appearances accordingly.
+    ///
+    /// [`Button`]: widget::Button
+    pub fn on_press(self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_press_maybe(Some(message))
+    }
+
+    /// Sets the message that should be produced when the [`Container`] is
+    /// pressed, if any.
+    ///
+    /// Unlike a regular [`Container`], a [`Container`] given `None` is
+    /// disabled: it is drawn with the [`StyleSheet::disabled`] appearance
+    /// and it ignores any presses.
+    pub fn on_press_maybe(mut self, message: Option<Message>) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_press = Some(match message {
+            Some(message) => Press::Enabled(Box::new(move || message.clone())),
+            None => Press::Disabled,
+        });
+        self
+    }
+
+    /// Sets the function that produces a message when a key is pressed while
+    /// the [`Container`] or any of its contents is focused.
+    ///
+    /// This scopes keyboard shortcuts to a part of the user interface. The
+    /// function decides which keys the [`Container`] captures: returning
+    /// `None` passes the key through to the contents—e.g. to a focused text
+    /// input—while returning a message captures it before the contents can
+    /// see it.
+    ///
+    /// A [`Container`] with key handlers is focusable. It is focused when
+    /// clicked outside of any focusable contents, with [`focus`], or when
+    /// traversing the focusable widgets with the focus operations.
+    pub fn on_key_press(
+        mut self,
+        f: impl Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_key_press = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function that produces a message when a key is released
+    /// while the [`Container`] or any of its contents is focused.
+    ///
+    /// Like [`Container::on_key_press`], returning `None` passes the key
+    /// through to the contents.
+    pub fn on_key_release(
+        mut self,
+        f: impl Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message> + 'a,
+    ) -> Self {
+        self.on_key_release = Some(Box::new(f));
+        self
+    }
+
+    /// Returns whether the [`Container`] handles any keyboard events.
+    fn is_focusable(&self) -> bool {
+        self.on_key_press.is_some()
+            || self.on_key_release.is_some()
+            || self.is_pressable()
+    }
+
+    /// Returns whether the [`Container`] can be pressed.
+    fn is_pressable(&self) -> bool {
+        matches!(self.on_press, Some(Press::Enabled(_)))
+    }
+
+    /// Sets the [`Overflow`] behavior of the [`Container`].
+    ///
+    /// [`Overflow::Scroll`] and [`Overflow::Auto`] wrap the contents in a
+    /// [`Scrollable`] that fills the [`Container`] once it is turned into an
+    /// [`Element`]. Only the last [`Overflow`] set is applied.
+    ///
+    /// Combined with [`Container::max_width`] and [`Container::max_height`],
+    /// a [`Container`] that does not [`Fill`] its axes sizes itself exactly
+    /// to its contents while they fit under the caps, and it scrolls them
+    /// once they exceed them—like a popover. A [`Container`] that fills an
+    /// axis takes all the space it is given along it, no matter its contents,
+    /// and scrolls them when they are bigger.
+    ///
+    /// # Performance
+    /// [`Overflow::Auto`] measures the contents with an unbounded width to
+    /// find out whether they overflow horizontally. Contents that fill the
+    /// horizontal axis—including any descendant that does—are laid out a
+    /// second time with the width of the [`Container`], like a [`Scrollable`]
+    /// with [`fit_fill`] horizontal [`Properties`] does. Nested [`Container`]s
+    /// with [`Overflow::Auto`] multiply these passes; [`Overflow::Scroll`]
+    /// only scrolls vertically and never lays out the contents twice.
+    ///
+    /// [`Fill`]: Length::Fill
+    /// [`fit_fill`]: scrollable::Properties::fit_fill
+    /// [`Properties`]: scrollable::Properties
+    pub fn overflow(mut self, overflow: Overflow) -> Self
+    where
+        Message: 'a,
+        Renderer: 'a,
+        Renderer::Theme: scrollable::StyleSheet,
+    {
+        self.scroll = match overflow {
+            Overflow::Visible | Overflow::Clip => None,
+            Overflow::Scroll | Overflow::Auto => Some(scroll),
+        };
+
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets whether the contents of the [`Container`] should be clipped to
+    /// its bounds, following the border radius of its style.
+    ///
+    /// Unlike [`Overflow::Clip`], this clips the rounded corners of the
+    /// [`Container`] too, as long as the renderer supports it. It can be
+    /// combined with any [`Overflow`]—e.g. to clip a [`Scrollable`] to a
+    /// rounded card. Overlays of the contents, like the menu of a pick list,
+    /// are never clipped.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+}
+
+/// A function wrapping the contents of a [`Container`] in a [`Scrollable`]
+/// for its [`Overflow`].
+type Scroll<'a, Message, Renderer> = fn(
+    Element<'a, Message, Renderer>,
+    Overflow,
+) -> Element<'a, Message, Renderer>;
+
+/// Wraps the given contents in a [`Scrollable`] for the given [`Overflow`].
+fn scroll<'a, Message, Renderer>(
+    content: Element<'a, Message, Renderer>,
+    overflow: Overflow,
+) -> Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: scrollable::StyleSheet,
+{
+    let vertical = match overflow {
+        Overflow::Scroll => scrollable::Properties::new().always_visible(true),
+        _ => scrollable::Properties::new(),
+    };
+
+    let scrollable = Scrollable::new(content)
+        .height(Length::Fill)
+        .vertical_scroll(vertical);
+
+    if overflow == Overflow::Auto {
+        scrollable
+            .horizontal_scroll(scrollable::Properties::new().fit_fill(true))
+            .into()
+    } else {
+        scrollable.into()
+    }
+}
+
+/// A function mapping a key and its modifiers to an optional message.
+type KeyHandler<'a, Message> =
+    dyn Fn(keyboard::KeyCode, keyboard::Modifiers) -> Option<Message> + 'a;
+
+/// The press behavior of a [`Container`].
+enum Press<'a, Message> {
+    /// The [`Container`] produces a message when pressed.
+    Enabled(Box<dyn Fn() -> Message + 'a>),
+
+    /// The [`Container`] can be pressed, but it is disabled.
+    Disabled,
+}
+
+/// The behavior of a [`Container`] when its contents do not fit in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// The contents are drawn outside of the [`Container`].
+    Visible,
+
+    /// The contents are clipped to the bounds of the [`Container`], ignoring
+    /// its border radius.
+    ///
+    /// Use [`Container::clip`] to clip the rounded corners too.
+    Clip,
+
+    /// The contents can be scrolled vertically and the scrollbar is always
+    /// shown.
+    Scroll,
+
+    /// The contents can be scrolled along any axis where they overflow the
+    /// [`Container`], and the scrollbars are only shown when they do.
+    Auto,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow::Visible
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -128,6 +420,14 @@ where
     Renderer: crate::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
         vec![Tree::new(&self.content)]
     }
@@ -157,14 +457,36 @@ where
             self.max_width,
             self.max_height,
             self.padding,
-            self.horizontal_alignment,
-            self.vertical_alignment,
+            // Content filling an axis has nothing to be aligned against
+            if self.content.as_widget().width().fill_factor() == 0 {
+                self.horizontal_alignment
+            } else {
+                alignment::Horizontal::Left
+            },
+            if self.content.as_widget().height().fill_factor() == 0 {
+                self.vertical_alignment
+            } else {
+                alignment::Vertical::Top
+            },
             |renderer, limits| {
                 self.content.as_widget().layout(renderer, limits)
             },
         )
     }
 
+    fn baseline(
+        &self,
+        renderer: &Renderer,
+        layout: &layout::Node,
+    ) -> Option<f32> {
+        let content = layout.children().first()?;
+
+        self.content
+            .as_widget()
+            .baseline(renderer, content)
+            .map(|baseline| content.bounds().y + baseline)
+    }
+
     fn operate(
         &self,
         tree: &mut Tree,
@@ -172,14 +494,36 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
-        operation.container(None, &mut |operation| {
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        operation.bounds(layout.bounds(), id);
+
+        if self.is_focusable() {
+            operation.focusable(tree.state.downcast_mut::<State>(), id);
+        }
+
+        let mut has_focused_content = None;
+
+        operation.container(id, &mut |operation| {
+            let mut operation = FocusTracker {
+                operation,
+                has_focus: has_focused_content.get_or_insert(false),
+            };
+
             self.content.as_widget().operate(
                 &mut tree.children[0],
                 layout.children().next().unwrap(),
                 renderer,
-                operation,
+                &mut operation,
             );
         });
+
+        // Focus only ever moves through an operation or a press, so the
+        // contents are not traversed again on every key
+        if let Some(has_focused_content) = has_focused_content {
+            tree.state.downcast_mut::<State>().has_focused_content =
+                has_focused_content;
+        }
     }
 
     fn on_event(
@@ -187,37 +531,201 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.content.as_widget_mut().on_event(
-            &mut tree.children[0],
+        if let Event::Mouse(event) = &event {
+            let is_hovered = match event {
+                mouse::Event::CursorLeft => false,
+                _ => cursor.is_over(&layout.bounds()),
+            };
+
+            let state = tree.state.downcast_mut::<State>();
+
+            if state.is_hovered != is_hovered {
+                state.is_hovered = is_hovered;
+
+                let on_hover = if is_hovered {
+                    &self.on_hover
+                } else {
+                    &self.on_unhover
+                };
+
+                if let Some(on_hover) = on_hover {
+                    shell.publish(on_hover());
+                }
+            }
+        }
+
+        if let Event::Keyboard(event) = &event {
+            let (key_code, modifiers, handler) = match *event {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => (Some(key_code), modifiers, &self.on_key_press),
+                keyboard::Event::KeyReleased {
+                    key_code,
+                    modifiers,
+                } => (Some(key_code), modifiers, &self.on_key_release),
+                _ => (None, keyboard::Modifiers::default(), &None),
+            };
+
+            if let (Some(key_code), Some(handler)) = (key_code, handler) {
+                let state = tree.state.downcast_ref::<State>();
+                let is_focused = state.is_focused || state.has_focused_content;
+
+                if is_focused {
+                    if let Some(message) = handler(key_code, modifiers) {
+                        shell.publish(message);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+        }
+
+        if let Some(Press::Enabled(on_press)) = &self.on_press {
+            let state = tree.state.downcast_mut::<State>();
+
+            match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    ..
+                }) if state.is_focused => match key_code {
+                    keyboard::KeyCode::Space => {
+                        shell.publish(on_press());
+
+                        // Capturing the key keeps any parent scrollable from
+                        // scrolling by a page
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        state.is_enter_pressed = true;
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                },
+                Event::Keyboard(keyboard::Event::KeyReleased {
+                    key_code:
+                        keyboard::KeyCode::Enter | keyboard::KeyCode::NumpadEnter,
+                    ..
+                }) if state.is_enter_pressed => {
+                    state.is_enter_pressed = false;
+
+                    shell.publish(on_press());
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+
+        let is_pressed = matches!(
             event,
-            layout.children().next().unwrap(),
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        )
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        let is_left_pressed = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerPressed { .. })
+        );
+        let is_released = matches!(
+            event,
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                | Event::Touch(touch::Event::FingerLifted { .. })
+        );
+        let is_lost =
+            matches!(event, Event::Touch(touch::Event::FingerLost { .. }));
+
+        let status = shell.traced(0, |shell| {
+            self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+            )
+        });
+
+        if is_pressed && self.is_focusable() {
+            // Focusable contents take the focus from the container
+            let has_focused_content =
+                self.has_focused_content(tree, layout, renderer);
+            let is_focused =
+                cursor.is_over(&layout.bounds()) && !has_focused_content;
+
+            let state = tree.state.downcast_mut::<State>();
+
+            state.is_focused = is_focused;
+            state.has_focused_content = has_focused_content;
+            state.is_focus_visible = false;
+            state.is_enter_pressed = false;
+        }
+
+        let on_press = match &self.on_press {
+            Some(Press::Enabled(on_press)) => on_press,
+            _ => return status,
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if is_left_pressed
+            && status == event::Status::Ignored
+            && cursor.is_over(&layout.bounds())
+        {
+            state.is_pressed = true;
+
+            return event::Status::Captured;
+        }
+
+        if is_released && state.is_pressed {
+            state.is_pressed = false;
+
+            if cursor.is_over(&layout.bounds()) {
+                shell.publish(on_press());
+            }
+
+            return event::Status::Captured;
+        }
+
+        if is_lost {
+            state.is_pressed = false;
+        }
+
+        status
     }
 
     fn mouse_interaction(
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.content.as_widget().mouse_interaction(
+        let interaction = self.content.as_widget().mouse_interaction(
             &tree.children[0],
             layout.children().next().unwrap(),
-            cursor_position,
+            cursor,
             viewport,
             renderer,
-        )
+        );
+
+        if interaction == mouse::Interaction::Idle
+            && self.is_pressable()
+            && cursor.is_over(&layout.bounds())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
     }
 
     fn draw(
@@ -227,26 +735,56 @@ where
         theme: &Renderer::Theme,
         renderer_style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let style = theme.appearance(&self.style);
+        let state = tree.state.downcast_ref::<State>();
+
+        let style = match &self.on_press {
+            Some(Press::Disabled) => theme.disabled(&self.style),
+            Some(Press::Enabled(_))
+                if state.is_pressed && cursor.is_over(&layout.bounds()) =>
+            {
+                theme.pressed(&self.style)
+            }
+            _ if state.is_hovered => theme.hovered(&self.style),
+            Some(Press::Enabled(_))
+                if state.is_focused && state.is_focus_visible =>
+            {
+                theme.focused(&self.style)
+            }
+            _ => theme.appearance(&self.style),
+        };
 
         draw_background(renderer, &style, layout.bounds());
 
-        self.content.as_widget().draw(
-            &tree.children[0],
-            renderer,
-            theme,
-            &renderer::Style {
-                text_color: style
-                    .text_color
-                    .unwrap_or(renderer_style.text_color),
-            },
-            layout.children().next().unwrap(),
-            cursor_position,
-            viewport,
-        );
+        let draw_content = |renderer: &mut Renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style
+                        .text_color
+                        .unwrap_or(renderer_style.text_color),
+                },
+                layout.children().next().unwrap(),
+                cursor,
+                viewport,
+            );
+        };
+
+        if self.clip {
+            renderer.with_rounded_clip(
+                layout.bounds(),
+                style.border_radius.into(),
+                draw_content,
+            );
+        } else if self.overflow == Overflow::Clip {
+            renderer.with_layer(layout.bounds(), draw_content);
+        } else {
+            draw_content(renderer);
+        }
     }
 
     fn overlay<'b>(
@@ -263,6 +801,51 @@ where
     }
 }
 
+impl<'a, Message, Renderer> Container<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns whether any of the contents of the [`Container`] is focused.
+    fn has_focused_content(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> bool {
+        struct HasFocus(bool);
+
+        impl<T> Operation<T> for HasFocus {
+            fn container(
+                &mut self,
+                _id: Option<&widget::Id>,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+            ) {
+                operate_on_children(self)
+            }
+
+            fn focusable(
+                &mut self,
+                state: &mut dyn Focusable,
+                _id: Option<&widget::Id>,
+            ) {
+                self.0 |= state.is_focused();
+            }
+        }
+
+        let mut operation = HasFocus(false);
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            &mut operation,
+        );
+
+        operation.0
+    }
+}
+
 impl<'a, Message, Renderer> From<Container<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -271,9 +854,148 @@ where
     Renderer::Theme: StyleSheet,
 {
     fn from(
-        column: Container<'a, Message, Renderer>,
+        mut container: Container<'a, Message, Renderer>,
     ) -> Element<'a, Message, Renderer> {
-        Element::new(column)
+        if let Some(scroll) = container.scroll.take() {
+            let content = std::mem::replace(
+                &mut container.content,
+                Space::new(Length::Shrink, Length::Shrink).into(),
+            );
+
+            container.content = scroll(content, container.overflow);
+        }
+
+        Element::new(container)
+    }
+}
+
+/// The identifier of a [`Container`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`Container`] with the given [`Id`].
+///
+/// Only a [`Container`] with key handlers or that can be pressed can be
+/// focused.
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// The local state of a [`Container`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    is_hovered: bool,
+    is_focused: bool,
+    has_focused_content: bool,
+    is_focus_visible: bool,
+    is_pressed: bool,
+    is_enter_pressed: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+        self.is_focus_visible = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_focus_visible = false;
+        self.is_enter_pressed = false;
+    }
+}
+
+/// An [`Operation`] that keeps track of whether any of the focusable widgets
+/// it operates on is focused once it is done with them.
+struct FocusTracker<'a, T> {
+    operation: &'a mut dyn Operation<T>,
+    has_focus: &'a mut bool,
+}
+
+impl<'a, T> Operation<T> for FocusTracker<'a, T> {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let has_focus = &mut *self.has_focus;
+
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut FocusTracker {
+                operation,
+                has_focus: &mut *has_focus,
+            })
+        });
+    }
+
+    fn bounds(&mut self, bounds: Rectangle, id: Option<&widget::Id>) {
+        self.operation.bounds(bounds, id);
+    }
+
+    fn focusable(
+        &mut self,
+        state: &mut dyn Focusable,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.focusable(state, id);
+
+        *self.has_focus |= state.is_focused();
+    }
+
+    fn scrollable(
+        &mut self,
+        state: &mut dyn operation::Scrollable,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn operation::TextInput,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(
+        &mut self,
+        state: &mut dyn std::any::Any,
+        id: Option<&widget::Id>,
+    ) {
+        self.operation.custom(state, id);
+    }
+
+    fn target(&self) -> Option<&widget::Id> {
+        self.operation.target()
+    }
+
+    fn finish(&self) -> operation::Outcome<T> {
+        self.operation.finish()
     }
 }
 
@@ -298,7 +1020,7 @@ pub fn layout<Renderer>(
         .height(height);
 
     let mut content = layout_content(renderer, &limits.pad(padding).loose());
-    let padding = padding.fit(content.size(), limits.max());
+    let padding = fit_padding(padding, content.size(), limits.max());
     let size = limits.pad(padding).resolve(content.size());
 
     content.move_to(Point::new(padding.left.into(), padding.top.into()));
@@ -311,7 +1033,49 @@ pub fn layout<Renderer>(
     layout::Node::with_children(size.pad(padding), vec![content])
 }
 
+/// Fits the [`Padding`] of a [`Container`] between the provided `inner` and
+/// `outer` [`Size`].
+///
+/// Unlike [`Padding::fit`], both sides of an axis are shrunk proportionally
+/// when there is not enough space for them, so the contents stay where an
+/// asymmetric [`Padding`] aligns them.
+fn fit_padding(padding: Padding, inner: Size, outer: Size) -> Padding {
+    let available = (outer - inner).max(Size::ZERO);
+
+    let fit = |start: u16, end: u16, available: f32| {
+        let total = f32::from(start) + f32::from(end);
+
+        if total <= available {
+            (start, end)
+        } else {
+            let ratio = available / total;
+
+            (
+                (f32::from(start) * ratio) as u16,
+                (f32::from(end) * ratio) as u16,
+            )
+        }
+    };
+
+    let (top, bottom) = fit(padding.top, padding.bottom, available.height);
+    let (left, right) = fit(padding.left, padding.right, available.width);
+
+    Padding {
+        top,
+        right,
+        bottom,
+        left,
+    }
+}
+
 /// Draws the background of a [`Container`] given its [`Appearance`] and its `bounds`.
+///
+/// A border with the same width and [`Color`] on every side is drawn along
+/// with the background in a single quad. Otherwise, every side is drawn as a
+/// straight edge on top of the background: the top and bottom sides span the
+/// whole width—corners included—while the left and right sides fit between
+/// them. With a border radius, the edges are clipped to the rounded bounds.
+/// Sides with a zero width or a transparent [`Color`] are not drawn.
 pub fn draw_background<Renderer>(
     renderer: &mut Renderer,
     appearance: &Appearance,
@@ -319,17 +1083,894 @@ pub fn draw_background<Renderer>(
 ) where
     Renderer: crate::Renderer,
 {
-    if appearance.background.is_some() || appearance.border_width > 0.0 {
+    let uniform_border = appearance
+        .side_border_widths()
+        .uniform()
+        .zip(appearance.side_border_colors().uniform());
+
+    let (border_width, border_color) =
+        uniform_border.unwrap_or((0.0, Color::TRANSPARENT));
+
+    if appearance.background.is_some() || border_width > 0.0 {
         renderer.fill_quad(
             renderer::Quad {
                 bounds,
                 border_radius: appearance.border_radius.into(),
-                border_width: appearance.border_width,
-                border_color: appearance.border_color,
+                border_width,
+                border_color,
             },
             appearance
                 .background
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
         );
     }
+
+    if uniform_border.is_none() {
+        if appearance.border_radius > 0.0 {
+            renderer.with_rounded_clip(
+                bounds,
+                appearance.border_radius.into(),
+                |renderer| draw_edges(renderer, appearance, bounds),
+            );
+        } else {
+            draw_edges(renderer, appearance, bounds);
+        }
+    }
+}
+
+/// Draws every side of the border of a [`Container`] as a straight edge.
+fn draw_edges<Renderer>(
+    renderer: &mut Renderer,
+    appearance: &Appearance,
+    bounds: Rectangle,
+) where
+    Renderer: crate::Renderer,
+{
+    let width = appearance.side_border_widths();
+    let color = appearance.side_border_colors();
+
+    let top = width.top.clamp(0.0, bounds.height);
+    let bottom = width.bottom.clamp(0.0, bounds.height - top);
+    let left = width.left.clamp(0.0, bounds.width);
+    let right = width.right.clamp(0.0, bounds.width - left);
+    let side_height = bounds.height - top - bottom;
+
+    let edges = [
+        (
+            Rectangle {
+                height: top,
+                ..bounds
+            },
+            color.top,
+        ),
+        (
+            Rectangle {
+                y: bounds.y + bounds.height - bottom,
+                height: bottom,
+                ..bounds
+            },
+            color.bottom,
+        ),
+        (
+            Rectangle {
+                y: bounds.y + top,
+                width: left,
+                height: side_height,
+                ..bounds
+            },
+            color.left,
+        ),
+        (
+            Rectangle {
+                x: bounds.x + bounds.width - right,
+                y: bounds.y + top,
+                width: right,
+                height: side_height,
+            },
+            color.right,
+        ),
+    ];
+
+    for (bounds, color) in edges {
+        if bounds.width > 0.0 && bounds.height > 0.0 && color.a > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::layout::Limits;
+    use crate::renderer::Null;
+    use crate::widget::Space;
+    use crate::{Renderer as _, Size};
+
+    fn content_bounds(container: Container<'static, (), Null>) -> Rectangle {
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> = container.into();
+
+        let node = renderer.layout(
+            &element,
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        node.children()[0].bounds()
+    }
+
+    fn boxed(width: Length, height: Length) -> Container<'static, (), Null> {
+        Container::new(Space::new(width, height))
+            .width(Length::Fill)
+            .height(Length::Fill)
+    }
+
+    #[test]
+    fn centers_within_asymmetric_padding() {
+        let container = boxed(Length::Units(20), Length::Units(10))
+            .padding([10, 20, 30, 40])
+            .center();
+
+        // The padded area is 140x60 at (40, 10)
+        assert_eq!(
+            content_bounds(container),
+            Rectangle {
+                x: 40.0 + (140.0 - 20.0) / 2.0,
+                y: 10.0 + (60.0 - 10.0) / 2.0,
+                width: 20.0,
+                height: 10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn aligns_to_the_end_of_the_padded_area() {
+        let container = boxed(Length::Units(20), Length::Units(10))
+            .padding([10, 20, 30, 40])
+            .align_x(alignment::Horizontal::Right)
+            .align_y(alignment::Vertical::Bottom);
+
+        assert_eq!(
+            content_bounds(container),
+            Rectangle {
+                x: 200.0 - 20.0 - 20.0,
+                y: 100.0 - 30.0 - 10.0,
+                width: 20.0,
+                height: 10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn aligns_each_axis_independently() {
+        use alignment::{Horizontal, Vertical};
+
+        // The padded area is 140x60 at (40, 10)
+        for (horizontal, x) in [
+            (Horizontal::Left, 40.0),
+            (Horizontal::Center, 40.0 + (140.0 - 20.0) / 2.0),
+            (Horizontal::Right, 200.0 - 20.0 - 20.0),
+        ] {
+            for (vertical, y) in [
+                (Vertical::Top, 10.0),
+                (Vertical::Center, 10.0 + (60.0 - 10.0) / 2.0),
+                (Vertical::Bottom, 100.0 - 30.0 - 10.0),
+            ] {
+                let container = boxed(Length::Units(20), Length::Units(10))
+                    .padding([10, 20, 30, 40])
+                    .align(horizontal, vertical);
+
+                assert_eq!(
+                    content_bounds(container),
+                    Rectangle {
+                        x,
+                        y,
+                        width: 20.0,
+                        height: 10.0,
+                    },
+                    "{horizontal:?} {vertical:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn alignment_of_a_filled_axis_is_ignored() {
+        let container = boxed(Length::Units(20), Length::Fill)
+            .padding([10, 20, 30, 40])
+            .align(alignment::Horizontal::Right, alignment::Vertical::Bottom);
+
+        assert_eq!(
+            content_bounds(container),
+            Rectangle {
+                x: 200.0 - 20.0 - 20.0,
+                y: 10.0,
+                width: 20.0,
+                height: 60.0,
+            }
+        );
+    }
+
+    #[test]
+    fn alignment_is_a_no_op_for_filling_content() {
+        let container = boxed(Length::Fill, Length::Units(10))
+            .padding([10, 20, 30, 40])
+            .center();
+
+        assert_eq!(
+            content_bounds(container),
+            Rectangle {
+                x: 40.0,
+                y: 10.0 + (60.0 - 10.0) / 2.0,
+                width: 140.0,
+                height: 10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn shrinks_asymmetric_padding_proportionally() {
+        let node = layout(
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+            Length::Shrink,
+            Length::Shrink,
+            u32::MAX,
+            u32::MAX,
+            Padding::from([0, 10, 0, 30]),
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+            |_, _| layout::Node::new(Size::new(180.0, 10.0)),
+        );
+
+        // Only 20 pixels are left for a padding of 40, so it is halved
+        assert_eq!(node.size().width, 200.0);
+        assert_eq!(node.children()[0].bounds().x, 15.0);
+    }
+
+    #[test]
+    fn publishes_hover_transitions_once() {
+        let renderer = Null::new();
+        let mut container: Container<'static, &str, Null> =
+            Container::new(Space::new(Length::Fill, Length::Fill))
+                .width(Length::Units(100))
+                .height(Length::Units(100))
+                .on_hover("hover")
+                .on_unhover("unhover");
+
+        let node = Widget::<&str, Null>::layout(
+            &container,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut tree = Tree::new(&container as &dyn Widget<&str, Null>);
+        let mut messages = Vec::new();
+
+        for (event, position) in [
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::ORIGIN,
+                },
+                (150.0, 150.0),
+            ),
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::ORIGIN,
+                },
+                (10.0, 10.0),
+            ),
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::ORIGIN,
+                },
+                (90.0, 90.0),
+            ),
+            (
+                mouse::Event::ButtonPressed(mouse::Button::Left),
+                (90.0, 90.0),
+            ),
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::ORIGIN,
+                },
+                (150.0, 90.0),
+            ),
+            (
+                mouse::Event::CursorMoved {
+                    position: Point::ORIGIN,
+                },
+                (50.0, 50.0),
+            ),
+            (mouse::Event::CursorLeft, (50.0, 50.0)),
+        ] {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = container.on_event(
+                &mut tree,
+                Event::Mouse(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(position.0, position.1)),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        assert_eq!(messages, ["hover", "unhover", "hover", "unhover"]);
+    }
+
+    #[test]
+    fn clips_contents_to_rounded_bounds() {
+        use crate::renderer::{BorderRadius, Recorder};
+        use crate::Theme;
+
+        let draw = |container: Container<'static, (), Recorder>| {
+            let mut renderer = Recorder::default();
+            let node = Widget::<(), Recorder>::layout(
+                &container,
+                &renderer,
+                &Limits::new(Size::ZERO, Size::new(100.0, 50.0)),
+            );
+            let tree = Tree::new(&container as &dyn Widget<(), Recorder>);
+
+            container.draw(
+                &tree,
+                &mut renderer,
+                &Theme::Light,
+                &renderer::Style {
+                    text_color: Color::BLACK,
+                },
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::ORIGIN),
+                &node.bounds(),
+            );
+
+            renderer.layers
+        };
+
+        fn rounded(_theme: &Theme) -> Appearance {
+            Appearance {
+                border_radius: 12.0,
+                ..Appearance::default()
+            }
+        }
+
+        let card = || {
+            Container::new(Space::new(Length::Fill, Length::Fill))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(rounded as fn(&Theme) -> Appearance)
+        };
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 50.0));
+
+        assert_eq!(draw(card()), []);
+        assert_eq!(
+            draw(card().overflow(Overflow::Clip)),
+            [(bounds, BorderRadius::default())]
+        );
+        assert_eq!(
+            draw(card().overflow(Overflow::Clip).clip(true)),
+            [(bounds, BorderRadius::from(12.0))]
+        );
+    }
+
+    #[test]
+    fn max_height_bounds_a_filling_container() {
+        let mut renderer = Null::new();
+        let element: Element<'static, (), Null> =
+            boxed(Length::Fill, Length::Fill).max_height(60).into();
+
+        let node = renderer.layout(
+            &element,
+            &Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        assert_eq!(node.size(), Size::new(200.0, 60.0));
+        assert_eq!(node.children()[0].size(), Size::new(200.0, 60.0));
+    }
+
+    fn key_pressed(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    fn shortcuts(
+        key_code: keyboard::KeyCode,
+        _modifiers: keyboard::Modifiers,
+    ) -> Option<&'static str> {
+        match key_code {
+            keyboard::KeyCode::Escape => Some("escape"),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn handles_keys_only_while_focused() {
+        let renderer = Null::new();
+        let mut container: Container<'static, &str, Null> =
+            Container::new(Space::new(Length::Fill, Length::Fill))
+                .width(Length::Units(100))
+                .height(Length::Units(100))
+                .on_key_press(shortcuts);
+
+        let node = Widget::<&str, Null>::layout(
+            &container,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut tree = Tree::new(&container as &dyn Widget<&str, Null>);
+        let escape = key_pressed(keyboard::KeyCode::Escape);
+        let press =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        let mut messages = Vec::new();
+        let mut statuses = Vec::new();
+
+        for (event, position) in [
+            (escape.clone(), (0.0, 0.0)),
+            (press.clone(), (50.0, 50.0)),
+            (escape.clone(), (0.0, 0.0)),
+            (key_pressed(keyboard::KeyCode::A), (0.0, 0.0)),
+            // Clicking elsewhere moves the focus out of the container
+            (press, (150.0, 150.0)),
+            (escape, (0.0, 0.0)),
+        ] {
+            let mut shell = Shell::new(&mut messages);
+
+            statuses.push(container.on_event(
+                &mut tree,
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(position.0, position.1)),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            ));
+        }
+
+        assert_eq!(
+            statuses,
+            [
+                event::Status::Ignored,
+                event::Status::Ignored,
+                event::Status::Captured,
+                event::Status::Ignored,
+                event::Status::Ignored,
+                event::Status::Ignored,
+            ]
+        );
+        assert_eq!(messages, ["escape"]);
+    }
+
+    #[test]
+    fn passes_keys_through_to_focused_contents() {
+        use crate::widget::{operation, TextInput};
+
+        let renderer = Null::new();
+        let id = widget::text_input::Id::new("input");
+        let mut container: Container<'static, &str, Null> = Container::new(
+            TextInput::new("", "hello", |_| "changed").id(id.clone()),
+        )
+        .on_key_press(shortcuts);
+
+        let node = Widget::<&str, Null>::layout(
+            &container,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut tree = Tree::new(&container as &dyn Widget<&str, Null>);
+
+        Widget::<&str, Null>::operate(
+            &container,
+            &mut tree,
+            Layout::new(&node),
+            &renderer,
+            &mut operation::focusable::focus(id.into()),
+        );
+
+        let mut messages = Vec::new();
+
+        for key_code in
+            [keyboard::KeyCode::Backspace, keyboard::KeyCode::Escape]
+        {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = container.on_event(
+                &mut tree,
+                key_pressed(key_code),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::ORIGIN),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        assert_eq!(messages, ["changed", "escape"]);
+    }
+
+    #[test]
+    fn stops_handling_keys_once_focus_leaves_the_contents() {
+        use crate::widget::{operation, TextInput};
+
+        let renderer = Null::new();
+        let id = widget::text_input::Id::new("input");
+        let mut container: Container<'static, &str, Null> = Container::new(
+            TextInput::new("", "", |_| "changed").id(id.clone()),
+        )
+        .on_key_press(shortcuts);
+
+        let node = Widget::<&str, Null>::layout(
+            &container,
+            &renderer,
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut tree = Tree::new(&container as &dyn Widget<&str, Null>);
+        let mut messages = Vec::new();
+
+        for target in [id.into(), widget::Id::new("elsewhere")] {
+            Widget::<&str, Null>::operate(
+                &container,
+                &mut tree,
+                Layout::new(&node),
+                &renderer,
+                &mut operation::focusable::focus(target),
+            );
+
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = container.on_event(
+                &mut tree,
+                key_pressed(keyboard::KeyCode::Escape),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::ORIGIN),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        assert_eq!(messages, ["escape"]);
+    }
+
+    fn press(
+        container: &mut Container<'static, &'static str, Null>,
+        tree: &mut Tree,
+        node: &layout::Node,
+        events: impl IntoIterator<Item = (Event, Point)>,
+    ) -> (Vec<event::Status>, Vec<&'static str>) {
+        let mut messages = Vec::new();
+        let mut statuses = Vec::new();
+
+        for (event, position) in events {
+            let mut shell = Shell::new(&mut messages);
+
+            statuses.push(container.on_event(
+                tree,
+                event,
+                Layout::new(node),
+                mouse::Cursor::Available(position),
+                &Null::new(),
+                &mut crate::clipboard::Null,
+                &mut shell,
+            ));
+        }
+
+        (statuses, messages)
+    }
+
+    #[test]
+    fn nested_buttons_take_precedence_over_on_press() {
+        use crate::widget::{Button, Row};
+
+        let mut container: Container<'static, &str, Null> = Container::new(
+            Row::new()
+                .push(
+                    Button::new(Space::new(
+                        Length::Units(50),
+                        Length::Units(50),
+                    ))
+                    .padding(0)
+                    .on_press("button"),
+                )
+                .push(Space::new(Length::Units(50), Length::Units(50))),
+        )
+        .on_press("container");
+
+        let node = Widget::<&str, Null>::layout(
+            &container,
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut tree = Tree::new(&container as &dyn Widget<&str, Null>);
+
+        let pressed =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+        let released =
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left));
+
+        let (_, messages) = press(
+            &mut container,
+            &mut tree,
+            &node,
+            [
+                // Clicking the button
+                (pressed.clone(), Point::new(25.0, 25.0)),
+                (released.clone(), Point::new(25.0, 25.0)),
+                // Clicking the rest of the container
+                (pressed.clone(), Point::new(75.0, 25.0)),
+                (released.clone(), Point::new(75.0, 25.0)),
+                // Releasing outside of the container cancels the press
+                (pressed, Point::new(75.0, 25.0)),
+                (released, Point::new(150.0, 150.0)),
+            ],
+        );
+
+        assert_eq!(messages, ["button", "container"]);
+    }
+
+    #[test]
+    fn pressable_containers_are_activated_with_the_keyboard() {
+        use crate::widget::operation;
+
+        let id = Id::new("card");
+        let mut container: Container<'static, &str, Null> =
+            Container::new(Space::new(Length::Units(50), Length::Units(50)))
+                .id(id.clone())
+                .on_press("pressed");
+
+        let node = Widget::<&str, Null>::layout(
+            &container,
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut tree = Tree::new(&container as &dyn Widget<&str, Null>);
+
+        let released = |key_code| {
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers: keyboard::Modifiers::default(),
+            })
+        };
+
+        // Keys are ignored until the container is focused
+        let (statuses, messages) = press(
+            &mut container,
+            &mut tree,
+            &node,
+            [(key_pressed(keyboard::KeyCode::Space), Point::ORIGIN)],
+        );
+
+        assert_eq!(statuses, [event::Status::Ignored]);
+        assert!(messages.is_empty());
+
+        Widget::<&str, Null>::operate(
+            &container,
+            &mut tree,
+            Layout::new(&node),
+            &Null::new(),
+            &mut operation::focusable::focus(id.into()),
+        );
+
+        let (statuses, messages) = press(
+            &mut container,
+            &mut tree,
+            &node,
+            [
+                (key_pressed(keyboard::KeyCode::Space), Point::ORIGIN),
+                (key_pressed(keyboard::KeyCode::Enter), Point::ORIGIN),
+                (released(keyboard::KeyCode::Enter), Point::ORIGIN),
+            ],
+        );
+
+        assert_eq!(statuses, [event::Status::Captured; 3]);
+        assert_eq!(messages, ["pressed", "pressed"]);
+    }
+
+    #[test]
+    fn disabled_containers_ignore_presses() {
+        let mut container: Container<'static, &str, Null> =
+            Container::new(Space::new(Length::Units(50), Length::Units(50)))
+                .on_press_maybe(None);
+
+        let node = Widget::<&str, Null>::layout(
+            &container,
+            &Null::new(),
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+        let mut tree = Tree::new(&container as &dyn Widget<&str, Null>);
+
+        let (statuses, messages) = press(
+            &mut container,
+            &mut tree,
+            &node,
+            [
+                (
+                    Event::Mouse(mouse::Event::ButtonPressed(
+                        mouse::Button::Left,
+                    )),
+                    Point::new(25.0, 25.0),
+                ),
+                (
+                    Event::Mouse(mouse::Event::ButtonReleased(
+                        mouse::Button::Left,
+                    )),
+                    Point::new(25.0, 25.0),
+                ),
+            ],
+        );
+
+        assert_eq!(statuses, [event::Status::Ignored; 2]);
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn draws_each_side_of_the_border() {
+        use crate::renderer::Recorder;
+
+        let bounds =
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(100.0, 40.0));
+
+        let draw = |appearance: Appearance| {
+            let mut renderer = Recorder::default();
+
+            draw_background(&mut renderer, &appearance, bounds);
+
+            (
+                renderer.layers.len(),
+                renderer
+                    .quads
+                    .iter()
+                    .map(|(quad, _)| (quad.bounds, quad.border_width))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        // A uniform border is drawn along with the background
+        assert_eq!(
+            draw(Appearance {
+                border_width: 2.0,
+                border_color: Color::BLACK,
+                ..Appearance::default()
+            }),
+            (0, vec![(bounds, 2.0)])
+        );
+
+        // An underline draws the bottom side only
+        assert_eq!(
+            draw(Appearance {
+                border_color: Color::BLACK,
+                border_widths: Some([0.0, 0.0, 2.0, 0.0].into()),
+                ..Appearance::default()
+            }),
+            (
+                0,
+                vec![(
+                    Rectangle::new(
+                        Point::new(10.0, 48.0),
+                        Size::new(100.0, 2.0)
+                    ),
+                    0.0
+                )]
+            )
+        );
+
+        // The left and right sides fit between the top and bottom ones,
+        // and the edges are clipped to the rounded bounds
+        let (layers, quads) = draw(Appearance {
+            background: Some(Color::WHITE.into()),
+            border_radius: 4.0,
+            border_widths: Some([1.0, 3.0, 1.0, 3.0].into()),
+            border_colors: Some(
+                [Color::BLACK, Color::BLACK, Color::BLACK, Color::TRANSPARENT]
+                    .into(),
+            ),
+            ..Appearance::default()
+        });
+
+        assert_eq!(layers, 1);
+        assert_eq!(
+            quads,
+            vec![
+                (bounds, 0.0),
+                (
+                    Rectangle::new(
+                        Point::new(10.0, 10.0),
+                        Size::new(100.0, 1.0)
+                    ),
+                    0.0
+                ),
+                (
+                    Rectangle::new(
+                        Point::new(10.0, 49.0),
+                        Size::new(100.0, 1.0)
+                    ),
+                    0.0
+                ),
+                (
+                    Rectangle::new(
+                        Point::new(107.0, 11.0),
+                        Size::new(3.0, 38.0)
+                    ),
+                    0.0
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn auto_overflow_scrolls_only_past_the_caps() {
+        let sizes = |content: Space| {
+            let mut renderer = Null::new();
+            let element: Element<'static, (), Null> = Container::new(content)
+                .max_width(100)
+                .max_height(100)
+                .overflow(Overflow::Auto)
+                .into();
+
+            let node = renderer.layout(
+                &element,
+                &Limits::new(Size::ZERO, Size::new(300.0, 300.0)),
+            );
+
+            let scrollable = &node.children()[0];
+
+            (node.size(), scrollable.children()[0].size())
+        };
+
+        // Contents that fit are not scrolled
+        assert_eq!(
+            sizes(Space::new(Length::Units(50), Length::Units(60))),
+            (Size::new(50.0, 60.0), Size::new(50.0, 60.0))
+        );
+
+        // Contents that do not fit keep their size and are scrolled
+        assert_eq!(
+            sizes(Space::new(Length::Units(150), Length::Units(60))),
+            (Size::new(100.0, 60.0), Size::new(150.0, 60.0))
+        );
+        assert_eq!(
+            sizes(Space::new(Length::Units(50), Length::Units(150))),
+            (Size::new(50.0, 100.0), Size::new(50.0, 150.0))
+        );
+
+        // Contents that fill the container never overflow it horizontally
+        assert_eq!(
+            sizes(Space::new(Length::Fill, Length::Units(150))),
+            (Size::new(100.0, 100.0), Size::new(100.0, 150.0))
+        );
+    }
+
+    #[test]
+    fn overflow_wraps_the_contents_once() {
+        let element: Element<'static, (), Null> =
+            Container::new(Space::new(Length::Units(50), Length::Units(60)))
+                .overflow(Overflow::Auto)
+                .overflow(Overflow::Scroll)
+                .into();
+
+        let tree = Tree::new(&element);
+        let scrollable = &tree.children[0];
+
+        assert_eq!(scrollable.children.len(), 1);
+        assert!(scrollable.children[0].children.is_empty());
+
+        let element: Element<'static, (), Null> =
+            Container::new(Space::new(Length::Units(50), Length::Units(60)))
+                .overflow(Overflow::Scroll)
+                .overflow(Overflow::Visible)
+                .into();
+
+        assert!(Tree::new(&element).children[0].children.is_empty());
+    }
 }
@@ -0,0 +1,748 @@
+//! Change a number by dragging it, or type it in.
+//!
+//! A [`DragValue`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::text_input::{self, Value};
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Rectangle, Shell, Widget,
+};
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+pub use iced_style::text_input::{Appearance, StyleSheet};
+
+/// The distance, in pixels, a [`DragValue`] can be dragged before the drag
+/// stops being treated as a click.
+const CLICK_THRESHOLD: f32 = 3.0;
+
+/// The factor applied to the sensitivity of a [`DragValue`] while `Shift` is
+/// pressed.
+const FINE: f64 = 0.1;
+
+/// The factor applied to the sensitivity of a [`DragValue`] while the command
+/// key is pressed.
+const COARSE: f64 = 10.0;
+
+/// A number that changes when dragged horizontally, like the number fields
+/// of 3D software.
+///
+/// Dragging changes the value by the sensitivity of the [`DragValue`] for
+/// every pixel, and produces the new value while dragging. Holding `Shift`
+/// makes the changes finer, while holding the command key makes them
+/// coarser.
+///
+/// Clicking the [`DragValue`] turns it into a text input where the value can
+/// be typed in. The value is committed by pressing `Enter` or by clicking
+/// elsewhere; pressing `Escape` discards it.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::drag_value;
+/// # use iced_native::renderer::Null;
+/// #
+/// # type DragValue<'a, T, Message> =
+/// #     drag_value::DragValue<'a, T, Message, Null>;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     AngleChanged(f32),
+/// }
+///
+/// let angle = 90.0;
+///
+/// DragValue::new(angle, Message::AngleChanged)
+///     .range(0.0..=360.0)
+///     .sensitivity(0.5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DragValue<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    value: T,
+    range: Option<RangeInclusive<T>>,
+    sensitivity: f64,
+    on_change: Box<dyn Fn(T) -> Message + 'a>,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> DragValue<'a, T, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default sensitivity of a [`DragValue`].
+    pub const DEFAULT_SENSITIVITY: f64 = 1.0;
+
+    /// Creates a new [`DragValue`].
+    ///
+    /// It expects:
+    ///   * the current value of the [`DragValue`]
+    ///   * a function that will be called when the value changes. It receives
+    ///   the new value of the [`DragValue`] and must produce a `Message`.
+    pub fn new<F>(value: T, on_change: F) -> Self
+    where
+        F: 'a + Fn(T) -> Message,
+    {
+        DragValue {
+            value,
+            range: None,
+            sensitivity: Self::DEFAULT_SENSITIVITY,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            padding: Padding::ZERO,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the inclusive range the values of the [`DragValue`] are clamped
+    /// to, both when dragged and when typed in.
+    pub fn range(mut self, range: RangeInclusive<T>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Sets the amount the value of the [`DragValue`] changes for every
+    /// pixel it is dragged.
+    pub fn sensitivity(mut self, sensitivity: f64) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Sets the width of the [`DragValue`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DragValue`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DragValue`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`DragValue`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`DragValue`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DragValue<'a, T, Message, Renderer>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + fmt::Display + FromStr,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::with_debug(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        text_input::layout(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.size,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        update(
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            tree.state.downcast_mut::<State>(),
+            self.value,
+            self.range.as_ref(),
+            self.sensitivity,
+            self.size,
+            &self.font,
+            self.on_change.as_ref(),
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        draw(
+            renderer,
+            theme,
+            layout,
+            cursor,
+            tree.state.downcast_ref::<State>(),
+            self.value,
+            self.size,
+            &self.font,
+            &self.style,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        mouse_interaction(layout, cursor, tree.state.downcast_ref::<State>())
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DragValue<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a
+        + Copy
+        + Into<f64>
+        + num_traits::FromPrimitive
+        + fmt::Display
+        + FromStr,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        drag_value: DragValue<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drag_value)
+    }
+}
+
+/// Processes an [`Event`] and updates the [`State`] of a [`DragValue`]
+/// accordingly.
+pub fn update<Message, T, Renderer>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+    state: &mut State,
+    value: T,
+    range: Option<&RangeInclusive<T>>,
+    sensitivity: f64,
+    size: Option<u16>,
+    font: &Renderer::Font,
+    on_change: &dyn Fn(T) -> Message,
+) -> event::Status
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive + fmt::Display + FromStr,
+    Renderer: text::Renderer,
+{
+    let change = |shell: &mut Shell<'_, Message>, new_value: f64| {
+        let new_value = match range {
+            Some(range) => new_value
+                .max((*range.start()).into())
+                .min((*range.end()).into()),
+            None => new_value,
+        };
+
+        if let Some(new_value) = T::from_f64(new_value) {
+            if (value.into() - new_value.into()).abs() > f64::EPSILON {
+                shell.publish((on_change)(new_value));
+            }
+        }
+    };
+
+    if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+        &event
+    {
+        state.keyboard_modifiers = *modifiers;
+
+        // Start over from the current value, so the new sensitivity does not
+        // apply to the distance dragged so far
+        if let (Some(drag), Some(cursor_position)) =
+            (&mut state.drag, cursor.position())
+        {
+            drag.origin = cursor_position.x;
+            drag.start = value.into();
+        }
+    }
+
+    if let Some(editing) = &mut state.editing {
+        let is_cancelled = matches!(
+            event,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            })
+        );
+
+        let mut edits = Vec::new();
+        let mut local_shell = Shell::new(&mut edits);
+
+        let status = text_input::update(
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &mut editing.text,
+            size,
+            font,
+            false,
+            &|_| Edit::Changed,
+            None,
+            &Some(Edit::Submitted),
+            text_input::Blink::default(),
+            || &mut editing.input,
+        );
+
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
+        let is_submitted = edits.contains(&Edit::Submitted);
+
+        if is_submitted || !editing.input.is_focused() {
+            if !is_cancelled {
+                if let Ok(new_value) =
+                    editing.text.to_string().trim().parse::<T>()
+                {
+                    change(shell, new_value.into());
+                }
+            }
+
+            state.editing = None;
+        }
+
+        return status;
+    }
+
+    match event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) => {
+            if let Some(cursor_position) =
+                cursor.position_over(&layout.bounds())
+            {
+                state.drag = Some(Drag {
+                    origin: cursor_position.x,
+                    start: value.into(),
+                    is_scrubbing: false,
+                });
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            if let (Some(drag), Some(cursor_position)) =
+                (&mut state.drag, cursor.position())
+            {
+                let distance = cursor_position.x - drag.origin;
+
+                if drag.is_scrubbing || distance.abs() >= CLICK_THRESHOLD {
+                    drag.is_scrubbing = true;
+
+                    let factor = if state.keyboard_modifiers.shift() {
+                        FINE
+                    } else if state.keyboard_modifiers.command() {
+                        COARSE
+                    } else {
+                        1.0
+                    };
+
+                    change(
+                        shell,
+                        drag.start + f64::from(distance) * sensitivity * factor,
+                    );
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerLifted { .. })
+        | Event::Touch(touch::Event::FingerLost { .. }) => {
+            if let Some(drag) = state.drag.take() {
+                // A tiny drag is a click, which starts editing the value
+                if !drag.is_scrubbing {
+                    let mut input = text_input::State::new();
+                    input.focus();
+                    input.select_all();
+
+                    state.editing = Some(Editing {
+                        text: Value::new(&value.to_string()),
+                        input,
+                    });
+                }
+
+                return event::Status::Captured;
+            }
+        }
+        _ => {}
+    }
+
+    event::Status::Ignored
+}
+
+/// Draws a [`DragValue`].
+pub fn draw<T, Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    state: &State,
+    value: T,
+    size: Option<u16>,
+    font: &Renderer::Font,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+) where
+    T: fmt::Display,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    match &state.editing {
+        Some(editing) => text_input::draw(
+            renderer,
+            theme,
+            layout,
+            cursor,
+            &editing.input,
+            &editing.text,
+            "",
+            size,
+            font,
+            false,
+            text_input::Blink::default(),
+            style,
+        ),
+        None => text_input::draw(
+            renderer,
+            theme,
+            layout,
+            cursor,
+            &text_input::State::new(),
+            &Value::new(&value.to_string()),
+            "",
+            size,
+            font,
+            false,
+            text_input::Blink::default(),
+            style,
+        ),
+    }
+}
+
+/// Computes the current [`mouse::Interaction`] of a [`DragValue`].
+pub fn mouse_interaction(
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    state: &State,
+) -> mouse::Interaction {
+    if state.editing.is_some() {
+        text_input::mouse_interaction(layout, cursor)
+    } else if state.drag.is_some() || cursor.is_over(&layout.bounds()) {
+        mouse::Interaction::ResizingHorizontally
+    } else {
+        mouse::Interaction::default()
+    }
+}
+
+/// The local state of a [`DragValue`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    drag: Option<Drag>,
+    editing: Option<Editing>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`DragValue`] is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.map_or(false, |drag| drag.is_scrubbing)
+    }
+
+    /// Returns whether the value of the [`DragValue`] is being typed in.
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+}
+
+/// A press on a [`DragValue`] that is either a click or a drag.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: f32,
+    start: f64,
+    is_scrubbing: bool,
+}
+
+/// The value of a [`DragValue`] being typed in.
+#[derive(Debug, Clone)]
+struct Editing {
+    text: Value,
+    input: text_input::State,
+}
+
+/// A change of the text input of a [`DragValue`] being edited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Changed,
+    Submitted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::{Point, Size};
+
+    /// Sends the given events to a [`DragValue`] with the given `value`,
+    /// returning the values it produces.
+    fn send(
+        state: &mut State,
+        value: f32,
+        range: Option<RangeInclusive<f32>>,
+        events: impl IntoIterator<Item = (Event, f32)>,
+    ) -> Vec<f32> {
+        let renderer = Null::new();
+        let node = text_input::layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            Length::Fill,
+            Padding::ZERO,
+            None,
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for (event, x) in events {
+            let _ = update(
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(x, 5.0)),
+                &renderer,
+                &mut clipboard::Null,
+                &mut shell,
+                state,
+                value,
+                range.as_ref(),
+                0.5,
+                None,
+                &Default::default(),
+                &|value| value,
+            );
+        }
+
+        messages
+    }
+
+    fn press() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    fn release() -> Event {
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+    }
+
+    fn moved(x: f32) -> (Event, f32) {
+        (
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(x, 5.0),
+            }),
+            x,
+        )
+    }
+
+    fn key(key_code: keyboard::KeyCode) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn dragging_produces_live_values_within_the_range() {
+        let mut state = State::new();
+
+        let values = send(
+            &mut state,
+            10.0,
+            Some(0.0..=30.0),
+            [(press(), 50.0), moved(60.0), moved(40.0), moved(100.0)],
+        );
+
+        assert_eq!(values, vec![15.0, 5.0, 30.0]);
+        assert!(state.is_dragging());
+
+        let _ = send(&mut state, 10.0, None, [(release(), 100.0)]);
+
+        assert!(!state.is_dragging());
+        assert!(!state.is_editing());
+    }
+
+    #[test]
+    fn modifiers_change_the_sensitivity() {
+        let mut state = State::new();
+
+        let values = send(
+            &mut state,
+            10.0,
+            None,
+            [
+                (press(), 50.0),
+                (
+                    Event::Keyboard(keyboard::Event::ModifiersChanged(
+                        keyboard::Modifiers::SHIFT,
+                    )),
+                    50.0,
+                ),
+                moved(70.0),
+            ],
+        );
+
+        assert_eq!(values, vec![11.0]);
+    }
+
+    #[test]
+    fn tiny_drags_start_editing() {
+        let mut state = State::new();
+
+        let values = send(
+            &mut state,
+            10.0,
+            None,
+            [(press(), 50.0), moved(52.0), (release(), 52.0)],
+        );
+
+        assert!(values.is_empty());
+        assert!(state.is_editing());
+    }
+
+    #[test]
+    fn typed_values_are_committed_within_the_range() {
+        let mut state = State::new();
+        let range = Some(0.0..=30.0);
+
+        let _ = send(
+            &mut state,
+            10.0,
+            range.clone(),
+            [(press(), 50.0), (release(), 50.0)],
+        );
+
+        let values = send(
+            &mut state,
+            10.0,
+            range.clone(),
+            "42".chars()
+                .map(|c| {
+                    (
+                        Event::Keyboard(keyboard::Event::CharacterReceived(c)),
+                        50.0,
+                    )
+                })
+                .chain([(key(keyboard::KeyCode::Enter), 50.0)]),
+        );
+
+        assert_eq!(values, vec![30.0]);
+        assert!(!state.is_editing());
+
+        // Escape discards the typed value
+        let _ = send(
+            &mut state,
+            10.0,
+            range.clone(),
+            [(press(), 50.0), (release(), 50.0)],
+        );
+
+        let values = send(
+            &mut state,
+            10.0,
+            range,
+            [
+                (
+                    Event::Keyboard(keyboard::Event::CharacterReceived('5')),
+                    50.0,
+                ),
+                (key(keyboard::KeyCode::Escape), 50.0),
+            ],
+        );
+
+        assert!(values.is_empty());
+        assert!(!state.is_editing());
+    }
+}
@@ -8,27 +8,78 @@ use std::ops::RangeInclusive;
 
 /// Creates a [`Column`] with the given children.
 ///
+/// Besides single elements, a child prefixed with `..` spreads the items of
+/// anything that can be turned into an iterator of elements, in place. Since
+/// an [`Option`] is an iterator too, this can be used for optional children.
+///
+/// ```
+/// # type Column<'a, Message> =
+/// #     iced_native::widget::Column<'a, Message, iced_native::renderer::Null>;
+/// #
+/// use iced_native::column;
+/// use iced_native::widget::text;
+///
+/// let items = ["Apples", "Oranges"];
+/// let footer = items.is_empty().then(|| text("Nothing to see here"));
+///
+/// let column: Column<'_, ()> = column![
+///     text("Fruits"),
+///     ..items.iter().map(text),
+///     ..footer,
+/// ];
+/// ```
+///
 /// [`Column`]: widget::Column
 #[macro_export]
 macro_rules! column {
     () => (
         $crate::widget::Column::new()
     );
-    ($($x:expr),+ $(,)?) => (
-        $crate::widget::Column::with_children(vec![$($crate::Element::from($x)),+])
-    );
+    ($($x:tt)+) => ({
+        let mut children = ::std::vec::Vec::new();
+
+        $crate::__push_children!(children; $($x)+);
+
+        $crate::widget::Column::with_children(children)
+    });
 }
 
 /// Creates a [`Row`] with the given children.
 ///
+/// Like in [`column!`], a child prefixed with `..` spreads the items of an
+/// iterator of elements in place.
+///
 /// [`Row`]: widget::Row
 #[macro_export]
 macro_rules! row {
     () => (
         $crate::widget::Row::new()
     );
-    ($($x:expr),+ $(,)?) => (
-        $crate::widget::Row::with_children(vec![$($crate::Element::from($x)),+])
+    ($($x:tt)+) => ({
+        let mut children = ::std::vec::Vec::new();
+
+        $crate::__push_children!(children; $($x)+);
+
+        $crate::widget::Row::with_children(children)
+    });
+}
+
+/// Pushes the children of a [`column!`] or a [`row!`], in order.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __push_children {
+    ($children:ident;) => ();
+    ($children:ident; .. $x:expr $(, $($rest:tt)*)?) => (
+        $children.extend(
+            ::std::iter::IntoIterator::into_iter($x).map($crate::Element::from),
+        );
+
+        $crate::__push_children!($children; $($($rest)*)?);
+    );
+    ($children:ident; $x:expr $(, $($rest:tt)*)?) => (
+        $children.push($crate::Element::from($x));
+
+        $crate::__push_children!($children; $($($rest)*)?);
     );
 }
 
@@ -45,6 +96,20 @@ where
     widget::Container::new(content)
 }
 
+/// Creates a new [`Opacity`] that draws the provided content with the given
+/// opacity.
+///
+/// [`Opacity`]: widget::Opacity
+pub fn opacity<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    opacity: f32,
+) -> widget::Opacity<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    widget::Opacity::new(content, opacity)
+}
+
 /// Creates a new [`Column`] with the given children.
 ///
 /// [`Column`]: widget::Column
@@ -63,6 +128,36 @@ pub fn row<Message, Renderer>(
     widget::Row::with_children(children)
 }
 
+/// Creates a new keyed [`Column`] with the given children.
+///
+/// The state of the children is matched by key, so it is kept when they are
+/// reordered.
+///
+/// [`Column`]: widget::keyed::Column
+pub fn keyed_column<'a, Key, Message, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Renderer>)>,
+) -> widget::keyed::Column<'a, Key, Message, Renderer>
+where
+    Key: Clone + Eq + std::hash::Hash + 'static,
+{
+    widget::keyed::Column::with_children(children)
+}
+
+/// Creates a new keyed [`Row`] with the given children.
+///
+/// The state of the children is matched by key, so it is kept when they are
+/// reordered.
+///
+/// [`Row`]: widget::keyed::Row
+pub fn keyed_row<'a, Key, Message, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Renderer>)>,
+) -> widget::keyed::Row<'a, Key, Message, Renderer>
+where
+    Key: Clone + Eq + std::hash::Hash + 'static,
+{
+    widget::keyed::Row::with_children(children)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: widget::Scrollable
@@ -150,6 +245,36 @@ where
     widget::Radio::new(value, label, selected, on_click)
 }
 
+/// Creates a new [`Choices`] group of radio buttons, where a single choice
+/// can be selected.
+///
+/// [`Choices`]: widget::Choices
+pub fn radio_group<'a, T, Message>(
+    choices: impl IntoIterator<Item = T>,
+    selected: Option<T>,
+    on_select: impl Fn(T) -> Message + 'a,
+) -> widget::Choices<'a, T, Message>
+where
+    T: Copy + Eq + ToString,
+{
+    widget::Choices::radio(choices, selected, on_select)
+}
+
+/// Creates a new [`Choices`] group of checkboxes, where any number of
+/// choices can be selected.
+///
+/// [`Choices`]: widget::Choices
+pub fn checkbox_group<'a, T, Message>(
+    choices: impl IntoIterator<Item = T>,
+    selected: &[T],
+    on_change: impl Fn(Vec<T>) -> Message + 'a,
+) -> widget::Choices<'a, T, Message>
+where
+    T: Copy + Eq + ToString,
+{
+    widget::Choices::checkbox(choices, selected, on_change)
+}
+
 /// Creates a new [`Toggler`].
 ///
 /// [`Toggler`]: widget::Toggler
@@ -198,6 +323,20 @@ where
     widget::Slider::new(range, value, on_change)
 }
 
+/// Creates a new [`DragValue`].
+///
+/// [`DragValue`]: widget::DragValue
+pub fn drag_value<'a, T, Message, Renderer>(
+    value: T,
+    on_change: impl Fn(T) -> Message + 'a,
+) -> widget::DragValue<'a, T, Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::drag_value::StyleSheet,
+{
+    widget::DragValue::new(value, on_change)
+}
+
 /// Creates a new [`VerticalSlider`].
 ///
 /// [`VerticalSlider`]: widget::VerticalSlider
@@ -237,6 +376,37 @@ where
     widget::PickList::new(options, selected, on_selected)
 }
 
+/// Creates a new [`SplitButton`] with the given content and alternative
+/// actions.
+///
+/// [`SplitButton`]: widget::SplitButton
+pub fn split_button<'a, T, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    options: impl Into<Cow<'a, [T]>>,
+    on_selected: impl Fn(T) -> Message + 'a,
+) -> widget::SplitButton<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::button::StyleSheet + overlay::menu::StyleSheet,
+{
+    widget::SplitButton::new(content, options, on_selected)
+}
+
+/// Creates a new [`MenuBar`] showing the given [`Menu`].
+///
+/// [`MenuBar`]: widget::MenuBar
+/// [`Menu`]: crate::menu::Menu
+pub fn menu_bar<Message, Renderer>(
+    menu: crate::menu::Menu<Message>,
+) -> widget::MenuBar<Message, Renderer>
+where
+    Renderer: crate::text::Renderer,
+    Renderer::Theme: widget::menu_bar::StyleSheet,
+{
+    widget::MenuBar::new(menu)
+}
+
 /// Creates a new [`Image`].
 ///
 /// [`Image`]: widget::Image
@@ -298,6 +468,17 @@ where
     widget::ProgressBar::new(range, value)
 }
 
+/// Creates a new [`Spinner`].
+///
+/// [`Spinner`]: widget::Spinner
+pub fn spinner<Renderer>() -> widget::Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: widget::spinner::StyleSheet,
+{
+    widget::Spinner::new()
+}
+
 /// Creates a new [`Svg`] widget from the given [`Handle`].
 ///
 /// [`Svg`]: widget::Svg
@@ -311,3 +492,52 @@ where
 {
     widget::Svg::new(handle)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::layout;
+    use crate::renderer::Null;
+    use crate::widget::{horizontal_space, Row, Space};
+    use crate::{Length, Size, Widget};
+
+    fn widths(row: Row<'_, (), Null>) -> Vec<f32> {
+        Widget::layout(
+            &row,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 100.0)),
+        )
+        .children()
+        .iter()
+        .map(|child| child.bounds().width)
+        .collect()
+    }
+
+    #[test]
+    fn spread_children_preserve_order() {
+        let items = [2, 3];
+        let empty: [u16; 0] = [];
+        let space = |width: &u16| horizontal_space(Length::Units(*width));
+
+        let row = row![
+            horizontal_space(Length::Units(1)),
+            ..items.iter().map(space),
+            ..empty.iter().map(space),
+            ..Some(space(&4)),
+            ..None::<Space>,
+            horizontal_space(Length::Units(5))
+        ];
+
+        assert_eq!(widths(row), [1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        assert!(widths(row![..empty.iter().map(space)]).is_empty());
+
+        // Fixed lists keep working
+        assert_eq!(
+            widths(row![
+                horizontal_space(Length::Units(1)),
+                horizontal_space(Length::Units(2)),
+            ]),
+            [1.0, 2.0]
+        );
+    }
+}
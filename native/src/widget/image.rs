@@ -1,13 +1,19 @@
 //! Display images in your user interface.
+pub mod deferred;
+pub mod nine_slice;
 pub mod viewer;
+
+pub use deferred::Deferred;
+pub use nine_slice::NineSlice;
 pub use viewer::Viewer;
 
-use crate::image;
+use crate::image::{self, Flip, Rotation};
 use crate::layout;
+use crate::mouse;
 use crate::renderer;
 use crate::widget::Tree;
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    ContentFit, Element, Layout, Length, Rectangle, Size, Vector, Widget,
 };
 
 use std::hash::Hash;
@@ -17,6 +23,17 @@ pub fn viewer<Handle>(handle: Handle) -> Viewer<Handle> {
     Viewer::new(handle)
 }
 
+/// Creates a new [`Deferred`] image with the given handle, which is decoded
+/// in the background.
+pub fn deferred<'a, Message, Renderer>(
+    handle: impl Into<Renderer::Handle>,
+) -> Deferred<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    Deferred::new(handle)
+}
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
 /// # Example
@@ -35,6 +52,9 @@ pub struct Image<Handle> {
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    rotation: Rotation,
+    flip: Flip,
+    nine_slice: Option<NineSlice>,
 }
 
 impl<Handle> Image<Handle> {
@@ -45,6 +65,9 @@ impl<Handle> Image<Handle> {
             width: Length::Shrink,
             height: Length::Shrink,
             content_fit: ContentFit::Contain,
+            rotation: Rotation::None,
+            flip: Flip::None,
+            nine_slice: None,
         }
     }
 
@@ -63,12 +86,44 @@ impl<Handle> Image<Handle> {
     /// Sets the [`ContentFit`] of the [`Image`].
     ///
     /// Defaults to [`ContentFit::Contain`]
+    ///
+    /// The [`ContentFit`] applies to the rotated [`Image`]; a quarter turn
+    /// fits the image as if its width and height were swapped.
     pub fn content_fit(self, content_fit: ContentFit) -> Self {
         Self {
             content_fit,
             ..self
         }
     }
+
+    /// Sets the [`Rotation`] of the [`Image`].
+    ///
+    /// The [`Image`] is laid out with its rotated size, so quarter turns
+    /// swap its intrinsic width and height.
+    pub fn rotation(self, rotation: Rotation) -> Self {
+        Self { rotation, ..self }
+    }
+
+    /// Sets the [`Flip`] of the [`Image`].
+    ///
+    /// The [`Image`] is flipped before it is rotated, so a horizontal flip
+    /// always mirrors the original image along its vertical axis.
+    pub fn flip(self, flip: Flip) -> Self {
+        Self { flip, ..self }
+    }
+
+    /// Splits the [`Image`] into nine slices with the given [`NineSlice`]
+    /// insets, so its borders keep their size while the [`Image`] fills its
+    /// bounds.
+    ///
+    /// The [`ContentFit`], [`Rotation`], and [`Flip`] of the [`Image`] are
+    /// ignored when it is sliced.
+    pub fn nine_slice(self, nine_slice: impl Into<NineSlice>) -> Self {
+        Self {
+            nine_slice: Some(nine_slice.into()),
+            ..self
+        }
+    }
 }
 
 /// Computes the layout of an [`Image`].
@@ -79,13 +134,15 @@ pub fn layout<Renderer, Handle>(
     width: Length,
     height: Length,
     content_fit: ContentFit,
+    rotation: Rotation,
 ) -> layout::Node
 where
     Renderer: image::Renderer<Handle = Handle>,
 {
-    // The raw w/h of the underlying image
+    // The raw w/h of the underlying image, once rotated
     let image_size = {
-        let Size { width, height } = renderer.dimensions(handle);
+        let Size { width, height } =
+            rotation.apply(renderer.dimensions(handle));
 
         Size::new(width as f32, height as f32)
     };
@@ -99,11 +156,15 @@ where
     // Shrink the widget to fit the resized image, if requested
     let final_size = Size {
         width: match width {
-            Length::Shrink => f32::min(raw_size.width, full_size.width),
+            Length::Shrink | Length::FitContent(_) => {
+                f32::min(raw_size.width, full_size.width)
+            }
             _ => raw_size.width,
         },
         height: match height {
-            Length::Shrink => f32::min(raw_size.height, full_size.height),
+            Length::Shrink | Length::FitContent(_) => {
+                f32::min(raw_size.height, full_size.height)
+            }
             _ => raw_size.height,
         },
     };
@@ -117,11 +178,13 @@ pub fn draw<Renderer, Handle>(
     layout: Layout<'_>,
     handle: &Handle,
     content_fit: ContentFit,
+    rotation: Rotation,
+    flip: Flip,
 ) where
     Renderer: image::Renderer<Handle = Handle>,
     Handle: Clone + Hash,
 {
-    let Size { width, height } = renderer.dimensions(handle);
+    let Size { width, height } = rotation.apply(renderer.dimensions(handle));
     let image_size = Size::new(width as f32, height as f32);
 
     let bounds = layout.bounds();
@@ -139,7 +202,18 @@ pub fn draw<Renderer, Handle>(
             ..bounds
         };
 
-        renderer.draw(handle.clone(), drawing_bounds + offset)
+        let drawing_bounds = drawing_bounds + offset;
+
+        if rotation == Rotation::None && flip == Flip::None {
+            renderer.draw(handle.clone(), drawing_bounds)
+        } else {
+            renderer.draw_oriented(
+                handle.clone(),
+                rotation,
+                flip,
+                drawing_bounds,
+            )
+        }
     };
 
     if adjusted_fit.width > bounds.width || adjusted_fit.height > bounds.height
@@ -168,13 +242,20 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let (content_fit, rotation) = if self.nine_slice.is_some() {
+            (ContentFit::Fill, Rotation::None)
+        } else {
+            (self.content_fit, self.rotation)
+        };
+
         layout(
             renderer,
             limits,
             &self.handle,
             self.width,
             self.height,
-            self.content_fit,
+            content_fit,
+            rotation,
         )
     }
 
@@ -185,10 +266,25 @@ where
         _theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        draw(renderer, layout, &self.handle, self.content_fit)
+        match self.nine_slice {
+            Some(nine_slice) => nine_slice::draw(
+                renderer,
+                &self.handle,
+                nine_slice,
+                layout.bounds(),
+            ),
+            None => draw(
+                renderer,
+                layout,
+                &self.handle,
+                self.content_fit,
+                self.rotation,
+                self.flip,
+            ),
+        }
     }
 }
 
@@ -202,3 +298,88 @@ where
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Recorder;
+    use crate::{Point, Theme};
+
+    fn draw_image(
+        image: Image<()>,
+        max: Size,
+    ) -> Vec<(Rotation, Flip, Rectangle)> {
+        let mut renderer = Recorder::new();
+
+        let node = Widget::<(), Recorder>::layout(
+            &image,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, max),
+        );
+
+        Widget::<(), Recorder>::draw(
+            &image,
+            &Tree::empty(),
+            &mut renderer,
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &node.bounds(),
+        );
+
+        renderer
+            .images
+            .iter()
+            .map(|image| (image.rotation, image.flip, image.bounds))
+            .collect()
+    }
+
+    #[test]
+    fn quarter_turns_swap_the_size_of_the_image() {
+        assert_eq!(
+            draw_image(
+                Image::new(()).rotation(Rotation::Clockwise),
+                Size::new(100.0, 100.0)
+            ),
+            vec![(
+                Rotation::Clockwise,
+                Flip::None,
+                Rectangle::new(Point::ORIGIN, Size::new(20.0, 40.0))
+            )]
+        );
+
+        assert_eq!(
+            draw_image(
+                Image::new(())
+                    .rotation(Rotation::UpsideDown)
+                    .flip(Flip::Horizontal),
+                Size::new(100.0, 100.0)
+            ),
+            vec![(
+                Rotation::UpsideDown,
+                Flip::Horizontal,
+                Rectangle::new(Point::ORIGIN, Size::new(40.0, 20.0))
+            )]
+        );
+    }
+
+    #[test]
+    fn content_fit_applies_to_the_rotated_image() {
+        // The rotated 20x40 image is scaled down to fit a height of 20
+        assert_eq!(
+            draw_image(
+                Image::new(())
+                    .width(Length::Fill)
+                    .rotation(Rotation::CounterClockwise),
+                Size::new(100.0, 20.0)
+            ),
+            vec![(
+                Rotation::CounterClockwise,
+                Flip::None,
+                Rectangle::new(Point::new(45.0, 0.0), Size::new(10.0, 20.0))
+            )]
+        );
+    }
+}
@@ -0,0 +1,496 @@
+//! Decode images in the background while showing a placeholder.
+use crate::event::{self, Event};
+use crate::image::{self, Flip, Rotation, Status};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, Color, ContentFit, Element, Layout, Length, Rectangle, Shell,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// An image that is decoded in the background.
+///
+/// A placeholder is shown until the image is decoded. Then, the image is
+/// swapped in and the layout is invalidated, since the size of the image is
+/// only known at that point. If the image cannot be found or decoded, an
+/// error placeholder is shown instead.
+///
+/// Both placeholders are transparent by default, and they can either be a
+/// [`Color`] or an [`Element`]. An [`Element`] placeholder is laid out with
+/// the width and height of the [`Deferred`] image, and it receives events as
+/// usual—for instance, to animate a spinner or to retry a failed load.
+///
+/// Decoded images are cached by the renderer by the id of their handle, so
+/// different widgets showing the same image decode it only once. Replacing
+/// the handle of a [`Deferred`] image before it is decoded cancels the stale
+/// decoding.
+#[allow(missing_debug_implementations)]
+pub struct Deferred<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    handle: Renderer::Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    placeholders: [Placeholder<'a, Message, Renderer>; 2],
+}
+
+impl<'a, Message, Renderer> Deferred<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+{
+    /// Creates a new [`Deferred`] image with the given handle.
+    pub fn new(handle: impl Into<Renderer::Handle>) -> Self {
+        Self {
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            placeholders: [
+                Placeholder::Color(Color::TRANSPARENT),
+                Placeholder::Color(Color::TRANSPARENT),
+            ],
+        }
+    }
+
+    /// Sets the width of the [`Deferred`] image boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Deferred`] image boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Deferred`] image.
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`Element`] shown while the image is being decoded.
+    pub fn placeholder(
+        mut self,
+        placeholder: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.placeholders[0] = Placeholder::Element(placeholder.into());
+        self
+    }
+
+    /// Sets the [`Color`] that fills the [`Deferred`] image while the image
+    /// is being decoded.
+    pub fn placeholder_color(mut self, color: Color) -> Self {
+        self.placeholders[0] = Placeholder::Color(color);
+        self
+    }
+
+    /// Sets the [`Element`] shown when the image cannot be found or decoded.
+    pub fn error(
+        mut self,
+        error: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.placeholders[1] = Placeholder::Element(error.into());
+        self
+    }
+
+    /// Sets the [`Color`] that fills the [`Deferred`] image when the image
+    /// cannot be found or decoded.
+    pub fn error_color(mut self, color: Color) -> Self {
+        self.placeholders[1] = Placeholder::Color(color);
+        self
+    }
+}
+
+/// Returns the index of the placeholder shown for the given [`Status`], if
+/// any.
+fn placeholder(status: Status) -> Option<usize> {
+    match status {
+        Status::Loading => Some(0),
+        Status::Loaded => None,
+        Status::Failed => Some(1),
+    }
+}
+
+/// What a [`Deferred`] image shows instead of its image.
+enum Placeholder<'a, Message, Renderer> {
+    Color(Color),
+    Element(Element<'a, Message, Renderer>),
+}
+
+impl<'a, Message, Renderer> Placeholder<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn tree(&self) -> Tree {
+        match self {
+            Placeholder::Color(_) => Tree::empty(),
+            Placeholder::Element(element) => Tree::new(element),
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        match self {
+            Placeholder::Color(_) => *tree = Tree::empty(),
+            Placeholder::Element(element) => tree.diff(element),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    status: Status,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Deferred<'a, Message, Renderer>
+where
+    Renderer: image::Renderer,
+    Renderer::Handle: Clone + Hash,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            status: Status::Loading,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.placeholders.iter().map(Placeholder::tree).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if tree.children.len() != self.placeholders.len() {
+            tree.children = self.children();
+            return;
+        }
+
+        for (placeholder, tree) in
+            self.placeholders.iter().zip(&mut tree.children)
+        {
+            placeholder.diff(tree);
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let index = match placeholder(renderer.load(&self.handle)) {
+            Some(index) => index,
+            None => {
+                return super::layout(
+                    renderer,
+                    limits,
+                    &self.handle,
+                    self.width,
+                    self.height,
+                    self.content_fit,
+                    Rotation::None,
+                );
+            }
+        };
+
+        let limits = limits.width(self.width).height(self.height);
+
+        match &self.placeholders[index] {
+            Placeholder::Color(_) => {
+                layout::Node::new(limits.resolve(Size::ZERO))
+            }
+            Placeholder::Element(element) => {
+                let content = element.as_widget().layout(renderer, &limits);
+                let size = limits.resolve(content.size());
+
+                layout::Node::with_children(size, vec![content])
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // The renderer wakes up the shell once the image is decoded
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            let state = tree.state.downcast_mut::<State>();
+            let status = renderer.load(&self.handle);
+
+            if status != state.status {
+                state.status = status;
+
+                shell.invalidate_layout();
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+
+        let index = match placeholder(tree.state.downcast_ref::<State>().status)
+        {
+            Some(index) => index,
+            None => return event::Status::Ignored,
+        };
+
+        match (&mut self.placeholders[index], layout.children().next()) {
+            (Placeholder::Element(element), Some(layout)) => {
+                shell.traced(index, |shell| {
+                    element.as_widget_mut().on_event(
+                        &mut tree.children[index],
+                        event,
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                })
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let index = match placeholder(tree.state.downcast_ref::<State>().status)
+        {
+            Some(index) => index,
+            None => return mouse::Interaction::Idle,
+        };
+
+        match (&self.placeholders[index], layout.children().next()) {
+            (Placeholder::Element(element), Some(layout)) => {
+                element.as_widget().mouse_interaction(
+                    &tree.children[index],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::Idle,
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        // Loading the image every frame keeps its decoding alive
+        let index = match placeholder(renderer.load(&self.handle)) {
+            Some(index) => index,
+            None => {
+                super::draw(
+                    renderer,
+                    layout,
+                    &self.handle,
+                    self.content_fit,
+                    Rotation::None,
+                    Flip::None,
+                );
+
+                return;
+            }
+        };
+
+        match (&self.placeholders[index], layout.children().next()) {
+            (Placeholder::Element(element), Some(content_layout)) => {
+                element.as_widget().draw(
+                    &tree.children[index],
+                    renderer,
+                    theme,
+                    style,
+                    content_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+            (Placeholder::Color(color), _) if color.a > 0.0 => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: layout.bounds(),
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    *color,
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Deferred<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: image::Renderer + 'a,
+    Renderer::Handle: Clone + Hash + 'a,
+{
+    fn from(deferred: Deferred<'a, Message, Renderer>) -> Self {
+        Element::new(deferred)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::renderer::Recorder;
+    use crate::time::Instant;
+    use crate::widget::Space;
+    use crate::{Point, Theme};
+
+    fn loading() -> Recorder {
+        let renderer = Recorder::new();
+        renderer.image_status.set(Status::Loading);
+
+        renderer
+    }
+
+    fn layout(
+        deferred: &Deferred<'_, (), Recorder>,
+        renderer: &Recorder,
+    ) -> layout::Node {
+        Widget::layout(
+            deferred,
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        )
+    }
+
+    fn redraw(
+        deferred: &mut Deferred<'_, (), Recorder>,
+        tree: &mut Tree,
+        node: &layout::Node,
+        renderer: &Recorder,
+        now: Instant,
+    ) -> (bool, Option<window::RedrawRequest>) {
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = deferred.on_event(
+            tree,
+            Event::Window(window::Event::RedrawRequested(now)),
+            Layout::new(node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            renderer,
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        (shell.is_layout_invalid(), shell.redraw_request())
+    }
+
+    #[test]
+    fn placeholder_is_swapped_once_the_image_is_decoded() {
+        let mut renderer = loading();
+        let mut deferred = Deferred::new(()).placeholder_color(Color::BLACK);
+        let mut tree = Tree::new(&deferred as &dyn Widget<_, _>);
+        let now = Instant::now();
+
+        let node = layout(&deferred, &renderer);
+        assert_eq!(node.size(), Size::ZERO);
+
+        // The renderer wakes up the shell once the image is decoded, so
+        // nothing is requested in the meantime
+        assert_eq!(
+            redraw(&mut deferred, &mut tree, &node, &renderer, now),
+            (false, None)
+        );
+
+        renderer.image_status.set(Status::Loaded);
+
+        assert_eq!(
+            redraw(&mut deferred, &mut tree, &node, &renderer, now),
+            (true, Some(window::RedrawRequest::NextFrame))
+        );
+
+        let node = layout(&deferred, &renderer);
+        assert_eq!(node.size(), Size::new(40.0, 20.0));
+
+        Widget::draw(
+            &deferred,
+            &tree,
+            &mut renderer,
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &node.bounds(),
+        );
+
+        assert_eq!(
+            renderer
+                .images
+                .iter()
+                .map(|image| image.bounds)
+                .collect::<Vec<_>>(),
+            vec![node.bounds()]
+        );
+    }
+
+    #[test]
+    fn images_that_fail_to_decode_show_the_error_placeholder() {
+        let renderer = Recorder::new();
+        renderer.image_status.set(Status::Failed);
+
+        let mut deferred = Deferred::new(())
+            .placeholder(Space::new(Length::Units(5), Length::Units(5)))
+            .error(Space::new(Length::Units(10), Length::Units(10)));
+        let mut tree = Tree::new(&deferred as &dyn Widget<_, _>);
+
+        let node = layout(&deferred, &renderer);
+        assert_eq!(node.size(), Size::new(10.0, 10.0));
+        assert_eq!(node.children().len(), 1);
+
+        // Nothing else is requested once the image has failed
+        assert_eq!(
+            redraw(&mut deferred, &mut tree, &node, &renderer, Instant::now()),
+            (true, Some(window::RedrawRequest::NextFrame))
+        );
+        assert_eq!(
+            redraw(&mut deferred, &mut tree, &node, &renderer, Instant::now()),
+            (false, None)
+        );
+    }
+}
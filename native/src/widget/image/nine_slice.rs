@@ -0,0 +1,309 @@
+//! Scale images while keeping their borders intact.
+use crate::image;
+use crate::Rectangle;
+
+/// The insets that split an [`Image`] into nine slices.
+///
+/// The insets are given in pixels of the source image and are measured from
+/// its respective edge—e.g. a `left` inset of `4` makes the first `4` columns
+/// of pixels the left border of the image.
+///
+/// When drawn, the corners keep their size—one logical unit per source
+/// pixel—while the edges stretch along their axis and the center fills the
+/// rest. If the bounds are too small to fit the corners, they are scaled
+/// down proportionally.
+///
+/// [`Image`]: crate::widget::Image
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NineSlice {
+    /// The top inset, in source pixels.
+    pub top: u32,
+    /// The right inset, in source pixels.
+    pub right: u32,
+    /// The bottom inset, in source pixels.
+    pub bottom: u32,
+    /// The left inset, in source pixels.
+    pub left: u32,
+    /// How the center of the image fills its region.
+    pub center: Center,
+}
+
+/// How the center of a [`NineSlice`] fills its region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Center {
+    /// The center is stretched to fill its region.
+    Stretch,
+    /// The center is repeated at its original size to fill its region.
+    Tile,
+}
+
+impl NineSlice {
+    /// Creates a new [`NineSlice`] with the same inset on every edge.
+    pub fn new(inset: u32) -> Self {
+        Self::from([inset, inset, inset, inset])
+    }
+
+    /// Sets how the center of the [`NineSlice`] fills its region.
+    pub fn center(self, center: Center) -> Self {
+        Self { center, ..self }
+    }
+}
+
+impl From<[u32; 4]> for NineSlice {
+    /// Creates a [`NineSlice`] from its `[top, right, bottom, left]` insets.
+    fn from([top, right, bottom, left]: [u32; 4]) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+            center: Center::Stretch,
+        }
+    }
+}
+
+/// Draws an image split with the given [`NineSlice`] inside the provided
+/// `bounds`.
+///
+/// Neighboring slices share the coordinates of their edges, so no seams
+/// appear between them regardless of the scale factor.
+pub fn draw<Renderer>(
+    renderer: &mut Renderer,
+    handle: &Renderer::Handle,
+    nine_slice: NineSlice,
+    bounds: Rectangle,
+) where
+    Renderer: image::Renderer,
+{
+    let size = renderer.dimensions(handle);
+
+    let (columns, x) = edges(
+        nine_slice.left,
+        nine_slice.right,
+        size.width,
+        bounds.x,
+        bounds.width,
+    );
+
+    let (rows, y) = edges(
+        nine_slice.top,
+        nine_slice.bottom,
+        size.height,
+        bounds.y,
+        bounds.height,
+    );
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let region = Rectangle {
+                x: columns[column],
+                y: rows[row],
+                width: columns[column + 1] - columns[column],
+                height: rows[row + 1] - rows[row],
+            };
+
+            let slice = Rectangle {
+                x: x[column],
+                y: y[row],
+                width: x[column + 1] - x[column],
+                height: y[row + 1] - y[row],
+            };
+
+            if region.width == 0
+                || region.height == 0
+                || slice.width <= 0.0
+                || slice.height <= 0.0
+            {
+                continue;
+            }
+
+            if (row, column) == (1, 1) && nine_slice.center == Center::Tile {
+                tile(renderer, handle, region, slice);
+            } else {
+                renderer.draw_region(handle.clone(), region, slice);
+            }
+        }
+    }
+}
+
+/// Returns the edges of the slices along an axis, both in source pixels and
+/// in logical coordinates.
+fn edges(
+    start: u32,
+    end: u32,
+    source: u32,
+    position: f32,
+    length: f32,
+) -> ([u32; 4], [f32; 4]) {
+    // Overlapping insets are shrunk to meet in the middle of the source
+    let (start, end) = if u64::from(start) + u64::from(end) > u64::from(source)
+    {
+        let start = (u64::from(start) * u64::from(source)
+            / (u64::from(start) + u64::from(end))) as u32;
+
+        (start, source - start)
+    } else {
+        (start, end)
+    };
+
+    let insets = start as f32 + end as f32;
+
+    let scale = if insets > length && insets > 0.0 {
+        length / insets
+    } else {
+        1.0
+    };
+
+    (
+        [0, start, source - end, source],
+        [
+            position,
+            position + start as f32 * scale,
+            position + length - end as f32 * scale,
+            position + length,
+        ],
+    )
+}
+
+fn tile<Renderer>(
+    renderer: &mut Renderer,
+    handle: &Renderer::Handle,
+    region: Rectangle<u32>,
+    bounds: Rectangle,
+) where
+    Renderer: image::Renderer,
+{
+    let width = region.width as f32;
+    let height = region.height as f32;
+
+    renderer.with_layer(bounds, |renderer| {
+        let mut y = bounds.y;
+
+        while y < bounds.y + bounds.height {
+            let mut x = bounds.x;
+
+            while x < bounds.x + bounds.width {
+                renderer.draw_region(
+                    handle.clone(),
+                    region,
+                    Rectangle {
+                        x,
+                        y,
+                        width,
+                        height,
+                    },
+                );
+
+                x += width;
+            }
+
+            y += height;
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Recorder;
+    use crate::Size;
+
+    fn slices(
+        nine_slice: NineSlice,
+        bounds: Rectangle,
+    ) -> Vec<(Rectangle<u32>, Rectangle)> {
+        let mut renderer = Recorder::new();
+        renderer.image_size = Size::new(30, 30);
+
+        draw(&mut renderer, &(), nine_slice, bounds);
+
+        renderer
+            .images
+            .into_iter()
+            .filter_map(|image| {
+                image.region.map(|region| (region, image.bounds))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn neighboring_slices_share_their_edges() {
+        let bounds = Rectangle {
+            x: 0.3,
+            y: 0.7,
+            width: 100.1,
+            height: 50.6,
+        };
+
+        let regions = slices(NineSlice::from([10, 5, 10, 5]), bounds);
+
+        assert_eq!(regions.len(), 9);
+
+        for (i, (_, slice)) in regions.iter().enumerate() {
+            if i % 3 < 2 {
+                let (_, next) = regions[i + 1];
+
+                assert_eq!(slice.x + slice.width, next.x);
+            }
+
+            if i < 6 {
+                let (_, below) = regions[i + 3];
+
+                assert_eq!(slice.y + slice.height, below.y);
+            }
+        }
+
+        let (corner, corner_bounds) = regions[0];
+
+        assert_eq!(
+            corner,
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: 5,
+                height: 10,
+            }
+        );
+        assert_eq!(corner_bounds.size(), Size::new(5.0, 10.0));
+    }
+
+    #[test]
+    fn corners_shrink_to_fit_small_bounds() {
+        let regions = slices(
+            NineSlice::new(10),
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 10.0,
+                height: 40.0,
+            },
+        );
+
+        // Only the left and right columns remain, scaled down
+        assert!(regions
+            .iter()
+            .all(|(region, bounds)| region.width == 10 && bounds.width == 5.0));
+    }
+
+    #[test]
+    fn center_can_be_tiled() {
+        let regions = slices(
+            NineSlice::new(10).center(Center::Tile),
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 45.0,
+                height: 30.0,
+            },
+        );
+
+        let centers = regions
+            .iter()
+            .filter(|(region, _)| region.x == 10 && region.y == 10)
+            .count();
+
+        // The center is 25x10 and each tile is 10x10
+        assert_eq!(centers, 3);
+    }
+}
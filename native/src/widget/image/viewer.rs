@@ -92,7 +92,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::with_debug(State::new())
     }
 
     fn width(&self) -> Length {
@@ -124,7 +124,10 @@ where
         // Only calculate viewport sizes if the images are constrained to a limited space.
         // If they are Fill|Portion let them expand within their alotted space.
         match expansion_size {
-            Length::Shrink | Length::Units(_) => {
+            Length::Shrink
+            | Length::FitContent(_)
+            | Length::Units(_)
+            | Length::Preferred(_) => {
                 let aspect_ratio = width as f32 / height as f32;
                 let viewport_aspect_ratio = size.width / size.height;
                 if viewport_aspect_ratio > aspect_ratio {
@@ -144,18 +147,20 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         _shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
+        let cursor_position = cursor.position_over(&bounds);
 
         match event {
             Event::Mouse(mouse::Event::WheelScrolled { delta })
-                if is_mouse_over =>
+                if cursor_position.is_some() =>
             {
+                let cursor_position = cursor_position.unwrap_or_default();
+
                 match delta {
                     mouse::ScrollDelta::Lines { y, .. }
                     | mouse::ScrollDelta::Pixels { y, .. } => {
@@ -206,11 +211,11 @@ where
                 event::Status::Captured
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                if is_mouse_over =>
+                if cursor_position.is_some() =>
             {
                 let state = tree.state.downcast_mut::<State>();
 
-                state.cursor_grabbed_at = Some(cursor_position);
+                state.cursor_grabbed_at = cursor_position;
                 state.starting_offset = state.current_offset;
 
                 event::Status::Captured
@@ -277,13 +282,13 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(&bounds);
 
         if state.is_cursor_grabbed() {
             mouse::Interaction::Grabbing
@@ -301,7 +306,7 @@ where
         _theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
@@ -0,0 +1,139 @@
+//! Distribute content with a stable identity across reorders.
+//!
+//! The state of the children of a [`Column`] or a [`Row`] is matched by
+//! position. If the children are reordered, their state is mismatched—e.g.
+//! a text input may keep the cursor of a different item.
+//!
+//! The keyed counterparts of those widgets associate every child with a key
+//! and match the state of the children by key instead.
+pub mod column;
+pub mod row;
+
+#[doc(no_inline)]
+pub use column::Column;
+#[doc(no_inline)]
+pub use row::Row;
+
+use crate::widget::tree::{self, Tree};
+use crate::Element;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The local state of a keyed widget: the keys of its children in the
+/// order of their state.
+#[derive(Debug, Clone)]
+struct State<Key> {
+    keys: Vec<Key>,
+}
+
+fn tag<Key: 'static>() -> tree::Tag {
+    tree::Tag::of::<State<Key>>()
+}
+
+fn state<Key: Clone + 'static>(keys: &[Key]) -> tree::State {
+    tree::State::new(State {
+        keys: keys.to_vec(),
+    })
+}
+
+/// Reconciliates the children of the [`Tree`] with the given keyed children,
+/// matching their state by key.
+///
+/// The state of removed children is dropped, and new children get a fresh
+/// state.
+fn diff<Key, Message, Renderer>(
+    tree: &mut Tree,
+    keys: &[Key],
+    children: &[Element<'_, Message, Renderer>],
+) where
+    Key: Clone + Eq + Hash + 'static,
+    Renderer: crate::Renderer,
+{
+    debug_assert!(
+        keys.iter().collect::<HashSet<_>>().len() == keys.len(),
+        "Keyed widgets must not have duplicate keys"
+    );
+
+    let state = tree.state.downcast_mut::<State<Key>>();
+
+    if state.keys == keys {
+        tree.diff_children(children);
+        return;
+    }
+
+    let mut old: HashMap<Key, Tree> = std::mem::take(&mut state.keys)
+        .into_iter()
+        .zip(std::mem::take(&mut tree.children))
+        .collect();
+
+    tree.children = keys
+        .iter()
+        .zip(children)
+        .map(|(key, child)| match old.remove(key) {
+            Some(mut child_tree) => {
+                child_tree.diff(child);
+                child_tree
+            }
+            None => Tree::new(child),
+        })
+        .collect();
+
+    state.keys = keys.to_vec();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::{text_input, TextInput};
+
+    fn column(keys: &[u32]) -> Element<'static, (), Null> {
+        Column::with_children(keys.iter().map(|key| {
+            (*key, TextInput::new("", &key.to_string(), |_| ()).into())
+        }))
+        .into()
+    }
+
+    fn focused(tree: &Tree) -> Vec<bool> {
+        tree.children
+            .iter()
+            .map(|child| {
+                child.state.downcast_ref::<text_input::State>().is_focused()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reordering_preserves_state_by_key() {
+        let mut tree = Tree::new(&column(&[1, 2, 3]));
+
+        tree.children[1]
+            .state
+            .downcast_mut::<text_input::State>()
+            .focus();
+
+        tree.diff(&column(&[3, 2, 1]));
+        assert_eq!(focused(&tree), [false, true, false]);
+
+        tree.diff(&column(&[0, 3, 1, 2]));
+        assert_eq!(focused(&tree), [false, false, false, true]);
+
+        // Removing a child drops exactly its state
+        tree.diff(&column(&[0, 3, 1]));
+        assert_eq!(focused(&tree), [false, false, false]);
+
+        tree.diff(&column(&[2, 0, 3, 1]));
+        assert_eq!(focused(&tree), [false, false, false, false]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "duplicate keys")]
+    fn duplicate_keys_are_detected() {
+        let mut tree = Tree::new(&column(&[1, 2]));
+
+        tree.diff(&column(&[1, 1]));
+    }
+}
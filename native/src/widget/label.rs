@@ -0,0 +1,152 @@
+//! Lay out a control next to its text label.
+//!
+//! This is used by toggle-like widgets (e.g. [`Checkbox`], [`Radio`] and
+//! [`Toggler`]) so that the whole widget, label included, acts as a single
+//! click target.
+//!
+//! [`Checkbox`]: crate::widget::Checkbox
+//! [`Radio`]: crate::widget::Radio
+//! [`Toggler`]: crate::widget::Toggler
+use crate::layout;
+use crate::text;
+use crate::{Length, Point, Size};
+
+/// The side of the control where the label is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The label is placed to the left of the control.
+    Left,
+
+    /// The label is placed to the right of the control.
+    Right,
+}
+
+/// The vertical alignment of a control relative to its label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// The control is centered against the whole label.
+    Center,
+
+    /// The control is centered against the first line of the label.
+    ///
+    /// This is useful when the label wraps to multiple lines.
+    FirstLine,
+}
+
+impl Default for Anchor {
+    fn default() -> Self {
+        Self::Center
+    }
+}
+
+/// Computes the layout of a control of the given [`Size`] and an optional
+/// text label.
+///
+/// The children of the resulting [`layout::Node`] are always the control
+/// first and then the label, if any, regardless of the [`Position`].
+pub fn layout<Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    spacing: u16,
+    control: Size,
+    label: Option<(&str, u16, Renderer::Font)>,
+    position: Position,
+    anchor: Anchor,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let limits = limits.width(width).height(Length::Shrink);
+
+    let (content, text_size, font) = match label {
+        Some(label) => label,
+        None => {
+            let size = limits.resolve(control);
+
+            return layout::Node::with_children(
+                size,
+                vec![layout::Node::new(control)],
+            );
+        }
+    };
+
+    let spacing = f32::from(spacing);
+
+    let text_limits = limits
+        .shrink(Size::new(control.width + spacing, 0.0))
+        .width(width);
+
+    let (text_width, text_height) = renderer.measure(
+        content,
+        text_size,
+        text::LineHeight::default(),
+        0.0,
+        font.clone(),
+        text_limits.max(),
+    );
+
+    let text = text_limits.resolve(Size::new(text_width, text_height));
+
+    let (control_y, text_y) = match anchor {
+        Anchor::Center => {
+            let height = control.height.max(text.height);
+
+            (
+                (height - control.height) / 2.0,
+                (height - text.height) / 2.0,
+            )
+        }
+        Anchor::FirstLine => {
+            let (_, line_height) = renderer.measure(
+                content.lines().next().unwrap_or_default(),
+                text_size,
+                text::LineHeight::default(),
+                0.0,
+                font,
+                Size::INFINITY,
+            );
+
+            let line_height = if line_height > 0.0 {
+                line_height
+            } else {
+                f32::from(text_size)
+            };
+
+            let offset = (line_height - control.height) / 2.0;
+
+            if offset >= 0.0 {
+                (offset, 0.0)
+            } else {
+                (0.0, -offset)
+            }
+        }
+    };
+
+    let (control_x, text_x) = match position {
+        Position::Left => (text.width + spacing, 0.0),
+        Position::Right => (0.0, control.width + spacing),
+    };
+
+    let mut control_node = layout::Node::new(control);
+    control_node.move_to(Point::new(control_x, control_y));
+
+    let mut text_node = layout::Node::new(text);
+    text_node.move_to(Point::new(text_x, text_y));
+
+    let intrinsic = Size::new(
+        control.width + spacing + text.width,
+        (control_y + control.height).max(text_y + text.height),
+    );
+
+    layout::Node::with_children(
+        limits.resolve(intrinsic),
+        vec![control_node, text_node],
+    )
+}
+
+/// Clamps the `stroke_width` of a control of the given `size`, so the mark
+/// inside of it stays legible even at small sizes.
+pub fn stroke_width(stroke_width: f32, size: f32) -> f32 {
+    stroke_width.min(size / 6.0).max(0.0)
+}
@@ -0,0 +1,777 @@
+//! Show the menu of an application inside its window.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::menu::{Entry, Item, Menu};
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+
+/// A horizontal bar showing the items of a [`Menu`] inside a window.
+///
+/// This is the in-window counterpart of a native menu bar, for platforms
+/// where the runtime does not show the [`Menu`] natively. Clicking a submenu
+/// opens a dropdown with its items. Nested submenus are shown inline,
+/// indented under their label.
+///
+/// The [`MenuBar`] does not handle accelerators, since the runtime already
+/// does.
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    menu: Menu<Message>,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of the items of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4,
+        right: 8,
+        bottom: 4,
+        left: 8,
+    };
+
+    /// Creates a new [`MenuBar`] showing the given [`Menu`].
+    pub fn new(menu: Menu<Message>) -> Self {
+        Self {
+            menu,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Padding`] of the items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    open: Option<usize>,
+    hovered_row: Option<usize>,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuBar<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let height = f32::from(text_size) + f32::from(self.padding.vertical());
+
+        let mut x = 0.0;
+
+        let children = self
+            .menu
+            .items()
+            .iter()
+            .map(|item| {
+                let width = match label(item) {
+                    Some(label) => {
+                        renderer.measure_width(
+                            label,
+                            text_size,
+                            self.font.clone(),
+                        ) + f32::from(self.padding.horizontal())
+                    }
+                    None => 0.0,
+                };
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits
+            .width(Length::Fill)
+            .height(Length::Shrink)
+            .resolve(Size::new(x, height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+        let hovered = hovered_item(layout, cursor);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let index = match hovered {
+                    Some(index) => index,
+                    None => return event::Status::Ignored,
+                };
+
+                match &self.menu.items()[index] {
+                    Item::Submenu { .. } => {
+                        state.open = if state.open == Some(index) {
+                            None
+                        } else {
+                            Some(index)
+                        };
+                    }
+                    Item::Entry(entry) => {
+                        if entry.enabled {
+                            shell.publish(entry.on_activate.clone());
+                        }
+
+                        state.open = None;
+                    }
+                    Item::Separator => return event::Status::Ignored,
+                }
+
+                state.hovered_row = None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                // Moving across the bar switches between open submenus
+                if let (Some(_), Some(index)) = (state.open, hovered) {
+                    if matches!(self.menu.items()[index], Item::Submenu { .. })
+                        && state.open != Some(index)
+                    {
+                        state.open = Some(index);
+                        state.hovered_row = None;
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if state.open.is_some() => {
+                state.open = None;
+                state.hovered_row = None;
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match hovered_item(layout, cursor)
+            .map(|index| &self.menu.items()[index])
+        {
+            Some(Item::Submenu { .. }) => mouse::Interaction::Pointer,
+            Some(Item::Entry(entry)) if entry.enabled => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let text_size = f32::from(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.background,
+        );
+
+        for (index, (item, layout)) in
+            self.menu.items().iter().zip(layout.children()).enumerate()
+        {
+            let label = match label(item) {
+                Some(label) => label,
+                None => continue,
+            };
+
+            let bounds = layout.bounds();
+            let is_enabled = match item {
+                Item::Entry(entry) => entry.enabled,
+                _ => true,
+            };
+            let is_highlighted = is_enabled
+                && (state.open == Some(index) || cursor.is_over(&bounds));
+
+            if is_highlighted {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            let color = if is_highlighted {
+                appearance.selected_text_color
+            } else {
+                appearance.text_color
+            };
+
+            renderer.fill_text(Text {
+                content: label,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                letter_spacing: 0.0,
+                color: if is_enabled { color } else { faded(color) },
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+            });
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let index = state.open?;
+
+        let menu = match self.menu.items().get(index)? {
+            Item::Submenu { menu, .. } => menu,
+            _ => return None,
+        };
+
+        let target = layout.children().nth(index)?.bounds();
+
+        let mut rows = Vec::new();
+        collect_rows(menu, 0, &mut rows);
+
+        Some(overlay::Element::new(
+            Point::new(target.x, target.y + target.height),
+            Box::new(Dropdown {
+                state,
+                rows,
+                bar: layout.bounds(),
+                padding: self.padding,
+                text_size: self
+                    .text_size
+                    .unwrap_or_else(|| renderer.default_size()),
+                font: self.font.clone(),
+                style: self.style.clone(),
+            }),
+        ))
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuBar<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(menu_bar: MenuBar<Message, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+/// A row of the dropdown of a submenu.
+enum Row<'a, Message> {
+    Entry(&'a Entry<Message>, usize),
+    Label(&'a str, usize),
+    Separator,
+}
+
+fn collect_rows<'a, Message>(
+    menu: &'a Menu<Message>,
+    depth: usize,
+    rows: &mut Vec<Row<'a, Message>>,
+) {
+    for item in menu.items() {
+        match item {
+            Item::Entry(entry) => rows.push(Row::Entry(entry, depth)),
+            Item::Submenu { label, menu } => {
+                rows.push(Row::Label(label, depth));
+
+                collect_rows(menu, depth + 1, rows);
+            }
+            Item::Separator => rows.push(Row::Separator),
+        }
+    }
+}
+
+struct Dropdown<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    state: &'a mut State,
+    rows: Vec<Row<'a, Message>>,
+    bar: Rectangle,
+    padding: Padding,
+    text_size: u16,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Dropdown<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn hovered_row(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        layout
+            .children()
+            .position(|row| cursor.is_over(&row.bounds()))
+            .filter(|index| {
+                matches!(self.rows[*index], Row::Entry(entry, _) if entry.enabled)
+            })
+    }
+
+    fn close(&mut self) {
+        self.state.open = None;
+        self.state.hovered_row = None;
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Dropdown<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = f32::from(self.text_size);
+        let row_height = text_size + f32::from(self.padding.vertical());
+        let measure = |content: &str| -> f32 {
+            renderer.measure_width(content, self.text_size, self.font.clone())
+        };
+
+        // Rows reserve a gutter for the checkmark of checkable entries
+        let width = self
+            .rows
+            .iter()
+            .map(|row| match row {
+                Row::Entry(entry, depth) => {
+                    text_size * (*depth as f32 + 1.0)
+                        + measure(&entry.label)
+                        + entry.accelerator.map_or(0.0, |accelerator| {
+                            text_size + measure(&accelerator.to_string())
+                        })
+                }
+                Row::Label(label, depth) => {
+                    text_size * (*depth as f32 + 1.0) + measure(label)
+                }
+                Row::Separator => 0.0,
+            })
+            .fold(0.0, f32::max)
+            + f32::from(self.padding.horizontal());
+
+        let mut y = 0.0;
+
+        let children = self
+            .rows
+            .iter()
+            .map(|row| {
+                let height = match row {
+                    Row::Separator => f32::from(self.padding.vertical()),
+                    _ => row_height,
+                };
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(0.0, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        let mut node =
+            layout::Node::with_children(Size::new(width, y), children);
+
+        node.move_to(Point::new(
+            position.x.min(bounds.width - width).max(0.0),
+            position.y,
+        ));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                self.state.hovered_row = self.hovered_row(layout, cursor);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if cursor.is_over(&layout.bounds()) {
+                    if let Some(index) = self.hovered_row(layout, cursor) {
+                        if let Row::Entry(entry, _) = self.rows[index] {
+                            shell.publish(entry.on_activate.clone());
+                        }
+
+                        self.close();
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                // The bar toggles the dropdown by itself
+                if !cursor.is_over(&self.bar) {
+                    self.close();
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered_row(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let appearance = theme.appearance(&self.style);
+        let text_size = f32::from(self.text_size);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        for (index, (row, layout)) in
+            self.rows.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = layout.bounds();
+            let content = Rectangle {
+                x: bounds.x + f32::from(self.padding.left),
+                width: bounds.width - f32::from(self.padding.horizontal()),
+                ..bounds
+            };
+
+            let (entry, label, depth) = match row {
+                Row::Entry(entry, depth) => {
+                    (Some(*entry), entry.label.as_str(), *depth)
+                }
+                Row::Label(label, depth) => (None, *label, *depth),
+                Row::Separator => {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                y: bounds.center_y().floor(),
+                                height: 1.0,
+                                ..content
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        Background::Color(faded(appearance.text_color)),
+                    );
+
+                    continue;
+                }
+            };
+
+            let is_hovered = self.state.hovered_row == Some(index);
+
+            if is_hovered {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            let color = match entry {
+                _ if is_hovered => appearance.selected_text_color,
+                Some(entry) if !entry.enabled => faded(appearance.text_color),
+                Some(_) => appearance.text_color,
+                None => faded(appearance.text_color),
+            };
+
+            let text =
+                |renderer: &mut Renderer,
+                 content: &str,
+                 font: Renderer::Font,
+                 x: f32,
+                 horizontal_alignment: alignment::Horizontal| {
+                    renderer.fill_text(Text {
+                        content,
+                        bounds: Rectangle {
+                            x,
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        letter_spacing: 0.0,
+                        color,
+                        font,
+                        horizontal_alignment,
+                        vertical_alignment: alignment::Vertical::Center,
+                    });
+                };
+
+            if let Some(true) = entry.and_then(|entry| entry.checked) {
+                text(
+                    renderer,
+                    &Renderer::CHECKMARK_ICON.to_string(),
+                    Renderer::ICON_FONT,
+                    content.x,
+                    alignment::Horizontal::Left,
+                );
+            }
+
+            text(
+                renderer,
+                label,
+                self.font.clone(),
+                content.x + text_size * (depth as f32 + 1.0),
+                alignment::Horizontal::Left,
+            );
+
+            if let Some(accelerator) = entry.and_then(|entry| entry.accelerator)
+            {
+                text(
+                    renderer,
+                    &accelerator.to_string(),
+                    self.font.clone(),
+                    content.x + content.width,
+                    alignment::Horizontal::Right,
+                );
+            }
+        }
+    }
+}
+
+fn label<Message>(item: &Item<Message>) -> Option<&str> {
+    match item {
+        Item::Entry(entry) => Some(&entry.label),
+        Item::Submenu { label, .. } => Some(label),
+        Item::Separator => None,
+    }
+}
+
+fn hovered_item(layout: Layout<'_>, cursor: mouse::Cursor) -> Option<usize> {
+    layout
+        .children()
+        .position(|item| cursor.is_over(&item.bounds()))
+}
+
+fn faded(color: Color) -> Color {
+    Color {
+        a: color.a * 0.5,
+        ..color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::menu::Entry;
+    use crate::renderer::Null;
+
+    fn click(
+        menu_bar: &mut MenuBar<&'static str, Null>,
+        tree: &mut Tree,
+        node: &layout::Node,
+        index: usize,
+        messages: &mut Vec<&'static str>,
+    ) -> event::Status {
+        let bounds = node.children()[index].bounds();
+        let mut shell = Shell::new(messages);
+
+        menu_bar.on_event(
+            tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(node),
+            mouse::Cursor::Available(Point::new(
+                bounds.center_x(),
+                bounds.center_y(),
+            )),
+            &Null::new(),
+            &mut crate::clipboard::Null,
+            &mut shell,
+        )
+    }
+
+    #[test]
+    fn opens_submenus_and_activates_entries() {
+        let menu = Menu::new()
+            .submenu("File", Menu::new().entry(Entry::new("Open", "open")))
+            .entry(Entry::new("Help", "help"));
+
+        let mut menu_bar = MenuBar::<&str, Null>::new(menu);
+        let mut tree = Tree::new(&menu_bar as &dyn Widget<&str, Null>);
+
+        let node = Widget::<&str, Null>::layout(
+            &menu_bar,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        assert_eq!(
+            click(&mut menu_bar, &mut tree, &node, 0, &mut messages),
+            event::Status::Captured
+        );
+        assert_eq!(tree.state.downcast_ref::<State>().open, Some(0));
+
+        let _ = click(&mut menu_bar, &mut tree, &node, 1, &mut messages);
+        assert_eq!(tree.state.downcast_ref::<State>().open, None);
+
+        assert_eq!(messages, ["help"]);
+    }
+}
@@ -0,0 +1,266 @@
+//! Fade a widget in and out.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::{Clipboard, Element, Layout, Length, Rectangle, Shell, Widget};
+
+/// A widget that draws its content with a uniform opacity.
+///
+/// The opacity multiplies the alpha of everything the content draws—quads,
+/// text, images, and meshes alike. Nested [`Opacity`] widgets multiply their
+/// opacities.
+///
+/// The [`Opacity`] does not change the widget tree of its content, so the
+/// opacity can change on every frame without losing any state. This makes
+/// it a good fit for fade transitions; for instance, by interpolating the
+/// opacity with [`Interpolate`].
+///
+/// The content keeps reacting to events regardless of its opacity, and its
+/// overlays are drawn fully opaque.
+///
+/// [`Interpolate`]: iced_style::transition::Interpolate
+#[allow(missing_debug_implementations)]
+pub struct Opacity<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    opacity: f32,
+}
+
+impl<'a, Message, Renderer> Opacity<'a, Message, Renderer> {
+    /// Creates a new [`Opacity`] with the given content and opacity.
+    ///
+    /// The opacity is clamped between `0.0`—fully transparent—and `1.0`—fully
+    /// opaque.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        opacity: f32,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            opacity: opacity.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Opacity<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn baseline(
+        &self,
+        renderer: &Renderer,
+        layout: &layout::Node,
+    ) -> Option<f32> {
+        self.content.as_widget().baseline(renderer, layout)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content
+            .as_widget_mut()
+            .on_event(tree, event, layout, cursor, renderer, clipboard, shell)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+
+        let draw = |renderer: &mut Renderer| {
+            self.content
+                .as_widget()
+                .draw(tree, renderer, theme, style, layout, cursor, viewport);
+        };
+
+        if self.opacity >= 1.0 {
+            draw(renderer);
+        } else {
+            renderer.with_opacity(self.opacity, draw);
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Opacity<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(opacity: Opacity<'a, Message, Renderer>) -> Self {
+        Element::new(opacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Recorder;
+    use crate::{Point, Renderer as _, Size, Theme};
+
+    struct Quad;
+
+    impl<Message> Widget<Message, Recorder> for Quad {
+        fn width(&self) -> Length {
+            Length::Units(10)
+        }
+
+        fn height(&self) -> Length {
+            Length::Units(10)
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Recorder,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::new(10.0, 10.0))
+        }
+
+        fn draw(
+            &self,
+            _tree: &Tree,
+            renderer: &mut Recorder,
+            _theme: &Theme,
+            _style: &renderer::Style,
+            layout: Layout<'_>,
+            _cursor: mouse::Cursor,
+            _viewport: &Rectangle,
+        ) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: crate::Color::TRANSPARENT,
+                },
+                crate::Color::BLACK,
+            );
+        }
+    }
+
+    fn quads(element: Element<'_, (), Recorder>) -> Vec<f32> {
+        let mut renderer = Recorder::new();
+
+        let tree = Tree::new(&element);
+        let node = element.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        element.as_widget().draw(
+            &tree,
+            &mut renderer,
+            &Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &Rectangle::with_size(node.size()),
+        );
+
+        renderer.quads.iter().map(|(_, opacity)| *opacity).collect()
+    }
+
+    #[test]
+    fn nested_opacities_multiply() {
+        let faded = Opacity::new(Opacity::new(Element::new(Quad), 0.5), 0.5);
+
+        assert_eq!(quads(faded.into()), vec![0.25]);
+    }
+
+    #[test]
+    fn transparent_content_is_not_drawn() {
+        assert!(quads(Opacity::new(Element::new(Quad), 0.0).into()).is_empty());
+        assert_eq!(
+            quads(Opacity::new(Element::new(Quad), 2.0).into()),
+            vec![1.0]
+        );
+    }
+}
@@ -1,4 +1,17 @@
 //! Query or update internal widget state.
+//!
+//! An [`Operation`] traverses the widget tree of an application. Every
+//! widget calls the methods of the [`Operation`] matching its capabilities
+//! (e.g. [`Operation::focusable`] for widgets that can be focused), and
+//! widgets with children call [`Operation::container`] to keep traversing.
+//! Once the whole tree is traversed, the [`Outcome`] of the [`Operation`]
+//! can produce a message for the application.
+//!
+//! Operations can be run from `update` by returning a [`Command::widget`].
+//! The [`custom`] builder covers most queries without implementing the
+//! [`Operation`] trait by hand.
+//!
+//! [`Command::widget`]: crate::Command::widget
 pub mod focusable;
 pub mod scrollable;
 pub mod text_input;
@@ -8,6 +21,7 @@ pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
 use crate::widget::Id;
+use crate::Rectangle;
 
 use std::any::Any;
 use std::fmt;
@@ -25,6 +39,12 @@ pub trait Operation<T> {
         operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
     );
 
+    /// Operates on the bounds of a widget.
+    ///
+    /// The bounds are given in the coordinates of the layout, regardless of
+    /// the scrolling of any ancestor.
+    fn bounds(&mut self, _bounds: Rectangle, _id: Option<&Id>) {}
+
     /// Operates on a widget that can be focused.
     fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
 
@@ -37,6 +57,16 @@ pub trait Operation<T> {
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
+    /// Returns the [`Id`] of the widget the [`Operation`] is looking for, if
+    /// it looks for a single one.
+    ///
+    /// Widgets that do not lay out all of their children—like virtualized
+    /// lists—can use it to report the estimated bounds of a child that is
+    /// not laid out with [`Operation::bounds`].
+    fn target(&self) -> Option<&Id> {
+        None
+    }
+
     /// Finishes the [`Operation`] and returns its [`Outcome`].
     fn finish(&self) -> Outcome<T> {
         Outcome::None
@@ -92,6 +122,10 @@ pub fn scoped<T: 'static>(
             }
         }
 
+        fn target(&self) -> Option<&Id> {
+            self.operation.target()
+        }
+
         fn finish(&self) -> Outcome<Message> {
             match self.operation.finish() {
                 Outcome::Chain(next) => {
@@ -110,3 +144,237 @@ pub fn scoped<T: 'static>(
         operation: Box::new(operation),
     }
 }
+
+/// Creates a [`Custom`] builder of an [`Operation`] that visits every widget,
+/// accumulating the given `state`.
+///
+/// The resulting [`Operation`] produces a single message out of the final
+/// state, no matter how many widgets it visited.
+///
+/// # Example
+/// An [`Operation`] that collects the [`Id`] of every focusable widget:
+///
+/// ```
+/// use iced_native::widget::{operation, Id};
+/// use iced_native::Command;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     FocusableIds(Vec<Id>),
+/// }
+///
+/// fn focusable_ids() -> Command<Message> {
+///     Command::widget(
+///         operation::custom(Vec::new())
+///             .focusable(|ids, _state, id| ids.extend(id.cloned()))
+///             .finish(|ids| Message::FocusableIds(ids.clone())),
+///     )
+/// }
+/// ```
+pub fn custom<S>(state: S) -> Custom<S> {
+    Custom {
+        state,
+        bounds: None,
+        focusable: None,
+        scrollable: None,
+        text_input: None,
+        custom: None,
+    }
+}
+
+/// A builder of an [`Operation`] that visits every widget of a tree,
+/// accumulating some state.
+///
+/// It can be created with [`custom`].
+#[allow(missing_debug_implementations)]
+pub struct Custom<S> {
+    state: S,
+    bounds: Option<Box<dyn FnMut(&mut S, Rectangle, Option<&Id>)>>,
+    focusable: Option<Box<dyn FnMut(&mut S, &mut dyn Focusable, Option<&Id>)>>,
+    scrollable:
+        Option<Box<dyn FnMut(&mut S, &mut dyn Scrollable, Option<&Id>)>>,
+    text_input: Option<Box<dyn FnMut(&mut S, &mut dyn TextInput, Option<&Id>)>>,
+    custom: Option<Box<dyn FnMut(&mut S, &mut dyn Any, Option<&Id>)>>,
+}
+
+impl<S: 'static> Custom<S> {
+    /// Sets the function called with the bounds of every widget that
+    /// reports them.
+    pub fn bounds(
+        mut self,
+        f: impl FnMut(&mut S, Rectangle, Option<&Id>) + 'static,
+    ) -> Self {
+        self.bounds = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function called for every widget that can be focused.
+    pub fn focusable(
+        mut self,
+        f: impl FnMut(&mut S, &mut dyn Focusable, Option<&Id>) + 'static,
+    ) -> Self {
+        self.focusable = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function called for every widget that can be scrolled.
+    pub fn scrollable(
+        mut self,
+        f: impl FnMut(&mut S, &mut dyn Scrollable, Option<&Id>) + 'static,
+    ) -> Self {
+        self.scrollable = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function called for every widget that has text input.
+    pub fn text_input(
+        mut self,
+        f: impl FnMut(&mut S, &mut dyn TextInput, Option<&Id>) + 'static,
+    ) -> Self {
+        self.text_input = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the function called for every custom widget with some state.
+    pub fn custom(
+        mut self,
+        f: impl FnMut(&mut S, &mut dyn Any, Option<&Id>) + 'static,
+    ) -> Self {
+        self.custom = Some(Box::new(f));
+        self
+    }
+
+    /// Finishes the [`Custom`] builder, producing an [`Operation`] that
+    /// turns its final state into a message with the given function.
+    pub fn finish<T>(self, f: impl Fn(&S) -> T + 'static) -> impl Operation<T> {
+        struct Finish<S, T> {
+            custom: Custom<S>,
+            finish: Box<dyn Fn(&S) -> T>,
+        }
+
+        impl<S, T> Operation<T> for Finish<S, T> {
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+            ) {
+                operate_on_children(self)
+            }
+
+            fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+                if let Some(f) = &mut self.custom.bounds {
+                    f(&mut self.custom.state, bounds, id);
+                }
+            }
+
+            fn focusable(
+                &mut self,
+                state: &mut dyn Focusable,
+                id: Option<&Id>,
+            ) {
+                if let Some(f) = &mut self.custom.focusable {
+                    f(&mut self.custom.state, state, id);
+                }
+            }
+
+            fn scrollable(
+                &mut self,
+                state: &mut dyn Scrollable,
+                id: Option<&Id>,
+            ) {
+                if let Some(f) = &mut self.custom.scrollable {
+                    f(&mut self.custom.state, state, id);
+                }
+            }
+
+            fn text_input(
+                &mut self,
+                state: &mut dyn TextInput,
+                id: Option<&Id>,
+            ) {
+                if let Some(f) = &mut self.custom.text_input {
+                    f(&mut self.custom.state, state, id);
+                }
+            }
+
+            fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+                if let Some(f) = &mut self.custom.custom {
+                    f(&mut self.custom.state, state, id);
+                }
+            }
+
+            fn finish(&self) -> Outcome<T> {
+                Outcome::Some((self.finish)(&self.custom.state))
+            }
+        }
+
+        Finish {
+            custom: self,
+            finish: Box::new(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::{text_input, Column, Scrollable, TextInput, Tree};
+    use crate::{layout, Element, Layout, Size};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed,
+        Focusable(Vec<Id>, usize),
+    }
+
+    #[test]
+    fn custom_aggregates_every_match() {
+        let first = text_input::Id::new("first");
+        let second = text_input::Id::new("second");
+
+        let content: Element<'_, Message, Null> = Scrollable::new(
+            Column::new()
+                .push(
+                    TextInput::new("", "", |_| Message::Changed)
+                        .id(first.clone()),
+                )
+                .push(TextInput::new("", "", |_| Message::Changed))
+                .push(
+                    TextInput::new("", "", |_| Message::Changed)
+                        .id(second.clone()),
+                ),
+        )
+        .into();
+
+        let renderer = Null::new();
+        let mut tree = Tree::new(&content);
+        let node = content.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let mut operation = custom((Vec::new(), 0))
+            .focusable(|(ids, _), _state, id| ids.extend(id.cloned()))
+            .text_input(|(_, count), _state, _id| *count += 1)
+            .finish(|(ids, count): &(Vec<Id>, usize)| {
+                Message::Focusable(ids.clone(), *count)
+            });
+
+        content.as_widget().operate(
+            &mut tree,
+            Layout::new(&node),
+            &renderer,
+            &mut operation,
+        );
+
+        match operation.finish() {
+            Outcome::Some(message) => assert_eq!(
+                message,
+                Message::Focusable(vec![first.into(), second.into()], 3)
+            ),
+            outcome => panic!("Unexpected outcome: {outcome:?}"),
+        }
+    }
+}
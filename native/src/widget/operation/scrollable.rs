@@ -1,10 +1,58 @@
 //! Operate on widgets that can be scrolled.
 use crate::widget::{Id, Operation};
+use crate::Rectangle;
 
 /// The internal state of a widget that can be scrolled.
 pub trait Scrollable {
     /// Snaps the scroll of the widget to the given `percentage` along the horizontal & vertical axis.
     fn snap_to(&mut self, offset: RelativeOffset);
+
+    /// Scrolls the widget by the least amount that makes the given `bounds`
+    /// visible, keeping a `margin` around them when possible. Nothing is
+    /// scrolled if the `bounds` are already visible.
+    ///
+    /// The `bounds` are given in the coordinates of the layout of the widget,
+    /// regardless of its scrolling. It returns the visible part of the
+    /// `bounds` afterwards, as seen by the parent of the widget.
+    ///
+    /// By default, it does not scroll and returns the `bounds` unchanged.
+    fn scroll_into_view(
+        &mut self,
+        bounds: Rectangle,
+        _margin: f32,
+    ) -> Rectangle {
+        bounds
+    }
+
+    /// Scrolls the widget to reveal the given `bounds`, placing them in the
+    /// viewport as the given [`Anchor`] describes.
+    ///
+    /// The `bounds` are given in the coordinates of the layout of the widget,
+    /// regardless of its scrolling.
+    ///
+    /// By default, it does not scroll.
+    fn scroll_to_bounds(&mut self, _bounds: Rectangle, _anchor: Anchor) {}
+}
+
+/// The place of the bounds of a widget in the viewport of a [`Scrollable`]
+/// after scrolling to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anchor {
+    /// The bounds are aligned to the top-left of the viewport.
+    Start,
+
+    /// The bounds are centered in the viewport.
+    Center,
+
+    /// The bounds are scrolled by the least amount that makes them
+    /// visible. Nothing is scrolled if they are already visible.
+    Nearest,
+}
+
+impl Default for Anchor {
+    fn default() -> Self {
+        Self::Nearest
+    }
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -34,6 +82,151 @@ pub fn snap_to<T>(target: Id, offset: RelativeOffset) -> impl Operation<T> {
     SnapTo { target, offset }
 }
 
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`]
+/// into view, keeping a `margin` around it when possible.
+///
+/// Every [`Scrollable`] ancestor of the widget is scrolled, from the innermost
+/// to the outermost, by the least amount that makes the widget visible.
+/// Nothing is scrolled if the widget is already visible.
+pub fn scroll_to_widget<T>(target: Id, margin: f32) -> impl Operation<T> {
+    struct ScrollToWidget {
+        target: Id,
+        margin: f32,
+        bounds: Option<Rectangle>,
+        contains_target: bool,
+    }
+
+    impl<T> Operation<T> for ScrollToWidget {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            let was_found = self.bounds.is_some();
+
+            operate_on_children(self);
+
+            // Scrollables operate on themselves right after their contents
+            self.contains_target = !was_found && self.bounds.is_some();
+        }
+
+        fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+            if self.bounds.is_none() && Some(&self.target) == id {
+                self.bounds = Some(bounds);
+            }
+        }
+
+        fn scrollable(&mut self, state: &mut dyn Scrollable, _id: Option<&Id>) {
+            if !self.contains_target {
+                return;
+            }
+
+            self.contains_target = false;
+
+            if let Some(bounds) = self.bounds {
+                self.bounds = Some(state.scroll_into_view(bounds, self.margin));
+            }
+        }
+
+        fn target(&self) -> Option<&Id> {
+            Some(&self.target)
+        }
+    }
+
+    ScrollToWidget {
+        target,
+        margin,
+        bounds: None,
+        contains_target: false,
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the [`Scrollable`] with the given
+/// [`Id`] to reveal the widget with the `item` [`Id`] inside of it, placing
+/// it as the given [`Anchor`] describes.
+///
+/// Only the `scrollable` is scrolled. The bounds of the `item` are the ones
+/// reported with [`Operation::bounds`] by the first widget with its [`Id`]
+/// inside the `scrollable`.
+///
+/// # Unknown bounds
+/// Nothing is scrolled if no widget reports the bounds of the `item`; for
+/// instance, when it is not part of the widget tree. Widgets that do not lay
+/// out all of their children—like virtualized lists—can report estimated
+/// bounds for the [`Operation::target`] instead. The `scrollable` is then
+/// scrolled to the estimate, which may be off until the `item` is laid out
+/// and the operation runs again.
+pub fn scroll_to_item<T>(
+    scrollable: Id,
+    item: Id,
+    anchor: Anchor,
+) -> impl Operation<T> {
+    struct ScrollToItem {
+        scrollable: Id,
+        item: Id,
+        anchor: Anchor,
+        bounds: Option<Rectangle>,
+        contained_bounds: Option<Rectangle>,
+        is_done: bool,
+    }
+
+    impl<T> Operation<T> for ScrollToItem {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            if self.is_done {
+                return;
+            }
+
+            let previous = self.bounds.take();
+
+            operate_on_children(self);
+
+            // Scrollables operate on themselves right after their contents
+            self.contained_bounds = self.bounds;
+
+            if previous.is_some() {
+                self.bounds = previous;
+            }
+        }
+
+        fn bounds(&mut self, bounds: Rectangle, id: Option<&Id>) {
+            if self.bounds.is_none() && Some(&self.item) == id {
+                self.bounds = Some(bounds);
+            }
+        }
+
+        fn scrollable(&mut self, state: &mut dyn Scrollable, id: Option<&Id>) {
+            let contained_bounds = self.contained_bounds.take();
+
+            if self.is_done || Some(&self.scrollable) != id {
+                return;
+            }
+
+            self.is_done = true;
+
+            if let Some(bounds) = contained_bounds {
+                state.scroll_to_bounds(bounds, self.anchor);
+            }
+        }
+
+        fn target(&self) -> Option<&Id> {
+            Some(&self.item)
+        }
+    }
+
+    ScrollToItem {
+        scrollable,
+        item,
+        anchor,
+        bounds: None,
+        contained_bounds: None,
+        is_done: false,
+    }
+}
+
 /// The amount of offset in each direction of a [`Scrollable`].
 ///
 /// A value of `0.0` means start, while `1.0` means end.
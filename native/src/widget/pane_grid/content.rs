@@ -95,7 +95,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         use container::StyleSheet;
@@ -113,7 +113,7 @@ where
             let title_bar_layout = children.next().unwrap();
             let body_layout = children.next().unwrap();
 
-            let show_controls = bounds.contains(cursor_position);
+            let show_controls = cursor.is_over(&bounds);
 
             self.body.as_widget().draw(
                 &tree.children[0],
@@ -121,7 +121,7 @@ where
                 theme,
                 style,
                 body_layout,
-                cursor_position,
+                cursor,
                 viewport,
             );
 
@@ -131,7 +131,7 @@ where
                 theme,
                 style,
                 title_bar_layout,
-                cursor_position,
+                cursor,
                 viewport,
                 show_controls,
             );
@@ -142,7 +142,7 @@ where
                 theme,
                 style,
                 layout,
-                cursor_position,
+                cursor,
                 viewport,
             );
         }
@@ -218,7 +218,7 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -229,15 +229,17 @@ where
         let body_layout = if let Some(title_bar) = &mut self.title_bar {
             let mut children = layout.children();
 
-            event_status = title_bar.on_event(
-                &mut tree.children[1],
-                event.clone(),
-                children.next().unwrap(),
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
-            );
+            event_status = shell.traced(1, |shell| {
+                title_bar.on_event(
+                    &mut tree.children[1],
+                    event.clone(),
+                    children.next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            });
 
             children.next().unwrap()
         } else {
@@ -247,15 +249,17 @@ where
         let body_status = if is_picked {
             event::Status::Ignored
         } else {
-            self.body.as_widget_mut().on_event(
-                &mut tree.children[0],
-                event,
-                body_layout,
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
-            )
+            shell.traced(0, |shell| {
+                self.body.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    body_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
         };
 
         event_status.merge(body_status)
@@ -265,7 +269,7 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
         drag_enabled: bool,
@@ -275,8 +279,8 @@ where
                 let mut children = layout.children();
                 let title_bar_layout = children.next().unwrap();
 
-                let is_over_pick_area = title_bar
-                    .is_over_pick_area(title_bar_layout, cursor_position);
+                let is_over_pick_area =
+                    title_bar.is_over_pick_area(title_bar_layout, cursor);
 
                 if is_over_pick_area && drag_enabled {
                     return mouse::Interaction::Grab;
@@ -285,7 +289,7 @@ where
                 let mouse_interaction = title_bar.mouse_interaction(
                     &tree.children[1],
                     title_bar_layout,
-                    cursor_position,
+                    cursor,
                     viewport,
                     renderer,
                 );
@@ -300,7 +304,7 @@ where
             .mouse_interaction(
                 &tree.children[0],
                 body_layout,
-                cursor_position,
+                cursor,
                 viewport,
                 renderer,
             )
@@ -354,7 +358,10 @@ where
             let mut children = layout.children();
             let title_bar_layout = children.next().unwrap();
 
-            title_bar.is_over_pick_area(title_bar_layout, cursor_position)
+            title_bar.is_over_pick_area(
+                title_bar_layout,
+                mouse::Cursor::Available(cursor_position),
+            )
         } else {
             false
         }
@@ -122,7 +122,7 @@ where
         theme: &Renderer::Theme,
         inherited_style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         show_controls: bool,
     ) {
@@ -158,7 +158,7 @@ where
                     theme,
                     &inherited_style,
                     controls_layout,
-                    cursor_position,
+                    cursor,
                     viewport,
                 );
             }
@@ -171,7 +171,7 @@ where
                 theme,
                 &inherited_style,
                 title_layout,
-                cursor_position,
+                cursor,
                 viewport,
             );
         }
@@ -184,9 +184,9 @@ where
     pub fn is_over_pick_area(
         &self,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> bool {
-        if layout.bounds().contains(cursor_position) {
+        if cursor.is_over(&layout.bounds()) {
             let mut children = layout.children();
             let padded = children.next().unwrap();
             let mut children = padded.children();
@@ -198,13 +198,13 @@ where
                 if title_layout.bounds().width + controls_layout.bounds().width
                     > padded.bounds().width
                 {
-                    !controls_layout.bounds().contains(cursor_position)
+                    !cursor.is_over(&controls_layout.bounds())
                 } else {
-                    !controls_layout.bounds().contains(cursor_position)
-                        && !title_layout.bounds().contains(cursor_position)
+                    !cursor.is_over(&controls_layout.bounds())
+                        && !cursor.is_over(&title_layout.bounds())
                 }
             } else {
-                !title_layout.bounds().contains(cursor_position)
+                !cursor.is_over(&title_layout.bounds())
             }
         } else {
             false
@@ -303,7 +303,7 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -323,29 +323,33 @@ where
                 show_title = false;
             }
 
-            controls.as_widget_mut().on_event(
-                &mut tree.children[1],
-                event.clone(),
-                controls_layout,
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
-            )
+            shell.traced(1, |shell| {
+                controls.as_widget_mut().on_event(
+                    &mut tree.children[1],
+                    event.clone(),
+                    controls_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
         } else {
             event::Status::Ignored
         };
 
         let title_status = if show_title {
-            self.content.as_widget_mut().on_event(
-                &mut tree.children[0],
-                event,
-                title_layout,
-                cursor_position,
-                renderer,
-                clipboard,
-                shell,
-            )
+            shell.traced(0, |shell| {
+                self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event,
+                    title_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
         } else {
             event::Status::Ignored
         };
@@ -357,7 +361,7 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
@@ -370,7 +374,7 @@ where
         let title_interaction = self.content.as_widget().mouse_interaction(
             &tree.children[0],
             title_layout,
-            cursor_position,
+            cursor,
             viewport,
             renderer,
         );
@@ -380,7 +384,7 @@ where
             let controls_interaction = controls.as_widget().mouse_interaction(
                 &tree.children[1],
                 controls_layout,
-                cursor_position,
+                cursor,
                 viewport,
                 renderer,
             );
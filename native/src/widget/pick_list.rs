@@ -8,19 +8,41 @@ use crate::overlay;
 use crate::overlay::menu::{self, Menu};
 use crate::renderer;
 use crate::text::{self, Text};
+use crate::time::{Duration, Instant};
 use crate::touch;
 use crate::widget::container;
+use crate::widget::operation::{self, Operation};
 use crate::widget::scrollable;
 use crate::widget::tree::{self, Tree};
 use crate::{
-    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size,
-    Widget,
+    Clipboard, Element, Layout, Length, Padding, Rectangle, Shell, Size, Widget,
 };
 use std::borrow::Cow;
 
 pub use iced_style::pick_list::{Appearance, StyleSheet};
 
 /// A widget for selecting a single value from a list of options.
+///
+/// A focused [`PickList`] can be operated with the keyboard:
+///
+/// - <kbd>Space</kbd> or <kbd>Enter</kbd> opens the list of options.
+/// - <kbd>↑</kbd> and <kbd>↓</kbd> move the highlighted option, wrapping
+///   around the ends of the list.
+/// - <kbd>Enter</kbd> selects the highlighted option and <kbd>Escape</kbd>
+///   closes the list.
+/// - Typing jumps to the next option starting with the typed letters. The
+///   typed letters are forgotten after a short pause.
+///
+/// The options can be split into labeled groups with
+/// [`PickList::with_groups`]. The headers of the groups are never hovered,
+/// selected, nor matched by the typed letters.
+///
+/// Keyboard events are only handled while the [`PickList`] is focused, so they
+/// never reach more than one [`PickList`] at a time.
+///
+/// A [`PickList`] can be [`disabled`], in which case it ignores every event.
+///
+/// [`disabled`]: PickList::disabled
 #[allow(missing_debug_implementations)]
 pub struct PickList<'a, T, Message, Renderer>
 where
@@ -30,8 +52,10 @@ where
 {
     on_selected: Box<dyn Fn(T) -> Message + 'a>,
     options: Cow<'a, [T]>,
+    headers: Vec<menu::Header>,
     placeholder: Option<String>,
     selected: Option<T>,
+    is_disabled: bool,
     width: Length,
     padding: Padding,
     text_size: Option<u16>,
@@ -65,8 +89,10 @@ where
         Self {
             on_selected: Box::new(on_selected),
             options: options.into(),
+            headers: Vec::new(),
             placeholder: None,
             selected,
+            is_disabled: false,
             width: Length::Shrink,
             padding: Self::DEFAULT_PADDING,
             text_size: None,
@@ -76,12 +102,58 @@ where
         }
     }
 
+    /// Creates a new [`PickList`] with the given groups of options, the
+    /// current selected value, and the message to produce when an option is
+    /// selected.
+    ///
+    /// The label of every [`Group`] is shown as a header before its options
+    /// in the list, while the [`PickList`] itself only shows the selected
+    /// option. Groups without options are hidden.
+    pub fn with_groups(
+        groups: impl IntoIterator<Item = Group<T>>,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut options = Vec::new();
+        let mut headers = Vec::new();
+
+        for group in groups {
+            if group.options.is_empty() {
+                continue;
+            }
+
+            headers.push(menu::Header {
+                label: group.label,
+                index: options.len(),
+            });
+
+            options.extend(group.options);
+        }
+
+        Self {
+            headers,
+            ..Self::new(options, selected, on_selected)
+        }
+    }
+
     /// Sets the placeholder of the [`PickList`].
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = Some(placeholder.into());
         self
     }
 
+    /// Sets whether the [`PickList`] is disabled.
+    ///
+    /// A disabled [`PickList`] cannot be opened, focused, or scrolled through,
+    /// and it is drawn with its disabled appearance.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -157,7 +229,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
+        layout_with_headers(
             renderer,
             limits,
             self.width,
@@ -166,23 +238,42 @@ where
             &self.font,
             self.placeholder.as_deref(),
             &self.options,
+            &self.headers,
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        operation.focusable(state, None);
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if self.is_disabled {
+            tree.state.downcast_mut::<State<T>>().unfocus();
+
+            return event::Status::Ignored;
+        }
+
         update(
             event,
             layout,
-            cursor_position,
+            cursor,
             shell,
             self.on_selected.as_ref(),
             self.selected.as_ref(),
@@ -195,11 +286,15 @@ where
         &self,
         _tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(layout, cursor_position)
+        if self.is_disabled {
+            mouse::Interaction::default()
+        } else {
+            mouse_interaction(layout, cursor)
+        }
     }
 
     fn draw(
@@ -209,14 +304,15 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        draw(
+        draw_with_status(
             renderer,
             theme,
             layout,
-            cursor_position,
+            cursor,
+            self.is_disabled,
             self.padding,
             self.text_size,
             &self.font,
@@ -234,15 +330,20 @@ where
         layout: Layout<'_>,
         _renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        if self.is_disabled {
+            return None;
+        }
+
         let state = tree.state.downcast_mut::<State<T>>();
 
-        overlay(
+        overlay_with_headers(
             layout,
             state,
             self.padding,
             self.text_size,
             self.font.clone(),
             &self.options,
+            &self.headers,
             self.style.clone(),
         )
     }
@@ -267,14 +368,39 @@ where
     }
 }
 
+/// A labeled group of options of a [`PickList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group<T> {
+    /// The label of the [`Group`], shown as a header before its options.
+    pub label: String,
+
+    /// The options of the [`Group`].
+    pub options: Vec<T>,
+}
+
+impl<T> Group<T> {
+    /// Creates a new [`Group`] with the given label and options.
+    pub fn new(
+        label: impl Into<String>,
+        options: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            options: options.into_iter().collect(),
+        }
+    }
+}
+
 /// The local state of a [`PickList`].
 #[derive(Debug)]
 pub struct State<T> {
     menu: menu::State,
     keyboard_modifiers: keyboard::Modifiers,
+    is_focused: bool,
     is_open: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
+    type_ahead: TypeAhead,
 }
 
 impl<T> State<T> {
@@ -283,11 +409,29 @@ impl<T> State<T> {
         Self {
             menu: menu::State::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            is_focused: bool::default(),
             is_open: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
+            type_ahead: TypeAhead::default(),
         }
     }
+
+    /// Returns whether the [`PickList`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`PickList`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`PickList`], closing its list of options.
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.is_open = false;
+    }
 }
 
 impl<T> Default for State<T> {
@@ -296,6 +440,47 @@ impl<T> Default for State<T> {
     }
 }
 
+impl<T> operation::Focusable for State<T> {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+/// The letters typed in quick succession on a focused [`PickList`].
+#[derive(Debug, Default)]
+struct TypeAhead {
+    query: String,
+    updated_at: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// The pause after which the typed letters are forgotten.
+    const TIMEOUT: Duration = Duration::from_millis(1000);
+
+    fn is_typing(&self, now: Instant) -> bool {
+        self.updated_at
+            .map(|updated_at| now.duration_since(updated_at) < Self::TIMEOUT)
+            .unwrap_or(false)
+    }
+
+    fn push(&mut self, c: char, now: Instant) {
+        if !self.is_typing(now) {
+            self.query.clear();
+        }
+
+        self.query.extend(c.to_lowercase());
+        self.updated_at = Some(now);
+    }
+}
+
 /// The handle to the right side of the [`PickList`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Handle<Font> {
@@ -347,6 +532,35 @@ pub fn layout<Renderer, T>(
     placeholder: Option<&str>,
     options: &[T],
 ) -> layout::Node
+where
+    Renderer: text::Renderer,
+    T: ToString,
+{
+    layout_with_headers(
+        renderer,
+        limits,
+        width,
+        padding,
+        text_size,
+        font,
+        placeholder,
+        options,
+        &[],
+    )
+}
+
+/// Computes the layout of a [`PickList`] whose options may have headers.
+fn layout_with_headers<Renderer, T>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: &Renderer::Font,
+    placeholder: Option<&str>,
+    options: &[T],
+    headers: &[menu::Header],
+) -> layout::Node
 where
     Renderer: text::Renderer,
     T: ToString,
@@ -358,11 +572,13 @@ where
     let text_size = text_size.unwrap_or_else(|| renderer.default_size());
 
     let max_width = match width {
-        Length::Shrink => {
+        Length::Shrink | Length::FitContent(_) => {
             let measure = |label: &str| -> u32 {
                 let (width, _) = renderer.measure(
                     label,
                     text_size,
+                    text::LineHeight::default(),
+                    0.0,
                     font.clone(),
                     Size::new(f32::INFINITY, f32::INFINITY),
                 );
@@ -370,7 +586,10 @@ where
                 width.round() as u32
             };
 
-            let labels = options.iter().map(ToString::to_string);
+            let labels = options
+                .iter()
+                .map(ToString::to_string)
+                .chain(headers.iter().map(|header| header.label.clone()));
 
             let labels_width =
                 labels.map(|label| measure(&label)).max().unwrap_or(100);
@@ -399,7 +618,7 @@ where
 pub fn update<'a, T, Message>(
     event: Event,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     shell: &mut Shell<'_, Message>,
     on_selected: &dyn Fn(T) -> Message,
     selected: Option<&T>,
@@ -407,20 +626,26 @@ pub fn update<'a, T, Message>(
     state: impl FnOnce() -> &'a mut State<T>,
 ) -> event::Status
 where
-    T: PartialEq + Clone + 'a,
+    T: PartialEq + Clone + ToString + 'a,
 {
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
 
+            // Clicks on the open menu are handled by its overlay, so the
+            // focus is kept while the menu is open
+            if !state.is_open {
+                state.is_focused = cursor.is_over(&layout.bounds());
+            }
+
             let event_status = if state.is_open {
                 // Event wasn't processed by overlay, so cursor was clicked either outside it's
                 // bounds or on the drop-down, either way we close the overlay.
                 state.is_open = false;
 
                 event::Status::Captured
-            } else if layout.bounds().contains(cursor_position) {
+            } else if cursor.is_over(&layout.bounds()) {
                 state.is_open = true;
                 state.hovered_option =
                     options.iter().position(|option| Some(option) == selected);
@@ -440,13 +665,25 @@ where
                 event_status
             }
         }
+        Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+            let state = state();
+
+            // Other buttons only dismiss the menu
+            if state.is_open {
+                state.is_open = false;
+
+                event::Status::Captured
+            } else {
+                event::Status::Ignored
+            }
+        }
         Event::Mouse(mouse::Event::WheelScrolled {
             delta: mouse::ScrollDelta::Lines { y, .. },
         }) => {
             let state = state();
 
             if state.keyboard_modifiers.command()
-                && layout.bounds().contains(cursor_position)
+                && cursor.is_over(&layout.bounds())
                 && !state.is_open
             {
                 fn find_next<'a, T: PartialEq>(
@@ -483,6 +720,87 @@ where
                 event::Status::Ignored
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
+            let state = state();
+
+            if !state.is_focused {
+                return event::Status::Ignored;
+            }
+
+            match key_code {
+                // A space may be part of the letters being typed
+                keyboard::KeyCode::Space
+                    if state.type_ahead.is_typing(Instant::now()) => {}
+                keyboard::KeyCode::Space
+                | keyboard::KeyCode::Enter
+                | keyboard::KeyCode::NumpadEnter => {
+                    if state.is_open {
+                        if let Some(option) = state
+                            .hovered_option
+                            .and_then(|index| options.get(index))
+                        {
+                            shell.publish((on_selected)(option.clone()));
+                        }
+
+                        state.is_open = false;
+                    } else {
+                        state.is_open = true;
+                        state.hovered_option = options
+                            .iter()
+                            .position(|option| Some(option) == selected);
+                    }
+                }
+                keyboard::KeyCode::Escape if state.is_open => {
+                    state.is_open = false;
+                }
+                keyboard::KeyCode::Up | keyboard::KeyCode::Down
+                    if state.is_open =>
+                {
+                    state.hovered_option = step(
+                        state.hovered_option,
+                        options.len(),
+                        key_code == keyboard::KeyCode::Down,
+                    );
+                }
+                _ => return event::Status::Ignored,
+            }
+
+            event::Status::Captured
+        }
+        Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+            let state = state();
+
+            if !state.is_focused || c.is_control() {
+                return event::Status::Ignored;
+            }
+
+            let now = Instant::now();
+
+            // A space that is not part of the typed letters opens the menu
+            if c == ' ' && !state.type_ahead.is_typing(now) {
+                return event::Status::Captured;
+            }
+
+            state.type_ahead.push(c, now);
+
+            let current = if state.is_open {
+                state.hovered_option
+            } else {
+                options.iter().position(|option| Some(option) == selected)
+            };
+
+            if let Some(index) =
+                type_ahead(options, &state.type_ahead.query, current)
+            {
+                if state.is_open {
+                    state.hovered_option = Some(index);
+                } else if Some(index) != current {
+                    shell.publish((on_selected)(options[index].clone()));
+                }
+            }
+
+            event::Status::Captured
+        }
         Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
             let state = state();
 
@@ -494,13 +812,72 @@ where
     }
 }
 
+/// Moves the `current` option of a list of `len` options one step forward
+/// or backward, wrapping around its ends.
+pub(crate) fn step(
+    current: Option<usize>,
+    len: usize,
+    forward: bool,
+) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+
+    Some(match current.map(|index| index.min(len - 1)) {
+        Some(index) if forward => (index + 1) % len,
+        Some(index) => (index + len - 1) % len,
+        None if forward => 0,
+        None => len - 1,
+    })
+}
+
+/// Finds the option matching the lowercase letters typed in a [`PickList`].
+///
+/// A single letter jumps to the next option starting with it, after the
+/// `current` one. Longer sequences keep the `current` option if it still
+/// matches. In both cases, the search wraps around the ends of the list.
+///
+/// Repeating the same letter cycles through the options starting with it,
+/// unless some option starts with the repeated sequence itself.
+fn type_ahead<T: ToString>(
+    options: &[T],
+    query: &str,
+    current: Option<usize>,
+) -> Option<usize> {
+    let find = |query: &str, skip_current: bool| {
+        let start = match current {
+            Some(index) if skip_current => index + 1,
+            Some(index) => index,
+            None => 0,
+        };
+
+        (0..options.len())
+            .map(|offset| (start + offset) % options.len())
+            .find(|&index| {
+                options[index].to_string().to_lowercase().starts_with(query)
+            })
+    };
+
+    let mut chars = query.chars();
+    let first = chars.next()?;
+    let is_single = query.len() == first.len_utf8();
+
+    find(query, is_single).or_else(|| {
+        if chars.all(|c| c == first) {
+            find(&query[..first.len_utf8()], true)
+        } else {
+            None
+        }
+    })
+}
+
 /// Returns the current [`mouse::Interaction`] of a [`PickList`].
 pub fn mouse_interaction(
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
 ) -> mouse::Interaction {
     let bounds = layout.bounds();
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&bounds);
 
     if is_mouse_over {
         mouse::Interaction::Pointer
@@ -519,6 +896,41 @@ pub fn overlay<'a, T, Message, Renderer>(
     options: &'a [T],
     style: <Renderer::Theme as StyleSheet>::Style,
 ) -> Option<overlay::Element<'a, Message, Renderer>>
+where
+    T: Clone + ToString,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet
+        + scrollable::StyleSheet
+        + menu::StyleSheet
+        + container::StyleSheet,
+    <Renderer::Theme as menu::StyleSheet>::Style:
+        From<<Renderer::Theme as StyleSheet>::Style>,
+{
+    overlay_with_headers(
+        layout,
+        state,
+        padding,
+        text_size,
+        font,
+        options,
+        &[],
+        style,
+    )
+}
+
+/// Returns the current overlay of a [`PickList`] whose options may have
+/// headers.
+fn overlay_with_headers<'a, T, Message, Renderer>(
+    layout: Layout<'_>,
+    state: &'a mut State<T>,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    options: &'a [T],
+    headers: &'a [menu::Header],
+    style: <Renderer::Theme as StyleSheet>::Style,
+) -> Option<overlay::Element<'a, Message, Renderer>>
 where
     T: Clone + ToString,
     Message: 'a,
@@ -539,6 +951,7 @@ where
             &mut state.hovered_option,
             &mut state.last_selection,
         )
+        .headers(headers)
         .width(bounds.width.round() as u16)
         .padding(padding)
         .font(font)
@@ -559,7 +972,44 @@ pub fn draw<'a, T, Renderer>(
     renderer: &mut Renderer,
     theme: &Renderer::Theme,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: &Renderer::Font,
+    placeholder: Option<&str>,
+    selected: Option<&T>,
+    handle: &Handle<Renderer::Font>,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+    state: impl FnOnce() -> &'a State<T>,
+) where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+    T: ToString + 'a,
+{
+    draw_with_status(
+        renderer,
+        theme,
+        layout,
+        cursor,
+        false,
+        padding,
+        text_size,
+        font,
+        placeholder,
+        selected,
+        handle,
+        style,
+        state,
+    )
+}
+
+/// Draws a [`PickList`] that may be disabled.
+fn draw_with_status<'a, T, Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    is_disabled: bool,
     padding: Padding,
     text_size: Option<u16>,
     font: &Renderer::Font,
@@ -573,11 +1023,14 @@ pub fn draw<'a, T, Renderer>(
     Renderer::Theme: StyleSheet,
     T: ToString + 'a,
 {
+    let state = state();
     let bounds = layout.bounds();
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&bounds);
     let is_selected = selected.is_some();
 
-    let style = if is_mouse_over {
+    let style = if is_disabled {
+        theme.disabled(style)
+    } else if is_mouse_over || state.is_focused {
         theme.hovered(style)
     } else {
         theme.active(style)
@@ -603,7 +1056,7 @@ pub fn draw<'a, T, Renderer>(
             size,
         }) => Some((font.clone(), *code_point, *size)),
         Handle::Dynamic { open, closed } => {
-            if state().is_open {
+            if state.is_open {
                 Some((open.font.clone(), open.code_point, open.size))
             } else {
                 Some((closed.font.clone(), closed.code_point, closed.size))
@@ -618,6 +1071,8 @@ pub fn draw<'a, T, Renderer>(
         renderer.fill_text(Text {
             content: &code_point.to_string(),
             size,
+            line_height: text::LineHeight::default(),
+            letter_spacing: 0.0,
             font,
             color: style.handle_color,
             bounds: Rectangle {
@@ -640,6 +1095,8 @@ pub fn draw<'a, T, Renderer>(
         renderer.fill_text(Text {
             content: label,
             size: text_size,
+            line_height: text::LineHeight::default(),
+            letter_spacing: 0.0,
             font: font.clone(),
             color: if is_selected {
                 style.text_color
@@ -657,3 +1114,257 @@ pub fn draw<'a, T, Renderer>(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Point;
+
+    const OPTIONS: &[&str] = &["Apple", "Banana", "Blueberry", "Cherry"];
+
+    #[test]
+    fn type_ahead_wraps_around_the_list() {
+        assert_eq!(type_ahead(OPTIONS, "b", None), Some(1));
+        assert_eq!(type_ahead(OPTIONS, "b", Some(1)), Some(2));
+        assert_eq!(type_ahead(OPTIONS, "b", Some(2)), Some(1));
+        assert_eq!(type_ahead(OPTIONS, "a", Some(3)), Some(0));
+    }
+
+    #[test]
+    fn type_ahead_matches_sequences() {
+        assert_eq!(type_ahead(OPTIONS, "bl", Some(1)), Some(2));
+        assert_eq!(type_ahead(OPTIONS, "bl", Some(2)), Some(2));
+        assert_eq!(type_ahead(OPTIONS, "bb", Some(1)), Some(2));
+        assert_eq!(type_ahead(OPTIONS, "bx", Some(1)), None);
+    }
+
+    #[test]
+    fn step_wraps_around_the_list() {
+        assert_eq!(step(None, 3, true), Some(0));
+        assert_eq!(step(None, 3, false), Some(2));
+        assert_eq!(step(Some(2), 3, true), Some(0));
+        assert_eq!(step(Some(0), 3, false), Some(2));
+        assert_eq!(step(Some(0), 0, true), None);
+    }
+
+    #[test]
+    fn other_buttons_only_close_the_menu() {
+        let node = layout::Node::new(crate::Size::new(100.0, 20.0));
+        let mut state = State::<&str>::new();
+
+        let press = |state: &mut State<&str>, button, cursor| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            update(
+                Event::Mouse(mouse::Event::ButtonPressed(button)),
+                Layout::new(&node),
+                cursor,
+                &mut shell,
+                &|option| option,
+                None,
+                OPTIONS,
+                || state,
+            )
+        };
+
+        let inside = mouse::Cursor::Available(Point::new(50.0, 10.0));
+
+        assert_eq!(
+            press(&mut state, mouse::Button::Right, inside),
+            event::Status::Ignored
+        );
+        assert!(!state.is_open);
+
+        let _ = press(&mut state, mouse::Button::Left, inside);
+        assert!(state.is_open);
+
+        assert_eq!(
+            press(
+                &mut state,
+                mouse::Button::Middle,
+                mouse::Cursor::Available(Point::ORIGIN)
+            ),
+            event::Status::Captured
+        );
+        assert!(!state.is_open);
+    }
+
+    #[test]
+    fn groups_become_headers_and_empty_ones_are_hidden() {
+        let pick_list: PickList<'_, &str, &str, crate::renderer::Null> =
+            PickList::with_groups(
+                [
+                    Group::new("Fruits", ["Apple", "Banana"]),
+                    Group::new("Nuts", []),
+                    Group::new("Vegetables", ["Carrot"]),
+                ],
+                Some("Carrot"),
+                |option| option,
+            );
+
+        assert_eq!(pick_list.options.as_ref(), ["Apple", "Banana", "Carrot"]);
+        assert_eq!(
+            pick_list.headers,
+            [
+                menu::Header {
+                    label: String::from("Fruits"),
+                    index: 0,
+                },
+                menu::Header {
+                    label: String::from("Vegetables"),
+                    index: 2,
+                },
+            ]
+        );
+    }
+
+    type GroupedPickList<'a> =
+        PickList<'a, &'static str, &'static str, crate::renderer::Null>;
+
+    fn grouped_pick_list() -> GroupedPickList<'static> {
+        PickList::with_groups(
+            [
+                Group::new("Fruits", ["Apple", "Banana"]),
+                Group::new("Vegetables", ["Carrot"]),
+            ],
+            None,
+            |option| option,
+        )
+        .padding(0)
+        .text_size(20)
+    }
+
+    /// Moves the cursor over the given row of the open menu of the
+    /// [`PickList`] and returns the option that ends up hovered.
+    fn hover_row(
+        pick_list: &mut GroupedPickList<'_>,
+        tree: &mut Tree,
+        node: &layout::Node,
+        row: usize,
+    ) -> Option<usize> {
+        let renderer = crate::renderer::Null::new();
+
+        let mut overlay =
+            Widget::overlay(pick_list, tree, Layout::new(node), &renderer)
+                .expect("Open pick list");
+
+        let overlay_node = overlay.layout(
+            &renderer,
+            crate::Size::new(100.0, 400.0),
+            crate::Vector::new(0.0, 0.0),
+        );
+
+        let position = Point::new(
+            50.0,
+            overlay_node.bounds().y + 20.0 * row as f32 + 10.0,
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = overlay.on_event(
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Layout::new(&overlay_node),
+            mouse::Cursor::Available(position),
+            &renderer,
+            &mut crate::clipboard::Null,
+            &mut shell,
+        );
+
+        drop(overlay);
+
+        tree.state.downcast_ref::<State<&str>>().hovered_option
+    }
+
+    #[test]
+    fn headers_are_never_hovered() {
+        let mut pick_list = grouped_pick_list();
+        let node = layout::Node::new(crate::Size::new(100.0, 20.0));
+        let mut tree = Tree::new(&pick_list as &dyn Widget<_, _>);
+
+        tree.state.downcast_mut::<State<&str>>().is_open = true;
+
+        let hovered: Vec<_> = (0..5)
+            .map(|row| hover_row(&mut pick_list, &mut tree, &node, row))
+            .collect();
+
+        assert_eq!(hovered, [None, Some(0), Some(1), None, Some(2)]);
+    }
+
+    #[test]
+    fn keyboard_navigation_skips_headers() {
+        let renderer = crate::renderer::Null::new();
+        let mut pick_list = grouped_pick_list();
+        let node = layout::Node::new(crate::Size::new(100.0, 20.0));
+        let mut tree = Tree::new(&pick_list as &dyn Widget<_, _>);
+
+        {
+            let state = tree.state.downcast_mut::<State<&str>>();
+            state.focus();
+            state.is_open = true;
+        }
+
+        // Hover "Banana", the last option before the "Vegetables" header
+        assert_eq!(hover_row(&mut pick_list, &mut tree, &node, 2), Some(1));
+
+        let mut messages = Vec::new();
+
+        for key_code in [keyboard::KeyCode::Down, keyboard::KeyCode::Enter] {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = pick_list.on_event(
+                &mut tree,
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers: keyboard::Modifiers::default(),
+                }),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+
+            if key_code == keyboard::KeyCode::Down {
+                let state = tree.state.downcast_ref::<State<&str>>();
+
+                // The header is skipped over, straight into "Carrot"
+                assert_eq!(state.hovered_option, Some(2));
+            }
+        }
+
+        assert_eq!(messages, ["Carrot"]);
+    }
+
+    #[test]
+    fn disabled_pick_lists_ignore_events() {
+        let renderer = crate::renderer::Null::new();
+        let mut pick_list: PickList<'_, &str, &str, crate::renderer::Null> =
+            PickList::new(OPTIONS, None, |option| option).disabled(true);
+
+        let node = layout::Node::new(crate::Size::new(100.0, 20.0));
+        let mut tree = Tree::new(&pick_list as &dyn Widget<_, _>);
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = pick_list.on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::new(50.0, 10.0)),
+            &renderer,
+            &mut crate::clipboard::Null,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Ignored);
+
+        let state = tree.state.downcast_ref::<State<&str>>();
+
+        assert!(!state.is_open);
+        assert!(!state.is_focused);
+        assert!(messages.is_empty());
+    }
+}
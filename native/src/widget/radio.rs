@@ -6,10 +6,9 @@ use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::touch;
-use crate::widget::{self, Row, Text, Tree};
+use crate::widget::{self, label, Tree};
 use crate::{
-    Alignment, Clipboard, Color, Element, Layout, Length, Point, Rectangle,
-    Shell, Widget,
+    Clipboard, Color, Element, Layout, Length, Rectangle, Shell, Size, Widget,
 };
 
 pub use iced_style::radio::{Appearance, StyleSheet};
@@ -50,10 +49,13 @@ where
     on_click: Message,
     label: String,
     width: Length,
-    size: u16,
+    size: Option<f32>,
+    stroke_width: Option<f32>,
     spacing: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
+    label_position: label::Position,
+    label_anchor: label::Anchor,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -63,7 +65,13 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
-    /// The default size of a [`Radio`] button.
+    /// The default size of a [`Radio`] button, relative to its text size.
+    pub const DEFAULT_SIZE_RATIO: f32 = 1.4;
+
+    /// The former fixed size of a [`Radio`] button, which now follows its text size.
+    #[deprecated(
+        note = "the default size follows the text size; use `DEFAULT_SIZE_RATIO`"
+    )]
     pub const DEFAULT_SIZE: u16 = 28;
 
     /// The default spacing of a [`Radio`] button.
@@ -92,17 +100,34 @@ where
             on_click: f(value),
             label: label.into(),
             width: Length::Shrink,
-            size: Self::DEFAULT_SIZE,
+            size: None,
+            stroke_width: None,
             spacing: Self::DEFAULT_SPACING, //15
             text_size: None,
             font: Default::default(),
+            label_position: label::Position::Right,
+            label_anchor: label::Anchor::default(),
             style: Default::default(),
         }
     }
 
     /// Sets the size of the [`Radio`] button.
-    pub fn size(mut self, size: u16) -> Self {
-        self.size = size;
+    ///
+    /// By default, the size is [`DEFAULT_SIZE_RATIO`] times the text size,
+    /// so it scales with it. The dot scales proportionally to the button.
+    ///
+    /// [`DEFAULT_SIZE_RATIO`]: Self::DEFAULT_SIZE_RATIO
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the width of the border of the [`Radio`] button, overriding the
+    /// one of its style.
+    ///
+    /// The width is clamped so the dot stays legible at small sizes.
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = Some(stroke_width);
         self
     }
 
@@ -130,6 +155,18 @@ where
         self
     }
 
+    /// Sets the side of the [`Radio`] button where its label is placed.
+    pub fn label_position(mut self, position: label::Position) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets how the [`Radio`] button is vertically aligned to its label.
+    pub fn label_anchor(mut self, anchor: label::Anchor) -> Self {
+        self.label_anchor = anchor;
+        self
+    }
+
     /// Sets the style of the [`Radio`] button.
     pub fn style(
         mut self,
@@ -159,19 +196,22 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        Row::<(), Renderer>::new()
-            .width(self.width)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(self.size))
-                    .height(Length::Units(self.size)),
-            )
-            .push(Text::new(&self.label).width(self.width).size(
-                self.text_size.unwrap_or_else(|| renderer.default_size()),
-            ))
-            .layout(renderer, limits)
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let size = self
+            .size
+            .unwrap_or_else(|| f32::from(text_size) * Self::DEFAULT_SIZE_RATIO);
+
+        label::layout(
+            renderer,
+            limits,
+            self.width,
+            self.spacing,
+            Size::new(size, size),
+            Some((&self.label, text_size, self.font.clone())),
+            self.label_position,
+            self.label_anchor,
+        )
     }
 
     fn on_event(
@@ -179,7 +219,7 @@ where
         _state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -187,7 +227,7 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout.bounds().contains(cursor_position) {
+                if cursor.is_over(&layout.bounds()) {
                     shell.publish(self.on_click.clone());
 
                     return event::Status::Captured;
@@ -203,11 +243,11 @@ where
         &self,
         _state: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if layout.bounds().contains(cursor_position) {
+        if cursor.is_over(&layout.bounds()) {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -221,11 +261,11 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(&bounds);
 
         let mut children = layout.children();
 
@@ -240,13 +280,19 @@ where
             let bounds = layout.bounds();
 
             let size = bounds.width;
-            let dot_size = size / 2.0;
+
+            // Keep the dot legible at small sizes
+            let dot_size = (size / 2.0).max(2.0).min(size);
+            let dot_offset = (size - dot_size) / 2.0;
 
             renderer.fill_quad(
                 renderer::Quad {
                     bounds,
                     border_radius: (size / 2.0).into(),
-                    border_width: custom_style.border_width,
+                    border_width: label::stroke_width(
+                        self.stroke_width.unwrap_or(custom_style.border_width),
+                        size,
+                    ),
                     border_color: custom_style.border_color,
                 },
                 custom_style.background,
@@ -256,10 +302,10 @@ where
                 renderer.fill_quad(
                     renderer::Quad {
                         bounds: Rectangle {
-                            x: bounds.x + dot_size / 2.0,
-                            y: bounds.y + dot_size / 2.0,
-                            width: bounds.width - dot_size,
-                            height: bounds.height - dot_size,
+                            x: bounds.x + dot_offset,
+                            y: bounds.y + dot_offset,
+                            width: dot_size,
+                            height: dot_size,
                         },
                         border_radius: (dot_size / 2.0).into(),
                         border_width: 0.0,
@@ -279,6 +325,8 @@ where
                 label_layout,
                 &self.label,
                 self.text_size,
+                text::LineHeight::default(),
+                0.0,
                 self.font.clone(),
                 widget::text::Appearance {
                     color: custom_style.text_color,
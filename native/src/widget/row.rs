@@ -6,10 +6,11 @@ use crate::overlay;
 use crate::renderer;
 use crate::widget::{Operation, Tree};
 use crate::{
-    Alignment, Clipboard, Element, Length, Padding, Point, Rectangle, Shell,
-    Widget,
+    Alignment, Clipboard, Element, Length, Padding, Rectangle, Shell, Widget,
 };
 
+use std::cell::RefCell;
+
 /// A container that distributes its contents horizontally.
 #[allow(missing_debug_implementations)]
 pub struct Row<'a, Message, Renderer> {
@@ -18,7 +19,9 @@ pub struct Row<'a, Message, Renderer> {
     width: Length,
     height: Length,
     align_items: Alignment,
+    wrap: bool,
     children: Vec<Element<'a, Message, Renderer>>,
+    caches: RefCell<Vec<layout::Cache>>,
 }
 
 impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
@@ -37,7 +40,9 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
             width: Length::Shrink,
             height: Length::Shrink,
             align_items: Alignment::Start,
+            wrap: false,
             children,
+            caches: RefCell::new(Vec::new()),
         }
     }
 
@@ -75,6 +80,21 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self
     }
 
+    /// Sets whether the contents of the [`Row`] wrap onto multiple rows when
+    /// they overflow its width.
+    ///
+    /// The contents are aligned within each row, and the rows are separated
+    /// by the same spacing as the contents. A [`Length::Fill`] element takes
+    /// the rest of the width of its row.
+    ///
+    /// It is disabled by default. See [`LayoutMode::Wrap`] for the details.
+    ///
+    /// [`LayoutMode::Wrap`]: layout::flex::LayoutMode::Wrap
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
     /// Adds an [`Element`] to the [`Row`].
     pub fn push(
         mut self,
@@ -91,17 +111,60 @@ impl<'a, Message, Renderer> Default for Row<'a, Message, Renderer> {
     }
 }
 
+impl<'a, Message, Renderer> Row<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Keeps the layout caches of the given children trees, so the next
+    /// layout can reuse the layout of the children that did not change.
+    fn store_caches(&self, children: &[Tree]) {
+        *self.caches.borrow_mut() =
+            children.iter().map(|child| child.layout.clone()).collect();
+    }
+
+    fn resolve<Item>(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+        items: &[Item],
+    ) -> layout::Node
+    where
+        Item: layout::flex::ItemProxy<Renderer>,
+    {
+        layout::flex::resolve(
+            layout::flex::Axis::Horizontal,
+            if self.wrap {
+                layout::flex::LayoutMode::Wrap
+            } else {
+                layout::flex::LayoutMode::Layout
+            },
+            renderer,
+            limits,
+            self.padding,
+            self.spacing as f32,
+            self.align_items,
+            items,
+        )
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Row<'a, Message, Renderer>
 where
     Renderer: crate::Renderer,
 {
     fn children(&self) -> Vec<Tree> {
-        self.children.iter().map(Tree::new).collect()
+        let children: Vec<Tree> = self.children.iter().map(Tree::new).collect();
+
+        self.store_caches(&children);
+
+        children
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&self.children)
+        tree.diff_children(&self.children);
+
+        self.store_caches(&tree.children);
     }
 
     fn width(&self) -> Length {
@@ -119,15 +182,22 @@ where
     ) -> layout::Node {
         let limits = limits.width(self.width).height(self.height);
 
-        layout::flex::resolve(
-            layout::flex::Axis::Horizontal,
-            renderer,
-            &limits,
-            self.padding,
-            self.spacing as f32,
-            self.align_items,
-            &self.children,
-        )
+        let caches = self.caches.borrow();
+
+        // The caches are only stored once the children trees are created or
+        // diffed
+        if caches.len() == self.children.len() {
+            let children: Vec<_> = self
+                .children
+                .iter()
+                .zip(caches.iter())
+                .map(|(child, cache)| layout::flex::Cached::new(child, cache))
+                .collect();
+
+            self.resolve(renderer, &limits, &children)
+        } else {
+            self.resolve(renderer, &limits, &self.children)
+        }
     }
 
     fn operate(
@@ -155,7 +225,7 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -164,16 +234,19 @@ where
             .iter_mut()
             .zip(&mut tree.children)
             .zip(layout.children())
-            .map(|((child, state), layout)| {
-                child.as_widget_mut().on_event(
-                    state,
-                    event.clone(),
-                    layout,
-                    cursor_position,
-                    renderer,
-                    clipboard,
-                    shell,
-                )
+            .enumerate()
+            .map(|(index, ((child, state), layout))| {
+                shell.traced(index, |shell| {
+                    child.as_widget_mut().on_event(
+                        state,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                })
             })
             .fold(event::Status::Ignored, event::Status::merge)
     }
@@ -182,7 +255,7 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
@@ -192,11 +265,7 @@ where
             .zip(layout.children())
             .map(|((child, state), layout)| {
                 child.as_widget().mouse_interaction(
-                    state,
-                    layout,
-                    cursor_position,
-                    viewport,
-                    renderer,
+                    state, layout, cursor, viewport, renderer,
                 )
             })
             .max()
@@ -210,7 +279,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         for ((child, state), layout) in self
@@ -219,15 +288,9 @@ where
             .zip(&tree.children)
             .zip(layout.children())
         {
-            child.as_widget().draw(
-                state,
-                renderer,
-                theme,
-                style,
-                layout,
-                cursor_position,
-                viewport,
-            );
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
         }
     }
 
@@ -1,8 +1,9 @@
 //! Display a horizontal or vertical rule for dividing content.
 use crate::layout;
+use crate::mouse;
 use crate::renderer;
 use crate::widget::Tree;
-use crate::{Color, Element, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::{Color, Element, Layout, Length, Rectangle, Size, Widget};
 
 pub use iced_style::rule::{Appearance, FillMode, StyleSheet};
 
@@ -84,7 +85,7 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
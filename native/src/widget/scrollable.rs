@@ -5,23 +5,27 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::time::{Duration, Instant};
 use crate::touch;
 use crate::widget;
 use crate::widget::operation::{self, Operation};
 use crate::widget::tree::{self, Tree};
+use crate::window;
 use crate::{
     Background, Clipboard, Color, Command, Element, Layout, Length, Point,
     Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::collections::HashMap;
+
 pub use iced_style::scrollable::StyleSheet;
-pub use operation::scrollable::RelativeOffset;
+pub use operation::scrollable::{Anchor, RelativeOffset};
 
 pub mod style {
     //! The styles of a [`Scrollable`].
     //!
     //! [`Scrollable`]: crate::widget::Scrollable
-    pub use iced_style::scrollable::{Scrollbar, Scroller};
+    pub use iced_style::scrollable::{PullIndicator, Scrollbar, Scroller};
 }
 
 /// A widget that can vertically display an infinite amount of content with a
@@ -38,6 +42,12 @@ where
     horizontal: Option<Properties>,
     content: Element<'a, Message, Renderer>,
     on_scroll: Option<Box<dyn Fn(RelativeOffset) -> Message + 'a>>,
+    on_refresh: Option<Box<dyn Fn() -> Message + 'a>>,
+    is_refreshing: bool,
+    scrolling: Scrolling,
+    space_scrolls: bool,
+    overscroll: Overscroll,
+    preserve: Preserve,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -55,6 +65,12 @@ where
             horizontal: None,
             content: content.into(),
             on_scroll: None,
+            on_refresh: None,
+            is_refreshing: false,
+            scrolling: Scrolling::default(),
+            space_scrolls: false,
+            overscroll: Overscroll::default(),
+            preserve: Preserve::default(),
             style: Default::default(),
         }
     }
@@ -95,6 +111,74 @@ where
         self
     }
 
+    /// Enables pull-to-refresh, producing the given message when the
+    /// [`Scrollable`] is pulled down past its top and released.
+    ///
+    /// Pulls are made by dragging the contents with touch. While pulling, an
+    /// indicator that grows with the pull distance is drawn at the top of the
+    /// [`Scrollable`]. Once released past the threshold, the indicator stays
+    /// pinned and spinning until [`Scrollable::refreshing`] is set to `false`.
+    pub fn on_refresh(mut self, message: Message) -> Self
+    where
+        Message: Clone + 'a,
+    {
+        self.on_refresh = Some(Box::new(move || message.clone()));
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] is refreshing.
+    ///
+    /// The [`Scrollable`] starts refreshing on its own when it is pulled past
+    /// the threshold, so an application should set this to `true` when it
+    /// handles the message of [`Scrollable::on_refresh`], and back to `false`
+    /// once it is done to hide the indicator. It can also be used to show
+    /// the indicator for refreshes that were not triggered by a pull.
+    ///
+    /// It has no effect unless pull-to-refresh is enabled with
+    /// [`Scrollable::on_refresh`].
+    pub fn refreshing(mut self, is_refreshing: bool) -> Self {
+        self.is_refreshing = is_refreshing;
+        self
+    }
+
+    /// Sets how the [`Scrollable`] moves when it is scrolled with the
+    /// keyboard.
+    ///
+    /// By default, keyboard scrolling is [`Scrolling::Instant`].
+    pub fn scrolling(mut self, scrolling: Scrolling) -> Self {
+        self.scrolling = scrolling;
+        self
+    }
+
+    /// Sets whether pressing `Space` scrolls the focused [`Scrollable`] down
+    /// by a page, and `Shift+Space` up by a page.
+    ///
+    /// This is disabled by default, since `Space` is commonly used to
+    /// activate other widgets.
+    pub fn space_scrolls(mut self, space_scrolls: bool) -> Self {
+        self.space_scrolls = space_scrolls;
+        self
+    }
+
+    /// Sets what happens to the mouse wheel scrolling that the [`Scrollable`]
+    /// cannot consume once it reaches the end of its contents.
+    ///
+    /// By default, it is contained. See [`Overscroll`] for details.
+    pub fn overscroll(mut self, overscroll: Overscroll) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    /// Sets the scroll position that the [`Scrollable`] keeps when its
+    /// contents change across rebuilds of the view.
+    ///
+    /// By default, it keeps its absolute offset. See [`Preserve`] for
+    /// details.
+    pub fn preserve(mut self, preserve: Preserve) -> Self {
+        self.preserve = preserve;
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     pub fn style(
         mut self,
@@ -105,12 +189,97 @@ where
     }
 }
 
+/// The way a [`Scrollable`] moves when it is scrolled with the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scrolling {
+    /// The offset jumps to its destination immediately.
+    Instant,
+
+    /// The offset is animated towards its destination over a few frames.
+    Smooth,
+}
+
+impl Default for Scrolling {
+    fn default() -> Self {
+        Self::Instant
+    }
+}
+
+/// The policy of a [`Scrollable`] for the mouse wheel scrolling it cannot
+/// consume, usually because it has reached the end of its contents.
+///
+/// Bubbling is opt-in: by default, a [`Scrollable`] consumes all the
+/// scrolling over it, like it always did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overscroll {
+    /// The scrolling is consumed by the [`Scrollable`] along every axis,
+    /// even if it is already at the end of its contents.
+    ///
+    /// This is the default, since it keeps the parent still while the
+    /// cursor is over the [`Scrollable`].
+    Contain,
+
+    /// The remaining scrolling is passed to the nearest [`Scrollable`]
+    /// ancestor, like scroll chaining in web browsers.
+    ///
+    /// Each axis is routed independently, so scrolling along an axis the
+    /// [`Scrollable`] cannot scroll at all—because its contents fit or
+    /// horizontal scrolling is disabled—bubbles up entirely. This way, a
+    /// horizontal [`Scrollable`] nested in a vertical one does not block
+    /// vertical scrolling.
+    Bubble,
+}
+
+impl Default for Overscroll {
+    fn default() -> Self {
+        Self::Contain
+    }
+}
+
+/// The scroll position that a [`Scrollable`] keeps when its contents change
+/// across rebuilds of the view—after filtering a list, for instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preserve {
+    /// The absolute offset is kept, in pixels. If the contents shrink, the
+    /// offset is clamped to their new end.
+    ///
+    /// This is the default. The contents may appear to jump when items are
+    /// added or removed before the visible ones.
+    Offset,
+
+    /// The [`RelativeOffset`] is kept. A [`Scrollable`] scrolled to the end
+    /// of its contents stays at the end as they grow.
+    RelativeOffset,
+
+    /// The item closest to the top-left of the viewport is kept in place.
+    ///
+    /// The items are the widgets inside the [`Scrollable`] that report their
+    /// bounds together with an [`Id`], like a [`Container`] with an id. If
+    /// the item is removed, the nearest remaining item is kept in place
+    /// instead. The absolute offset is kept if no item remains.
+    ///
+    /// Items should be identified by the logical entry they show—rather
+    /// than by their position—so they can be found after a rebuild.
+    ///
+    /// [`Id`]: widget::Id
+    /// [`Container`]: widget::Container
+    Item,
+}
+
+impl Default for Preserve {
+    fn default() -> Self {
+        Self::Offset
+    }
+}
+
 /// Properties of a scrollbar within a [`Scrollable`].
 #[derive(Debug)]
 pub struct Properties {
     width: u16,
     margin: u16,
     scroller_width: u16,
+    always_visible: bool,
+    fit_fill: bool,
 }
 
 impl Default for Properties {
@@ -119,6 +288,8 @@ impl Default for Properties {
             width: 10,
             margin: 0,
             scroller_width: 10,
+            always_visible: false,
+            fit_fill: false,
         }
     }
 }
@@ -148,6 +319,30 @@ impl Properties {
         self.scroller_width = scroller_width.max(1);
         self
     }
+
+    /// Sets whether the scrollbar of the [`Scrollable`] is shown even when
+    /// its contents fit in its bounds.
+    ///
+    /// By default, the scrollbar is only shown when the contents overflow.
+    pub fn always_visible(mut self, always_visible: bool) -> Self {
+        self.always_visible = always_visible;
+        self
+    }
+
+    /// Sets whether contents that fill the horizontal axis are laid out
+    /// again to fit the width of the [`Scrollable`].
+    ///
+    /// Contents are given an unbounded width when horizontal scrolling is
+    /// enabled, so contents that fill it end up infinitely wide. With this
+    /// enabled, they are laid out a second time without horizontal scrolling
+    /// instead—at the cost of that extra layout pass.
+    ///
+    /// It only applies to the horizontal [`Properties`] of a [`Scrollable`],
+    /// and it is disabled by default.
+    pub fn fit_fill(mut self, fit_fill: bool) -> Self {
+        self.fit_fill = fit_fill;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -161,7 +356,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::with_debug(State::new())
     }
 
     fn children(&self) -> Vec<Tree> {
@@ -169,6 +364,27 @@ where
     }
 
     fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        match (
+            self.on_refresh.is_some() && self.is_refreshing,
+            state.refresh,
+        ) {
+            (true, None) => {
+                state.refresh = Some(Refresh::new(Instant::now()));
+            }
+            (false, Some(_)) => {
+                state.refresh = None;
+            }
+            _ => {}
+        }
+
+        match self.preserve {
+            Preserve::Offset => {}
+            Preserve::RelativeOffset => state.relativize(),
+            Preserve::Item => state.is_anchor_outdated = true,
+        }
+
         tree.diff_children(std::slice::from_ref(&self.content))
     }
 
@@ -185,16 +401,33 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
-            renderer,
-            limits,
-            Widget::<Message, Renderer>::width(self),
-            self.height,
-            self.horizontal.is_some(),
-            |renderer, limits| {
-                self.content.as_widget().layout(renderer, limits)
-            },
-        )
+        let layout_with = |horizontal_enabled| {
+            layout(
+                renderer,
+                limits,
+                Widget::<Message, Renderer>::width(self),
+                self.height,
+                horizontal_enabled,
+                |renderer, limits| {
+                    self.content.as_widget().layout(renderer, limits)
+                },
+            )
+        };
+
+        let node = layout_with(self.horizontal.is_some());
+
+        // Contents that fill an unbounded width are fitted to the width of
+        // the `Scrollable` instead, if asked to
+        let fits_fill = self
+            .horizontal
+            .as_ref()
+            .map_or(false, |horizontal| horizontal.fit_fill);
+
+        if fits_fill && node.children()[0].size().width.is_infinite() {
+            layout_with(false)
+        } else {
+            node
+        }
     }
 
     fn operate(
@@ -205,17 +438,32 @@ where
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        let bounds = layout.bounds();
+        let content_layout = layout.children().next().unwrap();
+        let content_bounds = content_layout.bounds();
 
-        operation.scrollable(state, self.id.as_ref().map(|id| &id.0));
+        operation.bounds(bounds, id);
+        operation.focusable(state, id);
 
         operation.container(None, &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
-                layout.children().next().unwrap(),
+                content_layout,
                 renderer,
                 operation,
             );
         });
+
+        operation.scrollable(
+            &mut Viewport {
+                state,
+                bounds,
+                content_bounds,
+            },
+            id,
+        );
     }
 
     fn on_event(
@@ -223,31 +471,85 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        let content_layout = layout.children().next().unwrap();
+
+        if self.preserve == Preserve::Item
+            && tree.state.downcast_ref::<State>().is_anchor_outdated
+        {
+            let state = tree.state.downcast_mut::<State>();
+            let content_bounds = content_layout.bounds();
+
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                content_layout,
+                renderer,
+                &mut Items {
+                    items: &mut state.anchoring.items,
+                    origin: Vector::new(-content_bounds.x, -content_bounds.y),
+                },
+            );
+
+            state.anchor(layout.bounds(), content_bounds);
+        }
+
+        tree.state
+            .downcast_mut::<State>()
+            .remember(layout.bounds(), content_layout.bounds());
+
+        let is_text_input_focused =
+            matches!(
+                event,
+                Event::Keyboard(keyboard::Event::KeyPressed { .. })
+            ) && tree.state.downcast_ref::<State>().is_focused
+                && {
+                    let mut operation = TextInputFocused::default();
+
+                    self.content.as_widget().operate(
+                        &mut tree.children[0],
+                        content_layout,
+                        renderer,
+                        &mut operation,
+                    );
+
+                    operation.found
+                };
+
         update(
             tree.state.downcast_mut::<State>(),
             event,
             layout,
-            cursor_position,
+            cursor,
             clipboard,
             shell,
-            &self.vertical,
-            self.horizontal.as_ref(),
-            &self.on_scroll,
-            |event, layout, cursor_position, clipboard, shell| {
-                self.content.as_widget_mut().on_event(
-                    &mut tree.children[0],
-                    event,
-                    layout,
-                    cursor_position,
-                    renderer,
-                    clipboard,
-                    shell,
-                )
+            update::Options {
+                vertical: &self.vertical,
+                horizontal: self.horizontal.as_ref(),
+                on_scroll: self.on_scroll.as_deref(),
+                on_refresh: self.on_refresh.as_deref(),
+                overscroll: self.overscroll,
+                keyboard: KeyboardScroll {
+                    scrolling: self.scrolling,
+                    space_scrolls: self.space_scrolls,
+                    is_text_input_focused,
+                },
+            },
+            |event, layout, cursor, clipboard, shell| {
+                shell.traced(0, |shell| {
+                    self.content.as_widget_mut().on_event(
+                        &mut tree.children[0],
+                        event,
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                })
             },
         )
     }
@@ -259,7 +561,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         draw(
@@ -267,18 +569,18 @@ where
             renderer,
             theme,
             layout,
-            cursor_position,
+            cursor,
             &self.vertical,
             self.horizontal.as_ref(),
             &self.style,
-            |renderer, layout, cursor_position, viewport| {
+            |renderer, layout, cursor, viewport| {
                 self.content.as_widget().draw(
                     &tree.children[0],
                     renderer,
                     theme,
                     style,
                     layout,
-                    cursor_position,
+                    cursor,
                     viewport,
                 )
             },
@@ -289,21 +591,21 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
         mouse_interaction(
             tree.state.downcast_ref::<State>(),
             layout,
-            cursor_position,
+            cursor,
             &self.vertical,
             self.horizontal.as_ref(),
-            |layout, cursor_position, viewport| {
+            |layout, cursor, viewport| {
                 self.content.as_widget().mouse_interaction(
                     &tree.children[0],
                     layout,
-                    cursor_position,
+                    cursor,
                     viewport,
                     renderer,
                 )
@@ -385,6 +687,134 @@ pub fn snap_to<Message: 'static>(
     Command::widget(operation::scrollable::snap_to(id.0, offset))
 }
 
+/// Produces a [`Command`] that scrolls the widget with the given [`Id`] into
+/// view, adjusting every [`Scrollable`] containing it.
+///
+/// A `margin` is kept around the widget when possible. Nothing is scrolled
+/// if the widget is already visible.
+///
+/// [`Id`]: widget::Id
+pub fn scroll_to_widget<Message: 'static>(
+    target: impl Into<widget::Id>,
+    margin: f32,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to_widget(
+        target.into(),
+        margin,
+    ))
+}
+
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given [`Id`]
+/// to reveal the widget with the `item` id inside of it, placing it as the
+/// given [`Anchor`] describes.
+///
+/// Nothing is scrolled if the `item` is not found inside the [`Scrollable`].
+/// See [`operation::scrollable::scroll_to_item`] for the details, including
+/// how widgets can provide estimated bounds for items that are not laid out.
+pub fn scroll_to_item<Message: 'static>(
+    id: Id,
+    item: impl Into<widget::Id>,
+    anchor: Anchor,
+) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to_item(
+        id.0,
+        item.into(),
+        anchor,
+    ))
+}
+
+/// The keyboard configuration of a [`Scrollable`] used by [`update()`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeyboardScroll {
+    /// The way the [`Scrollable`] moves when scrolled with the keyboard.
+    pub scrolling: Scrolling,
+
+    /// Whether `Space` scrolls by a page.
+    pub space_scrolls: bool,
+
+    /// Whether a text input inside the [`Scrollable`] is focused.
+    ///
+    /// When it is, the arrow keys and `Space` are left to the text input.
+    pub is_text_input_focused: bool,
+}
+
+/// An [`Operation`] that finds out whether a focused text input is present
+/// in the widget tree.
+#[derive(Debug, Default)]
+struct TextInputFocused {
+    last_focusable: bool,
+    found: bool,
+}
+
+impl<T> Operation<T> for TextInputFocused {
+    fn focusable(
+        &mut self,
+        state: &mut dyn operation::Focusable,
+        _id: Option<&widget::Id>,
+    ) {
+        self.last_focusable = state.is_focused();
+    }
+
+    fn text_input(
+        &mut self,
+        _state: &mut dyn operation::TextInput,
+        _id: Option<&widget::Id>,
+    ) {
+        // Text inputs report their focus through `focusable` right before
+        // calling `text_input`
+        self.found |= self.last_focusable;
+    }
+
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if !self.found {
+            operate_on_children(self);
+        }
+    }
+}
+
+/// An [`Operation`] that records the new bounds of the widgets with an id
+/// that report them, relative to the given `origin`.
+///
+/// The ids of the known items are not cloned again.
+#[derive(Debug)]
+struct Items<'a> {
+    items: &'a mut HashMap<widget::Id, Item>,
+    origin: Vector,
+}
+
+impl<'a, T> Operation<T> for Items<'a> {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn bounds(&mut self, bounds: Rectangle, id: Option<&widget::Id>) {
+        if let Some(id) = id {
+            let bounds = bounds + self.origin;
+
+            match self.items.get_mut(id) {
+                Some(item) => item.new = Some(bounds),
+                None => {
+                    let _ = self.items.insert(
+                        id.clone(),
+                        Item {
+                            old: None,
+                            new: Some(bounds),
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
 /// Computes the layout of a [`Scrollable`].
 pub fn layout<Renderer>(
     renderer: &Renderer,
@@ -422,28 +852,74 @@ pub fn layout<Renderer>(
     layout::Node::with_children(size, vec![content])
 }
 
+pub mod update {
+    //! Configure the [`update`] of a [`Scrollable`].
+    //!
+    //! [`update`]: super::update()
+    //! [`Scrollable`]: super::Scrollable
+    use super::{KeyboardScroll, Overscroll, Properties, RelativeOffset};
+
+    /// The options of a [`Scrollable`] used by [`update`].
+    ///
+    /// [`update`]: super::update()
+    /// [`Scrollable`]: super::Scrollable
+    #[allow(missing_debug_implementations)]
+    pub struct Options<'a, Message> {
+        /// The [`Properties`] of the vertical scrollbar.
+        pub vertical: &'a Properties,
+
+        /// The [`Properties`] of the horizontal scrollbar, if enabled.
+        pub horizontal: Option<&'a Properties>,
+
+        /// The function producing a message when the [`Scrollable`] is
+        /// scrolled, if any.
+        ///
+        /// [`Scrollable`]: super::Scrollable
+        pub on_scroll: Option<&'a dyn Fn(RelativeOffset) -> Message>,
+
+        /// The message produced when the [`Scrollable`] is pulled to refresh,
+        /// if any.
+        ///
+        /// [`Scrollable`]: super::Scrollable
+        pub on_refresh: Option<&'a dyn Fn() -> Message>,
+
+        /// The [`Overscroll`] policy.
+        pub overscroll: Overscroll,
+
+        /// The [`KeyboardScroll`] configuration.
+        pub keyboard: KeyboardScroll,
+    }
+}
+
 /// Processes an [`Event`] and updates the [`State`] of a [`Scrollable`]
 /// accordingly.
 pub fn update<Message>(
     state: &mut State,
     event: Event,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     clipboard: &mut dyn Clipboard,
     shell: &mut Shell<'_, Message>,
-    vertical: &Properties,
-    horizontal: Option<&Properties>,
-    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
+    options: update::Options<'_, Message>,
     update_content: impl FnOnce(
         Event,
         Layout<'_>,
-        Point,
+        mouse::Cursor,
         &mut dyn Clipboard,
         &mut Shell<'_, Message>,
     ) -> event::Status,
 ) -> event::Status {
+    let update::Options {
+        vertical,
+        horizontal,
+        on_scroll,
+        on_refresh,
+        overscroll,
+        keyboard,
+    } = options;
+
     let bounds = layout.bounds();
-    let mouse_over_scrollable = bounds.contains(cursor_position);
+    let mouse_over_scrollable = cursor.is_over(&bounds);
 
     let content = layout.children().next().unwrap();
     let content_bounds = content.bounds();
@@ -452,31 +928,42 @@ pub fn update<Message>(
         Scrollbars::new(state, vertical, horizontal, bounds, content_bounds);
 
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
-        scrollbars.is_mouse_over(cursor_position);
+        scrollbars.is_mouse_over(cursor);
+
+    if let Event::Mouse(mouse::Event::ButtonPressed(_))
+    | Event::Touch(touch::Event::FingerPressed { .. }) = event
+    {
+        state.is_focused = mouse_over_scrollable;
+    }
 
     let event_status = {
-        let cursor_position = if mouse_over_scrollable
+        let cursor = if mouse_over_scrollable
             && !(mouse_over_y_scrollbar || mouse_over_x_scrollbar)
         {
-            cursor_position + state.offset(bounds, content_bounds)
+            cursor + state.offset(bounds, content_bounds)
         } else {
-            // TODO: Make `cursor_position` an `Option<Point>` so we can encode
-            // cursor availability.
-            // This will probably happen naturally once we add multi-window
-            // support.
-            Point::new(-1.0, -1.0)
+            mouse::Cursor::Unavailable
         };
 
-        update_content(
-            event.clone(),
-            content,
-            cursor_position,
-            clipboard,
-            shell,
-        )
+        update_content(event.clone(), content, cursor, clipboard, shell)
     };
 
+    // Whatever scrolling the contents could not consume is ours to handle
+    let overscrolled = shell.take_overscroll();
+
     if let event::Status::Captured = event_status {
+        if let (true, Some(delta)) = (mouse_over_scrollable, overscrolled) {
+            let _ = scroll_wheel(
+                state,
+                delta,
+                overscroll,
+                bounds,
+                content_bounds,
+                on_scroll,
+                shell,
+            );
+        }
+
         return event::Status::Captured;
     }
 
@@ -487,9 +974,76 @@ pub fn update<Message>(
         return event::Status::Ignored;
     }
 
-    if mouse_over_scrollable {
+    if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        if state.animate(now, bounds, content_bounds) {
+            notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+        }
+
+        // The indicator only spins while pull-to-refresh is enabled
+        let is_refreshing = on_refresh.is_some() && state.refresh.is_some();
+
+        if let Some(refresh) = state.refresh.as_mut().filter(|_| is_refreshing)
+        {
+            refresh.now = now;
+        }
+
+        if state.animation.is_some() || is_refreshing {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        return event::Status::Ignored;
+    }
+
+    if let Event::Keyboard(keyboard::Event::KeyPressed {
+        key_code,
+        modifiers,
+    }) = event
+    {
+        if state.is_focused {
+            if let Some(destination) = state.keyboard_destination(
+                key_code,
+                modifiers,
+                keyboard,
+                horizontal.is_some(),
+                bounds,
+                content_bounds,
+            ) {
+                match keyboard.scrolling {
+                    Scrolling::Instant => {
+                        state.scroll_to(destination, bounds, content_bounds);
+
+                        notify_on_scroll(
+                            state,
+                            on_scroll,
+                            bounds,
+                            content_bounds,
+                            shell,
+                        );
+                    }
+                    Scrolling::Smooth => {
+                        state.animation = Some(Animation {
+                            destination,
+                            last_frame: None,
+                        });
+
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+        }
+    }
+
+    // A touch that is pulling keeps dragging the contents after leaving the
+    // bounds, so pulls are not cut short
+    let is_pulling = state.pulled > 0.0;
+
+    if mouse_over_scrollable || is_pulling {
         match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if mouse_over_scrollable =>
+            {
                 let delta = match delta {
                     mouse::ScrollDelta::Lines { x, y } => {
                         // TODO: Configurable speed/friction (?)
@@ -504,35 +1058,40 @@ pub fn update<Message>(
                     mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
                 };
 
-                state.scroll(delta, bounds, content_bounds);
-
-                notify_on_scroll(
+                return scroll_wheel(
                     state,
-                    on_scroll,
+                    delta,
+                    overscroll,
                     bounds,
                     content_bounds,
+                    on_scroll,
                     shell,
                 );
-
-                return event::Status::Captured;
             }
             Event::Touch(event)
-                if state.scroll_area_touched_at.is_some()
+                if is_pulling
                     || !mouse_over_y_scrollbar && !mouse_over_x_scrollbar =>
             {
                 match event {
                     touch::Event::FingerPressed { .. } => {
-                        state.scroll_area_touched_at = Some(cursor_position);
+                        state.scroll_area_touched_at = cursor.position();
                     }
                     touch::Event::FingerMoved { .. } => {
-                        if let Some(scroll_box_touched_at) =
-                            state.scroll_area_touched_at
+                        if let (
+                            Some(scroll_box_touched_at),
+                            Some(cursor_position),
+                        ) = (state.scroll_area_touched_at, cursor.position())
                         {
-                            let delta = Vector::new(
+                            let mut delta = Vector::new(
                                 cursor_position.x - scroll_box_touched_at.x,
                                 cursor_position.y - scroll_box_touched_at.y,
                             );
 
+                            if on_refresh.is_some() && state.refresh.is_none() {
+                                delta.y =
+                                    state.pull(delta.y, bounds, content_bounds);
+                            }
+
                             state.scroll(delta, bounds, content_bounds);
 
                             state.scroll_area_touched_at =
@@ -547,9 +1106,27 @@ pub fn update<Message>(
                             );
                         }
                     }
-                    touch::Event::FingerLifted { .. }
-                    | touch::Event::FingerLost { .. } => {
+                    touch::Event::FingerLifted { .. } => {
+                        state.scroll_area_touched_at = None;
+
+                        if let Some(on_refresh) = on_refresh {
+                            if state.release_pull() {
+                                state.refresh =
+                                    Some(Refresh::new(Instant::now()));
+
+                                shell.publish(on_refresh());
+                                shell.request_redraw(
+                                    window::RedrawRequest::NextFrame,
+                                );
+                            }
+                        }
+                    }
+                    touch::Event::FingerLost { .. } => {
                         state.scroll_area_touched_at = None;
+                        state.pulled = 0.0;
+                    }
+                    touch::Event::Gesture(_) => {
+                        return event::Status::Ignored;
                     }
                 }
 
@@ -572,10 +1149,8 @@ pub fn update<Message>(
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if let Some(scrollbar) = scrollbars.y {
                     state.scroll_y_to(
-                        scrollbar.scroll_percentage_y(
-                            scroller_grabbed_at,
-                            cursor_position,
-                        ),
+                        scrollbar
+                            .scroll_percentage_y(scroller_grabbed_at, cursor),
                         bounds,
                         content_bounds,
                     );
@@ -598,13 +1173,11 @@ pub fn update<Message>(
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if let (Some(scroller_grabbed_at), Some(scrollbar)) =
-                    (scrollbars.grab_y_scroller(cursor_position), scrollbars.y)
+                    (scrollbars.grab_y_scroller(cursor), scrollbars.y)
                 {
                     state.scroll_y_to(
-                        scrollbar.scroll_percentage_y(
-                            scroller_grabbed_at,
-                            cursor_position,
-                        ),
+                        scrollbar
+                            .scroll_percentage_y(scroller_grabbed_at, cursor),
                         bounds,
                         content_bounds,
                     );
@@ -639,10 +1212,8 @@ pub fn update<Message>(
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if let Some(scrollbar) = scrollbars.x {
                     state.scroll_x_to(
-                        scrollbar.scroll_percentage_x(
-                            scroller_grabbed_at,
-                            cursor_position,
-                        ),
+                        scrollbar
+                            .scroll_percentage_x(scroller_grabbed_at, cursor),
                         bounds,
                         content_bounds,
                     );
@@ -665,13 +1236,11 @@ pub fn update<Message>(
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 if let (Some(scroller_grabbed_at), Some(scrollbar)) =
-                    (scrollbars.grab_x_scroller(cursor_position), scrollbars.x)
+                    (scrollbars.grab_x_scroller(cursor), scrollbars.x)
                 {
                     state.scroll_x_to(
-                        scrollbar.scroll_percentage_x(
-                            scroller_grabbed_at,
-                            cursor_position,
-                        ),
+                        scrollbar
+                            .scroll_percentage_x(scroller_grabbed_at, cursor),
                         bounds,
                         content_bounds,
                     );
@@ -696,21 +1265,59 @@ pub fn update<Message>(
     event::Status::Ignored
 }
 
+/// Scrolls the [`State`] of a [`Scrollable`] by the given mouse wheel
+/// `delta`, reporting any scrolling left to the parent through the [`Shell`]
+/// according to the [`Overscroll`] policy.
+fn scroll_wheel<Message>(
+    state: &mut State,
+    delta: Vector,
+    overscroll: Overscroll,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    on_scroll: Option<&dyn Fn(RelativeOffset) -> Message>,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status {
+    let before = state.offset(bounds, content_bounds);
+
+    state.scroll(delta, bounds, content_bounds);
+
+    let consumed = before - state.offset(bounds, content_bounds);
+
+    let remaining = match overscroll {
+        Overscroll::Contain => Vector::new(0.0, 0.0),
+        Overscroll::Bubble => delta - consumed,
+    };
+
+    if overscroll == Overscroll::Bubble && remaining == delta {
+        return event::Status::Ignored;
+    }
+
+    if consumed != Vector::new(0.0, 0.0) {
+        notify_on_scroll(state, on_scroll, bounds, content_bounds, shell);
+    }
+
+    if remaining != Vector::new(0.0, 0.0) {
+        shell.overscroll(remaining);
+    }
+
+    event::Status::Captured
+}
+
 /// Computes the current [`mouse::Interaction`] of a [`Scrollable`].
 pub fn mouse_interaction(
     state: &State,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     vertical: &Properties,
     horizontal: Option<&Properties>,
     content_interaction: impl FnOnce(
         Layout<'_>,
-        Point,
+        mouse::Cursor,
         &Rectangle,
     ) -> mouse::Interaction,
 ) -> mouse::Interaction {
     let bounds = layout.bounds();
-    let mouse_over_scrollable = bounds.contains(cursor_position);
+    let mouse_over_scrollable = cursor.is_over(&bounds);
 
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
@@ -719,7 +1326,7 @@ pub fn mouse_interaction(
         Scrollbars::new(state, vertical, horizontal, bounds, content_bounds);
 
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
-        scrollbars.is_mouse_over(cursor_position);
+        scrollbars.is_mouse_over(cursor);
 
     if (mouse_over_x_scrollbar || mouse_over_y_scrollbar)
         || state.scrollers_grabbed()
@@ -728,17 +1335,17 @@ pub fn mouse_interaction(
     } else {
         let offset = state.offset(bounds, content_bounds);
 
-        let cursor_position = if mouse_over_scrollable
+        let cursor = if mouse_over_scrollable
             && !(mouse_over_y_scrollbar || mouse_over_x_scrollbar)
         {
-            cursor_position + offset
+            cursor + offset
         } else {
-            Point::new(-1.0, -1.0)
+            mouse::Cursor::Unavailable
         };
 
         content_interaction(
             content_layout,
-            cursor_position,
+            cursor,
             &Rectangle {
                 y: bounds.y + offset.y,
                 x: bounds.x + offset.x,
@@ -748,52 +1355,84 @@ pub fn mouse_interaction(
     }
 }
 
-/// Draws a [`Scrollable`].
-pub fn draw<Renderer>(
+/// Explains the layout of the contents of a [`Scrollable`] with the given
+/// function, translated and clipped like [`draw`] does with the contents.
+pub(crate) fn explain<Renderer>(
     state: &State,
     renderer: &mut Renderer,
-    theme: &Renderer::Theme,
     layout: Layout<'_>,
-    cursor_position: Point,
-    vertical: &Properties,
-    horizontal: Option<&Properties>,
-    style: &<Renderer::Theme as StyleSheet>::Style,
-    draw_content: impl FnOnce(&mut Renderer, Layout<'_>, Point, &Rectangle),
+    explain_content: impl FnOnce(&mut Renderer, Layout<'_>),
 ) where
     Renderer: crate::Renderer,
-    Renderer::Theme: StyleSheet,
 {
     let bounds = layout.bounds();
     let content_layout = layout.children().next().unwrap();
     let content_bounds = content_layout.bounds();
 
-    let scrollbars =
-        Scrollbars::new(state, vertical, horizontal, bounds, content_bounds);
-
-    let mouse_over_scrollable = bounds.contains(cursor_position);
-    let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
-        scrollbars.is_mouse_over(cursor_position);
-
     let offset = state.offset(bounds, content_bounds);
 
-    let cursor_position = if mouse_over_scrollable
-        && !(mouse_over_x_scrollbar || mouse_over_y_scrollbar)
+    // The contents can only be scrolled—and are only clipped—when they
+    // overflow the bounds
+    if content_bounds.width > bounds.width
+        || content_bounds.height > bounds.height
     {
-        cursor_position + offset
-    } else {
-        Point::new(-1.0, -1.0)
-    };
-
-    // Draw inner content
-    if scrollbars.active() {
         renderer.with_layer(bounds, |renderer| {
             renderer.with_translation(
                 Vector::new(-offset.x, -offset.y),
-                |renderer| {
+                |renderer| explain_content(renderer, content_layout),
+            );
+        });
+    } else {
+        explain_content(renderer, content_layout);
+    }
+}
+
+/// Draws a [`Scrollable`].
+pub fn draw<Renderer>(
+    state: &State,
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    vertical: &Properties,
+    horizontal: Option<&Properties>,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+    draw_content: impl FnOnce(&mut Renderer, Layout<'_>, mouse::Cursor, &Rectangle),
+) where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let bounds = layout.bounds();
+    let content_layout = layout.children().next().unwrap();
+    let content_bounds = content_layout.bounds();
+
+    let scrollbars =
+        Scrollbars::new(state, vertical, horizontal, bounds, content_bounds);
+
+    let mouse_over_scrollable = cursor.is_over(&bounds);
+    let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
+        scrollbars.is_mouse_over(cursor);
+
+    let offset = state.offset(bounds, content_bounds);
+
+    let cursor = if mouse_over_scrollable
+        && !(mouse_over_x_scrollbar || mouse_over_y_scrollbar)
+    {
+        cursor + offset
+    } else {
+        mouse::Cursor::Unavailable
+    };
+
+    // Draw inner content
+    if scrollbars.active() {
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(-offset.x, -offset.y),
+                |renderer| {
                     draw_content(
                         renderer,
                         content_layout,
-                        cursor_position,
+                        cursor,
                         &Rectangle {
                             y: bounds.y + offset.y,
                             x: bounds.x + offset.x,
@@ -881,7 +1520,7 @@ pub fn draw<Renderer>(
         draw_content(
             renderer,
             content_layout,
-            cursor_position,
+            cursor,
             &Rectangle {
                 x: bounds.x + offset.x,
                 y: bounds.y + offset.y,
@@ -889,11 +1528,72 @@ pub fn draw<Renderer>(
             },
         );
     }
+
+    if let Some(indicator) = state.pull_indicator() {
+        renderer.with_layer(bounds, |renderer| {
+            draw_pull_indicator(renderer, theme, style, bounds, indicator);
+        });
+    }
+}
+
+/// Draws the pull-to-refresh indicator of a [`Scrollable`] at the top of
+/// the given bounds.
+fn draw_pull_indicator<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    style: &<Renderer::Theme as StyleSheet>::Style,
+    bounds: Rectangle,
+    indicator: Indicator,
+) where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    let appearance = theme.pull_indicator(style);
+
+    let size = PULL_INDICATOR_SIZE * indicator.progress;
+    let center = Point::new(
+        bounds.center_x(),
+        bounds.y + indicator.distance - PULL_INDICATOR_SIZE / 2.0,
+    );
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: center.x - size / 2.0,
+                y: center.y - size / 2.0,
+                width: size,
+                height: size,
+            },
+            border_radius: (size / 2.0).into(),
+            border_width: appearance.border_width,
+            border_color: appearance.border_color,
+        },
+        appearance.background,
+    );
+
+    let dot = size / 5.0;
+    let orbit = size / 2.0 - dot;
+    let (sin, cos) = indicator.angle.sin_cos();
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x: center.x + orbit * sin - dot / 2.0,
+                y: center.y - orbit * cos - dot / 2.0,
+                width: dot,
+                height: dot,
+            },
+            border_radius: (dot / 2.0).into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        appearance.color,
+    );
 }
 
 fn notify_on_scroll<Message>(
     state: &State,
-    on_scroll: &Option<Box<dyn Fn(RelativeOffset) -> Message + '_>>,
+    on_scroll: Option<&dyn Fn(RelativeOffset) -> Message>,
     bounds: Rectangle,
     content_bounds: Rectangle,
     shell: &mut Shell<'_, Message>,
@@ -918,7 +1618,7 @@ fn notify_on_scroll<Message>(
 }
 
 /// The local state of a [`Scrollable`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct State {
     scroll_area_touched_at: Option<Point>,
     offset_y: Offset,
@@ -926,8 +1626,158 @@ pub struct State {
     offset_x: Offset,
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
+    is_focused: bool,
+    animation: Option<Animation>,
+    pulled: f32,
+    refresh: Option<Refresh>,
+    viewport: Option<(Rectangle, Rectangle)>,
+    anchoring: Anchoring,
+    is_anchor_outdated: bool,
+}
+
+/// The items of the contents of a [`Scrollable`] as they were last laid
+/// out, used to keep one of them in place when the contents change.
+#[derive(Debug, Clone, Default)]
+struct Anchoring {
+    /// The bounds of the [`Scrollable`] and its contents when the items were
+    /// last laid out.
+    viewport: Option<(Rectangle, Rectangle)>,
+    items: HashMap<widget::Id, Item>,
+}
+
+/// The bounds of an item, relative to the top-left of the contents.
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    /// The bounds as they were last laid out, if the item was there.
+    old: Option<Rectangle>,
+    /// The bounds as they are laid out now, if the item is still there.
+    new: Option<Rectangle>,
+}
+
+impl Anchoring {
+    /// Returns how much the contents have moved at the given `offset`,
+    /// judging by the item closest to the top-left of the viewport that is
+    /// still there—or the nearest one to it that is.
+    fn shift(&self, offset: Vector, size: Size) -> Option<Vector> {
+        let viewport = Rectangle::new(Point::new(offset.x, offset.y), size);
+
+        let moved = |item: &Item| Some((item.old?, item.new));
+
+        let (old, new) = closest(
+            self.items.values().filter_map(moved).filter(|(bounds, _)| {
+                // Empty items are visible too, so the bounds are inclusive
+                bounds.x <= viewport.x + viewport.width
+                    && bounds.x + bounds.width >= viewport.x
+                    && bounds.y <= viewport.y + viewport.height
+                    && bounds.y + bounds.height >= viewport.y
+            }),
+            viewport.position(),
+        )?;
+
+        let (old, new) = match new {
+            Some(new) => (old, new),
+            None => {
+                let remaining = self
+                    .items
+                    .values()
+                    .filter_map(moved)
+                    .filter(|(_, new)| new.is_some());
+
+                let (nearest, new) = closest(remaining, old.position())?;
+
+                (nearest, new?)
+            }
+        };
+
+        Some(new.position() - old.position())
+    }
+
+    /// Forgets the items that were removed and remembers the new bounds of
+    /// the rest, as laid out with the given bounds.
+    fn update(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
+        self.items.retain(|_, item| {
+            item.old = item.new.take();
+            item.old.is_some()
+        });
+
+        self.viewport = Some((bounds, content_bounds));
+    }
+}
+
+/// Returns the item with old bounds closest to the given `point`, preferring
+/// the smallest one when there is a tie—so items are chosen over the widgets
+/// that contain them.
+fn closest(
+    items: impl Iterator<Item = (Rectangle, Option<Rectangle>)>,
+    point: Point,
+) -> Option<(Rectangle, Option<Rectangle>)> {
+    items.min_by(|(a, _), (b, _)| {
+        let distance = |bounds: &Rectangle| bounds.position().distance(point);
+
+        distance(a)
+            .total_cmp(&distance(b))
+            .then(a.area().total_cmp(&b.area()))
+    })
+}
+
+/// An ongoing refresh triggered by pulling a [`Scrollable`].
+#[derive(Debug, Clone, Copy)]
+struct Refresh {
+    started_at: Instant,
+    now: Instant,
+}
+
+impl Refresh {
+    fn new(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            now,
+        }
+    }
 }
 
+/// The placement of the pull-to-refresh indicator of a [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Indicator {
+    /// The distance from the top of the [`Scrollable`] to the bottom of the
+    /// indicator.
+    distance: f32,
+
+    /// How close the pull is to the threshold, from `0.0` to `1.0`.
+    progress: f32,
+
+    /// The angle of the spinning dot, in radians.
+    angle: f32,
+}
+
+/// An ongoing smooth scroll towards some absolute offset.
+#[derive(Debug, Clone, Copy)]
+struct Animation {
+    destination: Vector,
+    last_frame: Option<Instant>,
+}
+
+/// The amount of pixels scrolled by a line when using the keyboard.
+const LINE_HEIGHT: f32 = 60.0;
+
+/// The distance a [`Scrollable`] has to be pulled past its top to refresh.
+const PULL_THRESHOLD: f32 = 64.0;
+
+/// The ratio between the distance the finger moves and the distance the
+/// pull-to-refresh indicator moves.
+const PULL_RESISTANCE: f32 = 0.5;
+
+/// The diameter of the pull-to-refresh indicator.
+const PULL_INDICATOR_SIZE: f32 = 32.0;
+
+/// The time it takes the pull-to-refresh indicator to spin once while
+/// refreshing.
+const SPIN_PERIOD: Duration = Duration::from_millis(1000);
+
+/// The time it takes a smooth scroll to cover ~63% of the remaining
+/// distance, in seconds.
+const SMOOTH_SCROLL_TIME_CONSTANT: f32 = 0.05;
+
 impl Default for State {
     fn default() -> Self {
         Self {
@@ -937,16 +1787,134 @@ impl Default for State {
             offset_x: Offset::Absolute(0.0),
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
+            is_focused: false,
+            animation: None,
+            pulled: 0.0,
+            refresh: None,
+            viewport: None,
+            anchoring: Anchoring::default(),
+            is_anchor_outdated: true,
         }
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
 impl operation::Scrollable for State {
     fn snap_to(&mut self, offset: RelativeOffset) {
         State::snap_to(self, offset);
     }
 }
 
+/// The [`State`] of a [`Scrollable`] together with its bounds, as seen by an
+/// [`Operation`].
+struct Viewport<'a> {
+    state: &'a mut State,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+}
+
+impl<'a> operation::Scrollable for Viewport<'a> {
+    fn snap_to(&mut self, offset: RelativeOffset) {
+        self.state.snap_to(offset);
+    }
+
+    fn scroll_into_view(
+        &mut self,
+        target: Rectangle,
+        margin: f32,
+    ) -> Rectangle {
+        let offset = self.state.offset(self.bounds, self.content_bounds);
+
+        // The offset along an axis that makes the target visible
+        let follow =
+            |offset: f32, start: f32, size: f32, target: (f32, f32)| {
+                let (target_start, target_end) =
+                    (target.0 - margin, target.1 + margin);
+                let (start, end) = (start + offset, start + offset + size);
+
+                if target_start >= start && target_end <= end {
+                    offset
+                } else if target_end - target_start > size {
+                    // Targets bigger than the viewport are aligned to its start,
+                    // unless they already fill it
+                    if target_start <= start && target_end >= end {
+                        offset
+                    } else {
+                        offset + target_start - start
+                    }
+                } else if target_start < start {
+                    offset + target_start - start
+                } else {
+                    offset + target_end - end
+                }
+            };
+
+        let destination = Vector::new(
+            follow(
+                offset.x,
+                self.bounds.x,
+                self.bounds.width,
+                (target.x, target.x + target.width),
+            ),
+            follow(
+                offset.y,
+                self.bounds.y,
+                self.bounds.height,
+                (target.y, target.y + target.height),
+            ),
+        );
+
+        if destination != offset {
+            self.state.animation = None;
+            self.state
+                .scroll_to(destination, self.bounds, self.content_bounds);
+        }
+
+        let offset = self.state.offset(self.bounds, self.content_bounds);
+        let visible = Rectangle {
+            x: target.x - offset.x,
+            y: target.y - offset.y,
+            ..target
+        };
+
+        visible.intersection(&self.bounds).unwrap_or(visible)
+    }
+
+    fn scroll_to_bounds(&mut self, target: Rectangle, anchor: Anchor) {
+        let destination = match anchor {
+            Anchor::Start => {
+                Vector::new(target.x - self.bounds.x, target.y - self.bounds.y)
+            }
+            Anchor::Center => Vector::new(
+                target.center_x() - self.bounds.center_x(),
+                target.center_y() - self.bounds.center_y(),
+            ),
+            Anchor::Nearest => {
+                let _ = self.scroll_into_view(target, 0.0);
+
+                return;
+            }
+        };
+
+        self.state.animation = None;
+        self.state
+            .scroll_to(destination, self.bounds, self.content_bounds);
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Offset {
     Absolute(f32),
@@ -980,6 +1948,8 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
+        self.animation = None;
+
         if bounds.height < content_bounds.height {
             self.offset_y = Offset::Absolute(
                 (self.offset_y.absolute(bounds.height, content_bounds.height)
@@ -1007,6 +1977,7 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
+        self.animation = None;
         self.offset_y = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
     }
@@ -1021,12 +1992,14 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
+        self.animation = None;
         self.offset_x = Offset::Relative(percentage.clamp(0.0, 1.0));
         self.unsnap(bounds, content_bounds);
     }
 
     /// Snaps the scroll position to a [`RelativeOffset`].
     pub fn snap_to(&mut self, offset: RelativeOffset) {
+        self.animation = None;
         self.offset_x = Offset::Relative(offset.x.clamp(0.0, 1.0));
         self.offset_y = Offset::Relative(offset.y.clamp(0.0, 1.0));
     }
@@ -1055,11 +2028,255 @@ impl State {
         )
     }
 
+    /// Returns whether the [`Scrollable`] is refreshing, after being pulled
+    /// or because it was told so.
+    pub fn is_refreshing(&self) -> bool {
+        self.refresh.is_some()
+    }
+
     /// Returns whether any scroller is currently grabbed or not.
     pub fn scrollers_grabbed(&self) -> bool {
         self.x_scroller_grabbed_at.is_some()
             || self.y_scroller_grabbed_at.is_some()
     }
+
+    /// Remembers the bounds of the [`Scrollable`] and its contents, as they
+    /// were last laid out.
+    fn remember(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
+        self.viewport = Some((bounds, content_bounds));
+    }
+
+    /// Turns the offset into a [`RelativeOffset`], given the bounds the
+    /// [`Scrollable`] and its contents were last laid out with.
+    ///
+    /// Axes where the contents fit keep their offset.
+    fn relativize(&mut self) {
+        let (bounds, content_bounds) = match self.viewport {
+            Some(viewport) => viewport,
+            None => return,
+        };
+
+        let offset = self.offset(bounds, content_bounds);
+        let max = max_offset(bounds, content_bounds);
+
+        if max.x > 0.0 {
+            self.offset_x = Offset::Relative(offset.x / max.x);
+        }
+
+        if max.y > 0.0 {
+            self.offset_y = Offset::Relative(offset.y / max.y);
+        }
+    }
+
+    /// Scrolls the [`Scrollable`] to keep the item closest to its viewport in
+    /// place, once its [`Anchoring`] has the new bounds of the items of its
+    /// contents.
+    fn anchor(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
+        if let Some((old_bounds, old_content_bounds)) = self.anchoring.viewport
+        {
+            let offset = self.offset(old_bounds, old_content_bounds);
+            let shift = self.anchoring.shift(offset, old_bounds.size());
+
+            if let Some(shift) = shift {
+                if shift != Vector::ZERO {
+                    self.scroll_to(offset + shift, bounds, content_bounds);
+
+                    if let Some(animation) = &mut self.animation {
+                        animation.destination = animation.destination + shift;
+                    }
+                }
+            }
+        }
+
+        self.anchoring.update(bounds, content_bounds);
+        self.is_anchor_outdated = false;
+    }
+
+    /// Scrolls the [`Scrollable`] to an absolute offset, given the bounds of
+    /// the [`Scrollable`] and its contents.
+    fn scroll_to(
+        &mut self,
+        offset: Vector,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let max = max_offset(bounds, content_bounds);
+
+        self.offset_x = Offset::Absolute(offset.x.clamp(0.0, max.x));
+        self.offset_y = Offset::Absolute(offset.y.clamp(0.0, max.y));
+    }
+
+    /// Pulls the [`Scrollable`] past its top by the part of the vertical
+    /// touch `delta` that it cannot scroll, returning the part that is left
+    /// to scroll.
+    ///
+    /// While pulled, moving back up reduces the pull before scrolling again.
+    fn pull(
+        &mut self,
+        delta: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> f32 {
+        let offset =
+            self.offset_y.absolute(bounds.height, content_bounds.height);
+        let pulled = (self.pulled + delta - offset).max(0.0);
+        let remaining = delta - (pulled - self.pulled);
+
+        self.pulled = pulled;
+
+        remaining
+    }
+
+    /// Releases the current pull, returning whether it was past the
+    /// threshold.
+    fn release_pull(&mut self) -> bool {
+        let distance = self.pulled * PULL_RESISTANCE;
+
+        self.pulled = 0.0;
+
+        distance >= PULL_THRESHOLD
+    }
+
+    /// Returns the pull-to-refresh [`Indicator`] to draw, if the
+    /// [`Scrollable`] is being pulled or refreshing.
+    fn pull_indicator(&self) -> Option<Indicator> {
+        if let Some(refresh) = self.refresh {
+            let elapsed =
+                refresh.now.saturating_duration_since(refresh.started_at);
+            let turns = elapsed.as_secs_f32() / SPIN_PERIOD.as_secs_f32();
+
+            return Some(Indicator {
+                distance: PULL_THRESHOLD,
+                progress: 1.0,
+                angle: turns.fract() * std::f32::consts::TAU,
+            });
+        }
+
+        if self.pulled <= 0.0 {
+            return None;
+        }
+
+        let distance =
+            (self.pulled * PULL_RESISTANCE).min(2.0 * PULL_THRESHOLD);
+        let progress = (distance / PULL_THRESHOLD).min(1.0);
+
+        Some(Indicator {
+            distance,
+            progress,
+            angle: distance / PULL_THRESHOLD * std::f32::consts::PI,
+        })
+    }
+
+    /// Returns the absolute offset that the given key press should scroll
+    /// to, if the key scrolls the [`Scrollable`] at all.
+    fn keyboard_destination(
+        &self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+        keyboard: KeyboardScroll,
+        horizontal_enabled: bool,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Option<Vector> {
+        use keyboard::KeyCode;
+
+        let max = max_offset(bounds, content_bounds);
+
+        // Chain presses onto any ongoing animation, so that holding a key
+        // keeps moving forward
+        let current = self.animation.map_or_else(
+            || self.offset(bounds, content_bounds),
+            |animation| animation.destination,
+        );
+
+        let arrows_enabled = !keyboard.is_text_input_focused;
+        let space_enabled = keyboard.space_scrolls && arrows_enabled;
+        let horizontal = horizontal_enabled && max.x > 0.0;
+        let vertical = max.y > 0.0;
+
+        let destination = match key_code {
+            KeyCode::Up if arrows_enabled && vertical => {
+                Vector::new(current.x, current.y - LINE_HEIGHT)
+            }
+            KeyCode::Down if arrows_enabled && vertical => {
+                Vector::new(current.x, current.y + LINE_HEIGHT)
+            }
+            KeyCode::Left if arrows_enabled && horizontal => {
+                Vector::new(current.x - LINE_HEIGHT, current.y)
+            }
+            KeyCode::Right if arrows_enabled && horizontal => {
+                Vector::new(current.x + LINE_HEIGHT, current.y)
+            }
+            KeyCode::PageUp if vertical => {
+                Vector::new(current.x, current.y - bounds.height)
+            }
+            KeyCode::PageDown if vertical => {
+                Vector::new(current.x, current.y + bounds.height)
+            }
+            KeyCode::Space if space_enabled && vertical => {
+                if modifiers.shift() {
+                    Vector::new(current.x, current.y - bounds.height)
+                } else {
+                    Vector::new(current.x, current.y + bounds.height)
+                }
+            }
+            KeyCode::Home if vertical => Vector::new(current.x, 0.0),
+            KeyCode::End if vertical => Vector::new(current.x, max.y),
+            _ => return None,
+        };
+
+        Some(Vector::new(
+            destination.x.clamp(0.0, max.x),
+            destination.y.clamp(0.0, max.y),
+        ))
+    }
+
+    /// Advances the ongoing smooth scroll, if any, to the given frame.
+    ///
+    /// Returns whether the offset has changed.
+    fn animate(
+        &mut self,
+        now: Instant,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> bool {
+        let animation = match self.animation {
+            Some(animation) => animation,
+            None => return false,
+        };
+
+        let elapsed = animation.last_frame.map_or(1.0 / 60.0, |last_frame| {
+            now.saturating_duration_since(last_frame).as_secs_f32()
+        });
+
+        let progress = 1.0 - (-elapsed / SMOOTH_SCROLL_TIME_CONSTANT).exp();
+        let current = self.offset(bounds, content_bounds);
+        let remaining = animation.destination - current;
+
+        if remaining.x.abs() < 0.5 && remaining.y.abs() < 0.5 {
+            self.scroll_to(animation.destination, bounds, content_bounds);
+            self.animation = None;
+        } else {
+            self.scroll_to(
+                current + remaining * progress,
+                bounds,
+                content_bounds,
+            );
+            self.animation = Some(Animation {
+                last_frame: Some(now),
+                ..animation
+            });
+        }
+
+        true
+    }
+}
+
+fn max_offset(bounds: Rectangle, content_bounds: Rectangle) -> Vector {
+    Vector::new(
+        (content_bounds.width - bounds.width).max(0.0),
+        (content_bounds.height - bounds.height).max(0.0),
+    )
 }
 
 #[derive(Debug)]
@@ -1081,18 +2298,21 @@ impl Scrollbars {
         let offset = state.offset(bounds, content_bounds);
 
         let show_scrollbar_x = horizontal.and_then(|h| {
-            if content_bounds.width > bounds.width {
+            if content_bounds.width > bounds.width || h.always_visible {
                 Some(h)
             } else {
                 None
             }
         });
 
-        let y_scrollbar = if content_bounds.height > bounds.height {
+        let y_scrollbar = if content_bounds.height > bounds.height
+            || vertical.always_visible
+        {
             let Properties {
                 width,
                 margin,
                 scroller_width,
+                ..
             } = *vertical;
 
             // Adjust the height of the vertical scrollbar if the horizontal scrollbar
@@ -1120,7 +2340,7 @@ impl Scrollbars {
                 height: (bounds.height - x_scrollbar_height).max(0.0),
             };
 
-            let ratio = bounds.height / content_bounds.height;
+            let ratio = (bounds.height / content_bounds.height).min(1.0);
             // min height for easier grabbing with super tall content
             let scroller_height = (bounds.height * ratio).max(2.0);
             let scroller_offset = offset.y * ratio;
@@ -1150,6 +2370,7 @@ impl Scrollbars {
                 width,
                 margin,
                 scroller_width,
+                ..
             } = *horizontal;
 
             // Need to adjust the width of the horizontal scrollbar if the vertical scrollbar
@@ -1178,7 +2399,7 @@ impl Scrollbars {
                 height: width as f32,
             };
 
-            let ratio = bounds.width / content_bounds.width;
+            let ratio = (bounds.width / content_bounds.width).min(1.0);
             // min width for easier grabbing with extra wide content
             let scroller_length = (bounds.width * ratio).max(2.0);
             let scroller_offset = offset.x * ratio;
@@ -1211,22 +2432,24 @@ impl Scrollbars {
         }
     }
 
-    fn is_mouse_over(&self, cursor_position: Point) -> (bool, bool) {
+    fn is_mouse_over(&self, cursor: mouse::Cursor) -> (bool, bool) {
         (
             self.y
                 .as_ref()
-                .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+                .map(|scrollbar| scrollbar.is_mouse_over(cursor))
                 .unwrap_or(false),
             self.x
                 .as_ref()
-                .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+                .map(|scrollbar| scrollbar.is_mouse_over(cursor))
                 .unwrap_or(false),
         )
     }
 
-    fn grab_y_scroller(&self, cursor_position: Point) -> Option<f32> {
+    fn grab_y_scroller(&self, cursor: mouse::Cursor) -> Option<f32> {
         self.y.and_then(|scrollbar| {
-            if scrollbar.total_bounds.contains(cursor_position) {
+            if let Some(cursor_position) =
+                cursor.position_over(&scrollbar.total_bounds)
+            {
                 Some(if scrollbar.scroller.bounds.contains(cursor_position) {
                     (cursor_position.y - scrollbar.scroller.bounds.y)
                         / scrollbar.scroller.bounds.height
@@ -1239,9 +2462,11 @@ impl Scrollbars {
         })
     }
 
-    fn grab_x_scroller(&self, cursor_position: Point) -> Option<f32> {
+    fn grab_x_scroller(&self, cursor: mouse::Cursor) -> Option<f32> {
         self.x.and_then(|scrollbar| {
-            if scrollbar.total_bounds.contains(cursor_position) {
+            if let Some(cursor_position) =
+                cursor.position_over(&scrollbar.total_bounds)
+            {
                 Some(if scrollbar.scroller.bounds.contains(cursor_position) {
                     (cursor_position.x - scrollbar.scroller.bounds.x)
                         / scrollbar.scroller.bounds.width
@@ -1275,25 +2500,27 @@ struct Scrollbar {
 
 impl Scrollbar {
     /// Returns whether the mouse is over the scrollbar or not.
-    fn is_mouse_over(&self, cursor_position: Point) -> bool {
-        self.total_bounds.contains(cursor_position)
+    fn is_mouse_over(&self, cursor: mouse::Cursor) -> bool {
+        cursor.is_over(&self.total_bounds)
     }
 
     /// Returns the y-axis scrolled percentage from the cursor position.
     fn scroll_percentage_y(
         &self,
         grabbed_at: f32,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> f32 {
-        if cursor_position.x < 0.0 && cursor_position.y < 0.0 {
-            // cursor position is unavailable! Set to either end or beginning of scrollbar depending
-            // on where the thumb currently is in the track
-            (self.scroller.bounds.y / self.total_bounds.height).round()
-        } else {
-            (cursor_position.y
-                - self.bounds.y
-                - self.scroller.bounds.height * grabbed_at)
-                / (self.bounds.height - self.scroller.bounds.height)
+        match cursor.position() {
+            Some(cursor_position) => {
+                (cursor_position.y
+                    - self.bounds.y
+                    - self.scroller.bounds.height * grabbed_at)
+                    / (self.bounds.height - self.scroller.bounds.height)
+            }
+            // The cursor is unavailable! Set to either end or beginning of
+            // the scrollbar depending on where the thumb currently is in the
+            // track
+            None => (self.scroller.bounds.y / self.total_bounds.height).round(),
         }
     }
 
@@ -1301,15 +2528,16 @@ impl Scrollbar {
     fn scroll_percentage_x(
         &self,
         grabbed_at: f32,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> f32 {
-        if cursor_position.x < 0.0 && cursor_position.y < 0.0 {
-            (self.scroller.bounds.x / self.total_bounds.width).round()
-        } else {
-            (cursor_position.x
-                - self.bounds.x
-                - self.scroller.bounds.width * grabbed_at)
-                / (self.bounds.width - self.scroller.bounds.width)
+        match cursor.position() {
+            Some(cursor_position) => {
+                (cursor_position.x
+                    - self.bounds.x
+                    - self.scroller.bounds.width * grabbed_at)
+                    / (self.bounds.width - self.scroller.bounds.width)
+            }
+            None => (self.scroller.bounds.x / self.total_bounds.width).round(),
         }
     }
 }
@@ -1320,3 +2548,815 @@ struct Scroller {
     /// The bounds of the [`Scroller`].
     bounds: Rectangle,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::time::Duration;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    const CONTENT_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 1000.0,
+    };
+
+    fn destination(
+        state: &State,
+        key_code: keyboard::KeyCode,
+        keyboard: KeyboardScroll,
+    ) -> Option<Vector> {
+        state.keyboard_destination(
+            key_code,
+            keyboard::Modifiers::default(),
+            keyboard,
+            false,
+            BOUNDS,
+            CONTENT_BOUNDS,
+        )
+    }
+
+    #[test]
+    fn keys_scroll_by_lines_pages_and_extremes() {
+        use keyboard::KeyCode;
+
+        let keyboard = KeyboardScroll::default();
+        let mut state = State::new();
+
+        assert_eq!(
+            destination(&state, KeyCode::Down, keyboard),
+            Some(Vector::new(0.0, LINE_HEIGHT))
+        );
+        assert_eq!(
+            destination(&state, KeyCode::PageDown, keyboard),
+            Some(Vector::new(0.0, 100.0))
+        );
+        assert_eq!(
+            destination(&state, KeyCode::End, keyboard),
+            Some(Vector::new(0.0, 900.0))
+        );
+        assert_eq!(
+            destination(&state, KeyCode::Up, keyboard),
+            Some(Vector::new(0.0, 0.0))
+        );
+        assert_eq!(destination(&state, KeyCode::Left, keyboard), None);
+        assert_eq!(destination(&state, KeyCode::Space, keyboard), None);
+
+        state.scroll_to(Vector::new(0.0, 880.0), BOUNDS, CONTENT_BOUNDS);
+
+        assert_eq!(
+            destination(&state, KeyCode::PageDown, keyboard),
+            Some(Vector::new(0.0, 900.0))
+        );
+        assert_eq!(
+            destination(&state, KeyCode::Home, keyboard),
+            Some(Vector::new(0.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn focused_text_input_keeps_arrows_and_space() {
+        use keyboard::KeyCode;
+
+        let state = State::new();
+
+        let keyboard = KeyboardScroll {
+            space_scrolls: true,
+            ..KeyboardScroll::default()
+        };
+
+        assert_eq!(
+            destination(&state, KeyCode::Space, keyboard),
+            Some(Vector::new(0.0, 100.0))
+        );
+
+        let keyboard = KeyboardScroll {
+            is_text_input_focused: true,
+            ..keyboard
+        };
+
+        assert_eq!(destination(&state, KeyCode::Down, keyboard), None);
+        assert_eq!(destination(&state, KeyCode::Space, keyboard), None);
+        assert_eq!(
+            destination(&state, KeyCode::PageDown, keyboard),
+            Some(Vector::new(0.0, 100.0))
+        );
+    }
+
+    #[test]
+    fn overscroll_bubbles_at_the_boundary() {
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let mut state = State::new();
+
+        state.scroll_to(Vector::new(0.0, 850.0), BOUNDS, CONTENT_BOUNDS);
+
+        let status = scroll_wheel(
+            &mut state,
+            Vector::new(0.0, -100.0),
+            Overscroll::Bubble,
+            BOUNDS,
+            CONTENT_BOUNDS,
+            None,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS).y, 900.0);
+        assert_eq!(shell.take_overscroll(), Some(Vector::new(0.0, -50.0)));
+
+        let status = scroll_wheel(
+            &mut state,
+            Vector::new(0.0, -100.0),
+            Overscroll::Bubble,
+            BOUNDS,
+            CONTENT_BOUNDS,
+            None,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Ignored);
+        assert_eq!(shell.take_overscroll(), None);
+
+        // The contents fit horizontally, so horizontal scrolling bubbles up
+        let status = scroll_wheel(
+            &mut state,
+            Vector::new(-30.0, 10.0),
+            Overscroll::Bubble,
+            BOUNDS,
+            CONTENT_BOUNDS,
+            None,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS).y, 890.0);
+        assert_eq!(shell.take_overscroll(), Some(Vector::new(-30.0, 0.0)));
+    }
+
+    #[test]
+    fn overscroll_is_contained_by_default() {
+        let mut messages: Vec<()> = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+        let mut state = State::new();
+
+        state.scroll_to(Vector::new(0.0, 900.0), BOUNDS, CONTENT_BOUNDS);
+
+        let status = scroll_wheel(
+            &mut state,
+            Vector::new(0.0, -100.0),
+            Overscroll::Contain,
+            BOUNDS,
+            CONTENT_BOUNDS,
+            None,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(shell.take_overscroll(), None);
+
+        // Even scrolling along an axis that cannot scroll is consumed
+        let status = scroll_wheel(
+            &mut state,
+            Vector::new(-30.0, 10.0),
+            Overscroll::default(),
+            BOUNDS,
+            CONTENT_BOUNDS,
+            None,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS).y, 890.0);
+        assert_eq!(shell.take_overscroll(), None);
+    }
+
+    #[test]
+    fn smooth_scrolling_reaches_its_destination() {
+        let mut state = State::new();
+        let mut now = Instant::now();
+
+        state.animation = Some(Animation {
+            destination: Vector::new(0.0, 500.0),
+            last_frame: None,
+        });
+
+        assert!(state.animate(now, BOUNDS, CONTENT_BOUNDS));
+
+        let first_frame = state.offset(BOUNDS, CONTENT_BOUNDS).y;
+        assert!(first_frame > 0.0 && first_frame < 500.0);
+
+        while state.animation.is_some() {
+            now += Duration::from_millis(16);
+            assert!(state.animate(now, BOUNDS, CONTENT_BOUNDS));
+        }
+
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS).y, 500.0);
+        assert!(!state.animate(now, BOUNDS, CONTENT_BOUNDS));
+    }
+
+    #[test]
+    fn scroll_into_view_scrolls_the_least_amount() {
+        use operation::Scrollable as _;
+
+        let mut state = State::new();
+
+        let mut viewport = Viewport {
+            state: &mut state,
+            bounds: BOUNDS,
+            content_bounds: CONTENT_BOUNDS,
+        };
+
+        let target = Rectangle {
+            x: 0.0,
+            y: 500.0,
+            width: 50.0,
+            height: 20.0,
+        };
+
+        let visible = viewport.scroll_into_view(target, 10.0);
+
+        assert_eq!(viewport.state.offset(BOUNDS, CONTENT_BOUNDS).y, 430.0);
+        assert_eq!(visible.y, 70.0);
+
+        // Visible targets do not scroll
+        let _ =
+            viewport.scroll_into_view(Rectangle { y: 450.0, ..target }, 0.0);
+
+        assert_eq!(viewport.state.offset(BOUNDS, CONTENT_BOUNDS).y, 430.0);
+    }
+
+    #[test]
+    fn scroll_to_widget_adjusts_nested_scrollables() {
+        use crate::renderer::Null;
+        use crate::widget::{Column, Space, TextInput};
+
+        let target = crate::widget::text_input::Id::new("target");
+
+        let inner = Scrollable::new(
+            Column::new()
+                .push(Space::with_height(Length::Units(300)))
+                .push(TextInput::new("", "", |_| ()).id(target.clone())),
+        )
+        .height(Length::Units(100));
+
+        let outer: Element<'_, (), Null> = Scrollable::new(
+            Column::new()
+                .push(Space::with_height(Length::Units(500)))
+                .push(inner),
+        )
+        .height(Length::Units(100))
+        .into();
+
+        let renderer = Null::new();
+        let mut tree = Tree::new(&outer);
+        let node = outer.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let operate = |tree: &mut Tree| {
+            let mut operation = operation::scrollable::scroll_to_widget(
+                target.clone().into(),
+                0.0,
+            );
+
+            outer.as_widget().operate(
+                tree,
+                Layout::new(&node),
+                &renderer,
+                &mut operation,
+            );
+        };
+
+        let offsets = |tree: &Tree| {
+            let outer = tree.state.downcast_ref::<State>();
+            let inner =
+                tree.children[0].children[1].state.downcast_ref::<State>();
+
+            let outer_layout = Layout::new(&node);
+            let outer_content = outer_layout.children().next().unwrap();
+            let inner_layout = outer_content.children().nth(1).unwrap();
+            let inner_content = inner_layout.children().next().unwrap();
+
+            (
+                outer
+                    .offset(outer_layout.bounds(), outer_content.bounds())
+                    .y,
+                inner
+                    .offset(inner_layout.bounds(), inner_content.bounds())
+                    .y,
+            )
+        };
+
+        operate(&mut tree);
+
+        let (outer_offset, inner_offset) = offsets(&tree);
+
+        assert!(inner_offset > 0.0);
+        assert!(outer_offset > 0.0);
+
+        // Scrolling to a visible widget does nothing
+        operate(&mut tree);
+
+        assert_eq!(offsets(&tree), (outer_offset, inner_offset));
+    }
+
+    fn list() -> Element<'static, (), crate::renderer::Null> {
+        use crate::widget::{container, Column, Container, Space};
+
+        Scrollable::new(Column::with_children(
+            (0..20)
+                .map(|i| {
+                    Container::new(Space::with_height(Length::Units(50)))
+                        .id(container::Id::new(format!("item-{i}")))
+                        .into()
+                })
+                .collect(),
+        ))
+        .id(Id::new("list"))
+        .height(Length::Units(100))
+        .into()
+    }
+
+    fn scroll_to_item_offset(
+        element: &Element<'_, (), crate::renderer::Null>,
+        item: &str,
+        anchor: Anchor,
+    ) -> f32 {
+        let renderer = crate::renderer::Null::new();
+        let mut tree = Tree::new(element);
+        let node = element.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let mut operation = operation::scrollable::scroll_to_item(
+            Id::new("list").0,
+            widget::Id::new(item.to_owned()),
+            anchor,
+        );
+
+        element.as_widget().operate(
+            &mut tree,
+            Layout::new(&node),
+            &renderer,
+            &mut operation,
+        );
+
+        let layout = Layout::new(&node);
+        let content = layout.children().next().unwrap();
+
+        tree.state
+            .downcast_ref::<State>()
+            .offset(layout.bounds(), content.bounds())
+            .y
+    }
+
+    #[test]
+    fn scroll_to_item_places_the_item_by_anchor() {
+        let list = list();
+
+        assert_eq!(
+            scroll_to_item_offset(&list, "item-10", Anchor::Start),
+            500.0
+        );
+        assert_eq!(
+            scroll_to_item_offset(&list, "item-10", Anchor::Center),
+            475.0
+        );
+        assert_eq!(
+            scroll_to_item_offset(&list, "item-10", Anchor::Nearest),
+            450.0
+        );
+        assert_eq!(
+            scroll_to_item_offset(&list, "item-1", Anchor::Nearest),
+            0.0
+        );
+
+        // The offset is clamped to the content
+        assert_eq!(
+            scroll_to_item_offset(&list, "item-19", Anchor::Start),
+            900.0
+        );
+
+        // Unknown items do not scroll
+        assert_eq!(scroll_to_item_offset(&list, "missing", Anchor::Start), 0.0);
+    }
+
+    #[test]
+    fn scroll_to_item_uses_estimated_bounds() {
+        use crate::renderer::Null;
+
+        /// A list that only reports the estimated bounds of its items.
+        struct Estimated;
+
+        impl Widget<(), Null> for Estimated {
+            fn width(&self) -> Length {
+                Length::Fill
+            }
+
+            fn height(&self) -> Length {
+                Length::Units(1000)
+            }
+
+            fn layout(
+                &self,
+                _renderer: &Null,
+                limits: &layout::Limits,
+            ) -> layout::Node {
+                layout::Node::new(limits.height(Length::Units(1000)).max())
+            }
+
+            fn operate(
+                &self,
+                _tree: &mut Tree,
+                layout: Layout<'_>,
+                _renderer: &Null,
+                operation: &mut dyn Operation<()>,
+            ) {
+                if let Some(target) = operation.target().cloned() {
+                    let bounds = layout.bounds();
+
+                    operation.bounds(
+                        Rectangle {
+                            y: bounds.y + 600.0,
+                            height: 50.0,
+                            ..bounds
+                        },
+                        Some(&target),
+                    );
+                }
+            }
+
+            fn draw(
+                &self,
+                _tree: &Tree,
+                _renderer: &mut Null,
+                _theme: &<Null as crate::Renderer>::Theme,
+                _style: &renderer::Style,
+                _layout: Layout<'_>,
+                _cursor: mouse::Cursor,
+                _viewport: &Rectangle,
+            ) {
+            }
+        }
+
+        let list: Element<'_, (), Null> =
+            Scrollable::new(Element::new(Estimated))
+                .id(Id::new("list"))
+                .height(Length::Units(100))
+                .into();
+
+        assert_eq!(
+            scroll_to_item_offset(&list, "item-12", Anchor::Start),
+            600.0
+        );
+
+        // Other scrollables are left alone
+        let other: Element<'_, (), Null> =
+            Scrollable::new(Element::new(Estimated))
+                .id(Id::new("other"))
+                .height(Length::Units(100))
+                .into();
+
+        assert_eq!(
+            scroll_to_item_offset(&other, "item-12", Anchor::Start),
+            0.0
+        );
+    }
+
+    /// Drags a [`Scrollable`] down with touch by the given distances, then
+    /// lifts the finger, returning the published messages.
+    fn drag(
+        scrollable: &mut Element<'_, (), crate::renderer::Null>,
+        tree: &mut Tree,
+        distances: &[f32],
+    ) -> Vec<()> {
+        let renderer = crate::renderer::Null::new();
+        let node = scrollable.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let finger = touch::Finger(0);
+        let start = Point::new(50.0, 10.0);
+
+        let events = std::iter::once((
+            touch::Event::FingerPressed {
+                id: finger,
+                position: start,
+            },
+            start,
+        ))
+        .chain(distances.iter().map(|distance| {
+            let position = Point::new(start.x, start.y + distance);
+
+            (
+                touch::Event::FingerMoved {
+                    id: finger,
+                    position,
+                },
+                position,
+            )
+        }))
+        .chain(std::iter::once((
+            touch::Event::FingerLifted {
+                id: finger,
+                position: start,
+            },
+            start,
+        )));
+
+        let mut messages = Vec::new();
+
+        for (event, position) in events {
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = scrollable.as_widget_mut().on_event(
+                tree,
+                Event::Touch(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+        }
+
+        messages
+    }
+
+    fn refreshable(
+        is_refreshing: bool,
+    ) -> Element<'static, (), crate::renderer::Null> {
+        use crate::widget::Space;
+
+        Scrollable::new(Space::new(Length::Fill, Length::Units(1000)))
+            .height(Length::Units(100))
+            .on_refresh(())
+            .refreshing(is_refreshing)
+            .into()
+    }
+
+    #[test]
+    fn pulling_past_the_threshold_refreshes() {
+        let mut scrollable = refreshable(false);
+        let mut tree = Tree::new(&scrollable);
+
+        // Not far enough
+        assert!(drag(&mut scrollable, &mut tree, &[40.0, 80.0]).is_empty());
+        assert!(!tree.state.downcast_ref::<State>().is_refreshing());
+
+        assert_eq!(drag(&mut scrollable, &mut tree, &[80.0, 160.0]), vec![()]);
+
+        let state = tree.state.downcast_ref::<State>();
+
+        assert!(state.is_refreshing());
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS).y, 0.0);
+        assert_eq!(
+            state.pull_indicator().map(|indicator| indicator.distance),
+            Some(PULL_THRESHOLD)
+        );
+
+        // The indicator stays pinned while refreshing
+        assert!(drag(&mut scrollable, &mut tree, &[200.0]).is_empty());
+        assert!(tree.state.downcast_ref::<State>().is_refreshing());
+
+        // Until the application is done
+        tree.diff(&refreshable(true));
+        assert!(tree.state.downcast_ref::<State>().is_refreshing());
+
+        tree.diff(&refreshable(false));
+        assert!(!tree.state.downcast_ref::<State>().is_refreshing());
+    }
+
+    #[test]
+    fn touches_outside_are_only_handled_while_pulling() {
+        let renderer = crate::renderer::Null::new();
+        let finger = touch::Finger(0);
+
+        let touch = |scrollable: &mut Element<'_, (), _>,
+                     tree: &mut Tree,
+                     event: fn(touch::Finger, Point) -> touch::Event,
+                     y: f32| {
+            let node = scrollable.as_widget().layout(
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            let position = Point::new(50.0, y);
+            let mut messages = Vec::new();
+
+            scrollable.as_widget_mut().on_event(
+                tree,
+                Event::Touch(event(finger, position)),
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut Shell::new(&mut messages),
+            )
+        };
+
+        let pressed =
+            |id, position| touch::Event::FingerPressed { id, position };
+        let moved = |id, position| touch::Event::FingerMoved { id, position };
+
+        let mut scrollable = refreshable(false);
+        let mut tree = Tree::new(&scrollable);
+
+        let _ = touch(&mut scrollable, &mut tree, pressed, 10.0);
+        let _ = touch(&mut scrollable, &mut tree, moved, 90.0);
+
+        // Pulling past the bottom edge keeps the pull going
+        assert_eq!(
+            touch(&mut scrollable, &mut tree, moved, 150.0),
+            event::Status::Captured
+        );
+
+        tree.state.downcast_mut::<State>().pulled = 0.0;
+        tree.state.downcast_mut::<State>().scroll_to(
+            Vector::new(0.0, 500.0),
+            BOUNDS,
+            CONTENT_BOUNDS,
+        );
+
+        let _ = touch(&mut scrollable, &mut tree, pressed, 50.0);
+
+        // A touch that is only scrolling is left alone once outside
+        assert_eq!(
+            touch(&mut scrollable, &mut tree, moved, 150.0),
+            event::Status::Ignored
+        );
+    }
+
+    #[test]
+    fn fill_contents_are_only_fitted_when_asked() {
+        use crate::widget::Space;
+
+        let content_width = |horizontal: Properties| {
+            let scrollable: Element<'static, (), crate::renderer::Null> =
+                Scrollable::new(Space::new(Length::Fill, Length::Units(50)))
+                    .horizontal_scroll(horizontal)
+                    .into();
+
+            let node = scrollable.as_widget().layout(
+                &crate::renderer::Null::new(),
+                &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            );
+
+            node.children()[0].size().width
+        };
+
+        assert!(content_width(Properties::new()).is_infinite());
+        assert_eq!(content_width(Properties::new().fit_fill(true)), 100.0);
+    }
+
+    #[test]
+    fn pulling_back_up_scrolls_again() {
+        let mut state = State::new();
+
+        assert_eq!(state.pull(30.0, BOUNDS, CONTENT_BOUNDS), 0.0);
+        assert_eq!(state.pull(-50.0, BOUNDS, CONTENT_BOUNDS), -20.0);
+        assert!(state.pull_indicator().is_none());
+
+        state.scroll(Vector::new(0.0, -20.0), BOUNDS, CONTENT_BOUNDS);
+
+        // Scrolling back to the top comes first
+        assert_eq!(state.pull(50.0, BOUNDS, CONTENT_BOUNDS), 20.0);
+        assert!(state.pull_indicator().is_some());
+        assert!(!state.release_pull());
+    }
+
+    fn preserving(
+        ids: impl Iterator<Item = i32>,
+        preserve: Preserve,
+    ) -> Element<'static, (), crate::renderer::Null> {
+        use crate::widget::{container, Column, Container, Space};
+
+        Scrollable::new(Column::with_children(
+            ids.map(|id| {
+                Container::new(Space::with_height(Length::Units(50)))
+                    .id(container::Id::new(format!("item-{id}")))
+                    .into()
+            })
+            .collect(),
+        ))
+        .height(Length::Units(100))
+        .preserve(preserve)
+        .into()
+    }
+
+    /// Rebuilds the `tree` with the given `element` and redraws it, returning
+    /// the vertical offset afterwards.
+    fn rebuild(
+        element: &mut Element<'static, (), crate::renderer::Null>,
+        tree: &mut Tree,
+    ) -> f32 {
+        use crate::clipboard;
+
+        let renderer = crate::renderer::Null::new();
+
+        tree.diff(&*element);
+
+        let node = element.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = element.as_widget_mut().on_event(
+            tree,
+            Event::Window(window::Event::RedrawRequested(Instant::now())),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &renderer,
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        let layout = Layout::new(&node);
+        let content = layout.children().next().unwrap();
+
+        tree.state
+            .downcast_ref::<State>()
+            .offset(layout.bounds(), content.bounds())
+            .y
+    }
+
+    fn scroll_y(tree: &mut Tree, offset: f32, items: usize) {
+        tree.state.downcast_mut::<State>().scroll_to(
+            Vector::new(0.0, offset),
+            BOUNDS,
+            Rectangle {
+                height: 50.0 * items as f32,
+                ..CONTENT_BOUNDS
+            },
+        );
+    }
+
+    #[test]
+    fn preserving_the_offset_keeps_pixels_by_default() {
+        let mut list = preserving(0..10, Preserve::default());
+        let mut tree = Tree::new(&list);
+
+        assert_eq!(rebuild(&mut list, &mut tree), 0.0);
+
+        scroll_y(&mut tree, 260.0, 10);
+
+        let mut list = preserving(-2..10, Preserve::default());
+        assert_eq!(rebuild(&mut list, &mut tree), 260.0);
+
+        // Shrinking contents clamp the offset
+        let mut list = preserving(0..4, Preserve::default());
+        assert_eq!(rebuild(&mut list, &mut tree), 100.0);
+    }
+
+    #[test]
+    fn preserving_the_relative_offset_sticks_to_the_end() {
+        let mut list = preserving(0..10, Preserve::RelativeOffset);
+        let mut tree = Tree::new(&list);
+
+        let _ = rebuild(&mut list, &mut tree);
+
+        scroll_y(&mut tree, 400.0, 10);
+
+        let mut list = preserving(0..20, Preserve::RelativeOffset);
+        assert_eq!(rebuild(&mut list, &mut tree), 900.0);
+    }
+
+    #[test]
+    fn preserving_an_item_keeps_it_in_place() {
+        let mut list = preserving(0..10, Preserve::Item);
+        let mut tree = Tree::new(&list);
+
+        let _ = rebuild(&mut list, &mut tree);
+
+        // `item-5` is the closest to the top of the viewport
+        scroll_y(&mut tree, 260.0, 10);
+
+        let mut list = preserving(-2..10, Preserve::Item);
+        assert_eq!(rebuild(&mut list, &mut tree), 360.0);
+
+        // Unchanged contents stay put
+        assert_eq!(rebuild(&mut list, &mut tree), 360.0);
+
+        // Without `item-5`, the nearest remaining item is kept in place
+        let mut list = preserving(
+            (-2..10).filter(|id| *id != 4 && *id != 5),
+            Preserve::Item,
+        );
+        assert_eq!(rebuild(&mut list, &mut tree), 260.0);
+    }
+}
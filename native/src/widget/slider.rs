@@ -156,7 +156,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::with_debug(State::new())
     }
 
     fn width(&self) -> Length {
@@ -185,7 +185,7 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
@@ -193,7 +193,7 @@ where
         update(
             event,
             layout,
-            cursor_position,
+            cursor,
             shell,
             tree.state.downcast_mut::<State>(),
             &mut self.value,
@@ -211,13 +211,13 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         draw(
             renderer,
             layout,
-            cursor_position,
+            cursor,
             tree.state.downcast_ref::<State>(),
             self.value,
             &self.range,
@@ -230,15 +230,11 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(
-            layout,
-            cursor_position,
-            tree.state.downcast_ref::<State>(),
-        )
+        mouse_interaction(layout, cursor, tree.state.downcast_ref::<State>())
     }
 }
 
@@ -262,7 +258,7 @@ where
 pub fn update<Message, T>(
     event: Event,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     shell: &mut Shell<'_, Message>,
     state: &mut State,
     value: &mut T,
@@ -277,7 +273,7 @@ where
 {
     let is_dragging = state.is_dragging;
 
-    let mut change = || {
+    let mut change = |cursor_position: Point| {
         let bounds = layout.bounds();
         let new_value = if cursor_position.x <= bounds.x {
             *range.start()
@@ -311,8 +307,10 @@ where
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            if layout.bounds().contains(cursor_position) {
-                change();
+            if let Some(cursor_position) =
+                cursor.position_over(&layout.bounds())
+            {
+                change(cursor_position);
                 state.is_dragging = true;
 
                 return event::Status::Captured;
@@ -333,7 +331,9 @@ where
         Event::Mouse(mouse::Event::CursorMoved { .. })
         | Event::Touch(touch::Event::FingerMoved { .. }) => {
             if is_dragging {
-                change();
+                if let Some(cursor_position) = cursor.position() {
+                    change(cursor_position);
+                }
 
                 return event::Status::Captured;
             }
@@ -348,7 +348,7 @@ where
 pub fn draw<T, R>(
     renderer: &mut R,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     state: &State,
     value: T,
     range: &RangeInclusive<T>,
@@ -360,7 +360,7 @@ pub fn draw<T, R>(
     R::Theme: StyleSheet,
 {
     let bounds = layout.bounds();
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&bounds);
 
     let style = if state.is_dragging {
         style_sheet.dragging(style)
@@ -446,11 +446,11 @@ pub fn draw<T, R>(
 /// Computes the current [`mouse::Interaction`] of a [`Slider`].
 pub fn mouse_interaction(
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     state: &State,
 ) -> mouse::Interaction {
     let bounds = layout.bounds();
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&bounds);
 
     if state.is_dragging {
         mouse::Interaction::Grabbing
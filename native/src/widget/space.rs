@@ -1,8 +1,9 @@
 //! Distribute content vertically.
 use crate::layout;
+use crate::mouse;
 use crate::renderer;
 use crate::widget::Tree;
-use crate::{Element, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::{Element, Layout, Length, Rectangle, Size, Widget};
 
 /// An amount of empty space.
 ///
@@ -65,7 +66,7 @@ where
         _theme: &Renderer::Theme,
         _style: &renderer::Style,
         _layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
     }
@@ -0,0 +1,442 @@
+//! Show an animated indicator while something is in progress.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::window;
+use crate::{
+    Clipboard, Color, Element, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+use iced_style::transition::Interpolate;
+
+use std::cell::Cell;
+use std::f32::consts::PI;
+
+pub use iced_style::spinner::{Appearance, StyleSheet};
+
+/// The amount of dots of a [`Spinner`].
+const DOTS: usize = 8;
+
+/// The amount of dots trailing the leading dot of a [`Spinner`].
+const TRAIL: usize = 3;
+
+/// A ring of dots that cycles to show that something is in progress.
+///
+/// A [`Spinner`] requests a new frame every time it is drawn, but only while
+/// it is visible. It stops animating when it is scrolled out of sight, for
+/// instance. Since every spinner requests the very next frame, any amount of
+/// them can be on screen without redrawing more often than a single one.
+///
+/// # Example
+/// ```
+/// # type Spinner = iced_native::widget::Spinner<iced_native::renderer::Null>;
+/// use iced_native::time::Duration;
+///
+/// let spinner = Spinner::new()
+///     .size(32)
+///     .cycle_duration(Duration::from_millis(800));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    size: u16,
+    cycle_duration: Duration,
+    is_motion_reduced: bool,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Renderer> Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default size of a [`Spinner`].
+    pub const DEFAULT_SIZE: u16 = 24;
+
+    /// The default duration of a full cycle of a [`Spinner`].
+    pub const DEFAULT_CYCLE_DURATION: Duration = Duration::from_secs(1);
+
+    /// Creates a new [`Spinner`].
+    pub fn new() -> Self {
+        Spinner {
+            size: Self::DEFAULT_SIZE,
+            cycle_duration: Self::DEFAULT_CYCLE_DURATION,
+            is_motion_reduced: false,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the size of the [`Spinner`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the duration of a full cycle of the [`Spinner`].
+    ///
+    /// The shorter the duration, the faster the [`Spinner`] spins.
+    pub fn cycle_duration(mut self, cycle_duration: Duration) -> Self {
+        self.cycle_duration = cycle_duration;
+        self
+    }
+
+    /// Sets whether the [`Spinner`] should avoid motion.
+    ///
+    /// A [`Spinner`] with reduced motion is drawn as a static ring and never
+    /// requests any frames. The runtime does not know about the accessibility
+    /// preferences of the platform, so it is up to the application to honor
+    /// them.
+    pub fn reduce_motion(mut self, is_motion_reduced: bool) -> Self {
+        self.is_motion_reduced = is_motion_reduced;
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Renderer> Default for Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Spinner<Renderer>
+where
+    Renderer: crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let size = Length::Units(self.size);
+
+        layout::Node::new(limits.width(size).height(size).resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if self.is_motion_reduced {
+            return event::Status::Ignored;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let _ = state.started_at.get_or_insert(now);
+                state.now = Some(now);
+
+                if state.is_visible.get() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. })
+            | Event::Window(window::Event::Resized { .. }) => {
+                // Scrolling or resizing may bring the spinner back into view,
+                // so it is drawn again to find out
+                if !state.is_visible.get() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+
+        state
+            .is_visible
+            .set(bounds.intersection(viewport).is_some());
+
+        let appearance = theme.appearance(&self.style);
+
+        let head = if self.is_motion_reduced {
+            None
+        } else {
+            Some(state.head(self.cycle_duration))
+        };
+
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let dot_radius = radius / 4.0;
+        let center = bounds.center();
+
+        for dot in 0..DOTS {
+            let angle = dot as f32 / DOTS as f32 * 2.0 * PI - PI / 2.0;
+
+            let color = match head {
+                Some(head) => dot_color(appearance, (head + DOTS - dot) % DOTS),
+                None => appearance.color,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x + (radius - dot_radius) * angle.cos()
+                            - dot_radius,
+                        y: center.y + (radius - dot_radius) * angle.sin()
+                            - dot_radius,
+                        width: dot_radius * 2.0,
+                        height: dot_radius * 2.0,
+                    },
+                    border_radius: dot_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                color,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Spinner<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(spinner: Spinner<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(spinner)
+    }
+}
+
+/// Returns the [`Color`] of a dot that is the given `distance` behind the
+/// leading dot of a [`Spinner`].
+fn dot_color(appearance: Appearance, distance: usize) -> Color {
+    if distance < TRAIL {
+        appearance
+            .color
+            .interpolate(appearance.track_color, distance as f32 / TRAIL as f32)
+    } else {
+        appearance.track_color
+    }
+}
+
+/// The local state of a [`Spinner`].
+#[derive(Debug)]
+struct State {
+    started_at: Option<Instant>,
+    now: Option<Instant>,
+    is_visible: Cell<bool>,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            started_at: None,
+            now: None,
+            // A new spinner asks for a frame until it has been drawn once
+            is_visible: Cell::new(true),
+        }
+    }
+
+    /// Returns the index of the leading dot of the [`Spinner`].
+    fn head(&self, cycle_duration: Duration) -> usize {
+        match (self.started_at, self.now) {
+            (Some(started_at), Some(now)) if !cycle_duration.is_zero() => {
+                let progress = (now - started_at).as_secs_f32()
+                    / cycle_duration.as_secs_f32();
+
+                (progress.fract() * DOTS as f32) as usize % DOTS
+            }
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::Point;
+
+    fn redraw(
+        spinner: &mut Spinner<Null>,
+        tree: &mut Tree,
+        event: Event,
+    ) -> Option<window::RedrawRequest> {
+        let node = Widget::<(), Null>::layout(
+            spinner,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = Widget::<(), Null>::on_event(
+            spinner,
+            tree,
+            event,
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &Null::new(),
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        shell.redraw_request()
+    }
+
+    fn appearance(_theme: &crate::Theme) -> Appearance {
+        Appearance {
+            color: Color::BLACK,
+            track_color: Color::WHITE,
+        }
+    }
+
+    fn draw(spinner: &Spinner<Null>, tree: &Tree, viewport: Rectangle) {
+        let node = layout::Node::new(Size::new(24.0, 24.0));
+
+        Widget::<(), Null>::draw(
+            spinner,
+            tree,
+            &mut Null::new(),
+            &crate::Theme::Light,
+            &renderer::Style::default(),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &viewport,
+        );
+    }
+
+    #[test]
+    fn spinners_only_animate_while_visible() {
+        let mut spinner =
+            Spinner::new().style(appearance as fn(&crate::Theme) -> Appearance);
+        let mut tree = Tree::new(&Element::<(), Null>::from(Spinner::new()));
+
+        let frame =
+            Event::Window(window::Event::RedrawRequested(Instant::now()));
+
+        assert_eq!(
+            redraw(&mut spinner, &mut tree, frame.clone()),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        draw(
+            &spinner,
+            &tree,
+            Rectangle::new(Point::new(0.0, 100.0), Size::new(100.0, 100.0)),
+        );
+
+        assert_eq!(redraw(&mut spinner, &mut tree, frame.clone()), None);
+
+        let cursor_moved = Event::Mouse(crate::mouse::Event::CursorMoved {
+            position: Point::ORIGIN,
+        });
+
+        assert_eq!(redraw(&mut spinner, &mut tree, cursor_moved), None);
+
+        // Scrolling may reveal the spinner, so it checks again
+        let scroll = Event::Mouse(crate::mouse::Event::WheelScrolled {
+            delta: crate::mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+        });
+
+        assert_eq!(
+            redraw(&mut spinner, &mut tree, scroll),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        draw(
+            &spinner,
+            &tree,
+            Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+        );
+
+        assert_eq!(
+            redraw(&mut spinner, &mut tree, frame),
+            Some(window::RedrawRequest::NextFrame)
+        );
+    }
+
+    #[test]
+    fn spinners_with_reduced_motion_never_request_frames() {
+        let mut spinner = Spinner::new().reduce_motion(true);
+        let mut tree = Tree::new(&Element::<(), Null>::from(Spinner::new()));
+
+        let frame =
+            Event::Window(window::Event::RedrawRequested(Instant::now()));
+
+        assert_eq!(redraw(&mut spinner, &mut tree, frame), None);
+    }
+
+    #[test]
+    fn the_leading_dot_cycles_over_time() {
+        let started_at = Instant::now();
+        let cycle = Duration::from_millis(800);
+
+        let head = |elapsed: u64| {
+            State {
+                started_at: Some(started_at),
+                now: Some(started_at + Duration::from_millis(elapsed)),
+                is_visible: Cell::new(true),
+            }
+            .head(cycle)
+        };
+
+        assert_eq!(head(0), 0);
+        assert_eq!(head(150), 1);
+        assert_eq!(head(700), 7);
+        assert_eq!(head(900), 1);
+    }
+}
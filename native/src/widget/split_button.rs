@@ -0,0 +1,846 @@
+//! Pair a primary action with a dropdown of alternative actions.
+//!
+//! A [`SplitButton`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::button;
+use crate::widget::container;
+use crate::widget::operation::{self, Operation};
+use crate::widget::pick_list;
+use crate::widget::scrollable;
+use crate::widget::tree::{self, Tree};
+use crate::{
+    Clipboard, Element, Layout, Length, Padding, Point, Rectangle, Shell, Size,
+    Widget,
+};
+
+use std::borrow::Cow;
+
+/// A button with an attached arrow that opens a menu of alternative actions.
+///
+/// The main region produces the [`SplitButton::on_press`] message, like a
+/// [`Button`]. The arrow region opens a [`Menu`] with the options of the
+/// [`SplitButton`], and selecting one of them produces a message as well.
+/// Pressing the main region while the [`Menu`] is open closes it and still
+/// triggers the main action.
+///
+/// Both regions are styled independently with a [`button::StyleSheet`].
+///
+/// A focused [`SplitButton`] can be operated with the keyboard:
+///
+/// - <kbd>←</kbd> and <kbd>→</kbd> move the focus between the main region
+///   and the arrow.
+/// - <kbd>Space</kbd> or <kbd>Enter</kbd> presses the focused region.
+/// - <kbd>↑</kbd> and <kbd>↓</kbd> open the menu and move the highlighted
+///   option, wrapping around the ends of the list.
+/// - <kbd>Enter</kbd> selects the highlighted option and <kbd>Escape</kbd>
+///   closes the menu.
+///
+/// [`Button`]: crate::widget::Button
+#[allow(missing_debug_implementations)]
+pub struct SplitButton<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: button::StyleSheet + menu::StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    on_press: Option<Message>,
+    options: Cow<'a, [T]>,
+    on_selected: Box<dyn Fn(T) -> Message + 'a>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as button::StyleSheet>::Style,
+    arrow_style: <Renderer::Theme as button::StyleSheet>::Style,
+    menu_style: <Renderer::Theme as menu::StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> SplitButton<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer,
+    Renderer::Theme: button::StyleSheet + menu::StyleSheet,
+{
+    /// The default padding of a [`SplitButton`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`SplitButton`] with the given content, the alternative
+    /// actions of its menu, and the message to produce when one of them is
+    /// selected.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        options: impl Into<Cow<'a, [T]>>,
+        on_selected: impl Fn(T) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_press: None,
+            options: options.into(),
+            on_selected: Box::new(on_selected),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+            arrow_style: Default::default(),
+            menu_style: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the main region of the
+    /// [`SplitButton`] is pressed.
+    ///
+    /// Unless `on_press` is called, the main region will be disabled. The
+    /// arrow keeps opening the menu either way.
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the width of the [`SplitButton`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SplitButton`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Padding`] of the regions and the menu options of the
+    /// [`SplitButton`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the arrow and the menu of the [`SplitButton`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the menu of the [`SplitButton`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the main region of the [`SplitButton`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as button::StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the arrow of the [`SplitButton`].
+    pub fn arrow_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as button::StyleSheet>::Style>,
+    ) -> Self {
+        self.arrow_style = style.into();
+        self
+    }
+
+    /// Sets the style of the menu of the [`SplitButton`].
+    pub fn menu_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as menu::StyleSheet>::Style>,
+    ) -> Self {
+        self.menu_style = style.into();
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for SplitButton<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: button::StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<T>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<T>::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = f32::from(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+        let arrow_width = text_size + f32::from(self.padding.horizontal());
+
+        let main = button::layout(
+            renderer,
+            &limits.shrink(Size::new(arrow_width, 0.0)),
+            self.width,
+            self.height,
+            self.padding,
+            |renderer, limits| {
+                self.content.as_widget().layout(renderer, limits)
+            },
+        );
+
+        let size = main.size();
+
+        let mut arrow = layout::Node::new(Size::new(arrow_width, size.height));
+        arrow.move_to(Point::new(size.width, 0.0));
+
+        layout::Node::with_children(
+            Size::new(size.width + arrow_width, size.height),
+            vec![main, arrow],
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let main_layout = layout.children().next().unwrap();
+
+        operation.focusable(tree.state.downcast_mut::<State<T>>(), None);
+
+        operation.container(None, &mut |operation| {
+            self.content.as_widget().operate(
+                &mut tree.children[0],
+                main_layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let main_layout = children.next().unwrap();
+        let arrow_layout = children.next().unwrap();
+
+        if let event::Status::Captured = shell.traced(0, |shell| {
+            self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                main_layout.children().next().unwrap(),
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+            )
+        }) {
+            return event::Status::Captured;
+        }
+
+        let state = tree.state.downcast_mut::<State<T>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // The menu records the option clicked in its overlay
+                if let Some(selection) = state.last_selection.take() {
+                    shell.publish((self.on_selected)(selection));
+                    state.is_open = false;
+
+                    return event::Status::Captured;
+                }
+
+                if cursor.is_over(&arrow_layout.bounds()) {
+                    state.focus = Some(Region::Arrow);
+
+                    if state.is_open {
+                        state.is_open = false;
+                    } else {
+                        state.open(None);
+                    }
+                } else if cursor.is_over(&main_layout.bounds()) {
+                    // The menu is dismissed, but the main action still
+                    // takes place
+                    state.focus = Some(Region::Main);
+                    state.is_open = false;
+                } else {
+                    state.focus = None;
+
+                    if state.is_open {
+                        state.is_open = false;
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) if state.is_open => {
+                state.is_open = false;
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                return match state.focus {
+                    Some(focus) => self.on_key(key_code, focus, state, shell),
+                    None => event::Status::Ignored,
+                };
+            }
+            _ => {}
+        }
+
+        let main_status = button::update(
+            event.clone(),
+            main_layout,
+            cursor,
+            shell,
+            &self.on_press,
+            false,
+            button::DEFAULT_DURATION,
+            || &mut state.main,
+        );
+
+        // The arrow reacts to presses instead of releases, so its messages
+        // are only used to track its appearance
+        let mut presses = Vec::new();
+        let mut arrow_shell = Shell::new(&mut presses);
+
+        let arrow_status = button::update(
+            event,
+            arrow_layout,
+            cursor,
+            &mut arrow_shell,
+            &Some(()),
+            false,
+            button::DEFAULT_DURATION,
+            || &mut state.arrow,
+        );
+
+        if let Some(redraw_request) = arrow_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        main_status.merge(arrow_status)
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let main_layout = children.next().unwrap();
+        let arrow_layout = children.next().unwrap();
+
+        button::mouse_interaction(main_layout, cursor, self.on_press.is_some())
+            .max(button::mouse_interaction(arrow_layout, cursor, true))
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<T>>();
+
+        let mut children = layout.children();
+        let main_layout = children.next().unwrap();
+        let arrow_layout = children.next().unwrap();
+
+        // A focused region is drawn as if it was hovered, like a focused
+        // pick list
+        let cursor_over = |region, bounds: Rectangle| {
+            if state.focus == Some(region) {
+                mouse::Cursor::Available(bounds.center())
+            } else {
+                cursor
+            }
+        };
+
+        let main_bounds = main_layout.bounds();
+
+        let styling = button::draw(
+            renderer,
+            main_bounds,
+            cursor_over(Region::Main, main_bounds),
+            self.on_press.is_some(),
+            theme,
+            &self.style,
+            || &state.main,
+        );
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            &renderer::Style {
+                text_color: styling.text_color,
+            },
+            main_layout.children().next().unwrap(),
+            cursor,
+            &main_bounds,
+        );
+
+        let arrow_bounds = arrow_layout.bounds();
+        let arrow_cursor = if state.is_open {
+            mouse::Cursor::Available(arrow_bounds.center())
+        } else {
+            cursor_over(Region::Arrow, arrow_bounds)
+        };
+
+        let styling = button::draw(
+            renderer,
+            arrow_bounds,
+            arrow_cursor,
+            true,
+            theme,
+            &self.arrow_style,
+            || &state.arrow,
+        );
+
+        let size = f32::from(
+            self.text_size.unwrap_or_else(|| renderer.default_size()),
+        );
+
+        renderer.fill_text(Text {
+            content: &Renderer::ARROW_DOWN_ICON.to_string(),
+            size,
+            line_height: text::LineHeight::default(),
+            letter_spacing: 0.0,
+            font: Renderer::ICON_FONT,
+            color: styling.text_color,
+            bounds: Rectangle {
+                x: arrow_bounds.center_x(),
+                y: arrow_bounds.center_y(),
+                ..arrow_bounds
+            },
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let (state, children) = (&mut tree.state, &mut tree.children);
+        let state = state.downcast_mut::<State<T>>();
+
+        if !state.is_open {
+            return self.content.as_widget_mut().overlay(
+                &mut children[0],
+                layout.children().next().unwrap().children().next().unwrap(),
+                renderer,
+            );
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &self.options,
+            &mut state.hovered_option,
+            &mut state.last_selection,
+        )
+        .width(bounds.width.round() as u16)
+        .padding(self.padding)
+        .font(self.font.clone())
+        .style(self.menu_style.clone());
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+}
+
+impl<'a, T, Message, Renderer> SplitButton<'a, T, Message, Renderer>
+where
+    T: Clone,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: button::StyleSheet + menu::StyleSheet,
+{
+    fn on_key(
+        &self,
+        key_code: keyboard::KeyCode,
+        focus: Region,
+        state: &mut State<T>,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match key_code {
+            keyboard::KeyCode::Enter
+            | keyboard::KeyCode::NumpadEnter
+            | keyboard::KeyCode::Space => {
+                if state.is_open {
+                    if let Some(option) = state
+                        .hovered_option
+                        .and_then(|index| self.options.get(index))
+                    {
+                        shell.publish((self.on_selected)(option.clone()));
+                    }
+
+                    state.is_open = false;
+                } else {
+                    match focus {
+                        Region::Main => {
+                            if let Some(on_press) = self.on_press.clone() {
+                                shell.publish(on_press);
+                            }
+                        }
+                        Region::Arrow => {
+                            state.open(pick_list::step(
+                                None,
+                                self.options.len(),
+                                true,
+                            ));
+                        }
+                    }
+                }
+            }
+            keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                let forward = key_code == keyboard::KeyCode::Down;
+
+                if state.is_open {
+                    state.hovered_option = pick_list::step(
+                        state.hovered_option,
+                        self.options.len(),
+                        forward,
+                    );
+                } else {
+                    state.focus = Some(Region::Arrow);
+                    state.open(pick_list::step(
+                        None,
+                        self.options.len(),
+                        forward,
+                    ));
+                }
+            }
+            keyboard::KeyCode::Left if !state.is_open => {
+                state.focus = Some(Region::Main);
+            }
+            keyboard::KeyCode::Right if !state.is_open => {
+                state.focus = Some(Region::Arrow);
+            }
+            keyboard::KeyCode::Escape if state.is_open => {
+                state.is_open = false;
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+}
+
+impl<'a, T, Message, Renderer> From<SplitButton<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + 'static,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: button::StyleSheet
+        + menu::StyleSheet
+        + scrollable::StyleSheet
+        + container::StyleSheet,
+{
+    fn from(split_button: SplitButton<'a, T, Message, Renderer>) -> Self {
+        Self::new(split_button)
+    }
+}
+
+/// A region of a [`SplitButton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Main,
+    Arrow,
+}
+
+/// The local state of a [`SplitButton`].
+#[derive(Debug)]
+pub struct State<T> {
+    main: button::State,
+    arrow: button::State,
+    menu: menu::State,
+    focus: Option<Region>,
+    is_open: bool,
+    hovered_option: Option<usize>,
+    last_selection: Option<T>,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] for a [`SplitButton`].
+    pub fn new() -> Self {
+        Self {
+            main: button::State::new(),
+            arrow: button::State::new(),
+            menu: menu::State::new(),
+            focus: None,
+            is_open: false,
+            hovered_option: None,
+            last_selection: None,
+        }
+    }
+
+    /// Returns whether the menu of the [`SplitButton`] is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Returns whether the [`SplitButton`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_some()
+    }
+
+    /// Focuses the main region of the [`SplitButton`].
+    pub fn focus(&mut self) {
+        self.focus = Some(Region::Main);
+    }
+
+    /// Unfocuses the [`SplitButton`], closing its menu.
+    pub fn unfocus(&mut self) {
+        self.focus = None;
+        self.is_open = false;
+    }
+
+    fn open(&mut self, hovered_option: Option<usize>) {
+        self.is_open = true;
+        self.hovered_option = hovered_option;
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> operation::Focusable for State<T> {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Main,
+        Selected(&'static str),
+    }
+
+    const OPTIONS: &[&str] = &["Save as", "Export"];
+
+    struct Harness {
+        split_button: SplitButton<'static, &'static str, Message, Null>,
+        tree: Tree,
+        node: layout::Node,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            let split_button =
+                SplitButton::new("Save", OPTIONS, Message::Selected)
+                    .on_press(Message::Main);
+
+            let tree = Tree::new(&split_button as &dyn Widget<_, _>);
+            let node = Widget::<Message, Null>::layout(
+                &split_button,
+                &Null::new(),
+                &layout::Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+            );
+
+            Self {
+                split_button,
+                tree,
+                node,
+            }
+        }
+
+        fn regions(&self) -> (Point, Point) {
+            let children = self.node.children();
+
+            (children[0].bounds().center(), children[1].bounds().center())
+        }
+
+        fn state(&self) -> &State<&'static str> {
+            self.tree.state.downcast_ref()
+        }
+
+        fn send(
+            &mut self,
+            event: Event,
+            cursor: mouse::Cursor,
+        ) -> Vec<Message> {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = self.split_button.on_event(
+                &mut self.tree,
+                event,
+                Layout::new(&self.node),
+                cursor,
+                &Null::new(),
+                &mut crate::clipboard::Null,
+                &mut shell,
+            );
+
+            messages
+        }
+
+        fn click(&mut self, position: Point) -> Vec<Message> {
+            let cursor = mouse::Cursor::Available(position);
+
+            let mut messages = self.send(
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                cursor,
+            );
+
+            messages.extend(self.send(
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                cursor,
+            ));
+
+            messages
+        }
+
+        fn key(&mut self, key_code: keyboard::KeyCode) -> Vec<Message> {
+            self.send(
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers: keyboard::Modifiers::default(),
+                }),
+                mouse::Cursor::Available(Point::ORIGIN),
+            )
+        }
+    }
+
+    #[test]
+    fn main_region_closes_the_menu_and_fires() {
+        let mut harness = Harness::new();
+        let (main, arrow) = harness.regions();
+
+        assert!(harness.click(arrow).is_empty());
+        assert!(harness.state().is_open());
+
+        assert_eq!(harness.click(main), vec![Message::Main]);
+        assert!(!harness.state().is_open());
+    }
+
+    #[test]
+    fn arrow_toggles_the_menu() {
+        let mut harness = Harness::new();
+        let (_, arrow) = harness.regions();
+
+        let _ = harness.click(arrow);
+        assert!(harness.state().is_open());
+
+        let _ = harness.click(arrow);
+        assert!(!harness.state().is_open());
+
+        let _ = harness.click(arrow);
+        let _ = harness.click(Point::new(1000.0, 1000.0));
+        assert!(!harness.state().is_open());
+        assert!(!harness.state().is_focused());
+    }
+
+    #[test]
+    fn both_regions_are_keyboard_accessible() {
+        let mut harness = Harness::new();
+
+        assert!(harness.key(keyboard::KeyCode::Enter).is_empty());
+
+        harness.tree.state.downcast_mut::<State<&str>>().focus();
+
+        assert_eq!(harness.key(keyboard::KeyCode::Enter), vec![Message::Main]);
+
+        let _ = harness.key(keyboard::KeyCode::Right);
+        assert!(harness.key(keyboard::KeyCode::Space).is_empty());
+        assert!(harness.state().is_open());
+
+        let _ = harness.key(keyboard::KeyCode::Down);
+        assert_eq!(
+            harness.key(keyboard::KeyCode::Enter),
+            vec![Message::Selected("Export")]
+        );
+        assert!(!harness.state().is_open());
+
+        let _ = harness.key(keyboard::KeyCode::Up);
+        assert!(harness.state().is_open());
+
+        let _ = harness.key(keyboard::KeyCode::Escape);
+        assert!(!harness.state().is_open());
+    }
+}
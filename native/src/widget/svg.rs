@@ -1,10 +1,11 @@
 //! Display vector graphics in your application.
 use crate::layout;
+use crate::mouse;
 use crate::renderer;
 use crate::svg;
 use crate::widget::Tree;
 use crate::{
-    ContentFit, Element, Layout, Length, Point, Rectangle, Size, Vector, Widget,
+    ContentFit, Element, Layout, Length, Rectangle, Size, Vector, Widget,
 };
 
 use std::path::PathBuf;
@@ -18,6 +19,9 @@ pub use svg::Handle;
 ///
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
+///
+/// Nothing is drawn if the SVG cannot be loaded. The reason can be obtained
+/// with [`svg::Renderer::error`].
 #[allow(missing_debug_implementations)]
 pub struct Svg<Renderer>
 where
@@ -124,11 +128,15 @@ where
         // Shrink the widget to fit the resized image, if requested
         let final_size = Size {
             width: match self.width {
-                Length::Shrink => f32::min(raw_size.width, full_size.width),
+                Length::Shrink | Length::FitContent(_) => {
+                    f32::min(raw_size.width, full_size.width)
+                }
                 _ => raw_size.width,
             },
             height: match self.height {
-                Length::Shrink => f32::min(raw_size.height, full_size.height),
+                Length::Shrink | Length::FitContent(_) => {
+                    f32::min(raw_size.height, full_size.height)
+                }
                 _ => raw_size.height,
             },
         };
@@ -143,7 +151,7 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         let Size { width, height } = renderer.dimensions(&self.handle);
@@ -1,6 +1,7 @@
 //! Write some text for your users to read.
 use crate::alignment;
 use crate::layout;
+use crate::mouse;
 use crate::renderer;
 use crate::text;
 use crate::widget::Tree;
@@ -8,6 +9,7 @@ use crate::{Element, Layout, Length, Point, Rectangle, Size, Widget};
 
 use std::borrow::Cow;
 
+pub use crate::text::LineHeight;
 pub use iced_style::text::{Appearance, StyleSheet};
 
 /// A paragraph of text.
@@ -33,6 +35,8 @@ where
 {
     content: Cow<'a, str>,
     size: Option<u16>,
+    line_height: text::LineHeight,
+    letter_spacing: f32,
     width: Length,
     height: Length,
     horizontal_alignment: alignment::Horizontal,
@@ -51,6 +55,8 @@ where
         Text {
             content: content.into(),
             size: None,
+            line_height: text::LineHeight::default(),
+            letter_spacing: 0.0,
             font: Default::default(),
             width: Length::Shrink,
             height: Length::Shrink,
@@ -66,6 +72,30 @@ where
         self
     }
 
+    /// Sets the [`LineHeight`] of the [`Text`].
+    ///
+    /// It can be given either as a factor of the text size (e.g. `1.5`) or
+    /// as an absolute amount of logical pixels.
+    ///
+    /// [`LineHeight`]: text::LineHeight
+    pub fn line_height(
+        mut self,
+        line_height: impl Into<text::LineHeight>,
+    ) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the letter spacing of the [`Text`], in logical pixels.
+    ///
+    /// The spacing is added between every pair of adjacent characters of a
+    /// line and affects both drawing and measuring. Lines are wrapped with the
+    /// spacing applied, so spaced text stays within its bounds.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Font`]: crate::text::Renderer::Font
@@ -138,14 +168,56 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font.clone(), bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.line_height,
+            self.letter_spacing,
+            self.font.clone(),
+            bounds,
+        );
 
         let size = limits.resolve(Size::new(width, height));
 
         layout::Node::new(size)
     }
 
+    fn baseline(
+        &self,
+        renderer: &Renderer,
+        layout: &layout::Node,
+    ) -> Option<f32> {
+        let size = self.size.unwrap_or_else(|| renderer.default_size());
+        let bounds = layout.size();
+
+        let offset = match self.vertical_alignment {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center | alignment::Vertical::Bottom => {
+                let (_, height) = renderer.measure(
+                    &self.content,
+                    size,
+                    self.line_height,
+                    self.letter_spacing,
+                    self.font.clone(),
+                    bounds,
+                );
+
+                let free = (bounds.height - height).max(0.0);
+
+                if self.vertical_alignment == alignment::Vertical::Center {
+                    free / 2.0
+                } else {
+                    free
+                }
+            }
+        };
+
+        Some(
+            offset
+                + renderer.baseline(size, self.line_height, self.font.clone()),
+        )
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -153,7 +225,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         draw(
@@ -162,6 +234,8 @@ where
             layout,
             &self.content,
             self.size,
+            self.line_height,
+            self.letter_spacing,
             self.font.clone(),
             theme.appearance(self.style),
             self.horizontal_alignment,
@@ -186,6 +260,8 @@ pub fn draw<Renderer>(
     layout: Layout<'_>,
     content: &str,
     size: Option<u16>,
+    line_height: text::LineHeight,
+    letter_spacing: f32,
     font: Renderer::Font,
     appearance: Appearance,
     horizontal_alignment: alignment::Horizontal,
@@ -194,22 +270,14 @@ pub fn draw<Renderer>(
     Renderer: text::Renderer,
 {
     let bounds = layout.bounds();
-
-    let x = match horizontal_alignment {
-        alignment::Horizontal::Left => bounds.x,
-        alignment::Horizontal::Center => bounds.center_x(),
-        alignment::Horizontal::Right => bounds.x + bounds.width,
-    };
-
-    let y = match vertical_alignment {
-        alignment::Vertical::Top => bounds.y,
-        alignment::Vertical::Center => bounds.center_y(),
-        alignment::Vertical::Bottom => bounds.y + bounds.height,
-    };
+    let Point { x, y } =
+        text::anchor(bounds, horizontal_alignment, vertical_alignment);
 
     renderer.fill_text(crate::text::Text {
         content,
         size: f32::from(size.unwrap_or_else(|| renderer.default_size())),
+        line_height,
+        letter_spacing,
         bounds: Rectangle { x, y, ..bounds },
         color: appearance.color.unwrap_or(style.text_color),
         font,
@@ -238,6 +306,8 @@ where
         Self {
             content: self.content.clone(),
             size: self.size,
+            line_height: self.line_height,
+            letter_spacing: self.letter_spacing,
             width: self.width,
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
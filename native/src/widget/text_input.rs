@@ -13,9 +13,11 @@ use editor::Editor;
 
 use crate::alignment;
 use crate::event::{self, Event};
+use crate::input_method::{self, InputMethod};
 use crate::keyboard;
 use crate::layout;
 use crate::mouse::{self, click};
+use crate::overlay;
 use crate::renderer;
 use crate::text::{self, Text};
 use crate::time::{Duration, Instant};
@@ -29,6 +31,8 @@ use crate::{
     Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::ops::Range;
+
 pub use iced_style::text_input::{Appearance, StyleSheet};
 
 /// A field that can be filled with text.
@@ -50,6 +54,11 @@ pub use iced_style::text_input::{Appearance, StyleSheet};
 /// )
 /// .padding(10);
 /// ```
+///
+/// A [`TextInput`] can also show some content before and after its value,
+/// like a currency symbol or a button to clear it. See
+/// [`leading`](Self::leading) and [`trailing`](Self::trailing).
+///
 /// ![Text input drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text_input.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message, Renderer>
@@ -68,6 +77,10 @@ where
     on_change: Box<dyn Fn(String) -> Message + 'a>,
     on_paste: Option<Box<dyn Fn(String) -> Message + 'a>>,
     on_submit: Option<Message>,
+    blink: Blink,
+    leading: Option<Element<'a, Message, Renderer>>,
+    trailing: Option<Element<'a, Message, Renderer>>,
+    spacing: u16,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -77,6 +90,10 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
+    /// The default spacing between the value of a [`TextInput`] and its
+    /// leading and trailing content.
+    pub const DEFAULT_SPACING: u16 = 5;
+
     /// Creates a new [`TextInput`].
     ///
     /// It expects:
@@ -99,6 +116,10 @@ where
             on_change: Box::new(on_change),
             on_paste: None,
             on_submit: None,
+            blink: Blink::default(),
+            leading: None,
+            trailing: None,
+            spacing: Self::DEFAULT_SPACING,
             style: Default::default(),
         }
     }
@@ -157,6 +178,50 @@ where
         self
     }
 
+    /// Sets the [`Blink`] behavior of the cursor of the [`TextInput`].
+    pub fn blink(mut self, blink: Blink) -> Self {
+        self.blink = blink;
+        self
+    }
+
+    /// Sets the content shown before the value of the [`TextInput`], like a
+    /// currency symbol.
+    ///
+    /// The content is placed inside the borders of the [`TextInput`], but it
+    /// is not editable: the value is given the remaining width. Clicking the
+    /// content focuses the [`TextInput`], unless the content handles the click
+    /// itself—like a [`Button`] producing its own message.
+    ///
+    /// Its text is drawn with the [`StyleSheet::affix_color`].
+    ///
+    /// [`Button`]: widget::Button
+    pub fn leading(
+        mut self,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.leading = Some(content.into());
+        self
+    }
+
+    /// Sets the content shown after the value of the [`TextInput`], like a
+    /// search icon or a button to clear it.
+    ///
+    /// It behaves like the [`leading`](Self::leading) content.
+    pub fn trailing(
+        mut self,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.trailing = Some(content.into());
+        self
+    }
+
+    /// Sets the spacing between the value of the [`TextInput`] and its
+    /// leading and trailing content.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
     /// Sets the style of the [`TextInput`].
     pub fn style(
         mut self,
@@ -176,22 +241,68 @@ where
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         value: Option<&Value>,
     ) {
         draw(
             renderer,
             theme,
             layout,
-            cursor_position,
+            cursor,
             tree.state.downcast_ref::<State>(),
             value.unwrap_or(&self.value),
             &self.placeholder,
             self.size,
             &self.font,
             self.is_secure,
+            self.blink,
             &self.style,
-        )
+        );
+
+        self.draw_affixes(
+            tree,
+            renderer,
+            theme,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+
+    /// Returns the leading and trailing content of the [`TextInput`], in the
+    /// order of their trees and layouts.
+    fn affixes(&self) -> impl Iterator<Item = &Element<'a, Message, Renderer>> {
+        self.leading.iter().chain(self.trailing.iter())
+    }
+
+    fn affixes_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Element<'a, Message, Renderer>> {
+        self.leading.iter_mut().chain(self.trailing.iter_mut())
+    }
+
+    fn draw_affixes(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let style = renderer::Style {
+            text_color: theme.affix_color(&self.style),
+        };
+
+        for ((affix, tree), layout) in self
+            .affixes()
+            .zip(&tree.children)
+            .zip(layout.children().skip(1))
+        {
+            affix
+                .as_widget()
+                .draw(tree, renderer, theme, &style, layout, cursor, viewport);
+        }
     }
 }
 
@@ -207,7 +318,20 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::with_debug(State::new())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.affixes().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let affixes: Vec<_> = self.affixes().collect();
+
+        tree.diff_children(&affixes);
+
+        // Keep the cursor in place if the value grows later on
+        tree.state.downcast_mut::<State>().cursor.clamp(&self.value);
     }
 
     fn width(&self) -> Length {
@@ -223,20 +347,54 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(renderer, limits, self.width, self.padding, self.size)
+        if self.leading.is_none() && self.trailing.is_none() {
+            return layout(
+                renderer,
+                limits,
+                self.width,
+                self.padding,
+                self.size,
+            );
+        }
+
+        layout_with_affixes(
+            renderer,
+            limits,
+            self.width,
+            self.padding,
+            self.size,
+            self.spacing,
+            self.leading.as_ref(),
+            self.trailing.as_ref(),
+        )
     }
 
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
-        _renderer: &Renderer,
+        layout: Layout<'_>,
+        renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State>();
 
+        operation.bounds(layout.bounds(), self.id.as_ref().map(|id| &id.0));
         operation.focusable(state, self.id.as_ref().map(|id| &id.0));
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+
+        if tree.children.is_empty() {
+            return;
+        }
+
+        operation.container(None, &mut |operation| {
+            for ((affix, tree), layout) in self
+                .affixes()
+                .zip(&mut tree.children)
+                .zip(layout.children().skip(1))
+            {
+                affix.as_widget().operate(tree, layout, renderer, operation);
+            }
+        });
     }
 
     fn on_event(
@@ -244,15 +402,41 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        // The affixes handle their own clicks, like a button to clear the
+        // value, before they can focus the text input
+        let status = self
+            .affixes_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children().skip(1))
+            .enumerate()
+            .map(|(index, ((affix, tree), layout))| {
+                shell.traced(index, |shell| {
+                    affix.as_widget_mut().on_event(
+                        tree,
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                })
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
         update(
             event,
             layout,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             shell,
@@ -263,6 +447,7 @@ where
             self.on_change.as_ref(),
             self.on_paste.as_deref(),
             &self.on_submit,
+            self.blink,
             || tree.state.downcast_mut::<State>(),
         )
     }
@@ -274,33 +459,69 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
-        _viewport: &Rectangle,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
     ) {
         draw(
             renderer,
             theme,
             layout,
-            cursor_position,
+            cursor,
             tree.state.downcast_ref::<State>(),
             &self.value,
             &self.placeholder,
             self.size,
             &self.font,
             self.is_secure,
+            self.blink,
             &self.style,
-        )
+        );
+
+        self.draw_affixes(tree, renderer, theme, layout, cursor, viewport);
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
-        _viewport: &Rectangle,
-        _renderer: &Renderer,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(layout, cursor_position)
+        let affix = self
+            .affixes()
+            .zip(&tree.children)
+            .zip(layout.children().skip(1))
+            .find(|(_, layout)| cursor.is_over(&layout.bounds()))
+            .map(|((affix, tree), layout)| {
+                affix
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .filter(|interaction| *interaction != mouse::Interaction::Idle);
+
+        affix.unwrap_or_else(|| mouse_interaction(layout, cursor))
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let overlays: Vec<_> = self
+            .leading
+            .iter_mut()
+            .chain(self.trailing.iter_mut())
+            .zip(&mut tree.children)
+            .zip(layout.children().skip(1))
+            .filter_map(|((affix, tree), layout)| {
+                affix.as_widget_mut().overlay(tree, layout, renderer)
+            })
+            .collect();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
     }
 }
 
@@ -349,6 +570,9 @@ pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
 
 /// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the given [`Id`] to the
 /// end.
+///
+/// Focusing an unfocused [`TextInput`] moves its cursor to the end. When batched with [`focus`],
+/// cursor commands should come after it.
 pub fn move_cursor_to_end<Message: 'static>(id: Id) -> Command<Message> {
     Command::widget(operation::text_input::move_cursor_to_end(id.0))
 }
@@ -361,6 +585,8 @@ pub fn move_cursor_to_front<Message: 'static>(id: Id) -> Command<Message> {
 
 /// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the given [`Id`] to the
 /// provided position.
+///
+/// The position is clamped to the length of the current value.
 pub fn move_cursor_to<Message: 'static>(
     id: Id,
     position: usize,
@@ -369,6 +595,9 @@ pub fn move_cursor_to<Message: 'static>(
 }
 
 /// Produces a [`Command`] that selects all the content of the [`TextInput`] with the given [`Id`].
+///
+/// Focusing an unfocused [`TextInput`] discards its selection. When batched with [`focus`], this
+/// command should come after it.
 pub fn select_all<Message: 'static>(id: Id) -> Command<Message> {
     Command::widget(operation::text_input::select_all(id.0))
 }
@@ -399,12 +628,99 @@ where
     layout::Node::with_children(text.size().pad(padding), vec![text])
 }
 
+/// Computes the layout of a [`TextInput`] with some leading or trailing
+/// content, shrinking its text to make room for it.
+///
+/// The text is the first child of the resulting node, followed by the
+/// leading and the trailing content.
+fn layout_with_affixes<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &layout::Limits,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    spacing: u16,
+    leading: Option<&Element<'_, Message, Renderer>>,
+    trailing: Option<&Element<'_, Message, Renderer>>,
+) -> layout::Node
+where
+    Renderer: text::Renderer,
+{
+    let text_size = size.unwrap_or_else(|| renderer.default_size());
+    let spacing = f32::from(spacing);
+
+    let padding = padding.fit(Size::ZERO, limits.max());
+    let limits = limits.width(width).pad(padding);
+
+    let affix = |content: Option<&Element<'_, Message, Renderer>>| {
+        content.map(|content| {
+            content.as_widget().layout(renderer, &limits.loose())
+        })
+    };
+
+    let mut leading = affix(leading);
+    let mut trailing = affix(trailing);
+
+    let height = leading
+        .iter()
+        .chain(trailing.iter())
+        .map(|affix| affix.size().height)
+        .fold(f32::from(text_size), f32::max);
+
+    let affixes_width: f32 = leading
+        .iter()
+        .chain(trailing.iter())
+        .map(|affix| affix.size().width + spacing)
+        .sum();
+
+    let mut text = layout::Node::new(
+        limits
+            .height(Length::Units(text_size))
+            .shrink(Size::new(affixes_width, 0.0))
+            .resolve(Size::ZERO),
+    );
+
+    let mut x = f32::from(padding.left);
+
+    let mut place = |node: &mut layout::Node, spacing: f32| {
+        node.move_to(Point::new(
+            x,
+            f32::from(padding.top) + (height - node.size().height) / 2.0,
+        ));
+
+        x += node.size().width + spacing;
+    };
+
+    if let Some(leading) = &mut leading {
+        place(leading, spacing);
+    }
+
+    place(&mut text, if trailing.is_some() { spacing } else { 0.0 });
+
+    if let Some(trailing) = &mut trailing {
+        place(trailing, 0.0);
+    }
+
+    let size = Size::new(
+        x + f32::from(padding.right),
+        height + f32::from(padding.top + padding.bottom),
+    );
+
+    layout::Node::with_children(
+        size,
+        std::iter::once(text)
+            .chain(leading)
+            .chain(trailing)
+            .collect(),
+    )
+}
+
 /// Processes an [`Event`] and updates the [`State`] of a [`TextInput`]
 /// accordingly.
 pub fn update<'a, Message, Renderer>(
     event: Event,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     renderer: &Renderer,
     clipboard: &mut dyn Clipboard,
     shell: &mut Shell<'_, Message>,
@@ -415,6 +731,7 @@ pub fn update<'a, Message, Renderer>(
     on_change: &dyn Fn(String) -> Message,
     on_paste: Option<&dyn Fn(String) -> Message>,
     on_submit: &Option<Message>,
+    blink: Blink,
     state: impl FnOnce() -> &'a mut State,
 ) -> event::Status
 where
@@ -425,22 +742,21 @@ where
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
             let state = state();
-            let is_clicked = layout.bounds().contains(cursor_position);
+            let click_position = cursor.position_over(&layout.bounds());
 
-            state.is_focused = if is_clicked {
+            state.is_focused = if click_position.is_some() {
                 state.is_focused.or_else(|| {
                     let now = Instant::now();
 
-                    Some(Focus {
-                        updated_at: now,
-                        now,
-                    })
+                    Some(Focus::new(now))
                 })
             } else {
+                state.preedit = None;
+
                 None
             };
 
-            if is_clicked {
+            if let Some(cursor_position) = click_position {
                 let text_layout = layout.children().next().unwrap();
                 let target = cursor_position.x - text_layout.bounds().x;
 
@@ -514,6 +830,12 @@ where
                 return event::Status::Captured;
             }
         }
+        Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+            // Other buttons only move the focus away
+            if !cursor.is_over(&layout.bounds()) {
+                state().unfocus();
+            }
+        }
         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerLifted { .. })
         | Event::Touch(touch::Event::FingerLost { .. }) => {
@@ -556,6 +878,7 @@ where
 
             if let Some(focus) = &mut state.is_focused {
                 if state.is_pasting.is_none()
+                    && state.preedit.is_none()
                     && !state.keyboard_modifiers.command()
                     && !c.is_control()
                 {
@@ -575,6 +898,11 @@ where
         Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
             let state = state();
 
+            // The input method handles the keys while composing
+            if state.is_focused.is_some() && state.preedit.is_some() {
+                return event::Status::Captured;
+            }
+
             if let Some(focus) = &mut state.is_focused {
                 let modifiers = state.keyboard_modifiers;
                 focus.updated_at = Instant::now();
@@ -782,19 +1110,113 @@ where
 
             state.keyboard_modifiers = modifiers;
         }
+        Event::InputMethod(event) => {
+            let state = state();
+
+            if let Some(focus) = &mut state.is_focused {
+                match event {
+                    input_method::Event::Opened => {}
+                    input_method::Event::Preedit(content, selection) => {
+                        state.preedit = (!content.is_empty())
+                            .then_some(Preedit { content, selection });
+                    }
+                    input_method::Event::Commit(content) => {
+                        state.preedit = None;
+
+                        if content.is_empty() {
+                            return event::Status::Captured;
+                        }
+
+                        let mut editor = Editor::new(value, &mut state.cursor);
+
+                        editor.paste(Value::new(&content));
+
+                        let message = (on_change)(editor.contents());
+                        shell.publish(message);
+                    }
+                    input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                }
+
+                focus.updated_at = Instant::now();
+
+                return event::Status::Captured;
+            }
+        }
         Event::Window(window::Event::RedrawRequested(now)) => {
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
                 focus.now = now;
 
-                let millis_until_redraw = CURSOR_BLINK_INTERVAL_MILLIS
-                    - (now - focus.updated_at).as_millis()
-                        % CURSOR_BLINK_INTERVAL_MILLIS;
+                if let Some(next_toggle) = blink.next_toggle(focus) {
+                    shell
+                        .request_redraw(window::RedrawRequest::At(next_toggle));
+                }
+
+                // Secure inputs do not compose text, so that passwords never
+                // show up in the candidate window
+                if !is_secure {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+                    let size = size.unwrap_or_else(|| renderer.default_size());
+
+                    let position = match state.cursor.state(value) {
+                        cursor::State::Index(position) => position,
+                        cursor::State::Selection { start, end } => {
+                            start.min(end)
+                        }
+                    };
 
-                shell.request_redraw(window::RedrawRequest::At(
-                    now + Duration::from_millis(millis_until_redraw as u64),
-                ));
+                    let (cursor_x, _) = measure_cursor_and_scroll_offset(
+                        renderer,
+                        text_bounds,
+                        value,
+                        size,
+                        position,
+                        font.clone(),
+                    );
+
+                    let offset = offset(
+                        renderer,
+                        text_bounds,
+                        font.clone(),
+                        size,
+                        value,
+                        state,
+                    );
+
+                    shell.request_input_method(InputMethod::Enabled {
+                        position: Point::new(
+                            text_bounds.x + (cursor_x - offset).max(0.0),
+                            text_bounds.y + text_bounds.height,
+                        ),
+                    });
+                }
+            }
+        }
+        Event::Window(window::Event::Focused) => {
+            let state = state();
+
+            if let Some(focus) = &mut state.is_focused {
+                let now = Instant::now();
+
+                // The cursor starts a new blink, visible, right away
+                focus.is_window_focused = true;
+                focus.updated_at = now;
+                focus.now = now;
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        Event::Window(window::Event::Unfocused) => {
+            let state = state();
+
+            if let Some(focus) = &mut state.is_focused {
+                focus.is_window_focused = false;
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
             }
         }
         _ => {}
@@ -811,13 +1233,14 @@ pub fn draw<Renderer>(
     renderer: &mut Renderer,
     theme: &Renderer::Theme,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     state: &State,
     value: &Value,
     placeholder: &str,
     size: Option<u16>,
     font: &Renderer::Font,
     is_secure: bool,
+    blink: Blink,
     style: &<Renderer::Theme as StyleSheet>::Style,
 ) where
     Renderer: text::Renderer,
@@ -826,10 +1249,22 @@ pub fn draw<Renderer>(
     let secure_value = is_secure.then(|| value.secure());
     let value = secure_value.as_ref().unwrap_or(value);
 
+    // The text being composed is shown in place of the selection, if any
+    let composition = state
+        .preedit
+        .as_ref()
+        .filter(|_| state.is_focused() && !is_secure)
+        .map(|preedit| Composition::new(value, state.cursor, preedit));
+
+    let value = composition
+        .as_ref()
+        .map(|composition| &composition.value)
+        .unwrap_or(value);
+
     let bounds = layout.bounds();
     let text_bounds = layout.children().next().unwrap().bounds();
 
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&bounds);
 
     let appearance = if state.is_focused() {
         theme.focused(style)
@@ -852,8 +1287,13 @@ pub fn draw<Renderer>(
     let text = value.to_string();
     let size = size.unwrap_or_else(|| renderer.default_size());
 
+    let cursor_state = match &composition {
+        Some(composition) => cursor::State::Index(composition.cursor),
+        None => state.cursor.state(value),
+    };
+
     let (cursor, offset) = if let Some(focus) = &state.is_focused {
-        match state.cursor.state(value) {
+        match cursor_state {
             cursor::State::Index(position) => {
                 let (text_value_width, offset) =
                     measure_cursor_and_scroll_offset(
@@ -865,13 +1305,7 @@ pub fn draw<Renderer>(
                         font.clone(),
                     );
 
-                let is_cursor_visible = ((focus.now - focus.updated_at)
-                    .as_millis()
-                    / CURSOR_BLINK_INTERVAL_MILLIS)
-                    % 2
-                    == 0;
-
-                let cursor = if is_cursor_visible {
+                let cursor = if blink.is_cursor_visible(focus) {
                     Some((
                         renderer::Quad {
                             bounds: Rectangle {
@@ -931,7 +1365,11 @@ pub fn draw<Renderer>(
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
                         },
-                        theme.selection_color(style),
+                        if focus.is_window_focused {
+                            theme.selection_color(style)
+                        } else {
+                            theme.unfocused_selection_color(style)
+                        },
                     )),
                     if end == right {
                         right_offset
@@ -951,15 +1389,51 @@ pub fn draw<Renderer>(
         font.clone(),
     );
 
+    let underline = composition.as_ref().map(|composition| {
+        let (start, _) = measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            value,
+            size,
+            composition.start,
+            font.clone(),
+        );
+
+        let (end, _) = measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            value,
+            size,
+            composition.end,
+            font.clone(),
+        );
+
+        renderer::Quad {
+            bounds: Rectangle {
+                x: text_bounds.x + start,
+                y: text_bounds.y + text_bounds.height - 1.0,
+                width: end - start,
+                height: 1.0,
+            },
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    });
+
     let render = |renderer: &mut Renderer| {
         if let Some((cursor, color)) = cursor {
             renderer.fill_quad(cursor, color);
         }
 
+        if let Some(underline) = underline {
+            renderer.fill_quad(underline, theme.value_color(style));
+        }
+
         renderer.fill_text(Text {
             content: if text.is_empty() { placeholder } else { &text },
             color: if text.is_empty() {
-                theme.placeholder_color(style)
+                appearance.placeholder_color
             } else {
                 theme.value_color(style)
             },
@@ -970,6 +1444,8 @@ pub fn draw<Renderer>(
                 ..text_bounds
             },
             size: f32::from(size),
+            line_height: text::LineHeight::default(),
+            letter_spacing: 0.0,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
         });
@@ -987,9 +1463,9 @@ pub fn draw<Renderer>(
 /// Computes the current [`mouse::Interaction`] of the [`TextInput`].
 pub fn mouse_interaction(
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
 ) -> mouse::Interaction {
-    if layout.bounds().contains(cursor_position) {
+    if cursor.is_over(&layout.bounds()) {
         mouse::Interaction::Text
     } else {
         mouse::Interaction::default()
@@ -1005,13 +1481,137 @@ pub struct State {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    preedit: Option<Preedit>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
+/// The text being composed with an input method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Preedit {
+    content: String,
+    selection: Option<Range<usize>>,
+}
+
+/// A [`Value`] with a [`Preedit`] in place of its selection.
+struct Composition {
+    value: Value,
+    start: usize,
+    end: usize,
+    cursor: usize,
+}
+
+impl Composition {
+    fn new(value: &Value, cursor: Cursor, preedit: &Preedit) -> Self {
+        let (start, end) = match cursor.state(value) {
+            cursor::State::Index(position) => (position, position),
+            cursor::State::Selection { start, end } => {
+                (start.min(end), start.max(end))
+            }
+        };
+
+        let content = Value::new(&preedit.content);
+        let length = content.len();
+
+        let mut value = value.clone();
+        value.remove_many(start, end);
+        value.insert_many(start, content);
+
+        Self {
+            value,
+            start,
+            end: start + length,
+            cursor: start + preedit.cursor(),
+        }
+    }
+}
+
+impl Preedit {
+    /// Returns the amount of graphemes of the [`Preedit`] before its cursor.
+    fn cursor(&self) -> usize {
+        let before_cursor = self
+            .selection
+            .as_ref()
+            .and_then(|selection| self.content.get(..selection.start))
+            .unwrap_or(&self.content);
+
+        Value::new(before_cursor).len()
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Focus {
     updated_at: Instant,
     now: Instant,
+    is_window_focused: bool,
+}
+
+impl Focus {
+    fn new(now: Instant) -> Self {
+        Self {
+            updated_at: now,
+            now,
+            is_window_focused: true,
+        }
+    }
+}
+
+/// The blinking behavior of the cursor of a [`TextInput`].
+///
+/// The cursor is always visible right after typing, and it is hidden while
+/// the window is unfocused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blink {
+    /// The cursor is shown and hidden alternately, for the given interval
+    /// each.
+    Interval(Duration),
+
+    /// The cursor never blinks (e.g. to respect a reduced motion preference).
+    Steady,
+}
+
+impl Blink {
+    /// The default blink interval of a cursor.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+    fn interval(self) -> Option<u128> {
+        match self {
+            Blink::Interval(interval) if interval.as_millis() > 0 => {
+                Some(interval.as_millis())
+            }
+            _ => None,
+        }
+    }
+
+    fn is_cursor_visible(self, focus: &Focus) -> bool {
+        if !focus.is_window_focused {
+            return false;
+        }
+
+        match self.interval() {
+            Some(interval) => {
+                ((focus.now - focus.updated_at).as_millis() / interval) % 2 == 0
+            }
+            None => true,
+        }
+    }
+
+    fn next_toggle(self, focus: &Focus) -> Option<Instant> {
+        if !focus.is_window_focused {
+            return None;
+        }
+
+        let interval = self.interval()?;
+        let millis_until_toggle =
+            interval - (focus.now - focus.updated_at).as_millis() % interval;
+
+        Some(focus.now + Duration::from_millis(millis_until_toggle as u64))
+    }
+}
+
+impl Default for Blink {
+    fn default() -> Self {
+        Blink::Interval(Self::DEFAULT_INTERVAL)
+    }
 }
 
 impl State {
@@ -1029,6 +1629,7 @@ impl State {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            preedit: None,
         }
     }
 
@@ -1043,20 +1644,23 @@ impl State {
     }
 
     /// Focuses the [`TextInput`].
+    ///
+    /// The [`Cursor`] is moved to the end of the input text, unless the
+    /// [`TextInput`] is already focused.
     pub fn focus(&mut self) {
         let now = Instant::now();
 
-        self.is_focused = Some(Focus {
-            updated_at: now,
-            now,
-        });
+        if self.is_focused.is_none() {
+            self.move_cursor_to_end();
+        }
 
-        self.move_cursor_to_end();
+        self.is_focused = Some(Focus::new(now));
     }
 
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1210,4 +1814,489 @@ where
         .map(text::Hit::cursor)
 }
 
-const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::{Column, Space, Tree};
+    use crate::Size;
+
+    fn operate(
+        content: &Element<'_, (), Null>,
+        tree: &mut Tree,
+        operations: Vec<Box<dyn Operation<()>>>,
+    ) {
+        let renderer = Null::new();
+        let node = content.as_widget().layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        for mut operation in operations {
+            content.as_widget().operate(
+                tree,
+                Layout::new(&node),
+                &renderer,
+                operation.as_mut(),
+            );
+        }
+    }
+
+    fn cursor(tree: &Tree, index: usize) -> Cursor {
+        tree.children[index].state.downcast_ref::<State>().cursor()
+    }
+
+    #[test]
+    fn operations_target_ids_and_clamp_cursor() {
+        let id = Id::new("target");
+        let value = Value::new("hello");
+
+        let content: Element<'_, (), Null> = Column::new()
+            .push(TextInput::new("", "hello", |_| ()).id(id.clone()))
+            .push(TextInput::new("", "hello", |_| ()))
+            .into();
+
+        let mut tree = Tree::new(&content);
+
+        operate(
+            &content,
+            &mut tree,
+            vec![Box::new(operation::text_input::move_cursor_to(
+                id.0.clone(),
+                42,
+            ))],
+        );
+
+        assert!(matches!(
+            cursor(&tree, 0).state(&value),
+            cursor::State::Index(5)
+        ));
+        assert!(matches!(
+            cursor(&tree, 1).state(&value),
+            cursor::State::Index(0)
+        ));
+
+        // The cursor is clamped when the tree is diffed, so it stays in
+        // place when the value grows
+        tree.diff(&content);
+
+        let longer = Value::new("hello, world");
+
+        assert!(matches!(
+            cursor(&tree, 0).state(&longer),
+            cursor::State::Index(5)
+        ));
+    }
+
+    #[test]
+    fn focus_then_select_all_keeps_selection() {
+        let id = Id::new("target");
+        let value = Value::new("hello");
+
+        let content: Element<'_, (), Null> = Column::new()
+            .push(TextInput::new("", "hello", |_| ()).id(id.clone()))
+            .into();
+
+        let mut tree = Tree::new(&content);
+
+        operate(
+            &content,
+            &mut tree,
+            vec![
+                Box::new(operation::focusable::focus(id.0.clone())),
+                Box::new(operation::text_input::select_all(id.0.clone())),
+                // Focusing again does not discard the selection
+                Box::new(operation::focusable::focus(id.0.clone())),
+            ],
+        );
+
+        assert_eq!(cursor(&tree, 0).selection(&value), Some((0, 5)));
+    }
+
+    #[test]
+    fn blink_resets_on_typing_and_stops_when_window_unfocused() {
+        let start = Instant::now();
+        let blink = Blink::Interval(Duration::from_millis(500));
+        let mut focus = Focus::new(start);
+
+        focus.now = start + Duration::from_millis(600);
+        assert!(!blink.is_cursor_visible(&focus));
+        assert_eq!(
+            blink.next_toggle(&focus),
+            Some(start + Duration::from_millis(1000))
+        );
+
+        // Typing shows the cursor right away
+        focus.updated_at = focus.now;
+        assert!(blink.is_cursor_visible(&focus));
+
+        focus.is_window_focused = false;
+        assert!(!blink.is_cursor_visible(&focus));
+        assert_eq!(blink.next_toggle(&focus), None);
+    }
+
+    #[test]
+    fn window_focus_pauses_and_restarts_the_cursor() {
+        let renderer = Null::new();
+        let node = layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            Length::Fill,
+            Padding::ZERO,
+            None,
+        );
+
+        let blink = Blink::default();
+        let mut value = Value::new("hello");
+        let mut state = State::new();
+        state.focus();
+
+        let mut send = |event: window::Event| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = update(
+                Event::Window(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::ORIGIN),
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+                &mut value,
+                None,
+                &Default::default(),
+                false,
+                &|_| (),
+                None,
+                &None,
+                blink,
+                || &mut state,
+            );
+
+            shell.redraw_request()
+        };
+
+        assert_eq!(
+            send(window::Event::Unfocused),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        let _ = send(window::Event::Focused);
+
+        let focus = state.is_focused.unwrap();
+
+        assert!(focus.is_window_focused);
+        assert_eq!(focus.now, focus.updated_at);
+        assert!(blink.is_cursor_visible(&focus));
+    }
+
+    #[test]
+    fn other_buttons_only_unfocus() {
+        let renderer = Null::new();
+        let node = layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            Length::Fill,
+            Padding::ZERO,
+            None,
+        );
+
+        let mut value = Value::new("hello");
+        let mut state = State::new();
+
+        let mut press = |state: &mut State, cursor| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            let _ = update(
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)),
+                Layout::new(&node),
+                cursor,
+                &renderer,
+                &mut crate::clipboard::Null,
+                &mut shell,
+                &mut value,
+                None,
+                &Default::default(),
+                false,
+                &|_| (),
+                None,
+                &None,
+                Blink::default(),
+                || state,
+            );
+        };
+
+        press(&mut state, mouse::Cursor::Available(Point::new(10.0, 10.0)));
+        assert!(!state.is_focused());
+
+        state.focus();
+
+        press(&mut state, mouse::Cursor::Available(Point::new(10.0, 10.0)));
+        assert!(state.is_focused());
+
+        press(
+            &mut state,
+            mouse::Cursor::Available(Point::new(10.0, 500.0)),
+        );
+        assert!(!state.is_focused());
+    }
+
+    fn with_affixes<'a>() -> TextInput<'a, String, Null> {
+        TextInput::new("", "hello", |value| value)
+            .leading(Space::new(Length::Units(15), Length::Units(10)))
+            .trailing(
+                widget::Button::new(Space::new(
+                    Length::Units(10),
+                    Length::Units(10),
+                ))
+                .padding(0)
+                .on_press(String::from("clear")),
+            )
+    }
+
+    #[test]
+    fn affixes_make_room_inside_the_borders() {
+        let text_input: Element<'_, String, Null> = with_affixes().into();
+
+        let node = text_input.as_widget().layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let bounds: Vec<_> = Layout::new(&node)
+            .children()
+            .map(|layout| layout.bounds())
+            .collect();
+
+        assert_eq!(node.size(), Size::new(100.0, 30.0));
+        assert_eq!(
+            bounds,
+            vec![
+                Rectangle::new(Point::new(25.0, 5.0), Size::new(55.0, 20.0)),
+                Rectangle::new(Point::new(5.0, 10.0), Size::new(15.0, 10.0)),
+                Rectangle::new(Point::new(85.0, 10.0), Size::new(10.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn affixes_focus_the_input_unless_they_handle_clicks() {
+        let mut text_input: Element<'_, String, Null> = with_affixes().into();
+        let mut tree = Tree::new(&text_input);
+
+        let node = text_input.as_widget().layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let mut click = |tree: &mut Tree, cursor| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            for event in [
+                mouse::Event::ButtonPressed(mouse::Button::Left),
+                mouse::Event::ButtonReleased(mouse::Button::Left),
+            ] {
+                let _ = text_input.as_widget_mut().on_event(
+                    tree,
+                    Event::Mouse(event),
+                    Layout::new(&node),
+                    cursor,
+                    &Null::new(),
+                    &mut crate::clipboard::Null,
+                    &mut shell,
+                );
+            }
+
+            messages
+        };
+
+        let is_focused =
+            |tree: &Tree| tree.state.downcast_ref::<State>().is_focused();
+
+        assert_eq!(
+            click(&mut tree, mouse::Cursor::Available(Point::new(90.0, 15.0))),
+            ["clear"]
+        );
+        assert!(!is_focused(&tree));
+
+        assert!(click(
+            &mut tree,
+            mouse::Cursor::Available(Point::new(10.0, 15.0))
+        )
+        .is_empty());
+        assert!(is_focused(&tree));
+        assert!(matches!(
+            tree.state
+                .downcast_ref::<State>()
+                .cursor()
+                .state(&Value::new("hello")),
+            cursor::State::Index(0)
+        ));
+    }
+
+    #[test]
+    fn steady_cursor_never_blinks() {
+        let start = Instant::now();
+        let mut focus = Focus::new(start);
+
+        focus.now = start + Duration::from_millis(600);
+
+        for blink in [Blink::Steady, Blink::Interval(Duration::ZERO)] {
+            assert!(blink.is_cursor_visible(&focus));
+            assert_eq!(blink.next_toggle(&focus), None);
+        }
+    }
+
+    fn send_input(
+        event: Event,
+        state: &mut State,
+        value: &mut Value,
+        is_secure: bool,
+    ) -> (event::Status, Vec<String>, InputMethod) {
+        let renderer = Null::new();
+        let node = layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+            Length::Fill,
+            Padding::ZERO,
+            None,
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = update(
+            event,
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &renderer,
+            &mut crate::clipboard::Null,
+            &mut shell,
+            value,
+            None,
+            &Default::default(),
+            is_secure,
+            &|contents| contents,
+            None,
+            &None,
+            Blink::default(),
+            || state,
+        );
+
+        let input_method = shell.input_method();
+
+        (status, messages, input_method)
+    }
+
+    #[test]
+    fn input_method_composes_in_place_and_commits() {
+        let mut value = Value::new("ac");
+        let mut state = State::new();
+        state.focus();
+        state.move_cursor_to(1);
+
+        let (status, messages, _) = send_input(
+            Event::InputMethod(input_method::Event::Preedit(
+                String::from("bb"),
+                Some(1..1),
+            )),
+            &mut state,
+            &mut value,
+            false,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(messages.is_empty());
+
+        let composition = Composition::new(
+            &value,
+            state.cursor,
+            state.preedit.as_ref().unwrap(),
+        );
+
+        assert_eq!(composition.value.to_string(), "abbc");
+        assert_eq!((composition.start, composition.end), (1, 3));
+        assert_eq!(composition.cursor, 2);
+
+        // The input method handles the keys while composing
+        let (status, messages, _) = send_input(
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Backspace,
+                modifiers: keyboard::Modifiers::default(),
+            }),
+            &mut state,
+            &mut value,
+            false,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+        assert!(messages.is_empty());
+
+        let (_, messages, _) = send_input(
+            Event::InputMethod(input_method::Event::Commit(String::from("b"))),
+            &mut state,
+            &mut value,
+            false,
+        );
+
+        assert_eq!(messages, vec![String::from("abc")]);
+        assert!(state.preedit.is_none());
+        assert!(matches!(
+            state.cursor.state(&value),
+            cursor::State::Index(2)
+        ));
+    }
+
+    #[test]
+    fn empty_preedit_cancels_the_composition() {
+        let mut value = Value::new("hello");
+        let mut state = State::new();
+        state.focus();
+
+        for content in ["w", ""] {
+            let (_, messages, _) = send_input(
+                Event::InputMethod(input_method::Event::Preedit(
+                    String::from(content),
+                    None,
+                )),
+                &mut state,
+                &mut value,
+                false,
+            );
+
+            assert!(messages.is_empty());
+        }
+
+        assert!(state.preedit.is_none());
+        assert_eq!(value.to_string(), "hello");
+    }
+
+    #[test]
+    fn input_method_is_only_enabled_while_focused() {
+        let mut value = Value::new("hello");
+        let mut state = State::new();
+
+        let redraw =
+            || Event::Window(window::Event::RedrawRequested(Instant::now()));
+
+        let (_, _, input_method) =
+            send_input(redraw(), &mut state, &mut value, false);
+
+        assert_eq!(input_method, InputMethod::Disabled);
+
+        state.focus();
+
+        let (_, _, input_method) =
+            send_input(redraw(), &mut state, &mut value, false);
+
+        assert!(matches!(input_method, InputMethod::Enabled { .. }));
+
+        let (_, _, input_method) =
+            send_input(redraw(), &mut state, &mut value, true);
+
+        assert_eq!(input_method, InputMethod::Disabled);
+    }
+}
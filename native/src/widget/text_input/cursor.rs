@@ -60,6 +60,10 @@ impl Cursor {
         }
     }
 
+    pub(crate) fn clamp(&mut self, value: &Value) {
+        self.state = self.state(value);
+    }
+
     pub(crate) fn move_to(&mut self, position: usize) {
         self.state = State::Index(position);
     }
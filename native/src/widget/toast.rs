@@ -0,0 +1,954 @@
+//! Show transient notifications over some content.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text;
+use crate::time::{Duration, Instant};
+use crate::touch;
+use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::sync::atomic::{self, AtomicU64};
+
+pub use iced_style::toast::{Appearance, StyleSheet};
+
+/// The duration of the fade and slide animations of a [`Toast`].
+const ANIMATION_DURATION: Duration = Duration::from_millis(200);
+
+/// The distance a [`Toast`] slides while appearing and disappearing.
+const SLIDE_DISTANCE: f32 = 24.0;
+
+/// The identifier of a [`Toast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    /// Creates a unique [`Id`].
+    pub fn unique() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+/// The severity of a [`Toast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    /// Some information.
+    Info,
+    /// The report of a success.
+    Success,
+    /// A warning about something.
+    Warning,
+    /// The report of an error.
+    Error,
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self::Info
+    }
+}
+
+/// A transient notification shown by a [`Manager`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    /// The [`Id`] of the [`Toast`].
+    pub id: Id,
+
+    /// The message of the [`Toast`].
+    pub message: String,
+
+    /// The [`Level`] of the [`Toast`].
+    pub level: Level,
+
+    /// How long the [`Toast`] is shown before it is dismissed.
+    ///
+    /// The time only runs while the [`Toast`] is shown and not hovered.
+    pub duration: Duration,
+}
+
+impl Toast {
+    /// The default duration of a [`Toast`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+
+    /// Creates a new informational [`Toast`] with a unique [`Id`] and the
+    /// given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            id: Id::unique(),
+            message: message.into(),
+            level: Level::default(),
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Sets the [`Level`] of the [`Toast`].
+    pub fn level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the duration of the [`Toast`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// The corner of the screen where a [`Manager`] stacks its toasts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    BottomRight,
+}
+
+impl Default for Corner {
+    fn default() -> Self {
+        Self::BottomRight
+    }
+}
+
+/// A widget that shows a stack of [`Toast`]s over its content.
+///
+/// The application owns the [`Toast`]s: it pushes new ones and removes them
+/// once the [`Manager`] produces their dismissed message. Only the first
+/// [`Toast`]s up to a maximum are shown at a time; the rest wait in line, and
+/// their time does not run until they are shown.
+///
+/// Hovering a [`Toast`] pauses its time, and clicking it dismisses it.
+#[allow(missing_debug_implementations)]
+pub struct Manager<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    toasts: &'a [Toast],
+    on_dismiss: Box<dyn Fn(Id) -> Message + 'a>,
+    corner: Corner,
+    max_visible: usize,
+    width: f32,
+    spacing: f32,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Manager<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default maximum amount of [`Toast`]s shown at a time.
+    pub const DEFAULT_MAX_VISIBLE: usize = 5;
+
+    /// The default width of a [`Toast`].
+    pub const DEFAULT_WIDTH: f32 = 300.0;
+
+    /// The default [`Padding`] of a [`Toast`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(10);
+
+    /// Creates a new [`Manager`] showing the given [`Toast`]s over some
+    /// content.
+    ///
+    /// It produces the message returned by `on_dismiss` once a [`Toast`] is
+    /// dismissed and has faded out.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        toasts: &'a [Toast],
+        on_dismiss: impl Fn(Id) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            toasts,
+            on_dismiss: Box::new(on_dismiss),
+            corner: Corner::default(),
+            max_visible: Self::DEFAULT_MAX_VISIBLE,
+            width: Self::DEFAULT_WIDTH,
+            spacing: 10.0,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Corner`] where the [`Toast`]s are stacked.
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the maximum amount of [`Toast`]s shown at a time.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Sets the width of the [`Toast`]s.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the [`Toast`]s and from the edges of the
+    /// screen.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Toast`]s.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Toast`]s.
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the font of the [`Toast`]s.
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Toast`]s.
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Manager`].
+#[derive(Debug, Default)]
+struct State {
+    timers: Vec<Timer>,
+    last_tick: Option<Instant>,
+}
+
+impl State {
+    fn timer(&self, id: Id) -> Option<&Timer> {
+        self.timers.iter().find(|timer| timer.id == id)
+    }
+
+    fn is_gone(&self, id: Id) -> bool {
+        self.timer(id).map_or(false, |timer| timer.is_gone)
+    }
+}
+
+/// The remaining time and animation state of a shown [`Toast`].
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    id: Id,
+    shown_at: Instant,
+    remaining: Duration,
+    dismissed_at: Option<Instant>,
+    is_gone: bool,
+    is_hovered: bool,
+}
+
+impl Timer {
+    fn opacity(&self, now: Instant) -> f32 {
+        let progress = |since: Instant| {
+            (now.saturating_duration_since(since).as_secs_f32()
+                / ANIMATION_DURATION.as_secs_f32())
+            .min(1.0)
+        };
+
+        let appeared = progress(self.shown_at);
+        let disappeared = self.dismissed_at.map_or(0.0, progress);
+
+        appeared.min(1.0 - disappeared)
+    }
+
+    fn is_animating(&self, now: Instant) -> bool {
+        !self.is_gone
+            && (self.dismissed_at.is_some()
+                || now < self.shown_at + ANIMATION_DURATION)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Manager<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content))
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let mut children = Vec::with_capacity(2);
+
+        children.extend(self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        ));
+
+        if !self.toasts.is_empty() {
+            let state = tree.state.downcast_mut::<State>();
+
+            children.push(overlay::Element::new(
+                Point::ORIGIN,
+                Box::new(Toasts {
+                    state,
+                    toasts: self.toasts,
+                    on_dismiss: &self.on_dismiss,
+                    corner: self.corner,
+                    max_visible: self.max_visible,
+                    width: self.width,
+                    spacing: self.spacing,
+                    padding: self.padding,
+                    text_size: self
+                        .text_size
+                        .unwrap_or_else(|| renderer.default_size()),
+                    font: self.font.clone(),
+                    style: &self.style,
+                }),
+            ));
+        }
+
+        match children.len() {
+            0 => None,
+            1 => children.pop(),
+            _ => Some(overlay::Group::with_children(children).overlay()),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Manager<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(manager: Manager<'a, Message, Renderer>) -> Self {
+        Element::new(manager)
+    }
+}
+
+/// The overlay of a [`Manager`] showing its [`Toast`]s.
+struct Toasts<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    state: &'a mut State,
+    toasts: &'a [Toast],
+    on_dismiss: &'a dyn Fn(Id) -> Message,
+    corner: Corner,
+    max_visible: usize,
+    width: f32,
+    spacing: f32,
+    padding: Padding,
+    text_size: u16,
+    font: Renderer::Font,
+    style: &'a <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Toasts<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the [`Toast`]s that are currently shown, in order.
+    fn visible(&self) -> impl Iterator<Item = &'a Toast> + '_ {
+        self.toasts
+            .iter()
+            .filter(|toast| !self.state.is_gone(toast.id))
+            .take(self.max_visible)
+    }
+
+    fn tick(&mut self, now: Instant, shell: &mut Shell<'_, Message>) {
+        let last_tick = self.state.last_tick.replace(now);
+        let toasts = self.toasts;
+
+        // Forget the toasts removed by the application
+        self.state
+            .timers
+            .retain(|timer| toasts.iter().any(|toast| toast.id == timer.id));
+
+        for timer in &mut self.state.timers {
+            if timer.is_gone {
+                continue;
+            }
+
+            match timer.dismissed_at {
+                None => {
+                    if !timer.is_hovered {
+                        let since = last_tick
+                            .map_or(timer.shown_at, |last_tick| {
+                                last_tick.max(timer.shown_at)
+                            });
+
+                        timer.remaining = timer.remaining.saturating_sub(
+                            now.saturating_duration_since(since),
+                        );
+                    }
+
+                    if timer.remaining == Duration::ZERO {
+                        timer.dismissed_at = Some(now);
+                    }
+                }
+                Some(dismissed_at) => {
+                    if now >= dismissed_at + ANIMATION_DURATION {
+                        timer.is_gone = true;
+
+                        shell.publish((self.on_dismiss)(timer.id));
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+
+            if timer.is_gone || timer.is_animating(now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            } else if !timer.is_hovered {
+                shell.request_redraw(window::RedrawRequest::At(
+                    now + timer.remaining,
+                ));
+            }
+        }
+
+        // Start the time of the toasts that were waiting in line, including
+        // the ones taking the place of the toasts that just disappeared
+        let started: Vec<_> = self
+            .visible()
+            .filter(|toast| self.state.timer(toast.id).is_none())
+            .map(|toast| Timer {
+                id: toast.id,
+                shown_at: now,
+                remaining: toast.duration,
+                dismissed_at: None,
+                is_gone: false,
+                is_hovered: false,
+            })
+            .collect();
+
+        if !started.is_empty() {
+            self.state.timers.extend(started);
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    fn hovered(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<&'a Toast> {
+        self.visible()
+            .zip(layout.children())
+            .find(|(_, layout)| cursor.is_over(&layout.bounds()))
+            .map(|(toast, _)| toast)
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Toasts<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let width = self.width.min(bounds.width - 2.0 * self.spacing).max(0.0);
+
+        let mut offset = self.spacing;
+
+        let children = self
+            .visible()
+            .map(|toast| {
+                let (_, text_height) = renderer.measure(
+                    &toast.message,
+                    self.text_size,
+                    text::LineHeight::default(),
+                    0.0,
+                    self.font.clone(),
+                    Size::new(
+                        (width - f32::from(self.padding.horizontal())).max(0.0),
+                        f32::INFINITY,
+                    ),
+                );
+
+                let height = text_height + f32::from(self.padding.vertical());
+
+                let x = match self.corner {
+                    Corner::TopLeft | Corner::BottomLeft => self.spacing,
+                    Corner::TopRight | Corner::BottomRight => {
+                        bounds.width - self.spacing - width
+                    }
+                };
+
+                let y = match self.corner {
+                    Corner::TopLeft | Corner::TopRight => offset,
+                    Corner::BottomLeft | Corner::BottomRight => {
+                        bounds.height - offset - height
+                    }
+                };
+
+                offset += height + self.spacing;
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, y));
+
+                node
+            })
+            .collect();
+
+        let mut node = layout::Node::with_children(bounds, children);
+        node.move_to(position);
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.tick(now, shell);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Mouse(mouse::Event::CursorLeft) => {
+                let hovered = match event {
+                    Event::Mouse(mouse::Event::CursorLeft) => None,
+                    _ => self.hovered(layout, cursor).map(|toast| toast.id),
+                };
+
+                let mut is_changed = false;
+
+                for timer in &mut self.state.timers {
+                    let is_hovered = Some(timer.id) == hovered;
+
+                    is_changed |= timer.is_hovered != is_hovered;
+                    timer.is_hovered = is_hovered;
+                }
+
+                // Pause or resume the time of the toasts whose hover changed;
+                // the next tick only keeps redrawing while they animate
+                if is_changed {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let id = match self.hovered(layout, cursor) {
+                    Some(toast) => toast.id,
+                    None => return event::Status::Ignored,
+                };
+
+                let now = self.state.last_tick.unwrap_or_else(Instant::now);
+
+                if let Some(timer) =
+                    self.state.timers.iter_mut().find(|timer| timer.id == id)
+                {
+                    if timer.dismissed_at.is_none() {
+                        timer.dismissed_at = Some(now);
+                    }
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered(layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+    ) {
+        let now = self.state.last_tick.unwrap_or_else(Instant::now);
+
+        for (toast, layout) in self.visible().zip(layout.children()) {
+            // Toasts are invisible until their time starts
+            let opacity = self
+                .state
+                .timer(toast.id)
+                .map_or(0.0, |timer| timer.opacity(now));
+
+            if opacity <= 0.0 {
+                continue;
+            }
+
+            let appearance = match toast.level {
+                Level::Info => theme.info(self.style),
+                Level::Success => theme.success(self.style),
+                Level::Warning => theme.warning(self.style),
+                Level::Error => theme.error(self.style),
+            };
+
+            let fade = |color: Color| Color {
+                a: color.a * opacity,
+                ..color
+            };
+
+            let slide = SLIDE_DISTANCE * (1.0 - opacity);
+            let translation = match self.corner {
+                Corner::TopLeft | Corner::BottomLeft => {
+                    Vector::new(-slide, 0.0)
+                }
+                Corner::TopRight | Corner::BottomRight => {
+                    Vector::new(slide, 0.0)
+                }
+            };
+
+            let bounds = layout.bounds();
+
+            renderer.with_translation(translation, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius.into(),
+                        border_width: appearance.border_width,
+                        border_color: fade(appearance.border_color),
+                    },
+                    match appearance.background {
+                        Background::Color(color) => {
+                            Background::Color(fade(color))
+                        }
+                    },
+                );
+
+                renderer.fill_text_in(
+                    &toast.message,
+                    Rectangle {
+                        x: bounds.x + f32::from(self.padding.left),
+                        y: bounds.y + f32::from(self.padding.top),
+                        width: bounds.width
+                            - f32::from(self.padding.horizontal()),
+                        height: bounds.height
+                            - f32::from(self.padding.vertical()),
+                    },
+                    fade(appearance.text_color),
+                    Some(self.text_size),
+                    self.font.clone(),
+                    (alignment::Horizontal::Left, alignment::Vertical::Top),
+                );
+            });
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.hovered(layout, mouse::Cursor::Available(cursor_position))
+            .is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::renderer::Null;
+    use crate::widget::Space;
+
+    const BOUNDS: Size = Size::new(800.0, 600.0);
+
+    fn events(
+        toasts: &[Toast],
+        max_visible: usize,
+        tree: &mut Tree,
+        events: impl IntoIterator<Item = (Event, Point)>,
+    ) -> Vec<Id> {
+        let renderer = Null::new();
+        let mut manager = Manager::<Id, Null>::new(
+            Space::new(Length::Fill, Length::Fill),
+            toasts,
+            |id| id,
+        )
+        .max_visible(max_visible);
+
+        let mut messages = Vec::new();
+
+        {
+            let mut overlay = Widget::overlay(
+                &mut manager,
+                tree,
+                Layout::new(&layout::Node::new(BOUNDS)),
+                &renderer,
+            )
+            .expect("Toast overlay");
+
+            for (event, cursor_position) in events {
+                let node = overlay.layout(&renderer, BOUNDS, Vector::ZERO);
+                let mut shell = Shell::new(&mut messages);
+
+                let _ = overlay.on_event(
+                    event,
+                    Layout::new(&node),
+                    mouse::Cursor::Available(cursor_position),
+                    &renderer,
+                    &mut crate::clipboard::Null,
+                    &mut shell,
+                );
+            }
+        }
+
+        messages
+    }
+
+    fn tick(now: Instant) -> (Event, Point) {
+        (
+            Event::Window(window::Event::RedrawRequested(now)),
+            Point::ORIGIN,
+        )
+    }
+
+    fn state(toasts: &[Toast]) -> Tree {
+        Tree::new(&Element::<Id, Null>::from(Manager::new(
+            Space::new(Length::Fill, Length::Fill),
+            toasts,
+            |id| id,
+        )))
+    }
+
+    #[test]
+    fn dismisses_toasts_after_their_duration() {
+        let toasts = [Toast::new("Saved").duration(Duration::from_secs(1))];
+        let mut tree = state(&toasts);
+        let start = Instant::now();
+
+        let dismissed = events(
+            &toasts,
+            5,
+            &mut tree,
+            [
+                tick(start),
+                tick(start + Duration::from_secs(1)),
+                tick(start + Duration::from_secs(1) + ANIMATION_DURATION),
+            ],
+        );
+
+        assert_eq!(dismissed, [toasts[0].id]);
+    }
+
+    #[test]
+    fn hovering_pauses_the_dismissal() {
+        let toasts = [Toast::new("Saved").duration(Duration::from_secs(1))];
+        let mut tree = state(&toasts);
+        let start = Instant::now();
+
+        // Toasts are stacked in the bottom right corner by default
+        let hovered = Point::new(BOUNDS.width - 20.0, BOUNDS.height - 20.0);
+
+        let dismissed = events(
+            &toasts,
+            5,
+            &mut tree,
+            [
+                tick(start),
+                (
+                    Event::Mouse(mouse::Event::CursorMoved {
+                        position: hovered,
+                    }),
+                    hovered,
+                ),
+                tick(start + Duration::from_secs(10)),
+            ],
+        );
+
+        assert!(dismissed.is_empty());
+    }
+
+    #[test]
+    fn queues_toasts_beyond_the_limit() {
+        let toasts = [
+            Toast::new("First").duration(Duration::from_secs(1)),
+            Toast::new("Second").duration(Duration::from_secs(1)),
+        ];
+        let mut tree = state(&toasts);
+        let start = Instant::now();
+        let gone = start + Duration::from_secs(1) + ANIMATION_DURATION;
+
+        // The second toast only starts once the first one is gone
+        let dismissed = events(
+            &toasts,
+            1,
+            &mut tree,
+            [
+                tick(start),
+                tick(start + Duration::from_secs(1)),
+                tick(gone),
+                tick(gone + Duration::from_millis(500)),
+            ],
+        );
+
+        assert_eq!(dismissed, [toasts[0].id]);
+
+        let dismissed = events(
+            &toasts,
+            1,
+            &mut tree,
+            [
+                tick(gone + Duration::from_secs(1)),
+                tick(gone + Duration::from_secs(1) + ANIMATION_DURATION),
+            ],
+        );
+
+        assert_eq!(dismissed, [toasts[1].id]);
+    }
+}
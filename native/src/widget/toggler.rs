@@ -5,10 +5,10 @@ use crate::layout;
 use crate::mouse;
 use crate::renderer;
 use crate::text;
-use crate::widget::{self, Row, Text, Tree};
+use crate::touch;
+use crate::widget::{self, label, Tree};
 use crate::{
-    Alignment, Clipboard, Element, Event, Layout, Length, Point, Rectangle,
-    Shell, Widget,
+    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
 };
 
 pub use iced_style::toggler::{Appearance, StyleSheet};
@@ -38,11 +38,14 @@ where
     on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
     label: Option<String>,
     width: Length,
-    size: u16,
+    size: Option<f32>,
+    stroke_width: Option<f32>,
     text_size: Option<u16>,
     text_alignment: alignment::Horizontal,
     spacing: u16,
     font: Renderer::Font,
+    label_position: label::Position,
+    label_anchor: label::Anchor,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -51,7 +54,13 @@ where
     Renderer: text::Renderer,
     Renderer::Theme: StyleSheet,
 {
-    /// The default size of a [`Toggler`].
+    /// The default size of a [`Toggler`], relative to its text size.
+    pub const DEFAULT_SIZE_RATIO: f32 = 1.0;
+
+    /// The former fixed size of a [`Toggler`], which now follows its text size.
+    #[deprecated(
+        note = "the default size follows the text size; use `DEFAULT_SIZE_RATIO`"
+    )]
     pub const DEFAULT_SIZE: u16 = 20;
 
     /// Creates a new [`Toggler`].
@@ -75,18 +84,37 @@ where
             on_toggle: Box::new(f),
             label: label.into(),
             width: Length::Fill,
-            size: Self::DEFAULT_SIZE,
+            size: None,
+            stroke_width: None,
             text_size: None,
             text_alignment: alignment::Horizontal::Left,
             spacing: 0,
             font: Renderer::Font::default(),
+            label_position: label::Position::Left,
+            label_anchor: label::Anchor::default(),
             style: Default::default(),
         }
     }
 
-    /// Sets the size of the [`Toggler`].
-    pub fn size(mut self, size: u16) -> Self {
-        self.size = size;
+    /// Sets the size of the [`Toggler`], which is the height of its track.
+    ///
+    /// The track is twice as wide as it is tall, and the knob scales
+    /// proportionally to it. By default, the size is [`DEFAULT_SIZE_RATIO`]
+    /// times the text size, so it scales with it.
+    ///
+    /// [`DEFAULT_SIZE_RATIO`]: Self::DEFAULT_SIZE_RATIO
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the width of the borders of the track and the knob of the
+    /// [`Toggler`].
+    ///
+    /// The width is clamped so the knob stays legible at small sizes. By
+    /// default, it is `1.0`.
+    pub fn stroke_width(mut self, stroke_width: f32) -> Self {
+        self.stroke_width = Some(stroke_width);
         self
     }
 
@@ -122,6 +150,18 @@ where
         self
     }
 
+    /// Sets the side of the [`Toggler`] where its label is placed.
+    pub fn label_position(mut self, position: label::Position) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets how the [`Toggler`] is vertically aligned to its label.
+    pub fn label_anchor(mut self, anchor: label::Anchor) -> Self {
+        self.label_anchor = anchor;
+        self
+    }
+
     /// Sets the style of the [`Toggler`].
     pub fn style(
         mut self,
@@ -151,31 +191,24 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let mut row = Row::<(), Renderer>::new()
-            .width(self.width)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center);
-
-        if let Some(label) = &self.label {
-            row = row.push(
-                Text::new(label)
-                    .horizontal_alignment(self.text_alignment)
-                    .font(self.font.clone())
-                    .width(self.width)
-                    .size(
-                        self.text_size
-                            .unwrap_or_else(|| renderer.default_size()),
-                    ),
-            );
-        }
-
-        row = row.push(
-            Row::new()
-                .width(Length::Units(2 * self.size))
-                .height(Length::Units(self.size)),
-        );
-
-        row.layout(renderer, limits)
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let size = self
+            .size
+            .unwrap_or_else(|| f32::from(text_size) * Self::DEFAULT_SIZE_RATIO);
+
+        label::layout(
+            renderer,
+            limits,
+            self.width,
+            self.spacing,
+            Size::new(2.0 * size, size),
+            self.label
+                .as_deref()
+                .map(|label| (label, text_size, self.font.clone())),
+            self.label_position,
+            self.label_anchor,
+        )
     }
 
     fn on_event(
@@ -183,14 +216,15 @@ where
         _state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let mouse_over = cursor.is_over(&layout.bounds());
 
                 if mouse_over {
                     shell.publish((self.on_toggle)(!self.is_toggled));
@@ -208,11 +242,11 @@ where
         &self,
         _state: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        if layout.bounds().contains(cursor_position) {
+        if cursor.is_over(&layout.bounds()) {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -226,7 +260,7 @@ where
         theme: &Renderer::Theme,
         style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         /// Makes sure that the border radius of the toggler looks good at every size.
@@ -237,6 +271,7 @@ where
         const SPACE_RATIO: f32 = 0.05;
 
         let mut children = layout.children();
+        let toggler_layout = children.next().unwrap();
 
         if let Some(label) = &self.label {
             let label_layout = children.next().unwrap();
@@ -247,6 +282,8 @@ where
                 label_layout,
                 label,
                 self.text_size,
+                text::LineHeight::default(),
+                0.0,
                 self.font.clone(),
                 Default::default(),
                 self.text_alignment,
@@ -254,10 +291,9 @@ where
             );
         }
 
-        let toggler_layout = children.next().unwrap();
         let bounds = toggler_layout.bounds();
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(&layout.bounds());
 
         let style = if is_mouse_over {
             theme.hovered(&self.style, self.is_toggled)
@@ -267,6 +303,10 @@ where
 
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
         let space = SPACE_RATIO * bounds.height;
+        let border_width = label::stroke_width(
+            self.stroke_width.unwrap_or(1.0),
+            bounds.height,
+        );
 
         let toggler_background_bounds = Rectangle {
             x: bounds.x + space,
@@ -279,7 +319,7 @@ where
             renderer::Quad {
                 bounds: toggler_background_bounds,
                 border_radius: border_radius.into(),
-                border_width: 1.0,
+                border_width,
                 border_color: style
                     .background_border
                     .unwrap_or(style.background),
@@ -303,7 +343,7 @@ where
             renderer::Quad {
                 bounds: toggler_foreground_bounds,
                 border_radius: border_radius.into(),
-                border_width: 1.0,
+                border_width,
                 border_color: style
                     .foreground_border
                     .unwrap_or(style.foreground),
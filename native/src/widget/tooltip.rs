@@ -2,6 +2,7 @@
 use crate::event;
 use crate::layout;
 use crate::mouse;
+use crate::overlay::position::{self, Side, Strategy};
 use crate::renderer;
 use crate::text;
 use crate::widget;
@@ -9,13 +10,21 @@ use crate::widget::container;
 use crate::widget::overlay;
 use crate::widget::{Text, Tree};
 use crate::{
-    Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle,
+    Alignment, Clipboard, Element, Event, Layout, Length, Padding, Rectangle,
     Shell, Size, Vector, Widget,
 };
 
 use std::borrow::Cow;
 
 /// An element to display a widget over another.
+///
+/// The tooltip is shown whenever the cursor is over the content, whether or
+/// not the content reacts to it. This way, disabled widgets—like a
+/// [`Button`] without an `on_press` message or a disabled [`Checkbox`]—can
+/// still explain why they are disabled.
+///
+/// [`Button`]: crate::widget::Button
+/// [`Checkbox`]: crate::widget::Checkbox
 #[allow(missing_debug_implementations)]
 pub struct Tooltip<'a, Message, Renderer: text::Renderer>
 where
@@ -27,7 +36,7 @@ where
     position: Position,
     gap: u16,
     padding: u16,
-    snap_within_viewport: bool,
+    strategy: Strategy,
     style: <Renderer::Theme as container::StyleSheet>::Style,
 }
 
@@ -53,7 +62,7 @@ where
             position,
             gap: 0,
             padding: Self::DEFAULT_PADDING,
-            snap_within_viewport: true,
+            strategy: Strategy::Auto,
             style: Default::default(),
         }
     }
@@ -85,8 +94,21 @@ where
     }
 
     /// Sets whether the [`Tooltip`] is snapped within the viewport.
-    pub fn snap_within_viewport(mut self, snap: bool) -> Self {
-        self.snap_within_viewport = snap;
+    ///
+    /// This is a shorthand for [`Tooltip::strategy`] with either
+    /// [`Strategy::Auto`] or [`Strategy::None`].
+    pub fn snap_within_viewport(self, snap: bool) -> Self {
+        self.strategy(if snap { Strategy::Auto } else { Strategy::None })
+    }
+
+    /// Sets the [`Strategy`] used to keep the [`Tooltip`] within the
+    /// viewport.
+    ///
+    /// By default, it is [`Strategy::Auto`]: the [`Tooltip`] moves to the
+    /// opposite side of the content when it does not fit, and it is nudged
+    /// back within the viewport along the content.
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
         self
     }
 
@@ -135,34 +157,36 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        self.content.as_widget_mut().on_event(
-            &mut tree.children[0],
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            shell,
-        )
+        shell.traced(0, |shell| {
+            self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+            )
+        })
     }
 
     fn mouse_interaction(
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
         self.content.as_widget().mouse_interaction(
             &tree.children[0],
             layout,
-            cursor_position,
+            cursor,
             viewport,
             renderer,
         )
@@ -175,7 +199,7 @@ where
         theme: &Renderer::Theme,
         inherited_style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
         self.content.as_widget().draw(
@@ -184,28 +208,28 @@ where
             theme,
             inherited_style,
             layout,
-            cursor_position,
+            cursor,
             viewport,
         );
 
         let tooltip = &self.tooltip;
 
-        draw(
+        draw_with_strategy(
             renderer,
             theme,
             inherited_style,
             layout,
-            cursor_position,
+            cursor,
             viewport,
             self.position,
             self.gap,
             self.padding,
-            self.snap_within_viewport,
+            self.strategy,
             &self.style,
             |renderer, limits| {
                 Widget::<(), Renderer>::layout(tooltip, renderer, limits)
             },
-            |renderer, defaults, layout, cursor_position, viewport| {
+            |renderer, defaults, layout, cursor, viewport| {
                 Widget::<(), Renderer>::draw(
                     tooltip,
                     &Tree::empty(),
@@ -213,7 +237,7 @@ where
                     theme,
                     defaults,
                     layout,
-                    cursor_position,
+                    cursor,
                     viewport,
                 );
             },
@@ -264,12 +288,15 @@ pub enum Position {
 }
 
 /// Draws a [`Tooltip`].
+///
+/// When `snap_within_viewport` is `true`, the [`Tooltip`] is kept within the
+/// viewport with [`Strategy::Auto`].
 pub fn draw<Renderer>(
     renderer: &mut Renderer,
     theme: &Renderer::Theme,
     inherited_style: &renderer::Style,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     viewport: &Rectangle,
     position: Position,
     gap: u16,
@@ -281,7 +308,53 @@ pub fn draw<Renderer>(
         &mut Renderer,
         &renderer::Style,
         Layout<'_>,
-        Point,
+        mouse::Cursor,
+        &Rectangle,
+    ),
+) where
+    Renderer: crate::Renderer,
+    Renderer::Theme: container::StyleSheet,
+{
+    draw_with_strategy(
+        renderer,
+        theme,
+        inherited_style,
+        layout,
+        cursor,
+        viewport,
+        position,
+        gap,
+        padding,
+        if snap_within_viewport {
+            Strategy::Auto
+        } else {
+            Strategy::None
+        },
+        style,
+        layout_text,
+        draw_text,
+    )
+}
+
+/// Draws a [`Tooltip`] kept within the viewport with the given [`Strategy`].
+fn draw_with_strategy<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    inherited_style: &renderer::Style,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    viewport: &Rectangle,
+    position: Position,
+    gap: u16,
+    padding: u16,
+    strategy: Strategy,
+    style: &<Renderer::Theme as container::StyleSheet>::Style,
+    layout_text: impl FnOnce(&Renderer, &layout::Limits) -> layout::Node,
+    draw_text: impl FnOnce(
+        &mut Renderer,
+        &renderer::Style,
+        Layout<'_>,
+        mouse::Cursor,
         &Rectangle,
     ),
 ) where
@@ -292,7 +365,7 @@ pub fn draw<Renderer>(
 
     let bounds = layout.bounds();
 
-    if bounds.contains(cursor_position) {
+    if let Some(cursor_position) = cursor.position_over(&bounds) {
         let gap = f32::from(gap);
         let style = theme.appearance(style);
 
@@ -304,7 +377,7 @@ pub fn draw<Renderer>(
             renderer,
             &layout::Limits::new(
                 Size::ZERO,
-                snap_within_viewport
+                (strategy != Strategy::None)
                     .then(|| viewport.size())
                     .unwrap_or(Size::INFINITY),
             )
@@ -313,60 +386,27 @@ pub fn draw<Renderer>(
 
         let padding = f32::from(padding);
         let text_bounds = text_layout.bounds();
-        let x_center = bounds.x + (bounds.width - text_bounds.width) / 2.0;
-        let y_center = bounds.y + (bounds.height - text_bounds.height) / 2.0;
-
-        let mut tooltip_bounds = {
-            let offset = match position {
-                Position::Top => Vector::new(
-                    x_center,
-                    bounds.y - text_bounds.height - gap - padding,
-                ),
-                Position::Bottom => Vector::new(
-                    x_center,
-                    bounds.y + bounds.height + gap + padding,
-                ),
-                Position::Left => Vector::new(
-                    bounds.x - text_bounds.width - gap - padding,
-                    y_center,
-                ),
-                Position::Right => Vector::new(
-                    bounds.x + bounds.width + gap + padding,
-                    y_center,
-                ),
-                Position::FollowCursor => Vector::new(
-                    cursor_position.x,
-                    cursor_position.y - text_bounds.height,
-                ),
-            };
-
-            Rectangle {
-                x: offset.x - padding,
-                y: offset.y - padding,
-                width: text_bounds.width + padding * 2.0,
-                height: text_bounds.height + padding * 2.0,
-            }
+        let size = Size::new(
+            text_bounds.width + padding * 2.0,
+            text_bounds.height + padding * 2.0,
+        );
+
+        let (anchor, side, alignment, gap) = match position {
+            Position::Top => (bounds, Side::Top, Alignment::Center, gap),
+            Position::Bottom => (bounds, Side::Bottom, Alignment::Center, gap),
+            Position::Left => (bounds, Side::Left, Alignment::Center, gap),
+            Position::Right => (bounds, Side::Right, Alignment::Center, gap),
+            Position::FollowCursor => (
+                Rectangle::new(cursor_position, Size::ZERO),
+                Side::Top,
+                Alignment::Start,
+                0.0,
+            ),
         };
 
-        if snap_within_viewport {
-            if tooltip_bounds.x < viewport.x {
-                tooltip_bounds.x = viewport.x;
-            } else if viewport.x + viewport.width
-                < tooltip_bounds.x + tooltip_bounds.width
-            {
-                tooltip_bounds.x =
-                    viewport.x + viewport.width - tooltip_bounds.width;
-            }
-
-            if tooltip_bounds.y < viewport.y {
-                tooltip_bounds.y = viewport.y;
-            } else if viewport.y + viewport.height
-                < tooltip_bounds.y + tooltip_bounds.height
-            {
-                tooltip_bounds.y =
-                    viewport.y + viewport.height - tooltip_bounds.height;
-            }
-        }
+        let tooltip_bounds = position::place(
+            anchor, size, side, alignment, gap, *viewport, strategy,
+        );
 
         renderer.with_layer(Rectangle::with_size(Size::INFINITY), |renderer| {
             container::draw_background(renderer, &style, tooltip_bounds);
@@ -381,7 +421,7 @@ pub fn draw<Renderer>(
                     ),
                     &text_layout,
                 ),
-                cursor_position,
+                cursor,
                 viewport,
             )
         });
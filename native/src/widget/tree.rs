@@ -1,9 +1,128 @@
 //! Store internal widget state in a state tree to ensure continuity.
+//!
+//! # Stateful widgets
+//! Widgets are rebuilt on every `view`, so any state that must outlive them
+//! is kept in a [`Tree`] instead. A stateful widget identifies the type of
+//! its state with [`Widget::tag`], creates it with [`Widget::state`], and
+//! downcasts it back with [`State::downcast_ref`] and [`State::downcast_mut`]
+//! whenever it is given its [`Tree`]:
+//!
+//! ```
+//! use iced_native::event::{self, Event};
+//! use iced_native::layout::{self, Layout};
+//! use iced_native::mouse;
+//! use iced_native::renderer;
+//! use iced_native::widget::{tree, Tree, Widget};
+//! use iced_native::{Clipboard, Length, Point, Rectangle, Shell, Size};
+//!
+//! /// A square that counts how many times it has been clicked.
+//! struct Clicker;
+//!
+//! /// The state of a [`Clicker`], which outlives the widget itself.
+//! #[derive(Default)]
+//! struct State {
+//!     clicks: usize,
+//! }
+//!
+//! impl<Message, Renderer> Widget<Message, Renderer> for Clicker
+//! where
+//!     Renderer: iced_native::Renderer,
+//! {
+//!     fn tag(&self) -> tree::Tag {
+//!         tree::Tag::of::<State>()
+//!     }
+//!
+//!     fn state(&self) -> tree::State {
+//!         tree::State::new(State::default())
+//!     }
+//!
+//!     fn width(&self) -> Length {
+//!         Length::Units(50)
+//!     }
+//!
+//!     fn height(&self) -> Length {
+//!         Length::Units(50)
+//!     }
+//!
+//!     fn layout(
+//!         &self,
+//!         _renderer: &Renderer,
+//!         _limits: &layout::Limits,
+//!     ) -> layout::Node {
+//!         layout::Node::new(Size::new(50.0, 50.0))
+//!     }
+//!
+//!     fn on_event(
+//!         &mut self,
+//!         tree: &mut Tree,
+//!         event: Event,
+//!         layout: Layout<'_>,
+//!         cursor: mouse::Cursor,
+//!         _renderer: &Renderer,
+//!         _clipboard: &mut dyn Clipboard,
+//!         _shell: &mut Shell<'_, Message>,
+//!     ) -> event::Status {
+//!         if let Event::Mouse(mouse::Event::ButtonPressed(_)) = event {
+//!             if cursor.is_over(&layout.bounds()) {
+//!                 tree.state.downcast_mut::<State>().clicks += 1;
+//!
+//!                 return event::Status::Captured;
+//!             }
+//!         }
+//!
+//!         event::Status::Ignored
+//!     }
+//!
+//!     fn draw(
+//!         &self,
+//!         tree: &Tree,
+//!         renderer: &mut Renderer,
+//!         _theme: &Renderer::Theme,
+//!         _style: &renderer::Style,
+//!         layout: Layout<'_>,
+//!         _cursor: mouse::Cursor,
+//!         _viewport: &Rectangle,
+//!     ) {
+//!         let state = tree.state.downcast_ref::<State>();
+//!
+//!         // The more clicks, the rounder the square
+//!         renderer.fill_quad(
+//!             renderer::Quad {
+//!                 bounds: layout.bounds(),
+//!                 border_radius: (state.clicks.min(25) as f32).into(),
+//!                 border_width: 0.0,
+//!                 border_color: iced_native::Color::TRANSPARENT,
+//!             },
+//!             iced_native::Color::BLACK,
+//!         );
+//!     }
+//! }
+//! ```
+//!
+//! The `stateful_widget` example has a complete stateful widget.
+//!
+//! # Reconciliation
+//! After every `view`, the [`Tree`] is reconciled with the new widgets by
+//! [`Tree::diff`]. A [`Tree`] is kept only if its [`Tag`] matches the tag of
+//! the widget now at the same position; otherwise, it is recreated from
+//! scratch with [`Widget::state`] and [`Widget::children`].
+//!
+//! Notice that it is the [`Tag`]—and not the type of the widget—that decides
+//! whether the state is kept. Two different widgets that use the same state
+//! type—or that are both stateless—will happily inherit the state of each
+//! other when one replaces the other at the same position, and
+//! [`Widget::diff`] is called on the old [`Tree`] instead. Widgets that must
+//! not share their state should use a private state type. Likewise, a custom
+//! [`Widget::diff`] that does not reconcile the children of a [`Tree`] leaves
+//! their states untouched, and downcasting them to a different type panics.
+use crate::layout;
 use crate::Widget;
 
 use std::any::{self, Any};
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 
 /// A persistent state widget tree.
 ///
@@ -18,6 +137,15 @@ pub struct Tree {
 
     /// The children of the root widget of the [`Tree`].
     pub children: Vec<Tree>,
+
+    /// The layout [`Cache`] of the root widget of the [`Tree`].
+    ///
+    /// It is invalidated by [`Tree::diff`]. Containers can lay out their
+    /// children through the caches of their trees to reuse the layout of
+    /// the subtrees that were not diffed since.
+    ///
+    /// [`Cache`]: layout::Cache
+    pub layout: layout::Cache,
 }
 
 impl Tree {
@@ -27,6 +155,7 @@ impl Tree {
             tag: Tag::stateless(),
             state: State::None,
             children: Vec::new(),
+            layout: layout::Cache::new(),
         }
     }
 
@@ -43,6 +172,7 @@ impl Tree {
             tag: widget.tag(),
             state: widget.state(),
             children: widget.children(),
+            layout: layout::Cache::new(),
         }
     }
 
@@ -53,7 +183,11 @@ impl Tree {
     ///
     /// Otherwise, the whole [`Tree`] is recreated.
     ///
+    /// Either way, the layout [`Cache`] of the [`Tree`] is invalidated, since
+    /// the [`Widget`] may have changed.
+    ///
     /// [`Widget::diff`]: crate::Widget::diff
+    /// [`Cache`]: layout::Cache
     pub fn diff<'a, Message, Renderer>(
         &mut self,
         new: impl Borrow<dyn Widget<Message, Renderer> + 'a>,
@@ -61,6 +195,8 @@ impl Tree {
         Renderer: crate::Renderer,
     {
         if self.tag == new.borrow().tag() {
+            self.layout.invalidate();
+
             new.borrow().diff(self)
         } else {
             *self = Self::new(new);
@@ -105,11 +241,111 @@ impl Tree {
             );
         }
     }
+
+    /// Returns an [`Inspect`] of the [`Tree`], which can be used to dump its
+    /// contents for debugging purposes.
+    ///
+    /// Every node of the [`Tree`] is written on its own line, indented by its
+    /// depth, with the type name of its state followed by its [`fmt::Debug`]
+    /// representation:
+    ///
+    /// ```text
+    /// iced_native::widget::scrollable::State: State { .. }
+    ///   ()
+    ///     iced_native::widget::text_input::State: State { .. }
+    ///     iced_native::widget::button::State: State { is_pressed: false, ripples: [] }
+    ///     my_app::State: <opaque>
+    /// ```
+    ///
+    /// Stateless nodes are written as `()`. States that were not created with
+    /// [`State::with_debug`]—or any state when the `debug` feature is
+    /// disabled—are written as `<opaque>`.
+    ///
+    /// The output only depends on the contents of the [`Tree`], so it can be
+    /// diffed between frames.
+    pub fn inspect(&self) -> Inspect<'_> {
+        Inspect { tree: self }
+    }
+
+    /// Invalidates the layout caches of the [`Tree`] and all of its
+    /// descendants.
+    ///
+    /// The layout of a widget can change without its [`Tree`] being diffed
+    /// when the widget invalidates it with [`Shell::invalidate_layout`]; the
+    /// whole layout is computed again then.
+    ///
+    /// [`Shell::invalidate_layout`]: crate::Shell::invalidate_layout
+    pub fn invalidate_layout(&self) {
+        self.layout.invalidate();
+
+        for child in &self.children {
+            child.invalidate_layout();
+        }
+    }
+
+    /// Returns the sum of the [`Statistics`] of the layout caches of the
+    /// [`Tree`] and all of its descendants.
+    ///
+    /// [`Statistics`]: layout::cache::Statistics
+    pub fn layout_statistics(&self) -> layout::cache::Statistics {
+        self.children
+            .iter()
+            .map(Tree::layout_statistics)
+            .fold(self.layout.statistics(), |total, statistics| {
+                total + statistics
+            })
+    }
+}
+
+/// A displayable dump of a [`Tree`].
+///
+/// It can be obtained with [`Tree::inspect`].
+#[derive(Debug, Clone, Copy)]
+pub struct Inspect<'a> {
+    tree: &'a Tree,
+}
+
+impl<'a> fmt::Display for Inspect<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_node(
+            f: &mut fmt::Formatter<'_>,
+            tree: &Tree,
+            depth: usize,
+        ) -> fmt::Result {
+            write!(f, "{:indent$}{}", "", tree.tag.name(), indent = depth * 2)?;
+
+            match &tree.state {
+                State::None => {}
+                State::Some(state) => {
+                    match state.downcast_ref::<Debuggable>() {
+                        Some(debuggable) => {
+                            write!(f, ": ")?;
+                            (debuggable.debug)(debuggable.state.as_ref(), f)?;
+                        }
+                        None => write!(f, ": <opaque>")?,
+                    }
+                }
+            }
+
+            writeln!(f)?;
+
+            for child in &tree.children {
+                write_node(f, child, depth + 1)?;
+            }
+
+            Ok(())
+        }
+
+        write_node(f, self.tree, 0)
+    }
 }
 
 /// The identifier of some widget state.
-#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
-pub struct Tag(any::TypeId);
+#[derive(Debug, Clone, Copy)]
+pub struct Tag {
+    id: any::TypeId,
+    name: &'static str,
+}
 
 impl Tag {
     /// Creates a [`Tag`] for a state of type `T`.
@@ -117,13 +353,49 @@ impl Tag {
     where
         T: 'static,
     {
-        Self(any::TypeId::of::<T>())
+        Self {
+            id: any::TypeId::of::<T>(),
+            name: any::type_name::<T>(),
+        }
     }
 
     /// Creates a [`Tag`] for a stateless widget.
     pub fn stateless() -> Self {
         Self::of::<()>()
     }
+
+    /// Returns the type name of the state identified by the [`Tag`].
+    ///
+    /// The name is meant for debugging purposes only.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl PartialEq for Tag {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Tag {}
+
+impl PartialOrd for Tag {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tag {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+impl Hash for Tag {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
 }
 
 /// The internal [`State`] of a widget.
@@ -144,19 +416,47 @@ impl State {
         State::Some(Box::new(state))
     }
 
+    /// Creates a new [`State`] that can be dumped with [`Tree::inspect`].
+    ///
+    /// When the `debug` feature is disabled, this is equivalent to
+    /// [`State::new`].
+    pub fn with_debug<T>(state: T) -> Self
+    where
+        T: fmt::Debug + 'static,
+    {
+        if cfg!(feature = "debug") {
+            State::Some(Box::new(Debuggable {
+                state: Box::new(state),
+                debug: |state, f| match state.downcast_ref::<T>() {
+                    Some(state) => write!(f, "{:?}", state),
+                    None => write!(f, "<opaque>"),
+                },
+            }))
+        } else {
+            State::new(state)
+        }
+    }
+
     /// Downcasts the [`State`] to `T` and returns a reference to it.
     ///
     /// # Panics
     /// This method will panic if the downcast fails or the [`State`] is [`State::None`].
+    /// The panic message names the type `T`, which is normally the result of
+    /// a [`Tag`] that does not match the [`State`] of a widget.
     pub fn downcast_ref<T>(&self) -> &T
     where
         T: 'static,
     {
         match self {
-            State::None => panic!("Downcast on stateless state"),
-            State::Some(state) => {
-                state.downcast_ref().expect("Downcast widget state")
+            State::None => panic!(
+                "Downcast on stateless state: expected `{}`",
+                any::type_name::<T>()
+            ),
+            State::Some(state) => match state.downcast_ref::<Debuggable>() {
+                Some(debuggable) => debuggable.state.downcast_ref(),
+                None => state.downcast_ref(),
             }
+            .unwrap_or_else(|| mismatch::<T>()),
         }
     }
 
@@ -164,19 +464,38 @@ impl State {
     ///
     /// # Panics
     /// This method will panic if the downcast fails or the [`State`] is [`State::None`].
+    /// The panic message names the type `T`, like [`State::downcast_ref`].
     pub fn downcast_mut<T>(&mut self) -> &mut T
     where
         T: 'static,
     {
         match self {
-            State::None => panic!("Downcast on stateless state"),
-            State::Some(state) => {
-                state.downcast_mut().expect("Downcast widget state")
+            State::None => panic!(
+                "Downcast on stateless state: expected `{}`",
+                any::type_name::<T>()
+            ),
+            State::Some(state) => if state.is::<Debuggable>() {
+                state
+                    .downcast_mut::<Debuggable>()
+                    .and_then(|debuggable| debuggable.state.downcast_mut())
+            } else {
+                state.downcast_mut()
             }
+            .unwrap_or_else(|| mismatch::<T>()),
         }
     }
 }
 
+/// Panics because some widget [`State`] is not of type `T`.
+fn mismatch<T>() -> ! {
+    panic!(
+        "Downcast widget state: the state is not of type `{}`. \
+        Is the `tag` of the widget `Tag::of::<{}>()`?",
+        any::type_name::<T>(),
+        any::type_name::<T>()
+    )
+}
+
 impl fmt::Debug for State {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -185,3 +504,110 @@ impl fmt::Debug for State {
         }
     }
 }
+
+/// Some widget state that can be formatted with [`fmt::Debug`].
+struct Debuggable {
+    state: Box<dyn Any>,
+    debug: fn(&dyn Any, &mut fmt::Formatter<'_>) -> fmt::Result,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inspect_writes_a_line_per_node() {
+        let tree = Tree {
+            tag: Tag::of::<u32>(),
+            state: State::new(42u32),
+            children: vec![Tree {
+                tag: Tag::stateless(),
+                state: State::None,
+                children: vec![Tree {
+                    tag: Tag::of::<bool>(),
+                    state: State::with_debug(true),
+                    children: Vec::new(),
+                    layout: layout::Cache::new(),
+                }],
+                layout: layout::Cache::new(),
+            }],
+            layout: layout::Cache::new(),
+        };
+
+        let expected = if cfg!(feature = "debug") {
+            "u32: <opaque>\n  ()\n    bool: true\n"
+        } else {
+            "u32: <opaque>\n  ()\n    bool: <opaque>\n"
+        };
+
+        assert_eq!(tree.inspect().to_string(), expected);
+        assert!(*tree.children[0].children[0].state.downcast_ref::<bool>());
+    }
+
+    #[test]
+    fn minimal_widgets_are_stateless_leaves() {
+        use crate::layout;
+        use crate::mouse;
+        use crate::renderer::{self, Null};
+        use crate::{Layout, Length, Rectangle, Size};
+
+        struct Minimal;
+
+        impl Widget<(), Null> for Minimal {
+            fn width(&self) -> Length {
+                Length::Shrink
+            }
+
+            fn height(&self) -> Length {
+                Length::Shrink
+            }
+
+            fn layout(
+                &self,
+                _renderer: &Null,
+                _limits: &layout::Limits,
+            ) -> layout::Node {
+                layout::Node::new(Size::ZERO)
+            }
+
+            fn draw(
+                &self,
+                _state: &Tree,
+                _renderer: &mut Null,
+                _theme: &crate::Theme,
+                _style: &renderer::Style,
+                _layout: Layout<'_>,
+                _cursor: mouse::Cursor,
+                _viewport: &Rectangle,
+            ) {
+            }
+        }
+
+        let minimal: &dyn Widget<(), Null> = &Minimal;
+        let tree = Tree::new(minimal);
+
+        assert_eq!(tree.tag, Tag::stateless());
+        assert!(matches!(tree.state, State::None));
+        assert!(tree.children.is_empty());
+
+        // The state left behind by a different widget is dropped
+        let mut tree = Tree {
+            tag: Tag::of::<u32>(),
+            state: State::new(42u32),
+            children: vec![Tree::empty()],
+            layout: layout::Cache::new(),
+        };
+
+        tree.diff(minimal);
+
+        assert_eq!(tree.tag, Tag::stateless());
+        assert!(matches!(tree.state, State::None));
+        assert!(tree.children.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the state is not of type `bool`")]
+    fn downcast_mismatch_names_the_type() {
+        let _ = State::new(42u32).downcast_ref::<bool>();
+    }
+}
@@ -16,6 +16,8 @@ use crate::{
 /// values.
 ///
 /// A [`VerticalSlider`] will try to fill the vertical space of its container.
+/// Its value increases from bottom to top, unless it is
+/// [`reversed`](VerticalSlider::reversed).
 ///
 /// The [`VerticalSlider`] range of numeric values is generic and its step size defaults
 /// to 1 unit.
@@ -49,6 +51,7 @@ where
     on_release: Option<Message>,
     width: u16,
     height: Length,
+    is_reversed: bool,
     style: <Renderer::Theme as StyleSheet>::Style,
 }
 
@@ -94,6 +97,7 @@ where
             on_release: None,
             width: Self::DEFAULT_WIDTH,
             height: Length::Fill,
+            is_reversed: false,
             style: Default::default(),
         }
     }
@@ -121,6 +125,14 @@ where
         self
     }
 
+    /// Sets whether the [`VerticalSlider`] is reversed.
+    ///
+    /// A reversed [`VerticalSlider`] increases its value from top to bottom.
+    pub fn reversed(mut self, is_reversed: bool) -> Self {
+        self.is_reversed = is_reversed;
+        self
+    }
+
     /// Sets the style of the [`VerticalSlider`].
     pub fn style(
         mut self,
@@ -150,7 +162,7 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::with_debug(State::new())
     }
 
     fn width(&self) -> Length {
@@ -179,20 +191,21 @@ where
         tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
-        update(
+        update_with_direction(
             event,
             layout,
-            cursor_position,
+            cursor,
             shell,
             tree.state.downcast_mut::<State>(),
             &mut self.value,
             &self.range,
             self.step,
+            self.is_reversed,
             self.on_change.as_ref(),
             &self.on_release,
         )
@@ -205,16 +218,17 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
-        draw(
+        draw_with_direction(
             renderer,
             layout,
-            cursor_position,
+            cursor,
             tree.state.downcast_ref::<State>(),
             self.value,
             &self.range,
+            self.is_reversed,
             theme,
             &self.style,
         )
@@ -224,15 +238,11 @@ where
         &self,
         tree: &Tree,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
-        mouse_interaction(
-            layout,
-            cursor_position,
-            tree.state.downcast_ref::<State>(),
-        )
+        mouse_interaction(layout, cursor, tree.state.downcast_ref::<State>())
     }
 }
 
@@ -256,7 +266,7 @@ where
 pub fn update<Message, T>(
     event: Event,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     shell: &mut Shell<'_, Message>,
     state: &mut State,
     value: &mut T,
@@ -265,27 +275,52 @@ pub fn update<Message, T>(
     on_change: &dyn Fn(T) -> Message,
     on_release: &Option<Message>,
 ) -> event::Status
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+{
+    update_with_direction(
+        event, layout, cursor, shell, state, value, range, step, false,
+        on_change, on_release,
+    )
+}
+
+fn update_with_direction<Message, T>(
+    event: Event,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+    shell: &mut Shell<'_, Message>,
+    state: &mut State,
+    value: &mut T,
+    range: &RangeInclusive<T>,
+    step: T,
+    is_reversed: bool,
+    on_change: &dyn Fn(T) -> Message,
+    on_release: &Option<Message>,
+) -> event::Status
 where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
 {
     let is_dragging = state.is_dragging;
 
-    let mut change = || {
+    let mut change = |cursor_position: Point| {
         let bounds = layout.bounds();
-        let new_value = if cursor_position.y >= bounds.y + bounds.height {
+
+        let percent = f64::from(bounds.y + bounds.height - cursor_position.y)
+            / f64::from(bounds.height);
+
+        let percent = if is_reversed { 1.0 - percent } else { percent };
+
+        let new_value = if percent <= 0.0 {
             *range.start()
-        } else if cursor_position.y <= bounds.y {
+        } else if percent >= 1.0 {
             *range.end()
         } else {
             let step = step.into();
             let start = (*range.start()).into();
             let end = (*range.end()).into();
 
-            let percent = 1.0
-                - f64::from(cursor_position.y - bounds.y)
-                    / f64::from(bounds.height);
-
             let steps = (percent * (end - start) / step).round();
             let value = steps * step + start;
 
@@ -306,8 +341,10 @@ where
     match event {
         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
         | Event::Touch(touch::Event::FingerPressed { .. }) => {
-            if layout.bounds().contains(cursor_position) {
-                change();
+            if let Some(cursor_position) =
+                cursor.position_over(&layout.bounds())
+            {
+                change(cursor_position);
                 state.is_dragging = true;
 
                 return event::Status::Captured;
@@ -328,7 +365,9 @@ where
         Event::Mouse(mouse::Event::CursorMoved { .. })
         | Event::Touch(touch::Event::FingerMoved { .. }) => {
             if is_dragging {
-                change();
+                if let Some(cursor_position) = cursor.position() {
+                    change(cursor_position);
+                }
 
                 return event::Status::Captured;
             }
@@ -343,10 +382,38 @@ where
 pub fn draw<T, R>(
     renderer: &mut R,
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
+    state: &State,
+    value: T,
+    range: &RangeInclusive<T>,
+    style_sheet: &dyn StyleSheet<Style = <R::Theme as StyleSheet>::Style>,
+    style: &<R::Theme as StyleSheet>::Style,
+) where
+    T: Into<f64> + Copy,
+    R: crate::Renderer,
+    R::Theme: StyleSheet,
+{
+    draw_with_direction(
+        renderer,
+        layout,
+        cursor,
+        state,
+        value,
+        range,
+        false,
+        style_sheet,
+        style,
+    )
+}
+
+fn draw_with_direction<T, R>(
+    renderer: &mut R,
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
     state: &State,
     value: T,
     range: &RangeInclusive<T>,
+    is_reversed: bool,
     style_sheet: &dyn StyleSheet<Style = <R::Theme as StyleSheet>::Style>,
     style: &<R::Theme as StyleSheet>::Style,
 ) where
@@ -355,7 +422,7 @@ pub fn draw<T, R>(
     R::Theme: StyleSheet,
 {
     let bounds = layout.bounds();
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&bounds);
 
     let style = if state.is_dragging {
         style_sheet.dragging(style)
@@ -418,8 +485,14 @@ pub fn draw<T, R>(
     let handle_offset = if range_start >= range_end {
         0.0
     } else {
-        bounds.height * (value - range_end) / (range_start - range_end)
-            - handle_width / 2.0
+        let progress = (value - range_start) / (range_end - range_start);
+        let progress = if is_reversed {
+            progress
+        } else {
+            1.0 - progress
+        };
+
+        bounds.height * progress - handle_width / 2.0
     };
 
     renderer.fill_quad(
@@ -441,11 +514,11 @@ pub fn draw<T, R>(
 /// Computes the current [`mouse::Interaction`] of a [`VerticalSlider`].
 pub fn mouse_interaction(
     layout: Layout<'_>,
-    cursor_position: Point,
+    cursor: mouse::Cursor,
     state: &State,
 ) -> mouse::Interaction {
     let bounds = layout.bounds();
-    let is_mouse_over = bounds.contains(cursor_position);
+    let is_mouse_over = cursor.is_over(&bounds);
 
     if state.is_dragging {
         mouse::Interaction::Grabbing
@@ -468,3 +541,79 @@ impl State {
         State::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clipboard;
+    use crate::renderer::Null;
+
+    type VerticalSlider<'a> = super::VerticalSlider<'a, i32, i32, Null>;
+
+    fn press_at(
+        slider: VerticalSlider<'_>,
+        cursor_position: Point,
+    ) -> Vec<i32> {
+        let mut element = Element::from(slider);
+        let mut tree = Tree::new(&element);
+
+        let node = element.as_widget().layout(
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let status = element.as_widget_mut().on_event(
+            &mut tree,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(cursor_position),
+            &Null::new(),
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        assert_eq!(status, event::Status::Captured);
+
+        messages
+    }
+
+    #[test]
+    fn vertical_sliders_fill_their_height() {
+        let slider = VerticalSlider::new(0..=100, 0, |value| value);
+
+        assert_eq!(Widget::width(&slider), Length::Shrink);
+        assert_eq!(Widget::height(&slider), Length::Fill);
+
+        let node = Widget::layout(
+            &slider,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+
+        assert_eq!(node.size(), Size::new(22.0, 100.0));
+    }
+
+    #[test]
+    fn vertical_sliders_increase_upwards() {
+        let slider = || VerticalSlider::new(0..=100, 50, |value| value);
+
+        assert_eq!(press_at(slider(), Point::new(10.0, 0.0)), vec![100]);
+        assert_eq!(press_at(slider(), Point::new(10.0, 25.0)), vec![75]);
+        assert_eq!(press_at(slider(), Point::new(10.0, 90.0)), vec![10]);
+        assert_eq!(press_at(slider(), Point::new(10.0, 100.0)), vec![0]);
+    }
+
+    #[test]
+    fn reversed_vertical_sliders_increase_downwards() {
+        let slider =
+            || VerticalSlider::new(0..=100, 50, |value| value).reversed(true);
+
+        assert_eq!(press_at(slider(), Point::new(10.0, 0.0)), vec![0]);
+        assert_eq!(press_at(slider(), Point::new(10.0, 25.0)), vec![25]);
+        assert_eq!(press_at(slider(), Point::new(10.0, 100.0)), vec![100]);
+    }
+}
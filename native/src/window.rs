@@ -1,14 +1,21 @@
 //! Build window-based GUI applications.
 mod action;
 mod event;
+mod level;
 mod mode;
 mod redraw_request;
+mod scale_factor;
 mod user_attention;
 
+pub mod monitor;
+
 pub use action::Action;
 pub use event::Event;
+pub use level::Level;
 pub use mode::Mode;
+pub use monitor::Monitor;
 pub use redraw_request::RedrawRequest;
+pub use scale_factor::ScaleFactor;
 pub use user_attention::UserAttention;
 
 use crate::subscription::{self, Subscription};
@@ -28,3 +35,49 @@ pub fn frames() -> Subscription<Instant> {
         _ => None,
     })
 }
+
+/// Subscribes to the requests of the user to close the window of the running
+/// application—e.g. with its close button.
+///
+/// The requests are only delivered when the application does not exit on
+/// its own, which is configured with the `exit_on_close_request` setting.
+/// Then, the window is only closed when the application closes it with an
+/// [`Action::Close`], so it can veto a request—for instance, to ask the user
+/// to save their changes first.
+///
+/// The application has a single window, so closing it always exits the
+/// application.
+pub fn close_requests() -> Subscription<()> {
+    subscription::raw_events(|event, _status| match event {
+        crate::Event::Window(Event::CloseRequested) => Some(()),
+        _ => None,
+    })
+}
+
+/// Subscribes to the changes of the [`Monitor`] the window of the running
+/// application is on.
+///
+/// The resulting [`Subscription`] produces the [`monitor::Id`] of the new
+/// [`Monitor`], if known.
+pub fn monitor_changes() -> Subscription<Option<monitor::Id>> {
+    subscription::raw_events(|event, _status| match event {
+        crate::Event::Window(Event::MonitorChanged(id)) => Some(id),
+        _ => None,
+    })
+}
+
+/// Subscribes to the changes of the [`ScaleFactor`] of the window of the
+/// running application.
+///
+/// The current [`ScaleFactor`] can be fetched with a [`FetchScaleFactor`]
+/// action.
+///
+/// [`FetchScaleFactor`]: Action::FetchScaleFactor
+pub fn scale_factor_changes() -> Subscription<ScaleFactor> {
+    subscription::raw_events(|event, _status| match event {
+        crate::Event::Window(Event::ScaleFactorChanged(scale_factor)) => {
+            Some(scale_factor)
+        }
+        _ => None,
+    })
+}
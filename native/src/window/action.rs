@@ -1,4 +1,6 @@
-use crate::window::{Mode, UserAttention};
+use crate::window::{
+    monitor, Level, Mode, Monitor, ScaleFactor, UserAttention,
+};
 
 use iced_futures::MaybeSend;
 use std::fmt;
@@ -20,6 +22,10 @@ pub enum Action<T> {
         /// The new logical height of the window
         height: u32,
     },
+    /// Resize the window to fit its contents.
+    ///
+    /// Axes where the contents fill the window keep their current size.
+    ResizeToFit,
     /// Sets the window to maximized or back
     Maximize(bool),
     /// Set the window to minimized or back
@@ -33,10 +39,36 @@ pub enum Action<T> {
         /// The new logical y location of the window
         y: i32,
     },
-    /// Change the [`Mode`] of the window.
-    ChangeMode(Mode),
+    /// Move the window to the center of the [`Monitor`] with the given
+    /// [`monitor::Id`], keeping its logical size.
+    ///
+    /// The window moves to the primary [`Monitor`] if the given one is not
+    /// connected.
+    ///
+    /// Unsupported on Wayland.
+    MoveToMonitor(monitor::Id),
+    /// Fetch the available [`Monitor`]s.
+    FetchMonitors(Box<dyn FnOnce(Vec<Monitor>) -> T + 'static>),
+    /// Fetch the [`Monitor`] the window is on, if known.
+    FetchCurrentMonitor(Box<dyn FnOnce(Option<Monitor>) -> T + 'static>),
+    /// Fetch the current [`ScaleFactor`] of the window.
+    FetchScaleFactor(Box<dyn FnOnce(ScaleFactor) -> T + 'static>),
+    /// Set the [`Mode`] of the window.
+    ///
+    /// The resulting [`Mode`] is reported with an [`Event::ModeChanged`],
+    /// since it may differ from the requested one on some platforms.
+    ///
+    /// [`Event::ModeChanged`]: crate::window::Event::ModeChanged
+    SetMode(Mode),
     /// Fetch the current [`Mode`] of the window.
     FetchMode(Box<dyn FnOnce(Mode) -> T + 'static>),
+    /// Set the [`Level`] of the window.
+    ///
+    /// The applied [`Level`] is reported with an [`Event::LevelChanged`],
+    /// since it may differ from the requested one on some platforms.
+    ///
+    /// [`Event::LevelChanged`]: crate::window::Event::LevelChanged
+    SetLevel(Level),
     /// Toggle the window to maximized or back
     ToggleMaximize,
     /// Toggle whether window has decorations.
@@ -85,11 +117,23 @@ impl<T> Action<T> {
             Self::Close => Action::Close,
             Self::Drag => Action::Drag,
             Self::Resize { width, height } => Action::Resize { width, height },
+            Self::ResizeToFit => Action::ResizeToFit,
             Self::Maximize(bool) => Action::Maximize(bool),
             Self::Minimize(bool) => Action::Minimize(bool),
             Self::Move { x, y } => Action::Move { x, y },
-            Self::ChangeMode(mode) => Action::ChangeMode(mode),
+            Self::MoveToMonitor(id) => Action::MoveToMonitor(id),
+            Self::FetchMonitors(o) => {
+                Action::FetchMonitors(Box::new(move |s| f(o(s))))
+            }
+            Self::FetchCurrentMonitor(o) => {
+                Action::FetchCurrentMonitor(Box::new(move |s| f(o(s))))
+            }
+            Self::FetchScaleFactor(o) => {
+                Action::FetchScaleFactor(Box::new(move |s| f(o(s))))
+            }
+            Self::SetMode(mode) => Action::SetMode(mode),
             Self::FetchMode(o) => Action::FetchMode(Box::new(move |s| f(o(s)))),
+            Self::SetLevel(level) => Action::SetLevel(level),
             Self::ToggleMaximize => Action::ToggleMaximize,
             Self::ToggleDecorations => Action::ToggleDecorations,
             Self::RequestUserAttention(attention_type) => {
@@ -109,13 +153,23 @@ impl<T> fmt::Debug for Action<T> {
                 f,
                 "Action::Resize {{ widget: {width}, height: {height} }}"
             ),
+            Self::ResizeToFit => write!(f, "Action::ResizeToFit"),
             Self::Maximize(value) => write!(f, "Action::Maximize({value})"),
             Self::Minimize(value) => write!(f, "Action::Minimize({value}"),
             Self::Move { x, y } => {
                 write!(f, "Action::Move {{ x: {x}, y: {y} }}")
             }
-            Self::ChangeMode(mode) => write!(f, "Action::SetMode({mode:?})"),
+            Self::MoveToMonitor(id) => {
+                write!(f, "Action::MoveToMonitor({id:?})")
+            }
+            Self::FetchMonitors(_) => write!(f, "Action::FetchMonitors"),
+            Self::FetchCurrentMonitor(_) => {
+                write!(f, "Action::FetchCurrentMonitor")
+            }
+            Self::FetchScaleFactor(_) => write!(f, "Action::FetchScaleFactor"),
+            Self::SetMode(mode) => write!(f, "Action::SetMode({mode:?})"),
             Self::FetchMode(_) => write!(f, "Action::FetchMode"),
+            Self::SetLevel(level) => write!(f, "Action::SetLevel({level:?})"),
             Self::ToggleMaximize => write!(f, "Action::ToggleMaximize"),
             Self::ToggleDecorations => write!(f, "Action::ToggleDecorations"),
             Self::RequestUserAttention(_) => {
@@ -1,4 +1,5 @@
 use crate::time::Instant;
+use crate::window::{monitor, Level, Mode, ScaleFactor};
 
 use std::path::PathBuf;
 
@@ -28,14 +29,67 @@ pub enum Event {
 
     /// The user has requested for the window to close.
     ///
-    /// Usually, you will want to terminate the execution whenever this event
-    /// occurs.
+    /// This includes the platform shortcuts to quit, like `Cmd+Q` on macOS.
+    /// The event is only delivered when the runtime does not exit right away
+    /// on close requests, so the application can decide whether to close the
+    /// window or not.
+    ///
+    /// See [`close_requests`](crate::window::close_requests).
     CloseRequested,
 
+    /// The [`Mode`] of a window was changed by a [`set_mode`] command.
+    ///
+    /// The [`Mode`] may differ from the requested one if it is not supported
+    /// by the platform.
+    ///
+    /// [`set_mode`]: crate::window::Action::SetMode
+    ModeChanged(Mode),
+
+    /// The [`Level`] of a window was changed by a [`set_level`] command.
+    ///
+    /// The [`Level`] may differ from the requested one if it is not supported
+    /// by the platform.
+    ///
+    /// [`set_level`]: crate::window::Action::SetLevel
+    LevelChanged(Level),
+
+    /// The window moved to a different monitor.
+    ///
+    /// It contains the [`monitor::Id`] of the new monitor, if known.
+    MonitorChanged(Option<monitor::Id>),
+
+    /// The [`ScaleFactor`] of the window changed.
+    ///
+    /// This happens when the window moves to a monitor with a different
+    /// scale factor, or when the scale factor of the application changes.
+    ScaleFactorChanged(ScaleFactor),
+
+    /// A monitor was connected or disconnected.
+    ///
+    /// Since platforms do not report it, the change is only noticed when the
+    /// window is focused, changes its scale factor, or moves to a different
+    /// monitor.
+    ///
+    /// The available monitors can be fetched with a [`FetchMonitors`]
+    /// action.
+    ///
+    /// [`FetchMonitors`]: crate::window::Action::FetchMonitors
+    MonitorsChanged,
+
     /// A window was focused.
+    ///
+    /// Like every window event, it reaches the [`Widget::on_event`] of every
+    /// widget in the window.
+    ///
+    /// [`Widget::on_event`]: crate::Widget::on_event
     Focused,
 
     /// A window was unfocused.
+    ///
+    /// Widgets can use it to pause their animations while the window is in
+    /// the background, until a [`Focused`] event arrives.
+    ///
+    /// [`Focused`]: Self::Focused
     Unfocused,
 
     /// A file is being hovered over the window.
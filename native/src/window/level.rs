@@ -0,0 +1,31 @@
+/// The stacking level of a window, relative to other windows.
+///
+/// The level of a window persists when it gains or loses focus.
+///
+/// ## Platform-specific
+///
+/// - **Web / iOS / Android:** Unsupported.
+/// - **Wayland:** Unsupported, since the compositor decides the stacking
+///   order.
+/// - **All platforms:** [`AlwaysOnBottom`] is not supported yet and falls
+///   back to [`Normal`].
+///
+/// [`AlwaysOnBottom`]: Self::AlwaysOnBottom
+/// [`Normal`]: Self::Normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Level {
+    /// The window is stacked like any other window.
+    Normal,
+
+    /// The window is always on top of other windows.
+    AlwaysOnTop,
+
+    /// The window is always below other windows.
+    AlwaysOnBottom,
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
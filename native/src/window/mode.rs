@@ -1,4 +1,11 @@
 /// The mode of a window-based application.
+///
+/// ## Platform-specific
+/// - **Wayland / Web:** [`Mode::ExclusiveFullscreen`] is unsupported and
+///   falls back to [`Mode::Fullscreen`].
+/// - **macOS:** Entering and exiting fullscreen is animated, so the new
+///   [`Mode`] may take a moment to apply.
+/// - **iOS / Android:** [`Mode::Hidden`] is unsupported.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
     /// The application appears in its own window.
@@ -7,6 +14,13 @@ pub enum Mode {
     /// The application takes the whole screen of its current monitor.
     Fullscreen,
 
+    /// The application takes the whole screen of its current monitor,
+    /// switching it to an exclusive video mode.
+    ///
+    /// The video mode keeps the current resolution of the monitor and picks
+    /// its highest refresh rate.
+    ExclusiveFullscreen,
+
     /// The application is hidden
     Hidden,
 }
@@ -0,0 +1,85 @@
+//! Inspect the monitors available to an application.
+use crate::{Point, Size};
+
+/// The identifier of a [`Monitor`].
+///
+/// An [`Id`] stays the same while the [`Monitor`] remains connected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    /// Creates an [`Id`] out of a platform-specific identifier.
+    pub fn new(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+/// A monitor connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The [`Id`] of the [`Monitor`].
+    pub id: Id,
+
+    /// The human-readable name of the [`Monitor`], if available.
+    pub name: Option<String>,
+
+    /// The physical position of the top-left corner of the [`Monitor`] in
+    /// the desktop.
+    pub position: (i32, i32),
+
+    /// The physical size of the [`Monitor`].
+    pub size: (u32, u32),
+
+    /// The scale factor of the [`Monitor`].
+    pub scale_factor: f64,
+
+    /// Whether the [`Monitor`] is the primary one.
+    pub is_primary: bool,
+}
+
+impl Monitor {
+    /// Returns the logical size of the [`Monitor`], according to its own
+    /// scale factor.
+    pub fn logical_size(&self) -> Size {
+        let (width, height) = self.size;
+
+        Size::new(
+            (f64::from(width) / self.scale_factor) as f32,
+            (f64::from(height) / self.scale_factor) as f32,
+        )
+    }
+
+    /// Returns the physical position of a point given in logical coordinates
+    /// relative to the top-left corner of the [`Monitor`].
+    ///
+    /// Monitors may have different scale factors, so logical coordinates
+    /// only make sense within a single [`Monitor`].
+    pub fn to_physical(&self, point: Point) -> (i32, i32) {
+        let (x, y) = self.position;
+
+        (
+            x + (f64::from(point.x) * self.scale_factor).round() as i32,
+            y + (f64::from(point.y) * self.scale_factor).round() as i32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_logical_coordinates_with_own_scale_factor() {
+        let monitor = Monitor {
+            id: Id::new(1),
+            name: Some(String::from("DP-2")),
+            position: (1920, 0),
+            size: (3840, 2160),
+            scale_factor: 2.0,
+            is_primary: false,
+        };
+
+        assert_eq!(monitor.logical_size(), Size::new(1920.0, 1080.0));
+        assert_eq!(monitor.to_physical(Point::new(100.0, 50.5)), (2120, 101));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{Point, Rectangle, Size};
+
+/// The scale factor of a window, which maps the logical coordinates used by
+/// the widgets of an application to the physical pixels of the window.
+///
+/// It combines the scale factor of the monitor the window is on with the
+/// scale factor of the application. It may change at any time—e.g. when the
+/// window moves to a different monitor—so conversions should always use the
+/// latest [`ScaleFactor`], as reported by [`Event::ScaleFactorChanged`].
+///
+/// Converting to physical pixels rounds the result, while converting to
+/// logical coordinates is exact. Therefore, a round trip from logical
+/// coordinates may be off by less than a physical pixel.
+///
+/// [`Event::ScaleFactorChanged`]: crate::window::Event::ScaleFactorChanged
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaleFactor(f64);
+
+// The factor is always finite, so equality is reflexive
+impl Eq for ScaleFactor {}
+
+impl ScaleFactor {
+    /// Creates a new [`ScaleFactor`].
+    ///
+    /// In debug mode, it will panic if the factor is not finite and positive.
+    pub fn new(factor: f64) -> Self {
+        debug_assert!(
+            factor.is_finite() && factor > 0.0,
+            "Scale factor must be finite and positive"
+        );
+
+        Self(factor)
+    }
+
+    /// Returns the value of the [`ScaleFactor`].
+    pub fn get(self) -> f64 {
+        self.0
+    }
+
+    /// Converts a logical [`Point`] to physical pixels, rounding to the
+    /// nearest pixel.
+    pub fn to_physical_point(self, point: Point) -> (i32, i32) {
+        (
+            (f64::from(point.x) * self.0).round() as i32,
+            (f64::from(point.y) * self.0).round() as i32,
+        )
+    }
+
+    /// Converts a position in physical pixels to a logical [`Point`].
+    ///
+    /// Fractional physical positions—like the ones of raw input—can be
+    /// converted by dividing them by [`ScaleFactor::get`].
+    pub fn to_logical_point(self, (x, y): (i32, i32)) -> Point {
+        Point::new(
+            (f64::from(x) / self.0) as f32,
+            (f64::from(y) / self.0) as f32,
+        )
+    }
+
+    /// Converts a logical [`Size`] to physical pixels, rounding to the
+    /// nearest pixel.
+    pub fn to_physical_size(self, size: Size) -> Size<u32> {
+        Size::new(
+            (f64::from(size.width) * self.0).round().max(0.0) as u32,
+            (f64::from(size.height) * self.0).round().max(0.0) as u32,
+        )
+    }
+
+    /// Converts a [`Size`] in physical pixels to a logical [`Size`].
+    pub fn to_logical_size(self, size: Size<u32>) -> Size {
+        Size::new(
+            (f64::from(size.width) / self.0) as f32,
+            (f64::from(size.height) / self.0) as f32,
+        )
+    }
+
+    /// Converts a logical [`Rectangle`] to physical pixels.
+    ///
+    /// The edges are rounded outwards, so the resulting [`Rectangle`] covers
+    /// every pixel touched by the logical one—even if its edges and size
+    /// would round differently on their own.
+    pub fn to_physical_rectangle(self, rectangle: Rectangle) -> Rectangle<u32> {
+        let scale = |value: f32| (f64::from(value) * self.0).max(0.0);
+
+        let left = scale(rectangle.x).floor();
+        let top = scale(rectangle.y).floor();
+        let right = scale(rectangle.x + rectangle.width).ceil();
+        let bottom = scale(rectangle.y + rectangle.height).ceil();
+
+        Rectangle {
+            x: left as u32,
+            y: top as u32,
+            width: (right - left) as u32,
+            height: (bottom - top) as u32,
+        }
+    }
+
+    /// Converts a [`Rectangle`] in physical pixels to a logical
+    /// [`Rectangle`].
+    pub fn to_logical_rectangle(self, rectangle: Rectangle<u32>) -> Rectangle {
+        Rectangle::new(
+            self.to_logical_point((rectangle.x as i32, rectangle.y as i32)),
+            self.to_logical_size(Size::new(rectangle.width, rectangle.height)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_between_logical_and_physical() {
+        let scale_factor = ScaleFactor::new(1.5);
+
+        assert_eq!(
+            scale_factor.to_physical_point(Point::new(10.0, 10.5)),
+            (15, 16)
+        );
+        assert_eq!(
+            scale_factor.to_logical_point((15, 16)),
+            Point::new(10.0, 16.0 / 1.5)
+        );
+
+        assert_eq!(
+            scale_factor.to_physical_size(Size::new(101.0, 3.0)),
+            Size::new(152, 5)
+        );
+        assert_eq!(
+            scale_factor.to_logical_size(Size::new(150, 3)),
+            Size::new(100.0, 2.0)
+        );
+
+        // Edges round outwards, unlike the size on its own
+        assert_eq!(
+            scale_factor.to_physical_rectangle(Rectangle {
+                x: 0.5,
+                y: 1.0,
+                width: 1.0,
+                height: 1.0,
+            }),
+            Rectangle {
+                x: 0,
+                y: 1,
+                width: 3,
+                height: 2,
+            }
+        );
+        assert_eq!(
+            scale_factor.to_logical_rectangle(Rectangle {
+                x: 3,
+                y: 6,
+                width: 30,
+                height: 15,
+            }),
+            Rectangle {
+                x: 2.0,
+                y: 4.0,
+                width: 20.0,
+                height: 10.0,
+            }
+        );
+    }
+}
@@ -1,4 +1,6 @@
 //! Build interactive cross-platform applications.
+use crate::event::{self, Event};
+use crate::menu::Menu;
 use crate::{Command, Element, Executor, Settings, Subscription};
 
 pub use iced_native::application::{Appearance, StyleSheet};
@@ -167,6 +169,71 @@ pub trait Application: Sized {
         Subscription::none()
     }
 
+    /// Returns the current [`Menu`] of the [`Application`].
+    ///
+    /// The [`Menu`] is rebuilt after every update, so its entries can be
+    /// enabled, disabled, or checked dynamically. The accelerators of its
+    /// entries are handled before [`intercept`](#method.intercept) sees the
+    /// events.
+    ///
+    /// On macOS, the [`Menu`] is shown in the native menu bar when the `menu`
+    /// feature is enabled. Everywhere else, it can be shown inside the window
+    /// with a [`MenuBar`].
+    ///
+    /// By default, it returns an empty [`Menu`].
+    ///
+    /// [`MenuBar`]: crate::widget::MenuBar
+    fn menu(&self) -> Menu<Self::Message> {
+        Menu::new()
+    }
+
+    /// Intercepts an [`Event`] before it reaches the widgets of the
+    /// [`Application`].
+    ///
+    /// Returning [`event::Status::Captured`] stops the propagation of the
+    /// [`Event`]: none of the widgets will see it, including any focused
+    /// input. The produced message, if any, will be handled by
+    /// [`update`](#tymethod.update) before any messages produced by the
+    /// widgets.
+    ///
+    /// This runs before the widget tree and before any [`Subscription`] is
+    /// notified. Captured events still reach [`subscription::events_with`]
+    /// with [`event::Status::Captured`], while [`subscription::events`] will
+    /// skip them.
+    ///
+    /// By default, it ignores every [`Event`].
+    ///
+    /// [`subscription::events`]: crate::subscription::events
+    /// [`subscription::events_with`]: crate::subscription::events_with
+    fn intercept(
+        &self,
+        _event: &Event,
+    ) -> (event::Status, Option<Self::Message>) {
+        (event::Status::Ignored, None)
+    }
+
+    /// Returns the message to handle right before the [`Application`] exits,
+    /// if any.
+    ///
+    /// It is handled by [`update`](#tymethod.update) no matter the reason of
+    /// the exit: a close request, a [`window::close`] command, or the
+    /// platform terminating the event loop—like macOS does when the user
+    /// logs out. The [`Command`] returned is discarded, since nothing runs
+    /// after it, so persist any state synchronously.
+    ///
+    /// Combined with [`window::close_requests`] and the
+    /// [`exit_on_close_request`] setting, it can be used to ask the user to
+    /// save their changes before leaving.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`window::close`]: crate::window::close
+    /// [`window::close_requests`]: crate::window::close_requests
+    /// [`exit_on_close_request`]: crate::Settings::exit_on_close_request
+    fn on_exit(&self) -> Option<Self::Message> {
+        None
+    }
+
     /// Returns the scale factor of the [`Application`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -198,11 +265,13 @@ pub trait Application: Sized {
             default_font: settings.default_font,
             default_text_size: settings.default_text_size,
             text_multithreading: settings.text_multithreading,
+            text_rendering: settings.text_rendering,
             antialiasing: if settings.antialiasing {
-                Some(crate::renderer::settings::Antialiasing::MSAAx4)
+                Some(crate::renderer::settings::Antialiasing::default())
             } else {
                 None
             },
+            damage_tracking: settings.damage_tracking,
             ..crate::renderer::Settings::from_env()
         };
 
@@ -260,6 +329,21 @@ where
         self.0.subscription()
     }
 
+    fn menu(&self) -> Menu<Self::Message> {
+        self.0.menu()
+    }
+
+    fn intercept(
+        &self,
+        event: &Event,
+    ) -> (event::Status, Option<Self::Message>) {
+        self.0.intercept(event)
+    }
+
+    fn on_exit(&self) -> Option<Self::Message> {
+        self.0.on_exit()
+    }
+
     fn scale_factor(&self) -> f64 {
         self.0.scale_factor()
     }
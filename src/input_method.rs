@@ -0,0 +1,2 @@
+//! Compose text with an input method editor (IME).
+pub use crate::runtime::input_method::{Event, InputMethod};
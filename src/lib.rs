@@ -173,6 +173,7 @@ mod sandbox;
 pub mod application;
 pub mod clipboard;
 pub mod executor;
+pub mod input_method;
 pub mod keyboard;
 pub mod mouse;
 pub mod overlay;
@@ -182,20 +183,31 @@ pub mod touch;
 pub mod widget;
 pub mod window;
 
-#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg(all(
+    not(feature = "glow"),
+    any(feature = "wgpu", feature = "tiny-skia")
+))]
 use iced_winit as runtime;
 
 #[cfg(feature = "glow")]
 use iced_glutin as runtime;
 
-#[cfg(all(not(feature = "glow"), feature = "wgpu"))]
+#[cfg(all(
+    not(feature = "glow"),
+    not(feature = "tiny-skia"),
+    feature = "wgpu"
+))]
 use iced_wgpu as renderer;
 
+#[cfg(all(not(feature = "glow"), feature = "tiny-skia"))]
+use iced_tiny_skia as renderer;
+
 #[cfg(feature = "glow")]
 use iced_glow as renderer;
 
 pub use iced_native::theme;
 pub use runtime::event;
+pub use runtime::profile;
 pub use runtime::subscription;
 
 pub use application::Application;
@@ -219,3 +231,6 @@ pub use runtime::{
 
 #[cfg(feature = "system")]
 pub use runtime::system;
+
+pub use runtime::menu;
+pub use runtime::tray;
@@ -8,9 +8,16 @@
 pub type Element<'a, Message, Renderer = crate::Renderer> =
     iced_native::overlay::Element<'a, Message, Renderer>;
 
+pub mod position {
+    //! Keep overlays within the viewport.
+    pub use iced_native::overlay::position::{place, Side, Strategy};
+}
+
 pub mod menu {
     //! Build and show dropdown menus.
-    pub use iced_native::overlay::menu::{Appearance, State, StyleSheet};
+    pub use iced_native::overlay::menu::{
+        Appearance, Header, State, StyleSheet,
+    };
 
     /// A widget that produces a message when clicked.
     pub type Menu<'a, Message, Renderer = crate::Renderer> =
@@ -1,6 +1,9 @@
 //! Configure your application.
+use crate::profile;
 use crate::window;
 
+pub use crate::renderer::settings::TextRendering;
+
 /// The settings of an application.
 #[derive(Debug, Clone)]
 pub struct Settings<Flags> {
@@ -8,6 +11,17 @@ pub struct Settings<Flags> {
     ///
     /// If provided, this identifier may be used to identify the application or
     /// communicate with it through the windowing system.
+    ///
+    /// It is expected to be a reverse domain name, like `com.example.Editor`,
+    /// made of ASCII letters, digits, `.`, `_`, and `-`. On Linux and the BSDs,
+    /// it is used as the `app_id` on Wayland and as the `WM_CLASS` on X11,
+    /// which desktops use to group windows and find their icon—so it should
+    /// match the name of the `.desktop` file of the application. Other
+    /// platforms ignore it.
+    ///
+    /// The identifier is only read when the window is created, since it cannot
+    /// be changed afterwards. An empty or invalid identifier is replaced with
+    /// the name of the executable.
     pub id: Option<String>,
 
     /// The window settings.
@@ -37,23 +51,55 @@ pub struct Settings<Flags> {
     /// By default, it is disabled.
     pub text_multithreading: bool,
 
+    /// The [`TextRendering`] strategy of the renderer.
+    ///
+    /// Hinting makes small text sharper on low-DPI displays, while subpixel
+    /// antialiasing triples its horizontal resolution on displays with
+    /// horizontal RGB stripes. Both have tradeoffs; see [`TextRendering`]
+    /// for details.
+    ///
+    /// By default, it is [`TextRendering::Unhinted`].
+    pub text_rendering: TextRendering,
+
     /// If set to true, the renderer will try to perform antialiasing for some
     /// primitives.
     ///
     /// Enabling it can produce a smoother result in some widgets, like the
     /// [`Canvas`], at a performance cost.
     ///
+    /// When enabled, meshes are multisampled with the default sample count
+    /// of the renderer, which is supported on every platform. Quads and text
+    /// are not affected. Use the `Settings` of the renderer directly to pick
+    /// a different sample count.
+    ///
     /// By default, it is disabled.
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
 
+    /// If enabled, the renderer will only redraw the regions of the window
+    /// that changed since the last frame.
+    ///
+    /// This can save a lot of work in mostly static applications.
+    ///
+    /// By default, it is disabled.
+    ///
+    /// **Note:** Only works for the `wgpu` and `tiny-skia` backends. The
+    /// `glow` backend logs a warning and redraws the whole window.
+    pub damage_tracking: bool,
+
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     ///
+    /// When disabled, the requests are delivered to the [`Application`]
+    /// instead—see [`window::close_requests`]—and the window stays open until
+    /// it is closed with [`window::close`].
+    ///
     /// By default, it is enabled.
     ///
     /// [`Application`]: crate::Application
+    /// [`window::close_requests`]: crate::window::close_requests
+    /// [`window::close`]: crate::window::close
     pub exit_on_close_request: bool,
 
     /// Whether the [`Application`] should try to build the context
@@ -64,6 +110,18 @@ pub struct Settings<Flags> {
     ///
     /// [`Application`]: crate::Application
     pub try_opengles_first: bool,
+
+    /// The [`profile::Hook`] that will be called with the timings of every
+    /// presented frame, e.g. to feed a debug overlay.
+    ///
+    /// By default, it is `None` and no timings are recorded.
+    pub profiler: Option<profile::Hook>,
+
+    /// The settings of the built-in overlay displaying the frames per second,
+    /// the frame time and the amount of primitives of the application.
+    ///
+    /// By default, the overlay is hidden and has no toggle shortcut.
+    pub overlay: profile::overlay::Settings,
 }
 
 impl<Flags> Settings<Flags> {
@@ -80,9 +138,13 @@ impl<Flags> Settings<Flags> {
             default_font: default_settings.default_font,
             default_text_size: default_settings.default_text_size,
             text_multithreading: default_settings.text_multithreading,
+            text_rendering: default_settings.text_rendering,
             antialiasing: default_settings.antialiasing,
+            damage_tracking: default_settings.damage_tracking,
             exit_on_close_request: default_settings.exit_on_close_request,
             try_opengles_first: default_settings.try_opengles_first,
+            profiler: default_settings.profiler,
+            overlay: default_settings.overlay,
         }
     }
 }
@@ -99,9 +161,13 @@ where
             default_font: Default::default(),
             default_text_size: 20,
             text_multithreading: false,
+            text_rendering: TextRendering::default(),
             antialiasing: false,
+            damage_tracking: false,
             exit_on_close_request: true,
             try_opengles_first: false,
+            profiler: None,
+            overlay: profile::overlay::Settings::default(),
         }
     }
 }
@@ -114,6 +180,8 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             flags: settings.flags,
             exit_on_close_request: settings.exit_on_close_request,
             try_opengles_first: settings.try_opengles_first,
+            profiler: settings.profiler,
+            overlay: settings.overlay,
         }
     }
 }
@@ -2,3 +2,104 @@
 pub use iced_core::time::{Duration, Instant};
 
 pub use iced_futures::backend::default::time::*;
+
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+use iced_futures::MaybeSend;
+
+#[cfg(all(
+    any(feature = "tokio", feature = "async-std", feature = "smol"),
+    not(target_arch = "wasm32")
+))]
+use crate::Subscription;
+
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+use crate::Command;
+
+/// Returns a [`Subscription`] that produces messages at a set interval.
+///
+/// The first message is produced after a `duration`, and then continues to
+/// produce more messages every `duration` after that. Each message contains
+/// the [`Instant`] the tick was actually produced at.
+///
+/// Ticks are scheduled against a fixed grid of deadlines, so they do not
+/// drift over long runs. If ticks are missed (e.g. the application was
+/// suspended or the machine went to sleep), a single tick is produced
+/// instead of a burst and the schedule is realigned to the grid.
+///
+/// # Precision
+/// Ticks are subject to the resolution of the timers of the runtime (usually
+/// around a millisecond) and to the latency of the event loop. They are
+/// delivered as regular messages, independently of the frames of the
+/// application: a tick triggers an update, which is then followed by a redraw
+/// whenever the next frame is scheduled. Use the [`Instant`] of a tick,
+/// instead of counting ticks, to measure elapsed time.
+#[cfg(all(
+    any(feature = "tokio", feature = "async-std", feature = "smol"),
+    not(target_arch = "wasm32")
+))]
+pub fn every(duration: Duration) -> Subscription<Instant> {
+    iced_futures::backend::default::time::every(duration)
+}
+
+/// Returns a [`Command`] that produces the given message once a `duration`
+/// has elapsed.
+///
+/// Unlike [`every`], this is a one-shot timer that needs no [`Subscription`]:
+/// it is useful to dismiss a notification after a while, for instance. A
+/// [`Command`] cannot be cancelled, so the message is always produced.
+/// Debouncing is achieved by tagging the message—with a counter or an
+/// [`Instant`]—and ignoring it in `update` if it is stale.
+///
+/// # Precision
+/// The message is produced no sooner than `duration`, but it may be
+/// delayed by the resolution of the timers of the runtime (usually around a
+/// millisecond) and by the latency of the event loop.
+///
+/// Every timer is a lightweight task of the executor, so many of them can be
+/// pending at once. Pending timers are dropped together with the executor
+/// when the application exits.
+///
+/// [`Subscription`]: crate::Subscription
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+pub fn after<Message>(duration: Duration, message: Message) -> Command<Message>
+where
+    Message: MaybeSend + 'static,
+{
+    after_with(duration, move || message)
+}
+
+/// Returns a [`Command`] that produces the message returned by the given
+/// function once a `duration` has elapsed.
+///
+/// The function is only called when the timer fires. See [`after`] for
+/// more details.
+#[cfg(any(
+    feature = "tokio",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
+))]
+pub fn after_with<Message>(
+    duration: Duration,
+    f: impl FnOnce() -> Message + MaybeSend + 'static,
+) -> Command<Message>
+where
+    Message: MaybeSend + 'static,
+{
+    Command::perform(sleep(duration), move |()| f())
+}
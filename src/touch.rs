@@ -1,2 +1,2 @@
 //! Listen and react to touch events.
-pub use crate::runtime::touch::{Event, Finger};
+pub use crate::runtime::touch::{gesture, Event, Finger, Gesture};
@@ -11,9 +11,27 @@ pub type Column<'a, Message, Renderer = crate::Renderer> =
 pub type Row<'a, Message, Renderer = crate::Renderer> =
     iced_native::widget::Row<'a, Message, Renderer>;
 
+/// A widget that draws its content with a uniform opacity.
+pub type Opacity<'a, Message, Renderer = crate::Renderer> =
+    iced_native::widget::Opacity<'a, Message, Renderer>;
+
+pub mod keyed {
+    //! Distribute content with a stable identity across reorders.
+
+    /// A container that distributes its contents vertically, matching the
+    /// state of its children by key.
+    pub type Column<'a, Key, Message, Renderer = crate::Renderer> =
+        iced_native::widget::keyed::Column<'a, Key, Message, Renderer>;
+
+    /// A container that distributes its contents horizontally, matching the
+    /// state of its children by key.
+    pub type Row<'a, Key, Message, Renderer = crate::Renderer> =
+        iced_native::widget::keyed::Row<'a, Key, Message, Renderer>;
+}
+
 pub mod text {
     //! Write some text for your users to read.
-    pub use iced_native::widget::text::{Appearance, StyleSheet};
+    pub use iced_native::widget::text::{Appearance, LineHeight, StyleSheet};
 
     /// A paragraph of text.
     pub type Text<'a, Renderer = crate::Renderer> =
@@ -22,7 +40,9 @@ pub mod text {
 
 pub mod button {
     //! Allow your users to perform actions by pressing a button.
-    pub use iced_native::widget::button::{Appearance, StyleSheet};
+    pub use iced_native::widget::button::{
+        focus, Appearance, Id, StyleSheet, DEFAULT_DURATION,
+    };
 
     /// A widget that produces a message when clicked.
     pub type Button<'a, Message, Renderer = crate::Renderer> =
@@ -40,13 +60,24 @@ pub mod checkbox {
 
 pub mod container {
     //! Decorate content and apply alignment.
-    pub use iced_native::widget::container::{Appearance, StyleSheet};
+    pub use iced_native::widget::container::{
+        focus, Appearance, BorderColor, BorderWidth, Id, Overflow, StyleSheet,
+    };
 
     /// An element decorating some content.
     pub type Container<'a, Message, Renderer = crate::Renderer> =
         iced_native::widget::Container<'a, Message, Renderer>;
 }
 
+pub mod drag_value {
+    //! Change a number by dragging it, or type it in.
+    pub use iced_native::widget::drag_value::{Appearance, StyleSheet};
+
+    /// A number that changes when dragged horizontally.
+    pub type DragValue<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::DragValue<'a, T, Message, Renderer>;
+}
+
 pub mod pane_grid {
     //! Let your users split regions of your application and organize layout dynamically.
     //!
@@ -78,10 +109,19 @@ pub mod pane_grid {
         iced_native::widget::pane_grid::TitleBar<'a, Message, Renderer>;
 }
 
+pub mod menu_bar {
+    //! Show the menu of an application inside its window.
+    pub use iced_native::widget::menu_bar::{Appearance, StyleSheet};
+
+    /// A horizontal bar showing the items of a menu inside a window.
+    pub type MenuBar<Message, Renderer = crate::Renderer> =
+        iced_native::widget::MenuBar<Message, Renderer>;
+}
+
 pub mod pick_list {
     //! Display a dropdown list of selectable values.
     pub use iced_native::widget::pick_list::{
-        Appearance, Handle, Icon, StyleSheet,
+        Appearance, Group, Handle, Icon, StyleSheet,
     };
 
     /// A widget allowing the selection of a single value from a list of options.
@@ -101,8 +141,9 @@ pub mod radio {
 pub mod scrollable {
     //! Navigate an endless amount of content with a scrollbar.
     pub use iced_native::widget::scrollable::{
-        snap_to, style::Scrollbar, style::Scroller, Id, Properties,
-        RelativeOffset, StyleSheet,
+        scroll_to_item, scroll_to_widget, snap_to, style::PullIndicator,
+        style::Scrollbar, style::Scroller, Anchor, Id, Overscroll, Preserve,
+        Properties, RelativeOffset, Scrolling, StyleSheet,
     };
 
     /// A widget that can vertically display an infinite amount of content
@@ -111,6 +152,27 @@ pub mod scrollable {
         iced_native::widget::Scrollable<'a, Message, Renderer>;
 }
 
+pub mod split_button {
+    //! Pair a primary action with a dropdown of alternative actions.
+    pub use iced_native::widget::split_button::State;
+
+    /// A button with an attached arrow that opens a menu of alternative
+    /// actions.
+    pub type SplitButton<'a, T, Message, Renderer = crate::Renderer> =
+        iced_native::widget::SplitButton<'a, T, Message, Renderer>;
+}
+
+pub mod toast {
+    //! Show transient notifications over some content.
+    pub use iced_native::widget::toast::{
+        Appearance, Corner, Id, Level, StyleSheet, Toast,
+    };
+
+    /// A widget that shows a stack of toasts over its content.
+    pub type Manager<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::toast::Manager<'a, Message, Renderer>;
+}
+
 pub mod toggler {
     //! Show toggle controls using togglers.
     pub use iced_native::widget::toggler::{Appearance, StyleSheet};
@@ -124,7 +186,7 @@ pub mod text_input {
     //! Display fields that can be filled with text.
     pub use iced_native::widget::text_input::{
         focus, move_cursor_to, move_cursor_to_end, move_cursor_to_front,
-        select_all, Appearance, Id, StyleSheet,
+        select_all, Appearance, Blink, Id, StyleSheet,
     };
 
     /// A field that can be filled with text.
@@ -141,15 +203,21 @@ pub mod tooltip {
         iced_native::widget::Tooltip<'a, Message, Renderer>;
 }
 
+pub use iced_native::widget::choices;
+pub use iced_native::widget::label;
 pub use iced_native::widget::progress_bar;
 pub use iced_native::widget::rule;
 pub use iced_native::widget::slider;
+pub use iced_native::widget::spinner;
 pub use iced_native::widget::vertical_slider;
 pub use iced_native::widget::Space;
 
 pub use button::Button;
 pub use checkbox::Checkbox;
+pub use choices::Choices;
 pub use container::Container;
+pub use drag_value::DragValue;
+pub use menu_bar::MenuBar;
 pub use pane_grid::PaneGrid;
 pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
@@ -157,6 +225,8 @@ pub use radio::Radio;
 pub use rule::Rule;
 pub use scrollable::Scrollable;
 pub use slider::Slider;
+pub use spinner::Spinner;
+pub use split_button::SplitButton;
 pub use text::Text;
 pub use text_input::TextInput;
 pub use toggler::Toggler;
@@ -181,15 +251,25 @@ where
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub mod image {
     //! Display images in your user interface.
-    pub use iced_native::image::Handle;
+    pub use iced_native::image::{Flip, Handle, Rotation};
 
     /// A frame that displays an image.
     pub type Image = iced_native::widget::Image<Handle>;
 
-    pub use iced_native::widget::image::viewer;
+    /// An image that is decoded in the background.
+    pub type Deferred<'a, Message, Renderer = crate::Renderer> =
+        iced_native::widget::image::Deferred<'a, Message, Renderer>;
+
+    pub use iced_native::widget::image::{
+        deferred, nine_slice, viewer, NineSlice,
+    };
     pub use viewer::Viewer;
 }
 
+#[cfg(feature = "canvas")]
+#[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
+pub use iced_graphics::widget::matrix;
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub use iced_graphics::widget::qr_code;
@@ -210,6 +290,10 @@ pub use canvas::Canvas;
 #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
 pub use image::Image;
 
+#[cfg(feature = "canvas")]
+#[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
+pub use matrix::Matrix;
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub use qr_code::QRCode;
@@ -218,8 +302,9 @@ pub use qr_code::QRCode;
 #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
 pub use svg::Svg;
 
+pub use iced_native::widget::{operation, Id, Operation};
+
 use crate::Command;
-use iced_native::widget::operation;
 
 /// Focuses the previous focusable widget.
 pub fn focus_previous<Message>() -> Command<Message>
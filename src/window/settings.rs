@@ -1,14 +1,36 @@
-use crate::window::{Icon, Position};
+use crate::window::{Icon, Level, Position};
+
+pub use iced_winit::settings::PlatformSpecific;
 
 /// The window settings of an application.
 #[derive(Debug, Clone)]
 pub struct Settings {
     /// The initial size of the window.
+    ///
+    /// When [`fit_content`](Self::fit_content) is enabled, this is only used
+    /// for the axes where the contents have no intrinsic size.
     pub size: (u32, u32),
 
+    /// Whether the window should be sized to fit its contents at startup.
+    ///
+    /// Axes where the contents fill the window fall back to
+    /// [`size`](Self::size). The window can be fitted again later with
+    /// [`window::resize_to_fit`](crate::window::resize_to_fit).
+    pub fit_content: bool,
+
     /// The initial position of the window.
+    ///
+    /// When a [`monitor`](Self::monitor) is given, the position is relative
+    /// to it.
     pub position: Position,
 
+    /// The name of the monitor the window should be opened on.
+    ///
+    /// The window is opened on the primary monitor if no connected monitor
+    /// has the given name. The available monitors can be fetched with
+    /// [`window::fetch_monitors`](crate::window::fetch_monitors).
+    pub monitor: Option<String>,
+
     /// The minimum size of the window.
     pub min_size: Option<(u32, u32)>,
 
@@ -27,26 +49,32 @@ pub struct Settings {
     /// Whether the window should be transparent.
     pub transparent: bool,
 
-    /// Whether the window will always be on top of other windows.
-    pub always_on_top: bool,
+    /// The initial [`Level`] of the window.
+    pub level: Level,
 
     /// The icon of the window.
     pub icon: Option<Icon>,
+
+    /// Platform specific settings.
+    pub platform_specific: PlatformSpecific,
 }
 
 impl Default for Settings {
     fn default() -> Settings {
         Settings {
             size: (1024, 768),
+            fit_content: false,
             position: Position::default(),
+            monitor: None,
             min_size: None,
             max_size: None,
             visible: true,
             resizable: true,
             decorations: true,
             transparent: false,
-            always_on_top: false,
+            level: Level::default(),
             icon: None,
+            platform_specific: PlatformSpecific::default(),
         }
     }
 }
@@ -55,16 +83,18 @@ impl From<Settings> for iced_winit::settings::Window {
     fn from(settings: Settings) -> Self {
         Self {
             size: settings.size,
+            fit_content: settings.fit_content,
             position: iced_winit::Position::from(settings.position),
+            monitor: settings.monitor,
             min_size: settings.min_size,
             max_size: settings.max_size,
             visible: settings.visible,
             resizable: settings.resizable,
             decorations: settings.decorations,
             transparent: settings.transparent,
-            always_on_top: settings.always_on_top,
+            level: settings.level,
             icon: settings.icon.map(Icon::into),
-            platform_specific: Default::default(),
+            platform_specific: settings.platform_specific,
         }
     }
 }
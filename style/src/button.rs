@@ -1,4 +1,8 @@
 //! Change the apperance of a button.
+use crate::theme::cache;
+use crate::transition::{self, Interpolate};
+
+use iced_core::time::Duration;
 use iced_core::{Background, Color, Vector};
 
 /// The appearance of a button.
@@ -31,6 +35,27 @@ impl std::default::Default for Appearance {
     }
 }
 
+impl Interpolate for Appearance {
+    fn interpolate(self, other: Self, factor: f32) -> Self {
+        Appearance {
+            shadow_offset: self
+                .shadow_offset
+                .interpolate(other.shadow_offset, factor),
+            background: self.background.interpolate(other.background, factor),
+            border_radius: self
+                .border_radius
+                .interpolate(other.border_radius, factor),
+            border_width: self
+                .border_width
+                .interpolate(other.border_width, factor),
+            border_color: self
+                .border_color
+                .interpolate(other.border_color, factor),
+            text_color: self.text_color.interpolate(other.text_color, factor),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
@@ -57,6 +82,49 @@ pub trait StyleSheet {
         }
     }
 
+    /// Produces the [`Color`] of the ripples of a button, if enabled.
+    fn ripple(&self, style: &Self::Style) -> Color {
+        let active = self.active(style);
+
+        Color {
+            a: active.text_color.a * 0.3,
+            ..active.text_color
+        }
+    }
+
+    /// Produces the [`Color`] of the focus ring of a button, which is drawn
+    /// around it when it is focused with the keyboard.
+    fn focus_ring(&self, style: &Self::Style) -> Color {
+        let active = self.active(style);
+
+        match active.background {
+            Some(Background::Color(color)) => color,
+            None => active.text_color,
+        }
+    }
+
+    /// Produces the duration of the transition between the appearances of a
+    /// button.
+    ///
+    /// By default, it is [`transition::DEFAULT_DURATION`], which switches
+    /// appearances immediately.
+    fn transition(&self, _style: &Self::Style) -> Duration {
+        transition::DEFAULT_DURATION
+    }
+
+    /// Produces the [`cache::Key`] of the given style, if the appearances of
+    /// a button can be cached.
+    ///
+    /// By default, it is `None` and every [`Appearance`] is produced again
+    /// each time a button is drawn. A [`StyleSheet`] that does some actual
+    /// work to produce them can opt in to caching, so that each button
+    /// produces the [`Appearance`] of each of its states once, until the
+    /// [`cache::Key`] changes. Styles backed by a closure, which may capture
+    /// some external state, should keep returning `None`.
+    fn cache_key(&self, _style: &Self::Style) -> Option<cache::Key> {
+        None
+    }
+
     /// Produces the disabled [`Appearance`] of a button.
     fn disabled(&self, style: &Self::Style) -> Appearance {
         let active = self.active(style);
@@ -1,4 +1,5 @@
 //! Change the appearance of a checkbox.
+use crate::disabled;
 use iced_core::{Background, Color};
 
 /// The appearance of a checkbox.
@@ -28,4 +29,17 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a checkbox.
     fn hovered(&self, style: &Self::Style, is_checked: bool) -> Appearance;
+
+    /// Produces the disabled [`Appearance`] of a checkbox.
+    fn disabled(&self, style: &Self::Style, is_checked: bool) -> Appearance {
+        let active = self.active(style, is_checked);
+
+        Appearance {
+            background: disabled::background(active.background),
+            checkmark_color: disabled::color(active.checkmark_color),
+            border_color: disabled::color(active.border_color),
+            text_color: active.text_color.map(disabled::color),
+            ..active
+        }
+    }
 }
@@ -1,4 +1,5 @@
 //! Change the appearance of a container.
+use crate::disabled;
 use iced_core::{Background, Color};
 
 /// The appearance of a container.
@@ -14,6 +15,12 @@ pub struct Appearance {
     pub border_width: f32,
     /// The border [`Color`] of the container.
     pub border_color: Color,
+    /// The width of each side of the border of the container, if it differs
+    /// from the [`border_width`](Self::border_width) of every side.
+    pub border_widths: Option<BorderWidth>,
+    /// The [`Color`] of each side of the border of the container, if it
+    /// differs from the [`border_color`](Self::border_color) of every side.
+    pub border_colors: Option<BorderColor>,
 }
 
 impl std::default::Default for Appearance {
@@ -24,6 +31,150 @@ impl std::default::Default for Appearance {
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            border_widths: None,
+            border_colors: None,
+        }
+    }
+}
+
+impl Appearance {
+    /// Returns the width of each side of the border of the [`Appearance`].
+    pub fn side_border_widths(&self) -> BorderWidth {
+        self.border_widths
+            .unwrap_or_else(|| BorderWidth::from(self.border_width))
+    }
+
+    /// Returns the [`Color`] of each side of the border of the
+    /// [`Appearance`].
+    pub fn side_border_colors(&self) -> BorderColor {
+        self.border_colors
+            .unwrap_or_else(|| BorderColor::from(self.border_color))
+    }
+}
+
+/// The width of each side of the border of a container.
+///
+/// You can leverage the `From` trait to build a [`BorderWidth`]
+/// conveniently:
+///
+/// ```
+/// # use iced_style::container::BorderWidth;
+/// #
+/// let width = BorderWidth::from(2.0);                  // 2px on all sides
+/// let width = BorderWidth::from([0.0, 0.0, 2.0, 0.0]); // top, right, bottom, left
+/// ```
+///
+/// Sides with a zero width are not drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BorderWidth {
+    /// The width of the top side.
+    pub top: f32,
+    /// The width of the right side.
+    pub right: f32,
+    /// The width of the bottom side.
+    pub bottom: f32,
+    /// The width of the left side.
+    pub left: f32,
+}
+
+impl BorderWidth {
+    /// A [`BorderWidth`] of zero on every side.
+    pub const ZERO: Self = Self {
+        top: 0.0,
+        right: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+    };
+
+    /// Returns the width shared by every side, if they are all the same.
+    pub fn uniform(&self) -> Option<f32> {
+        let Self {
+            top,
+            right,
+            bottom,
+            left,
+        } = *self;
+
+        (top == right && top == bottom && top == left).then_some(top)
+    }
+}
+
+impl From<f32> for BorderWidth {
+    fn from(width: f32) -> Self {
+        Self {
+            top: width,
+            right: width,
+            bottom: width,
+            left: width,
+        }
+    }
+}
+
+impl From<[f32; 4]> for BorderWidth {
+    fn from([top, right, bottom, left]: [f32; 4]) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+}
+
+/// The [`Color`] of each side of the border of a container.
+///
+/// Like [`BorderWidth`], it can be built from a single [`Color`] or from an
+/// array of colors in the order: top, right, bottom, left.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderColor {
+    /// The [`Color`] of the top side.
+    pub top: Color,
+    /// The [`Color`] of the right side.
+    pub right: Color,
+    /// The [`Color`] of the bottom side.
+    pub bottom: Color,
+    /// The [`Color`] of the left side.
+    pub left: Color,
+}
+
+impl BorderColor {
+    /// Returns the [`Color`] shared by every side, if they are all the same.
+    pub fn uniform(&self) -> Option<Color> {
+        let Self {
+            top,
+            right,
+            bottom,
+            left,
+        } = *self;
+
+        (top == right && top == bottom && top == left).then_some(top)
+    }
+}
+
+impl Default for BorderColor {
+    fn default() -> Self {
+        Self::from(Color::TRANSPARENT)
+    }
+}
+
+impl From<Color> for BorderColor {
+    fn from(color: Color) -> Self {
+        Self {
+            top: color,
+            right: color,
+            bottom: color,
+            left: color,
+        }
+    }
+}
+
+impl From<[Color; 4]> for BorderColor {
+    fn from([top, right, bottom, left]: [Color; 4]) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
         }
     }
 }
@@ -35,4 +186,42 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of a container.
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a hovered container.
+    ///
+    /// By default, it is the same as the regular [`Appearance`].
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.appearance(style)
+    }
+
+    /// Produces the [`Appearance`] of a container that is being pressed.
+    ///
+    /// Only a container that can be pressed is drawn with it. By default, it
+    /// is the same as the hovered [`Appearance`].
+    fn pressed(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the [`Appearance`] of a container that can be pressed and is
+    /// focused with the keyboard.
+    ///
+    /// By default, it is the same as the hovered [`Appearance`].
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+
+    /// Produces the [`Appearance`] of a container that can be pressed but is
+    /// disabled.
+    ///
+    /// By default, it is the regular [`Appearance`] with a translucent
+    /// background and text.
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        let appearance = self.appearance(style);
+
+        Appearance {
+            text_color: appearance.text_color.map(disabled::color),
+            background: appearance.background.map(disabled::background),
+            ..appearance
+        }
+    }
 }
@@ -0,0 +1,17 @@
+//! Fade the appearance of disabled widgets.
+use crate::{Background, Color};
+
+/// Fades the given [`Color`] for a disabled widget, halving its alpha.
+pub fn color(color: Color) -> Color {
+    Color {
+        a: color.a * 0.5,
+        ..color
+    }
+}
+
+/// Fades the given [`Background`] for a disabled widget, like [`color`].
+pub fn background(background: Background) -> Background {
+    match background {
+        Background::Color(background) => Background::Color(color(background)),
+    }
+}
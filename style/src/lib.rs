@@ -24,6 +24,7 @@ pub mod application;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod disabled;
 pub mod menu;
 pub mod pane_grid;
 pub mod pick_list;
@@ -32,10 +33,13 @@ pub mod radio;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spinner;
 pub mod svg;
 pub mod text;
 pub mod text_input;
 pub mod theme;
+pub mod toast;
 pub mod toggler;
+pub mod transition;
 
 pub use theme::Theme;
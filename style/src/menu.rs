@@ -27,4 +27,18 @@ pub trait StyleSheet {
 
     /// Produces the [`Appearance`] of a menu.
     fn appearance(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the text [`Color`] of the headers of the groups of options
+    /// in a menu.
+    ///
+    /// By default, it is a translucent version of the text color of the
+    /// [`Appearance`].
+    fn header_color(&self, style: &Self::Style) -> Color {
+        let appearance = self.appearance(style);
+
+        Color {
+            a: appearance.text_color.a * 0.6,
+            ..appearance.text_color
+        }
+    }
 }
@@ -1,4 +1,5 @@
 //! Change the appearance of a pick list.
+use crate::disabled;
 use iced_core::{Background, Color};
 
 /// The appearance of a pick list.
@@ -30,4 +31,18 @@ pub trait StyleSheet {
 
     /// Produces the hovered [`Appearance`] of a pick list.
     fn hovered(&self, style: &<Self as StyleSheet>::Style) -> Appearance;
+
+    /// Produces the disabled [`Appearance`] of a pick list.
+    fn disabled(&self, style: &<Self as StyleSheet>::Style) -> Appearance {
+        let active = self.active(style);
+
+        Appearance {
+            text_color: disabled::color(active.text_color),
+            placeholder_color: disabled::color(active.placeholder_color),
+            handle_color: disabled::color(active.handle_color),
+            background: disabled::background(active.background),
+            border_color: disabled::color(active.border_color),
+            ..active
+        }
+    }
 }
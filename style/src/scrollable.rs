@@ -29,6 +29,19 @@ pub struct Scroller {
     pub border_color: Color,
 }
 
+/// The appearance of the pull-to-refresh indicator of a scrollable.
+#[derive(Debug, Clone, Copy)]
+pub struct PullIndicator {
+    /// The [`Background`] of the indicator.
+    pub background: Background,
+    /// The border width of the indicator.
+    pub border_width: f32,
+    /// The border [`Color`] of the indicator.
+    pub border_color: Color,
+    /// The [`Color`] of the spinning dot of the indicator.
+    pub color: Color,
+}
+
 /// A set of rules that dictate the style of a scrollable.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
@@ -59,4 +72,20 @@ pub trait StyleSheet {
     fn dragging_horizontal(&self, style: &Self::Style) -> Scrollbar {
         self.hovered_horizontal(style)
     }
+
+    /// Produces the style of the pull-to-refresh indicator.
+    ///
+    /// By default, it uses the colors of an active scrollbar.
+    fn pull_indicator(&self, style: &Self::Style) -> PullIndicator {
+        let scrollbar = self.active(style);
+
+        PullIndicator {
+            background: scrollbar
+                .background
+                .unwrap_or(Background::Color(Color::WHITE)),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            color: scrollbar.scroller.color,
+        }
+    }
 }
@@ -0,0 +1,20 @@
+//! Change the appearance of a spinner.
+use iced_core::Color;
+
+/// The appearance of a spinner.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Color`] of the moving dots of the spinner.
+    pub color: Color,
+    /// The [`Color`] of the resting dots of the spinner.
+    pub track_color: Color,
+}
+
+/// A set of rules that dictate the style of a spinner.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of the spinner.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
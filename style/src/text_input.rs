@@ -12,6 +12,11 @@ pub struct Appearance {
     pub border_width: f32,
     /// The border [`Color`] of the text input.
     pub border_color: Color,
+    /// The [`Color`] of the placeholder of the text input.
+    ///
+    /// The placeholder is shown while the text input is empty, and it is
+    /// styled independently of its value.
+    pub placeholder_color: Color,
 }
 
 /// A set of rules that dictate the style of a text input.
@@ -26,7 +31,13 @@ pub trait StyleSheet {
     fn focused(&self, style: &Self::Style) -> Appearance;
 
     /// Produces the [`Color`] of the placeholder of a text input.
-    fn placeholder_color(&self, style: &Self::Style) -> Color;
+    ///
+    /// The placeholder is now styled by the [`Appearance`] of every state,
+    /// and this method is no longer used to draw it.
+    #[deprecated(note = "use the `placeholder_color` of `Appearance` instead")]
+    fn placeholder_color(&self, style: &Self::Style) -> Color {
+        self.active(style).placeholder_color
+    }
 
     /// Produces the [`Color`] of the value of a text input.
     fn value_color(&self, style: &Self::Style) -> Color;
@@ -34,6 +45,29 @@ pub trait StyleSheet {
     /// Produces the [`Color`] of the selection of a text input.
     fn selection_color(&self, style: &Self::Style) -> Color;
 
+    /// Produces the [`Color`] of the selection of a text input while its
+    /// window is unfocused.
+    ///
+    /// By default, it is the [`StyleSheet::selection_color`] with half of its
+    /// alpha.
+    fn unfocused_selection_color(&self, style: &Self::Style) -> Color {
+        let color = self.selection_color(style);
+
+        Color {
+            a: color.a * 0.5,
+            ..color
+        }
+    }
+
+    /// Produces the [`Color`] of the text of the leading and trailing content
+    /// of a text input, like a currency symbol.
+    ///
+    /// By default, it is the placeholder color of the
+    /// [`StyleSheet::active`] appearance.
+    fn affix_color(&self, style: &Self::Style) -> Color {
+        self.active(style).placeholder_color
+    }
+
     /// Produces the style of an hovered text input.
     fn hovered(&self, style: &Self::Style) -> Appearance {
         self.focused(style)
@@ -1,4 +1,5 @@
 //! Use the built-in theme and styles.
+pub mod cache;
 pub mod palette;
 
 use self::palette::Extended;
@@ -8,6 +9,7 @@ use crate::application;
 use crate::button;
 use crate::checkbox;
 use crate::container;
+use crate::disabled;
 use crate::menu;
 use crate::pane_grid;
 use crate::pick_list;
@@ -16,11 +18,15 @@ use crate::radio;
 use crate::rule;
 use crate::scrollable;
 use crate::slider;
+use crate::spinner;
 use crate::svg;
 use crate::text;
 use crate::text_input;
+use crate::toast;
 use crate::toggler;
+use crate::transition;
 
+use iced_core::time::Duration;
 use iced_core::{Background, Color, Vector};
 
 use std::rc::Rc;
@@ -203,6 +209,33 @@ impl button::StyleSheet for Theme {
         }
     }
 
+    fn ripple(&self, style: &Self::Style) -> Color {
+        if let Button::Custom(custom) = style {
+            return custom.ripple(self);
+        }
+
+        let active = self.active(style);
+
+        Color {
+            a: active.text_color.a * 0.3,
+            ..active.text_color
+        }
+    }
+
+    fn focus_ring(&self, style: &Self::Style) -> Color {
+        match style {
+            Button::Custom(custom) => custom.focus_ring(self),
+            _ => self.extended_palette().primary.strong.color,
+        }
+    }
+
+    fn transition(&self, style: &Self::Style) -> Duration {
+        match style {
+            Button::Custom(custom) => custom.transition(self),
+            _ => transition::DEFAULT_DURATION,
+        }
+    }
+
     fn disabled(&self, style: &Self::Style) -> button::Appearance {
         if let Button::Custom(custom) = style {
             return custom.disabled(self);
@@ -372,11 +405,66 @@ impl container::StyleSheet for Theme {
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    border_widths: None,
+                    border_colors: None,
                 }
             }
             Container::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn hovered(&self, style: &Self::Style) -> container::Appearance {
+        match style {
+            Container::Custom(custom) => custom.hovered(self),
+            _ => self.appearance(style),
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> container::Appearance {
+        match style {
+            Container::Transparent => self.hovered(style),
+            Container::Box => container::Appearance {
+                background: self
+                    .extended_palette()
+                    .background
+                    .strong
+                    .color
+                    .into(),
+                ..self.hovered(style)
+            },
+            Container::Custom(custom) => custom.pressed(self),
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> container::Appearance {
+        match style {
+            Container::Transparent => self.hovered(style),
+            Container::Box => container::Appearance {
+                border_width: 1.0,
+                border_color: self.extended_palette().primary.strong.color,
+                ..self.hovered(style)
+            },
+            Container::Custom(custom) => custom.focused(self),
+        }
+    }
+
+    fn disabled(&self, style: &Self::Style) -> container::Appearance {
+        match style {
+            Container::Custom(custom) => custom.disabled(self),
+            _ => {
+                let appearance = self.appearance(style);
+                let text_color = appearance
+                    .text_color
+                    .unwrap_or(self.extended_palette().background.base.text);
+
+                container::Appearance {
+                    text_color: Some(disabled::color(text_color)),
+                    background: appearance.background.map(disabled::background),
+                    ..appearance
+                }
+            }
+        }
+    }
 }
 
 impl container::StyleSheet for fn(&Theme) -> container::Appearance {
@@ -499,6 +587,13 @@ impl menu::StyleSheet for Theme {
             Menu::Custom(custom) => custom.appearance(self),
         }
     }
+
+    fn header_color(&self, style: &Self::Style) -> Color {
+        match style {
+            Menu::Default => self.extended_palette().primary.strong.color,
+            Menu::Custom(custom) => custom.header_color(self),
+        }
+    }
 }
 
 impl From<PickList> for Menu {
@@ -687,6 +782,68 @@ impl toggler::StyleSheet for Theme {
     }
 }
 
+/// The style of a toast.
+#[derive(Default)]
+pub enum Toast {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn toast::StyleSheet<Style = Theme>>),
+}
+
+impl Toast {
+    fn appearance(&self, theme: &Theme, accent: Color) -> toast::Appearance {
+        let palette = theme.extended_palette();
+
+        toast::Appearance {
+            text_color: palette.background.weak.text,
+            background: palette.background.weak.color.into(),
+            border_radius: 4.0,
+            border_width: 1.0,
+            border_color: accent,
+        }
+    }
+}
+
+impl toast::StyleSheet for Theme {
+    type Style = Toast;
+
+    fn info(&self, style: &Self::Style) -> toast::Appearance {
+        match style {
+            Toast::Default => style
+                .appearance(self, self.extended_palette().primary.base.color),
+            Toast::Custom(custom) => custom.info(self),
+        }
+    }
+
+    fn success(&self, style: &Self::Style) -> toast::Appearance {
+        match style {
+            Toast::Default => style
+                .appearance(self, self.extended_palette().success.base.color),
+            Toast::Custom(custom) => custom.success(self),
+        }
+    }
+
+    fn warning(&self, style: &Self::Style) -> toast::Appearance {
+        match style {
+            // The palette has no warning color
+            Toast::Default => {
+                style.appearance(self, Color::from_rgb8(0xE8, 0xA3, 0x17))
+            }
+            Toast::Custom(custom) => custom.warning(self),
+        }
+    }
+
+    fn error(&self, style: &Self::Style) -> toast::Appearance {
+        match style {
+            Toast::Default => style
+                .appearance(self, self.extended_palette().danger.base.color),
+            Toast::Custom(custom) => custom.error(self),
+        }
+    }
+}
+
 /// The style of a pane grid.
 #[derive(Default)]
 pub enum PaneGrid {
@@ -782,6 +939,59 @@ impl progress_bar::StyleSheet for fn(&Theme) -> progress_bar::Appearance {
     }
 }
 
+/// The style of a spinner.
+#[derive(Default)]
+pub enum Spinner {
+    /// The primary style.
+    #[default]
+    Primary,
+    /// A style that blends with the text around the spinner.
+    Text,
+    /// A custom style.
+    Custom(Box<dyn spinner::StyleSheet<Style = Theme>>),
+}
+
+impl From<fn(&Theme) -> spinner::Appearance> for Spinner {
+    fn from(f: fn(&Theme) -> spinner::Appearance) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl spinner::StyleSheet for Theme {
+    type Style = Spinner;
+
+    fn appearance(&self, style: &Self::Style) -> spinner::Appearance {
+        if let Spinner::Custom(custom) = style {
+            return custom.appearance(self);
+        }
+
+        let palette = self.extended_palette();
+
+        match style {
+            Spinner::Primary => spinner::Appearance {
+                color: palette.primary.base.color,
+                track_color: palette.background.strong.color,
+            },
+            Spinner::Text => spinner::Appearance {
+                color: palette.background.base.text,
+                track_color: Color {
+                    a: 0.2,
+                    ..palette.background.base.text
+                },
+            },
+            Spinner::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl spinner::StyleSheet for fn(&Theme) -> spinner::Appearance {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> spinner::Appearance {
+        (self)(style)
+    }
+}
+
 /// The style of a rule.
 #[derive(Default)]
 pub enum Rule {
@@ -958,6 +1168,22 @@ impl scrollable::StyleSheet for Theme {
             Scrollable::Custom(custom) => custom.dragging_horizontal(self),
         }
     }
+
+    fn pull_indicator(&self, style: &Self::Style) -> scrollable::PullIndicator {
+        match style {
+            Scrollable::Default => {
+                let palette = self.extended_palette();
+
+                scrollable::PullIndicator {
+                    background: palette.background.base.color.into(),
+                    border_width: 1.0,
+                    border_color: palette.background.strong.color,
+                    color: palette.primary.strong.color,
+                }
+            }
+            Scrollable::Custom(custom) => custom.pull_indicator(self),
+        }
+    }
 }
 
 /// The style of text.
@@ -1012,6 +1238,7 @@ impl text_input::StyleSheet for Theme {
             border_radius: 2.0,
             border_width: 1.0,
             border_color: palette.background.strong.color,
+            placeholder_color: palette.background.strong.color,
         }
     }
 
@@ -1027,6 +1254,7 @@ impl text_input::StyleSheet for Theme {
             border_radius: 2.0,
             border_width: 1.0,
             border_color: palette.background.base.text,
+            placeholder_color: palette.background.strong.color,
         }
     }
 
@@ -1042,36 +1270,53 @@ impl text_input::StyleSheet for Theme {
             border_radius: 2.0,
             border_width: 1.0,
             border_color: palette.primary.strong.color,
+            placeholder_color: palette.background.strong.color,
         }
     }
 
-    fn placeholder_color(&self, style: &Self::Style) -> Color {
+    fn value_color(&self, style: &Self::Style) -> Color {
         if let TextInput::Custom(custom) = style {
-            return custom.placeholder_color(self);
+            return custom.value_color(self);
         }
 
         let palette = self.extended_palette();
 
-        palette.background.strong.color
+        palette.background.base.text
     }
 
-    fn value_color(&self, style: &Self::Style) -> Color {
+    fn selection_color(&self, style: &Self::Style) -> Color {
         if let TextInput::Custom(custom) = style {
-            return custom.value_color(self);
+            return custom.selection_color(self);
         }
 
         let palette = self.extended_palette();
 
-        palette.background.base.text
+        palette.primary.weak.color
     }
 
-    fn selection_color(&self, style: &Self::Style) -> Color {
+    fn unfocused_selection_color(&self, style: &Self::Style) -> Color {
         if let TextInput::Custom(custom) = style {
-            return custom.selection_color(self);
+            return custom.unfocused_selection_color(self);
+        }
+
+        let color = self.selection_color(style);
+
+        Color {
+            a: color.a * 0.5,
+            ..color
+        }
+    }
+
+    fn affix_color(&self, style: &Self::Style) -> Color {
+        if let TextInput::Custom(custom) = style {
+            return custom.affix_color(self);
         }
 
         let palette = self.extended_palette();
 
-        palette.primary.weak.color
+        Color {
+            a: 0.7,
+            ..palette.background.base.text
+        }
     }
 }
@@ -0,0 +1,172 @@
+//! Cache the appearances resolved by a theme.
+//!
+//! Widgets ask their theme for their appearance every time they are drawn.
+//! The built-in [`Theme`] resolves appearances with a handful of palette
+//! lookups, so it does not cache them. A custom theme that does some actual
+//! work—like blending colors or querying a dynamic stylesheet—can opt in to
+//! caching by returning a [`Key`] for its styles.
+//!
+//! A [`Key`] identifies both the theme and the style variant. Widgets that
+//! support caching keep a [`Cache`] of the appearances of every status they
+//! have been drawn with—e.g. a hovered primary button—so state transitions
+//! always pick up the right appearance, and they discard all of them as soon
+//! as they are drawn with a different theme.
+//!
+//! Only return a [`Key`] for the styles that are fully described by it! A
+//! style backed by a closure may capture some external state, so it must be
+//! resolved every time.
+//!
+//! ```
+//! use iced_style::button;
+//! use iced_style::theme::cache;
+//! use iced_style::Color;
+//!
+//! struct Theme {
+//!     id: u64,
+//!     accent: Color,
+//! }
+//!
+//! #[derive(Default)]
+//! enum Button {
+//!     #[default]
+//!     Primary,
+//!     Custom(Box<dyn Fn(&Theme) -> button::Appearance>),
+//! }
+//!
+//! impl button::StyleSheet for Theme {
+//!     type Style = Button;
+//!
+//!     fn active(&self, style: &Button) -> button::Appearance {
+//!         match style {
+//!             // Some expensive computation...
+//!             Button::Primary => button::Appearance {
+//!                 text_color: self.accent,
+//!                 ..button::Appearance::default()
+//!             },
+//!             Button::Custom(appearance) => appearance(self),
+//!         }
+//!     }
+//!
+//!     fn cache_key(&self, style: &Button) -> Option<cache::Key> {
+//!         match style {
+//!             Button::Primary => Some(cache::Key {
+//!                 theme: self.id,
+//!                 style: 0,
+//!             }),
+//!             // Closures are never cached
+//!             Button::Custom(_) => None,
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`Theme`]: crate::theme::Theme
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The identity of the appearances of a style in a [`Cache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    /// The id of the theme resolving the appearances.
+    ///
+    /// It must change whenever the theme does—e.g. when switching to a dark
+    /// variant—since the cached appearances are discarded only then.
+    pub theme: u64,
+
+    /// The id of the style variant, unique within the theme.
+    pub style: u64,
+}
+
+/// A cache of resolved appearances.
+///
+/// It uses interior mutability, so it can be filled while drawing.
+#[derive(Debug, Clone)]
+pub struct Cache<K, V> {
+    entries: RefCell<Entries<K, V>>,
+}
+
+#[derive(Debug, Clone)]
+struct Entries<K, V> {
+    theme: Option<u64>,
+    values: HashMap<K, V>,
+}
+
+impl<K, V> Cache<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    /// Creates a new, empty [`Cache`].
+    pub fn new() -> Self {
+        Self {
+            entries: RefCell::new(Entries {
+                theme: None,
+                values: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Returns the value cached for the given theme id and key, resolving
+    /// it and caching it first if needed.
+    ///
+    /// If the theme id is not the one of the cached values, all of them are
+    /// discarded.
+    ///
+    /// The cache is not borrowed while resolving the value, so `resolve` may
+    /// use the [`Cache`] too—for instance, to derive a hovered appearance
+    /// from the active one.
+    pub fn get(&self, theme: u64, key: K, resolve: impl FnOnce() -> V) -> V {
+        {
+            let entries = self.entries.borrow();
+
+            if entries.theme == Some(theme) {
+                if let Some(value) = entries.values.get(&key) {
+                    return value.clone();
+                }
+            }
+        }
+
+        let value = resolve();
+
+        let mut entries = self.entries.borrow_mut();
+
+        if entries.theme != Some(theme) {
+            entries.theme = Some(theme);
+            entries.values.clear();
+        }
+
+        let _ = entries.values.insert(key, value.clone());
+
+        value
+    }
+
+    /// Discards all the cached values.
+    ///
+    /// This is only needed when the values change while the theme id stays
+    /// the same.
+    pub fn clear(&self) {
+        let mut entries = self.entries.borrow_mut();
+
+        entries.theme = None;
+        entries.values.clear();
+    }
+}
+
+impl<K, V> Default for Cache<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Caches are always equal, since they only remember what would be resolved
+/// anyway.
+impl<K, V> PartialEq for Cache<K, V> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,35 @@
+//! Change the appearance of toasts.
+use iced_core::{Background, Color};
+
+/// The appearance of a toast.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the toast.
+    pub text_color: Color,
+    /// The [`Background`] of the toast.
+    pub background: Background,
+    /// The border radius of the toast.
+    pub border_radius: f32,
+    /// The border width of the toast.
+    pub border_width: f32,
+    /// The border [`Color`] of the toast.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the [`Appearance`] of a toast.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of an informational toast.
+    fn info(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a toast reporting a success.
+    fn success(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a toast warning about something.
+    fn warning(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a toast reporting an error.
+    fn error(&self, style: &Self::Style) -> Appearance;
+}
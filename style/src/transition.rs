@@ -0,0 +1,186 @@
+//! Ease between appearances when the status of a widget changes.
+//!
+//! A [`Transition`] keeps track of the status a widget is moving towards
+//! and produces the appearance to draw by interpolating between the
+//! appearances of its recent statuses.
+use iced_core::time::{Duration, Instant};
+use iced_core::{Background, Color, Vector};
+
+/// The default duration of a [`Transition`], which switches appearances
+/// immediately.
+pub const DEFAULT_DURATION: Duration = Duration::ZERO;
+
+/// A value that can be interpolated with another one of the same type.
+pub trait Interpolate: Copy {
+    /// Interpolates between `self` and the `other` value with the given
+    /// `factor`, where `0.0` is `self` and `1.0` is the `other` value.
+    fn interpolate(self, other: Self, factor: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(self, other: Self, factor: f32) -> Self {
+        self + (other - self) * factor
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(self, other: Self, factor: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(other.x, factor),
+            self.y.interpolate(other.y, factor),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(self, other: Self, factor: f32) -> Self {
+        // Interpolate premultiplied components, so a transparent color does
+        // not tint the result while fading in or out
+        let a = self.a.interpolate(other.a, factor);
+
+        if a <= 0.0 {
+            return Color::TRANSPARENT;
+        }
+
+        let component = |from: f32, to: f32| {
+            (from * self.a).interpolate(to * other.a, factor) / a
+        };
+
+        Color {
+            r: component(self.r, other.r),
+            g: component(self.g, other.g),
+            b: component(self.b, other.b),
+            a,
+        }
+    }
+}
+
+impl Interpolate for Background {
+    fn interpolate(self, other: Self, factor: f32) -> Self {
+        match (self, other) {
+            (Background::Color(from), Background::Color(to)) => {
+                Background::Color(from.interpolate(to, factor))
+            }
+        }
+    }
+}
+
+impl Interpolate for Option<Background> {
+    fn interpolate(self, other: Self, factor: f32) -> Self {
+        let transparent = Background::Color(Color::TRANSPARENT);
+
+        match (self, other) {
+            (None, None) => None,
+            (from, to) => Some(
+                from.unwrap_or(transparent)
+                    .interpolate(to.unwrap_or(transparent), factor),
+            ),
+        }
+    }
+}
+
+/// The transition of a widget between the appearances of its statuses.
+///
+/// Retargeting a [`Transition`] before it finishes starts a new step from
+/// the appearance currently shown, so the widget never snaps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition<Status> {
+    initial: Status,
+    steps: Vec<Step<Status>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Step<Status> {
+    target: Status,
+    started_at: Instant,
+    progress: f32,
+}
+
+impl<Status: Copy + PartialEq> Transition<Status> {
+    /// Creates a new [`Transition`] resting at the given status.
+    pub fn new(status: Status) -> Self {
+        Self {
+            initial: status,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Returns the status the [`Transition`] is moving towards.
+    pub fn target(&self) -> Status {
+        self.steps.last().map_or(self.initial, |step| step.target)
+    }
+
+    /// Returns whether the [`Transition`] is still in progress.
+    pub fn is_animating(&self) -> bool {
+        !self.steps.is_empty()
+    }
+
+    /// Moves the [`Transition`] towards the given status, starting at the
+    /// given [`Instant`].
+    ///
+    /// The appearance shown at that [`Instant`] is kept as the starting
+    /// point. A zero `duration` switches to the status immediately.
+    pub fn retarget(
+        &mut self,
+        status: Status,
+        now: Instant,
+        duration: Duration,
+    ) {
+        if status == self.target() {
+            return;
+        }
+
+        if duration.is_zero() {
+            *self = Self::new(status);
+        } else {
+            self.update(now, duration);
+
+            self.steps.push(Step {
+                target: status,
+                started_at: now,
+                progress: 0.0,
+            });
+        }
+    }
+
+    /// Advances the [`Transition`] to the given [`Instant`].
+    ///
+    /// Only the latest step advances; earlier steps stay where they were
+    /// retargeted and are dropped once the latest step finishes.
+    pub fn update(&mut self, now: Instant, duration: Duration) {
+        if let Some(step) = self.steps.last_mut() {
+            step.progress = if duration.is_zero() {
+                1.0
+            } else {
+                (now.saturating_duration_since(step.started_at).as_secs_f32()
+                    / duration.as_secs_f32())
+                .min(1.0)
+            };
+
+            if step.progress >= 1.0 {
+                let target = step.target;
+
+                *self = Self::new(target);
+            }
+        }
+    }
+
+    /// Returns the appearance of the [`Transition`], given the appearance of
+    /// every status.
+    pub fn appearance<T: Interpolate>(
+        &self,
+        appearance: impl Fn(Status) -> T,
+    ) -> T {
+        self.steps
+            .iter()
+            .fold(appearance(self.initial), |current, step| {
+                current
+                    .interpolate(appearance(step.target), ease(step.progress))
+            })
+    }
+}
+
+/// Eases in and out, so a transition starts and ends gently.
+fn ease(progress: f32) -> f32 {
+    progress * progress * (3.0 - 2.0 * progress)
+}
@@ -0,0 +1,877 @@
+use crate::text;
+use crate::triangle;
+use crate::Settings;
+
+use iced_graphics::backend;
+use iced_graphics::font;
+use iced_graphics::layer::{self, Layer};
+use iced_graphics::{Primitive, Viewport};
+use iced_native::alignment;
+use iced_native::{Color, Font, Rectangle, Size};
+
+#[cfg(any(feature = "image", feature = "svg"))]
+use crate::image;
+
+/// A [`tiny-skia`] graphics backend for [`iced`].
+///
+/// [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+/// [`iced`]: https://github.com/iced-rs/iced
+#[derive(Debug)]
+pub struct Backend {
+    text_pipeline: text::Pipeline,
+
+    #[cfg(any(feature = "image", feature = "svg"))]
+    image_pipeline: image::Pipeline,
+
+    default_text_size: u16,
+    sample_count: u32,
+    scratch: Option<tiny_skia::Pixmap>,
+}
+
+impl Backend {
+    /// Creates a new [`Backend`].
+    pub fn new(settings: Settings) -> Self {
+        let text_pipeline = text::Pipeline::new(
+            settings.default_font,
+            settings.text_multithreading,
+            settings.text_rendering,
+        );
+
+        Self {
+            text_pipeline,
+
+            #[cfg(any(feature = "image", feature = "svg"))]
+            image_pipeline: image::Pipeline::new(),
+
+            default_text_size: settings.default_text_size,
+            sample_count: settings
+                .antialiasing
+                .map(iced_graphics::Antialiasing::sample_count)
+                .unwrap_or(1),
+            scratch: None,
+        }
+    }
+
+    /// Changes the [`TextRendering`] strategy used for text.
+    ///
+    /// The glyph cache is recreated if subpixel antialiasing is toggled.
+    ///
+    /// [`TextRendering`]: crate::TextRendering
+    pub fn set_text_rendering(&mut self, rendering: crate::TextRendering) {
+        self.text_pipeline.set_rendering(rendering);
+    }
+
+    /// Draws the provided primitives in the given pixel buffer, clearing it
+    /// with the `background_color` first.
+    ///
+    /// The text provided as overlay will be rendered on top of the primitives.
+    /// This is useful for rendering debug information.
+    pub fn draw<T: AsRef<str>>(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay_text: &[T],
+    ) {
+        let region = Rectangle {
+            x: 0,
+            y: 0,
+            width: pixels.width(),
+            height: pixels.height(),
+        };
+
+        self.draw_damage(
+            pixels,
+            primitives,
+            viewport,
+            background_color,
+            overlay_text,
+            &[region],
+        );
+    }
+
+    /// Draws the provided primitives in the given pixel buffer, but only
+    /// inside the given damage regions.
+    ///
+    /// Each region is cleared with the `background_color` and then redrawn,
+    /// while the rest of the pixel buffer is left untouched. The damage
+    /// regions are expected in physical coordinates, like the ones produced
+    /// by [`damage::group`].
+    ///
+    /// [`damage::group`]: iced_graphics::damage::group
+    pub fn draw_damage<T: AsRef<str>>(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        overlay_text: &[T],
+        damage: &[Rectangle<u32>],
+    ) {
+        log::debug!("Drawing");
+
+        let scale_factor = viewport.scale_factor() as f32;
+
+        let mut layers = Layer::generate(primitives, viewport);
+        layers.push(Layer::overlay(overlay_text, viewport));
+
+        let target = Rectangle::new(
+            iced_native::Point::ORIGIN,
+            Size::new(pixels.width() as f32, pixels.height() as f32),
+        );
+
+        for region in damage {
+            let region =
+                match Rectangle::<f32>::from(*region).intersection(&target) {
+                    Some(region) => region.snap(),
+                    None => continue,
+                };
+
+            clear(pixels, region, background_color);
+
+            for layer in &layers {
+                self.flush(pixels, scale_factor, layer, region);
+            }
+        }
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.image_pipeline.trim_cache();
+    }
+
+    fn flush(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        scale_factor: f32,
+        layer: &Layer<'_>,
+        region: Rectangle<u32>,
+    ) {
+        let bounds = match (layer.bounds * scale_factor)
+            .intersection(&Rectangle::from(region))
+        {
+            Some(bounds) => bounds.snap(),
+            None => return,
+        };
+
+        if bounds.width < 1 || bounds.height < 1 {
+            return;
+        }
+
+        if layer.rounded_clips.is_empty() && layer.opacity >= 1.0 {
+            self.draw_layer(pixels, scale_factor, layer, bounds);
+
+            return;
+        }
+
+        // Text and meshes are not drawn with a clip mask, so a layer with
+        // rounded clips or some opacity is drawn offscreen first and then
+        // composited through them—clipping and fading every kind of primitive
+        // alike
+        let mut scratch = match self.scratch.take() {
+            Some(scratch)
+                if scratch.width() == pixels.width()
+                    && scratch.height() == pixels.height() =>
+            {
+                scratch
+            }
+            _ => {
+                match tiny_skia::Pixmap::new(pixels.width(), pixels.height()) {
+                    Some(scratch) => scratch,
+                    None => return,
+                }
+            }
+        };
+
+        clear(&mut scratch.as_mut(), bounds, Color::TRANSPARENT);
+
+        self.draw_layer(&mut scratch.as_mut(), scale_factor, layer, bounds);
+
+        let rect = tiny_skia::Rect::from_xywh(
+            bounds.x as f32,
+            bounds.y as f32,
+            bounds.width as f32,
+            bounds.height as f32,
+        );
+
+        let clip_mask =
+            clip_mask(pixels, bounds, &layer.rounded_clips, scale_factor);
+
+        // Without rounded clips, the rectangle alone clips the layer
+        let is_clipped = clip_mask.is_some() || layer.rounded_clips.is_empty();
+
+        if let Some(rect) = rect.filter(|_| is_clipped) {
+            let _ = pixels.fill_rect(
+                rect,
+                &tiny_skia::Paint {
+                    shader: tiny_skia::Pattern::new(
+                        scratch.as_ref(),
+                        tiny_skia::SpreadMode::Pad,
+                        tiny_skia::FilterQuality::Nearest,
+                        layer.opacity,
+                        tiny_skia::Transform::identity(),
+                    ),
+                    ..tiny_skia::Paint::default()
+                },
+                tiny_skia::Transform::identity(),
+                clip_mask.as_ref(),
+            );
+        }
+
+        self.scratch = Some(scratch);
+    }
+
+    /// Draws the primitives of the given [`Layer`], clipped to the given
+    /// physical `bounds`.
+    fn draw_layer(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        scale_factor: f32,
+        layer: &Layer<'_>,
+        bounds: Rectangle<u32>,
+    ) {
+        let transform =
+            tiny_skia::Transform::from_scale(scale_factor, scale_factor);
+        let clip_mask = clip_mask(pixels, bounds, &[], scale_factor);
+
+        for quad in &layer.quads {
+            draw_quad(pixels, quad, transform, clip_mask.as_ref());
+        }
+
+        if !layer.meshes.is_empty() {
+            let clip = Rectangle::from(bounds) * (1.0 / scale_factor);
+
+            for mesh in layer.meshes.iter().filter_map(|mesh| mesh.clip(&clip))
+            {
+                triangle::draw(pixels, &mesh, scale_factor, self.sample_count);
+            }
+        }
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        if !layer.images.is_empty() {
+            self.image_pipeline.draw(
+                pixels,
+                &layer.images,
+                scale_factor,
+                clip_mask.as_ref(),
+            );
+        }
+
+        if !layer.text.is_empty() {
+            for text in layer.text.iter() {
+                // Target physical coordinates directly to match the layout
+                // of the GPU renderers
+                let section = glyph_brush::Section {
+                    screen_position: (
+                        (text.bounds.x * scale_factor).round(),
+                        (text.bounds.y * scale_factor).round(),
+                    ),
+                    bounds: (
+                        (text.bounds.width * scale_factor).ceil(),
+                        (text.bounds.height * scale_factor).ceil(),
+                    ),
+                    text: vec![glyph_brush::Text {
+                        text: text.content,
+                        scale: glyph_brush::ab_glyph::PxScale {
+                            x: text.size * scale_factor,
+                            y: text.size * scale_factor,
+                        },
+                        font_id: self.text_pipeline.find_font(text.font),
+                        extra: glyph_brush::Extra {
+                            color: text.color,
+                            z: 0.0,
+                        },
+                    }],
+                    layout: glyph_brush::Layout::default()
+                        .h_align(match text.horizontal_alignment {
+                            alignment::Horizontal::Left => {
+                                glyph_brush::HorizontalAlign::Left
+                            }
+                            alignment::Horizontal::Center => {
+                                glyph_brush::HorizontalAlign::Center
+                            }
+                            alignment::Horizontal::Right => {
+                                glyph_brush::HorizontalAlign::Right
+                            }
+                        })
+                        .v_align(match text.vertical_alignment {
+                            alignment::Vertical::Top => {
+                                glyph_brush::VerticalAlign::Top
+                            }
+                            alignment::Vertical::Center => {
+                                glyph_brush::VerticalAlign::Center
+                            }
+                            alignment::Vertical::Bottom => {
+                                glyph_brush::VerticalAlign::Bottom
+                            }
+                        }),
+                };
+
+                let line_height = text
+                    .line_height
+                    .to_absolute(text.size)
+                    .map(|line_height| line_height * scale_factor);
+
+                self.text_pipeline.queue(
+                    section,
+                    line_height,
+                    text.letter_spacing * scale_factor,
+                );
+            }
+
+            self.text_pipeline.draw_queued(pixels, bounds);
+        }
+    }
+}
+
+/// Fills the given region of the pixel buffer with the given [`Color`],
+/// replacing its contents.
+fn clear(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    region: Rectangle<u32>,
+    color: Color,
+) {
+    let [r, g, b, a] = color.into_rgba8();
+    let premultiply =
+        |channel: u8| (f32::from(channel) * f32::from(a) / 255.0).round() as u8;
+
+    let pixel = [premultiply(r), premultiply(g), premultiply(b), a];
+    let stride = pixels.width() as usize;
+    let data = pixels.data_mut();
+
+    for y in region.y..region.y + region.height {
+        let start = (y as usize * stride + region.x as usize) * 4;
+        let end = start + region.width as usize * 4;
+
+        for target in data[start..end].chunks_exact_mut(4) {
+            target.copy_from_slice(&pixel);
+        }
+    }
+}
+
+/// Blends a straight __sRGB__ color over a premultiplied RGBA pixel.
+pub(crate) fn blend(pixel: &mut [u8], color: [f32; 4], coverage: f32) {
+    let alpha = (color[3] * coverage).clamp(0.0, 1.0);
+
+    if alpha <= 0.0 {
+        return;
+    }
+
+    for (target, source) in pixel.iter_mut().zip(color.iter()).take(3) {
+        let blended =
+            source * alpha * 255.0 + f32::from(*target) * (1.0 - alpha);
+
+        *target = blended.round().clamp(0.0, 255.0) as u8;
+    }
+
+    pixel[3] = (alpha * 255.0 + f32::from(pixel[3]) * (1.0 - alpha))
+        .round()
+        .clamp(0.0, 255.0) as u8;
+}
+
+/// Blends the given color into an opaque pixel, with a different coverage
+/// for each of its red, green, and blue channels.
+pub(crate) fn blend_subpixel(
+    pixel: &mut [u8],
+    color: [f32; 4],
+    coverage: [f32; 3],
+) {
+    for ((target, source), coverage) in
+        pixel.iter_mut().zip(color.iter()).zip(coverage)
+    {
+        let alpha = (color[3] * coverage).clamp(0.0, 1.0);
+        let blended =
+            source * alpha * 255.0 + f32::from(*target) * (1.0 - alpha);
+
+        *target = blended.round().clamp(0.0, 255.0) as u8;
+    }
+}
+
+/// Converts a color in __linear RGB__ to a straight __sRGB__ color.
+pub(crate) fn into_srgb([r, g, b, a]: [f32; 4]) -> [f32; 4] {
+    fn channel(linear: f32) -> f32 {
+        let linear = linear.clamp(0.0, 1.0);
+
+        if linear <= 0.0031308 {
+            linear * 12.92
+        } else {
+            1.055 * linear.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    [channel(r), channel(g), channel(b), a]
+}
+
+fn into_color(linear: [f32; 4]) -> tiny_skia::Color {
+    let [r, g, b, a] = into_srgb(linear);
+
+    tiny_skia::Color::from_rgba(r, g, b, a.clamp(0.0, 1.0))
+        .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
+
+fn clip_mask(
+    pixels: &tiny_skia::PixmapMut<'_>,
+    bounds: Rectangle<u32>,
+    rounded_clips: &[layer::RoundedClip],
+    scale_factor: f32,
+) -> Option<tiny_skia::ClipMask> {
+    // No mask is needed when the whole buffer is visible
+    if rounded_clips.is_empty()
+        && bounds.x == 0
+        && bounds.y == 0
+        && bounds.width == pixels.width()
+        && bounds.height == pixels.height()
+    {
+        return None;
+    }
+
+    let rect = tiny_skia::Rect::from_xywh(
+        bounds.x as f32,
+        bounds.y as f32,
+        bounds.width as f32,
+        bounds.height as f32,
+    )?;
+
+    let mut clip_mask = tiny_skia::ClipMask::new();
+
+    clip_mask.set_path(
+        pixels.width(),
+        pixels.height(),
+        &tiny_skia::PathBuilder::from_rect(rect),
+        tiny_skia::FillRule::EvenOdd,
+        false,
+    )?;
+
+    for rounded_clip in rounded_clips {
+        let path = rounded_rectangle(
+            rounded_clip.bounds * scale_factor,
+            rounded_clip
+                .border_radius
+                .map(|radius| radius * scale_factor),
+        )?;
+
+        clip_mask.intersect_path(&path, tiny_skia::FillRule::EvenOdd, true)?;
+    }
+
+    Some(clip_mask)
+}
+
+fn draw_quad(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    quad: &layer::Quad,
+    transform: tiny_skia::Transform,
+    clip_mask: Option<&tiny_skia::ClipMask>,
+) {
+    let [x, y] = quad.position;
+    let [width, height] = quad.size;
+
+    let bounds = Rectangle {
+        x,
+        y,
+        width,
+        height,
+    };
+
+    if let Some(path) = rounded_rectangle(bounds, quad.border_radius) {
+        let _ = pixels.fill_path(
+            &path,
+            &tiny_skia::Paint {
+                shader: tiny_skia::Shader::SolidColor(into_color(quad.color)),
+                anti_alias: true,
+                ..tiny_skia::Paint::default()
+            },
+            tiny_skia::FillRule::EvenOdd,
+            transform,
+            clip_mask,
+        );
+    }
+
+    if quad.border_width > 0.0 {
+        // Strokes are centered on their path, while borders are drawn inside
+        // the bounds of the quad
+        let half = quad.border_width / 2.0;
+
+        let border_bounds = Rectangle {
+            x: x + half,
+            y: y + half,
+            width: width - quad.border_width,
+            height: height - quad.border_width,
+        };
+
+        let border_radius = quad.border_radius.map(|radius| radius - half);
+
+        if let Some(path) = rounded_rectangle(border_bounds, border_radius) {
+            let _ = pixels.stroke_path(
+                &path,
+                &tiny_skia::Paint {
+                    shader: tiny_skia::Shader::SolidColor(into_color(
+                        quad.border_color,
+                    )),
+                    anti_alias: true,
+                    ..tiny_skia::Paint::default()
+                },
+                &tiny_skia::Stroke {
+                    width: quad.border_width,
+                    ..tiny_skia::Stroke::default()
+                },
+                transform,
+                clip_mask,
+            );
+        }
+    }
+}
+
+/// Builds the path of a rectangle with the given border radius for each of
+/// its corners, starting from the top left one and going clockwise.
+fn rounded_rectangle(
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+) -> Option<tiny_skia::Path> {
+    if bounds.width <= 0.0 || bounds.height <= 0.0 {
+        return None;
+    }
+
+    let max_radius = bounds.width.min(bounds.height) / 2.0;
+    let [top_left, top_right, bottom_right, bottom_left] =
+        border_radius.map(|radius| radius.clamp(0.0, max_radius));
+
+    if top_left == 0.0
+        && top_right == 0.0
+        && bottom_right == 0.0
+        && bottom_left == 0.0
+    {
+        return tiny_skia::Rect::from_xywh(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        )
+        .map(tiny_skia::PathBuilder::from_rect);
+    }
+
+    // The distance of the control points of a cubic approximating a
+    // quarter of a circle
+    const KAPPA: f32 = 0.552_284_8;
+
+    let left = bounds.x;
+    let top = bounds.y;
+    let right = bounds.x + bounds.width;
+    let bottom = bounds.y + bounds.height;
+
+    let mut builder = tiny_skia::PathBuilder::new();
+
+    builder.move_to(left + top_left, top);
+    builder.line_to(right - top_right, top);
+    builder.cubic_to(
+        right - top_right * (1.0 - KAPPA),
+        top,
+        right,
+        top + top_right * (1.0 - KAPPA),
+        right,
+        top + top_right,
+    );
+    builder.line_to(right, bottom - bottom_right);
+    builder.cubic_to(
+        right,
+        bottom - bottom_right * (1.0 - KAPPA),
+        right - bottom_right * (1.0 - KAPPA),
+        bottom,
+        right - bottom_right,
+        bottom,
+    );
+    builder.line_to(left + bottom_left, bottom);
+    builder.cubic_to(
+        left + bottom_left * (1.0 - KAPPA),
+        bottom,
+        left,
+        bottom - bottom_left * (1.0 - KAPPA),
+        left,
+        bottom - bottom_left,
+    );
+    builder.line_to(left, top + top_left);
+    builder.cubic_to(
+        left,
+        top + top_left * (1.0 - KAPPA),
+        left + top_left * (1.0 - KAPPA),
+        top,
+        left + top_left,
+        top,
+    );
+    builder.close();
+
+    builder.finish()
+}
+
+impl iced_graphics::Backend for Backend {
+    fn trim_measurements(&mut self) {
+        self.text_pipeline.trim_measurement_cache()
+    }
+}
+
+impl backend::Text for Backend {
+    const ICON_FONT: Font = font::ICONS;
+    const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+
+    fn default_size(&self) -> u16 {
+        self.default_text_size
+    }
+
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        line_height: iced_native::text::LineHeight,
+        letter_spacing: f32,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(
+            contents,
+            size,
+            line_height,
+            letter_spacing,
+            font,
+            bounds,
+        )
+    }
+
+    fn baseline(
+        &self,
+        size: f32,
+        line_height: iced_native::text::LineHeight,
+        font: Font,
+    ) -> f32 {
+        self.text_pipeline.baseline(size, line_height, font)
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline.hit_test(
+            contents,
+            size,
+            font,
+            bounds,
+            point,
+            nearest_only,
+        )
+    }
+}
+
+#[cfg(feature = "image")]
+impl backend::Image for Backend {
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
+        self.image_pipeline.dimensions(handle)
+    }
+
+    fn load(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::Status {
+        self.image_pipeline.load(handle)
+    }
+
+    fn notify_on_decode(&self, notify: iced_graphics::image::Notify) {
+        self.image_pipeline.notify_on_decode(notify);
+    }
+}
+
+#[cfg(feature = "svg")]
+impl backend::Svg for Backend {
+    fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Size<u32> {
+        self.image_pipeline.viewport_dimensions(handle)
+    }
+
+    fn svg_error(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Option<iced_native::svg::Error> {
+        self.image_pipeline.svg_error(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::{Background, Point};
+
+    const SIZE: u32 = 40;
+
+    const WHITE: [u8; 4] = [255, 255, 255, 255];
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    fn draw(primitives: &[Primitive]) -> tiny_skia::Pixmap {
+        let mut backend = Backend::new(Settings::default());
+        let viewport = Viewport::with_physical_size(Size::new(SIZE, SIZE), 1.0);
+
+        let mut pixmap = tiny_skia::Pixmap::new(SIZE, SIZE).unwrap();
+
+        backend.draw(
+            &mut pixmap.as_mut(),
+            primitives,
+            &viewport,
+            Color::WHITE,
+            &[] as &[String],
+        );
+
+        pixmap
+    }
+
+    fn pixel(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> [u8; 4] {
+        let start = (y * pixmap.width() + x) as usize * 4;
+
+        pixmap.data()[start..start + 4].try_into().unwrap()
+    }
+
+    fn quad(bounds: Rectangle, border_radius: f32) -> Primitive {
+        Primitive::Quad {
+            bounds,
+            background: Background::Color(Color::from_rgb(1.0, 0.0, 0.0)),
+            border_radius: [border_radius; 4],
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+
+    #[test]
+    fn quads_fill_their_bounds() {
+        let pixmap = draw(&[quad(
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(20.0, 10.0)),
+            0.0,
+        )]);
+
+        assert_eq!(pixel(&pixmap, 10, 10), RED);
+        assert_eq!(pixel(&pixmap, 29, 19), RED);
+        assert_eq!(pixel(&pixmap, 9, 10), WHITE);
+        assert_eq!(pixel(&pixmap, 30, 19), WHITE);
+        assert_eq!(pixel(&pixmap, 10, 20), WHITE);
+    }
+
+    #[test]
+    fn quads_round_their_corners_and_draw_their_borders_inside() {
+        let pixmap = draw(&[Primitive::Quad {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(40.0, 40.0)),
+            background: Background::Color(Color::from_rgb(1.0, 0.0, 0.0)),
+            border_radius: [10.0; 4],
+            border_width: 2.0,
+            border_color: Color::from_rgb(0.0, 0.0, 1.0),
+        }]);
+
+        // The corners are cut off
+        assert_eq!(pixel(&pixmap, 0, 0), WHITE);
+        assert_eq!(pixel(&pixmap, 39, 39), WHITE);
+
+        // The border covers the edges
+        assert_eq!(pixel(&pixmap, 20, 0), BLUE);
+        assert_eq!(pixel(&pixmap, 39, 20), BLUE);
+
+        assert_eq!(pixel(&pixmap, 20, 20), RED);
+    }
+
+    #[test]
+    fn clips_hide_what_is_outside_of_them() {
+        let clip = |border_radius| Primitive::Clip {
+            bounds: Rectangle::new(
+                Point::new(10.0, 10.0),
+                Size::new(20.0, 20.0),
+            ),
+            border_radius: [border_radius; 4],
+            content: Box::new(quad(
+                Rectangle::with_size(Size::new(40.0, 40.0)),
+                0.0,
+            )),
+        };
+
+        let pixmap = draw(&[clip(0.0)]);
+
+        assert_eq!(pixel(&pixmap, 10, 10), RED);
+        assert_eq!(pixel(&pixmap, 29, 29), RED);
+        assert_eq!(pixel(&pixmap, 9, 20), WHITE);
+        assert_eq!(pixel(&pixmap, 20, 30), WHITE);
+
+        // Rounded clips cut off the corners too
+        let pixmap = draw(&[clip(10.0)]);
+
+        assert_eq!(pixel(&pixmap, 10, 10), WHITE);
+        assert_eq!(pixel(&pixmap, 29, 29), WHITE);
+        assert_eq!(pixel(&pixmap, 20, 10), RED);
+        assert_eq!(pixel(&pixmap, 20, 20), RED);
+    }
+
+    #[test]
+    fn text_is_drawn_inside_of_its_bounds() {
+        let bounds =
+            Rectangle::new(Point::new(5.0, 5.0), Size::new(30.0, 30.0));
+
+        let pixmap = draw(&[Primitive::Text {
+            content: String::from("Hi"),
+            bounds,
+            color: Color::BLACK,
+            size: 20.0,
+            line_height: text::LineHeight::default(),
+            letter_spacing: 0.0,
+            font: Font::Default,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+        }]);
+
+        let mut inked = 0;
+
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                if pixel(&pixmap, x, y) == WHITE {
+                    continue;
+                }
+
+                assert!(
+                    bounds.contains(Point::new(x as f32, y as f32)),
+                    "Pixel ({x}, {y}) is inked outside of the text bounds"
+                );
+
+                inked += 1;
+            }
+        }
+
+        assert!(inked > 0);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn images_are_stretched_to_their_bounds() {
+        let handle = iced_native::image::Handle::from_pixels(
+            2,
+            1,
+            vec![255, 0, 0, 255, 0, 0, 255, 255],
+        );
+
+        let pixmap = draw(&[Primitive::Image {
+            handle,
+            region: None,
+            rotation: iced_native::image::Rotation::None,
+            flip: iced_native::image::Flip::None,
+            bounds: Rectangle::new(
+                Point::new(0.0, 10.0),
+                Size::new(40.0, 20.0),
+            ),
+            opacity: 1.0,
+        }]);
+
+        // Each pixel of the image covers half of the bounds
+        assert_eq!(pixel(&pixmap, 2, 20), RED);
+        assert_eq!(pixel(&pixmap, 37, 20), BLUE);
+
+        assert_eq!(pixel(&pixmap, 20, 9), WHITE);
+        assert_eq!(pixel(&pixmap, 20, 30), WHITE);
+    }
+}
@@ -0,0 +1,315 @@
+#[cfg(feature = "image")]
+use iced_graphics::image::{raster, Orientation};
+
+#[cfg(feature = "svg")]
+use iced_graphics::image::vector;
+
+use iced_graphics::image::storage;
+use iced_graphics::layer;
+use iced_native::Size;
+
+use std::cell::RefCell;
+
+#[cfg(feature = "image")]
+use iced_native::image;
+
+#[cfg(feature = "svg")]
+use iced_native::svg;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    #[cfg(feature = "image")]
+    raster_cache: RefCell<raster::Cache<Storage>>,
+    #[cfg(feature = "svg")]
+    vector_cache: RefCell<vector::Cache<Storage>>,
+
+    storage: Storage,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self {
+            #[cfg(feature = "image")]
+            raster_cache: RefCell::new(raster::Cache::default()),
+            #[cfg(feature = "svg")]
+            vector_cache: RefCell::new(vector::Cache::default()),
+
+            storage: Storage,
+        }
+    }
+
+    #[cfg(feature = "image")]
+    pub fn dimensions(&self, handle: &image::Handle) -> Size<u32> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(handle);
+
+        memory.dimensions()
+    }
+
+    #[cfg(feature = "image")]
+    pub fn load(&self, handle: &image::Handle) -> image::Status {
+        self.raster_cache.borrow_mut().load_in_background(handle)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn notify_on_decode(&self, notify: iced_graphics::image::Notify) {
+        self.raster_cache.borrow_mut().notify_on_decode(notify);
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn viewport_dimensions(&self, handle: &svg::Handle) -> Size<u32> {
+        let mut cache = self.vector_cache.borrow_mut();
+        let svg = cache.load(handle);
+
+        svg.viewport_dimensions()
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn svg_error(&self, handle: &svg::Handle) -> Option<svg::Error> {
+        self.vector_cache.borrow_mut().load(handle).error().cloned()
+    }
+
+    pub fn draw(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        images: &[layer::Image],
+        _scale: f32,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        #[cfg(feature = "image")]
+        let mut raster_cache = self.raster_cache.borrow_mut();
+
+        #[cfg(feature = "svg")]
+        let mut vector_cache = self.vector_cache.borrow_mut();
+
+        for image in images {
+            match &image {
+                #[cfg(feature = "image")]
+                layer::Image::Raster {
+                    handle,
+                    region,
+                    rotation,
+                    flip,
+                    bounds,
+                    opacity,
+                } => {
+                    if let Some(entry) =
+                        raster_cache.upload(handle, &mut (), &mut self.storage)
+                    {
+                        let orientation = Orientation::new(*rotation, *flip);
+
+                        match region {
+                            None if orientation.is_identity() => entry.draw(
+                                pixels,
+                                *bounds * _scale,
+                                *opacity,
+                                clip_mask,
+                            ),
+                            _ => entry.draw_region(
+                                pixels,
+                                region.unwrap_or(iced_native::Rectangle {
+                                    x: 0,
+                                    y: 0,
+                                    width: entry.pixmap.width(),
+                                    height: entry.pixmap.height(),
+                                }),
+                                orientation,
+                                *bounds * _scale,
+                                *opacity,
+                                clip_mask,
+                            ),
+                        }
+                    }
+                }
+                #[cfg(not(feature = "image"))]
+                layer::Image::Raster { .. } => {}
+
+                #[cfg(feature = "svg")]
+                layer::Image::Vector {
+                    handle,
+                    color,
+                    bounds,
+                    opacity,
+                } => {
+                    if let Some(entry) = vector_cache.upload(
+                        handle,
+                        *color,
+                        [bounds.width, bounds.height],
+                        _scale,
+                        &mut (),
+                        &mut self.storage,
+                    ) {
+                        entry.draw(
+                            pixels,
+                            *bounds * _scale,
+                            *opacity,
+                            clip_mask,
+                        );
+                    }
+                }
+                #[cfg(not(feature = "svg"))]
+                layer::Image::Vector { .. } => {}
+            }
+        }
+    }
+
+    pub fn trim_cache(&mut self) {
+        #[cfg(feature = "image")]
+        self.raster_cache
+            .borrow_mut()
+            .trim(&mut self.storage, &mut ());
+
+        #[cfg(feature = "svg")]
+        self.vector_cache
+            .borrow_mut()
+            .trim(&mut self.storage, &mut ());
+    }
+}
+
+/// A [`storage::Storage`] keeping images in memory as pixel maps.
+#[derive(Debug)]
+struct Storage;
+
+#[derive(Debug)]
+struct Entry {
+    pixmap: tiny_skia::Pixmap,
+}
+
+impl Entry {
+    fn draw(
+        &self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        bounds: iced_native::Rectangle,
+        opacity: f32,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        let transform = tiny_skia::Transform::from_row(
+            bounds.width / self.pixmap.width() as f32,
+            0.0,
+            0.0,
+            bounds.height / self.pixmap.height() as f32,
+            bounds.x,
+            bounds.y,
+        );
+
+        let _ = pixels.draw_pixmap(
+            0,
+            0,
+            self.pixmap.as_ref(),
+            &tiny_skia::PixmapPaint {
+                opacity,
+                quality: tiny_skia::FilterQuality::Bilinear,
+                ..tiny_skia::PixmapPaint::default()
+            },
+            transform,
+            clip_mask,
+        );
+    }
+
+    #[cfg(feature = "image")]
+    fn draw_region(
+        &self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        region: iced_native::Rectangle<u32>,
+        orientation: Orientation,
+        bounds: iced_native::Rectangle,
+        opacity: f32,
+        clip_mask: Option<&tiny_skia::ClipMask>,
+    ) {
+        if region.width == 0 || region.height == 0 {
+            return;
+        }
+
+        // Maps the pixels of the region to the normalized points of the
+        // drawn image showing them, and those to the bounds
+        let [origin_x, origin_y] = orientation.unmap([0.0, 0.0]);
+        let [x_x, x_y] = orientation.unmap([1.0, 0.0]);
+        let [y_x, y_y] = orientation.unmap([0.0, 1.0]);
+
+        let sx = bounds.width * (x_x - origin_x) / region.width as f32;
+        let kx = bounds.width * (y_x - origin_x) / region.height as f32;
+        let ky = bounds.height * (x_y - origin_y) / region.width as f32;
+        let sy = bounds.height * (y_y - origin_y) / region.height as f32;
+
+        let transform = tiny_skia::Transform::from_row(
+            sx,
+            ky,
+            kx,
+            sy,
+            bounds.x + bounds.width * origin_x
+                - sx * region.x as f32
+                - kx * region.y as f32,
+            bounds.y + bounds.height * origin_y
+                - ky * region.x as f32
+                - sy * region.y as f32,
+        );
+
+        let rect = match tiny_skia::Rect::from_xywh(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        ) {
+            Some(rect) => rect,
+            None => return,
+        };
+
+        // Anti-aliasing is disabled, so regions sharing an edge cover every
+        // pixel exactly once and do not leave seams between them
+        let _ = pixels.fill_rect(
+            rect,
+            &tiny_skia::Paint {
+                shader: tiny_skia::Pattern::new(
+                    self.pixmap.as_ref(),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::FilterQuality::Bilinear,
+                    opacity,
+                    transform,
+                ),
+                anti_alias: false,
+                ..tiny_skia::Paint::default()
+            },
+            tiny_skia::Transform::identity(),
+            clip_mask,
+        );
+    }
+}
+
+impl storage::Entry for Entry {
+    fn size(&self) -> Size<u32> {
+        Size::new(self.pixmap.width(), self.pixmap.height())
+    }
+}
+
+impl storage::Storage for Storage {
+    type Entry = Entry;
+    type State<'a> = ();
+
+    fn upload(
+        &mut self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+        _state: &mut Self::State<'_>,
+    ) -> Option<Self::Entry> {
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+        for (target, rgba) in pixmap
+            .data_mut()
+            .chunks_exact_mut(4)
+            .zip(data.chunks_exact(4))
+        {
+            let alpha = f32::from(rgba[3]) / 255.0;
+
+            for (target, channel) in target.iter_mut().zip(rgba).take(3) {
+                *target = (f32::from(*channel) * alpha).round() as u8;
+            }
+
+            target[3] = rgba[3];
+        }
+
+        Some(Entry { pixmap })
+    }
+
+    fn remove(&mut self, _entry: &Self::Entry, _state: &mut Self::State<'_>) {}
+}
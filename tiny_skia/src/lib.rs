@@ -0,0 +1,87 @@
+//! A software renderer for [`iced_native`], powered by [`tiny-skia`].
+//!
+//! It rasterizes every primitive on the CPU, which makes it useful in
+//! environments without a GPU (e.g. CI containers) and for headless snapshot
+//! testing, since its output is a plain pixel buffer.
+//!
+//! Currently, `iced_tiny_skia` supports the following primitives:
+//! - Text, which is laid out with [`glyph_brush`] using the same metrics as
+//!   the GPU renderers. No shaping at all.
+//! - Quads or rectangles, with rounded borders and a solid background color.
+//! - Clip areas, useful to implement scrollables or hide overflowing content.
+//! - Images and SVG, loaded from memory or the file system.
+//! - Meshes of triangles, useful to draw geometry freely.
+//!
+//! # Headless rendering
+//! The primitives recorded by a [`Renderer`] can be drawn to any
+//! [`tiny_skia::PixmapMut`] with [`Backend::draw`]:
+//!
+//! ```no_run
+//! use iced_tiny_skia::{Backend, Color, Renderer, Settings, Viewport};
+//! use iced_native::Size;
+//!
+//! let mut renderer: Renderer = Renderer::new(Backend::new(Settings::default()));
+//! let viewport = Viewport::with_physical_size(Size::new(800, 600), 1.0);
+//!
+//! // Build, lay out, and draw a `UserInterface` with the `renderer` here...
+//!
+//! let mut pixmap = tiny_skia::Pixmap::new(800, 600).unwrap();
+//!
+//! renderer.with_primitives(|backend, primitives| {
+//!     backend.draw(
+//!         &mut pixmap.as_mut(),
+//!         primitives,
+//!         &viewport,
+//!         Color::WHITE,
+//!         &[] as &[String],
+//!     );
+//! });
+//!
+//! pixmap.save_png("snapshot.png").unwrap();
+//! ```
+//!
+//! [`iced_native`]: https://github.com/iced-rs/iced/tree/0.7/native
+//! [`tiny-skia`]: https://github.com/RazrFalcon/tiny-skia
+//! [`glyph_brush`]: https://github.com/alexheretic/glyph-brush
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unsafe_code,
+    unused_results,
+    clippy::extra_unused_lifetimes,
+    clippy::from_over_into,
+    clippy::needless_borrow,
+    clippy::new_without_default,
+    clippy::useless_conversion
+)]
+#![forbid(rust_2018_idioms)]
+#![allow(clippy::inherent_to_string, clippy::type_complexity)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+pub mod settings;
+pub mod window;
+
+mod backend;
+mod text;
+mod triangle;
+
+pub use iced_graphics::{
+    Antialiasing, Color, Error, Primitive, TextRendering, Viewport,
+};
+pub use iced_native::Theme;
+pub use tiny_skia;
+
+pub use backend::Backend;
+pub use settings::Settings;
+
+#[cfg(any(feature = "image", feature = "svg"))]
+mod image;
+
+/// A software graphics renderer for [`iced`].
+///
+/// [`iced`]: https://github.com/iced-rs/iced
+pub type Renderer<Theme = iced_native::Theme> =
+    iced_graphics::Renderer<Backend, Theme>;
@@ -0,0 +1,649 @@
+use iced_graphics::font;
+use iced_graphics::text::Spacing;
+use iced_graphics::TextRendering;
+use iced_native::Rectangle;
+
+use glyph_brush::ab_glyph;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+pub use iced_native::text::{Hit, LineHeight};
+
+#[derive(Debug)]
+pub struct Pipeline {
+    draw_brush: RefCell<glyph_brush::GlyphBrush<Glyph>>,
+    draw_font_map: RefCell<HashMap<String, glyph_brush::FontId>>,
+    measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    cache: Cache,
+    glyphs: Vec<Glyph>,
+    rendering: TextRendering,
+}
+
+/// The amount of subpixels of a pixel with subpixel antialiasing.
+const SUBPIXELS: f32 = 3.0;
+
+impl Pipeline {
+    pub fn new(
+        default_font: Option<&[u8]>,
+        multithreading: bool,
+        rendering: TextRendering,
+    ) -> Self {
+        let default_font = default_font.map(|slice| slice.to_vec());
+
+        // The default font of the settings takes precedence over the system
+        // one, and any other font is loaded by `find_font` when first used
+        #[cfg(not(target_os = "ios"))]
+        #[cfg(feature = "default_system_font")]
+        let default_font = {
+            default_font.or_else(|| {
+                font::Source::new()
+                    .load(&[font::Family::SansSerif, font::Family::Serif])
+                    .ok()
+            })
+        };
+
+        let default_font =
+            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+
+        let font = ab_glyph::FontArc::try_from_vec(default_font)
+            .unwrap_or_else(|_| {
+                log::warn!(
+                    "System font failed to load. Falling back to \
+                    embedded font..."
+                );
+
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            });
+
+        let draw_brush =
+            glyph_brush::GlyphBrushBuilder::using_font(font.clone())
+                .initial_cache_size((2048, 2048))
+                .draw_cache_multithread(multithreading)
+                .build();
+
+        let (width, height) = draw_brush.texture_dimensions();
+
+        let measure_brush =
+            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        Pipeline {
+            draw_brush: RefCell::new(draw_brush),
+            draw_font_map: RefCell::new(HashMap::new()),
+            measure_brush: RefCell::new(measure_brush),
+            cache: Cache::new(width, height),
+            glyphs: Vec::new(),
+            rendering,
+        }
+    }
+
+    /// Changes the [`TextRendering`] strategy of the [`Pipeline`].
+    ///
+    /// Subpixel glyphs are rasterized at a different resolution, so the
+    /// glyph cache is recreated when subpixel antialiasing is toggled.
+    pub fn set_rendering(&mut self, rendering: TextRendering) {
+        let is_subpixel =
+            |rendering: TextRendering| rendering == TextRendering::Subpixel;
+
+        if is_subpixel(self.rendering) != is_subpixel(rendering) {
+            let draw_brush = self.draw_brush.get_mut();
+            let (width, height) = draw_brush.texture_dimensions();
+
+            draw_brush.resize_texture(width, height);
+            self.cache.resize(width, height);
+            self.glyphs.clear();
+        }
+
+        self.rendering = rendering;
+    }
+
+    pub fn queue(
+        &mut self,
+        mut section: glyph_brush::Section<'_>,
+        line_height: Option<f32>,
+        letter_spacing: f32,
+    ) {
+        // Subpixel glyphs are laid out and rasterized with three times the
+        // horizontal resolution
+        let letter_spacing = if self.rendering == TextRendering::Subpixel {
+            section.screen_position.0 *= SUBPIXELS;
+            section.bounds.0 *= SUBPIXELS;
+
+            for text in &mut section.text {
+                text.scale.x *= SUBPIXELS;
+            }
+
+            letter_spacing * SUBPIXELS
+        } else {
+            letter_spacing
+        };
+
+        let spacing = Spacing::new(
+            section.layout,
+            line_height,
+            letter_spacing,
+            self.rendering,
+        );
+
+        if spacing.is_natural() {
+            self.draw_brush.borrow_mut().queue(section);
+        } else {
+            self.draw_brush
+                .borrow_mut()
+                .queue_custom_layout(section, &spacing);
+        }
+    }
+
+    pub fn draw_queued(
+        &mut self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        let mut draw_brush = self.draw_brush.borrow_mut();
+        let cache = &mut self.cache;
+
+        let action = loop {
+            let result = draw_brush.process_queued(
+                |rectangle, data| cache.update(rectangle, data),
+                Glyph::from_vertex,
+            );
+
+            match result {
+                Ok(action) => break action,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    log::info!("Resizing glyph cache: {}x{}", width, height);
+
+                    draw_brush.resize_texture(width, height);
+                    cache.resize(width, height);
+                }
+            }
+        };
+
+        // A redraw reuses the glyphs of the last call
+        if let glyph_brush::BrushAction::Draw(glyphs) = action {
+            self.glyphs = glyphs;
+        }
+
+        for glyph in &self.glyphs {
+            if self.rendering == TextRendering::Subpixel {
+                glyph.draw_subpixel(pixels, &self.cache, clip_bounds);
+            } else {
+                glyph.draw(pixels, &self.cache, clip_bounds);
+            }
+        }
+    }
+
+    pub fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        line_height: LineHeight,
+        letter_spacing: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        use glyph_brush::GlyphCruncher;
+
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: glyph_brush::FontId(font_id),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        let spacing = Spacing::new(
+            section.layout,
+            line_height.to_absolute(size),
+            letter_spacing,
+            self.rendering,
+        );
+
+        let mut measure_brush = self.measure_brush.borrow_mut();
+
+        if spacing.is_natural() {
+            if let Some(bounds) = measure_brush.glyph_bounds(section) {
+                (bounds.width().ceil(), bounds.height().ceil())
+            } else {
+                (0.0, 0.0)
+            }
+        } else {
+            use ab_glyph::{Font, ScaleFont};
+
+            let font = measure_brush.fonts()[font_id].clone().into_scaled(size);
+            let natural = font.height() + font.line_gap();
+
+            // The glyph bounds only span the line boxes of the glyphs, so
+            // the extra space of the last line needs to be accounted for.
+            let extra = spacing
+                .line_height
+                .map_or(0.0, |line_height| (line_height - natural).max(0.0));
+
+            if let Some(bounds) =
+                measure_brush.glyph_bounds_custom_layout(section, &spacing)
+            {
+                (bounds.width().ceil(), (bounds.height() + extra).ceil())
+            } else {
+                (0.0, 0.0)
+            }
+        }
+    }
+
+    pub fn baseline(
+        &self,
+        size: f32,
+        line_height: LineHeight,
+        font: iced_native::Font,
+    ) -> f32 {
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        iced_graphics::text::baseline(
+            &self.measure_brush.borrow().fonts()[font_id],
+            size,
+            line_height.to_absolute(size),
+        )
+    }
+
+    pub fn hit_test(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        use glyph_brush::GlyphCruncher;
+
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: glyph_brush::FontId(font_id),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        let mut mb = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        use glyph_brush::ab_glyph::{Font, ScaleFont};
+        let font = mb.fonts()[font_id].clone().into_scaled(size);
+
+        // Implements an iterator over the glyph bounding boxes.
+        let bounds = mb.glyphs(section).map(
+            |glyph_brush::SectionGlyph {
+                 byte_index, glyph, ..
+             }| {
+                (
+                    *byte_index,
+                    iced_native::Rectangle::new(
+                        iced_native::Point::new(
+                            glyph.position.x - font.h_side_bearing(glyph.id),
+                            glyph.position.y - font.ascent(),
+                        ),
+                        iced_native::Size::new(
+                            font.h_advance(glyph.id),
+                            font.ascent() - font.descent(),
+                        ),
+                    ),
+                )
+            },
+        );
+
+        // Implements computation of the character index based on the byte index
+        // within the input string.
+        let char_index = |byte_index| {
+            let mut b_count = 0;
+            for (i, utf8_len) in
+                content.chars().map(|c| c.len_utf8()).enumerate()
+            {
+                if byte_index < (b_count + utf8_len) {
+                    return i;
+                }
+                b_count += utf8_len;
+            }
+
+            byte_index
+        };
+
+        if !nearest_only {
+            for (idx, bounds) in bounds.clone() {
+                if bounds.contains(point) {
+                    return Some(Hit::CharOffset(char_index(idx)));
+                }
+            }
+        }
+
+        let nearest = bounds
+            .map(|(index, bounds)| (index, bounds.center()))
+            .min_by(|(_, center_a), (_, center_b)| {
+                center_a
+                    .distance(point)
+                    .partial_cmp(&center_b.distance(point))
+                    .unwrap_or(std::cmp::Ordering::Greater)
+            });
+
+        nearest.map(|(idx, center)| {
+            Hit::NearestCharOffset(char_index(idx), point - center)
+        })
+    }
+
+    pub fn trim_measurement_cache(&mut self) {
+        // Processing the queue of the measurement brush without drawing
+        // anything drops the sections that were not measured since the last
+        // trim, which keeps its cache bounded to the text that is still in use
+        loop {
+            let action = self
+                .measure_brush
+                .borrow_mut()
+                .process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.measure_brush
+                        .borrow_mut()
+                        .resize_texture(width, height);
+                }
+            }
+        }
+    }
+
+    pub fn find_font(&self, font: iced_native::Font) -> glyph_brush::FontId {
+        match font {
+            iced_native::Font::Default => glyph_brush::FontId(0),
+            iced_native::Font::External { name, bytes } => {
+                if let Some(font_id) = self.draw_font_map.borrow().get(name) {
+                    return *font_id;
+                }
+
+                let font = ab_glyph::FontArc::try_from_slice(bytes)
+                    .expect("Load font");
+
+                let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+
+                let font_id = self.draw_brush.borrow_mut().add_font(font);
+
+                let _ = self
+                    .draw_font_map
+                    .borrow_mut()
+                    .insert(String::from(name), font_id);
+
+                font_id
+            }
+        }
+    }
+}
+
+/// The coverage of the glyphs rasterized by a [`glyph_brush::GlyphBrush`].
+#[derive(Debug)]
+struct Cache {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Cache {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![0; (width * height) as usize],
+        }
+    }
+
+    fn resize(&mut self, width: u32, height: u32) {
+        *self = Self::new(width, height);
+    }
+
+    fn update(&mut self, rectangle: glyph_brush::Rectangle<u32>, data: &[u8]) {
+        let width = rectangle.width() as usize;
+
+        for (row, coverage) in data.chunks_exact(width).enumerate() {
+            let start = (rectangle.min[1] as usize + row) * self.width as usize
+                + rectangle.min[0] as usize;
+
+            self.data[start..start + width].copy_from_slice(coverage);
+        }
+    }
+
+    fn coverage(&self, x: u32, y: u32) -> f32 {
+        let x = x.min(self.width - 1);
+        let y = y.min(self.height - 1);
+
+        f32::from(self.data[(y * self.width + x) as usize]) / 255.0
+    }
+}
+
+/// A glyph positioned in physical pixels, ready to be drawn.
+#[derive(Debug, Clone, Copy)]
+struct Glyph {
+    pixels: ab_glyph::Rect,
+    texels: ab_glyph::Rect,
+    bounds: ab_glyph::Rect,
+    color: [f32; 4],
+}
+
+impl Glyph {
+    fn from_vertex(vertex: glyph_brush::GlyphVertex<'_>) -> Self {
+        Self {
+            pixels: vertex.pixel_coords,
+            texels: vertex.tex_coords,
+            bounds: vertex.bounds,
+            color: crate::backend::into_srgb(vertex.extra.color),
+        }
+    }
+
+    fn draw(
+        &self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        cache: &Cache,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        let min_x = self
+            .pixels
+            .min
+            .x
+            .max(self.bounds.min.x)
+            .max(clip_bounds.x as f32)
+            .max(0.0)
+            .floor() as u32;
+
+        let min_y = self
+            .pixels
+            .min
+            .y
+            .max(self.bounds.min.y)
+            .max(clip_bounds.y as f32)
+            .max(0.0)
+            .floor() as u32;
+
+        let max_x = self
+            .pixels
+            .max
+            .x
+            .min(self.bounds.max.x)
+            .min((clip_bounds.x + clip_bounds.width) as f32)
+            .min(pixels.width() as f32)
+            .ceil()
+            .max(0.0) as u32;
+
+        let max_y = self
+            .pixels
+            .max
+            .y
+            .min(self.bounds.max.y)
+            .min((clip_bounds.y + clip_bounds.height) as f32)
+            .min(pixels.height() as f32)
+            .ceil()
+            .max(0.0) as u32;
+
+        let width = self.pixels.width();
+        let height = self.pixels.height();
+
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let stride = pixels.width() as usize;
+        let data = pixels.data_mut();
+
+        for y in min_y..max_y {
+            let v = (y as f32 + 0.5 - self.pixels.min.y) / height;
+            let texel_y = (self.texels.min.y
+                + v * (self.texels.max.y - self.texels.min.y))
+                * cache.height as f32;
+
+            for x in min_x..max_x {
+                let u = (x as f32 + 0.5 - self.pixels.min.x) / width;
+                let texel_x = (self.texels.min.x
+                    + u * (self.texels.max.x - self.texels.min.x))
+                    * cache.width as f32;
+
+                let coverage = cache.coverage(texel_x as u32, texel_y as u32);
+
+                if coverage > 0.0 {
+                    let index = (y as usize * stride + x as usize) * 4;
+
+                    crate::backend::blend(
+                        &mut data[index..index + 4],
+                        self.color,
+                        coverage,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Draws the [`Glyph`] using the red, green, and blue stripes of every
+    /// pixel separately.
+    ///
+    /// The [`Glyph`] must have been laid out with three times the horizontal
+    /// resolution. Pixels that are not opaque fall back to grayscale
+    /// antialiasing, since the stripes of whatever is behind them are
+    /// unknown. So does the whole [`Glyph`] if it is stretched, since its
+    /// subpixels would not line up with the stripes anymore.
+    fn draw_subpixel(
+        &self,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        cache: &Cache,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        let width = self.pixels.width();
+        let height = self.pixels.height();
+
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let texel_width =
+            (self.texels.max.x - self.texels.min.x) * cache.width as f32;
+        let texel_height =
+            (self.texels.max.y - self.texels.min.y) * cache.height as f32;
+
+        let is_aligned = (texel_width - width).abs() < 0.5
+            && (texel_height - height).abs() < 0.5;
+
+        let left = self.pixels.min.x.max(self.bounds.min.x) / SUBPIXELS;
+        let right = self.pixels.max.x.min(self.bounds.max.x) / SUBPIXELS;
+
+        let min_x = left.max(clip_bounds.x as f32).max(0.0).floor() as u32;
+        let max_x = right
+            .min((clip_bounds.x + clip_bounds.width) as f32)
+            .min(pixels.width() as f32)
+            .ceil()
+            .max(0.0) as u32;
+        let min_y = self
+            .pixels
+            .min
+            .y
+            .max(self.bounds.min.y)
+            .max(clip_bounds.y as f32)
+            .max(0.0)
+            .floor() as u32;
+        let max_y = self
+            .pixels
+            .max
+            .y
+            .min(self.bounds.max.y)
+            .min((clip_bounds.y + clip_bounds.height) as f32)
+            .min(pixels.height() as f32)
+            .ceil()
+            .max(0.0) as u32;
+
+        let stride = pixels.width() as usize;
+        let data = pixels.data_mut();
+
+        for y in min_y..max_y {
+            let v = (y as f32 + 0.5 - self.pixels.min.y) / height;
+            let texel_y = (self.texels.min.y
+                + v * (self.texels.max.y - self.texels.min.y))
+                * cache.height as f32;
+
+            let coverage = |subpixel: i64| {
+                let u = (subpixel as f32 + 0.5 - self.pixels.min.x) / width;
+
+                if !(0.0..1.0).contains(&u) {
+                    return 0.0;
+                }
+
+                let texel_x = (self.texels.min.x
+                    + u * (self.texels.max.x - self.texels.min.x))
+                    * cache.width as f32;
+
+                cache.coverage(texel_x as u32, texel_y as u32)
+            };
+
+            for x in min_x..max_x {
+                let first = i64::from(x) * SUBPIXELS as i64 - 2;
+                let mut samples = [0.0; 7];
+
+                for (offset, sample) in samples.iter_mut().enumerate() {
+                    *sample = coverage(first + offset as i64);
+                }
+
+                // A low-pass filter spreads the coverage of every subpixel
+                // over its neighbors, which reduces color fringes
+                let filter = |center: usize| {
+                    (samples[center - 2]
+                        + 2.0 * samples[center - 1]
+                        + 3.0 * samples[center]
+                        + 2.0 * samples[center + 1]
+                        + samples[center + 2])
+                        / 9.0
+                };
+
+                let channels = [filter(2), filter(3), filter(4)];
+
+                if channels.iter().all(|coverage| *coverage <= 0.0) {
+                    continue;
+                }
+
+                let index = (y as usize * stride + x as usize) * 4;
+                let pixel = &mut data[index..index + 4];
+
+                if !is_aligned || pixel[3] < u8::MAX {
+                    let coverage = channels.iter().sum::<f32>() / 3.0;
+
+                    crate::backend::blend(pixel, self.color, coverage);
+                } else {
+                    crate::backend::blend_subpixel(pixel, self.color, channels);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,396 @@
+//! Rasterize meshes of triangles.
+use crate::backend;
+
+use iced_graphics::layer::Mesh;
+use iced_graphics::Gradient;
+use iced_native::Rectangle;
+
+/// Draws the given [`Mesh`], supersampling every pixel with the given amount
+/// of samples.
+///
+/// The coverage of all the triangles is accumulated before blending, so the
+/// shared edges of adjacent triangles do not leave any seams.
+pub fn draw(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    mesh: &Mesh<'_>,
+    scale_factor: f32,
+    sample_count: u32,
+) {
+    let target = Rectangle::new(
+        iced_native::Point::ORIGIN,
+        iced_native::Size::new(pixels.width() as f32, pixels.height() as f32),
+    );
+
+    let clip_bounds =
+        match (mesh.clip_bounds() * scale_factor).intersection(&target) {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+    let origin = mesh.origin();
+
+    let position = |[x, y]: [f32; 2]| {
+        [(origin.x + x) * scale_factor, (origin.y + y) * scale_factor]
+    };
+
+    if let Mesh::Instanced {
+        mesh: handle,
+        instances,
+        ..
+    } = mesh
+    {
+        // Every instance is composited on its own, so overlapping instances
+        // are blended in order
+        for instance in instances.iter() {
+            let vertices: Vec<_> = handle
+                .mesh()
+                .vertices
+                .iter()
+                .map(|vertex| instance.apply(*vertex))
+                .collect();
+
+            let bounds = match vertex_bounds(
+                vertices.iter().map(|vertex| position(vertex.position)),
+            )
+            .and_then(|bounds| bounds.intersection(&clip_bounds))
+            {
+                Some(bounds) => bounds.snap(),
+                None => continue,
+            };
+
+            let mut accumulator = Accumulator::new(bounds, sample_count);
+
+            accumulator.rasterize(
+                &handle.mesh().indices,
+                |index| position(vertices[index].position),
+                |[a, b, c], [wa, wb, wc]| {
+                    let [a, b, c] =
+                        [a, b, c].map(|index| vertices[index].color);
+
+                    [0, 1, 2, 3].map(|i| a[i] * wa + b[i] * wb + c[i] * wc)
+                },
+            );
+
+            accumulator.composite(pixels);
+        }
+
+        return;
+    }
+
+    let mut accumulator = Accumulator::new(clip_bounds.snap(), sample_count);
+
+    match mesh {
+        Mesh::Solid { buffers, .. } => {
+            accumulator.rasterize(
+                &buffers.indices,
+                |index| position(buffers.vertices[index].position),
+                |[a, b, c], [wa, wb, wc]| {
+                    let [a, b, c] =
+                        [a, b, c].map(|index| buffers.vertices[index].color);
+
+                    [0, 1, 2, 3].map(|i| a[i] * wa + b[i] * wb + c[i] * wc)
+                },
+            );
+        }
+        Mesh::Lit { buffers, light, .. } => {
+            accumulator.rasterize(
+                &buffers.indices,
+                |index| position(buffers.vertices[index].position),
+                |[a, b, c], [wa, wb, wc]| {
+                    let [a, b, c] =
+                        [a, b, c].map(|index| &buffers.vertices[index]);
+
+                    let normal = [0, 1, 2].map(|i| {
+                        a.normal[i] * wa + b.normal[i] * wb + c.normal[i] * wc
+                    });
+
+                    let intensity = light.intensity(normal);
+
+                    [0, 1, 2, 3].map(|i| {
+                        let channel =
+                            a.color[i] * wa + b.color[i] * wb + c.color[i] * wc;
+
+                        if i < 3 {
+                            channel * intensity
+                        } else {
+                            channel
+                        }
+                    })
+                },
+            );
+        }
+        Mesh::Instanced { .. } => {}
+        Mesh::Gradient {
+            buffers, gradient, ..
+        } => {
+            accumulator.rasterize(
+                &buffers.indices,
+                |index| position(buffers.vertices[index].position),
+                |[a, b, c], [wa, wb, wc]| {
+                    let [a, b, c] =
+                        [a, b, c].map(|index| buffers.vertices[index].position);
+
+                    gradient_color(
+                        gradient,
+                        [
+                            a[0] * wa + b[0] * wb + c[0] * wc,
+                            a[1] * wa + b[1] * wb + c[1] * wc,
+                        ],
+                    )
+                },
+            );
+        }
+    }
+
+    accumulator.composite(pixels);
+}
+
+/// Returns the bounding box of the given positions, expanded to include the
+/// pixels they touch.
+fn vertex_bounds(
+    mut positions: impl Iterator<Item = [f32; 2]>,
+) -> Option<Rectangle> {
+    let [x, y] = positions.next()?;
+
+    let (min, max) = positions.fold(
+        ([x, y], [x, y]),
+        |([min_x, min_y], [max_x, max_y]), [x, y]| {
+            ([min_x.min(x), min_y.min(y)], [max_x.max(x), max_y.max(y)])
+        },
+    );
+
+    let min = [min[0].floor(), min[1].floor()];
+    let max = [max[0].ceil(), max[1].ceil()];
+
+    Some(Rectangle {
+        x: min[0],
+        y: min[1],
+        width: max[0] - min[0],
+        height: max[1] - min[1],
+    })
+}
+
+/// The premultiplied __linear__ color accumulated for every pixel of some
+/// bounds.
+struct Accumulator {
+    bounds: Rectangle<u32>,
+    samples: Vec<[f32; 2]>,
+    colors: Vec<[f32; 4]>,
+}
+
+impl Accumulator {
+    fn new(bounds: Rectangle<u32>, sample_count: u32) -> Self {
+        // Samples are laid out in a grid, which is good enough for
+        // antialiasing edges
+        let grid = (sample_count.max(1) as f32).sqrt().ceil() as u32;
+
+        let samples = (0..grid)
+            .flat_map(|y| {
+                (0..grid).map(move |x| {
+                    [
+                        (x as f32 + 0.5) / grid as f32,
+                        (y as f32 + 0.5) / grid as f32,
+                    ]
+                })
+            })
+            .collect();
+
+        Self {
+            bounds,
+            samples,
+            colors: vec![[0.0; 4]; (bounds.width * bounds.height) as usize],
+        }
+    }
+
+    fn rasterize(
+        &mut self,
+        indices: &[u32],
+        position: impl Fn(usize) -> [f32; 2],
+        shade: impl Fn([usize; 3], [f32; 3]) -> [f32; 4],
+    ) {
+        for triangle in indices.chunks_exact(3) {
+            let mut vertices =
+                [triangle[0], triangle[1], triangle[2]].map(|i| i as usize);
+
+            let mut points = vertices.map(&position);
+
+            let area = edge(points[0], points[1], points[2]);
+
+            if area == 0.0 {
+                continue;
+            }
+
+            // Keep a consistent winding, so the fill rule of shared edges
+            // works for every triangle
+            if area < 0.0 {
+                vertices.swap(1, 2);
+                points.swap(1, 2);
+            }
+
+            let area = area.abs();
+            let [a, b, c] = points;
+
+            let min_x = a[0].min(b[0]).min(c[0]).floor().max(0.0) as u32;
+            let min_y = a[1].min(b[1]).min(c[1]).floor().max(0.0) as u32;
+            let max_x = a[0].max(b[0]).max(c[0]).ceil().max(0.0) as u32;
+            let max_y = a[1].max(b[1]).max(c[1]).ceil().max(0.0) as u32;
+
+            let min_x = min_x.max(self.bounds.x);
+            let min_y = min_y.max(self.bounds.y);
+            let max_x = max_x.min(self.bounds.x + self.bounds.width);
+            let max_y = max_y.min(self.bounds.y + self.bounds.height);
+
+            for y in min_y..max_y {
+                for x in min_x..max_x {
+                    let covered = self
+                        .samples
+                        .iter()
+                        .filter(|&&[sample_x, sample_y]| {
+                            let point =
+                                [x as f32 + sample_x, y as f32 + sample_y];
+
+                            is_inside(b, c, point)
+                                && is_inside(c, a, point)
+                                && is_inside(a, b, point)
+                        })
+                        .count();
+
+                    if covered == 0 {
+                        continue;
+                    }
+
+                    // Shade the center of the pixel, clamped to the triangle
+                    let center = [x as f32 + 0.5, y as f32 + 0.5];
+
+                    let weights = [
+                        edge(b, c, center) / area,
+                        edge(c, a, center) / area,
+                        edge(a, b, center) / area,
+                    ]
+                    .map(|weight| weight.max(0.0));
+
+                    let total: f32 = weights.iter().sum();
+
+                    if total <= 0.0 {
+                        continue;
+                    }
+
+                    let [red, green, blue, alpha] =
+                        shade(vertices, weights.map(|weight| weight / total));
+
+                    let coverage =
+                        alpha * covered as f32 / self.samples.len() as f32;
+
+                    let index = ((y - self.bounds.y) * self.bounds.width
+                        + (x - self.bounds.x))
+                        as usize;
+
+                    let color = &mut self.colors[index];
+
+                    color[0] += red * coverage;
+                    color[1] += green * coverage;
+                    color[2] += blue * coverage;
+                    color[3] += coverage;
+                }
+            }
+        }
+    }
+
+    fn composite(&self, pixels: &mut tiny_skia::PixmapMut<'_>) {
+        let stride = pixels.width() as usize;
+        let data = pixels.data_mut();
+
+        for (i, [r, g, b, alpha]) in self.colors.iter().copied().enumerate() {
+            if alpha <= 0.0 {
+                continue;
+            }
+
+            let x = self.bounds.x as usize + i % self.bounds.width as usize;
+            let y = self.bounds.y as usize + i / self.bounds.width as usize;
+            let index = (y * stride + x) * 4;
+
+            let color =
+                backend::into_srgb([r / alpha, g / alpha, b / alpha, alpha]);
+
+            backend::blend(&mut data[index..index + 4], color, 1.0);
+        }
+    }
+}
+
+/// Returns twice the signed area of the triangle `a`, `b`, `point`.
+fn edge(a: [f32; 2], b: [f32; 2], point: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (point[1] - a[1]) - (b[1] - a[1]) * (point[0] - a[0])
+}
+
+/// Returns whether the `point` is on the inner side of the edge from `a` to
+/// `b`.
+///
+/// Points exactly on the edge only belong to one of the two triangles that
+/// may share it, since the edge is traversed in opposite directions by each
+/// of them.
+fn is_inside(a: [f32; 2], b: [f32; 2], point: [f32; 2]) -> bool {
+    let distance = edge(a, b, point);
+
+    if distance == 0.0 {
+        let dx = b[0] - a[0];
+        let dy = b[1] - a[1];
+
+        dy > 0.0 || (dy == 0.0 && dx < 0.0)
+    } else {
+        distance > 0.0
+    }
+}
+
+/// Computes the __linear__ color of the [`Gradient`] at the given position.
+fn gradient_color(gradient: &Gradient, [x, y]: [f32; 2]) -> [f32; 4] {
+    match gradient {
+        Gradient::Linear(linear) => {
+            let direction =
+                [linear.end.x - linear.start.x, linear.end.y - linear.start.y];
+            let length =
+                direction[0] * direction[0] + direction[1] * direction[1];
+
+            let offset = if length > 0.0 {
+                ((x - linear.start.x) * direction[0]
+                    + (y - linear.start.y) * direction[1])
+                    / length
+            } else {
+                0.0
+            };
+
+            let stops = &linear.color_stops;
+
+            let (first, last) = match (stops.first(), stops.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => return [0.0; 4],
+            };
+
+            if offset <= first.offset {
+                return first.color.into_linear();
+            }
+
+            if offset >= last.offset {
+                return last.color.into_linear();
+            }
+
+            stops
+                .windows(2)
+                .find(|pair| offset <= pair[1].offset)
+                .map(|pair| {
+                    let range = pair[1].offset - pair[0].offset;
+
+                    let t = if range > 0.0 {
+                        (offset - pair[0].offset) / range
+                    } else {
+                        0.0
+                    };
+
+                    let start = pair[0].color.into_linear();
+                    let end = pair[1].color.into_linear();
+
+                    [0, 1, 2, 3].map(|i| start[i] + (end[i] - start[i]) * t)
+                })
+                .unwrap_or_else(|| last.color.into_linear())
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+
+use iced_graphics::compositor::{Information, SurfaceError};
+use iced_graphics::damage;
+use iced_graphics::Primitive;
+use iced_native::profile;
+use iced_native::Rectangle;
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
+
+use std::marker::PhantomData;
+
+/// A window graphics backend for iced that rasterizes on the CPU and
+/// presents the result using [`softbuffer`].
+///
+/// [`softbuffer`]: https://github.com/rust-windowing/softbuffer
+#[allow(missing_debug_implementations)]
+pub struct Compositor<Theme> {
+    settings: Settings,
+    theme: PhantomData<Theme>,
+}
+
+/// The pixel buffer of a window.
+#[allow(missing_debug_implementations)]
+pub struct Surface {
+    window: softbuffer::GraphicsContext,
+    pixels: tiny_skia::Pixmap,
+    buffer: Vec<u32>,
+    last: Option<Last>,
+}
+
+/// The last frame drawn on a [`Surface`] with damage tracking enabled.
+struct Last {
+    generation: u64,
+    scale_factor: f64,
+    background_color: Color,
+}
+
+impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
+    type Settings = Settings;
+    type Renderer = Renderer<Theme>;
+    type Surface = Surface;
+
+    fn new<W: HasRawWindowHandle + HasRawDisplayHandle>(
+        settings: Self::Settings,
+        _compatible_window: Option<&W>,
+    ) -> Result<(Self, Self::Renderer), Error> {
+        log::info!("{:#?}", settings);
+
+        let compositor = Self {
+            settings,
+            theme: PhantomData,
+        };
+
+        Ok((compositor, Renderer::new(Backend::new(settings))))
+    }
+
+    fn create_surface<W: HasRawWindowHandle + HasRawDisplayHandle>(
+        &mut self,
+        window: &W,
+    ) -> Surface {
+        #[allow(unsafe_code)]
+        let window =
+            unsafe { softbuffer::GraphicsContext::new(window, window) }
+                .expect("Create softbuffer for window");
+
+        Surface {
+            window,
+            pixels: tiny_skia::Pixmap::new(1, 1).expect("Create pixel map"),
+            buffer: vec![0; 1],
+            last: None,
+        }
+    }
+
+    fn configure_surface(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+    ) {
+        let (width, height) = (width.max(1), height.max(1));
+
+        surface.pixels =
+            tiny_skia::Pixmap::new(width, height).expect("Create pixel map");
+        surface.buffer = vec![0; (width * height) as usize];
+        surface.last = None;
+    }
+
+    fn fetch_information(&self) -> Information {
+        Information {
+            adapter: String::from("CPU"),
+            backend: String::from("tiny-skia"),
+        }
+    }
+
+    fn present<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Result<(), SurfaceError> {
+        let damage_tracking = self.settings.damage_tracking;
+        let generation = renderer.generation();
+
+        renderer.with_previous_primitives(|backend, primitives, previous| {
+            present(
+                backend,
+                surface,
+                primitives,
+                previous,
+                generation,
+                viewport,
+                background_color,
+                overlay,
+                damage_tracking,
+            );
+        });
+
+        Ok(())
+    }
+
+    fn count(&self, renderer: &mut Self::Renderer) -> profile::Count {
+        let mut count = profile::Count::default();
+
+        renderer.with_primitives(|_, primitives| {
+            for primitive in primitives {
+                let nested = primitive.count();
+
+                count.primitives += nested.primitives;
+                count.meshes += nested.meshes;
+            }
+        });
+
+        count
+    }
+
+    fn notify_on_decode(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        notify: iced_graphics::image::Notify,
+    ) {
+        #[cfg(feature = "image")]
+        {
+            use iced_graphics::backend::Image;
+
+            renderer.backend().notify_on_decode(notify);
+        }
+
+        #[cfg(not(feature = "image"))]
+        let _ = (renderer, notify);
+    }
+}
+
+fn present<T: AsRef<str>>(
+    backend: &mut Backend,
+    surface: &mut Surface,
+    primitives: &[Primitive],
+    previous: &[Primitive],
+    generation: u64,
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+    damage_tracking: bool,
+) {
+    let size = viewport.physical_size();
+
+    if surface.pixels.width() != size.width
+        || surface.pixels.height() != size.height
+    {
+        // The surface may not have been configured for the current size yet
+        if let Some(pixels) = tiny_skia::Pixmap::new(size.width, size.height) {
+            surface.pixels = pixels;
+            surface.buffer = vec![0; (size.width * size.height) as usize];
+            surface.last = None;
+        } else {
+            return;
+        }
+    }
+
+    let last = surface.last.take().filter(|last| {
+        damage_tracking
+            && overlay.is_empty()
+            && last.scale_factor == viewport.scale_factor()
+            && last.background_color == background_color
+    });
+
+    // The previous primitives can only be compared if they are the ones drawn
+    // in the last frame
+    let damage = match last {
+        Some(last) if last.generation == generation => vec![],
+        Some(last) if last.generation + 1 == generation => damage::group(
+            damage::list(previous, primitives),
+            viewport.scale_factor() as f32,
+            size,
+        ),
+        _ => vec![Rectangle {
+            x: 0,
+            y: 0,
+            width: size.width,
+            height: size.height,
+        }],
+    };
+
+    if !damage.is_empty() {
+        backend.draw_damage(
+            &mut surface.pixels.as_mut(),
+            primitives,
+            viewport,
+            background_color,
+            overlay,
+            &damage,
+        );
+    }
+
+    if damage_tracking {
+        surface.last = Some(Last {
+            generation,
+            scale_factor: viewport.scale_factor(),
+            background_color,
+        });
+    }
+
+    // `softbuffer` expects pixels in the `0RGB` format. Since windows are
+    // opaque, the premultiplied channels are used directly.
+    for (target, pixel) in surface
+        .buffer
+        .iter_mut()
+        .zip(surface.pixels.data().chunks_exact(4))
+    {
+        *target = u32::from(pixel[0]) << 16
+            | u32::from(pixel[1]) << 8
+            | u32::from(pixel[2]);
+    }
+
+    surface.window.set_buffer(
+        &surface.buffer,
+        size.width as u16,
+        size.height as u16,
+    );
+}
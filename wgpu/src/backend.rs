@@ -1,3 +1,4 @@
+use crate::clip;
 use crate::quad;
 use crate::text;
 use crate::triangle;
@@ -5,10 +6,10 @@ use crate::{Settings, Transformation};
 
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::layer::Layer;
+use iced_graphics::layer::{self, Layer};
 use iced_graphics::{Primitive, Viewport};
 use iced_native::alignment;
-use iced_native::{Font, Size};
+use iced_native::{Color, Font, Rectangle, Size};
 
 #[cfg(feature = "tracing")]
 use tracing::info_span;
@@ -25,6 +26,7 @@ pub struct Backend {
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    clip_pipeline: clip::Pipeline,
 
     #[cfg(any(feature = "image", feature = "svg"))]
     image_pipeline: image::Pipeline,
@@ -44,11 +46,13 @@ impl Backend {
             format,
             settings.default_font,
             settings.text_multithreading,
+            settings.text_rendering,
         );
 
         let quad_pipeline = quad::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, settings.antialiasing);
+        let clip_pipeline = clip::Pipeline::new(device, format);
 
         #[cfg(any(feature = "image", feature = "svg"))]
         let image_pipeline = image::Pipeline::new(device, format);
@@ -57,6 +61,7 @@ impl Backend {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+            clip_pipeline,
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
@@ -65,6 +70,38 @@ impl Backend {
         }
     }
 
+    /// Changes the [`Antialiasing`] strategy used for triangle primitives.
+    ///
+    /// The triangle pipeline is recreated only if the strategy changes. The
+    /// resources of the previous pipeline, including its multisample
+    /// targets, are released. Quads and text are not affected.
+    ///
+    /// The sample count must be supported by the `device` for the given
+    /// `format`; see [`Compositor::set_antialiasing`].
+    ///
+    /// [`Antialiasing`]: crate::Antialiasing
+    /// [`Compositor::set_antialiasing`]: crate::window::Compositor::set_antialiasing
+    pub fn set_antialiasing(
+        &mut self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        antialiasing: Option<crate::Antialiasing>,
+    ) {
+        if self.triangle_pipeline.antialiasing() == antialiasing {
+            return;
+        }
+
+        self.triangle_pipeline =
+            triangle::Pipeline::new(device, format, antialiasing);
+    }
+
+    /// Changes the [`TextRendering`] strategy used for text.
+    ///
+    /// [`TextRendering`]: crate::TextRendering
+    pub fn set_text_rendering(&mut self, rendering: crate::TextRendering) {
+        self.text_pipeline.set_rendering(rendering);
+    }
+
     /// Draws the provided primitives in the given `TextureView`.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -90,12 +127,20 @@ impl Backend {
         let mut layers = Layer::generate(primitives, viewport);
         layers.push(Layer::overlay(overlay_text, viewport));
 
+        let clip = Rectangle {
+            x: 0,
+            y: 0,
+            width: target_size.width,
+            height: target_size.height,
+        };
+
         for layer in layers {
             self.flush(
                 device,
                 scale_factor,
                 transformation,
                 &layer,
+                clip,
                 staging_belt,
                 encoder,
                 frame,
@@ -103,6 +148,77 @@ impl Backend {
             );
         }
 
+        self.triangle_pipeline.trim_cache();
+
+        #[cfg(any(feature = "image", feature = "svg"))]
+        self.image_pipeline.trim_cache(device, encoder);
+    }
+
+    /// Draws the provided primitives in the given `TextureView`, but only
+    /// inside the given damage regions.
+    ///
+    /// Each region is cleared with the `background_color` and then redrawn,
+    /// while the rest of the `TextureView` is left untouched. The damage
+    /// regions are expected in physical coordinates, like the ones produced
+    /// by [`damage::group`].
+    ///
+    /// [`damage::group`]: iced_graphics::damage::group
+    pub fn present_damage(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+        primitives: &[Primitive],
+        viewport: &Viewport,
+        background_color: Color,
+        damage: &[Rectangle<u32>],
+    ) {
+        log::debug!("Drawing {} damaged regions", damage.len());
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Backend", "PRESENT_DAMAGE").entered();
+
+        let target_size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor() as f32;
+        let transformation = viewport.projection();
+
+        let layers = Layer::generate(primitives, viewport);
+
+        for region in damage {
+            // Clear the region by drawing the background over it. The quad
+            // overflows the region to avoid antialiasing its edges.
+            let bounds = (Rectangle::<f32>::from(*region)
+                * (1.0 / scale_factor))
+                .expand(1.0);
+
+            let mut background = Layer::new(bounds);
+
+            background.quads.push(layer::Quad {
+                position: [bounds.x, bounds.y],
+                size: [bounds.width, bounds.height],
+                color: background_color.into_linear(),
+                border_color: [0.0; 4],
+                border_radius: [0.0; 4],
+                border_width: 0.0,
+            });
+
+            for layer in std::iter::once(&background).chain(&layers) {
+                self.flush(
+                    device,
+                    scale_factor,
+                    transformation,
+                    layer,
+                    *region,
+                    staging_belt,
+                    encoder,
+                    frame,
+                    target_size,
+                );
+            }
+        }
+
+        self.triangle_pipeline.trim_cache();
+
         #[cfg(any(feature = "image", feature = "svg"))]
         self.image_pipeline.trim_cache(device, encoder);
     }
@@ -113,17 +229,83 @@ impl Backend {
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
+        clip: Rectangle<u32>,
         staging_belt: &mut wgpu::util::StagingBelt,
         encoder: &mut wgpu::CommandEncoder,
         target: &wgpu::TextureView,
         target_size: Size<u32>,
     ) {
-        let bounds = (layer.bounds * scale_factor).snap();
+        let bounds = match (layer.bounds * scale_factor)
+            .intersection(&Rectangle::from(clip))
+        {
+            Some(bounds) => bounds.snap(),
+            None => return,
+        };
 
         if bounds.width < 1 || bounds.height < 1 {
             return;
         }
 
+        if layer.rounded_clips.is_empty() && layer.opacity >= 1.0 {
+            self.draw_layer(
+                device,
+                scale_factor,
+                transformation,
+                layer,
+                bounds,
+                staging_belt,
+                encoder,
+                target,
+                target_size,
+            );
+
+            return;
+        }
+
+        // A layer with rounded clips or some opacity is drawn offscreen first
+        // and then composited through them, clipping and fading every kind of
+        // primitive alike
+        let offscreen = self.clip_pipeline.target(device, encoder, target_size);
+
+        self.draw_layer(
+            device,
+            scale_factor,
+            transformation,
+            layer,
+            bounds,
+            staging_belt,
+            encoder,
+            offscreen.view(),
+            target_size,
+        );
+
+        self.clip_pipeline.composite(
+            device,
+            staging_belt,
+            encoder,
+            offscreen,
+            &layer.rounded_clips,
+            layer.opacity,
+            scale_factor,
+            bounds,
+            target,
+        );
+    }
+
+    /// Draws the primitives of the given [`Layer`], clipped to the given
+    /// physical `bounds`.
+    fn draw_layer(
+        &mut self,
+        device: &wgpu::Device,
+        scale_factor: f32,
+        transformation: Transformation,
+        layer: &Layer<'_>,
+        bounds: Rectangle<u32>,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        target_size: Size<u32>,
+    ) {
         if !layer.quads.is_empty() {
             self.quad_pipeline.draw(
                 device,
@@ -141,16 +323,26 @@ impl Backend {
             let scaled = transformation
                 * Transformation::scale(scale_factor, scale_factor);
 
-            self.triangle_pipeline.draw(
-                device,
-                staging_belt,
-                encoder,
-                target,
-                target_size,
-                scaled,
-                scale_factor,
-                &layer.meshes,
-            );
+            let clip = Rectangle::from(bounds) * (1.0 / scale_factor);
+
+            let meshes: Vec<_> = layer
+                .meshes
+                .iter()
+                .filter_map(|mesh| mesh.clip(&clip))
+                .collect();
+
+            if !meshes.is_empty() {
+                self.triangle_pipeline.draw(
+                    device,
+                    staging_belt,
+                    encoder,
+                    target,
+                    target_size,
+                    scaled,
+                    scale_factor,
+                    &meshes,
+                );
+            }
         }
 
         #[cfg(any(feature = "image", feature = "svg"))]
@@ -175,7 +367,7 @@ impl Backend {
         if !layer.text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = wgpu_glyph::Section {
+                let section = wgpu_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -234,7 +426,16 @@ impl Backend {
                         }),
                 };
 
-                self.text_pipeline.queue(text);
+                let line_height = text
+                    .line_height
+                    .to_absolute(text.size)
+                    .map(|line_height| line_height * scale_factor);
+
+                self.text_pipeline.queue(
+                    section,
+                    line_height,
+                    text.letter_spacing * scale_factor,
+                );
             }
 
             self.text_pipeline.draw_queued(
@@ -273,10 +474,28 @@ impl backend::Text for Backend {
         &self,
         contents: &str,
         size: f32,
+        line_height: iced_native::text::LineHeight,
+        letter_spacing: f32,
         font: Font,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline.measure(
+            contents,
+            size,
+            line_height,
+            letter_spacing,
+            font,
+            bounds,
+        )
+    }
+
+    fn baseline(
+        &self,
+        size: f32,
+        line_height: iced_native::text::LineHeight,
+        font: Font,
+    ) -> f32 {
+        self.text_pipeline.baseline(size, line_height, font)
     }
 
     fn hit_test(
@@ -304,6 +523,17 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> Size<u32> {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn load(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::Status {
+        self.image_pipeline.load(handle)
+    }
+
+    fn notify_on_decode(&self, notify: iced_graphics::image::Notify) {
+        self.image_pipeline.notify_on_decode(notify);
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -314,4 +544,11 @@ impl backend::Svg for Backend {
     ) -> Size<u32> {
         self.image_pipeline.viewport_dimensions(handle)
     }
+
+    fn svg_error(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> Option<iced_native::svg::Error> {
+        self.image_pipeline.svg_error(handle)
+    }
 }
@@ -0,0 +1,339 @@
+use iced_graphics::layer;
+use iced_native::{Rectangle, Size};
+
+use bytemuck::{Pod, Zeroable};
+use std::mem;
+
+#[cfg(feature = "tracing")]
+use tracing::info_span;
+
+/// The maximum amount of rounded clips applied to a layer at once.
+///
+/// Only the innermost ones are applied when a layer is nested deeper. The
+/// rest still clip its contents to their bounds.
+const MAX_CLIPS: usize = 8;
+
+/// A pipeline that composites layers drawn offscreen through their rounded
+/// clips and with their opacity.
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    constants: wgpu::BindGroup,
+    constants_buffer: wgpu::Buffer,
+    texture_layout: wgpu::BindGroupLayout,
+    target: Option<Target>,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Pipeline {
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::clip uniforms layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(
+                            mem::size_of::<Uniforms>() as wgpu::BufferAddress,
+                        ),
+                    },
+                    count: None,
+                }],
+            });
+
+        let constants_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::clip uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::clip uniforms bind group"),
+            layout: &constant_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: constants_buffer.as_entire_binding(),
+            }],
+        });
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::clip texture layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: false,
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::clip pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::clip::shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/clip.wgsl"),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::clip pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(
+                            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+                        ),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            });
+
+        Pipeline {
+            format,
+            pipeline,
+            constants,
+            constants_buffer,
+            texture_layout,
+            target: None,
+        }
+    }
+
+    /// Returns an offscreen [`Target`] of the given size, cleared and ready
+    /// to draw a layer into.
+    ///
+    /// The [`Target`] is reused across calls, so it must be given back with
+    /// [`Pipeline::composite`].
+    pub fn target(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        size: Size<u32>,
+    ) -> Target {
+        let target = match self.target.take() {
+            Some(target) if target.size == size => target,
+            _ => Target::new(device, self.format, &self.texture_layout, size),
+        };
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::clip clear render pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &target.view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: true,
+                },
+            })],
+            depth_stencil_attachment: None,
+        });
+
+        target
+    }
+
+    /// Composites the contents of the offscreen [`Target`] inside the given
+    /// physical `bounds` of the `target`, through the given rounded clips and
+    /// with the given `opacity`.
+    pub fn composite(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        offscreen: Target,
+        rounded_clips: &[layer::RoundedClip],
+        opacity: f32,
+        scale_factor: f32,
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
+        #[cfg(feature = "tracing")]
+        let _ = info_span!("Wgpu::Clip", "COMPOSITE").entered();
+
+        let uniforms = Uniforms::new(rounded_clips, opacity, scale_factor);
+
+        {
+            let mut constants_buffer = staging_belt.write_buffer(
+                encoder,
+                &self.constants_buffer,
+                0,
+                wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64)
+                    .unwrap(),
+                device,
+            );
+
+            constants_buffer.copy_from_slice(bytemuck::bytes_of(&uniforms));
+        }
+
+        {
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::clip render pass"),
+                    color_attachments: &[Some(
+                        wgpu::RenderPassColorAttachment {
+                            view: target,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: true,
+                            },
+                        },
+                    )],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass.set_bind_group(1, &offscreen.bind_group, &[]);
+            render_pass.set_scissor_rect(
+                bounds.x,
+                bounds.y,
+                bounds.width,
+                bounds.height,
+            );
+            render_pass.draw(0..6, 0..1);
+        }
+
+        self.target = Some(offscreen);
+    }
+}
+
+/// An offscreen texture where a layer with rounded clips or some opacity is
+/// drawn before being composited.
+#[derive(Debug)]
+pub struct Target {
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    size: Size<u32>,
+}
+
+impl Target {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        texture_layout: &wgpu::BindGroupLayout,
+        size: Size<u32>,
+    ) -> Target {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::clip offscreen target"),
+            size: wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::clip texture bind group"),
+            layout: texture_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Target {
+            view,
+            bind_group,
+            size,
+        }
+    }
+
+    /// Returns the view of the offscreen texture to draw into.
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Clip {
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    clips: [Clip; MAX_CLIPS],
+    count: u32,
+    opacity: f32,
+    // Uniforms must be aligned to their largest member,
+    // this uses a vec4<f32> which aligns to 16, so align to that
+    _padding: [u32; 2],
+}
+
+impl Uniforms {
+    fn new(
+        rounded_clips: &[layer::RoundedClip],
+        opacity: f32,
+        scale_factor: f32,
+    ) -> Self {
+        let innermost =
+            &rounded_clips[rounded_clips.len().saturating_sub(MAX_CLIPS)..];
+
+        let mut clips = [Clip::zeroed(); MAX_CLIPS];
+
+        for (clip, rounded_clip) in clips.iter_mut().zip(innermost) {
+            let bounds = rounded_clip.bounds * scale_factor;
+
+            *clip = Clip {
+                bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
+                border_radius: rounded_clip
+                    .border_radius
+                    .map(|radius| radius * scale_factor),
+            };
+        }
+
+        Self {
+            clips,
+            count: innermost.len() as u32,
+            opacity,
+            _padding: [0; 2],
+        }
+    }
+}
@@ -9,6 +9,7 @@ use iced_graphics::image::vector;
 use crate::Transformation;
 use atlas::Atlas;
 
+use iced_graphics::image::Orientation;
 use iced_graphics::layer;
 use iced_native::{Rectangle, Size};
 
@@ -173,6 +174,8 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Float32,
+                                7 => Uint32,
                             ),
                         },
                     ],
@@ -273,6 +276,16 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image")]
+    pub fn load(&self, handle: &image::Handle) -> image::Status {
+        self.raster_cache.borrow_mut().load_in_background(handle)
+    }
+
+    #[cfg(feature = "image")]
+    pub fn notify_on_decode(&self, notify: iced_graphics::image::Notify) {
+        self.raster_cache.borrow_mut().notify_on_decode(notify);
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> Size<u32> {
         let mut cache = self.vector_cache.borrow_mut();
@@ -281,6 +294,11 @@ impl Pipeline {
         svg.viewport_dimensions()
     }
 
+    #[cfg(feature = "svg")]
+    pub fn svg_error(&self, handle: &svg::Handle) -> Option<svg::Error> {
+        self.vector_cache.borrow_mut().load(handle).error().cloned()
+    }
+
     pub fn draw(
         &mut self,
         device: &wgpu::Device,
@@ -306,7 +324,14 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    region,
+                    rotation,
+                    flip,
+                    bounds,
+                    opacity,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
                         &mut (device, encoder),
@@ -315,6 +340,9 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
+                            *region,
+                            Orientation::new(*rotation, *flip),
+                            *opacity,
                             atlas_entry,
                             instances,
                         );
@@ -328,6 +356,7 @@ impl Pipeline {
                     handle,
                     color,
                     bounds,
+                    opacity,
                 } => {
                     let size = [bounds.width, bounds.height];
 
@@ -342,6 +371,9 @@ impl Pipeline {
                         add_instances(
                             [bounds.x, bounds.y],
                             size,
+                            None,
+                            Orientation::default(),
+                            *opacity,
                             atlas_entry,
                             instances,
                         );
@@ -504,6 +536,8 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _opacity: f32,
+    _orientation: u32,
 }
 
 impl Instance {
@@ -519,38 +553,79 @@ struct Uniforms {
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
+    region: Option<Rectangle<u32>>,
+    orientation: Orientation,
+    opacity: f32,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    use iced_graphics::image::storage::Entry as _;
+
+    let region = region.unwrap_or_else(|| {
+        let Size { width, height } = entry.size();
+
+        Rectangle {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    });
+
+    if region.width == 0 || region.height == 0 {
+        return;
+    }
+
+    let mut add_fragment =
+        |(fragment_x, fragment_y): (u32, u32),
+         allocation: &atlas::Allocation| {
+            let Size { width, height } = allocation.size();
+
+            // The part of the region covered by the fragment, in pixels of the
+            // image
+            let left = region.x.max(fragment_x);
+            let top = region.y.max(fragment_y);
+            let right = (region.x + region.width).min(fragment_x + width);
+            let bottom = (region.y + region.height).min(fragment_y + height);
+
+            if left >= right || top >= bottom {
+                return;
+            }
+
+            // The part of the drawn image showing the fragment, once oriented
+            let [x0, y0, x1, y1] = orientation.place([
+                (left - region.x) as f32 / region.width as f32,
+                (top - region.y) as f32 / region.height as f32,
+                (right - region.x) as f32 / region.width as f32,
+                (bottom - region.y) as f32 / region.height as f32,
+            ]);
+
+            let [x, y] = image_position;
+            let [image_width, image_height] = image_size;
+
+            add_instance(
+                [x + x0 * image_width, y + y0 * image_height],
+                [(x1 - x0) * image_width, (y1 - y0) * image_height],
+                allocation,
+                Rectangle {
+                    x: left - fragment_x,
+                    y: top - fragment_y,
+                    width: right - left,
+                    height: bottom - top,
+                },
+                orientation,
+                opacity,
+                instances,
+            );
+        };
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_fragment((0, 0), allocation);
         }
-        atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = image_size[0] / size.width as f32;
-            let scaling_y = image_size[1] / size.height as f32;
-
+        atlas::Entry::Fragmented { fragments, .. } => {
             for fragment in fragments {
-                let allocation = &fragment.allocation;
-
-                let [x, y] = image_position;
-                let (fragment_x, fragment_y) = fragment.position;
-                let Size {
-                    width: fragment_width,
-                    height: fragment_height,
-                } = allocation.size();
-
-                let position = [
-                    x + fragment_x as f32 * scaling_x,
-                    y + fragment_y as f32 * scaling_y,
-                ];
-
-                let size = [
-                    fragment_width as f32 * scaling_x,
-                    fragment_height as f32 * scaling_y,
-                ];
-
-                add_instance(position, size, allocation, instances);
+                add_fragment(fragment.position, &fragment.allocation);
             }
         }
     }
@@ -561,24 +636,28 @@ fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
     allocation: &atlas::Allocation,
+    region: Rectangle<u32>,
+    orientation: Orientation,
+    opacity: f32,
     instances: &mut Vec<Instance>,
 ) {
     let (x, y) = allocation.position();
-    let Size { width, height } = allocation.size();
     let layer = allocation.layer();
 
     let instance = Instance {
         _position: position,
         _size: size,
         _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
+            (x as f32 + region.x as f32 + 0.5) / atlas::SIZE as f32,
+            (y as f32 + region.y as f32 + 0.5) / atlas::SIZE as f32,
         ],
         _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
+            (region.width as f32 - 1.0) / atlas::SIZE as f32,
+            (region.height as f32 - 1.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
+        _opacity: opacity,
+        _orientation: orientation.bits(),
     };
 
     instances.push(instance);
@@ -43,11 +43,14 @@ pub mod window;
 
 mod backend;
 mod buffer;
+mod clip;
 mod quad;
 mod text;
 mod triangle;
 
-pub use iced_graphics::{Antialiasing, Color, Error, Primitive, Viewport};
+pub use iced_graphics::{
+    Antialiasing, Color, Error, Primitive, TextRendering, Viewport,
+};
 pub use iced_native::Theme;
 pub use wgpu;
 
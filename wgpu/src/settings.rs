@@ -1,5 +1,5 @@
 //! Configure a renderer.
-pub use crate::Antialiasing;
+pub use crate::{Antialiasing, TextRendering};
 
 /// The settings of a [`Backend`].
 ///
@@ -30,10 +30,33 @@ pub struct Settings {
     /// By default, it is disabled.
     pub text_multithreading: bool,
 
+    /// The [`TextRendering`] strategy that will be used for text.
+    ///
+    /// Subpixel antialiasing is not supported by this renderer yet, so
+    /// [`TextRendering::Subpixel`] is treated as [`TextRendering::Grayscale`].
+    ///
+    /// By default, it is [`TextRendering::Unhinted`].
+    pub text_rendering: TextRendering,
+
     /// The antialiasing strategy that will be used for triangle primitives.
     ///
+    /// If the graphics adapter does not support the sample count of the
+    /// strategy, [`Antialiasing::default`] is used instead. Higher sample
+    /// counts use more video memory; see [`Antialiasing`] for details.
+    ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// If enabled, only the regions of the window that changed since the
+    /// last frame will be redrawn.
+    ///
+    /// This can greatly reduce the rendering work of mostly static user
+    /// interfaces, at the cost of keeping the primitives of the last frame
+    /// and an additional texture around. Windows with a translucent
+    /// background are always fully redrawn.
+    ///
+    /// By default, it is disabled.
+    pub damage_tracking: bool,
 }
 
 impl Settings {
@@ -68,7 +91,9 @@ impl Default for Settings {
             default_font: None,
             default_text_size: 20,
             text_multithreading: false,
+            text_rendering: TextRendering::default(),
             antialiasing: None,
+            damage_tracking: false,
         }
     }
 }
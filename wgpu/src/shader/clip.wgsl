@@ -0,0 +1,79 @@
+struct Clip {
+    bounds: vec4<f32>,
+    border_radius: vec4<f32>,
+}
+
+struct Uniforms {
+    clips: array<Clip, 8>,
+    count: u32,
+    opacity: f32,
+}
+
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(positions[input.vertex_index], 0.0, 1.0);
+
+    return out;
+}
+
+// The signed distance from the given position to a rounded rectangle.
+// Order matches CSS border radius attribute:
+// radi.x = top-left, radi.y = top-right, radi.z = bottom-right, radi.w = bottom-left
+fn rounded_box_distance(position: vec2<f32>, clip: Clip) -> f32 {
+    var size: vec2<f32> = clip.bounds.zw;
+    var offset: vec2<f32> = position - (clip.bounds.xy + size * 0.5);
+
+    var radi: vec2<f32> = select(
+        clip.border_radius.xw,
+        clip.border_radius.yz,
+        offset.x > 0.0
+    );
+    var radius: f32 = min(
+        select(radi.x, radi.y, offset.y > 0.0),
+        min(size.x, size.y) * 0.5
+    );
+
+    var q: vec2<f32> = abs(offset) - size * 0.5 + vec2<f32>(radius, radius);
+
+    return min(max(q.x, q.y), 0.0)
+        + length(max(q, vec2<f32>(0.0, 0.0)))
+        - radius;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    var coverage: f32 = uniforms.opacity;
+
+    for (var i: u32 = 0u; i < uniforms.count; i = i + 1u) {
+        var clip_distance: f32 = rounded_box_distance(
+            input.position.xy,
+            uniforms.clips[i]
+        );
+
+        coverage = min(coverage, clamp(0.5 - clip_distance, 0.0, 1.0));
+    }
+
+    // The layer is premultiplied, so every channel is scaled alike
+    return textureLoad(u_texture, vec2<i32>(input.position.xy), 0) * coverage;
+}
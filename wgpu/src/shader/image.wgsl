@@ -13,20 +13,39 @@ struct VertexInput {
     @location(3) atlas_pos: vec2<f32>,
     @location(4) atlas_scale: vec2<f32>,
     @location(5) layer: i32,
+    @location(6) opacity: f32,
+    @location(7) orientation: u32,
 }
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) layer: f32, // this should be an i32, but naga currently reads that as requiring interpolation.
+    @location(2) opacity: f32,
 }
 
 @vertex
 fn vs_main(input: VertexInput) -> VertexOutput {
     var out: VertexOutput;
 
-    out.uv = vec2<f32>(input.v_pos * input.atlas_scale + input.atlas_pos);
+    // The point of the original image shown by this vertex, once oriented
+    var uv: vec2<f32> = input.v_pos;
+
+    if ((input.orientation & 1u) != 0u) {
+        uv = uv.yx;
+    }
+
+    if ((input.orientation & 2u) != 0u) {
+        uv.x = 1.0 - uv.x;
+    }
+
+    if ((input.orientation & 4u) != 0u) {
+        uv.y = 1.0 - uv.y;
+    }
+
+    out.uv = vec2<f32>(uv * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
+    out.opacity = input.opacity;
 
     var transform: mat4x4<f32> = mat4x4<f32>(
         vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
@@ -42,5 +61,7 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
 @fragment
 fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    let color = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    return vec4<f32>(color.rgb, color.a * input.opacity);
 }
@@ -0,0 +1,48 @@
+struct Globals {
+    transform: mat4x4<f32>,
+    light: vec4<f32>,
+}
+
+@group(0) @binding(0) var<uniform> globals: Globals;
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) color: vec4<f32>,
+    @location(2) normal: vec3<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    @location(1) normal: vec3<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    out.color = input.color;
+    out.normal = input.normal;
+    out.position = globals.transform * vec4<f32>(input.position, 0.0, 1.0);
+
+    return out;
+}
+
+// Lambertian shading: `light.xyz` points towards the light and `light.w` is
+// the ambient intensity.
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    var diffuse: f32 = 0.0;
+
+    if (length(input.normal) > 0.0 && length(globals.light.xyz) > 0.0) {
+        diffuse = max(
+            dot(normalize(input.normal), normalize(globals.light.xyz)),
+            0.0
+        );
+    }
+
+    let ambient = clamp(globals.light.w, 0.0, 1.0);
+    let intensity = ambient + (1.0 - ambient) * diffuse;
+
+    return vec4<f32>(input.color.rgb * intensity, input.color.a);
+}
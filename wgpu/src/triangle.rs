@@ -13,10 +13,13 @@ use tracing::info_span;
 
 #[derive(Debug)]
 pub struct Pipeline {
+    antialiasing: Option<settings::Antialiasing>,
     blit: Option<msaa::Blit>,
     index_buffer: Buffer<u32>,
     index_strides: Vec<u32>,
     solid: solid::Pipeline,
+    lit: lit::Pipeline,
+    instanced: instanced::Pipeline,
 
     /// Gradients are currently not supported on WASM targets due to their need of storage buffers.
     #[cfg(not(target_arch = "wasm32"))]
@@ -30,6 +33,7 @@ impl Pipeline {
         antialiasing: Option<settings::Antialiasing>,
     ) -> Pipeline {
         Pipeline {
+            antialiasing,
             blit: antialiasing.map(|a| msaa::Blit::new(device, format, a)),
             index_buffer: Buffer::new(
                 device,
@@ -38,12 +42,24 @@ impl Pipeline {
             ),
             index_strides: Vec::new(),
             solid: solid::Pipeline::new(device, format, antialiasing),
+            lit: lit::Pipeline::new(device, format, antialiasing),
+            instanced: instanced::Pipeline::new(device, format, antialiasing),
 
             #[cfg(not(target_arch = "wasm32"))]
             gradient: gradient::Pipeline::new(device, format, antialiasing),
         }
     }
 
+    pub fn antialiasing(&self) -> Option<settings::Antialiasing> {
+        self.antialiasing
+    }
+
+    /// Discards the cached vertices of the instanced meshes that were not
+    /// drawn since the last call.
+    pub fn trim_cache(&mut self) {
+        self.instanced.trim();
+    }
+
     pub fn draw(
         &mut self,
         device: &wgpu::Device,
@@ -67,6 +83,8 @@ impl Pipeline {
         // the majority of use cases. Therefore we will write GPU data every frame (for now).
         let _ = self.index_buffer.resize(device, count.indices);
         let _ = self.solid.vertices.resize(device, count.solid_vertices);
+        let _ = self.lit.vertices.resize(device, count.lit_vertices);
+        let _ = self.instanced.instances.resize(device, count.instances);
 
         #[cfg(not(target_arch = "wasm32"))]
         let _ = self
@@ -78,6 +96,10 @@ impl Pipeline {
         self.index_strides.clear();
         self.solid.vertices.clear();
         self.solid.uniforms.clear();
+        self.lit.vertices.clear();
+        self.lit.uniforms.clear();
+        self.instanced.instances.clear();
+        self.instanced.uniforms.clear();
 
         #[cfg(not(target_arch = "wasm32"))]
         {
@@ -87,6 +109,8 @@ impl Pipeline {
         }
 
         let mut solid_vertex_offset = 0;
+        let mut lit_vertex_offset = 0;
+        let mut instance_offset = 0;
         let mut index_offset = 0;
 
         #[cfg(not(target_arch = "wasm32"))]
@@ -125,6 +149,45 @@ impl Pipeline {
 
                     solid_vertex_offset += written_bytes;
                 }
+                Mesh::Lit { buffers, light, .. } => {
+                    self.lit
+                        .uniforms
+                        .push(&lit::Uniforms::new(transform, *light));
+
+                    let written_bytes = self.lit.vertices.write(
+                        device,
+                        staging_belt,
+                        encoder,
+                        lit_vertex_offset,
+                        &buffers.vertices,
+                    );
+
+                    lit_vertex_offset += written_bytes;
+                }
+                Mesh::Instanced {
+                    mesh, instances, ..
+                } => {
+                    self.instanced
+                        .uniforms
+                        .push(&solid::Uniforms::new(transform));
+
+                    self.instanced.upload(device, mesh);
+
+                    let instances: Vec<instanced::Instance> = instances
+                        .iter()
+                        .map(instanced::Instance::from)
+                        .collect();
+
+                    let written_bytes = self.instanced.instances.write(
+                        device,
+                        staging_belt,
+                        encoder,
+                        instance_offset,
+                        &instances,
+                    );
+
+                    instance_offset += written_bytes;
+                }
                 #[cfg(not(target_arch = "wasm32"))]
                 Mesh::Gradient {
                     buffers, gradient, ..
@@ -206,6 +269,34 @@ impl Pipeline {
             self.solid.uniforms.write(device, staging_belt, encoder);
         }
 
+        if count.lit_vertices > 0 {
+            let uniforms_resized = self.lit.uniforms.resize(device);
+
+            if uniforms_resized {
+                self.lit.bind_group = lit::Pipeline::bind_group(
+                    device,
+                    self.lit.uniforms.raw(),
+                    &self.lit.bind_group_layout,
+                )
+            }
+
+            self.lit.uniforms.write(device, staging_belt, encoder);
+        }
+
+        if count.instances > 0 {
+            let uniforms_resized = self.instanced.uniforms.resize(device);
+
+            if uniforms_resized {
+                self.instanced.bind_group = solid::Pipeline::bind_group(
+                    device,
+                    self.instanced.uniforms.raw(),
+                    &self.instanced.bind_group_layout,
+                )
+            }
+
+            self.instanced.uniforms.write(device, staging_belt, encoder);
+        }
+
         #[cfg(not(target_arch = "wasm32"))]
         if count.gradient_vertices > 0 {
             // First write the pending color stops to the CPU buffer
@@ -269,9 +360,11 @@ impl Pipeline {
                 });
 
             let mut num_solids = 0;
+            let mut num_lits = 0;
+            let mut num_instanced = 0;
             #[cfg(not(target_arch = "wasm32"))]
             let mut num_gradients = 0;
-            let mut last_is_solid = None;
+            let mut last_kind = None;
 
             for (index, mesh) in meshes.iter().enumerate() {
                 let clip_bounds = (mesh.clip_bounds() * scale_factor).snap();
@@ -285,10 +378,10 @@ impl Pipeline {
 
                 match mesh {
                     Mesh::Solid { .. } => {
-                        if !last_is_solid.unwrap_or(false) {
+                        if last_kind != Some(Kind::Solid) {
                             render_pass.set_pipeline(&self.solid.pipeline);
 
-                            last_is_solid = Some(true);
+                            last_kind = Some(Kind::Solid);
                         }
 
                         render_pass.set_bind_group(
@@ -304,12 +397,62 @@ impl Pipeline {
 
                         num_solids += 1;
                     }
+                    Mesh::Lit { .. } => {
+                        if last_kind != Some(Kind::Lit) {
+                            render_pass.set_pipeline(&self.lit.pipeline);
+
+                            last_kind = Some(Kind::Lit);
+                        }
+
+                        render_pass.set_bind_group(
+                            0,
+                            &self.lit.bind_group,
+                            &[self.lit.uniforms.offset_at_index(num_lits)],
+                        );
+
+                        render_pass.set_vertex_buffer(
+                            0,
+                            self.lit.vertices.slice_from_index(num_lits),
+                        );
+
+                        num_lits += 1;
+                    }
+                    Mesh::Instanced { mesh, .. } => {
+                        if last_kind != Some(Kind::Instanced) {
+                            render_pass.set_pipeline(&self.instanced.pipeline);
+
+                            last_kind = Some(Kind::Instanced);
+                        }
+
+                        render_pass.set_bind_group(
+                            0,
+                            &self.instanced.bind_group,
+                            &[self
+                                .instanced
+                                .uniforms
+                                .offset_at_index(num_instanced)],
+                        );
+
+                        render_pass.set_vertex_buffer(
+                            0,
+                            self.instanced.vertices(mesh).slice(..),
+                        );
+
+                        render_pass.set_vertex_buffer(
+                            1,
+                            self.instanced
+                                .instances
+                                .slice_from_index(num_instanced),
+                        );
+
+                        num_instanced += 1;
+                    }
                     #[cfg(not(target_arch = "wasm32"))]
                     Mesh::Gradient { .. } => {
-                        if last_is_solid.unwrap_or(true) {
+                        if last_kind != Some(Kind::Gradient) {
                             render_pass.set_pipeline(&self.gradient.pipeline);
 
-                            last_is_solid = Some(false);
+                            last_kind = Some(Kind::Gradient);
                         }
 
                         render_pass.set_bind_group(
@@ -339,7 +482,16 @@ impl Pipeline {
                     wgpu::IndexFormat::Uint32,
                 );
 
-                render_pass.draw_indexed(0..self.index_strides[index], 0, 0..1);
+                let instances = match mesh {
+                    Mesh::Instanced { instances, .. } => instances.len() as u32,
+                    _ => 1,
+                };
+
+                render_pass.draw_indexed(
+                    0..self.index_strides[index],
+                    0,
+                    0..instances,
+                );
             }
         }
 
@@ -351,6 +503,16 @@ impl Pipeline {
     }
 }
 
+/// The kind of pipeline used to draw a [`Mesh`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Solid,
+    Lit,
+    Instanced,
+    #[cfg(not(target_arch = "wasm32"))]
+    Gradient,
+}
+
 fn fragment_target(
     texture_format: wgpu::TextureFormat,
 ) -> Option<wgpu::ColorTargetState> {
@@ -531,6 +693,360 @@ mod solid {
     }
 }
 
+mod lit {
+    use crate::buffer::dynamic;
+    use crate::buffer::r#static::Buffer;
+    use crate::settings;
+    use crate::triangle;
+    use encase::ShaderType;
+    use iced_graphics::triangle::{Light, LitVertex2D};
+    use iced_graphics::Transformation;
+
+    #[derive(Debug)]
+    pub struct Pipeline {
+        pub pipeline: wgpu::RenderPipeline,
+        pub vertices: Buffer<LitVertex2D>,
+        pub uniforms: dynamic::Buffer<Uniforms>,
+        pub bind_group_layout: wgpu::BindGroupLayout,
+        pub bind_group: wgpu::BindGroup,
+    }
+
+    #[derive(Debug, Clone, Copy, ShaderType)]
+    pub struct Uniforms {
+        transform: glam::Mat4,
+        light: glam::Vec4,
+    }
+
+    impl Uniforms {
+        pub fn new(transform: Transformation, light: Light) -> Self {
+            let [x, y, z] = light.direction;
+
+            Self {
+                transform: transform.into(),
+                light: glam::Vec4::new(x, y, z, light.ambient),
+            }
+        }
+    }
+
+    impl Pipeline {
+        /// Creates a new [LitPipeline] using `lit.wgsl` shader.
+        pub fn new(
+            device: &wgpu::Device,
+            format: wgpu::TextureFormat,
+            antialiasing: Option<settings::Antialiasing>,
+        ) -> Self {
+            let vertices = Buffer::new(
+                device,
+                "iced_wgpu::triangle::lit vertex buffer",
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            );
+
+            let uniforms = dynamic::Buffer::uniform(
+                device,
+                "iced_wgpu::triangle::lit uniforms",
+            );
+
+            let bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("iced_wgpu::triangle::lit bind group layout"),
+                    entries: &[wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: true,
+                            min_binding_size: Some(Uniforms::min_size()),
+                        },
+                        count: None,
+                    }],
+                },
+            );
+
+            let bind_group =
+                Self::bind_group(device, uniforms.raw(), &bind_group_layout);
+
+            let layout = device.create_pipeline_layout(
+                &wgpu::PipelineLayoutDescriptor {
+                    label: Some("iced_wgpu::triangle::lit pipeline layout"),
+                    bind_group_layouts: &[&bind_group_layout],
+                    push_constant_ranges: &[],
+                },
+            );
+
+            let shader =
+                device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: Some(
+                        "iced_wgpu::triangle::lit create shader module",
+                    ),
+                    source: wgpu::ShaderSource::Wgsl(
+                        std::borrow::Cow::Borrowed(include_str!(
+                            "shader/lit.wgsl"
+                        )),
+                    ),
+                });
+
+            let pipeline = device.create_render_pipeline(
+                &wgpu::RenderPipelineDescriptor {
+                    label: Some("iced_wgpu::triangle::lit pipeline"),
+                    layout: Some(&layout),
+                    vertex: wgpu::VertexState {
+                        module: &shader,
+                        entry_point: "vs_main",
+                        buffers: &[wgpu::VertexBufferLayout {
+                            array_stride: std::mem::size_of::<LitVertex2D>()
+                                as u64,
+                            step_mode: wgpu::VertexStepMode::Vertex,
+                            attributes: &wgpu::vertex_attr_array!(
+                                // Position
+                                0 => Float32x2,
+                                // Color
+                                1 => Float32x4,
+                                // Normal
+                                2 => Float32x3,
+                            ),
+                        }],
+                    },
+                    fragment: Some(wgpu::FragmentState {
+                        module: &shader,
+                        entry_point: "fs_main",
+                        targets: &[triangle::fragment_target(format)],
+                    }),
+                    primitive: triangle::primitive_state(),
+                    depth_stencil: None,
+                    multisample: triangle::multisample_state(antialiasing),
+                    multiview: None,
+                },
+            );
+
+            Self {
+                pipeline,
+                vertices,
+                uniforms,
+                bind_group_layout,
+                bind_group,
+            }
+        }
+
+        pub fn bind_group(
+            device: &wgpu::Device,
+            buffer: &wgpu::Buffer,
+            layout: &wgpu::BindGroupLayout,
+        ) -> wgpu::BindGroup {
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("iced_wgpu::triangle::lit bind group"),
+                layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(
+                        wgpu::BufferBinding {
+                            buffer,
+                            offset: 0,
+                            size: Some(Uniforms::min_size()),
+                        },
+                    ),
+                }],
+            })
+        }
+    }
+}
+
+mod instanced {
+    use crate::buffer::dynamic;
+    use crate::buffer::r#static::Buffer;
+    use crate::settings;
+    use crate::triangle::{self, solid};
+
+    use bytemuck::{Pod, Zeroable};
+    use encase::ShaderType;
+    use iced_graphics::triangle::{ColoredVertex2D, Handle};
+    use std::collections::{HashMap, HashSet};
+    use wgpu::util::DeviceExt;
+
+    #[derive(Debug)]
+    pub struct Pipeline {
+        pub pipeline: wgpu::RenderPipeline,
+        pub instances: Buffer<Instance>,
+        pub uniforms: dynamic::Buffer<solid::Uniforms>,
+        pub bind_group_layout: wgpu::BindGroupLayout,
+        pub bind_group: wgpu::BindGroup,
+        meshes: HashMap<u64, wgpu::Buffer>,
+        hits: HashSet<u64>,
+    }
+
+    #[derive(Debug, Clone, Copy, Zeroable, Pod)]
+    #[repr(C)]
+    pub struct Instance {
+        transform: [f32; 16],
+        color: [f32; 4],
+    }
+
+    impl From<&iced_graphics::triangle::Instance> for Instance {
+        fn from(instance: &iced_graphics::triangle::Instance) -> Self {
+            Self {
+                transform: instance.transformation.into(),
+                color: instance.color.into_linear(),
+            }
+        }
+    }
+
+    impl Pipeline {
+        /// Creates a new instanced [Pipeline] using `instanced.wgsl` shader.
+        pub fn new(
+            device: &wgpu::Device,
+            format: wgpu::TextureFormat,
+            antialiasing: Option<settings::Antialiasing>,
+        ) -> Self {
+            let instances = Buffer::new(
+                device,
+                "iced_wgpu::triangle::instanced instance buffer",
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            );
+
+            let uniforms = dynamic::Buffer::uniform(
+                device,
+                "iced_wgpu::triangle::instanced uniforms",
+            );
+
+            let bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some(
+                        "iced_wgpu::triangle::instanced bind group layout",
+                    ),
+                    entries: &[wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: true,
+                            min_binding_size: Some(solid::Uniforms::min_size()),
+                        },
+                        count: None,
+                    }],
+                },
+            );
+
+            let bind_group = solid::Pipeline::bind_group(
+                device,
+                uniforms.raw(),
+                &bind_group_layout,
+            );
+
+            let layout = device.create_pipeline_layout(
+                &wgpu::PipelineLayoutDescriptor {
+                    label: Some(
+                        "iced_wgpu::triangle::instanced pipeline layout",
+                    ),
+                    bind_group_layouts: &[&bind_group_layout],
+                    push_constant_ranges: &[],
+                },
+            );
+
+            let shader =
+                device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: Some(
+                        "iced_wgpu::triangle::instanced create shader module",
+                    ),
+                    source: wgpu::ShaderSource::Wgsl(
+                        std::borrow::Cow::Borrowed(include_str!(
+                            "shader/instanced.wgsl"
+                        )),
+                    ),
+                });
+
+            let pipeline =
+                device.create_render_pipeline(
+                    &wgpu::RenderPipelineDescriptor {
+                        label: Some("iced_wgpu::triangle::instanced pipeline"),
+                        layout: Some(&layout),
+                        vertex: wgpu::VertexState {
+                            module: &shader,
+                            entry_point: "vs_main",
+                            buffers: &[
+                                wgpu::VertexBufferLayout {
+                                    array_stride: std::mem::size_of::<
+                                        ColoredVertex2D,
+                                    >(
+                                    )
+                                        as u64,
+                                    step_mode: wgpu::VertexStepMode::Vertex,
+                                    attributes: &wgpu::vertex_attr_array!(
+                                        // Position
+                                        0 => Float32x2,
+                                        // Color
+                                        1 => Float32x4,
+                                    ),
+                                },
+                                wgpu::VertexBufferLayout {
+                                    array_stride: std::mem::size_of::<Instance>(
+                                    )
+                                        as u64,
+                                    step_mode: wgpu::VertexStepMode::Instance,
+                                    attributes: &wgpu::vertex_attr_array!(
+                                        // Transform
+                                        2 => Float32x4,
+                                        3 => Float32x4,
+                                        4 => Float32x4,
+                                        5 => Float32x4,
+                                        // Color
+                                        6 => Float32x4,
+                                    ),
+                                },
+                            ],
+                        },
+                        fragment: Some(wgpu::FragmentState {
+                            module: &shader,
+                            entry_point: "fs_main",
+                            targets: &[triangle::fragment_target(format)],
+                        }),
+                        primitive: triangle::primitive_state(),
+                        depth_stencil: None,
+                        multisample: triangle::multisample_state(antialiasing),
+                        multiview: None,
+                    },
+                );
+
+            Self {
+                pipeline,
+                instances,
+                uniforms,
+                bind_group_layout,
+                bind_group,
+                meshes: HashMap::new(),
+                hits: HashSet::new(),
+            }
+        }
+
+        /// Uploads the vertices of the given [`Handle`], unless they are
+        /// already cached.
+        pub fn upload(&mut self, device: &wgpu::Device, handle: &Handle) {
+            let _ = self.hits.insert(handle.id());
+
+            let _ = self.meshes.entry(handle.id()).or_insert_with(|| {
+                device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("iced_wgpu::triangle::instanced vertex buffer"),
+                    contents: bytemuck::cast_slice(&handle.mesh().vertices),
+                    usage: wgpu::BufferUsages::VERTEX,
+                })
+            });
+        }
+
+        /// Returns the cached vertices of the given [`Handle`].
+        ///
+        /// The [`Handle`] must have been uploaded first.
+        pub fn vertices(&self, handle: &Handle) -> &wgpu::Buffer {
+            &self.meshes[&handle.id()]
+        }
+
+        /// Discards the meshes that were not uploaded since the last call.
+        pub fn trim(&mut self) {
+            let hits = &self.hits;
+
+            self.meshes.retain(|id, _| hits.contains(id));
+            self.hits.clear();
+        }
+    }
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 mod gradient {
     use crate::buffer::dynamic;
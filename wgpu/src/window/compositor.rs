@@ -1,9 +1,15 @@
-use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+use crate::{
+    Antialiasing, Backend, Color, Error, Renderer, Settings, TextRendering,
+    Viewport,
+};
 
 use futures::stream::{self, StreamExt};
 
 use iced_graphics::compositor;
+use iced_graphics::damage;
 use iced_native::futures;
+use iced_native::profile;
+use iced_native::{Rectangle, Size};
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
 use std::marker::PhantomData;
@@ -18,9 +24,56 @@ pub struct Compositor<Theme> {
     queue: wgpu::Queue,
     staging_belt: wgpu::util::StagingBelt,
     format: wgpu::TextureFormat,
+    damage: Option<Damage>,
     theme: PhantomData<Theme>,
 }
 
+/// The last frame presented with damage tracking enabled.
+struct Damage {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    size: Size<u32>,
+    scale_factor: f64,
+    background_color: Color,
+    generation: Option<u64>,
+}
+
+impl Damage {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        size: Size<u32>,
+        scale_factor: f64,
+        background_color: Color,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::window::Compositor damage texture"),
+            size: wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        Self {
+            texture,
+            view,
+            size,
+            scale_factor,
+            background_color,
+            generation: None,
+        }
+    }
+}
+
 impl<Theme> Compositor<Theme> {
     const CHUNK_SIZE: u64 = 10 * 1024;
 
@@ -68,6 +121,19 @@ impl<Theme> Compositor<Theme> {
 
         log::info!("Selected format: {:?}", format);
 
+        // Sample counts other than 4 need adapter-specific format features
+        let features = adapter.features()
+            & wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES;
+
+        let settings = Settings {
+            antialiasing: settings.antialiasing.map(|antialiasing| {
+                supported_antialiasing(&adapter, features, format, antialiasing)
+            }),
+            damage_tracking: settings.damage_tracking
+                && supports_surface_copies(&adapter, format),
+            ..settings
+        };
+
         #[cfg(target_arch = "wasm32")]
         let limits = [wgpu::Limits::downlevel_webgl2_defaults()
             .using_resolution(adapter.limits())];
@@ -88,7 +154,7 @@ impl<Theme> Compositor<Theme> {
                         label: Some(
                             "iced_wgpu::window::compositor device descriptor",
                         ),
-                        features: wgpu::Features::empty(),
+                        features,
                         limits,
                     },
                     None,
@@ -108,6 +174,7 @@ impl<Theme> Compositor<Theme> {
             queue,
             staging_belt,
             format,
+            damage: None,
             theme: PhantomData,
         })
     }
@@ -116,6 +183,126 @@ impl<Theme> Compositor<Theme> {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings, self.format)
     }
+
+    /// Changes the [`Antialiasing`] strategy of the given [`Renderer`].
+    ///
+    /// If the graphics adapter does not support the sample count of the
+    /// strategy, [`Antialiasing::default`] is used instead.
+    pub fn set_antialiasing(
+        &mut self,
+        renderer: &mut Renderer<Theme>,
+        antialiasing: Option<Antialiasing>,
+    ) {
+        let antialiasing = antialiasing.map(|antialiasing| {
+            supported_antialiasing(
+                &self.adapter,
+                self.device.features(),
+                self.format,
+                antialiasing,
+            )
+        });
+
+        self.settings.antialiasing = antialiasing;
+
+        renderer.with_primitives(|backend, _| {
+            backend.set_antialiasing(&self.device, self.format, antialiasing);
+        });
+    }
+
+    /// Changes the [`TextRendering`] strategy of the given [`Renderer`].
+    ///
+    /// The last frame is drawn again from scratch the next time it is
+    /// presented.
+    pub fn set_text_rendering(
+        &mut self,
+        renderer: &mut Renderer<Theme>,
+        rendering: TextRendering,
+    ) {
+        self.settings.text_rendering = rendering;
+        self.damage = None;
+
+        renderer.with_primitives(|backend, _| {
+            backend.set_text_rendering(rendering);
+        });
+    }
+
+    /// Redraws the damaged regions of the last frame and copies the result
+    /// to the given `frame`.
+    fn present_damage(
+        &mut self,
+        renderer: &mut Renderer<Theme>,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::Texture,
+        viewport: &Viewport,
+        background_color: Color,
+    ) {
+        let size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor();
+
+        // Any change to the target invalidates the whole last frame
+        let mut last = match self.damage.take() {
+            Some(damage)
+                if damage.size == size
+                    && damage.scale_factor == scale_factor
+                    && damage.background_color == background_color =>
+            {
+                damage
+            }
+            _ => Damage::new(
+                &self.device,
+                self.format,
+                size,
+                scale_factor,
+                background_color,
+            ),
+        };
+
+        let device = &self.device;
+        let staging_belt = &mut self.staging_belt;
+
+        let generation = renderer.generation();
+
+        renderer.with_previous_primitives(|backend, primitives, previous| {
+            // The previous primitives can only be compared if they are the
+            // ones drawn in the last frame
+            let damage = match last.generation {
+                Some(last) if last == generation => vec![],
+                Some(last) if last + 1 == generation => {
+                    damage::list(previous, primitives)
+                }
+                _ => vec![Rectangle::with_size(viewport.logical_size())],
+            };
+
+            let regions = damage::group(damage, scale_factor as f32, size);
+
+            if !regions.is_empty() {
+                backend.present_damage(
+                    device,
+                    staging_belt,
+                    encoder,
+                    &last.view,
+                    primitives,
+                    viewport,
+                    background_color,
+                    &regions,
+                );
+            }
+
+            last.generation = Some(generation);
+        });
+
+        encoder.copy_texture_to_texture(
+            last.texture.as_image_copy(),
+            frame.as_image_copy(),
+            wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+        );
+
+        self.damage = Some(last);
+    }
 }
 
 impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
@@ -154,10 +341,19 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
         width: u32,
         height: u32,
     ) {
+        let usage = if self.settings.damage_tracking {
+            // The last frame is copied to the surface when tracking damage,
+            // which is only enabled if the adapter supports it
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_DST
+        } else {
+            wgpu::TextureUsages::RENDER_ATTACHMENT
+        };
+
         surface.configure(
             &self.device,
             &wgpu::SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                usage,
                 format: self.format,
                 present_mode: self.settings.present_mode,
                 width,
@@ -192,49 +388,66 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
                     },
                 );
 
-                let view = &frame
-                    .texture
-                    .create_view(&wgpu::TextureViewDescriptor::default());
+                let damage_tracking = self.settings.damage_tracking
+                    && background_color.a >= 1.0
+                    && overlay.is_empty();
 
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Clear({
-                                        let [r, g, b, a] =
-                                            background_color.into_linear();
-
-                                        wgpu::Color {
-                                            r: f64::from(r),
-                                            g: f64::from(g),
-                                            b: f64::from(b),
-                                            a: f64::from(a),
-                                        }
-                                    }),
-                                    store: true,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                    });
-
-                renderer.with_primitives(|backend, primitives| {
-                    backend.present(
-                        &self.device,
-                        &mut self.staging_belt,
+                if damage_tracking {
+                    self.present_damage(
+                        renderer,
                         &mut encoder,
-                        view,
-                        primitives,
+                        &frame.texture,
                         viewport,
-                        overlay,
+                        background_color,
+                    );
+                } else {
+                    self.damage = None;
+
+                    let view = &frame
+                        .texture
+                        .create_view(&wgpu::TextureViewDescriptor::default());
+
+                    let _ = encoder.begin_render_pass(
+                        &wgpu::RenderPassDescriptor {
+                            label: Some(
+                                "iced_wgpu::window::Compositor render pass",
+                            ),
+                            color_attachments: &[Some(
+                                wgpu::RenderPassColorAttachment {
+                                    view,
+                                    resolve_target: None,
+                                    ops: wgpu::Operations {
+                                        load: wgpu::LoadOp::Clear({
+                                            let [r, g, b, a] =
+                                                background_color.into_linear();
+
+                                            wgpu::Color {
+                                                r: f64::from(r),
+                                                g: f64::from(g),
+                                                b: f64::from(b),
+                                                a: f64::from(a),
+                                            }
+                                        }),
+                                        store: true,
+                                    },
+                                },
+                            )],
+                            depth_stencil_attachment: None,
+                        },
                     );
-                });
+
+                    renderer.with_primitives(|backend, primitives| {
+                        backend.present(
+                            &self.device,
+                            &mut self.staging_belt,
+                            &mut encoder,
+                            view,
+                            primitives,
+                            viewport,
+                            overlay,
+                        );
+                    });
+                }
 
                 // Submit work
                 self.staging_belt.finish();
@@ -260,4 +473,95 @@ impl<Theme> iced_graphics::window::Compositor for Compositor<Theme> {
             },
         }
     }
+
+    fn count(&self, renderer: &mut Self::Renderer) -> profile::Count {
+        let mut count = profile::Count::default();
+
+        renderer.with_primitives(|_, primitives| {
+            for primitive in primitives {
+                let nested = primitive.count();
+
+                count.primitives += nested.primitives;
+                count.meshes += nested.meshes;
+            }
+        });
+
+        count
+    }
+
+    fn notify_on_decode(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        notify: iced_graphics::image::Notify,
+    ) {
+        #[cfg(feature = "image")]
+        {
+            use iced_graphics::backend::Image;
+
+            renderer.backend().notify_on_decode(notify);
+        }
+
+        #[cfg(not(feature = "image"))]
+        let _ = (renderer, notify);
+    }
+}
+
+/// Returns the given [`Antialiasing`] if its sample count is supported by the
+/// adapter for the given `format` and `features`. Otherwise, falls back to
+/// [`Antialiasing::default`], which is supported everywhere.
+fn supported_antialiasing(
+    adapter: &wgpu::Adapter,
+    features: wgpu::Features,
+    format: wgpu::TextureFormat,
+    antialiasing: Antialiasing,
+) -> Antialiasing {
+    let sample_count = antialiasing.sample_count();
+
+    if sample_count == Antialiasing::default().sample_count() {
+        return antialiasing;
+    }
+
+    let is_supported = features
+        .contains(wgpu::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES)
+        && adapter
+            .get_texture_format_features(format)
+            .flags
+            .sample_count_supported(sample_count);
+
+    if is_supported {
+        antialiasing
+    } else {
+        log::warn!(
+            "{:?} is not supported by the adapter, using {:?} instead",
+            antialiasing,
+            Antialiasing::default()
+        );
+
+        Antialiasing::default()
+    }
+}
+
+/// Returns whether the last frame can be copied to the surfaces of the
+/// adapter with the given `format`, which damage tracking relies on.
+///
+/// The surfaces of the OpenGL backend can only be rendered to, and the
+/// `format` must support being the destination of a copy everywhere else.
+fn supports_surface_copies(
+    adapter: &wgpu::Adapter,
+    format: wgpu::TextureFormat,
+) -> bool {
+    let is_supported = adapter.get_info().backend != wgpu::Backend::Gl
+        && adapter
+            .get_texture_format_features(format)
+            .allowed_usages
+            .contains(wgpu::TextureUsages::COPY_DST);
+
+    if !is_supported {
+        log::warn!(
+            "Damage tracking is not supported by the surfaces of the \
+            adapter. The whole window will be redrawn every frame."
+        );
+    }
+
+    is_supported
 }
@@ -7,17 +7,26 @@ pub use state::State;
 
 use crate::clipboard::{self, Clipboard};
 use crate::conversion;
+use crate::event;
+use crate::input_method::InputMethod;
+use crate::keyboard;
+use crate::layout;
+use crate::menu::{self, Menu};
 use crate::mouse;
+use crate::profile;
 use crate::renderer;
+use crate::text;
+use crate::touch;
 use crate::widget::operation;
 use crate::{
-    Command, Debug, Error, Event, Executor, Proxy, Runtime, Settings, Size,
-    Subscription,
+    Command, Debug, Error, Event, Executor, Length, Proxy, Runtime, Settings,
+    Size, Subscription, UserEvent,
 };
 
 use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::compositor;
+use iced_graphics::image::Notify;
 use iced_graphics::window;
 use iced_native::program::Program;
 use iced_native::time::Instant;
@@ -26,6 +35,7 @@ use iced_native::user_interface::{self, UserInterface};
 pub use iced_native::application::{Appearance, StyleSheet};
 
 use std::mem::ManuallyDrop;
+use std::sync::Mutex;
 
 #[cfg(feature = "trace")]
 pub use profiler::Profiler;
@@ -42,7 +52,8 @@ use tracing::{info_span, instrument::Instrument};
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. Pressing `Shift+F12` toggles outlines
+/// around the layout bounds of every widget.
 pub trait Application: Program
 where
     <Self::Renderer as crate::Renderer>::Theme: StyleSheet,
@@ -89,6 +100,68 @@ where
         Subscription::none()
     }
 
+    /// Returns the current [`Menu`] of the [`Application`].
+    ///
+    /// The runtime rebuilds the [`Menu`] after every update, so the state of
+    /// its entries can change dynamically. The accelerators of the [`Menu`]
+    /// are handled before the events reach [`intercept`](#method.intercept).
+    ///
+    /// On macOS, the [`Menu`] is shown in the native menu bar when the `menu`
+    /// feature is enabled. Everywhere else, it can be shown inside the window
+    /// with a [`MenuBar`].
+    ///
+    /// By default, it returns an empty [`Menu`].
+    ///
+    /// [`MenuBar`]: crate::widget::MenuBar
+    fn menu(&self) -> Menu<Self::Message> {
+        Menu::new()
+    }
+
+    /// Intercepts an [`Event`] before it reaches the widgets of the
+    /// [`Application`].
+    ///
+    /// This method is called for every runtime event before the widget tree
+    /// processes it. Returning [`event::Status::Captured`] stops the
+    /// propagation of the [`Event`] and none of the widgets will see it,
+    /// including any focused input. The produced message, if any, will be
+    /// handled by [`update`](#tymethod.update) before any messages produced by
+    /// the widgets.
+    ///
+    /// The [`Subscription`] runtime is notified afterwards. Captured events
+    /// are delivered with [`event::Status::Captured`], so
+    /// [`subscription::events`] will skip them while
+    /// [`subscription::events_with`] will still receive them.
+    ///
+    /// By default, it ignores every [`Event`].
+    ///
+    /// [`subscription::events`]: crate::subscription::events
+    /// [`subscription::events_with`]: crate::subscription::events_with
+    fn intercept(
+        &self,
+        _event: &Event,
+    ) -> (event::Status, Option<Self::Message>) {
+        (event::Status::Ignored, None)
+    }
+
+    /// Returns the message to handle right before the [`Application`] exits,
+    /// if any.
+    ///
+    /// It is handled by [`update`](#tymethod.update) no matter the reason of
+    /// the exit: a close request, a [`window::close`] command, or the
+    /// platform terminating the event loop—like macOS does when the user
+    /// logs out. The [`Command`] returned is discarded, since nothing runs
+    /// after it, so persist any state synchronously.
+    ///
+    /// The process may still be killed without any notice, in which case
+    /// this message is never handled.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`window::close`]: crate::window::close
+    fn on_exit(&self) -> Option<Self::Message> {
+        None
+    }
+
     /// Returns the scale factor of the [`Application`].
     ///
     /// It can be used to dynamically control the size of the UI at runtime
@@ -113,6 +186,7 @@ where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: text::Renderer,
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
 {
     use futures::task;
@@ -148,13 +222,12 @@ where
     let target = settings.window.platform_specific.target.clone();
 
     let should_be_visible = settings.window.visible;
+    let fit_content = settings.window.fit_content;
+    let (size, position) = (settings.window.size, settings.window.position);
+    let monitor = settings.window.target_monitor(&event_loop);
     let builder = settings
         .window
-        .into_builder(
-            &application.title(),
-            event_loop.primary_monitor(),
-            settings.id,
-        )
+        .into_builder(&application.title(), monitor, settings.id)
         .with_visible(false);
 
     log::info!("Window builder: {:#?}", builder);
@@ -189,7 +262,22 @@ where
         };
     }
 
-    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
+    let (compositor, mut renderer) =
+        C::new(compositor_settings, Some(&window))?;
+
+    // The window is still hidden, so fitting it to its contents here avoids
+    // any flickering
+    if fit_content {
+        let size = resize_to_fit(&application, &mut renderer, &window, size);
+
+        if let Some(position) = conversion::position(
+            window.current_monitor().as_ref(),
+            size,
+            position,
+        ) {
+            window.set_outer_position(position);
+        }
+    }
 
     let (mut event_sender, event_receiver) = mpsc::unbounded();
     let (control_sender, mut control_receiver) = mpsc::unbounded();
@@ -208,6 +296,8 @@ where
             window,
             should_be_visible,
             settings.exit_on_close_request,
+            settings.profiler,
+            settings.overlay,
         );
 
         #[cfg(feature = "trace")]
@@ -265,20 +355,23 @@ async fn run_instance<A, E, C>(
     mut compositor: C,
     mut renderer: A::Renderer,
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
-    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut proxy: winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     mut debug: Debug,
     mut event_receiver: mpsc::UnboundedReceiver<
-        winit::event::Event<'_, A::Message>,
+        winit::event::Event<'_, UserEvent<A::Message>>,
     >,
     mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     init_command: Command<A::Message>,
     window: winit::window::Window,
     should_be_visible: bool,
     exit_on_close_request: bool,
+    profiler: Option<profile::Hook>,
+    overlay: profile::overlay::Settings,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: text::Renderer,
     <A::Renderer as crate::Renderer>::Theme: StyleSheet,
 {
     use iced_futures::futures::stream::StreamExt;
@@ -288,6 +381,8 @@ async fn run_instance<A, E, C>(
     let mut clipboard = Clipboard::connect(&window);
     let mut cache = user_interface::Cache::default();
     let mut surface = compositor.create_surface(&window);
+
+    compositor.notify_on_decode(&mut renderer, wake(&proxy));
     let mut should_exit = false;
 
     let mut state = State::new(&application, &window);
@@ -308,7 +403,7 @@ async fn run_instance<A, E, C>(
     run_command(
         &application,
         &mut cache,
-        &state,
+        &mut state,
         &mut renderer,
         init_command,
         &mut runtime,
@@ -330,9 +425,12 @@ async fn run_instance<A, E, C>(
     ));
 
     let mut mouse_interaction = mouse::Interaction::default();
+    let mut input_method = InputMethod::Disabled;
     let mut events = Vec::new();
     let mut messages = Vec::new();
     let mut redraw_pending = false;
+    let mut gestures = touch::gesture::Recognizer::new();
+    let mut profiler = profile::Profiler::new(profiler, overlay);
 
     debug.startup_finished();
 
@@ -347,24 +445,44 @@ async fn run_instance<A, E, C>(
                 );
             }
             event::Event::MainEventsCleared => {
+                for path in menu::activations() {
+                    messages.extend(application.menu().activate(&path));
+                }
+
                 if !redraw_pending && events.is_empty() && messages.is_empty() {
                     continue;
                 }
 
                 debug.event_processing_started();
+                profiler.phase_started(profile::Phase::EventProcessing);
+
+                let (uncaptured, interceptions) =
+                    intercept(&application, &events, &mut messages);
 
                 let (interface_state, statuses) = user_interface.update(
-                    &events,
-                    state.cursor_position(),
+                    &uncaptured,
+                    state.cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
                 );
 
                 debug.event_processing_finished();
+                profiler.phase_finished(profile::Phase::EventProcessing);
+
+                let mut statuses = statuses.into_iter();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                for (event, interception) in
+                    events.drain(..).zip(interceptions.into_iter())
+                {
+                    let status = match interception {
+                        event::Status::Captured => event::Status::Captured,
+                        event::Status::Ignored => {
+                            statuses.next().unwrap_or(event::Status::Ignored)
+                        }
+                    };
+
+                    runtime.broadcast((event, status));
                 }
 
                 if !messages.is_empty()
@@ -377,10 +495,11 @@ async fn run_instance<A, E, C>(
                         ManuallyDrop::into_inner(user_interface).into_cache();
 
                     // Update application
+                    profiler.phase_started(profile::Phase::Update);
                     update(
                         &mut application,
                         &mut cache,
-                        &state,
+                        &mut state,
                         &mut renderer,
                         &mut runtime,
                         &mut clipboard,
@@ -391,10 +510,14 @@ async fn run_instance<A, E, C>(
                         &window,
                         || compositor.fetch_information(),
                     );
+                    profiler.phase_finished(profile::Phase::Update);
 
                     // Update window
                     state.synchronize(&application, &window);
+                    events
+                        .extend(state.scale_factor_event().map(Event::Window));
 
+                    profiler.phase_started(profile::Phase::Layout);
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &application,
                         cache,
@@ -402,6 +525,7 @@ async fn run_instance<A, E, C>(
                         state.logical_size(),
                         &mut debug,
                     ));
+                    profiler.phase_finished(profile::Phase::Layout);
 
                     if should_exit {
                         break;
@@ -419,22 +543,30 @@ async fn run_instance<A, E, C>(
 
                 let (interface_state, _) = user_interface.update(
                     &[redraw_event.clone()],
-                    state.cursor_position(),
+                    state.cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
                 );
 
                 debug.draw_started();
+                profiler.phase_started(profile::Phase::Draw);
                 let new_mouse_interaction = user_interface.draw(
                     &mut renderer,
                     state.theme(),
                     &renderer::Style {
                         text_color: state.text_color(),
                     },
-                    state.cursor_position(),
+                    state.cursor(),
                 );
                 debug.draw_finished();
+                profiler.phase_finished(profile::Phase::Draw);
+
+                if let Some(color) = debug.layout_explanation() {
+                    user_interface.explain(&mut renderer, color);
+                }
+
+                profiler.overlay().draw(&mut renderer, state.logical_size());
 
                 if new_mouse_interaction != mouse_interaction {
                     window.set_cursor_icon(conversion::mouse_interaction(
@@ -444,6 +576,27 @@ async fn run_instance<A, E, C>(
                     mouse_interaction = new_mouse_interaction;
                 }
 
+                if let user_interface::State::Updated {
+                    input_method: new_input_method,
+                    ..
+                } = interface_state
+                {
+                    if new_input_method != input_method {
+                        let position = conversion::input_method_position(
+                            new_input_method,
+                            state.scale_factor(),
+                        );
+
+                        window.set_ime_allowed(position.is_some());
+
+                        if let Some(position) = position {
+                            window.set_ime_position(position);
+                        }
+
+                        input_method = new_input_method;
+                    }
+                }
+
                 window.request_redraw();
                 runtime
                     .broadcast((redraw_event, crate::event::Status::Ignored));
@@ -451,6 +604,7 @@ async fn run_instance<A, E, C>(
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         crate::window::RedrawRequest::NextFrame => {
                             ControlFlow::Poll
@@ -475,9 +629,12 @@ async fn run_instance<A, E, C>(
                     )),
                 ));
             }
-            event::Event::UserEvent(message) => {
+            event::Event::UserEvent(UserEvent::Message(message)) => {
                 messages.push(message);
             }
+            event::Event::UserEvent(UserEvent::Wake) => {
+                redraw_pending = true;
+            }
             event::Event::RedrawRequested(_) => {
                 #[cfg(feature = "trace")]
                 let _ = info_span!("Application", "FRAME").entered();
@@ -495,20 +652,23 @@ async fn run_instance<A, E, C>(
                     let logical_size = state.logical_size();
 
                     debug.layout_started();
+                    profiler.phase_started(profile::Phase::Layout);
                     user_interface = ManuallyDrop::new(
                         ManuallyDrop::into_inner(user_interface)
                             .relayout(logical_size, &mut renderer),
                     );
                     debug.layout_finished();
+                    profiler.phase_finished(profile::Phase::Layout);
 
                     debug.draw_started();
+                    profiler.phase_started(profile::Phase::Draw);
                     let new_mouse_interaction = user_interface.draw(
                         &mut renderer,
                         state.theme(),
                         &renderer::Style {
                             text_color: state.text_color(),
                         },
-                        state.cursor_position(),
+                        state.cursor(),
                     );
 
                     if new_mouse_interaction != mouse_interaction {
@@ -519,6 +679,15 @@ async fn run_instance<A, E, C>(
                         mouse_interaction = new_mouse_interaction;
                     }
                     debug.draw_finished();
+                    profiler.phase_finished(profile::Phase::Draw);
+
+                    if let Some(color) = debug.layout_explanation() {
+                        user_interface.explain(&mut renderer, color);
+                    }
+
+                    profiler
+                        .overlay()
+                        .draw(&mut renderer, state.logical_size());
 
                     compositor.configure_surface(
                         &mut surface,
@@ -529,13 +698,18 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                match compositor.present(
+                profiler.phase_started(profile::Phase::Render);
+                let result = compositor.present(
                     &mut renderer,
                     &mut surface,
                     state.viewport(),
                     state.background_color(),
                     &debug.overlay(),
-                ) {
+                );
+                profiler.phase_finished(profile::Phase::Render);
+                profiler.frame_finished(|| compositor.count(&mut renderer));
+
+                match result {
                     Ok(()) => {
                         debug.render_finished();
 
@@ -560,30 +734,88 @@ async fn run_instance<A, E, C>(
                 event: window_event,
                 ..
             } => {
-                if requests_exit(&window_event, state.modifiers())
-                    && exit_on_close_request
-                {
-                    break;
+                if requests_exit(&window_event, state.modifiers()) {
+                    if exit_on_close_request {
+                        break;
+                    }
+
+                    // Quit shortcuts are requests too, so they can be vetoed
+                    if !matches!(
+                        window_event,
+                        event::WindowEvent::CloseRequested
+                    ) {
+                        events.push(Event::Window(
+                            crate::window::Event::CloseRequested,
+                        ));
+                    }
                 }
 
                 state.update(&window, &window_event, &mut debug);
 
+                if let event::WindowEvent::Moved(_)
+                | event::WindowEvent::ScaleFactorChanged { .. }
+                | event::WindowEvent::Focused(true) = window_event
+                {
+                    let refresh =
+                        !matches!(window_event, event::WindowEvent::Moved(_));
+
+                    events.extend(
+                        state
+                            .monitor_events(&window, refresh)
+                            .into_iter()
+                            .map(Event::Window),
+                    );
+                }
+
+                events.extend(state.scale_factor_event().map(Event::Window));
+
                 if let Some(event) = conversion::window_event(
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
                 ) {
+                    let _ = profiler.overlay_mut().update(&event);
+
+                    let gesture = match &event {
+                        Event::Touch(touch) => gestures.update(touch),
+                        _ => None,
+                    };
+
                     events.push(event);
+                    events.extend(gesture.map(|gesture| {
+                        Event::Touch(touch::Event::Gesture(gesture))
+                    }));
                 }
             }
+            event::Event::LoopDestroyed => break,
             _ => {}
         }
     }
 
+    if let Some(message) = application.on_exit() {
+        debug.log_message(&message);
+
+        let _ = runtime.enter(|| application.update(message));
+    }
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
 
+/// Returns a [`Notify`] that wakes up the event loop of the given proxy.
+pub fn wake<Message: Send + 'static>(
+    proxy: &winit::event_loop::EventLoopProxy<UserEvent<Message>>,
+) -> Notify {
+    // The proxy is not always `Sync`
+    let proxy = Mutex::new(proxy.clone());
+
+    Notify::new(move || {
+        if let Ok(proxy) = proxy.lock() {
+            let _ = proxy.send_event(UserEvent::Wake);
+        }
+    })
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(
@@ -608,6 +840,68 @@ pub fn requests_exit(
     }
 }
 
+/// Runs [`Application::intercept`] for the given events, pushing any produced
+/// messages.
+///
+/// It returns the events that were not captured, in order, together with the
+/// interception status of every event.
+pub fn intercept<A: Application>(
+    application: &A,
+    events: &[Event],
+    messages: &mut Vec<A::Message>,
+) -> (Vec<Event>, Vec<event::Status>)
+where
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    let mut uncaptured = Vec::with_capacity(events.len());
+
+    let statuses = events
+        .iter()
+        .map(|event| {
+            let (status, message) = match accelerate(application, event) {
+                Some(message) => (event::Status::Captured, Some(message)),
+                None => application.intercept(event),
+            };
+
+            messages.extend(message);
+
+            if status == event::Status::Ignored {
+                uncaptured.push(event.clone());
+            }
+
+            status
+        })
+        .collect();
+
+    (uncaptured, statuses)
+}
+
+/// Returns the message of the [`Menu`] entry of an [`Application`] whose
+/// accelerator matches the given [`Event`], if any.
+///
+/// Native menus handle their own accelerators, so only the ones unknown to
+/// the platform are matched when the [`Menu`] is shown natively.
+fn accelerate<A: Application>(
+    application: &A,
+    event: &Event,
+) -> Option<A::Message>
+where
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }) if !menu::is_native_accelerator(menu::Accelerator::new(
+            *modifiers, *key_code,
+        )) =>
+        {
+            application.menu().accelerate(*key_code, *modifiers)
+        }
+        _ => None,
+    }
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -648,12 +942,12 @@ where
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<A>,
     renderer: &mut A::Renderer,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
-    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
     window: &winit::window::Window,
@@ -694,17 +988,78 @@ pub fn update<A: Application, E: Executor>(
     runtime.track(subscription);
 }
 
+/// Resizes the window to fit the contents of the [`Application`], measured
+/// within the bounds of the current monitor.
+///
+/// Axes where the root element fills the available space have no intrinsic
+/// size, so they use the logical size of the `fallback` instead.
+///
+/// Returns the new logical size of the window, before any minimum or maximum
+/// size of the window is applied.
+pub fn resize_to_fit<A>(
+    application: &A,
+    renderer: &mut A::Renderer,
+    window: &winit::window::Window,
+    fallback: (u32, u32),
+) -> (u32, u32)
+where
+    A: Application,
+    <A::Renderer as crate::Renderer>::Theme: StyleSheet,
+{
+    let scale_factor = application.scale_factor() as f32;
+
+    let bounds = window.current_monitor().map_or(Size::INFINITY, |monitor| {
+        let size: winit::dpi::LogicalSize<f32> =
+            monitor.size().to_logical(monitor.scale_factor());
+
+        Size::new(size.width, size.height)
+    });
+
+    let view = application.view();
+    let node = crate::Renderer::layout(
+        renderer,
+        &view,
+        &layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                bounds.width / scale_factor,
+                bounds.height / scale_factor,
+            ),
+        ),
+    );
+
+    let fit = |length: Length, content: f32, fallback: u32| {
+        if length.fill_factor() == 0 {
+            (content * scale_factor).ceil() as u32
+        } else {
+            fallback
+        }
+    };
+
+    let size = (
+        fit(view.as_widget().width(), node.size().width, fallback.0),
+        fit(view.as_widget().height(), node.size().height, fallback.1),
+    );
+
+    window.set_inner_size(winit::dpi::LogicalSize {
+        width: size.0,
+        height: size.1,
+    });
+
+    size
+}
+
 /// Runs the actions of a [`Command`].
 pub fn run_command<A, E>(
     application: &A,
     cache: &mut user_interface::Cache,
-    state: &State<A>,
+    state: &mut State<A>,
     renderer: &mut A::Renderer,
     command: Command<A::Message>,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
     should_exit: &mut bool,
-    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     window: &winit::window::Window,
     _graphics_info: impl FnOnce() -> compositor::Information + Copy,
@@ -727,7 +1082,7 @@ pub fn run_command<A, E>(
                     let message = tag(clipboard.read());
 
                     proxy
-                        .send_event(message)
+                        .send_event(UserEvent::Message(message))
                         .expect("Send message to event loop");
                 }
                 clipboard::Action::Write(contents) => {
@@ -747,6 +1102,17 @@ pub fn run_command<A, E>(
                         height,
                     });
                 }
+                window::Action::ResizeToFit => {
+                    let size: winit::dpi::LogicalSize<u32> =
+                        window.inner_size().to_logical(window.scale_factor());
+
+                    let _ = resize_to_fit(
+                        application,
+                        renderer,
+                        window,
+                        (size.width, size.height),
+                    );
+                }
                 window::Action::Maximize(value) => {
                     window.set_maximized(value);
                 }
@@ -759,24 +1125,84 @@ pub fn run_command<A, E>(
                         y,
                     });
                 }
-                window::Action::ChangeMode(mode) => {
-                    window.set_visible(conversion::visible(mode));
-                    window.set_fullscreen(conversion::fullscreen(
-                        window.primary_monitor(),
-                        mode,
+                window::Action::MoveToMonitor(id) => {
+                    let monitor = window
+                        .available_monitors()
+                        .find(|monitor| conversion::monitor_id(monitor) == id)
+                        .or_else(|| window.primary_monitor());
+
+                    if let Some(monitor) = monitor {
+                        let size: winit::dpi::LogicalSize<u32> = window
+                            .outer_size()
+                            .to_logical(window.scale_factor());
+
+                        if let Some(position) = conversion::position(
+                            Some(&monitor),
+                            (size.width, size.height),
+                            crate::Position::Centered,
+                        ) {
+                            window.set_outer_position(position);
+                        }
+                    }
+                }
+                window::Action::FetchMonitors(tag) => {
+                    let primary = window.primary_monitor();
+                    let monitors = window
+                        .available_monitors()
+                        .map(|monitor| {
+                            conversion::monitor(&monitor, primary.as_ref())
+                        })
+                        .collect();
+
+                    proxy
+                        .send_event(UserEvent::Message(tag(monitors)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::FetchCurrentMonitor(tag) => {
+                    let primary = window.primary_monitor();
+                    let monitor = window.current_monitor().map(|monitor| {
+                        conversion::monitor(&monitor, primary.as_ref())
+                    });
+
+                    proxy
+                        .send_event(UserEvent::Message(tag(monitor)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::FetchScaleFactor(tag) => {
+                    let scale_factor =
+                        window::ScaleFactor::new(state.scale_factor());
+
+                    proxy
+                        .send_event(UserEvent::Message(tag(scale_factor)))
+                        .expect("Send message to event loop");
+                }
+                window::Action::SetMode(mode) => {
+                    let mode = state.set_mode(window, mode);
+
+                    runtime.broadcast((
+                        Event::Window(window::Event::ModeChanged(mode)),
+                        event::Status::Ignored,
                     ));
                 }
                 window::Action::FetchMode(tag) => {
-                    let mode = if window.is_visible().unwrap_or(true) {
-                        conversion::mode(window.fullscreen())
-                    } else {
-                        window::Mode::Hidden
-                    };
+                    let mode = state.mode(window);
 
                     proxy
-                        .send_event(tag(mode))
+                        .send_event(UserEvent::Message(tag(mode)))
                         .expect("Send message to event loop");
                 }
+                window::Action::SetLevel(level) => {
+                    let always_on_top = conversion::always_on_top(level);
+
+                    window.set_always_on_top(always_on_top);
+
+                    runtime.broadcast((
+                        Event::Window(window::Event::LevelChanged(
+                            conversion::level(always_on_top),
+                        )),
+                        event::Status::Ignored,
+                    ));
+                }
                 window::Action::ToggleMaximize => {
                     window.set_maximized(!window.is_maximized())
                 }
@@ -803,7 +1229,7 @@ pub fn run_command<A, E>(
                             let message = _tag(information);
 
                             proxy
-                                .send_event(message)
+                                .send_event(UserEvent::Message(message))
                                 .expect("Send message to event loop")
                         });
                     }
@@ -828,7 +1254,7 @@ pub fn run_command<A, E>(
                         operation::Outcome::None => {}
                         operation::Outcome::Some(message) => {
                             proxy
-                                .send_event(message)
+                                .send_event(UserEvent::Message(message))
                                 .expect("Send message to event loop");
                         }
                         operation::Outcome::Chain(next) => {
@@ -840,6 +1266,13 @@ pub fn run_command<A, E>(
                 current_cache = user_interface.into_cache();
                 *cache = current_cache;
             }
+            command::Action::Tray(action) => {
+                if let Some(message) = crate::tray::run(action) {
+                    proxy
+                        .send_event(UserEvent::Message(message))
+                        .expect("Send message to event loop");
+                }
+            }
         }
     }
 }
@@ -1,9 +1,12 @@
 use crate::application::{self, StyleSheet as _};
 use crate::conversion;
-use crate::{Application, Color, Debug, Point, Size, Viewport};
+use crate::menu;
+use crate::mouse;
+use crate::window;
+use crate::{Application, Color, Debug, Size, Viewport};
 
 use std::marker::PhantomData;
-use winit::event::{Touch, WindowEvent};
+use winit::event::{ElementState, Touch, WindowEvent};
 use winit::window::Window;
 
 /// The state of a windowed [`Application`].
@@ -14,15 +17,28 @@ where
 {
     title: String,
     scale_factor: f64,
+    reported_scale_factor: f64,
     viewport: Viewport,
     viewport_version: usize,
-    cursor_position: winit::dpi::PhysicalPosition<f64>,
+    cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
+    cursor_outside: bool,
+    mouse_buttons_pressed: usize,
     modifiers: winit::event::ModifiersState,
     theme: <A::Renderer as crate::Renderer>::Theme,
     appearance: application::Appearance,
+    windowed_geometry: Option<WindowedGeometry>,
+    monitor: Option<window::monitor::Id>,
+    monitors: Vec<window::monitor::Id>,
     application: PhantomData<A>,
 }
 
+/// The geometry of a window before entering fullscreen.
+#[derive(Debug, Clone, Copy)]
+struct WindowedGeometry {
+    position: Option<winit::dpi::PhysicalPosition<i32>>,
+    size: winit::dpi::PhysicalSize<u32>,
+}
+
 impl<A: Application> State<A>
 where
     <A::Renderer as crate::Renderer>::Theme: application::StyleSheet,
@@ -34,6 +50,10 @@ where
         let theme = application.theme();
         let appearance = theme.appearance(&application.style());
 
+        if menu::IS_NATIVE {
+            menu::synchronize(&application.menu());
+        }
+
         let viewport = {
             let physical_size = window.inner_size();
 
@@ -46,13 +66,18 @@ where
         Self {
             title,
             scale_factor,
+            reported_scale_factor: viewport.scale_factor(),
             viewport,
             viewport_version: 0,
-            // TODO: Encode cursor availability in the type-system
-            cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
+            cursor_position: None,
+            cursor_outside: false,
+            mouse_buttons_pressed: 0,
             modifiers: winit::event::ModifiersState::default(),
             theme,
             appearance,
+            windowed_geometry: None,
+            monitor: current_monitor(window),
+            monitors: monitors(window),
             application: PhantomData,
         }
     }
@@ -84,12 +109,21 @@ where
         self.viewport.scale_factor()
     }
 
-    /// Returns the current cursor position of the [`State`].
-    pub fn cursor_position(&self) -> Point {
-        conversion::cursor_position(
-            self.cursor_position,
-            self.viewport.scale_factor(),
-        )
+    /// Returns the current [`mouse::Cursor`] of the [`State`].
+    ///
+    /// The cursor becomes unavailable when it leaves the window, unless a
+    /// mouse button is being held—so a widget being dragged keeps receiving
+    /// the cursor until the drag ends.
+    pub fn cursor(&self) -> mouse::Cursor {
+        match self.cursor_position {
+            Some(position) => {
+                mouse::Cursor::Available(conversion::cursor_position(
+                    position,
+                    self.viewport.scale_factor(),
+                ))
+            }
+            None => mouse::Cursor::Unavailable,
+        }
     }
 
     /// Returns the current keyboard modifiers of the [`State`].
@@ -149,12 +183,44 @@ where
             | WindowEvent::Touch(Touch {
                 location: position, ..
             }) => {
-                self.cursor_position = *position;
+                self.cursor_position = Some(*position);
+
+                // Without buttons held, the cursor is only tracked within
+                // the window
+                if self.mouse_buttons_pressed == 0 {
+                    self.cursor_outside = false;
+                }
+            }
+            WindowEvent::CursorEntered { .. } => {
+                self.cursor_outside = false;
             }
             WindowEvent::CursorLeft { .. } => {
-                // TODO: Encode cursor availability in the type-system
-                self.cursor_position =
-                    winit::dpi::PhysicalPosition::new(-1.0, -1.0);
+                self.cursor_outside = true;
+
+                if self.mouse_buttons_pressed == 0 {
+                    self.cursor_position = None;
+                }
+            }
+            WindowEvent::MouseInput { state, .. } => match state {
+                ElementState::Pressed => {
+                    self.mouse_buttons_pressed += 1;
+                }
+                ElementState::Released => {
+                    self.mouse_buttons_pressed =
+                        self.mouse_buttons_pressed.saturating_sub(1);
+
+                    if self.mouse_buttons_pressed == 0 && self.cursor_outside {
+                        self.cursor_position = None;
+                    }
+                }
+            },
+            WindowEvent::Focused(false) => {
+                // Releases are not reported while the window is unfocused
+                self.mouse_buttons_pressed = 0;
+
+                if self.cursor_outside {
+                    self.cursor_position = None;
+                }
             }
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = *new_modifiers;
@@ -168,11 +234,138 @@ where
                         ..
                     },
                 ..
-            } => _debug.toggle(),
+            } => {
+                if self.modifiers.shift() {
+                    _debug.toggle_layout();
+                } else {
+                    _debug.toggle();
+                }
+            }
             _ => {}
         }
     }
 
+    /// Returns the current [`window::Mode`] of the window.
+    pub fn mode(&self, window: &Window) -> window::Mode {
+        if window.is_visible().unwrap_or(true) {
+            conversion::mode(window.fullscreen())
+        } else {
+            window::Mode::Hidden
+        }
+    }
+
+    /// Sets the [`window::Mode`] of the window and returns the resulting one,
+    /// which may differ on platforms that do not support the requested one.
+    ///
+    /// Fullscreen modes use the current monitor of the window. The size and
+    /// position of the window are restored when exiting them.
+    pub fn set_mode(
+        &mut self,
+        window: &Window,
+        mode: window::Mode,
+    ) -> window::Mode {
+        let was_fullscreen = window.fullscreen().is_some();
+        let monitor = window
+            .current_monitor()
+            .or_else(|| window.primary_monitor());
+        let fullscreen = conversion::fullscreen(monitor.clone(), mode);
+
+        if fullscreen.is_some() && !was_fullscreen {
+            self.windowed_geometry = Some(WindowedGeometry {
+                position: window.outer_position().ok(),
+                size: window.inner_size(),
+            });
+        }
+
+        let is_fullscreen = fullscreen.is_some();
+
+        window.set_visible(conversion::visible(mode));
+        window.set_fullscreen(fullscreen);
+
+        if !is_fullscreen && was_fullscreen {
+            if let Some(geometry) = self.windowed_geometry.take() {
+                window.set_inner_size(geometry.size);
+
+                if let Some(position) = geometry.position {
+                    window.set_outer_position(position);
+                }
+            }
+        }
+
+        // macOS applies fullscreen asynchronously, so the window cannot be
+        // queried for the resulting mode yet
+        if cfg!(target_os = "macos") {
+            return mode;
+        }
+
+        // Exclusive fullscreen is ignored on some platforms (e.g. Wayland)
+        if mode == window::Mode::ExclusiveFullscreen
+            && window.fullscreen().is_none()
+        {
+            window.set_fullscreen(conversion::fullscreen(
+                monitor,
+                window::Mode::Fullscreen,
+            ));
+        }
+
+        self.mode(window)
+    }
+
+    /// Returns the window events describing how the monitors changed since
+    /// the last call.
+    ///
+    /// Platforms do not report when monitors are connected or disconnected,
+    /// so this should be called whenever the window is moved, its scale
+    /// factor changes, or it is focused—which normally happens when its
+    /// monitor is disconnected.
+    ///
+    /// Querying the available monitors can be slow, so they are only queried
+    /// when `refresh` is true or the current monitor of the window changed.
+    pub fn monitor_events(
+        &mut self,
+        window: &Window,
+        refresh: bool,
+    ) -> Vec<window::Event> {
+        let mut events = Vec::new();
+
+        let monitor = current_monitor(window);
+        let is_monitor_changed = monitor != self.monitor;
+
+        if refresh || is_monitor_changed {
+            let monitors = monitors(window);
+
+            if monitors != self.monitors {
+                self.monitors = monitors;
+
+                events.push(window::Event::MonitorsChanged);
+            }
+        }
+
+        if is_monitor_changed {
+            self.monitor = monitor;
+
+            events.push(window::Event::MonitorChanged(monitor));
+        }
+
+        events
+    }
+
+    /// Returns a [`window::Event::ScaleFactorChanged`] if the scale factor of
+    /// the [`Viewport`] changed since the last call.
+    pub fn scale_factor_event(&mut self) -> Option<window::Event> {
+        let scale_factor = self.viewport.scale_factor();
+
+        if scale_factor == self.reported_scale_factor {
+            return None;
+        }
+
+        self.reported_scale_factor = scale_factor;
+
+        Some(window::Event::ScaleFactorChanged(window::ScaleFactor::new(
+            scale_factor,
+        )))
+    }
+
     /// Synchronizes the [`State`] with its [`Application`] and its respective
     /// window.
     ///
@@ -211,5 +404,24 @@ where
         // Update theme and appearance
         self.theme = application.theme();
         self.appearance = self.theme.appearance(&application.style());
+
+        // Update native menu
+        if menu::IS_NATIVE {
+            menu::synchronize(&application.menu());
+        }
     }
 }
+
+fn current_monitor(window: &Window) -> Option<window::monitor::Id> {
+    window
+        .current_monitor()
+        .as_ref()
+        .map(conversion::monitor_id)
+}
+
+fn monitors(window: &Window) -> Vec<window::monitor::Id> {
+    window
+        .available_monitors()
+        .map(|monitor| conversion::monitor_id(&monitor))
+        .collect()
+}
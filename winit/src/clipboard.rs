@@ -35,9 +35,14 @@ impl Clipboard {
     }
 
     /// Reads the current content of the [`Clipboard`] as text.
+    ///
+    /// Returns `None` if the [`Clipboard`] is empty or unavailable.
     pub fn read(&self) -> Option<String> {
         match &self.state {
-            State::Connected(clipboard) => clipboard.read().ok(),
+            State::Connected(clipboard) => clipboard
+                .read()
+                .ok()
+                .filter(|contents| !contents.is_empty()),
             State::Unavailable => None,
         }
     }
@@ -67,6 +72,15 @@ impl iced_native::Clipboard for Clipboard {
 }
 
 /// Read the current contents of the clipboard.
+///
+/// The clipboard is read by the runtime after the [`Command`] is returned, and
+/// the result is delivered to `update` as the message produced by `f`. The
+/// contents are `None` if the clipboard is empty or unavailable. A message
+/// variant wrapping an `Option<String>` can be used directly as `f`—e.g.
+/// `clipboard::read(Message::Pasted)`.
+///
+/// The runtime reads the same clipboard that widgets use in `on_event`, so a
+/// value pasted here always matches what a text input would paste.
 pub fn read<Message>(
     f: impl Fn(Option<String>) -> Message + 'static,
 ) -> Command<Message> {
@@ -74,6 +88,9 @@ pub fn read<Message>(
 }
 
 /// Write the given contents to the clipboard.
+///
+/// This is useful to copy text in response to actions outside of any widget,
+/// like a menu item. Like [`read`], it uses the same clipboard as widgets.
 pub fn write<Message>(contents: String) -> Command<Message> {
     Command::single(command::Action::Clipboard(Action::Write(contents)))
 }
@@ -2,6 +2,7 @@
 //!
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_native`]: https://github.com/iced-rs/iced/tree/0.7/native
+use crate::input_method::{self, InputMethod};
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -109,6 +110,9 @@ pub fn window_event(
                 }
             }
         })),
+        WindowEvent::Ime(ime) => {
+            Some(Event::InputMethod(self::input_method_event(ime)))
+        }
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(self::modifiers(*new_modifiers)),
         )),
@@ -139,6 +143,38 @@ pub fn window_event(
     }
 }
 
+/// Converts a winit input method event into an iced input method event.
+pub fn input_method_event(ime: &winit::event::Ime) -> input_method::Event {
+    use winit::event::Ime;
+
+    match ime {
+        Ime::Enabled => input_method::Event::Opened,
+        Ime::Preedit(content, selection) => input_method::Event::Preedit(
+            content.clone(),
+            selection.map(|(start, end)| start..end),
+        ),
+        Ime::Commit(content) => input_method::Event::Commit(content.clone()),
+        Ime::Disabled => input_method::Event::Closed,
+    }
+}
+
+/// Converts an [`InputMethod`] request to the physical position of the
+/// candidate window, if the input method is enabled.
+pub fn input_method_position(
+    input_method: InputMethod,
+    scale_factor: f64,
+) -> Option<winit::dpi::PhysicalPosition<f64>> {
+    match input_method {
+        InputMethod::Disabled => None,
+        InputMethod::Enabled { position } => {
+            Some(winit::dpi::PhysicalPosition::new(
+                f64::from(position.x) * scale_factor,
+                f64::from(position.y) * scale_factor,
+            ))
+        }
+    }
+}
+
 /// Converts a [`Position`] to a [`winit`] logical position for a given monitor.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -182,6 +218,68 @@ pub fn position(
     }
 }
 
+/// Converts a [`Position`] relative to the given monitor to a [`winit`]
+/// position.
+///
+/// [`Position::Default`] is the top-left corner of the monitor, and
+/// specific coordinates are logical according to the scale factor of the
+/// monitor.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn position_on_monitor(
+    monitor: &winit::monitor::MonitorHandle,
+    size: (u32, u32),
+    position: Position,
+) -> Option<winit::dpi::Position> {
+    let monitor_info = self::monitor(monitor, None);
+
+    match position {
+        Position::Default => Some(physical_position(monitor_info.position)),
+        Position::Specific(x, y) => Some(physical_position(
+            monitor_info.to_physical(Point::new(x as f32, y as f32)),
+        )),
+        Position::Centered => self::position(Some(monitor), size, position),
+    }
+}
+
+fn physical_position((x, y): (i32, i32)) -> winit::dpi::Position {
+    winit::dpi::Position::Physical(winit::dpi::PhysicalPosition { x, y })
+}
+
+/// Converts a [`winit`] monitor handle to a [`window::Monitor`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor(
+    monitor: &winit::monitor::MonitorHandle,
+    primary: Option<&winit::monitor::MonitorHandle>,
+) -> window::Monitor {
+    let position = monitor.position();
+    let size = monitor.size();
+
+    window::Monitor {
+        id: monitor_id(monitor),
+        name: monitor.name(),
+        position: (position.x, position.y),
+        size: (size.width, size.height),
+        scale_factor: monitor.scale_factor(),
+        is_primary: primary == Some(monitor),
+    }
+}
+
+/// Returns the [`window::monitor::Id`] of a [`winit`] monitor handle.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor_id(
+    monitor: &winit::monitor::MonitorHandle,
+) -> window::monitor::Id {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    monitor.hash(&mut hasher);
+
+    window::monitor::Id::new(hasher.finish())
+}
+
 /// Converts a [`window::Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -194,24 +292,77 @@ pub fn fullscreen(
         window::Mode::Fullscreen => {
             Some(winit::window::Fullscreen::Borderless(monitor))
         }
+        window::Mode::ExclusiveFullscreen => {
+            // Keep the current resolution of the monitor
+            let video_mode = monitor.as_ref().and_then(|monitor| {
+                let size = monitor.size();
+
+                monitor
+                    .video_modes()
+                    .filter(|video_mode| video_mode.size() == size)
+                    .max_by_key(|video_mode| {
+                        (
+                            video_mode.refresh_rate_millihertz(),
+                            video_mode.bit_depth(),
+                        )
+                    })
+            });
+
+            Some(match video_mode {
+                Some(video_mode) => {
+                    winit::window::Fullscreen::Exclusive(video_mode)
+                }
+                None => winit::window::Fullscreen::Borderless(monitor),
+            })
+        }
     }
 }
 
 /// Converts a [`window::Mode`] to a visibility flag.
 pub fn visible(mode: window::Mode) -> bool {
     match mode {
-        window::Mode::Windowed | window::Mode::Fullscreen => true,
+        window::Mode::Windowed
+        | window::Mode::Fullscreen
+        | window::Mode::ExclusiveFullscreen => true,
         window::Mode::Hidden => false,
     }
 }
 
+/// Converts a [`window::Level`] to whether a [`winit`] window is always on top.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn always_on_top(level: window::Level) -> bool {
+    match level {
+        window::Level::AlwaysOnTop => true,
+        // `winit` does not support always-on-bottom windows yet
+        window::Level::Normal | window::Level::AlwaysOnBottom => false,
+    }
+}
+
+/// Converts whether a [`winit`] window is always on top to a
+/// [`window::Level`].
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn level(always_on_top: bool) -> window::Level {
+    if always_on_top {
+        window::Level::AlwaysOnTop
+    } else {
+        window::Level::Normal
+    }
+}
+
 /// Converts a [`winit`] fullscreen mode to a [`window::Mode`].
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
 pub fn mode(mode: Option<winit::window::Fullscreen>) -> window::Mode {
     match mode {
         None => window::Mode::Windowed,
-        Some(_) => window::Mode::Fullscreen,
+        Some(winit::window::Fullscreen::Exclusive(_)) => {
+            window::Mode::ExclusiveFullscreen
+        }
+        Some(winit::window::Fullscreen::Borderless(_)) => {
+            window::Mode::Fullscreen
+        }
     }
 }
 
@@ -241,6 +392,10 @@ pub fn mouse_interaction(
 
 /// Converts a `MouseButton` from [`winit`] to an [`iced_native`] mouse button.
 ///
+/// The back and forward buttons are reported with different codes on every
+/// platform, so they are mapped to [`mouse::Button::Back`] and
+/// [`mouse::Button::Forward`]. Any other button keeps its platform code.
+///
 /// [`winit`]: https://github.com/rust-windowing/winit
 /// [`iced_native`]: https://github.com/iced-rs/iced/tree/0.7/native
 pub fn mouse_button(mouse_button: winit::event::MouseButton) -> mouse::Button {
@@ -248,9 +403,36 @@ pub fn mouse_button(mouse_button: winit::event::MouseButton) -> mouse::Button {
         winit::event::MouseButton::Left => mouse::Button::Left,
         winit::event::MouseButton::Right => mouse::Button::Right,
         winit::event::MouseButton::Middle => mouse::Button::Middle,
-        winit::event::MouseButton::Other(other) => {
-            mouse::Button::Other(other as u8)
-        }
+        winit::event::MouseButton::Other(other) => extra_mouse_button(other),
+    }
+}
+
+/// Converts the platform code of an extra mouse button to a mouse button.
+fn extra_mouse_button(code: u16) -> mouse::Button {
+    // `XBUTTON1` and `XBUTTON2`
+    #[cfg(target_os = "windows")]
+    const CODES: ([u16; 1], [u16; 1]) = ([1], [2]);
+
+    // `buttonNumber` on macOS and `MouseEvent.button` on the web
+    #[cfg(any(target_os = "macos", target_arch = "wasm32"))]
+    const CODES: ([u16; 1], [u16; 1]) = ([3], [4]);
+
+    // X11 buttons and the evdev codes of Wayland, which do not overlap
+    #[cfg(not(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_arch = "wasm32"
+    )))]
+    const CODES: ([u16; 3], [u16; 3]) = ([8, 0x113, 0x116], [9, 0x114, 0x115]);
+
+    let (back, forward) = CODES;
+
+    if back.contains(&code) {
+        mouse::Button::Back
+    } else if forward.contains(&code) {
+        mouse::Button::Forward
+    } else {
+        mouse::Button::Other(code)
     }
 }
 
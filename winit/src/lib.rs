@@ -39,7 +39,9 @@ pub use winit;
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+pub mod menu;
 pub mod settings;
+pub mod tray;
 pub mod window;
 
 #[cfg(feature = "system")]
@@ -56,7 +58,7 @@ pub use application::Profiler;
 pub use clipboard::Clipboard;
 pub use error::Error;
 pub use position::Position;
-pub use proxy::Proxy;
+pub use proxy::{Proxy, UserEvent};
 pub use settings::Settings;
 
 pub use iced_graphics::Viewport;
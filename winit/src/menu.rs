@@ -0,0 +1,523 @@
+//! Show the menu of an application.
+//!
+//! The [`Menu`] of an [`Application`] is shown in the native menu bar on
+//! macOS when the `menu` feature is enabled. Everywhere else, the runtime
+//! only handles the accelerators of the [`Menu`], and it can be shown
+//! inside the window with a [`MenuBar`].
+//!
+//! [`Application`]: crate::Application
+//! [`MenuBar`]: crate::widget::MenuBar
+pub use iced_native::menu::{Accelerator, Entry, Item, Menu};
+
+/// Whether the [`Menu`] is shown natively on the current platform.
+///
+/// When `true`, the platform also handles the accelerators of the [`Menu`];
+/// see [`is_native_accelerator`].
+pub const IS_NATIVE: bool = platform::IS_NATIVE;
+
+/// Shows the given [`Menu`] natively, replacing the current one.
+///
+/// The native menu is only rebuilt if its items changed since the last call.
+/// Entries that were only enabled, disabled, checked, or unchecked are updated
+/// in place. It does nothing when the [`Menu`] is not [`IS_NATIVE`].
+pub fn synchronize<Message>(menu: &Menu<Message>) {
+    platform::synchronize(menu);
+}
+
+/// Returns true if the given [`Accelerator`] is handled by the native
+/// [`Menu`] on the current platform.
+///
+/// Accelerators with a key unknown to the platform are handled by the
+/// runtime instead, like on platforms without a native [`Menu`].
+pub fn is_native_accelerator(accelerator: Accelerator) -> bool {
+    platform::is_native_accelerator(accelerator)
+}
+
+/// Returns the paths of the native [`Entry`] items activated since the last
+/// call.
+///
+/// A path contains the index of the [`Item`] at every level of the [`Menu`]
+/// and can be turned into a message with [`Menu::activate`].
+pub fn activations() -> Vec<Vec<usize>> {
+    platform::activations()
+}
+
+/// The events of the native menus, shared by the [`Menu`] of the application
+/// and the menu of a system tray.
+///
+/// A single handler receives every event and routes it by the id of the
+/// activated item.
+#[cfg(any(
+    all(feature = "menu", target_os = "macos"),
+    all(feature = "tray", any(target_os = "windows", target_os = "macos"))
+))]
+pub(crate) mod events {
+    #[cfg(all(feature = "menu", target_os = "macos"))]
+    use muda::MenuEvent;
+    #[cfg(not(all(feature = "menu", target_os = "macos")))]
+    use tray_icon::menu::MenuEvent;
+
+    use std::sync::{Mutex, Once};
+
+    /// The prefix of the ids of the items of the [`Menu`] of the
+    /// application, telling them apart from the items of a tray menu.
+    ///
+    /// [`Menu`]: super::Menu
+    pub const ID_PREFIX: &str = "iced-menu:";
+
+    type Forward = Box<dyn Fn(String) + Send + Sync>;
+
+    static ACTIVATIONS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    static TRAY: Mutex<Option<Forward>> = Mutex::new(None);
+
+    /// Returns the ids of the items of the [`Menu`] of the application
+    /// activated since the last call.
+    ///
+    /// [`Menu`]: super::Menu
+    #[cfg_attr(
+        not(all(feature = "menu", target_os = "macos")),
+        allow(dead_code)
+    )]
+    pub fn activations() -> Vec<String> {
+        install();
+
+        std::mem::take(&mut *lock(&ACTIVATIONS))
+    }
+
+    /// Forwards the ids of the activated items of a tray menu to the given
+    /// function, replacing the previous one.
+    #[cfg_attr(
+        not(all(
+            feature = "tray",
+            any(target_os = "windows", target_os = "macos")
+        )),
+        allow(dead_code)
+    )]
+    pub fn forward_tray(f: impl Fn(String) + Send + Sync + 'static) {
+        install();
+
+        *lock(&TRAY) = Some(Box::new(f));
+    }
+
+    fn install() {
+        static INSTALL: Once = Once::new();
+
+        INSTALL.call_once(|| {
+            MenuEvent::set_event_handler(Some(|event: MenuEvent| {
+                let id = event.id.0;
+
+                if id.starts_with(ID_PREFIX) {
+                    lock(&ACTIVATIONS).push(id);
+                } else if let Some(forward) = lock(&TRAY).as_ref() {
+                    forward(id);
+                }
+            }));
+        });
+    }
+
+    fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+        mutex
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(all(feature = "menu", target_os = "macos"))]
+mod platform {
+    use super::events::{self, ID_PREFIX};
+    use super::{Accelerator, Item, Menu};
+
+    use iced_native::keyboard::{KeyCode, Modifiers};
+    use muda::accelerator::{self, Code};
+
+    use std::cell::RefCell;
+
+    pub const IS_NATIVE: bool = true;
+
+    /// The description of a menu, ignoring its messages.
+    #[derive(Debug, PartialEq)]
+    enum Node {
+        Entry {
+            label: String,
+            accelerator: Option<Accelerator>,
+            enabled: bool,
+            checked: Option<bool>,
+        },
+        Submenu {
+            label: String,
+            nodes: Vec<Node>,
+        },
+        Separator,
+    }
+
+    impl Node {
+        /// Returns true if the native items of both nodes can be shared,
+        /// i.e. they only differ in whether their entries are enabled or
+        /// checked.
+        fn has_same_items(&self, other: &Node) -> bool {
+            match (self, other) {
+                (
+                    Node::Entry {
+                        label,
+                        accelerator,
+                        checked,
+                        ..
+                    },
+                    Node::Entry {
+                        label: other_label,
+                        accelerator: other_accelerator,
+                        checked: other_checked,
+                        ..
+                    },
+                ) => {
+                    label == other_label
+                        && accelerator == other_accelerator
+                        && checked.is_some() == other_checked.is_some()
+                }
+                (
+                    Node::Submenu { label, nodes },
+                    Node::Submenu {
+                        label: other_label,
+                        nodes: other_nodes,
+                    },
+                ) => {
+                    label == other_label
+                        && nodes.len() == other_nodes.len()
+                        && nodes
+                            .iter()
+                            .zip(other_nodes)
+                            .all(|(node, other)| node.has_same_items(other))
+                }
+                (Node::Separator, Node::Separator) => true,
+                _ => false,
+            }
+        }
+    }
+
+    /// A native entry, in the same order as the entries of the [`Node`]s.
+    enum Native {
+        Item(muda::MenuItem),
+        Check(muda::CheckMenuItem),
+    }
+
+    struct Current {
+        nodes: Vec<Node>,
+        entries: Vec<Native>,
+        _menu: muda::Menu,
+    }
+
+    thread_local! {
+        // The native menu must be kept alive and used on the main thread
+        static MENU: RefCell<Option<Current>> = RefCell::new(None);
+    }
+
+    pub fn synchronize<Message>(menu: &Menu<Message>) {
+        let nodes = nodes(menu);
+
+        MENU.with(|current| {
+            let mut current = current.borrow_mut();
+
+            match current.as_mut() {
+                Some(current) if current.nodes == nodes => {}
+                Some(current)
+                    if current.nodes.len() == nodes.len()
+                        && current.nodes.iter().zip(&nodes).all(
+                            |(current, node)| node.has_same_items(current),
+                        ) =>
+                {
+                    let mut entries = current.entries.iter();
+
+                    for node in &nodes {
+                        update(node, &mut entries);
+                    }
+
+                    current.nodes = nodes;
+                }
+                _ => {
+                    let native = muda::Menu::new();
+                    let mut entries = Vec::new();
+
+                    for (index, node) in nodes.iter().enumerate() {
+                        if let Err(error) = append(
+                            Parent::Menu(&native),
+                            node,
+                            &[index],
+                            &mut entries,
+                        ) {
+                            log::warn!(
+                                "Failed to build the native menu: {error}"
+                            );
+                        }
+                    }
+
+                    native.init_for_nsapp();
+
+                    *current = Some(Current {
+                        nodes,
+                        entries,
+                        _menu: native,
+                    });
+                }
+            }
+        });
+    }
+
+    pub fn is_native_accelerator(accelerator: Accelerator) -> bool {
+        code(accelerator.key_code).is_some()
+    }
+
+    pub fn activations() -> Vec<Vec<usize>> {
+        events::activations()
+            .into_iter()
+            .filter_map(|id| {
+                id.strip_prefix(ID_PREFIX)?
+                    .split('.')
+                    .map(|index| index.parse().ok())
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn nodes<Message>(menu: &Menu<Message>) -> Vec<Node> {
+        menu.items()
+            .iter()
+            .map(|item| match item {
+                Item::Entry(entry) => Node::Entry {
+                    label: entry.label.clone(),
+                    accelerator: entry.accelerator,
+                    enabled: entry.enabled,
+                    checked: entry.checked,
+                },
+                Item::Submenu { label, menu } => Node::Submenu {
+                    label: label.clone(),
+                    nodes: nodes(menu),
+                },
+                Item::Separator => Node::Separator,
+            })
+            .collect()
+    }
+
+    fn update<'a>(node: &Node, entries: &mut impl Iterator<Item = &'a Native>) {
+        match node {
+            Node::Entry {
+                enabled, checked, ..
+            } => match entries.next() {
+                Some(Native::Item(item)) => item.set_enabled(*enabled),
+                Some(Native::Check(item)) => {
+                    item.set_enabled(*enabled);
+                    item.set_checked(checked.unwrap_or(false));
+                }
+                None => {}
+            },
+            Node::Submenu { nodes, .. } => {
+                for node in nodes {
+                    update(node, entries);
+                }
+            }
+            Node::Separator => {}
+        }
+    }
+
+    fn append(
+        parent: Parent<'_>,
+        node: &Node,
+        path: &[usize],
+        entries: &mut Vec<Native>,
+    ) -> Result<(), muda::Error> {
+        let id = format!(
+            "{ID_PREFIX}{}",
+            path.iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(".")
+        );
+
+        match node {
+            Node::Entry {
+                label,
+                accelerator,
+                enabled,
+                checked,
+            } => {
+                let accelerator = accelerator.and_then(native_accelerator);
+
+                match checked {
+                    Some(checked) => {
+                        let item = muda::CheckMenuItem::with_id(
+                            id,
+                            label,
+                            *enabled,
+                            *checked,
+                            accelerator,
+                        );
+
+                        parent.append(&item)?;
+                        entries.push(Native::Check(item));
+                    }
+                    None => {
+                        let item = muda::MenuItem::with_id(
+                            id,
+                            label,
+                            *enabled,
+                            accelerator,
+                        );
+
+                        parent.append(&item)?;
+                        entries.push(Native::Item(item));
+                    }
+                }
+
+                Ok(())
+            }
+            Node::Submenu { label, nodes } => {
+                let submenu = muda::Submenu::new(label, true);
+
+                for (index, node) in nodes.iter().enumerate() {
+                    let path = [path, &[index]].concat();
+
+                    append(Parent::Submenu(&submenu), node, &path, entries)?;
+                }
+
+                parent.append(&submenu)
+            }
+            Node::Separator => {
+                parent.append(&muda::PredefinedMenuItem::separator())
+            }
+        }
+    }
+
+    fn native_accelerator(
+        accelerator: Accelerator,
+    ) -> Option<accelerator::Accelerator> {
+        let code = code(accelerator.key_code)?;
+
+        let modifiers = [
+            (Modifiers::CTRL, accelerator::Modifiers::CONTROL),
+            (Modifiers::ALT, accelerator::Modifiers::ALT),
+            (Modifiers::SHIFT, accelerator::Modifiers::SHIFT),
+            (Modifiers::LOGO, accelerator::Modifiers::SUPER),
+        ]
+        .into_iter()
+        .filter(|(modifier, _)| accelerator.modifiers.contains(*modifier))
+        .fold(accelerator::Modifiers::empty(), |modifiers, (_, native)| {
+            modifiers | native
+        });
+
+        Some(accelerator::Accelerator::new(Some(modifiers), code))
+    }
+
+    fn code(key_code: KeyCode) -> Option<Code> {
+        Some(match key_code {
+            KeyCode::Key1 => Code::Digit1,
+            KeyCode::Key2 => Code::Digit2,
+            KeyCode::Key3 => Code::Digit3,
+            KeyCode::Key4 => Code::Digit4,
+            KeyCode::Key5 => Code::Digit5,
+            KeyCode::Key6 => Code::Digit6,
+            KeyCode::Key7 => Code::Digit7,
+            KeyCode::Key8 => Code::Digit8,
+            KeyCode::Key9 => Code::Digit9,
+            KeyCode::Key0 => Code::Digit0,
+            KeyCode::A => Code::KeyA,
+            KeyCode::B => Code::KeyB,
+            KeyCode::C => Code::KeyC,
+            KeyCode::D => Code::KeyD,
+            KeyCode::E => Code::KeyE,
+            KeyCode::F => Code::KeyF,
+            KeyCode::G => Code::KeyG,
+            KeyCode::H => Code::KeyH,
+            KeyCode::I => Code::KeyI,
+            KeyCode::J => Code::KeyJ,
+            KeyCode::K => Code::KeyK,
+            KeyCode::L => Code::KeyL,
+            KeyCode::M => Code::KeyM,
+            KeyCode::N => Code::KeyN,
+            KeyCode::O => Code::KeyO,
+            KeyCode::P => Code::KeyP,
+            KeyCode::Q => Code::KeyQ,
+            KeyCode::R => Code::KeyR,
+            KeyCode::S => Code::KeyS,
+            KeyCode::T => Code::KeyT,
+            KeyCode::U => Code::KeyU,
+            KeyCode::V => Code::KeyV,
+            KeyCode::W => Code::KeyW,
+            KeyCode::X => Code::KeyX,
+            KeyCode::Y => Code::KeyY,
+            KeyCode::Z => Code::KeyZ,
+            KeyCode::Escape => Code::Escape,
+            KeyCode::F1 => Code::F1,
+            KeyCode::F2 => Code::F2,
+            KeyCode::F3 => Code::F3,
+            KeyCode::F4 => Code::F4,
+            KeyCode::F5 => Code::F5,
+            KeyCode::F6 => Code::F6,
+            KeyCode::F7 => Code::F7,
+            KeyCode::F8 => Code::F8,
+            KeyCode::F9 => Code::F9,
+            KeyCode::F10 => Code::F10,
+            KeyCode::F11 => Code::F11,
+            KeyCode::F12 => Code::F12,
+            KeyCode::Insert => Code::Insert,
+            KeyCode::Home => Code::Home,
+            KeyCode::Delete => Code::Delete,
+            KeyCode::End => Code::End,
+            KeyCode::PageDown => Code::PageDown,
+            KeyCode::PageUp => Code::PageUp,
+            KeyCode::Left => Code::ArrowLeft,
+            KeyCode::Up => Code::ArrowUp,
+            KeyCode::Right => Code::ArrowRight,
+            KeyCode::Down => Code::ArrowDown,
+            KeyCode::Backspace => Code::Backspace,
+            KeyCode::Enter => Code::Enter,
+            KeyCode::Space => Code::Space,
+            KeyCode::Tab => Code::Tab,
+            KeyCode::Apostrophe => Code::Quote,
+            KeyCode::Backslash => Code::Backslash,
+            KeyCode::Comma => Code::Comma,
+            KeyCode::Equals => Code::Equal,
+            KeyCode::Grave => Code::Backquote,
+            KeyCode::LBracket => Code::BracketLeft,
+            KeyCode::Minus => Code::Minus,
+            KeyCode::Period => Code::Period,
+            KeyCode::RBracket => Code::BracketRight,
+            KeyCode::Semicolon => Code::Semicolon,
+            KeyCode::Slash => Code::Slash,
+            _ => return None,
+        })
+    }
+
+    #[derive(Clone, Copy)]
+    enum Parent<'a> {
+        Menu(&'a muda::Menu),
+        Submenu(&'a muda::Submenu),
+    }
+
+    impl<'a> Parent<'a> {
+        fn append(
+            &self,
+            item: &dyn muda::IsMenuItem,
+        ) -> Result<(), muda::Error> {
+            match self {
+                Parent::Menu(menu) => menu.append(item),
+                Parent::Submenu(submenu) => submenu.append(item),
+            }
+        }
+    }
+}
+
+#[cfg(not(all(feature = "menu", target_os = "macos")))]
+mod platform {
+    use super::{Accelerator, Menu};
+
+    pub const IS_NATIVE: bool = false;
+
+    pub fn synchronize<Message>(_menu: &Menu<Message>) {}
+
+    pub fn is_native_accelerator(_accelerator: Accelerator) -> bool {
+        false
+    }
+
+    pub fn activations() -> Vec<Vec<usize>> {
+        Vec::new()
+    }
+}
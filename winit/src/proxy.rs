@@ -5,10 +5,21 @@ use iced_native::futures::{
 };
 use std::pin::Pin;
 
+/// An event sent to the event loop of an application.
+#[derive(Debug)]
+pub enum UserEvent<Message> {
+    /// A message for the application.
+    Message(Message),
+
+    /// Wakes up the event loop, so the user interface is updated—for
+    /// instance, once an image finishes decoding in the background.
+    Wake,
+}
+
 /// An event loop proxy that implements `Sink`.
 #[derive(Debug)]
 pub struct Proxy<Message: 'static> {
-    raw: winit::event_loop::EventLoopProxy<Message>,
+    raw: winit::event_loop::EventLoopProxy<UserEvent<Message>>,
 }
 
 impl<Message: 'static> Clone for Proxy<Message> {
@@ -21,7 +32,9 @@ impl<Message: 'static> Clone for Proxy<Message> {
 
 impl<Message: 'static> Proxy<Message> {
     /// Creates a new [`Proxy`] from an `EventLoopProxy`.
-    pub fn new(raw: winit::event_loop::EventLoopProxy<Message>) -> Self {
+    pub fn new(
+        raw: winit::event_loop::EventLoopProxy<UserEvent<Message>>,
+    ) -> Self {
         Self { raw }
     }
 }
@@ -40,7 +53,7 @@ impl<Message: 'static> Sink<Message> for Proxy<Message> {
         self: Pin<&mut Self>,
         message: Message,
     ) -> Result<(), Self::Error> {
-        let _ = self.raw.send_event(message);
+        let _ = self.raw.send_event(UserEvent::Message(message));
 
         Ok(())
     }
@@ -22,7 +22,10 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
+use crate::profile;
+use crate::window;
 use crate::Position;
+use winit::event_loop::EventLoopWindowTarget;
 use winit::monitor::MonitorHandle;
 use winit::window::WindowBuilder;
 
@@ -33,6 +36,17 @@ pub struct Settings<Flags> {
     ///
     /// If provided, this identifier may be used to identify the application or
     /// communicate with it through the windowing system.
+    ///
+    /// It is expected to be a reverse domain name, like `com.example.Editor`,
+    /// made of ASCII letters, digits, `.`, `_`, and `-`. On Linux and the BSDs,
+    /// it is used as the `app_id` on Wayland and as the `WM_CLASS` on X11,
+    /// which desktops use to group windows and find their icon—so it should
+    /// match the name of the `.desktop` file of the application. Other
+    /// platforms ignore it.
+    ///
+    /// The identifier is only read when the window is created, since it cannot
+    /// be changed afterwards. An empty or invalid identifier is replaced with
+    /// the name of the executable.
     pub id: Option<String>,
 
     /// The [`Window`] settings.
@@ -46,7 +60,13 @@ pub struct Settings<Flags> {
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     ///
+    /// When disabled, the requests are delivered to the [`Application`]
+    /// instead—see [`window::close_requests`]—and the window stays open until
+    /// it is closed with [`window::close`].
+    ///
     /// [`Application`]: crate::Application
+    /// [`window::close_requests`]: crate::window::close_requests
+    /// [`window::close`]: crate::window::close
     pub exit_on_close_request: bool,
 
     /// Whether the [`Application`] should try to build the context
@@ -56,17 +76,47 @@ pub struct Settings<Flags> {
     ///
     /// [`Application`]: crate::Application
     pub try_opengles_first: bool,
+
+    /// The [`profile::Hook`] that will be called with the timings of every
+    /// presented frame.
+    ///
+    /// When `None`, no timings are recorded.
+    pub profiler: Option<profile::Hook>,
+
+    /// The settings of the frame statistics [`profile::Overlay`].
+    pub overlay: profile::overlay::Settings,
 }
 
 /// The window settings of an application.
 #[derive(Debug, Clone)]
 pub struct Window {
     /// The size of the window.
+    ///
+    /// When [`fit_content`](Self::fit_content) is enabled, this is only used
+    /// for the axes where the contents have no intrinsic size.
     pub size: (u32, u32),
 
+    /// Whether the window should be sized to fit its contents at startup.
+    ///
+    /// The root element of the application is measured before the window
+    /// is shown, and the window is resized to its size—within the
+    /// minimum and maximum size of the window. Axes where the root element
+    /// fills the available space fall back to [`size`](Self::size).
+    pub fit_content: bool,
+
     /// The position of the window.
+    ///
+    /// When a [`monitor`](Self::monitor) is given, the position is relative
+    /// to it, and [`Position::Default`] places the window at its top-left
+    /// corner.
     pub position: Position,
 
+    /// The name of the monitor the window should be opened on.
+    ///
+    /// The window is opened on the primary monitor if no connected monitor
+    /// has the given name.
+    pub monitor: Option<String>,
+
     /// The minimum size of the window.
     pub min_size: Option<(u32, u32)>,
 
@@ -85,8 +135,10 @@ pub struct Window {
     /// Whether the window should be transparent.
     pub transparent: bool,
 
-    /// Whether the window will always be on top of other windows.
-    pub always_on_top: bool,
+    /// The initial [`Level`] of the window.
+    ///
+    /// [`Level`]: crate::window::Level
+    pub level: window::Level,
 
     /// The window icon, which is also usually used in the taskbar
     pub icon: Option<winit::window::Icon>,
@@ -96,11 +148,41 @@ pub struct Window {
 }
 
 impl Window {
+    /// Returns the monitor the window should be opened on.
+    ///
+    /// It is the connected monitor named by [`monitor`](Self::monitor), if
+    /// any, or the primary one.
+    pub fn target_monitor<T>(
+        &self,
+        event_loop: &EventLoopWindowTarget<T>,
+    ) -> Option<MonitorHandle> {
+        self.monitor
+            .as_deref()
+            .and_then(|name| {
+                let monitor = event_loop
+                    .available_monitors()
+                    .find(|monitor| monitor.name().as_deref() == Some(name));
+
+                if monitor.is_none() {
+                    log::warn!(
+                        "Monitor {name:?} is not connected, \
+                        falling back to the primary monitor"
+                    );
+                }
+
+                monitor
+            })
+            .or_else(|| event_loop.primary_monitor())
+    }
+
     /// Converts the window settings into a `WindowBuilder` from `winit`.
+    ///
+    /// The window is positioned on the given monitor, which is normally the
+    /// [`target_monitor`](Self::target_monitor).
     pub fn into_builder(
         self,
         title: &str,
-        primary_monitor: Option<MonitorHandle>,
+        monitor: Option<MonitorHandle>,
         _id: Option<String>,
     ) -> WindowBuilder {
         let mut window_builder = WindowBuilder::new();
@@ -114,13 +196,21 @@ impl Window {
             .with_decorations(self.decorations)
             .with_transparent(self.transparent)
             .with_window_icon(self.icon)
-            .with_always_on_top(self.always_on_top);
+            .with_always_on_top(conversion::always_on_top(self.level));
+
+        let position = if self.monitor.is_some() {
+            monitor.as_ref().and_then(|monitor| {
+                conversion::position_on_monitor(
+                    monitor,
+                    self.size,
+                    self.position,
+                )
+            })
+        } else {
+            conversion::position(monitor.as_ref(), self.size, self.position)
+        };
 
-        if let Some(position) = conversion::position(
-            primary_monitor.as_ref(),
-            self.size,
-            self.position,
-        ) {
+        if let Some(position) = position {
             window_builder = window_builder.with_position(position);
         }
 
@@ -144,7 +234,9 @@ impl Window {
         {
             use ::winit::platform::unix::WindowBuilderExtUnix;
 
-            if let Some(id) = _id {
+            let id = self.platform_specific.application_id.or(_id);
+
+            if let Some(id) = application_id(id) {
                 window_builder = window_builder.with_name(id.clone(), id);
             }
         }
@@ -179,18 +271,58 @@ impl Window {
     }
 }
 
+/// Validates the given application id, falling back to the name of the
+/// executable when it is invalid.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+fn application_id(id: Option<String>) -> Option<String> {
+    fn is_valid(id: &str) -> bool {
+        !id.is_empty()
+            && id.chars().all(|c| {
+                c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')
+            })
+    }
+
+    let id = id?;
+
+    if is_valid(&id) {
+        return Some(id);
+    }
+
+    let fallback = std::env::current_exe()
+        .ok()
+        .and_then(|path| {
+            path.file_stem()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .filter(|name| is_valid(name));
+
+    log::warn!(
+        "Application id {id:?} is invalid, falling back to {fallback:?}"
+    );
+
+    fallback
+}
+
 impl Default for Window {
     fn default() -> Window {
         Window {
             size: (1024, 768),
+            fit_content: false,
             position: Position::default(),
+            monitor: None,
             min_size: None,
             max_size: None,
             visible: true,
             resizable: true,
             decorations: true,
             transparent: false,
-            always_on_top: false,
+            level: window::Level::default(),
             icon: None,
             platform_specific: Default::default(),
         }
@@ -1,3 +1,14 @@
+//! Platform specific settings for Linux and the BSDs.
+
 /// The platform specific window settings of an application.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
-pub struct PlatformSpecific;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PlatformSpecific {
+    /// The application id of the window, which takes precedence over the
+    /// [`Settings::id`] of the application.
+    ///
+    /// It is used as the `app_id` on Wayland and as both parts of the
+    /// `WM_CLASS` on X11. It follows the same format as [`Settings::id`].
+    ///
+    /// [`Settings::id`]: crate::Settings::id
+    pub application_id: Option<String>,
+}
@@ -0,0 +1,240 @@
+//! Show an icon with a menu in the system tray.
+//!
+//! Tray icons require the `tray` feature. Only a single [`Tray`] can be
+//! registered at a time, and it is removed when the application exits.
+//!
+//! ## Platform-specific
+//!
+//! - **Windows / macOS:** Supported.
+//! - **Linux and others:** Unsupported, since the tray needs a GTK event
+//!   loop. Registering a [`Tray`] produces an [`Error::Unsupported`].
+pub use iced_native::tray::{Action, Error, Event, Icon, Item, Menu, Tray};
+
+use crate::command::{self, Command};
+use crate::futures::channel::mpsc;
+use crate::subscription::{self, Subscription};
+
+/// Registers a [`Tray`], replacing the current one, and produces a message
+/// with the result.
+///
+/// The result is an [`Error::Unsupported`] if the current platform does not
+/// support tray icons.
+pub fn register<Message>(
+    tray: Tray,
+    f: impl FnOnce(Result<(), Error>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::Register(tray, Box::new(f))))
+}
+
+/// Changes the [`Icon`] of the registered [`Tray`] and produces a message
+/// with the result.
+///
+/// This can be used to reflect the state of the application (e.g. an unread
+/// count).
+pub fn set_icon<Message>(
+    icon: Icon,
+    f: impl FnOnce(Result<(), Error>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::SetIcon(icon, Box::new(f))))
+}
+
+/// Changes the tooltip of the registered [`Tray`] and produces a message with
+/// the result.
+pub fn set_tooltip<Message>(
+    tooltip: Option<String>,
+    f: impl FnOnce(Result<(), Error>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::SetTooltip(
+        tooltip,
+        Box::new(f),
+    )))
+}
+
+/// Changes the [`Menu`] of the registered [`Tray`] and produces a message with
+/// the result.
+pub fn set_menu<Message>(
+    menu: Menu,
+    f: impl FnOnce(Result<(), Error>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Tray(Action::SetMenu(menu, Box::new(f))))
+}
+
+/// Removes the registered [`Tray`], if any.
+pub fn remove<Message>() -> Command<Message> {
+    Command::single(command::Action::Tray(Action::Remove))
+}
+
+/// Listens to the interactions with the registered [`Tray`].
+///
+/// The events are delivered by a single handler owned by the runtime, so only
+/// a single instance of this [`Subscription`] can be active at a time.
+pub fn events() -> Subscription<Event> {
+    #[derive(Hash)]
+    struct Events;
+
+    subscription::run(Events, || {
+        let (sender, receiver) = mpsc::unbounded();
+
+        platform::listen(sender);
+
+        receiver
+    })
+}
+
+/// Runs a tray [`Action`] and returns the resulting message, if any.
+pub(crate) fn run<T>(action: Action<T>) -> Option<T> {
+    match action {
+        Action::Register(tray, f) => Some(f(platform::register(tray))),
+        Action::SetIcon(icon, f) => Some(f(platform::set_icon(icon))),
+        Action::SetTooltip(tooltip, f) => {
+            Some(f(platform::set_tooltip(tooltip)))
+        }
+        Action::SetMenu(menu, f) => Some(f(platform::set_menu(menu))),
+        Action::Remove => {
+            platform::remove();
+
+            None
+        }
+    }
+}
+
+#[cfg(all(feature = "tray", any(target_os = "windows", target_os = "macos")))]
+mod platform {
+    use super::{Error, Event, Icon, Item, Menu, Tray};
+    use crate::futures::channel::mpsc;
+
+    use std::cell::RefCell;
+
+    thread_local! {
+        // Tray icons must be kept alive and used on the main thread
+        static TRAY: RefCell<Option<tray_icon::TrayIcon>> = RefCell::new(None);
+    }
+
+    pub fn register(tray: Tray) -> Result<(), Error> {
+        let mut builder = tray_icon::TrayIconBuilder::new()
+            .with_icon(icon(tray.icon)?)
+            .with_menu(Box::new(menu(tray.menu)?));
+
+        if let Some(tooltip) = tray.tooltip {
+            builder = builder.with_tooltip(tooltip);
+        }
+
+        let tray = builder.build().map_err(platform_error)?;
+
+        TRAY.with(|current| *current.borrow_mut() = Some(tray));
+
+        Ok(())
+    }
+
+    pub fn set_icon(new_icon: Icon) -> Result<(), Error> {
+        let new_icon = icon(new_icon)?;
+
+        with_tray(|tray| tray.set_icon(Some(new_icon)).map_err(platform_error))
+    }
+
+    pub fn set_tooltip(tooltip: Option<String>) -> Result<(), Error> {
+        with_tray(|tray| tray.set_tooltip(tooltip).map_err(platform_error))
+    }
+
+    pub fn set_menu(new_menu: Menu) -> Result<(), Error> {
+        let new_menu = menu(new_menu)?;
+
+        with_tray(|tray| {
+            tray.set_menu(Some(Box::new(new_menu)));
+
+            Ok(())
+        })
+    }
+
+    pub fn remove() {
+        TRAY.with(|current| *current.borrow_mut() = None);
+    }
+
+    pub fn listen(sender: mpsc::UnboundedSender<Event>) {
+        let menu_sender = sender.clone();
+
+        // Replacing the handlers drops the senders of the previous listener
+        crate::menu::events::forward_tray(move |id| {
+            let _ = menu_sender.unbounded_send(Event::MenuItemSelected(id));
+        });
+
+        tray_icon::TrayIconEvent::set_event_handler(Some(
+            move |event: tray_icon::TrayIconEvent| {
+                let event = match event.click_type {
+                    tray_icon::ClickType::Left => Event::Clicked,
+                    tray_icon::ClickType::Right => Event::RightClicked,
+                    tray_icon::ClickType::Double => Event::DoubleClicked,
+                };
+
+                let _ = sender.unbounded_send(event);
+            },
+        ));
+    }
+
+    fn with_tray(
+        f: impl FnOnce(&mut tray_icon::TrayIcon) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        TRAY.with(|current| match current.borrow_mut().as_mut() {
+            Some(tray) => f(tray),
+            None => Err(Error::NotRegistered),
+        })
+    }
+
+    fn icon(icon: Icon) -> Result<tray_icon::Icon, Error> {
+        tray_icon::Icon::from_rgba(icon.rgba, icon.width, icon.height)
+            .map_err(|_| Error::InvalidIcon)
+    }
+
+    fn menu(menu: Menu) -> Result<tray_icon::menu::Menu, Error> {
+        let native = tray_icon::menu::Menu::new();
+
+        for item in menu.items {
+            let result = match item {
+                Item::Entry { id, label, enabled } => {
+                    native.append(&tray_icon::menu::MenuItem::with_id(
+                        id, label, enabled, None,
+                    ))
+                }
+                Item::Separator => native
+                    .append(&tray_icon::menu::PredefinedMenuItem::separator()),
+            };
+
+            result.map_err(platform_error)?;
+        }
+
+        Ok(native)
+    }
+
+    fn platform_error(error: impl std::fmt::Display) -> Error {
+        Error::Platform(error.to_string())
+    }
+}
+
+#[cfg(not(all(
+    feature = "tray",
+    any(target_os = "windows", target_os = "macos")
+)))]
+mod platform {
+    use super::{Error, Event, Icon, Menu, Tray};
+    use crate::futures::channel::mpsc;
+
+    pub fn register(_tray: Tray) -> Result<(), Error> {
+        Err(Error::Unsupported)
+    }
+
+    pub fn set_icon(_icon: Icon) -> Result<(), Error> {
+        Err(Error::Unsupported)
+    }
+
+    pub fn set_tooltip(_tooltip: Option<String>) -> Result<(), Error> {
+        Err(Error::Unsupported)
+    }
+
+    pub fn set_menu(_menu: Menu) -> Result<(), Error> {
+        Err(Error::Unsupported)
+    }
+
+    pub fn remove() {}
+
+    pub fn listen(_sender: mpsc::UnboundedSender<Event>) {}
+}
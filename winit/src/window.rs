@@ -2,7 +2,10 @@
 use crate::command::{self, Command};
 use iced_native::window;
 
-pub use window::{frames, Event, Mode, RedrawRequest, UserAttention};
+pub use window::{
+    close_requests, frames, monitor, monitor_changes, scale_factor_changes,
+    Event, Level, Mode, Monitor, RedrawRequest, ScaleFactor, UserAttention,
+};
 
 /// Closes the current window and exits the application.
 pub fn close<Message>() -> Command<Message> {
@@ -22,6 +25,15 @@ pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
     }))
 }
 
+/// Resizes the window to fit the measured size of its contents.
+///
+/// Axes where the contents fill the window have no intrinsic size, so they
+/// keep their current size. The new size respects the minimum and maximum
+/// size of the window.
+pub fn resize_to_fit<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::ResizeToFit))
+}
+
 /// Maximizes the window.
 pub fn maximize<Message>(value: bool) -> Command<Message> {
     Command::single(command::Action::Window(window::Action::Maximize(value)))
@@ -37,9 +49,62 @@ pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
     Command::single(command::Action::Window(window::Action::Move { x, y }))
 }
 
+/// Moves the window to the center of the [`Monitor`] with the given
+/// [`monitor::Id`], keeping its logical size.
+///
+/// The window moves to the primary [`Monitor`] if the given one is no
+/// longer connected.
+pub fn move_to_monitor<Message>(id: monitor::Id) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::MoveToMonitor(id)))
+}
+
+/// Fetches the available [`Monitor`]s.
+///
+/// An [`Event::MonitorsChanged`] is produced when a [`Monitor`] is connected
+/// or disconnected.
+pub fn fetch_monitors<Message>(
+    f: impl FnOnce(Vec<Monitor>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::FetchMonitors(
+        Box::new(f),
+    )))
+}
+
+/// Fetches the [`Monitor`] the window is on, if known.
+pub fn fetch_current_monitor<Message>(
+    f: impl FnOnce(Option<Monitor>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::FetchCurrentMonitor(Box::new(f)),
+    ))
+}
+
+/// Fetches the current [`ScaleFactor`] of the window.
+///
+/// An [`Event::ScaleFactorChanged`] is produced whenever it changes.
+pub fn fetch_scale_factor<Message>(
+    f: impl FnOnce(ScaleFactor) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::FetchScaleFactor(
+        Box::new(f),
+    )))
+}
+
 /// Sets the [`Mode`] of the window.
+///
+/// Fullscreen modes use the monitor the window is currently on. Exiting them
+/// restores the size and position the window had before entering them.
+///
+/// Since some platforms do not support every [`Mode`], the resulting one is
+/// reported with an [`Event::ModeChanged`]. See [`Mode`] for the details.
+pub fn set_mode<Message>(mode: Mode) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMode(mode)))
+}
+
+/// Changes the [`Mode`] of the window.
+#[deprecated(note = "use `set_mode` instead")]
 pub fn change_mode<Message>(mode: Mode) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::ChangeMode(mode)))
+    set_mode(mode)
 }
 
 /// Fetches the current [`Mode`] of the window.
@@ -51,6 +116,15 @@ pub fn fetch_mode<Message>(
     )))
 }
 
+/// Sets the [`Level`] of the window.
+///
+/// The applied [`Level`] is reported with an [`Event::LevelChanged`], since
+/// unsupported levels fall back to [`Level::Normal`]. See [`Level`] for the
+/// details.
+pub fn set_level<Message>(level: Level) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetLevel(level)))
+}
+
 /// Toggles the window to maximized or back.
 pub fn toggle_maximize<Message>() -> Command<Message> {
     Command::single(command::Action::Window(window::Action::ToggleMaximize))